@@ -86,62 +86,217 @@ pub enum ErrorCode {
     #[msg("Big note is either already verified or no application for verification has been submitted")]
     BigNoteNotAppliedForVerification, //0x178A
 
-    Reserved27, //0x178B
-    Reserved28, //0x178C
-    Reserved29, //0x178D
-
-    Reserved30, //0x178E
-    Reserved31, //0x178F
-    Reserved32, //0x1790
-    Reserved33, //0x1791
-    Reserved34, //0x1792
-    Reserved35, //0x1793
-    Reserved36, //0x1794
-    Reserved37, //0x1795
-    Reserved38, //0x1796
-    Reserved39, //0x1797
-
-    Reserved40, //0x1798
-    Reserved41, //0x1799
-    Reserved42, //0x179A
-    Reserved43, //0x179B
-    Reserved44, //0x179C
-    Reserved45, //0x179D
-    Reserved46, //0x179E
-    Reserved47, //0x179F
-    Reserved48, //0x17A0
-    Reserved49, //0x17A1
-
-    Reserved50, //0x17A2
-    Reserved51, //0x17A3
-    Reserved52, //0x17A4
-    Reserved53, //0x17A5
-    Reserved54, //0x17A6
-    Reserved55, //0x17A7
-    Reserved56, //0x17A8
-    Reserved57, //0x17A9
-    Reserved58, //0x17AA
-    Reserved59, //0x17AB
-
-    Reserved60, //0x17AC
-    Reserved61, //0x17AD
-    Reserved62, //0x17AE
-    Reserved63, //0x17AF
-    Reserved64, //0x17B0
-    Reserved65, //0x17B1
-    Reserved66, //0x17B2
-    Reserved67, //0x17B3
-    Reserved68, //0x17B4
-    Reserved69, //0x17B5
-
-    Reserved70, //0x17B6
-    Reserved71, //0x17B7
-    Reserved72, //0x17B8
-    Reserved73, //0x17B9
-    Reserved74, //0x17BA
-    Reserved75, //0x17BB
-    Reserved76, //0x17BC
-    Reserved77, //0x17BD
-    Reserved78, //0x17BE
-    Reserved79, //0x17BF
+    #[msg("reply would exceed the maximum comment depth specified in forum constants")]
+    MaxCommentDepthExceeded, //0x178B
+
+    #[msg("all replies to a comment must be deleted before the comment can be deleted")]
+    CommentHasReplies, //0x178C
+
+    #[msg("the commented on account is not a valid comment target of the given type for this forum")]
+    InvalidCommentTarget, //0x178D
+
+    #[msg("the question has been marked as a duplicate and is no longer accepting answers or bounty contributions")]
+    QuestionMarkedAsDuplicate, //0x178E
+
+    #[msg("a question cannot be marked as a duplicate of itself, of a question already marked as a duplicate, or more than once")]
+    InvalidDuplicateQuestion, //0x178F
+
+    #[msg("the question has not been marked as a duplicate of the canonical question provided")]
+    QuestionNotMarkedAsDuplicate, //0x1790
+
+    #[msg("the linked content account is not a valid question, big note or challenge of this forum")]
+    InvalidLinkedContent, //0x1791
+
+    #[msg("the answer provided is not the accepted answer of the question")]
+    AnswerNotAccepted, //0x1792
+
+    #[msg("prize pools require a non-zero winner count, a non-zero prize per winner for first n distributions, and ranked tiers not exceeding the maximum tier count or 10,000 basis points in total")]
+    InvalidPrizeDistribution, //0x1793
+
+    #[msg("the challenge has not yet expired")]
+    ChallengeNotExpired, //0x1794
+
+    #[msg("all prize pool funds must be paid out or refunded before the challenge can be closed")]
+    PrizePoolNotSettled, //0x1795
+
+    #[msg("evaluation panels require between one and the maximum number of distinct judges and a quorum between one and the number of judges")]
+    InvalidEvaluationPanel, //0x1796
+
+    #[msg("the provided profile is not a judge on the challenge's evaluation panel")]
+    ProfileIsNotJudge, //0x1797
+
+    #[msg("the submission evaluation has already been finalized")]
+    SubmissionAlreadyFinalized, //0x1798
+
+    #[msg("challenge rubrics may not exceed the maximum number of criteria and every criterion requires a non-zero maximum score and weight")]
+    InvalidRubric, //0x1799
+
+    #[msg("scores must be given for every criterion of the challenge rubric and may not exceed each criterion's maximum score")]
+    InvalidRubricScores, //0x179A
+
+    #[msg("the challenge leaderboard has already been closed and its reputation awarded")]
+    LeaderboardAlreadyClosed, //0x179B
+
+    #[msg("the user profile accounts provided do not match the ranked leaderboard entries")]
+    InvalidLeaderboardAccounts, //0x179C
+
+    #[msg("the previous challenge in the track must be completed before making a submission to this challenge")]
+    PrerequisiteNotCompleted, //0x179D
+
+    #[msg("the track already contains the maximum number of challenges")]
+    TrackIsFull, //0x179E
+
+    #[msg("completed submissions must be provided for every challenge in the track, in order")]
+    TrackNotCompleted, //0x179F
+
+    #[msg("user profile does not have enough reputation to perform this action")]
+    InsufficientReputation, //0x17A0
+
+    #[msg("challenge has not been approved by a moderator")]
+    ChallengeNotApproved, //0x17A1
+
+    #[msg("challenge proposal has already been approved or rejected")]
+    ProposalAlreadyReviewed, //0x17A2
+
+    #[msg("only rejected submissions can be resubmitted")]
+    SubmissionNotRejected, //0x17A3
+
+    #[msg("maximum number of attempts for this submission has been reached")]
+    MaxSubmissionAttemptsReached, //0x17A4
+
+    #[msg("challenge judging period has ended")]
+    JudgingPeriodEnded, //0x17A5
+
+    #[msg("challenge has already been closed")]
+    ChallengeAlreadyClosed, //0x17A6
+
+    #[msg("challenge has not been closed")]
+    ChallengeNotClosed, //0x17A7
+
+    #[msg("challenge still has submissions and must be archived before deletion")]
+    ChallengeHasSubmissions, //0x17A8
+
+    #[msg("only users who have completed the challenge can peer review other submissions")]
+    ProfileCannotPeerReview, //0x17A9
+
+    #[msg("peer review has already been settled")]
+    PeerReviewAlreadySettled, //0x17AA
+
+    #[msg("submission has not yet been finalized for this attempt")]
+    SubmissionNotFinalized, //0x17AB
+
+    #[msg("user profile must be a moderator or have enough reputation to attest, and cannot attest their own big note")]
+    ProfileCannotAttest, //0x17AC
+
+    #[msg("attestation stake cannot be withdrawn while its verification round is active")]
+    AttestationStillActive, //0x17AD
+
+    #[msg("big note is not verified")]
+    BigNoteNotVerified, //0x17AE
+
+    #[msg("attestation accounts provided do not belong to the big note's current verification round")]
+    InvalidAttestationAccounts, //0x17AF
+
+    #[msg("big note verification has not expired")]
+    VerificationNotExpired, //0x17B0
+
+    #[msg("big note is not a fork of the given parent big note")]
+    InvalidBigNoteFork, //0x17B1
+
+    #[msg("profile is not the current owner")]
+    NotCurrentOwner, //0x17B2
+
+    #[msg("profile is not the pending owner")]
+    NotPendingOwner, //0x17B3
+
+    #[msg("wallet has not been approved to take over the user profile")]
+    WalletNotApprovedForRotation, //0x17B4
+
+    #[msg("wallet already has a user profile in this forum")]
+    WalletAlreadyHasProfile, //0x17B5
+
+    #[msg("signer is neither the profile owner nor a registered delegate")]
+    InvalidSessionKey, //0x17B6
+
+    #[msg("session key has expired")]
+    SessionKeyExpired, //0x17B7
+
+    #[msg("session key scope does not permit this action")]
+    SessionScopeNotPermitted, //0x17B8
+
+    #[msg("token account does not hold the NFT for the profile owner")]
+    InvalidNftPfp, //0x17B9
+
+    #[msg("NFT is not a verified member of an allowed collection")]
+    NftPfpCollectionNotAllowed, //0x17BA
+
+    #[msg("NFT PFP is still held by the profile owner")]
+    NftPfpStillHeld, //0x17BB
+
+    #[msg("too many collections in the PFP collection allowlist")]
+    TooManyPfpCollections, //0x17BC
+
+    #[msg("handle does not meet the forum's length and character rules")]
+    InvalidHandle, //0x17BD
+
+    #[msg("user profile already has a handle")]
+    ProfileAlreadyHasHandle, //0x17BE
+
+    #[msg("handle length limits must be non-zero, ordered and at most 32 characters")]
+    InvalidHandleRules, //0x17BF
+
+    #[msg("user profile's handle must be released first")]
+    HandleNotReleased, //0x17C0
+
+    #[msg("user profile cannot follow itself")]
+    CannotFollowSelf, //0x17C1
+
+    #[msg("subscribed to account does not match the subscription target")]
+    InvalidSubscriptionTarget, //0x17C2
+
+    #[msg("wallet does not meet the forum's access policy")]
+    NotEligibleForForum, //0x17C3
+
+    #[msg("user profile is suspended from the forum")]
+    ProfileSuspended, //0x17C4
+
+    #[msg("user profile eligibility recheck is not yet due")]
+    EligibilityRecheckNotDue, //0x17C5
+
+    #[msg("category moderators exceed the maximum allowed")]
+    TooManyCategoryModerators, //0x17C6
+
+    #[msg("submission cannot be deleted once it has been voted on, scored, peer reviewed, rejected or resubmitted")]
+    SubmissionCannotBeDeleted, //0x17C7
+
+    #[msg("prize token accounts do not match the prize pool's vault and mint")]
+    InvalidPrizeTokenAccounts, //0x17C8
+
+    #[msg("prize pool funding closes once the first prize has been paid")]
+    PrizePoolFundingClosed, //0x17C9
+
+    #[msg("prize token can only be set before the prize pool is funded")]
+    PrizePoolAlreadyFunded, //0x17CA
+
+    #[msg("only completed submissions can be scored")]
+    SubmissionNotCompleted, //0x17CB
+
+    #[msg("Unfollow all followed profiles before deleting the user profile")]
+    ProfileStillFollowing, //0x17CC
+
+    #[msg("Wallet rotation account does not belong to this user profile")]
+    InvalidWalletRotation, //0x17CD
+
+    #[msg("Challenge leaderboard must be closed before ranked prizes are refunded")]
+    LeaderboardNotClosed, //0x17CE
+
+    #[msg("Challenge proposal has not been reviewed")]
+    ProposalNotReviewed, //0x17CF
+
+    #[msg("Challenge must be removed from its track first")]
+    ChallengeInTrack, //0x17D0
+
+    #[msg("Only the last challenge of a track can be removed")]
+    ChallengeNotLastInTrack, //0x17D1
+
 }
@@ -13,13 +13,13 @@ pub struct AcceptAnswer<'info> {
     pub profile_owner: Signer<'info>,
 
     // The user profile
-    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), profile_owner.key().as_ref()],
+    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), user_profile.profile_seed.as_ref()],
               bump = bump_user_profile, has_one = forum, has_one = profile_owner)]
     pub user_profile: Box<Account<'info, UserProfile>>,
 
     // Question PDA account and seed
-    #[account(mut, seeds = [b"question".as_ref(), forum.key().as_ref(), user_profile.key().as_ref(), question_seed.key().as_ref()],
-              bump = bump_question, has_one = forum, has_one = user_profile, has_one = question_seed)]
+    #[account(mut, seeds = [b"question".as_ref(), forum.key().as_ref(), question.user_profile.as_ref(), question_seed.key().as_ref()],
+              bump = bump_question, has_one = forum, has_one = question_seed)]
     pub question: Box<Account<'info, Question>>,
 
     /// CHECK: The seed address used for initialization of the question PDA
@@ -34,7 +34,7 @@ pub struct AcceptAnswer<'info> {
     pub answer_profile_owner: AccountInfo<'info>,
 
     // The user profile
-    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), answer_profile_owner.key().as_ref()],
+    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), answer_user_profile.profile_seed.as_ref()],
               bump = bump_answer_user_profile, has_one = forum, constraint = answer_user_profile.profile_owner == answer_profile_owner.key())]
     pub answer_user_profile: Box<Account<'info, UserProfile>>,
 
@@ -57,6 +57,16 @@ pub fn accept_answer(ctx:Context<AcceptAnswer>) -> Result<()> {
 
     let now_ts = now_ts()?;
 
+    // Ensure the user profile has not been suspended from the forum
+    if ctx.accounts.user_profile.is_suspended {
+        return Err(error!(ErrorCode::ProfileSuspended));
+    }
+
+    // Ensure the signer's profile is the current owner of the question
+    if ctx.accounts.question.current_owner != ctx.accounts.user_profile.key() {
+        return Err(error!(ErrorCode::NotCurrentOwner));
+    }
+
     // Ensure there is not already an accepted answer for this question
     let is_bounty_awarded = ctx.accounts.question.bounty_awarded;
     if is_bounty_awarded {
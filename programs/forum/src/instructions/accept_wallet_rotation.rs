@@ -0,0 +1,83 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{Forum, UserProfile, WalletRotation};
+use prog_common::{close_account, now_ts, errors::ErrorCode};
+
+#[derive(Accounts)]
+#[instruction(bump_user_profile: u8, bump_new_wallet_user_profile: u8, bump_previous_wallet_rotation: u8)]
+pub struct AcceptWalletRotation<'info> {
+
+    // Forum
+    pub forum: Box<Account<'info, Forum>>,
+
+    #[account(mut)]
+    pub new_profile_owner: Signer<'info>,
+
+    // The user profile being taken over
+    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), user_profile.profile_seed.as_ref()],
+              bump = bump_user_profile, has_one = forum)]
+    pub user_profile: Box<Account<'info, UserProfile>>,
+
+    /// CHECK: The user profile PDA account seeded by the new wallet (must be empty)
+    #[account(seeds = [b"user_profile".as_ref(), forum.key().as_ref(), new_profile_owner.key().as_ref()], bump = bump_new_wallet_user_profile)]
+    pub new_wallet_user_profile: AccountInfo<'info>,
+
+    // Wallet rotation PDA account, reserving the new wallet for the user profile
+    #[account(init, seeds = [b"wallet_rotation".as_ref(), forum.key().as_ref(), new_profile_owner.key().as_ref()],
+              bump, payer = new_profile_owner, space = 8 + std::mem::size_of::<WalletRotation>())]
+    pub wallet_rotation: Box<Account<'info, WalletRotation>>,
+
+    /// CHECK: The wallet rotation PDA account of the current profile owner (non-empty if the user profile was rotated before)
+    #[account(mut, seeds = [b"wallet_rotation".as_ref(), forum.key().as_ref(), user_profile.profile_owner.as_ref()], bump = bump_previous_wallet_rotation)]
+    pub previous_wallet_rotation: AccountInfo<'info>,
+
+    /// CHECK: The current profile owner, receiving the rent of its wallet rotation PDA account
+    #[account(mut, address = user_profile.profile_owner)]
+    pub previous_profile_owner: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn accept_wallet_rotation(ctx: Context<AcceptWalletRotation>) -> Result<()> {
+
+    let now_ts: u64 = now_ts()?;
+
+    // Ensure the new wallet has been approved by the current profile owner
+    if ctx.accounts.user_profile.pending_profile_owner != ctx.accounts.new_profile_owner.key() {
+        return Err(error!(ErrorCode::WalletNotApprovedForRotation));
+    }
+
+    // Ensure the new wallet does not already have a user profile of its own
+    if !ctx.accounts.new_wallet_user_profile.data_is_empty() {
+        return Err(error!(ErrorCode::WalletAlreadyHasProfile));
+    }
+
+    // Close the current profile owner's wallet rotation state account (if it exists) so that wallet is freed again
+    if !ctx.accounts.previous_wallet_rotation.data_is_empty() {
+        let previous_wallet_rotation = Account::<WalletRotation>::try_from(&ctx.accounts.previous_wallet_rotation)?;
+
+        if previous_wallet_rotation.user_profile != ctx.accounts.user_profile.key() {
+            return Err(error!(ErrorCode::InvalidWalletRotation));
+        }
+
+        close_account(&mut ctx.accounts.previous_wallet_rotation, &mut ctx.accounts.previous_profile_owner)?;
+    }
+
+    // Record Wallet Rotation's State
+    let wallet_rotation = &mut ctx.accounts.wallet_rotation;
+    wallet_rotation.forum = ctx.accounts.forum.key();
+    wallet_rotation.user_profile = ctx.accounts.user_profile.key();
+    wallet_rotation.previous_profile_owner = ctx.accounts.user_profile.profile_owner;
+    wallet_rotation.new_profile_owner = ctx.accounts.new_profile_owner.key();
+    wallet_rotation.rotated_ts = now_ts;
+
+    // Re-point the user profile to the new wallet
+    let user_profile = &mut ctx.accounts.user_profile;
+    user_profile.profile_owner = ctx.accounts.new_profile_owner.key();
+    user_profile.pending_profile_owner = Pubkey::default();
+    user_profile.most_recent_engagement_ts = now_ts;
+
+    msg!("User profile with pubkey {} now owned by wallet with address {}",
+         ctx.accounts.user_profile.key(), ctx.accounts.new_profile_owner.key());
+    Ok(())
+}
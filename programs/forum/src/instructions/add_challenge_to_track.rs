@@ -0,0 +1,93 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{Challenge, ChallengeProposal, Forum, MAX_TRACK_CHALLENGES, Track, TrackMembership, UserProfile};
+use prog_common::{now_ts, TryAdd, errors::ErrorCode};
+
+#[derive(Accounts)]
+#[instruction(bump_moderator_profile: u8, bump_track: u8, bump_challenge: u8, bump_challenge_proposal: u8)]
+pub struct AddChallengeToTrack<'info> {
+
+    // Forum
+    pub forum: Box<Account<'info, Forum>>,
+
+    #[account(mut)]
+    pub moderator: Signer<'info>,
+
+    // The moderator profile
+    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), moderator_profile.profile_seed.as_ref()],
+              bump = bump_moderator_profile, has_one = forum, constraint = moderator_profile.profile_owner == moderator.key())]
+    pub moderator_profile: Box<Account<'info, UserProfile>>,
+
+    // Track PDA account and seed
+    #[account(mut, seeds = [b"track".as_ref(), forum.key().as_ref(), track_seed.key().as_ref()],
+              bump = bump_track, has_one = forum, has_one = track_seed)]
+    pub track: Box<Account<'info, Track>>,
+
+    /// CHECK: The seed address used for initialization of the track PDA
+    pub track_seed: AccountInfo<'info>,
+
+    // Challenge PDA account and seed
+    #[account(seeds = [b"challenge".as_ref(), forum.key().as_ref(), challenge_seed.key().as_ref()],
+              bump = bump_challenge, has_one = forum, has_one = challenge_seed)]
+    pub challenge: Box<Account<'info, Challenge>>,
+
+    /// CHECK: The seed address used for initialization of the challenge PDA
+    pub challenge_seed: AccountInfo<'info>,
+
+    /// CHECK: The challenge's proposal PDA account (empty if the challenge was created by a moderator)
+    #[account(seeds = [b"challenge_proposal".as_ref(), challenge.key().as_ref()], bump = bump_challenge_proposal)]
+    pub challenge_proposal: AccountInfo<'info>,
+
+    // Track membership PDA account (a challenge can belong to at most one track)
+    #[account(init, seeds = [b"track_membership".as_ref(), challenge.key().as_ref()],
+              bump, payer = moderator, space = 8 + std::mem::size_of::<TrackMembership>())]
+    pub track_membership: Box<Account<'info, TrackMembership>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn add_challenge_to_track(ctx: Context<AddChallengeToTrack>) -> Result<()> {
+
+    let now_ts: u64 = now_ts()?;
+
+    if !ctx.accounts.moderator_profile.is_moderator {
+        return Err(error!(ErrorCode::ProfileIsNotModerator));
+    }
+
+    // Ensure a community proposed challenge has been approved by a moderator
+    if !ChallengeProposal::is_challenge_approved(&ctx.accounts.challenge_proposal)? {
+        return Err(error!(ErrorCode::ChallengeNotApproved));
+    }
+
+    let track_position: usize = ctx.accounts.track.challenge_count as usize;
+
+    if track_position >= MAX_TRACK_CHALLENGES {
+        return Err(error!(ErrorCode::TrackIsFull));
+    }
+
+    // The first challenge in a track has no prerequisite
+    let prerequisite_challenge: Pubkey = match track_position {
+        0 => Pubkey::default(),
+        _ => ctx.accounts.track.challenges[track_position - 1],
+    };
+
+    // Update the track's state account
+    let track = &mut ctx.accounts.track;
+    track.challenges[track_position] = ctx.accounts.challenge.key();
+    track.challenge_count.try_add_assign(1)?;
+
+    // Record Track Membership's State
+    let track_membership = &mut ctx.accounts.track_membership;
+    track_membership.track = ctx.accounts.track.key();
+    track_membership.challenge = ctx.accounts.challenge.key();
+    track_membership.prerequisite_challenge = prerequisite_challenge;
+    track_membership.track_position = track_position as u64;
+
+    // Update the moderator profile's state account
+    let moderator_profile = &mut ctx.accounts.moderator_profile;
+    moderator_profile.most_recent_engagement_ts = now_ts;
+
+    msg!("Challenge with address {} added to track with address {} at position {}",
+         ctx.accounts.challenge.key(), ctx.accounts.track.key(), track_position);
+    Ok(())
+}
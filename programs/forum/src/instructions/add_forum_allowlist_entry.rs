@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{Forum, ForumAllowlistEntry};
+use prog_common::{now_ts};
+
+#[derive(Accounts)]
+pub struct AddForumAllowlistEntry<'info> {
+
+    // Forum and Forum Manager
+    #[account(has_one = forum_manager)]
+    pub forum: Box<Account<'info, Forum>>,
+
+    #[account(mut)]
+    pub forum_manager: Signer<'info>,
+
+    /// CHECK: The wallet to be admitted to the forum
+    pub wallet: AccountInfo<'info>,
+
+    // Forum allowlist entry PDA account
+    #[account(init, seeds = [b"forum_allowlist_entry".as_ref(), forum.key().as_ref(), wallet.key().as_ref()],
+              bump, payer = forum_manager, space = 8 + std::mem::size_of::<ForumAllowlistEntry>())]
+    pub forum_allowlist_entry: Box<Account<'info, ForumAllowlistEntry>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn add_forum_allowlist_entry(ctx: Context<AddForumAllowlistEntry>) -> Result<()> {
+
+    let now_ts: u64 = now_ts()?;
+
+    // Record Forum Allowlist Entry's State
+    let forum_allowlist_entry = &mut ctx.accounts.forum_allowlist_entry;
+    forum_allowlist_entry.forum = ctx.accounts.forum.key();
+    forum_allowlist_entry.wallet = ctx.accounts.wallet.key();
+    forum_allowlist_entry.entry_added_ts = now_ts;
+
+    msg!("Wallet with address {} added to the allowlist of forum {}",
+         ctx.accounts.wallet.key(), ctx.accounts.forum.key());
+    Ok(())
+}
@@ -15,7 +15,7 @@ pub struct AddModerator<'info> {
     pub profile_owner: AccountInfo<'info>,
 
     // The user profile
-    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), profile_owner.key().as_ref()],
+    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), user_profile.profile_seed.as_ref()],
               bump = bump_user_profile, has_one = forum, has_one = profile_owner)]
     pub user_profile: Box<Account<'info, UserProfile>>,
 
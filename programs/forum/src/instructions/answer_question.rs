@@ -1,31 +1,36 @@
 use anchor_lang::prelude::*;
 
-use crate::state::{Answer, Forum, Question, UserProfile};
-use prog_common::{now_ts, TryAdd};
+use crate::state::{Answer, Forum, Question, SessionScope, UserProfile};
+use prog_common::{now_ts, TryAdd, errors::ErrorCode};
 
 #[derive(Accounts)]
-#[instruction(bump_user_profile: u8)]
+#[instruction(bump_user_profile: u8, bump_session_key: u8)]
 pub struct AnswerQuestion<'info> {
 
     // Forum
     #[account(mut)]
     pub forum: Box<Account<'info, Forum>>,
 
+    // The profile owner or a delegate holding a session key for the user profile
     #[account(mut)]
-    pub profile_owner: Signer<'info>,
+    pub authority: Signer<'info>,
 
     // The user profile
-    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), profile_owner.key().as_ref()],
-              bump = bump_user_profile, has_one = forum, has_one = profile_owner)]
+    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), user_profile.profile_seed.as_ref()],
+              bump = bump_user_profile, has_one = forum)]
     pub user_profile: Box<Account<'info, UserProfile>>,
 
+    /// CHECK: Session key PDA account of the signer (empty when the profile owner signs)
+    #[account(seeds = [b"session_key".as_ref(), user_profile.key().as_ref(), authority.key().as_ref()], bump = bump_session_key)]
+    pub session_key: AccountInfo<'info>,
+
     // Question PDA account
     #[account(mut, has_one = forum)]
     pub question: Box<Account<'info, Question>>,
 
     // Answer PDA account and seed
     #[account(init, seeds = [b"answer".as_ref(), forum.key().as_ref(), user_profile.key().as_ref(), answer_seed.key().as_ref()],
-              bump, payer = profile_owner, space = 8 + std::mem::size_of::<Answer>())]
+              bump, payer = authority, space = 8 + std::mem::size_of::<Answer>())]
     pub answer: Box<Account<'info, Answer>>,
 
     /// CHECK: The seed address used for initialization of the answer PDA
@@ -41,8 +46,22 @@ pub struct AnswerQuestion<'info> {
 pub fn answer_question(ctx: Context<AnswerQuestion>) -> Result<()> {
 
     let now_ts: u64 = now_ts()?;
+
+    // Ensure the user profile has not been suspended from the forum
+    if ctx.accounts.user_profile.is_suspended {
+        return Err(error!(ErrorCode::ProfileSuspended));
+    }
+
+    // Ensure the signer is the profile owner or a delegate with a valid session key
+    SessionScope::CommentAndAnswer.verify_authority(&ctx.accounts.user_profile.profile_owner, &ctx.accounts.authority.key(), &ctx.accounts.session_key, now_ts)?;
+
     let answer_rep = ctx.accounts.forum.reputation_matrix.answer_rep;
 
+    // Ensure question has not been marked as a duplicate
+    if ctx.accounts.question.duplicate_of != Pubkey::default() {
+        return Err(error!(ErrorCode::QuestionMarkedAsDuplicate));
+    }
+
     // Record Answer's State
     let answer = &mut ctx.accounts.answer;
     answer.question = ctx.accounts.question.key();
@@ -56,6 +75,7 @@ pub fn answer_question(ctx: Context<AnswerQuestion>) -> Result<()> {
     answer.answer_rep = answer_rep;
     answer.accepted_answer = false;
     answer.accepted_answer_rep = 0;
+    answer.revision_count = 0;
 
     // Increment answer count in forum state's account
     let forum = &mut ctx.accounts.forum;
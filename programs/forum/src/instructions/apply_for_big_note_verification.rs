@@ -3,8 +3,8 @@ use anchor_lang::prelude::*;
 use anchor_lang::solana_program::program::{invoke};
 use anchor_lang::solana_program::system_instruction;
 
-use crate::state::{BigNote, BigNoteVerificationApplication, BigNoteVerificationState, Forum, UserProfile};
-use prog_common::{now_ts, errors::ErrorCode};
+use crate::state::{ATTESTATION_SLASHING_WINDOW, BigNote, BigNoteVerificationApplication, BigNoteVerificationRecord, BigNoteVerificationState, Category, Forum, UserProfile};
+use prog_common::{now_ts, TryAdd, errors::ErrorCode};
 
 #[derive(Accounts)]
 #[instruction(bump_user_profile: u8, bump_big_note: u8)]
@@ -17,15 +17,19 @@ pub struct ApplyForBigNoteVerification<'info> {
     pub profile_owner: Signer<'info>,
 
     // The user profile
-    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), profile_owner.key().as_ref()],
+    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), user_profile.profile_seed.as_ref()],
               bump = bump_user_profile, has_one = forum, has_one = profile_owner)]
     pub user_profile: Box<Account<'info, UserProfile>>,
 
     // Big Note pda account and seed
-    #[account(mut, seeds = [b"big_note".as_ref(), forum.key().as_ref(), user_profile.key().as_ref(), big_note_seed.key().as_ref()],
-              bump = bump_big_note, has_one = forum, has_one = user_profile, has_one = big_note_seed)]
+    #[account(mut, seeds = [b"big_note".as_ref(), forum.key().as_ref(), big_note.user_profile.as_ref(), big_note_seed.key().as_ref()],
+              bump = bump_big_note, has_one = forum, has_one = big_note_seed)]
     pub big_note: Box<Account<'info, BigNote>>,
 
+    // Category of the big note, whose fee overrides apply
+    #[account(has_one = forum, constraint = category.key() == big_note.category)]
+    pub category: Box<Account<'info, Category>>,
+
     /// CHECK: The seed address used for initialization of the big note PDA
     pub big_note_seed: AccountInfo<'info>,
 
@@ -38,6 +42,11 @@ pub struct ApplyForBigNoteVerification<'info> {
     #[account(init, seeds = [b"verification_fee_pda".as_ref(), big_note.key().as_ref()], bump, payer = profile_owner, space = 8)]
     pub verification_fee_pda: AccountInfo<'info>,
 
+    // Big Note Verification Record PDA account
+    #[account(init_if_needed, seeds = [b"verification_record".as_ref(), big_note.key().as_ref()],
+              bump, payer = profile_owner, space = 8 + std::mem::size_of::<BigNoteVerificationRecord>())]
+    pub verification_record: Box<Account<'info, BigNoteVerificationRecord>>,
+
     pub system_program: Program<'info, System>,
 }
 
@@ -59,11 +68,22 @@ impl<'info> ApplyForBigNoteVerification<'info> {
 pub fn apply_for_big_note_verification(ctx: Context<ApplyForBigNoteVerification>) -> Result<()> {
 
     let now_ts: u64 = now_ts()?;
-    let verification_fee: u64 = ctx.accounts.forum.forum_fees.forum_big_notes_verification_fee;
+
+    // Ensure the user profile has not been suspended from the forum
+    if ctx.accounts.user_profile.is_suspended {
+        return Err(error!(ErrorCode::ProfileSuspended));
+    }
+
+    // Ensure the signer's profile is the current owner of the big note
+    if ctx.accounts.big_note.current_owner != ctx.accounts.user_profile.key() {
+        return Err(error!(ErrorCode::NotCurrentOwner));
+    }
+    let verification_fee: u64 = ctx.accounts.category.fees(&ctx.accounts.forum.forum_fees).forum_big_notes_verification_fee;
     let big_note_verification_state = ctx.accounts.big_note.verification_state;
 
     // Ensure big note is not already verified or an application for verification has not already been submitted
-    if !(big_note_verification_state == BigNoteVerificationState::Unverified) {
+    if !((big_note_verification_state == BigNoteVerificationState::Unverified) ||
+        (big_note_verification_state == BigNoteVerificationState::NeedsReverification)) {
         return Err(error!(ErrorCode::BigNoteNotUnverified));
     }
 
@@ -76,6 +96,23 @@ pub fn apply_for_big_note_verification(ctx: Context<ApplyForBigNoteVerification>
     verification_application.verification_fee_pda = ctx.accounts.verification_fee_pda.key();
     verification_application.verification_fee = verification_fee;
 
+    // Start a new verification round requiring the forum's current quorum of attestations
+    let verification_record = &mut ctx.accounts.verification_record;
+    if verification_record.big_note == Pubkey::default() {
+        verification_record.big_note = ctx.accounts.big_note.key();
+    } else {
+        // Keep the attestations of a verification needing reverification slashable for the rest of the slashing window
+        if big_note_verification_state == BigNoteVerificationState::NeedsReverification {
+            verification_record.slashable_round = verification_record.verification_round;
+            verification_record.slashable_until_ts = verification_record.reverification_ts.try_add(ATTESTATION_SLASHING_WINDOW)?;
+        }
+
+        verification_record.verification_round.try_add_assign(1)?;
+    }
+    verification_record.attestation_count = 0;
+    verification_record.reverification_ts = 0;
+    verification_record.quorum = ctx.accounts.forum.forum_constants.big_note_verification_quorum.max(1);
+
     // Update big note account's most recent engagement timestamp and verification state
     let big_note = &mut ctx.accounts.big_note;
     big_note.most_recent_engagement_ts = now_ts;
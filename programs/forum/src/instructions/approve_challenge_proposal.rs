@@ -0,0 +1,102 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{Category, Challenge, ChallengeProposal, ChallengeProposalState, Forum, UserProfile};
+use prog_common::{now_ts, TryAdd, TrySub, errors::ErrorCode};
+
+#[derive(Accounts)]
+#[instruction(bump_moderator_profile: u8, bump_author_profile: u8, bump_challenge: u8, bump_challenge_proposal: u8)]
+pub struct ApproveChallengeProposal<'info> {
+
+    // Forum
+    #[account(mut)]
+    pub forum: Box<Account<'info, Forum>>,
+
+    pub moderator: Signer<'info>,
+
+    // The moderator profile
+    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), moderator_profile.profile_seed.as_ref()],
+              bump = bump_moderator_profile, has_one = forum, constraint = moderator_profile.profile_owner == moderator.key())]
+    pub moderator_profile: Box<Account<'info, UserProfile>>,
+
+    /// CHECK: Used for seed verification of author profile pda account and receives the refunded deposit
+    #[account(mut)]
+    pub author: AccountInfo<'info>,
+
+    // The challenge author's profile
+    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), author_profile.profile_seed.as_ref()],
+              bump = bump_author_profile, has_one = forum, constraint = author_profile.profile_owner == author.key())]
+    pub author_profile: Box<Account<'info, UserProfile>>,
+
+    // Challenge PDA account and seed
+    #[account(seeds = [b"challenge".as_ref(), forum.key().as_ref(), challenge_seed.key().as_ref()],
+              bump = bump_challenge, has_one = forum, has_one = challenge_seed)]
+    pub challenge: Box<Account<'info, Challenge>>,
+
+    // Category of the challenge, whose moderators may also moderate it
+    #[account(mut, has_one = forum, constraint = category.key() == challenge.category)]
+    pub category: Box<Account<'info, Category>>,
+
+    /// CHECK: The seed address used for initialization of the challenge PDA
+    pub challenge_seed: AccountInfo<'info>,
+
+    // Challenge proposal PDA account
+    #[account(mut, seeds = [b"challenge_proposal".as_ref(), challenge.key().as_ref()],
+              bump = bump_challenge_proposal, has_one = forum, has_one = challenge, has_one = author_profile)]
+    pub challenge_proposal: Box<Account<'info, ChallengeProposal>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn approve_challenge_proposal(ctx: Context<ApproveChallengeProposal>) -> Result<()> {
+
+    let now_ts: u64 = now_ts()?;
+
+    if !ctx.accounts.moderator_profile.is_moderator && !ctx.accounts.category.is_category_moderator(&ctx.accounts.moderator_profile.key()) {
+        return Err(error!(ErrorCode::ProfileIsNotModerator));
+    }
+
+    // Ensure moderators do not review their own proposals
+    if ctx.accounts.author_profile.key() == ctx.accounts.moderator_profile.key() {
+        return Err(error!(ErrorCode::InvalidParameter));
+    }
+
+    // Ensure the proposal has not already been reviewed
+    if ctx.accounts.challenge_proposal.proposal_state != ChallengeProposalState::Pending {
+        return Err(error!(ErrorCode::ProposalAlreadyReviewed));
+    }
+
+    // Refund the deposit to the author
+    let deposit_amount: u64 = ctx.accounts.challenge_proposal.deposit_amount;
+
+    let challenge_proposal_account_info = ctx.accounts.challenge_proposal.to_account_info();
+    let author_account_info = ctx.accounts.author.to_account_info();
+
+    **challenge_proposal_account_info.lamports.borrow_mut() = challenge_proposal_account_info.lamports().try_sub(deposit_amount)?;
+    **author_account_info.lamports.borrow_mut() = author_account_info.lamports().try_add(deposit_amount)?;
+
+    // Update the challenge proposal's state account
+    let challenge_proposal = &mut ctx.accounts.challenge_proposal;
+    challenge_proposal.proposal_reviewed_ts = now_ts;
+    challenge_proposal.deposit_amount = 0;
+    challenge_proposal.proposal_state = ChallengeProposalState::Approved;
+
+    // Count the challenge in forum's and category's state accounts now that it has been approved
+    let forum = &mut ctx.accounts.forum;
+    forum.forum_counts.forum_challenge_count.try_add_assign(1)?;
+
+    let category = &mut ctx.accounts.category;
+    category.challenge_count.try_add_assign(1)?;
+
+    // Award reputation to the author
+    let approved_challenge_proposal_rep = ctx.accounts.forum.reputation_matrix.approved_challenge_proposal_rep;
+
+    let author_profile = &mut ctx.accounts.author_profile;
+    author_profile.reputation_score.try_add_assign(approved_challenge_proposal_rep)?;
+
+    // Update the moderator profile's state account
+    let moderator_profile = &mut ctx.accounts.moderator_profile;
+    moderator_profile.most_recent_engagement_ts = now_ts;
+
+    msg!("Challenge proposal PDA account with address {} now approved", ctx.accounts.challenge_proposal.key());
+    Ok(())
+}
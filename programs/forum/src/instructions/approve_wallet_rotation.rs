@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{Forum, UserProfile};
+use prog_common::{now_ts};
+
+#[derive(Accounts)]
+#[instruction(bump_user_profile: u8)]
+pub struct ApproveWalletRotation<'info> {
+
+    // Forum
+    pub forum: Box<Account<'info, Forum>>,
+
+    pub profile_owner: Signer<'info>,
+
+    // The user profile
+    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), user_profile.profile_seed.as_ref()],
+              bump = bump_user_profile, has_one = forum, has_one = profile_owner)]
+    pub user_profile: Box<Account<'info, UserProfile>>,
+
+    /// CHECK: The wallet approved to take over the user profile
+    pub new_profile_owner: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn approve_wallet_rotation(ctx: Context<ApproveWalletRotation>) -> Result<()> {
+
+    let now_ts: u64 = now_ts()?;
+
+    // Approve the new wallet (approving the current wallet cancels a pending rotation)
+    let new_profile_owner_key: Pubkey = ctx.accounts.new_profile_owner.key();
+    let user_profile = &mut ctx.accounts.user_profile;
+
+    user_profile.pending_profile_owner = if new_profile_owner_key == user_profile.profile_owner {
+        Pubkey::default()
+    } else {
+        new_profile_owner_key
+    };
+
+    user_profile.most_recent_engagement_ts = now_ts;
+
+    msg!("Wallet with address {} approved to take over user profile with pubkey {}",
+         ctx.accounts.user_profile.pending_profile_owner, ctx.accounts.user_profile.key());
+    Ok(())
+}
@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{Category, Challenge, ChallengeState, Forum, UserProfile};
+use prog_common::{now_ts, errors::ErrorCode};
+
+#[derive(Accounts)]
+#[instruction(bump_moderator_profile: u8, bump_challenge: u8)]
+pub struct ArchiveChallenge<'info> {
+
+    // Forum
+    pub forum: Box<Account<'info, Forum>>,
+
+    pub moderator: Signer<'info>,
+
+    // The moderator profile
+    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), moderator_profile.profile_seed.as_ref()],
+              bump = bump_moderator_profile, has_one = forum, constraint = moderator_profile.profile_owner == moderator.key())]
+    pub moderator_profile: Box<Account<'info, UserProfile>>,
+
+    // Challenge PDA account and seed
+    #[account(mut, seeds = [b"challenge".as_ref(), forum.key().as_ref(), challenge_seed.key().as_ref()],
+              bump = bump_challenge, has_one = forum, has_one = challenge_seed)]
+    pub challenge: Box<Account<'info, Challenge>>,
+
+    // Category of the challenge, whose moderators may also moderate it
+    #[account(has_one = forum, constraint = category.key() == challenge.category)]
+    pub category: Box<Account<'info, Category>>,
+
+    /// CHECK: The seed address used for initialization of the challenge PDA
+    pub challenge_seed: AccountInfo<'info>,
+}
+
+pub fn archive_challenge(ctx: Context<ArchiveChallenge>) -> Result<()> {
+
+    let now_ts: u64 = now_ts()?;
+
+    if !ctx.accounts.moderator_profile.is_moderator && !ctx.accounts.category.is_category_moderator(&ctx.accounts.moderator_profile.key()) {
+        return Err(error!(ErrorCode::ProfileIsNotModerator));
+    }
+
+    // Ensure challenge has been closed
+    if ctx.accounts.challenge.challenge_state != ChallengeState::Closed {
+        return Err(error!(ErrorCode::ChallengeNotClosed));
+    }
+
+    // Update challenge account's state
+    let challenge = &mut ctx.accounts.challenge;
+    challenge.challenge_state = ChallengeState::Archived;
+
+    // Update the moderator profile's state account
+    let moderator_profile = &mut ctx.accounts.moderator_profile;
+    moderator_profile.most_recent_engagement_ts = now_ts;
+
+    msg!("Challenge PDA account with address {} has been archived by moderator profile with pubkey {}",
+         ctx.accounts.challenge.key(), ctx.accounts.moderator_profile.key());
+    Ok(())
+}
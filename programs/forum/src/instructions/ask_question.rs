@@ -4,7 +4,7 @@ use anchor_lang::solana_program::hash::hash;
 use anchor_lang::solana_program::program::{invoke, invoke_signed};
 use anchor_lang::solana_program::system_instruction::{self, create_account};
 
-use crate::state::{BountyContribution, BountyContributionState, Forum, Tags, UserProfile};
+use crate::state::{BountyContribution, BountyContributionState, Category, Forum, Tags, UserProfile};
 use prog_common::{now_ts, TryAdd, TrySub, TryDiv, TryMul, errors::ErrorCode};
 
 #[derive(Accounts)]
@@ -23,10 +23,14 @@ pub struct AskQuestion<'info> {
     pub profile_owner: Signer<'info>,
 
     // The user profile
-    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), profile_owner.key().as_ref()],
+    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), user_profile.profile_seed.as_ref()],
               bump = bump_user_profile, has_one = forum, has_one = profile_owner)]
     pub user_profile: Box<Account<'info, UserProfile>>,
 
+    // Category in which the question is asked
+    #[account(mut, has_one = forum)]
+    pub category: Box<Account<'info, Category>>,
+
     /// CHECK:
     #[account(mut)]
     pub question: AccountInfo<'info>,
@@ -75,11 +79,21 @@ impl<'info> AskQuestion<'info> {
 pub fn ask_question(ctx: Context<AskQuestion>, tags: Vec<Tags>, title: String, content_data_url: String, bounty_amount: u64) -> Result<()> {
 
     let now_ts: u64 = now_ts()?;
+
+    // Ensure the user profile has not been suspended from the forum
+    if ctx.accounts.user_profile.is_suspended {
+        return Err(error!(ErrorCode::ProfileSuspended));
+    }
+
     let bounty_awarded = false;
+    let revision_count: u64 = 0;
+    let duplicate_of: Pubkey = Pubkey::default();
+    let pending_owner: Pubkey = Pubkey::default();
 
     let question_rep: u64 = ctx.accounts.forum.reputation_matrix.question_rep;
     let bounty_contribution_rep: u64 = ctx.accounts.forum.reputation_matrix.bounty_contribution_rep;
-    let forum_question_bounty_minimum: u64 = ctx.accounts.forum.forum_fees.forum_question_bounty_minimum;
+    let category_fees = ctx.accounts.category.fees(&ctx.accounts.forum.forum_fees);
+    let forum_question_bounty_minimum: u64 = category_fees.forum_question_bounty_minimum;
 
     // Record vector length of tags and character lengths of title and content_data_url to be added
     let tags_length: u64 = tags.len() as u64;
@@ -173,7 +187,7 @@ pub fn ask_question(ctx: Context<AskQuestion>, tags: Vec<Tags>, title: String, c
                 &[bump],
             ],
             &ctx.accounts.question,
-            8 + 120 + contribution_buffer_slice_length + tag_buffer_slice_length + title_buffer_slice_length + content_data_url_buffer_slice_length + 32 + 8 + 1,
+            8 + 120 + contribution_buffer_slice_length + tag_buffer_slice_length + title_buffer_slice_length + content_data_url_buffer_slice_length + 32 + 8 + 1 + 8 + 32 + 32 + 32 + 32,
             ctx.program_id,
             &ctx.accounts.profile_owner.to_account_info(),
             &ctx.accounts.system_program.to_account_info(),
@@ -198,9 +212,14 @@ pub fn ask_question(ctx: Context<AskQuestion>, tags: Vec<Tags>, title: String, c
         question_account_raw[content_data_url_slice_end_byte..content_data_url_slice_end_byte+32].clone_from_slice(&ctx.accounts.content_data_hash.key().to_bytes());
         question_account_raw[content_data_url_slice_end_byte+32..content_data_url_slice_end_byte+40].clone_from_slice(&question_rep.to_le_bytes());
         question_account_raw[content_data_url_slice_end_byte+40..content_data_url_slice_end_byte+41].clone_from_slice(&(bounty_awarded as u8).to_le_bytes());
+        question_account_raw[content_data_url_slice_end_byte+41..content_data_url_slice_end_byte+49].clone_from_slice(&revision_count.to_le_bytes());
+        question_account_raw[content_data_url_slice_end_byte+49..content_data_url_slice_end_byte+81].clone_from_slice(&duplicate_of.to_bytes());
+        question_account_raw[content_data_url_slice_end_byte+81..content_data_url_slice_end_byte+113].clone_from_slice(&ctx.accounts.user_profile.key().to_bytes());
+        question_account_raw[content_data_url_slice_end_byte+113..content_data_url_slice_end_byte+145].clone_from_slice(&pending_owner.to_bytes());
+        question_account_raw[content_data_url_slice_end_byte+145..content_data_url_slice_end_byte+177].clone_from_slice(&ctx.accounts.category.key().to_bytes());
 
         // Transfer fee for asking question
-        let forum_question_fee = ctx.accounts.forum.forum_fees.forum_question_fee;
+        let forum_question_fee = category_fees.forum_question_fee;
 
         if forum_question_fee > 0 {
             let bounty_bps_remainder = bounty_amount % 10000;
@@ -218,6 +237,10 @@ pub fn ask_question(ctx: Context<AskQuestion>, tags: Vec<Tags>, title: String, c
         let forum = &mut ctx.accounts.forum;
         forum.forum_counts.forum_question_count.try_add_assign(1)?;
 
+        // Increment question count in category's state account
+        let category = &mut ctx.accounts.category;
+        category.question_count.try_add_assign(1)?;
+
         // Increment question count and total bounty contributed in user profile's state account
         let user_profile = &mut ctx.accounts.user_profile;
         user_profile.questions_asked.try_add_assign(1)?;
@@ -0,0 +1,193 @@
+use anchor_lang::prelude::*;
+
+use anchor_lang::solana_program::program::{invoke};
+use anchor_lang::solana_program::system_instruction;
+
+use crate::state::{BigNote, BigNoteVerificationApplication, BigNoteVerificationRecord, BigNoteVerificationState, Category, Forum, UserProfile, VerificationAttestation};
+use prog_common::{close_account, now_ts, TryAdd, errors::ErrorCode};
+
+#[derive(Accounts)]
+#[instruction(bump_treasury: u8, bump_attester_profile: u8, bump_user_profile: u8, bump_big_note: u8, bump_verification_application: u8, bump_verification_fee_pda: u8, bump_verification_record: u8)]
+pub struct AttestBigNoteVerification<'info> {
+
+    // Forum
+    #[account(has_one = forum_treasury)]
+    pub forum: Box<Account<'info, Forum>>,
+
+    /// CHECK:
+    #[account(mut, seeds = [b"treasury".as_ref(), forum.key().as_ref()], bump = bump_treasury)]
+    pub forum_treasury: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub attester: Signer<'info>,
+
+    // The attester profile
+    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), attester_profile.profile_seed.as_ref()],
+              bump = bump_attester_profile, has_one = forum, constraint = attester_profile.profile_owner == attester.key())]
+    pub attester_profile: Box<Account<'info, UserProfile>>,
+
+    /// CHECK:
+    #[account(mut)]
+    pub profile_owner: AccountInfo<'info>,
+
+    // The user profile
+    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), user_profile.profile_seed.as_ref()],
+              bump = bump_user_profile, has_one = forum, has_one = profile_owner)]
+    pub user_profile: Box<Account<'info, UserProfile>>,
+
+    // Big Note pda account and seed
+    #[account(mut, seeds = [b"big_note".as_ref(), forum.key().as_ref(), user_profile.key().as_ref(), big_note_seed.key().as_ref()],
+              bump = bump_big_note, has_one = forum, has_one = user_profile, has_one = big_note_seed)]
+    pub big_note: Box<Account<'info, BigNote>>,
+
+    // Category of the big note, whose fee overrides apply
+    #[account(has_one = forum, constraint = category.key() == big_note.category)]
+    pub category: Box<Account<'info, Category>>,
+
+    /// CHECK: The seed address used for initialization of the big note PDA
+    pub big_note_seed: AccountInfo<'info>,
+
+    // Big Note Verification Application PDA account
+    #[account(mut, seeds = [b"verification_application".as_ref(), big_note.key().as_ref()],
+              bump = bump_verification_application, has_one = big_note, has_one = verification_fee_pda)]
+    pub verification_application: Box<Account<'info, BigNoteVerificationApplication>>,
+
+    /// CHECK:
+    #[account(mut, seeds = [b"verification_fee_pda".as_ref(), big_note.key().as_ref()], bump = bump_verification_fee_pda)]
+    pub verification_fee_pda: AccountInfo<'info>,
+
+    // Big Note Verification Record PDA account
+    #[account(mut, seeds = [b"verification_record".as_ref(), big_note.key().as_ref()],
+              bump = bump_verification_record, has_one = big_note)]
+    pub verification_record: Box<Account<'info, BigNoteVerificationRecord>>,
+
+    // Verification Attestation PDA account (one per attester per verification round)
+    #[account(init, seeds = [b"verification_attestation".as_ref(), big_note.key().as_ref(), attester_profile.key().as_ref(), verification_record.verification_round.to_le_bytes().as_ref()],
+              bump, payer = attester, space = 8 + std::mem::size_of::<VerificationAttestation>())]
+    pub verification_attestation: Box<Account<'info, VerificationAttestation>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> AttestBigNoteVerification<'info> {
+
+    fn transfer_fee_ctx(&self, lamports: u64) -> Result<()> {
+        invoke(
+            &system_instruction::transfer(self.verification_fee_pda.key, &self.forum_treasury.key(), lamports),
+            &[
+                self.verification_fee_pda.to_account_info(),
+                self.forum_treasury.to_account_info(),
+                self.system_program.to_account_info(),
+            ],
+        )
+            .map_err(Into::into)
+    }
+
+    fn transfer_stake_ctx(&self, lamports: u64) -> Result<()> {
+        invoke(
+            &system_instruction::transfer(self.attester.key, &self.verification_attestation.key(), lamports),
+            &[
+                self.attester.to_account_info(),
+                self.verification_attestation.to_account_info(),
+                self.system_program.to_account_info(),
+            ],
+        )
+            .map_err(Into::into)
+    }
+}
+
+pub fn attest_big_note_verification(ctx: Context<AttestBigNoteVerification>) -> Result<()> {
+
+    let now_ts: u64 = now_ts()?;
+
+    // Ensure the attester profile has not been suspended from the forum
+    if ctx.accounts.attester_profile.is_suspended {
+        return Err(error!(ErrorCode::ProfileSuspended));
+    }
+
+    let big_notes_verification_rep = ctx.accounts.forum.reputation_matrix.big_notes_verification_rep;
+
+    // Ensure attester is a moderator or an expert, and is not attesting to their own big note
+    let is_expert: bool = ctx.accounts.attester_profile.reputation_score >= ctx.accounts.forum.forum_constants.min_attester_rep;
+
+    if !(ctx.accounts.attester_profile.is_moderator || is_expert) ||
+        (ctx.accounts.attester_profile.key() == ctx.accounts.user_profile.key()) {
+        return Err(error!(ErrorCode::ProfileCannotAttest));
+    }
+
+    // Ensure that big note account's verification state is 'AppliedForVerification'
+    if !(ctx.accounts.big_note.verification_state == BigNoteVerificationState::AppliedForVerification) {
+        return Err(error!(ErrorCode::BigNoteNotAppliedForVerification));
+    }
+
+    // Transfer the attestation stake to the attestation pda
+    let stake_amount: u64 = ctx.accounts.category.fees(&ctx.accounts.forum.forum_fees).forum_verification_attestation_stake;
+
+    if stake_amount > 0 {
+        ctx.accounts.transfer_stake_ctx(stake_amount)?;
+    }
+
+    // Record Verification Attestation's State
+    let verification_attestation = &mut ctx.accounts.verification_attestation;
+    verification_attestation.big_note = ctx.accounts.big_note.key();
+    verification_attestation.attester_profile = ctx.accounts.attester_profile.key();
+    verification_attestation.verification_round = ctx.accounts.verification_record.verification_round;
+    verification_attestation.attestation_ts = now_ts;
+    verification_attestation.stake_amount = stake_amount;
+    verification_attestation.is_slashed = false;
+
+    // Update attester profile account's most recent engagement timestamp
+    let attester_profile = &mut ctx.accounts.attester_profile;
+    attester_profile.most_recent_engagement_ts = now_ts;
+
+    // Tally the attestation
+    let verification_record = &mut ctx.accounts.verification_record;
+    verification_record.attestation_count.try_add_assign(1)?;
+
+    msg!("Big Note PDA account with address {} attested by profile with pubkey {}",
+         ctx.accounts.big_note.key(), ctx.accounts.attester_profile.key());
+
+    if ctx.accounts.verification_record.attestation_count < ctx.accounts.verification_record.quorum {
+        return Ok(());
+    }
+
+    // Quorum reached: transfer verification fee to the forum's treasury account
+    let verification_fee = ctx.accounts.verification_application.verification_fee;
+    ctx.accounts.transfer_fee_ctx(verification_fee)?;
+
+    // Set the receiver of the lamports to be reclaimed from the rent of the accounts to be closed
+    let receiver = &mut ctx.accounts.profile_owner;
+
+    // Close the verification PDA account
+    let verification_application_account_info = &mut ctx.accounts.verification_application.to_account_info();
+    close_account(verification_application_account_info, receiver)?;
+
+    // Close the verification fee PDA account
+    let verification_fee_pda_account_info = &mut ctx.accounts.verification_fee_pda.to_account_info();
+    close_account(verification_fee_pda_account_info, receiver)?;
+
+    // Record when the verification was granted and when it expires
+    let validity_period: u64 = ctx.accounts.forum.forum_constants.big_note_verification_validity_period;
+
+    let verification_record = &mut ctx.accounts.verification_record;
+    verification_record.verified_ts = now_ts;
+    verification_record.verification_expires_ts = match validity_period {
+        0 => 0,
+        _ => now_ts.try_add(validity_period)?,
+    };
+
+    // Update big note account's most recent engagement timestamp and verification state
+    let big_note = &mut ctx.accounts.big_note;
+    big_note.most_recent_engagement_ts = now_ts;
+    big_note.verification_state = BigNoteVerificationState::Verified;
+    big_note.big_note_verification_rep = big_notes_verification_rep;
+
+    // Update answer user profile's state
+    let user_profile = &mut ctx.accounts.user_profile;
+    user_profile.most_recent_engagement_ts = now_ts;
+    user_profile.big_notes_verified.try_add_assign(1)?;
+    user_profile.reputation_score.try_add_assign(big_notes_verification_rep)?;
+
+    msg!("Big Note PDA account with address {} now verified", ctx.accounts.big_note.key());
+    Ok(())
+}
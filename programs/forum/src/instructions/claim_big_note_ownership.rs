@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{Forum, BigNote, UserProfile};
+use prog_common::{now_ts, errors::ErrorCode};
+
+#[derive(Accounts)]
+#[instruction(bump_user_profile: u8, bump_big_note: u8)]
+pub struct ClaimBigNoteOwnership<'info> {
+
+    // Forum
+    pub forum: Box<Account<'info, Forum>>,
+
+    pub profile_owner: Signer<'info>,
+
+    // The user profile of the pending owner
+    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), user_profile.profile_seed.as_ref()],
+              bump = bump_user_profile, has_one = forum, has_one = profile_owner)]
+    pub user_profile: Box<Account<'info, UserProfile>>,
+
+    // Big Note pda account and seed
+    #[account(mut, seeds = [b"big_note".as_ref(), forum.key().as_ref(), big_note.user_profile.as_ref(), big_note_seed.key().as_ref()],
+              bump = bump_big_note, has_one = forum, has_one = big_note_seed)]
+    pub big_note: Box<Account<'info, BigNote>>,
+
+    /// CHECK: The seed address used for initialization of the big note PDA
+    pub big_note_seed: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn claim_big_note_ownership(ctx: Context<ClaimBigNoteOwnership>) -> Result<()> {
+
+    let now_ts: u64 = now_ts()?;
+
+    // Ensure the user profile has not been suspended from the forum
+    if ctx.accounts.user_profile.is_suspended {
+        return Err(error!(ErrorCode::ProfileSuspended));
+    }
+
+    // Ensure the signer's profile has been nominated as the new owner of the big note
+    if ctx.accounts.big_note.pending_owner != ctx.accounts.user_profile.key() {
+        return Err(error!(ErrorCode::NotPendingOwner));
+    }
+
+    // Update big note account's state
+    let big_note = &mut ctx.accounts.big_note;
+    big_note.current_owner = ctx.accounts.user_profile.key();
+    big_note.pending_owner = Pubkey::default();
+    big_note.most_recent_engagement_ts = now_ts;
+
+    // Update user profile's most recent engagement
+    let user_profile = &mut ctx.accounts.user_profile;
+    user_profile.most_recent_engagement_ts = now_ts;
+
+    msg!("Ownership of big note PDA account with address {} claimed by user profile with pubkey {}",
+         ctx.accounts.big_note.key(), ctx.accounts.user_profile.key());
+    Ok(())
+}
@@ -0,0 +1,97 @@
+use anchor_lang::prelude::*;
+
+use anchor_lang::solana_program::program::{invoke};
+use anchor_lang::solana_program::system_instruction;
+
+use crate::state::{Forum, Handle, UserProfile};
+use prog_common::{now_ts, errors::ErrorCode};
+
+#[derive(Accounts)]
+#[instruction(bump_treasury: u8, bump_user_profile: u8, handle_name: String)]
+pub struct ClaimHandle<'info> {
+
+    // Forum
+    #[account(has_one = forum_treasury)]
+    pub forum: Box<Account<'info, Forum>>,
+
+    /// CHECK:
+    #[account(mut, seeds = [b"treasury".as_ref(), forum.key().as_ref()], bump = bump_treasury)]
+    pub forum_treasury: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub profile_owner: Signer<'info>,
+
+    // The user profile
+    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), user_profile.profile_seed.as_ref()],
+              bump = bump_user_profile, has_one = forum, has_one = profile_owner)]
+    pub user_profile: Box<Account<'info, UserProfile>>,
+
+    // Handle PDA account seeded by the normalized handle name
+    #[account(init, seeds = [b"handle".as_ref(), forum.key().as_ref(), handle_name.as_bytes()],
+              bump, payer = profile_owner, space = 8 + 32 + 32 + 8 + 4 + handle_name.len())]
+    pub handle: Box<Account<'info, Handle>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ClaimHandle<'info> {
+
+    fn transfer_payment_ctx(&self, lamports: u64) -> Result<()> {
+        invoke(
+            &system_instruction::transfer(self.profile_owner.key, self.forum_treasury.key, lamports),
+            &[
+                self.profile_owner.to_account_info(),
+                self.forum_treasury.to_account_info(),
+                self.system_program.to_account_info(),
+            ],
+        )
+            .map_err(Into::into)
+    }
+}
+
+pub fn claim_handle(ctx: Context<ClaimHandle>, handle_name: String) -> Result<()> {
+
+    let now_ts: u64 = now_ts()?;
+
+    // Ensure the user profile has not been suspended from the forum
+    if ctx.accounts.user_profile.is_suspended {
+        return Err(error!(ErrorCode::ProfileSuspended));
+    }
+
+    let forum_constants = ctx.accounts.forum.forum_constants;
+    let handle_length: u64 = handle_name.len() as u64;
+
+    // Ensure the handle meets the forum's length and character rules (handles must already be normalized to lowercase)
+    if (handle_length < forum_constants.min_handle_length) || (handle_length > forum_constants.max_handle_length) ||
+        !handle_name.bytes().all(|character| forum_constants.handle_character_set.permits(character)) {
+        return Err(error!(ErrorCode::InvalidHandle));
+    }
+
+    // Ensure the user profile does not already have a handle
+    if ctx.accounts.user_profile.has_handle {
+        return Err(error!(ErrorCode::ProfileAlreadyHasHandle));
+    }
+
+    // Transfer fee for registering handle
+    let forum_handle_registration_fee = ctx.accounts.forum.forum_fees.forum_handle_registration_fee;
+
+    if forum_handle_registration_fee > 0 {
+        ctx.accounts.transfer_payment_ctx(forum_handle_registration_fee)?;
+    }
+
+    // Record Handle's State
+    let handle = &mut ctx.accounts.handle;
+    handle.forum = ctx.accounts.forum.key();
+    handle.user_profile = ctx.accounts.user_profile.key();
+    handle.handle_claimed_ts = now_ts;
+    handle.handle_name = handle_name;
+
+    // Update user profile's state account
+    let user_profile = &mut ctx.accounts.user_profile;
+    user_profile.has_handle = true;
+    user_profile.most_recent_engagement_ts = now_ts;
+
+    msg!("Handle {} claimed by user profile with pubkey {}",
+         ctx.accounts.handle.handle_name, ctx.accounts.user_profile.key());
+    Ok(())
+}
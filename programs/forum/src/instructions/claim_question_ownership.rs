@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{Forum, Question, UserProfile};
+use prog_common::{now_ts, errors::ErrorCode};
+
+#[derive(Accounts)]
+#[instruction(bump_user_profile: u8, bump_question: u8)]
+pub struct ClaimQuestionOwnership<'info> {
+
+    // Forum
+    pub forum: Box<Account<'info, Forum>>,
+
+    pub profile_owner: Signer<'info>,
+
+    // The user profile of the pending owner
+    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), user_profile.profile_seed.as_ref()],
+              bump = bump_user_profile, has_one = forum, has_one = profile_owner)]
+    pub user_profile: Box<Account<'info, UserProfile>>,
+
+    // Question PDA account and seed
+    #[account(mut, seeds = [b"question".as_ref(), forum.key().as_ref(), question.user_profile.as_ref(), question_seed.key().as_ref()],
+              bump = bump_question, has_one = forum, has_one = question_seed)]
+    pub question: Box<Account<'info, Question>>,
+
+    /// CHECK: The seed address used for initialization of the question PDA
+    pub question_seed: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn claim_question_ownership(ctx: Context<ClaimQuestionOwnership>) -> Result<()> {
+
+    let now_ts: u64 = now_ts()?;
+
+    // Ensure the user profile has not been suspended from the forum
+    if ctx.accounts.user_profile.is_suspended {
+        return Err(error!(ErrorCode::ProfileSuspended));
+    }
+
+    // Ensure the signer's profile has been nominated as the new owner of the question
+    if ctx.accounts.question.pending_owner != ctx.accounts.user_profile.key() {
+        return Err(error!(ErrorCode::NotPendingOwner));
+    }
+
+    // Update question account's state
+    let question = &mut ctx.accounts.question;
+    question.current_owner = ctx.accounts.user_profile.key();
+    question.pending_owner = Pubkey::default();
+    question.most_recent_engagement_ts = now_ts;
+
+    // Update user profile's most recent engagement
+    let user_profile = &mut ctx.accounts.user_profile;
+    user_profile.most_recent_engagement_ts = now_ts;
+
+    msg!("Ownership of question PDA account with address {} claimed by user profile with pubkey {}",
+         ctx.accounts.question.key(), ctx.accounts.user_profile.key());
+    Ok(())
+}
@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{Forum, Submission, SubmissionState, Track, TrackCompletion, UserProfile};
+use prog_common::{now_ts, TryAdd, errors::ErrorCode};
+
+#[derive(Accounts)]
+#[instruction(bump_user_profile: u8, bump_track: u8)]
+pub struct ClaimTrackCompletion<'info> {
+
+    // Forum
+    pub forum: Box<Account<'info, Forum>>,
+
+    #[account(mut)]
+    pub profile_owner: Signer<'info>,
+
+    // The user profile
+    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), user_profile.profile_seed.as_ref()],
+              bump = bump_user_profile, has_one = forum, has_one = profile_owner)]
+    pub user_profile: Box<Account<'info, UserProfile>>,
+
+    // Track PDA account and seed
+    #[account(seeds = [b"track".as_ref(), forum.key().as_ref(), track_seed.key().as_ref()],
+              bump = bump_track, has_one = forum, has_one = track_seed)]
+    pub track: Box<Account<'info, Track>>,
+
+    /// CHECK: The seed address used for initialization of the track PDA
+    pub track_seed: AccountInfo<'info>,
+
+    // Track completion PDA account (prevents the bonus being claimed twice)
+    #[account(init, seeds = [b"track_completion".as_ref(), track.key().as_ref(), user_profile.key().as_ref()],
+              bump, payer = profile_owner, space = 8 + std::mem::size_of::<TrackCompletion>())]
+    pub track_completion: Box<Account<'info, TrackCompletion>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+// The user's completed submissions for each challenge in the track must be passed in as remaining accounts, in track order
+pub fn claim_track_completion<'info>(ctx: Context<'_, '_, '_, 'info, ClaimTrackCompletion<'info>>) -> Result<()> {
+
+    let now_ts: u64 = now_ts()?;
+
+    // Ensure the user profile has not been suspended from the forum
+    if ctx.accounts.user_profile.is_suspended {
+        return Err(error!(ErrorCode::ProfileSuspended));
+    }
+
+    let challenge_count: usize = ctx.accounts.track.challenge_count as usize;
+
+    if (challenge_count == 0) || (ctx.remaining_accounts.len() != challenge_count) {
+        return Err(error!(ErrorCode::TrackNotCompleted));
+    }
+
+    for (position, submission_account_info) in ctx.remaining_accounts.iter().enumerate() {
+        let submission = Account::<Submission>::try_from(submission_account_info)?;
+
+        if (submission.challenge != ctx.accounts.track.challenges[position]) ||
+            (submission.user_profile != ctx.accounts.user_profile.key()) ||
+            (submission.submission_state != SubmissionState::Completed) {
+            return Err(error!(ErrorCode::TrackNotCompleted));
+        }
+    }
+
+    let completion_rep: u64 = ctx.accounts.track.completion_rep;
+
+    // Record Track Completion's State
+    let track_completion = &mut ctx.accounts.track_completion;
+    track_completion.track = ctx.accounts.track.key();
+    track_completion.user_profile = ctx.accounts.user_profile.key();
+    track_completion.track_completed_ts = now_ts;
+    track_completion.completion_rep = completion_rep;
+
+    // Update the user profile's state account
+    let user_profile = &mut ctx.accounts.user_profile;
+    user_profile.tracks_completed.try_add_assign(1)?;
+    user_profile.reputation_score.try_add_assign(completion_rep)?;
+    user_profile.most_recent_engagement_ts = now_ts;
+
+    msg!("Track with address {} completed by user profile with address {} for reputation of {}",
+         ctx.accounts.track.key(), ctx.accounts.user_profile.key(), completion_rep);
+    Ok(())
+}
@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{TokenAccount};
+
+use crate::state::{Forum, UserProfile};
+use prog_common::{errors::ErrorCode};
+
+#[derive(Accounts)]
+#[instruction(bump_user_profile: u8)]
+pub struct ClearNftPfp<'info> {
+
+    // Forum
+    pub forum: Box<Account<'info, Forum>>,
+
+    // Anyone may clear a PFP which is no longer held
+    pub cranker: Signer<'info>,
+
+    // The user profile
+    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), user_profile.profile_seed.as_ref()],
+              bump = bump_user_profile, has_one = forum, has_one = nft_pfp_token_account)]
+    pub user_profile: Box<Account<'info, UserProfile>>,
+
+    /// CHECK: The token account verified to hold the NFT PFP when it was set (may since have been closed)
+    pub nft_pfp_token_account: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn clear_nft_pfp(ctx: Context<ClearNftPfp>) -> Result<()> {
+
+    let user_profile = &ctx.accounts.user_profile;
+
+    // Ensure the profile owner no longer holds the NFT in the verified token account
+    if !ctx.accounts.nft_pfp_token_account.data_is_empty() {
+        let nft_pfp_token_account = Account::<TokenAccount>::try_from(&ctx.accounts.nft_pfp_token_account)?;
+
+        if (nft_pfp_token_account.mint == user_profile.nft_pfp_token_mint) && (nft_pfp_token_account.owner == user_profile.profile_owner) &&
+            (nft_pfp_token_account.amount >= 1) {
+            return Err(error!(ErrorCode::NftPfpStillHeld));
+        }
+    }
+
+    // Clear the user profile account's pfp
+    let user_profile = &mut ctx.accounts.user_profile;
+    user_profile.nft_pfp_token_mint = Pubkey::default();
+    user_profile.nft_pfp_token_account = Pubkey::default();
+
+    msg!("NFT PFP of user profile {} cleared", ctx.accounts.user_profile.key());
+    Ok(())
+}
@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{Challenge, ChallengeState, Forum};
+use prog_common::{now_ts, errors::ErrorCode};
+
+#[derive(Accounts)]
+#[instruction(bump_challenge: u8)]
+pub struct CloseChallenge<'info> {
+
+    // Forum
+    pub forum: Box<Account<'info, Forum>>,
+
+    // Anyone can crank the challenge lifecycle
+    pub cranker: Signer<'info>,
+
+    // Challenge PDA account and seed
+    #[account(mut, seeds = [b"challenge".as_ref(), forum.key().as_ref(), challenge_seed.key().as_ref()],
+              bump = bump_challenge, has_one = forum, has_one = challenge_seed)]
+    pub challenge: Box<Account<'info, Challenge>>,
+
+    /// CHECK: The seed address used for initialization of the challenge PDA
+    pub challenge_seed: AccountInfo<'info>,
+}
+
+// Moves an expired challenge into judging, and closes it once the judging period has ended
+pub fn close_challenge(ctx: Context<CloseChallenge>) -> Result<()> {
+
+    let now_ts: u64 = now_ts()?;
+    let challenge = &mut ctx.accounts.challenge;
+
+    // Ensure challenge has not already been closed
+    if (challenge.challenge_state == ChallengeState::Closed) || (challenge.challenge_state == ChallengeState::Archived) {
+        return Err(error!(ErrorCode::ChallengeAlreadyClosed));
+    }
+
+    // Ensure challenge has expired
+    if now_ts <= challenge.challenge_expires_ts {
+        return Err(error!(ErrorCode::ChallengeNotExpired));
+    }
+
+    challenge.challenge_state = ChallengeState::Judging;
+
+    // Close the challenge, locking evaluations and scoring, and record the final counts
+    if now_ts > challenge.judging_ends_ts {
+        challenge.challenge_state = ChallengeState::Closed;
+        challenge.final_submission_count = challenge.submission_count;
+        challenge.final_completion_count = challenge.completion_count;
+    }
+
+    msg!("Challenge PDA account with address {} now in state {:?}", ctx.accounts.challenge.key(), ctx.accounts.challenge.challenge_state);
+    Ok(())
+}
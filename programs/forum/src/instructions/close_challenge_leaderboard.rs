@@ -0,0 +1,145 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{Challenge, ChallengeLeaderboard, ChallengePrizePool, ChallengeState, Forum, PrizeDistribution, UserProfile};
+use prog_common::{TryAdd, TryDiv, TryMul, TrySub, errors::ErrorCode};
+
+#[derive(Accounts)]
+#[instruction(bump_challenge: u8, bump_leaderboard: u8, bump_prize_pool: u8)]
+pub struct CloseChallengeLeaderboard<'info> {
+
+    // Forum
+    pub forum: Box<Account<'info, Forum>>,
+
+    // Anyone can close the leaderboard once the challenge is closed
+    pub cranker: Signer<'info>,
+
+    // Challenge PDA account and seed
+    #[account(seeds = [b"challenge".as_ref(), forum.key().as_ref(), challenge_seed.key().as_ref()],
+              bump = bump_challenge, has_one = forum, has_one = challenge_seed)]
+    pub challenge: Box<Account<'info, Challenge>>,
+
+    /// CHECK: The seed address used for initialization of the challenge PDA
+    pub challenge_seed: AccountInfo<'info>,
+
+    // Challenge leaderboard PDA account
+    #[account(mut, seeds = [b"challenge_leaderboard".as_ref(), challenge.key().as_ref()],
+              bump = bump_leaderboard, has_one = challenge)]
+    pub leaderboard: Box<Account<'info, ChallengeLeaderboard>>,
+
+    /// CHECK: The challenge prize pool PDA account (empty if the challenge has no prize pool)
+    #[account(mut, seeds = [b"challenge_prize_pool".as_ref(), challenge.key().as_ref()], bump = bump_prize_pool)]
+    pub prize_pool: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+// The ranked accounts must be passed in as remaining accounts, in leaderboard order: [user profile] per rank, or if the challenge
+// has a ranked tiers prize pool, [user profile, profile owner] per rank for lamport prizes and
+// [user profile, profile owner, prize vault, profile owner token account, token program] per rank for SPL prizes
+pub fn close_challenge_leaderboard<'info>(ctx: Context<'_, '_, '_, 'info, CloseChallengeLeaderboard<'info>>) -> Result<()> {
+
+    let reputation = ctx.accounts.challenge.reputation;
+
+    // Ensure challenge has been closed (scoring stays open until the judging period ends)
+    let challenge_state = ctx.accounts.challenge.challenge_state;
+    if (challenge_state != ChallengeState::Closed) && (challenge_state != ChallengeState::Archived) {
+        return Err(error!(ErrorCode::ChallengeNotClosed));
+    }
+
+    // Ensure the leaderboard has not already been closed
+    if ctx.accounts.leaderboard.is_closed {
+        return Err(error!(ErrorCode::LeaderboardAlreadyClosed));
+    }
+
+    // Ranked tiers prizes are paid out by final rank once the leaderboard closes
+    let mut prize_pool: Option<Account<ChallengePrizePool>> = None;
+    if !ctx.accounts.prize_pool.data_is_empty() {
+        let challenge_prize_pool = Account::<ChallengePrizePool>::try_from(&ctx.accounts.prize_pool)?;
+
+        if challenge_prize_pool.prize_distribution == PrizeDistribution::RankedTiers {
+            prize_pool = Some(challenge_prize_pool);
+        }
+    }
+
+    let accounts_per_rank: usize = match &prize_pool {
+        Some(prize_pool) if prize_pool.is_spl() => 5,
+        Some(_) => 2,
+        None => 1,
+    };
+
+    let entry_count: usize = ctx.accounts.leaderboard.entry_count as usize;
+    let max_entries: u64 = ctx.accounts.leaderboard.max_entries;
+
+    if ctx.remaining_accounts.len() != (entry_count * accounts_per_rank) {
+        return Err(error!(ErrorCode::InvalidLeaderboardAccounts));
+    }
+
+    // Award reputation scaled by rank: first place receives the full challenge reputation, each lower rank a smaller share
+    for (rank, rank_accounts) in ctx.remaining_accounts.chunks(accounts_per_rank).enumerate() {
+        let user_profile_account_info = &rank_accounts[0];
+
+        if user_profile_account_info.key() != ctx.accounts.leaderboard.entries[rank].user_profile {
+            return Err(error!(ErrorCode::InvalidLeaderboardAccounts));
+        }
+
+        // Skip ranked user profiles which have since been deleted
+        if user_profile_account_info.data_is_empty() || (user_profile_account_info.lamports() == 0) {
+            msg!("User profile with pubkey {} ranked {} has been closed", user_profile_account_info.key(), rank + 1);
+            continue;
+        }
+
+        let rank_share: u64 = max_entries.try_sub(rank as u64)?;
+        let rank_reputation: u64 = reputation.try_mul(rank_share)?.try_div(max_entries)?;
+
+        let mut user_profile = Account::<UserProfile>::try_from(user_profile_account_info)?;
+        user_profile.reputation_score.try_add_assign(rank_reputation)?;
+
+        msg!("User profile with pubkey {} ranked {} and awarded reputation of {}",
+             user_profile_account_info.key(), rank + 1, rank_reputation);
+
+        // Pay out the prize tier of the rank
+        if let Some(prize_pool) = prize_pool.as_mut() {
+            let prize_amount: u64 = prize_pool.ranked_prize_amount(rank as u64)?;
+            let profile_owner_account_info = &rank_accounts[1];
+
+            if profile_owner_account_info.key() != user_profile.profile_owner {
+                return Err(error!(ErrorCode::InvalidLeaderboardAccounts));
+            }
+
+            if prize_amount > 0 {
+                if prize_pool.is_spl() {
+                    // Transfer the tokens from the prize vault to the profile owner
+                    prize_pool.transfer_from_vault(&ctx.accounts.prize_pool, &profile_owner_account_info.key(), &rank_accounts[2..], prize_amount)?;
+                }
+                else {
+                    // Manually transfer the lamports from the prize pool PDA to the profile owner
+                    let prize_pool_lamports_initial = ctx.accounts.prize_pool.lamports();
+                    let profile_owner_lamports_initial = profile_owner_account_info.lamports();
+
+                    **ctx.accounts.prize_pool.lamports.borrow_mut() = prize_pool_lamports_initial.try_sub(prize_amount)?;
+                    **profile_owner_account_info.lamports.borrow_mut() = profile_owner_lamports_initial.try_add(prize_amount)?;
+                }
+
+                user_profile.total_bounty_earned.try_add_assign(prize_amount)?;
+
+                prize_pool.total_paid.try_add_assign(prize_amount)?;
+                prize_pool.winners_paid.try_add_assign(1)?;
+
+                msg!("User profile with pubkey {} awarded prize of {}", user_profile_account_info.key(), prize_amount);
+            }
+        }
+
+        user_profile.exit(&crate::ID)?;
+    }
+
+    if let Some(prize_pool) = &prize_pool {
+        prize_pool.exit(&crate::ID)?;
+    }
+
+    // Update the leaderboard's state account
+    let leaderboard = &mut ctx.accounts.leaderboard;
+    leaderboard.is_closed = true;
+
+    msg!("Challenge leaderboard PDA account with address {} now closed", ctx.accounts.leaderboard.key());
+    Ok(())
+}
@@ -0,0 +1,229 @@
+use anchor_lang::prelude::*;
+
+use anchor_lang::solana_program::program::{invoke};
+use anchor_lang::solana_program::system_instruction;
+
+use crate::state::{Answer, BigNote, BigNoteType, BigNoteVerificationState, Category, Forum, Question, UserProfile};
+use prog_common::{now_ts, TryAdd, errors::ErrorCode};
+
+#[derive(Accounts)]
+#[instruction(bump_treasury: u8, bump_user_profile: u8, bump_question: u8, bump_answer_user_profile: u8, bump_answer: u8)]
+pub struct ConvertQuestionToBigNote<'info> {
+
+    // Forum
+    #[account(mut, has_one = forum_treasury)]
+    pub forum: Box<Account<'info, Forum>>,
+
+    /// CHECK:
+    #[account(mut, seeds = [b"treasury".as_ref(), forum.key().as_ref()], bump = bump_treasury)]
+    pub forum_treasury: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub profile_owner: Signer<'info>,
+
+    // The user profile
+    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), user_profile.profile_seed.as_ref()],
+              bump = bump_user_profile, has_one = forum, has_one = profile_owner)]
+    pub user_profile: Box<Account<'info, UserProfile>>,
+
+    // Question PDA account and seed
+    #[account(mut, seeds = [b"question".as_ref(), forum.key().as_ref(), question.user_profile.as_ref(), question_seed.key().as_ref()],
+              bump = bump_question, has_one = forum, has_one = question_seed)]
+    pub question: Box<Account<'info, Question>>,
+
+    /// CHECK: The seed address used for initialization of the question PDA
+    pub question_seed: AccountInfo<'info>,
+
+    // Category of the question (the big note is created in the same category)
+    #[account(mut, has_one = forum, constraint = category.key() == question.category)]
+    pub category: Box<Account<'info, Category>>,
+
+    /// CHECK: Used for seed verification of user profile pda account
+    pub answer_profile_owner: AccountInfo<'info>,
+
+    // The answer user profile
+    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), answer_user_profile.profile_seed.as_ref()],
+              bump = bump_answer_user_profile, has_one = forum, constraint = answer_user_profile.profile_owner == answer_profile_owner.key())]
+    pub answer_user_profile: Box<Account<'info, UserProfile>>,
+
+    // Accepted answer PDA account and seed
+    #[account(mut, seeds = [b"answer".as_ref(), forum.key().as_ref(), answer_user_profile.key().as_ref(), answer_seed.key().as_ref()],
+              bump = bump_answer, constraint = answer.user_profile == answer_user_profile.key(), has_one = answer_seed, has_one = question)]
+    pub answer: Box<Account<'info, Answer>>,
+
+    /// CHECK: The seed address used for initialization of the answer PDA
+    pub answer_seed: AccountInfo<'info>,
+
+    /// CHECK: Big note PDA account, seeded with the question address so each question can only be converted once
+    #[account(mut)]
+    pub big_note: AccountInfo<'info>,
+
+    /// CHECK:
+    // The content data hash of the big note struct
+    pub content_data_hash: AccountInfo<'info>,
+
+    /// CHECK:
+    #[account(init, seeds = [b"bignote_bounty_pda".as_ref(), big_note.key().as_ref()], bump, payer = profile_owner, space = 8)]
+    pub bounty_pda: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ConvertQuestionToBigNote<'info> {
+
+    fn transfer_payment_ctx(&self, lamports: u64) -> Result<()> {
+        invoke(
+            &system_instruction::transfer(self.profile_owner.key, self.forum_treasury.key, lamports),
+            &[
+                self.profile_owner.to_account_info(),
+                self.forum_treasury.to_account_info(),
+                self.system_program.to_account_info(),
+            ],
+        )
+            .map_err(Into::into)
+    }
+}
+
+pub fn convert_question_to_big_note(ctx: Context<ConvertQuestionToBigNote>, big_note_type: BigNoteType, content_data_url: String) -> Result<()> {
+
+    let now_ts: u64 = now_ts()?;
+
+    // Ensure the user profile has not been suspended from the forum
+    if ctx.accounts.user_profile.is_suspended {
+        return Err(error!(ErrorCode::ProfileSuspended));
+    }
+
+    // Ensure the signer's profile is the current owner of the question
+    if ctx.accounts.question.current_owner != ctx.accounts.user_profile.key() {
+        return Err(error!(ErrorCode::NotCurrentOwner));
+    }
+    let bounty_amount: u64 = 0;
+    let bounty_awarded = false;
+    let revision_count: u64 = 0;
+    let fork_count: u64 = 0;
+    let successor: Pubkey = Pubkey::default();
+    let pending_owner: Pubkey = Pubkey::default();
+    let verification_state = BigNoteVerificationState::Unverified;
+    let big_note_creation_rep = ctx.accounts.forum.reputation_matrix.create_big_notes_rep;
+    let big_note_verification_rep: u64 = 0;
+
+    // Ensure the answer provided is the question's accepted answer
+    if !ctx.accounts.question.bounty_awarded || !ctx.accounts.answer.accepted_answer {
+        return Err(error!(ErrorCode::AnswerNotAccepted));
+    }
+
+    // Copy tags and title from the question
+    let tags = ctx.accounts.question.tags.clone();
+    let title = ctx.accounts.question.title.clone();
+    let source_question: Pubkey = ctx.accounts.question.key();
+    let source_answer: Pubkey = ctx.accounts.answer.key();
+
+    // Record character length of content_data_url to be added
+    let url_length: u64 = content_data_url.len() as u64;
+    let max_url_length = ctx.accounts.forum.forum_constants.max_url_length;
+
+    // Ensure that the length of the content_data_url string is non-zero and not more than max_url_length characters long
+    if (url_length == 0) || (url_length > max_url_length) {
+        return Err(error!(ErrorCode::InvalidUrlStringInput));
+    }
+
+    // find bump - doing this program-side to reduce amount of info to be passed in (tx size)
+    let (_pk, bump) = Pubkey::find_program_address(
+        &[
+            b"big_note".as_ref(),
+            ctx.accounts.forum.key().as_ref(),
+            ctx.accounts.user_profile.key().as_ref(),
+            source_question.as_ref()
+        ],
+        ctx.program_id,
+    );
+
+    // Create the big note account PDA if it doesn't exist
+    if ctx.accounts.big_note.data_is_empty() {
+
+        // Record Big Note's State
+        let big_note = BigNote {
+            forum: ctx.accounts.forum.key(),
+            user_profile: ctx.accounts.user_profile.key(),
+            big_note_seed: source_question,
+            big_note_created_ts: now_ts,
+            most_recent_engagement_ts: now_ts,
+            bounty_amount,
+            bounty_contributions: Vec::new(),
+            big_note_type,
+            verification_state,
+            tags,
+            title,
+            content_data_url,
+            content_data_hash: ctx.accounts.content_data_hash.key(),
+            big_note_creation_rep,
+            big_note_verification_rep,
+            bounty_awarded,
+            revision_count,
+            source_question,
+            source_answer,
+            fork_count,
+            successor,
+            current_owner: ctx.accounts.user_profile.key(),
+            pending_owner,
+            category: ctx.accounts.category.key(),
+        };
+
+        // Create the big note PDA account and write the big note into it
+        big_note.create_account(
+            &[
+                b"big_note".as_ref(),
+                ctx.accounts.forum.key().as_ref(),
+                ctx.accounts.user_profile.key().as_ref(),
+                source_question.as_ref(),
+                &[bump],
+            ],
+            &ctx.accounts.big_note,
+            &ctx.accounts.profile_owner.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+        )?;
+
+        // Transfer fee for posting big_note
+        let forum_big_notes_submission_fee = ctx.accounts.category.fees(&ctx.accounts.forum.forum_fees).forum_big_notes_submission_fee;
+
+        if forum_big_notes_submission_fee > 0 {
+            ctx.accounts.transfer_payment_ctx(forum_big_notes_submission_fee)?;
+        }
+
+        // Increment big note count in forum state's account
+        let forum = &mut ctx.accounts.forum;
+        forum.forum_counts.forum_big_notes_count.try_add_assign(1)?;
+
+        // Increment big note count in category's state account
+        let category = &mut ctx.accounts.category;
+        category.big_notes_count.try_add_assign(1)?;
+
+        // Increment big note posted and credited counts in user profile's state account
+        let user_profile = &mut ctx.accounts.user_profile;
+        user_profile.big_notes_created.try_add_assign(1)?;
+        user_profile.big_notes_credited.try_add_assign(1)?;
+
+        // Update user profile's most recent engagement timestamp and reputation score
+        user_profile.most_recent_engagement_ts = now_ts;
+        user_profile.reputation_score.try_add_assign(big_note_creation_rep)?;
+
+        // Increment big note credited count in answer user profile's state account
+        let answer_user_profile = &mut ctx.accounts.answer_user_profile;
+        answer_user_profile.big_notes_credited.try_add_assign(1)?;
+
+        // Update question and answer accounts' most recent engagement timestamps
+        let question = &mut ctx.accounts.question;
+        question.most_recent_engagement_ts = now_ts;
+
+        let answer = &mut ctx.accounts.answer;
+        answer.most_recent_engagement_ts = now_ts;
+
+        msg!("Big Note PDA account with address {} now created from question with address {}",
+             ctx.accounts.big_note.key(), ctx.accounts.question.key());
+    }
+    else {
+        msg!("Big Note PDA account with address {} already exists", ctx.accounts.big_note.key());
+    }
+
+    Ok(())
+}
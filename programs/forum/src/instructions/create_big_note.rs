@@ -1,10 +1,9 @@
 use anchor_lang::prelude::*;
 
-use anchor_lang::solana_program::hash::hash;
-use anchor_lang::solana_program::program::{invoke, invoke_signed};
-use anchor_lang::solana_program::system_instruction::{self, create_account};
+use anchor_lang::solana_program::program::{invoke};
+use anchor_lang::solana_program::system_instruction;
 
-use crate::state::{BigNoteType, BigNoteVerificationState, BountyContribution, Forum, Tags, UserProfile};
+use crate::state::{BigNote, BigNoteType, BigNoteVerificationState, Category, Forum, Tags, UserProfile};
 use prog_common::{now_ts, TryAdd, errors::ErrorCode};
 
 #[derive(Accounts)]
@@ -23,10 +22,14 @@ pub struct CreateBigNote<'info> {
     pub profile_owner: Signer<'info>,
 
     // The user profile
-    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), profile_owner.key().as_ref()],
+    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), user_profile.profile_seed.as_ref()],
               bump = bump_user_profile, has_one = forum, has_one = profile_owner)]
     pub user_profile: Box<Account<'info, UserProfile>>,
 
+    // Category in which the big note is created
+    #[account(mut, has_one = forum)]
+    pub category: Box<Account<'info, Category>>,
+
     /// CHECK:
     #[account(mut)]
     pub big_note: AccountInfo<'info>,
@@ -63,8 +66,20 @@ impl<'info> CreateBigNote<'info> {
 pub fn create_big_note(ctx: Context<CreateBigNote>, big_note_type: BigNoteType, tags: Vec<Tags>, title: String, content_data_url: String) -> Result<()> {
 
     let now_ts: u64 = now_ts()?;
+
+    // Ensure the user profile has not been suspended from the forum
+    if ctx.accounts.user_profile.is_suspended {
+        return Err(error!(ErrorCode::ProfileSuspended));
+    }
+
     let bounty_amount: u64 = 0;
     let bounty_awarded = false;
+    let revision_count: u64 = 0;
+    let fork_count: u64 = 0;
+    let successor: Pubkey = Pubkey::default();
+    let pending_owner: Pubkey = Pubkey::default();
+    let source_question: Pubkey = Pubkey::default();
+    let source_answer: Pubkey = Pubkey::default();
     let verification_state = BigNoteVerificationState::Unverified;
     let big_note_creation_rep = ctx.accounts.forum.reputation_matrix.create_big_notes_rep;
     let big_note_verification_rep: u64 = 0;
@@ -107,53 +122,36 @@ pub fn create_big_note(ctx: Context<CreateBigNote>, big_note_type: BigNoteType,
     // Create the big note account PDA if it doesn't exist
     if ctx.accounts.big_note.data_is_empty() {
 
-        // Create bounty contribution
-        let bounty_contributions: Vec<BountyContribution> = Vec::new();
-
-        // Calculate data sizes and convert data to slice arrays
-        let mut contribution_buffer: Vec<u8> = Vec::new();
-        bounty_contributions.serialize(&mut contribution_buffer).unwrap();
-
-        let contribution_buffer_as_slice: &[u8] = contribution_buffer.as_slice();
-        let contribution_buffer_slice_length: usize = contribution_buffer_as_slice.len();
-        let contribution_slice_end_byte = 128 + contribution_buffer_slice_length;
-
-        let mut type_buffer: Vec<u8> = Vec::new();
-        big_note_type.serialize(&mut type_buffer).unwrap();
-
-        let type_buffer_as_slice: &[u8] = type_buffer.as_slice();
-        let type_buffer_slice_length: usize = type_buffer_as_slice.len();
-        let type_slice_end_byte = contribution_slice_end_byte + type_buffer_slice_length;
-
-        let mut verification_buffer: Vec<u8> = Vec::new();
-        verification_state.serialize(&mut verification_buffer).unwrap();
-
-        let verification_buffer_as_slice: &[u8] = verification_buffer.as_slice();
-        let verification_buffer_slice_length: usize = verification_buffer_as_slice.len();
-        let verification_slice_end_byte = type_slice_end_byte + verification_buffer_slice_length;
-
-        let mut tag_buffer: Vec<u8> = Vec::new();
-        tags.serialize(&mut tag_buffer).unwrap();
-
-        let tag_buffer_as_slice: &[u8] = tag_buffer.as_slice();
-        let tag_buffer_slice_length: usize = tag_buffer_as_slice.len();
-        let tag_slice_end_byte = verification_slice_end_byte + tag_buffer_slice_length;
-
-        let mut title_buffer: Vec<u8> = Vec::new();
-        title.serialize(&mut title_buffer).unwrap();
-
-        let title_buffer_as_slice: &[u8] = title_buffer.as_slice();
-        let title_buffer_slice_length: usize = title_buffer_as_slice.len();
-        let title_slice_end_byte = tag_slice_end_byte + title_buffer_slice_length;
-
-        let mut content_data_url_buffer: Vec<u8> = Vec::new();
-        content_data_url.serialize(&mut content_data_url_buffer).unwrap();
-
-        let content_data_url_buffer_as_slice: &[u8] = content_data_url_buffer.as_slice();
-        let content_data_url_buffer_slice_length: usize = content_data_url_buffer_as_slice.len();
-        let content_data_url_slice_end_byte = title_slice_end_byte + content_data_url_buffer_slice_length;
-
-        create_pda_with_space(
+        // Record Big Note's State
+        let big_note = BigNote {
+            forum: ctx.accounts.forum.key(),
+            user_profile: ctx.accounts.user_profile.key(),
+            big_note_seed: ctx.accounts.big_note_seed.key(),
+            big_note_created_ts: now_ts,
+            most_recent_engagement_ts: now_ts,
+            bounty_amount,
+            bounty_contributions: Vec::new(),
+            big_note_type,
+            verification_state,
+            tags,
+            title,
+            content_data_url,
+            content_data_hash: ctx.accounts.content_data_hash.key(),
+            big_note_creation_rep,
+            big_note_verification_rep,
+            bounty_awarded,
+            revision_count,
+            source_question,
+            source_answer,
+            fork_count,
+            successor,
+            current_owner: ctx.accounts.user_profile.key(),
+            pending_owner,
+            category: ctx.accounts.category.key(),
+        };
+
+        // Create the big note PDA account and write the big note into it
+        big_note.create_account(
             &[
                 b"big_note".as_ref(),
                 ctx.accounts.forum.key().as_ref(),
@@ -162,37 +160,12 @@ pub fn create_big_note(ctx: Context<CreateBigNote>, big_note_type: BigNoteType,
                 &[bump],
             ],
             &ctx.accounts.big_note,
-            8 + 120 + contribution_buffer_slice_length + type_buffer_slice_length + verification_buffer_slice_length + tag_buffer_slice_length + title_buffer_slice_length + content_data_url_buffer_slice_length + 49,
-            ctx.program_id,
             &ctx.accounts.profile_owner.to_account_info(),
             &ctx.accounts.system_program.to_account_info(),
         )?;
 
-        // Perform all necessary conversions to bytes
-        let disc = hash("account:BigNote".as_bytes());
-
-        // Pack byte data into Listing account
-        let mut big_note_account_raw = ctx.accounts.big_note.data.borrow_mut();
-        big_note_account_raw[..8].clone_from_slice(&disc.to_bytes()[..8]);
-        big_note_account_raw[8..40].clone_from_slice(&ctx.accounts.forum.key().to_bytes());
-        big_note_account_raw[40..72].clone_from_slice(&ctx.accounts.user_profile.key().to_bytes());
-        big_note_account_raw[72..104].clone_from_slice(&ctx.accounts.big_note_seed.key().to_bytes());
-        big_note_account_raw[104..112].clone_from_slice(&now_ts.to_le_bytes());
-        big_note_account_raw[112..120].clone_from_slice(&now_ts.to_le_bytes());
-        big_note_account_raw[120..128].clone_from_slice(&bounty_amount.to_le_bytes());
-        big_note_account_raw[128..contribution_slice_end_byte].clone_from_slice(contribution_buffer_as_slice);
-        big_note_account_raw[contribution_slice_end_byte..type_slice_end_byte].clone_from_slice(type_buffer_as_slice);
-        big_note_account_raw[type_slice_end_byte..verification_slice_end_byte].clone_from_slice(tag_buffer_as_slice);
-        big_note_account_raw[verification_slice_end_byte..tag_slice_end_byte].clone_from_slice(verification_buffer_as_slice);
-        big_note_account_raw[tag_slice_end_byte..title_slice_end_byte].clone_from_slice(title_buffer_as_slice);
-        big_note_account_raw[title_slice_end_byte..content_data_url_slice_end_byte].clone_from_slice(content_data_url_buffer_as_slice);
-        big_note_account_raw[content_data_url_slice_end_byte..content_data_url_slice_end_byte+32].clone_from_slice(&ctx.accounts.content_data_hash.key().to_bytes());
-        big_note_account_raw[content_data_url_slice_end_byte+32..content_data_url_slice_end_byte+40].clone_from_slice(&big_note_creation_rep.to_le_bytes());
-        big_note_account_raw[content_data_url_slice_end_byte+40..content_data_url_slice_end_byte+48].clone_from_slice(&big_note_verification_rep.to_le_bytes());
-        big_note_account_raw[content_data_url_slice_end_byte+48..content_data_url_slice_end_byte+49].clone_from_slice(&(bounty_awarded as u8).to_le_bytes());
-
         // Transfer fee for posting big_note
-        let forum_big_notes_submission_fee = ctx.accounts.forum.forum_fees.forum_big_notes_submission_fee;
+        let forum_big_notes_submission_fee = ctx.accounts.category.fees(&ctx.accounts.forum.forum_fees).forum_big_notes_submission_fee;
 
         if forum_big_notes_submission_fee > 0 {
             ctx.accounts.transfer_payment_ctx(forum_big_notes_submission_fee)?;
@@ -202,6 +175,10 @@ pub fn create_big_note(ctx: Context<CreateBigNote>, big_note_type: BigNoteType,
         let forum = &mut ctx.accounts.forum;
         forum.forum_counts.forum_big_notes_count.try_add_assign(1)?;
 
+        // Increment big note count in category's state account
+        let category = &mut ctx.accounts.category;
+        category.big_notes_count.try_add_assign(1)?;
+
         // Increment big note posted count in user profile's state account
         let user_profile = &mut ctx.accounts.user_profile;
         user_profile.big_notes_created.try_add_assign(1)?;
@@ -219,33 +196,3 @@ pub fn create_big_note(ctx: Context<CreateBigNote>, big_note_type: BigNoteType,
 
     Ok(())
 }
-
-// Auxiliary helper functions
-
-fn create_pda_with_space<'info>(
-    pda_seeds: &[&[u8]],
-    pda_info: &AccountInfo<'info>,
-    space: usize,
-    owner: &Pubkey,
-    funder_info: &AccountInfo<'info>,
-    system_program_info: &AccountInfo<'info>,
-) -> Result<()> {
-    //create a PDA and allocate space inside of it at the same time - can only be done from INSIDE the program
-    //based on https://github.com/solana-labs/solana-program-library/blob/7c8e65292a6ebc90de54468c665e30bc590c513a/feature-proposal/program/src/processor.rs#L148-L163
-    invoke_signed(
-        &create_account(
-            &funder_info.key,
-            &pda_info.key,
-            1.max(Rent::get()?.minimum_balance(space)),
-            space as u64,
-            owner,
-        ),
-        &[
-            funder_info.clone(),
-            pda_info.clone(),
-            system_program_info.clone(),
-        ],
-        &[pda_seeds], //this is the part you can't do outside the program
-    )
-        .map_err(Into::into)
-}
@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{Category, Forum, ForumFees};
+use prog_common::{now_ts, errors::ErrorCode};
+
+#[derive(Accounts)]
+pub struct CreateCategory<'info> {
+
+    // Forum and Forum Manager
+    #[account(has_one = forum_manager)]
+    pub forum: Box<Account<'info, Forum>>,
+
+    #[account(mut)]
+    pub forum_manager: Signer<'info>,
+
+    // Category PDA account and seed
+    #[account(init, seeds = [b"category".as_ref(), forum.key().as_ref(), category_seed.key().as_ref()],
+              bump, payer = forum_manager, space = 8 + std::mem::size_of::<Category>())]
+    pub category: Box<Account<'info, Category>>,
+
+    /// CHECK: The seed address used for initialization of the category PDA
+    pub category_seed: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn create_category(ctx: Context<CreateCategory>, fee_overrides_enabled: bool, category_fees: ForumFees) -> Result<()> {
+
+    let now_ts: u64 = now_ts()?;
+
+    // Assert category question and big notes solicitation fees and rejected submission refund in basis points are between 0 - 10,000
+    if (category_fees.forum_question_fee > 10000) || (category_fees.forum_big_notes_solicitation_fee > 10000) ||
+        (category_fees.forum_rejected_submission_refund > 10000) {
+        return Err(error!(ErrorCode::InvalidFeeInputs));
+    }
+
+    // Record Category's State
+    let category = &mut ctx.accounts.category;
+    category.forum = ctx.accounts.forum.key();
+    category.category_seed = ctx.accounts.category_seed.key();
+    category.category_created_ts = now_ts;
+    category.category_fees = category_fees;
+    category.fee_overrides_enabled = fee_overrides_enabled;
+
+    msg!("Category PDA account with address {} now created", ctx.accounts.category.key());
+    Ok(())
+}
@@ -1,10 +1,6 @@
 use anchor_lang::prelude::*;
 
-use anchor_lang::solana_program::hash::hash;
-use anchor_lang::solana_program::program::{invoke_signed};
-use anchor_lang::solana_program::system_instruction::{create_account};
-
-use crate::state::{Forum, Tags, UserProfile};
+use crate::state::{Challenge, ChallengeState, Category, Forum, MAX_RUBRIC_CRITERIA, RubricCriterion, Tags, UserProfile};
 use prog_common::{now_ts, TryAdd, errors::ErrorCode};
 
 #[derive(Accounts)]
@@ -19,10 +15,14 @@ pub struct CreateChallenge<'info> {
     pub moderator: Signer<'info>,
 
     // The moderator profile
-    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), moderator.key().as_ref()],
+    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), moderator_profile.profile_seed.as_ref()],
               bump = bump_moderator_profile, has_one = forum, constraint = moderator_profile.profile_owner == moderator.key())]
     pub moderator_profile: Box<Account<'info, UserProfile>>,
 
+    // Category in which the challenge is created
+    #[account(mut, has_one = forum)]
+    pub category: Box<Account<'info, Category>>,
+
     /// CHECK:
     #[account(mut)]
     pub challenge: AccountInfo<'info>,
@@ -37,11 +37,11 @@ pub struct CreateChallenge<'info> {
     pub system_program: Program<'info, System>,
 }
 
-pub fn create_challenge(ctx: Context<CreateChallenge>, tags: Vec<Tags>, title: String, content_data_url: String, challenge_expires_ts: u64, reputation: u64) -> Result<()> {
+pub fn create_challenge(ctx: Context<CreateChallenge>, tags: Vec<Tags>, title: String, content_data_url: String, challenge_expires_ts: u64, reputation: u64, rubric: Vec<RubricCriterion>) -> Result<()> {
 
     let now_ts: u64 = now_ts()?;
 
-    if !ctx.accounts.moderator_profile.is_moderator {
+    if !ctx.accounts.moderator_profile.is_moderator && !ctx.accounts.category.is_category_moderator(&ctx.accounts.moderator_profile.key()) {
         return Err(error!(ErrorCode::ProfileIsNotModerator));
     }
 
@@ -69,11 +69,19 @@ pub fn create_challenge(ctx: Context<CreateChallenge>, tags: Vec<Tags>, title: S
         return Err(error!(ErrorCode::InvalidUrlStringInput));
     }
 
+    // Ensure that the rubric does not exceed the maximum number of criteria and each criterion is non-zero
+    if (rubric.len() > MAX_RUBRIC_CRITERIA) || rubric.iter().any(|x| (x.max_score == 0) || (x.weight == 0)) {
+        return Err(error!(ErrorCode::InvalidRubric));
+    }
+
     // Ensure challenge expires timestamp is greater than now timestamp
     if !(challenge_expires_ts > now_ts) {
         return Err(error!(ErrorCode::InvalidExpiryTs));
     }
 
+    // Judging period ends a forum-configured period after the challenge expires
+    let judging_ends_ts: u64 = challenge_expires_ts.try_add(ctx.accounts.forum.forum_constants.challenge_judging_period)?;
+
     // find bump - doing this program-side to reduce amount of info to be passed in (tx size)
     let (_pk, bump) = Pubkey::find_program_address(
         &[
@@ -87,29 +95,29 @@ pub fn create_challenge(ctx: Context<CreateChallenge>, tags: Vec<Tags>, title: S
     // Create the challenge account PDA if it doesn't exist
     if ctx.accounts.challenge.data_is_empty() {
 
-        // Calculate data sizes and convert data to slice arrays
-        let mut tag_buffer: Vec<u8> = Vec::new();
-        tags.serialize(&mut tag_buffer).unwrap();
-
-        let tag_buffer_as_slice: &[u8] = tag_buffer.as_slice();
-        let tag_buffer_slice_length: usize = tag_buffer_as_slice.len();
-        let tag_slice_end_byte = 96 + tag_buffer_slice_length;
-
-        let mut title_buffer: Vec<u8> = Vec::new();
-        title.serialize(&mut title_buffer).unwrap();
-
-        let title_buffer_as_slice: &[u8] = title_buffer.as_slice();
-        let title_buffer_slice_length: usize = title_buffer_as_slice.len();
-        let title_slice_end_byte = tag_slice_end_byte + title_buffer_slice_length;
-
-        let mut content_data_url_buffer: Vec<u8> = Vec::new();
-        content_data_url.serialize(&mut content_data_url_buffer).unwrap();
-
-        let content_data_url_buffer_as_slice: &[u8] = content_data_url_buffer.as_slice();
-        let content_data_url_buffer_slice_length: usize = content_data_url_buffer_as_slice.len();
-        let content_data_url_slice_end_byte = title_slice_end_byte + content_data_url_buffer_slice_length;
-
-        create_pda_with_space(
+        // Record Challenge's State
+        let challenge = Challenge {
+            forum: ctx.accounts.forum.key(),
+            challenge_seed: ctx.accounts.challenge_seed.key(),
+            challenge_posted_ts: now_ts,
+            challenge_expires_ts,
+            tags,
+            title,
+            content_data_url,
+            content_data_hash: ctx.accounts.content_data_hash.key(),
+            reputation,
+            rubric,
+            challenge_state: ChallengeState::Open,
+            judging_ends_ts,
+            submission_count: 0,
+            completion_count: 0,
+            final_submission_count: 0,
+            final_completion_count: 0,
+            category: ctx.accounts.category.key(),
+        };
+
+        // Create the challenge PDA account and write the challenge into it
+        challenge.create_account(
             &[
                 b"challenge".as_ref(),
                 ctx.accounts.forum.key().as_ref(),
@@ -117,32 +125,18 @@ pub fn create_challenge(ctx: Context<CreateChallenge>, tags: Vec<Tags>, title: S
                 &[bump],
             ],
             &ctx.accounts.challenge,
-            8 + 80 + tag_buffer_slice_length + title_buffer_slice_length + content_data_url_buffer_slice_length + 40,
-            ctx.program_id,
             &ctx.accounts.moderator.to_account_info(),
             &ctx.accounts.system_program.to_account_info(),
         )?;
 
-        // Perform all necessary conversions to bytes
-        let disc = hash("account:Challenge".as_bytes());
-
-        // Pack byte data into Challenge account
-        let mut challenge_account_raw = ctx.accounts.challenge.data.borrow_mut();
-        challenge_account_raw[..8].clone_from_slice(&disc.to_bytes()[..8]);
-        challenge_account_raw[8..40].clone_from_slice(&ctx.accounts.forum.key().to_bytes());
-        challenge_account_raw[40..72].clone_from_slice(&ctx.accounts.challenge_seed.key().to_bytes());
-        challenge_account_raw[72..80].clone_from_slice(&now_ts.to_le_bytes());
-        challenge_account_raw[80..88].clone_from_slice(&challenge_expires_ts.to_le_bytes());
-        challenge_account_raw[88..tag_slice_end_byte].clone_from_slice(tag_buffer_as_slice);
-        challenge_account_raw[tag_slice_end_byte..title_slice_end_byte].clone_from_slice(title_buffer_as_slice);
-        challenge_account_raw[title_slice_end_byte..content_data_url_slice_end_byte].clone_from_slice(content_data_url_buffer_as_slice);
-        challenge_account_raw[content_data_url_slice_end_byte..content_data_url_slice_end_byte+32].clone_from_slice(&ctx.accounts.content_data_hash.key().to_bytes());
-        challenge_account_raw[content_data_url_slice_end_byte+32..content_data_url_slice_end_byte+40].clone_from_slice(&reputation.to_le_bytes());
-
         // Increment challenge count in forum's state account
         let forum = &mut ctx.accounts.forum;
         forum.forum_counts.forum_challenge_count.try_add_assign(1)?;
 
+        // Increment challenge count in category's state account
+        let category = &mut ctx.accounts.category;
+        category.challenge_count.try_add_assign(1)?;
+
         // Update the moderator profile's state account
         let moderator_profile = &mut ctx.accounts.moderator_profile;
         moderator_profile.most_recent_engagement_ts = now_ts;
@@ -156,32 +150,3 @@ pub fn create_challenge(ctx: Context<CreateChallenge>, tags: Vec<Tags>, title: S
     Ok(())
 }
 
-// Auxiliary helper functions
-
-fn create_pda_with_space<'info>(
-    pda_seeds: &[&[u8]],
-    pda_info: &AccountInfo<'info>,
-    space: usize,
-    owner: &Pubkey,
-    funder_info: &AccountInfo<'info>,
-    system_program_info: &AccountInfo<'info>,
-) -> Result<()> {
-    //create a PDA and allocate space inside of it at the same time - can only be done from INSIDE the program
-    //based on https://github.com/solana-labs/solana-program-library/blob/7c8e65292a6ebc90de54468c665e30bc590c513a/feature-proposal/program/src/processor.rs#L148-L163
-    invoke_signed(
-        &create_account(
-            &funder_info.key,
-            &pda_info.key,
-            1.max(Rent::get()?.minimum_balance(space)),
-            space as u64,
-            owner,
-        ),
-        &[
-            funder_info.clone(),
-            pda_info.clone(),
-            system_program_info.clone(),
-        ],
-        &[pda_seeds], //this is the part you can't do outside the program
-    )
-        .map_err(Into::into)
-}
@@ -0,0 +1,74 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{Category, Challenge, ChallengeLeaderboard, Forum, LeaderboardEntry, MAX_LEADERBOARD_ENTRIES, UserProfile};
+use prog_common::{now_ts, errors::ErrorCode};
+
+#[derive(Accounts)]
+#[instruction(bump_moderator_profile: u8, bump_challenge: u8)]
+pub struct CreateChallengeLeaderboard<'info> {
+
+    // Forum
+    pub forum: Box<Account<'info, Forum>>,
+
+    #[account(mut)]
+    pub moderator: Signer<'info>,
+
+    // The moderator profile
+    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), moderator_profile.profile_seed.as_ref()],
+              bump = bump_moderator_profile, has_one = forum, constraint = moderator_profile.profile_owner == moderator.key())]
+    pub moderator_profile: Box<Account<'info, UserProfile>>,
+
+    // Challenge PDA account and seed
+    #[account(seeds = [b"challenge".as_ref(), forum.key().as_ref(), challenge_seed.key().as_ref()],
+              bump = bump_challenge, has_one = forum, has_one = challenge_seed)]
+    pub challenge: Box<Account<'info, Challenge>>,
+
+    // Category of the challenge, whose moderators may also moderate it
+    #[account(has_one = forum, constraint = category.key() == challenge.category)]
+    pub category: Box<Account<'info, Category>>,
+
+    /// CHECK: The seed address used for initialization of the challenge PDA
+    pub challenge_seed: AccountInfo<'info>,
+
+    // Challenge leaderboard PDA account
+    #[account(init, seeds = [b"challenge_leaderboard".as_ref(), challenge.key().as_ref()],
+              bump, payer = moderator, space = 8 + std::mem::size_of::<ChallengeLeaderboard>())]
+    pub leaderboard: Box<Account<'info, ChallengeLeaderboard>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn create_challenge_leaderboard(ctx: Context<CreateChallengeLeaderboard>, max_entries: u64) -> Result<()> {
+
+    let now_ts: u64 = now_ts()?;
+
+    if !ctx.accounts.moderator_profile.is_moderator && !ctx.accounts.category.is_category_moderator(&ctx.accounts.moderator_profile.key()) {
+        return Err(error!(ErrorCode::ProfileIsNotModerator));
+    }
+
+    // Ensure challenge submissions are scored against a rubric
+    if ctx.accounts.challenge.rubric.is_empty() {
+        return Err(error!(ErrorCode::InvalidRubric));
+    }
+
+    // Ensure the number of ranks is non-zero and not greater than the maximum number of leaderboard entries
+    if (max_entries == 0) || (max_entries > MAX_LEADERBOARD_ENTRIES as u64) {
+        return Err(error!(ErrorCode::InvalidParameter));
+    }
+
+    // Record Challenge Leaderboard's State
+    let leaderboard = &mut ctx.accounts.leaderboard;
+    leaderboard.challenge = ctx.accounts.challenge.key();
+    leaderboard.entries = [LeaderboardEntry::default(); MAX_LEADERBOARD_ENTRIES];
+    leaderboard.max_entries = max_entries;
+    leaderboard.entry_count = 0;
+    leaderboard.is_closed = false;
+
+    // Update the moderator profile's state account
+    let moderator_profile = &mut ctx.accounts.moderator_profile;
+    moderator_profile.most_recent_engagement_ts = now_ts;
+
+    msg!("Challenge leaderboard PDA account with address {} now created for challenge with address {}",
+         ctx.accounts.leaderboard.key(), ctx.accounts.challenge.key());
+    Ok(())
+}
@@ -0,0 +1,125 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{Category, Challenge, ChallengePrizePool, ChallengeProposal, Forum, MAX_PRIZE_TIERS, PrizeDistribution, UserProfile};
+use prog_common::{now_ts, TryAdd, errors::ErrorCode};
+
+#[derive(Accounts)]
+#[instruction(bump_moderator_profile: u8, bump_challenge: u8, bump_challenge_proposal: u8)]
+pub struct CreateChallengePrizePool<'info> {
+
+    // Forum
+    pub forum: Box<Account<'info, Forum>>,
+
+    #[account(mut)]
+    pub moderator: Signer<'info>,
+
+    // The moderator profile
+    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), moderator_profile.profile_seed.as_ref()],
+              bump = bump_moderator_profile, has_one = forum, constraint = moderator_profile.profile_owner == moderator.key())]
+    pub moderator_profile: Box<Account<'info, UserProfile>>,
+
+    // Challenge PDA account and seed
+    #[account(seeds = [b"challenge".as_ref(), forum.key().as_ref(), challenge_seed.key().as_ref()],
+              bump = bump_challenge, has_one = forum, has_one = challenge_seed)]
+    pub challenge: Box<Account<'info, Challenge>>,
+
+    // Category of the challenge, whose moderators may also moderate it
+    #[account(has_one = forum, constraint = category.key() == challenge.category)]
+    pub category: Box<Account<'info, Category>>,
+
+    /// CHECK: The seed address used for initialization of the challenge PDA
+    pub challenge_seed: AccountInfo<'info>,
+
+    /// CHECK: The challenge's proposal PDA account (empty if the challenge was created by a moderator)
+    #[account(seeds = [b"challenge_proposal".as_ref(), challenge.key().as_ref()], bump = bump_challenge_proposal)]
+    pub challenge_proposal: AccountInfo<'info>,
+
+    // Challenge prize pool PDA account
+    #[account(init, seeds = [b"challenge_prize_pool".as_ref(), challenge.key().as_ref()],
+              bump, payer = moderator, space = 8 + std::mem::size_of::<ChallengePrizePool>())]
+    pub prize_pool: Box<Account<'info, ChallengePrizePool>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn create_challenge_prize_pool(
+    ctx: Context<CreateChallengePrizePool>,
+    prize_distribution: PrizeDistribution,
+    winner_count: u64,
+    prize_per_winner: u64,
+    tier_bps: Vec<u16>,
+) -> Result<()> {
+
+    let now_ts: u64 = now_ts()?;
+
+    if !ctx.accounts.moderator_profile.is_moderator && !ctx.accounts.category.is_category_moderator(&ctx.accounts.moderator_profile.key()) {
+        return Err(error!(ErrorCode::ProfileIsNotModerator));
+    }
+
+    // Ensure a community proposed challenge has been approved by a moderator
+    if !ChallengeProposal::is_challenge_approved(&ctx.accounts.challenge_proposal)? {
+        return Err(error!(ErrorCode::ChallengeNotApproved));
+    }
+
+    // Ensure challenge has not yet expired
+    if now_ts > ctx.accounts.challenge.challenge_expires_ts {
+        return Err(error!(ErrorCode::ChallengeExpired));
+    }
+
+    // Ensure prize distribution parameters are valid
+    if winner_count == 0 {
+        return Err(error!(ErrorCode::InvalidPrizeDistribution));
+    }
+
+    let mut tiers: [u16; MAX_PRIZE_TIERS] = [0; MAX_PRIZE_TIERS];
+
+    match prize_distribution {
+        PrizeDistribution::FirstN => {
+            if prize_per_winner == 0 {
+                return Err(error!(ErrorCode::InvalidPrizeDistribution));
+            }
+        }
+        PrizeDistribution::SplitEvenly => {}
+        PrizeDistribution::RankedTiers => {
+            if (tier_bps.len() as u64 != winner_count) || (tier_bps.len() > MAX_PRIZE_TIERS) {
+                return Err(error!(ErrorCode::InvalidPrizeDistribution));
+            }
+
+            let mut total_bps: u64 = 0;
+            for (index, &bps) in tier_bps.iter().enumerate() {
+                total_bps.try_add_assign(bps as u64)?;
+                tiers[index] = bps;
+            }
+
+            if total_bps > 10000 {
+                return Err(error!(ErrorCode::InvalidPrizeDistribution));
+            }
+        }
+    }
+
+    // Record Challenge Prize Pool's State
+    let prize_pool = &mut ctx.accounts.prize_pool;
+    prize_pool.challenge = ctx.accounts.challenge.key();
+    prize_pool.prize_mint = Pubkey::default();
+
+    prize_pool.total_funded = 0;
+    prize_pool.total_paid = 0;
+    prize_pool.total_refunded = 0;
+    prize_pool.sponsor_count = 0;
+
+    prize_pool.winner_count = winner_count;
+    prize_pool.winners_paid = 0;
+    prize_pool.prize_per_winner = prize_per_winner;
+    prize_pool.tier_bps = tiers;
+
+    prize_pool.prize_distribution = prize_distribution;
+    prize_pool.payouts_closed = false;
+
+    // Update the moderator profile's state account
+    let moderator_profile = &mut ctx.accounts.moderator_profile;
+    moderator_profile.most_recent_engagement_ts = now_ts;
+
+    msg!("Challenge prize pool PDA account with address {} now created for challenge with address {}",
+         ctx.accounts.prize_pool.key(), ctx.accounts.challenge.key());
+    Ok(())
+}
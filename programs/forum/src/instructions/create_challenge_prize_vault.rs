@@ -0,0 +1,76 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+use crate::state::{Category, Challenge, ChallengePrizePool, Forum, UserProfile};
+use prog_common::{now_ts, errors::ErrorCode};
+
+#[derive(Accounts)]
+#[instruction(bump_moderator_profile: u8, bump_challenge: u8, bump_prize_pool: u8)]
+pub struct CreateChallengePrizeVault<'info> {
+
+    // Forum
+    pub forum: Box<Account<'info, Forum>>,
+
+    #[account(mut)]
+    pub moderator: Signer<'info>,
+
+    // The moderator profile
+    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), moderator_profile.profile_seed.as_ref()],
+              bump = bump_moderator_profile, has_one = forum, constraint = moderator_profile.profile_owner == moderator.key())]
+    pub moderator_profile: Box<Account<'info, UserProfile>>,
+
+    // Challenge PDA account and seed
+    #[account(seeds = [b"challenge".as_ref(), forum.key().as_ref(), challenge_seed.key().as_ref()],
+              bump = bump_challenge, has_one = forum, has_one = challenge_seed)]
+    pub challenge: Box<Account<'info, Challenge>>,
+
+    // Category of the challenge, whose moderators may also moderate it
+    #[account(has_one = forum, constraint = category.key() == challenge.category)]
+    pub category: Box<Account<'info, Category>>,
+
+    /// CHECK: The seed address used for initialization of the challenge PDA
+    pub challenge_seed: AccountInfo<'info>,
+
+    // Challenge prize pool PDA account
+    #[account(mut, seeds = [b"challenge_prize_pool".as_ref(), challenge.key().as_ref()],
+              bump = bump_prize_pool, has_one = challenge)]
+    pub prize_pool: Box<Account<'info, ChallengePrizePool>>,
+
+    // SPL token mint of the prize
+    pub prize_mint: Box<Account<'info, Mint>>,
+
+    // Prize vault PDA token account (owned by the prize pool PDA)
+    #[account(init, seeds = [b"prize_pool_vault".as_ref(), prize_pool.key().as_ref()],
+              bump, payer = moderator, token::mint = prize_mint, token::authority = prize_pool)]
+    pub prize_vault: Box<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn create_challenge_prize_vault(ctx: Context<CreateChallengePrizeVault>) -> Result<()> {
+
+    let now_ts: u64 = now_ts()?;
+
+    if !ctx.accounts.moderator_profile.is_moderator && !ctx.accounts.category.is_category_moderator(&ctx.accounts.moderator_profile.key()) {
+        return Err(error!(ErrorCode::ProfileIsNotModerator));
+    }
+
+    // Ensure the prize pool has not yet been funded in lamports
+    if ctx.accounts.prize_pool.total_funded > 0 {
+        return Err(error!(ErrorCode::PrizePoolAlreadyFunded));
+    }
+
+    // Record the prize mint in the prize pool's state account
+    let prize_pool = &mut ctx.accounts.prize_pool;
+    prize_pool.prize_mint = ctx.accounts.prize_mint.key();
+
+    // Update the moderator profile's state account
+    let moderator_profile = &mut ctx.accounts.moderator_profile;
+    moderator_profile.most_recent_engagement_ts = now_ts;
+
+    msg!("Challenge prize pool PDA account with address {} now pays prizes in mint {} from vault {}",
+         ctx.accounts.prize_pool.key(), ctx.accounts.prize_mint.key(), ctx.accounts.prize_vault.key());
+    Ok(())
+}
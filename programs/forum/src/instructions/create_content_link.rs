@@ -0,0 +1,76 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{ContentLink, ContentRelationType, Forum, LinkedContentType, UserProfile};
+use prog_common::{now_ts, errors::ErrorCode};
+
+#[derive(Accounts)]
+#[instruction(bump_moderator_profile: u8)]
+pub struct CreateContentLink<'info> {
+
+    // Forum
+    pub forum: Box<Account<'info, Forum>>,
+
+    #[account(mut)]
+    pub moderator: Signer<'info>,
+
+    // The moderator profile
+    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), moderator_profile.profile_seed.as_ref()],
+              bump = bump_moderator_profile, has_one = forum, constraint = moderator_profile.profile_owner == moderator.key())]
+    pub moderator_profile: Box<Account<'info, UserProfile>>,
+
+    /// CHECK: Question/BigNote/Challenge PDA account being linked from, verified against the source content type
+    pub source_content: AccountInfo<'info>,
+
+    /// CHECK: Question/BigNote/Challenge PDA account being linked to, verified against the target content type
+    pub target_content: AccountInfo<'info>,
+
+    // Content link PDA account
+    #[account(init, seeds = [b"content_link".as_ref(), source_content.key().as_ref(), target_content.key().as_ref()],
+              bump, payer = moderator, space = 8 + std::mem::size_of::<ContentLink>())]
+    pub content_link: Box<Account<'info, ContentLink>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn create_content_link(
+    ctx: Context<CreateContentLink>,
+    source_content_type: LinkedContentType,
+    target_content_type: LinkedContentType,
+    relation_type: ContentRelationType,
+) -> Result<()> {
+
+    let now_ts: u64 = now_ts()?;
+
+    if !ctx.accounts.moderator_profile.is_moderator {
+        return Err(error!(ErrorCode::ProfileIsNotModerator));
+    }
+
+    // Ensure content is not linked to itself
+    if ctx.accounts.source_content.key() == ctx.accounts.target_content.key() {
+        return Err(error!(ErrorCode::InvalidLinkedContent));
+    }
+
+    // Ensure the linked content accounts match their content types and belong to the forum
+    source_content_type.verify_linked_content(&ctx.accounts.source_content, &ctx.accounts.forum.key())?;
+    target_content_type.verify_linked_content(&ctx.accounts.target_content, &ctx.accounts.forum.key())?;
+
+    // Record Content Link's State
+    let content_link = &mut ctx.accounts.content_link;
+    content_link.forum = ctx.accounts.forum.key();
+    content_link.moderator_profile = ctx.accounts.moderator_profile.key();
+    content_link.source_content = ctx.accounts.source_content.key();
+    content_link.target_content = ctx.accounts.target_content.key();
+
+    content_link.content_link_posted_ts = now_ts;
+
+    content_link.source_content_type = source_content_type;
+    content_link.target_content_type = target_content_type;
+    content_link.relation_type = relation_type;
+
+    // Update moderator profile's most recent engagement
+    let moderator_profile = &mut ctx.accounts.moderator_profile;
+    moderator_profile.most_recent_engagement_ts = now_ts;
+
+    msg!("Content link PDA account with address {} now created", ctx.accounts.content_link.key());
+    Ok(())
+}
@@ -0,0 +1,87 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{Category, Challenge, ChallengeProposal, EvaluationPanel, Forum, MAX_JUDGES, UserProfile};
+use prog_common::{now_ts, errors::ErrorCode};
+
+#[derive(Accounts)]
+#[instruction(bump_moderator_profile: u8, bump_challenge: u8, bump_challenge_proposal: u8)]
+pub struct CreateEvaluationPanel<'info> {
+
+    // Forum
+    pub forum: Box<Account<'info, Forum>>,
+
+    #[account(mut)]
+    pub moderator: Signer<'info>,
+
+    // The moderator profile
+    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), moderator_profile.profile_seed.as_ref()],
+              bump = bump_moderator_profile, has_one = forum, constraint = moderator_profile.profile_owner == moderator.key())]
+    pub moderator_profile: Box<Account<'info, UserProfile>>,
+
+    // Challenge PDA account and seed
+    #[account(seeds = [b"challenge".as_ref(), forum.key().as_ref(), challenge_seed.key().as_ref()],
+              bump = bump_challenge, has_one = forum, has_one = challenge_seed)]
+    pub challenge: Box<Account<'info, Challenge>>,
+
+    // Category of the challenge, whose moderators may also moderate it
+    #[account(has_one = forum, constraint = category.key() == challenge.category)]
+    pub category: Box<Account<'info, Category>>,
+
+    /// CHECK: The seed address used for initialization of the challenge PDA
+    pub challenge_seed: AccountInfo<'info>,
+
+    /// CHECK: The challenge's proposal PDA account (empty if the challenge was created by a moderator)
+    #[account(seeds = [b"challenge_proposal".as_ref(), challenge.key().as_ref()], bump = bump_challenge_proposal)]
+    pub challenge_proposal: AccountInfo<'info>,
+
+    // Evaluation panel PDA account
+    #[account(init, seeds = [b"evaluation_panel".as_ref(), challenge.key().as_ref()],
+              bump, payer = moderator, space = 8 + std::mem::size_of::<EvaluationPanel>())]
+    pub evaluation_panel: Box<Account<'info, EvaluationPanel>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn create_evaluation_panel(ctx: Context<CreateEvaluationPanel>, judges: Vec<Pubkey>, quorum: u64) -> Result<()> {
+
+    let now_ts: u64 = now_ts()?;
+
+    if !ctx.accounts.moderator_profile.is_moderator && !ctx.accounts.category.is_category_moderator(&ctx.accounts.moderator_profile.key()) {
+        return Err(error!(ErrorCode::ProfileIsNotModerator));
+    }
+
+    // Ensure a community proposed challenge has been approved by a moderator
+    if !ChallengeProposal::is_challenge_approved(&ctx.accounts.challenge_proposal)? {
+        return Err(error!(ErrorCode::ChallengeNotApproved));
+    }
+
+    // Ensure the number of judges and quorum are within bounds
+    let judge_count: u64 = judges.len() as u64;
+    if (judge_count == 0) || (judges.len() > MAX_JUDGES) || (quorum == 0) || (quorum > judge_count) {
+        return Err(error!(ErrorCode::InvalidEvaluationPanel));
+    }
+
+    // Ensure judges are distinct
+    let mut panel_judges: [Pubkey; MAX_JUDGES] = [Pubkey::default(); MAX_JUDGES];
+    for (index, judge) in judges.iter().enumerate() {
+        if panel_judges[..index].contains(judge) {
+            return Err(error!(ErrorCode::InvalidEvaluationPanel));
+        }
+        panel_judges[index] = *judge;
+    }
+
+    // Record Evaluation Panel's State
+    let evaluation_panel = &mut ctx.accounts.evaluation_panel;
+    evaluation_panel.challenge = ctx.accounts.challenge.key();
+    evaluation_panel.judges = panel_judges;
+    evaluation_panel.judge_count = judge_count;
+    evaluation_panel.quorum = quorum;
+
+    // Update the moderator profile's state account
+    let moderator_profile = &mut ctx.accounts.moderator_profile;
+    moderator_profile.most_recent_engagement_ts = now_ts;
+
+    msg!("Evaluation panel PDA account with address {} now created for challenge with address {}",
+         ctx.accounts.evaluation_panel.key(), ctx.accounts.challenge.key());
+    Ok(())
+}
@@ -3,42 +3,55 @@ use anchor_lang::prelude::*;
 use anchor_lang::solana_program::program::{invoke};
 use anchor_lang::solana_program::system_instruction;
 
-use crate::state::{Challenge, Forum, Submission, SubmissionState, UserProfile};
+use crate::state::{Category, Challenge, ChallengeProposal, Forum, Submission, SubmissionFeeEscrow, SubmissionState, TrackMembership, UserProfile};
 use prog_common::{now_ts, TryAdd, errors::ErrorCode};
 
 #[derive(Accounts)]
-#[instruction(bump_treasury: u8, bump_user_profile: u8, bump_challenge: u8)]
+#[instruction(bump_user_profile: u8, bump_challenge: u8, bump_challenge_proposal: u8, bump_track_membership: u8)]
 pub struct CreateSubmission<'info> {
 
     // Forum
-    #[account(mut, has_one = forum_treasury)]
+    #[account(mut)]
     pub forum: Box<Account<'info, Forum>>,
 
-    /// CHECK:
-    #[account(mut, seeds = [b"treasury".as_ref(), forum.key().as_ref()], bump = bump_treasury)]
-    pub forum_treasury: AccountInfo<'info>,
-
     #[account(mut)]
     pub profile_owner: Signer<'info>,
 
     // The user profile
-    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), profile_owner.key().as_ref()],
+    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), user_profile.profile_seed.as_ref()],
               bump = bump_user_profile, has_one = forum, has_one = profile_owner)]
     pub user_profile: Box<Account<'info, UserProfile>>,
 
     // Challenge PDA account and seed
-    #[account(seeds = [b"challenge".as_ref(), forum.key().as_ref(), challenge_seed.key().as_ref()],
+    #[account(mut, seeds = [b"challenge".as_ref(), forum.key().as_ref(), challenge_seed.key().as_ref()],
               bump = bump_challenge, has_one = forum, has_one = challenge_seed)]
     pub challenge: Box<Account<'info, Challenge>>,
 
+    // Category of the challenge, whose fee overrides apply
+    #[account(has_one = forum, constraint = category.key() == challenge.category)]
+    pub category: Box<Account<'info, Category>>,
+
     /// CHECK: The seed address used for initialization of the challenge PDA
     pub challenge_seed: AccountInfo<'info>,
 
+    /// CHECK: The challenge's proposal PDA account (empty if the challenge was created by a moderator)
+    #[account(seeds = [b"challenge_proposal".as_ref(), challenge.key().as_ref()], bump = bump_challenge_proposal)]
+    pub challenge_proposal: AccountInfo<'info>,
+
+    /// CHECK: The challenge's track membership PDA account (empty if the challenge is not part of a track)
+    #[account(seeds = [b"track_membership".as_ref(), challenge.key().as_ref()], bump = bump_track_membership)]
+    pub track_membership: AccountInfo<'info>,
+
     // Submission PDA account
     #[account(init, seeds = [b"submission".as_ref(), challenge.key().as_ref(), user_profile.key().as_ref()],
               bump, payer = profile_owner, space = 8 + std::mem::size_of::<Submission>())]
     pub submission: Box<Account<'info, Submission>>,
 
+    // Submission fee escrow PDA account (holds the submission fee until the submission is evaluated)
+    #[account(init, seeds = [b"submission_fee_escrow".as_ref(), submission.key().as_ref()],
+              bump, payer = profile_owner, space = 8 + std::mem::size_of::<SubmissionFeeEscrow>())]
+    pub submission_fee_escrow: Box<Account<'info, SubmissionFeeEscrow>>,
+
     /// CHECK:
     // The content data hash of the submission struct
     pub content_data_hash: AccountInfo<'info>,
@@ -50,10 +63,10 @@ impl<'info> CreateSubmission<'info> {
 
     fn transfer_payment_ctx(&self, lamports: u64) -> Result<()> {
         invoke(
-            &system_instruction::transfer(self.profile_owner.key, self.forum_treasury.key, lamports),
+            &system_instruction::transfer(self.profile_owner.key, &self.submission_fee_escrow.key(), lamports),
             &[
                 self.profile_owner.to_account_info(),
-                self.forum_treasury.to_account_info(),
+                self.submission_fee_escrow.to_account_info(),
                 self.system_program.to_account_info(),
             ],
         )
@@ -61,16 +74,43 @@ impl<'info> CreateSubmission<'info> {
     }
 }
 
-pub fn create_submission(ctx: Context<CreateSubmission>) -> Result<()> {
+// If the challenge has a prerequisite, the completed prerequisite submission must be passed in as the first remaining account
+pub fn create_submission<'info>(ctx: Context<'_, '_, '_, 'info, CreateSubmission<'info>>) -> Result<()> {
 
     let now_ts: u64 = now_ts()?;
 
+    // Ensure the user profile has not been suspended from the forum
+    if ctx.accounts.user_profile.is_suspended {
+        return Err(error!(ErrorCode::ProfileSuspended));
+    }
+
     // Ensure challenge expires timestamp has not yet passed
     let challenge_expires_ts = ctx.accounts.challenge.challenge_expires_ts;
     if now_ts > challenge_expires_ts {
         return Err(error!(ErrorCode::ChallengeExpired));
     }
 
+    // Ensure a community proposed challenge has been approved by a moderator
+    if !ChallengeProposal::is_challenge_approved(&ctx.accounts.challenge_proposal)? {
+        return Err(error!(ErrorCode::ChallengeNotApproved));
+    }
+
+    // Ensure the previous challenge in the track (if any) has been completed
+    if !ctx.accounts.track_membership.data_is_empty() {
+        let track_membership = Account::<TrackMembership>::try_from(&ctx.accounts.track_membership)?;
+
+        if track_membership.prerequisite_challenge != Pubkey::default() {
+            let prerequisite_account_info = ctx.remaining_accounts.first().ok_or(error!(ErrorCode::PrerequisiteNotCompleted))?;
+            let prerequisite_submission = Account::<Submission>::try_from(prerequisite_account_info)?;
+
+            if (prerequisite_submission.challenge != track_membership.prerequisite_challenge) ||
+                (prerequisite_submission.user_profile != ctx.accounts.user_profile.key()) ||
+                (prerequisite_submission.submission_state != SubmissionState::Completed) {
+                return Err(error!(ErrorCode::PrerequisiteNotCompleted));
+            }
+        }
+    }
+
     // Record Submission's State
     let submission = &mut ctx.accounts.submission;
     submission.challenge = ctx.accounts.challenge.key();
@@ -81,14 +121,30 @@ pub fn create_submission(ctx: Context<CreateSubmission>) -> Result<()> {
 
     submission.content_data_hash = ctx.accounts.content_data_hash.key();
     submission.submission_state = SubmissionState::Pending;
+    submission.approval_votes = 0;
+    submission.rejection_votes = 0;
+    submission.total_score = 0;
+    submission.score_count = 0;
+    submission.peer_review_count = 0;
+    submission.revision_count = 0;
 
-    // Transfer fee for making submission
-    let submission_fee = ctx.accounts.forum.forum_fees.forum_challenge_submission_fee;
+    // Transfer fee for making submission into escrow
+    let submission_fee = ctx.accounts.category.fees(&ctx.accounts.forum.forum_fees).forum_challenge_submission_fee;
 
     if submission_fee > 0 {
         ctx.accounts.transfer_payment_ctx(submission_fee)?;
     }
 
+    // Record Submission Fee Escrow's State
+    let submission_fee_escrow = &mut ctx.accounts.submission_fee_escrow;
+    submission_fee_escrow.submission = ctx.accounts.submission.key();
+    submission_fee_escrow.user_profile = ctx.accounts.user_profile.key();
+    submission_fee_escrow.fee_amount = submission_fee;
+
+    // Increment submission count in challenge's state account
+    let challenge = &mut ctx.accounts.challenge;
+    challenge.submission_count.try_add_assign(1)?;
+
     // Increment submission count in forum's state account
     let forum = &mut ctx.accounts.forum;
     forum.forum_counts.forum_submission_count.try_add_assign(1)?;
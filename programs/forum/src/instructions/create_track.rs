@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{Forum, MAX_TRACK_CHALLENGES, Track, UserProfile};
+use prog_common::{now_ts, errors::ErrorCode};
+
+#[derive(Accounts)]
+#[instruction(bump_moderator_profile: u8)]
+pub struct CreateTrack<'info> {
+
+    // Forum
+    pub forum: Box<Account<'info, Forum>>,
+
+    #[account(mut)]
+    pub moderator: Signer<'info>,
+
+    // The moderator profile
+    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), moderator_profile.profile_seed.as_ref()],
+              bump = bump_moderator_profile, has_one = forum, constraint = moderator_profile.profile_owner == moderator.key())]
+    pub moderator_profile: Box<Account<'info, UserProfile>>,
+
+    // Track PDA account and seed
+    #[account(init, seeds = [b"track".as_ref(), forum.key().as_ref(), track_seed.key().as_ref()],
+              bump, payer = moderator, space = 8 + std::mem::size_of::<Track>())]
+    pub track: Box<Account<'info, Track>>,
+
+    /// CHECK: The seed address used for initialization of the track PDA
+    pub track_seed: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn create_track(ctx: Context<CreateTrack>, completion_rep: u64) -> Result<()> {
+
+    let now_ts: u64 = now_ts()?;
+
+    if !ctx.accounts.moderator_profile.is_moderator {
+        return Err(error!(ErrorCode::ProfileIsNotModerator));
+    }
+
+    // Record Track's State
+    let track = &mut ctx.accounts.track;
+    track.forum = ctx.accounts.forum.key();
+    track.track_seed = ctx.accounts.track_seed.key();
+    track.challenges = [Pubkey::default(); MAX_TRACK_CHALLENGES];
+    track.challenge_count = 0;
+    track.track_created_ts = now_ts;
+    track.completion_rep = completion_rep;
+
+    // Update the moderator profile's state account
+    let moderator_profile = &mut ctx.accounts.moderator_profile;
+    moderator_profile.most_recent_engagement_ts = now_ts;
+
+    msg!("Track PDA account with address {} now created", ctx.accounts.track.key());
+    Ok(())
+}
@@ -1,11 +1,11 @@
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::{program::invoke, system_instruction};
 
-use crate::state::{Forum, UserProfile};
-use prog_common::{now_ts, TryAdd};
+use crate::state::{Forum, ForumAccessPolicy, UserProfile};
+use prog_common::{now_ts, TryAdd, errors::ErrorCode};
 
 #[derive(Accounts)]
-#[instruction(bump_treasury: u8)]
+#[instruction(bump_treasury: u8, bump_wallet_rotation: u8, bump_forum_access_policy: u8)]
 pub struct CreateUserProfile<'info> {
 
     // Forum
@@ -24,6 +24,14 @@ pub struct CreateUserProfile<'info> {
               bump, payer = profile_owner, space = 8 + std::mem::size_of::<UserProfile>())]
     pub user_profile: Box<Account<'info, UserProfile>>,
 
+    /// CHECK: The wallet rotation PDA account (non-empty if the wallet has taken over another user profile)
+    #[account(seeds = [b"wallet_rotation".as_ref(), forum.key().as_ref(), profile_owner.key().as_ref()], bump = bump_wallet_rotation)]
+    pub wallet_rotation: AccountInfo<'info>,
+
+    /// CHECK: The forum access policy PDA account (empty if the forum is open)
+    #[account(seeds = [b"forum_access_policy".as_ref(), forum.key().as_ref()], bump = bump_forum_access_policy)]
+    pub forum_access_policy: AccountInfo<'info>,
+
     pub system_program: Program<'info, System>,
 }
 
@@ -47,6 +55,20 @@ pub fn create_user_profile(ctx: Context<CreateUserProfile>) -> Result<()> {
     let now_ts: u64 = now_ts()?;
     let forum_profile_fee = ctx.accounts.forum.forum_fees.forum_profile_fee;
 
+    // Ensure the wallet does not already own a user profile through a wallet rotation
+    if !ctx.accounts.wallet_rotation.data_is_empty() {
+        return Err(error!(ErrorCode::WalletAlreadyHasProfile));
+    }
+
+    // Ensure the wallet meets the forum's access policy (proof accounts are passed in as remaining accounts)
+    if !ctx.accounts.forum_access_policy.data_is_empty() {
+        let forum_access_policy = Account::<ForumAccessPolicy>::try_from(&ctx.accounts.forum_access_policy)?;
+
+        if !forum_access_policy.is_eligible(&ctx.accounts.profile_owner.key(), ctx.remaining_accounts) {
+            return Err(error!(ErrorCode::NotEligibleForForum));
+        }
+    }
+
     if forum_profile_fee > 0 {
         ctx.accounts.transfer_payment_ctx(forum_profile_fee)?;
     }
@@ -55,15 +77,20 @@ pub fn create_user_profile(ctx: Context<CreateUserProfile>) -> Result<()> {
     let user_profile = &mut ctx.accounts.user_profile;
     user_profile.profile_owner = ctx.accounts.profile_owner.key();
     user_profile.forum = ctx.accounts.forum.key();
+    user_profile.profile_seed = ctx.accounts.profile_owner.key();
+    user_profile.pending_profile_owner = Pubkey::default();
     user_profile.profile_created_ts = now_ts;
     user_profile.most_recent_engagement_ts = now_ts;
+    user_profile.eligibility_checked_ts = now_ts;
 
     user_profile.big_notes_created = 0;
     user_profile.big_notes_verified = 0;
     user_profile.big_notes_contributions_proposed = 0;
     user_profile.big_notes_contributions_accepted = 0;
+    user_profile.big_notes_credited = 0;
     user_profile.challenges_submitted = 0;
     user_profile.challenges_completed = 0;
+    user_profile.tracks_completed = 0;
     user_profile.questions_asked = 0;
     user_profile.questions_answered = 0;
     user_profile.answers_accepted = 0;
@@ -71,11 +98,14 @@ pub fn create_user_profile(ctx: Context<CreateUserProfile>) -> Result<()> {
     user_profile.total_bounty_contributed = 0;
     user_profile.total_bounty_earned = 0;
     user_profile.reputation_score = 0;
+    user_profile.follower_count = 0;
+    user_profile.following_count = 0;
 
     // user_profile.nft_pfp_token_mint = ;
     user_profile.has_about_me = false;
     user_profile.has_had_about_me = false;
     user_profile.is_moderator = false;
+    user_profile.is_suspended = false;
 
     // Increment user profile count in forum state's account
     let forum = &mut ctx.accounts.forum;
@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 
-use crate::state::{Answer, Forum, Question, UserProfile};
+use crate::state::{Answer, Category, Forum, Question, UserProfile};
 use prog_common::{now_ts, close_account, TrySub, errors::ErrorCode};
 
 #[derive(Accounts)]
@@ -14,7 +14,7 @@ pub struct DeleteAnswerModerator<'info> {
     pub moderator: Signer<'info>,
 
     // The moderator profile
-    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), moderator.key().as_ref()],
+    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), moderator_profile.profile_seed.as_ref()],
               bump = bump_moderator_profile, has_one = forum, constraint = moderator_profile.profile_owner == moderator.key())]
     pub moderator_profile: Box<Account<'info, UserProfile>>,
 
@@ -22,7 +22,7 @@ pub struct DeleteAnswerModerator<'info> {
     pub profile_owner: AccountInfo<'info>,
 
     // The user profile
-    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), profile_owner.key().as_ref()],
+    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), user_profile.profile_seed.as_ref()],
               bump = bump_user_profile, has_one = forum, has_one = profile_owner)]
     pub user_profile: Box<Account<'info, UserProfile>>,
 
@@ -30,6 +30,10 @@ pub struct DeleteAnswerModerator<'info> {
     #[account(mut, has_one = forum)]
     pub question: Box<Account<'info, Question>>,
 
+    // Category of the question, whose moderators may also moderate it
+    #[account(has_one = forum, constraint = category.key() == question.category)]
+    pub category: Box<Account<'info, Category>>,
+
     // Answer PDA account and seed
     #[account(mut, seeds = [b"answer".as_ref(), forum.key().as_ref(), user_profile.key().as_ref(), answer_seed.key().as_ref()],
               bump = bump_answer, has_one = question, has_one = user_profile, has_one = answer_seed)]
@@ -52,7 +56,7 @@ pub fn delete_answer_moderator(ctx: Context<DeleteAnswerModerator>) -> Result<()
     let answer_rep = ctx.accounts.answer.answer_rep;
     let is_accepted_answer = ctx.accounts.answer.accepted_answer;
 
-    if !ctx.accounts.moderator_profile.is_moderator {
+    if !ctx.accounts.moderator_profile.is_moderator && !ctx.accounts.category.is_category_moderator(&ctx.accounts.moderator_profile.key()) {
         return Err(error!(ErrorCode::ProfileIsNotModerator));
     }
 
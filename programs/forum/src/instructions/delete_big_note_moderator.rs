@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 
-use crate::state::{BigNote, BigNoteVerificationState, BountyContribution, BountyContributionState, Forum, UserProfile};
+use crate::state::{BigNote, BigNoteVerificationState, BountyContribution, BountyContributionState, Category, Forum, UserProfile};
 use prog_common::{close_account, now_ts, TrySub, errors::ErrorCode};
 
 #[derive(Accounts)]
@@ -14,7 +14,7 @@ pub struct DeleteBigNoteModerator<'info> {
     pub moderator: Signer<'info>,
 
     // The moderator profile
-    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), moderator.key().as_ref()],
+    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), moderator_profile.profile_seed.as_ref()],
               bump = bump_moderator_profile, has_one = forum, constraint = moderator_profile.profile_owner == moderator.key())]
     pub moderator_profile: Box<Account<'info, UserProfile>>,
 
@@ -22,7 +22,7 @@ pub struct DeleteBigNoteModerator<'info> {
     pub profile_owner: AccountInfo<'info>,
 
     // The user profile
-    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), profile_owner.key().as_ref()],
+    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), user_profile.profile_seed.as_ref()],
               bump = bump_user_profile, has_one = forum, has_one = profile_owner)]
     pub user_profile: Box<Account<'info, UserProfile>>,
 
@@ -34,6 +34,18 @@ pub struct DeleteBigNoteModerator<'info> {
     /// CHECK: The seed address used for initialization of the Big Note PDA
     pub big_note_seed: AccountInfo<'info>,
 
+    // Category of the big note (its category moderators may moderate it)
+    #[account(mut, has_one = forum, constraint = category.key() == big_note.category)]
+    pub category: Box<Account<'info, Category>>,
+
+    /// CHECK: Profile of the source answer's author, verified against the source answer's address (ignored unless the big note
+    /// was converted from a question, and left untouched if the profile has since been deleted)
+    #[account(mut)]
+    pub answer_user_profile: AccountInfo<'info>,
+
+    /// CHECK: The seed address used for initialization of the source answer PDA
+    pub answer_seed: AccountInfo<'info>,
+
     /// CHECK:
     #[account(mut)]
     pub receiver: AccountInfo<'info>,
@@ -48,7 +60,8 @@ pub fn delete_big_note_moderator(ctx: Context<DeleteBigNoteModerator>) -> Result
     let big_note_creation_rep = ctx.accounts.big_note.big_note_creation_rep;
     let big_note_verification_state = ctx.accounts.big_note.verification_state;
 
-    if !ctx.accounts.moderator_profile.is_moderator {
+    // Ensure the moderator is either a forum moderator or a moderator of the content's category
+    if !ctx.accounts.moderator_profile.is_moderator && !ctx.accounts.category.is_category_moderator(&ctx.accounts.moderator_profile.key()) {
         return Err(error!(ErrorCode::ProfileIsNotModerator));
     }
 
@@ -71,6 +84,10 @@ pub fn delete_big_note_moderator(ctx: Context<DeleteBigNoteModerator>) -> Result
     let forum = &mut ctx.accounts.forum;
     forum.forum_counts.forum_big_notes_count.try_sub_assign(1)?;
 
+    // Decrement big note count in category's state
+    let category = &mut ctx.accounts.category;
+    category.big_notes_count.try_sub_assign(1)?;
+
     // Decrement big notes created and reputation score in user profile's state account
     let user_profile = &mut ctx.accounts.user_profile;
     user_profile.big_notes_created.try_sub_assign(1)?;
@@ -82,6 +99,29 @@ pub fn delete_big_note_moderator(ctx: Context<DeleteBigNoteModerator>) -> Result
         user_profile.reputation_score.try_sub_assign(big_note_verification_rep)?;
     }
 
+    // Decrement big notes credited in the converting and answering profiles' state accounts if converted from a question
+    if ctx.accounts.big_note.source_question != Pubkey::default() {
+        user_profile.big_notes_credited.try_sub_assign(1)?;
+
+        let (source_answer, _bump) = Pubkey::find_program_address(&[b"answer".as_ref(), ctx.accounts.forum.key().as_ref(),
+            ctx.accounts.answer_user_profile.key().as_ref(), ctx.accounts.answer_seed.key().as_ref()], &crate::ID);
+
+        if source_answer != ctx.accounts.big_note.source_answer {
+            return Err(error!(ErrorCode::InvalidParameter));
+        }
+
+        // A self-answered question's converting profile is also its answering profile, which is already loaded and decremented above
+        if (ctx.accounts.answer_user_profile.key() != ctx.accounts.user_profile.key()) && !ctx.accounts.answer_user_profile.data_is_empty() {
+            let mut answer_user_profile = Account::<UserProfile>::try_from(&ctx.accounts.answer_user_profile)?;
+
+            // A profile recreated at the same address after the conversion was never credited
+            if answer_user_profile.profile_created_ts <= ctx.accounts.big_note.big_note_created_ts {
+                answer_user_profile.big_notes_credited.try_sub_assign(1)?;
+                answer_user_profile.exit(&crate::ID)?;
+            }
+        }
+    }
+
     // Update moderator profile's most recent engagement
     let moderator_profile = &mut ctx.accounts.moderator_profile;
     moderator_profile.most_recent_engagement_ts = now_ts;
@@ -17,13 +17,13 @@ pub struct DeleteBigNoteVerificationApplication<'info> {
     pub profile_owner: Signer<'info>,
 
     // The user profile
-    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), profile_owner.key().as_ref()],
+    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), user_profile.profile_seed.as_ref()],
               bump = bump_user_profile, has_one = forum, has_one = profile_owner)]
     pub user_profile: Box<Account<'info, UserProfile>>,
 
     // Big Note pda account and seed
-    #[account(mut, seeds = [b"big_note".as_ref(), forum.key().as_ref(), user_profile.key().as_ref(), big_note_seed.key().as_ref()],
-              bump = bump_big_note, has_one = forum, has_one = user_profile, has_one = big_note_seed)]
+    #[account(mut, seeds = [b"big_note".as_ref(), forum.key().as_ref(), big_note.user_profile.as_ref(), big_note_seed.key().as_ref()],
+              bump = bump_big_note, has_one = forum, has_one = big_note_seed)]
     pub big_note: Box<Account<'info, BigNote>>,
 
     /// CHECK: The seed address used for initialization of the big note PDA
@@ -60,6 +60,11 @@ pub fn delete_big_note_verification_application(ctx: Context<DeleteBigNoteVerifi
 
     let now_ts: u64 = now_ts()?;
 
+    // Ensure the signer's profile is the current owner of the big note
+    if ctx.accounts.big_note.current_owner != ctx.accounts.user_profile.key() {
+        return Err(error!(ErrorCode::NotCurrentOwner));
+    }
+
     // Ensure that big note account's verification state is 'AppliedForVerification'
     if !(ctx.accounts.big_note.verification_state == BigNoteVerificationState::AppliedForVerification) {
         return Err(error!(ErrorCode::BigNoteNotAppliedForVerification));
@@ -1,10 +1,10 @@
 use anchor_lang::prelude::*;
 
-use crate::state::{Challenge, Forum, UserProfile};
+use crate::state::{Challenge, ChallengePrizePool, ChallengeProposal, ChallengeProposalState, ChallengeState, Category, Forum, UserProfile};
 use prog_common::{now_ts, close_account, TrySub, errors::ErrorCode};
 
 #[derive(Accounts)]
-#[instruction(bump_moderator_profile: u8, bump_challenge: u8)]
+#[instruction(bump_moderator_profile: u8, bump_challenge: u8, bump_prize_pool: u8, bump_challenge_proposal: u8, bump_track_membership: u8)]
 pub struct DeleteChallenge<'info> {
 
     // Forum
@@ -14,7 +14,7 @@ pub struct DeleteChallenge<'info> {
     pub moderator: Signer<'info>,
 
     // The moderator profile
-    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), moderator.key().as_ref()],
+    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), moderator_profile.profile_seed.as_ref()],
               bump = bump_moderator_profile, has_one = forum, constraint = moderator_profile.profile_owner == moderator.key())]
     pub moderator_profile: Box<Account<'info, UserProfile>>,
 
@@ -26,6 +26,22 @@ pub struct DeleteChallenge<'info> {
     /// CHECK: The seed address used for initialization of the challenge PDA
     pub challenge_seed: AccountInfo<'info>,
 
+    /// CHECK: The challenge's proposal PDA account (empty if the challenge was created by a moderator)
+    #[account(mut, seeds = [b"challenge_proposal".as_ref(), challenge.key().as_ref()], bump = bump_challenge_proposal)]
+    pub challenge_proposal: AccountInfo<'info>,
+
+    // Category of the challenge
+    #[account(mut, has_one = forum, constraint = category.key() == challenge.category)]
+    pub category: Box<Account<'info, Category>>,
+
+    /// CHECK: The challenge prize pool PDA account (empty if the challenge has no prize pool)
+    #[account(mut, seeds = [b"challenge_prize_pool".as_ref(), challenge.key().as_ref()], bump = bump_prize_pool)]
+    pub prize_pool: AccountInfo<'info>,
+
+    /// CHECK: The challenge's track membership PDA account (empty if the challenge is not part of a track)
+    #[account(seeds = [b"track_membership".as_ref(), challenge.key().as_ref()], bump = bump_track_membership)]
+    pub track_membership: AccountInfo<'info>,
+
     /// CHECK:
     #[account(mut)]
     pub receiver: AccountInfo<'info>,
@@ -37,10 +53,39 @@ pub fn delete_challenge(ctx: Context<DeleteChallenge>) -> Result<()> {
 
     let now_ts = now_ts()?;
 
-    if !ctx.accounts.moderator_profile.is_moderator {
+    if !ctx.accounts.moderator_profile.is_moderator && !ctx.accounts.category.is_category_moderator(&ctx.accounts.moderator_profile.key()) {
         return Err(error!(ErrorCode::ProfileIsNotModerator));
     }
 
+    // Ensure a challenge still referenced by submissions has been archived
+    if (ctx.accounts.challenge.submission_count > 0) && (ctx.accounts.challenge.challenge_state != ChallengeState::Archived) {
+        return Err(error!(ErrorCode::ChallengeHasSubmissions));
+    }
+
+    // Ensure the challenge has been removed from its track (its successor would otherwise never be unlocked)
+    if !ctx.accounts.track_membership.data_is_empty() {
+        return Err(error!(ErrorCode::ChallengeInTrack));
+    }
+
+    // Ensure the prize pool (if any) has been fully paid out or refunded
+    if !ctx.accounts.prize_pool.data_is_empty() {
+        let prize_pool = Account::<ChallengePrizePool>::try_from(&ctx.accounts.prize_pool)?;
+        if prize_pool.remaining_amount()? > 0 {
+            return Err(error!(ErrorCode::PrizePoolNotSettled));
+        }
+    }
+
+    // Proposed challenges are only counted in forum's and category's state once approved
+    let challenge_approved: bool = ChallengeProposal::is_challenge_approved(&ctx.accounts.challenge_proposal)?;
+
+    // Ensure the proposal (if any) has been reviewed, so its deposit has already been refunded or slashed
+    if !ctx.accounts.challenge_proposal.data_is_empty() {
+        let challenge_proposal = Account::<ChallengeProposal>::try_from(&ctx.accounts.challenge_proposal)?;
+        if challenge_proposal.proposal_state == ChallengeProposalState::Pending {
+            return Err(error!(ErrorCode::ProposalNotReviewed));
+        }
+    }
+
     // Set the receiver of the lamports to be reclaimed from the rent of the accounts to be closed
     let receiver = &mut ctx.accounts.receiver;
 
@@ -48,9 +93,19 @@ pub fn delete_challenge(ctx: Context<DeleteChallenge>) -> Result<()> {
     let challenge_account_info = &mut (*ctx.accounts.challenge).to_account_info();
     close_account(challenge_account_info, receiver)?;
 
-    // Decrement challenge count in forum's state
-    let forum = &mut ctx.accounts.forum;
-    forum.forum_counts.forum_challenge_count.try_sub_assign(1)?;
+    // Close the challenge proposal state account (if it exists)
+    if !ctx.accounts.challenge_proposal.data_is_empty() {
+        close_account(&mut ctx.accounts.challenge_proposal, receiver)?;
+    }
+
+    // Decrement challenge count in forum's and category's state
+    if challenge_approved {
+        let forum = &mut ctx.accounts.forum;
+        forum.forum_counts.forum_challenge_count.try_sub_assign(1)?;
+
+        let category = &mut ctx.accounts.category;
+        category.challenge_count.try_sub_assign(1)?;
+    }
 
     // Update the moderator profile's state account
     let moderator_profile = &mut ctx.accounts.moderator_profile;
@@ -1,11 +1,11 @@
 use anchor_lang::prelude::*;
 
-use crate::state::{Answer, Comment, Forum, Question, UserProfile};
-use prog_common::{now_ts, close_account, TrySub};
+use crate::state::{Comment, CommentTarget, Forum, UserProfile};
+use prog_common::{now_ts, close_account, TrySub, errors::ErrorCode};
 
 #[derive(Accounts)]
 #[instruction(bump_user_profile: u8, bump_comment: u8)]
-pub struct DeleteCommentOnAnswer<'info> {
+pub struct DeleteComment<'info> {
 
     // Forum
     #[account(mut)]
@@ -14,21 +14,17 @@ pub struct DeleteCommentOnAnswer<'info> {
     pub profile_owner: Signer<'info>,
 
     // The user profile
-    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), profile_owner.key().as_ref()],
+    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), user_profile.profile_seed.as_ref()],
               bump = bump_user_profile, has_one = forum, has_one = profile_owner)]
     pub user_profile: Box<Account<'info, UserProfile>>,
 
-    // Question PDA account
-    #[account(mut, has_one = forum)]
-    pub question: Box<Account<'info, Question>>,
-
-    // Answer PDA account
-    #[account(mut, has_one = question)]
-    pub answer: Box<Account<'info, Answer>>,
+    /// CHECK: Account for which comment belongs, verified against the comment's state
+    #[account(mut)]
+    pub commented_on: AccountInfo<'info>,
 
     // Comment PDA account and seed
     #[account(mut, seeds = [b"comment".as_ref(), forum.key().as_ref(), user_profile.key().as_ref(), comment_seed.key().as_ref()],
-              bump = bump_comment, has_one = user_profile, has_one = comment_seed, constraint = comment.commented_on == answer.key())]
+              bump = bump_comment, has_one = user_profile, has_one = comment_seed, has_one = commented_on)]
     pub comment: Box<Account<'info, Comment>>,
 
     /// CHECK: The seed address used for initialization of the comment PDA
@@ -41,11 +37,17 @@ pub struct DeleteCommentOnAnswer<'info> {
     pub system_program: Program<'info, System>,
 }
 
-pub fn delete_comment_on_answer(ctx: Context<DeleteCommentOnAnswer>) -> Result<()> {
+pub fn delete_comment(ctx: Context<DeleteComment>) -> Result<()> {
 
     let now_ts: u64 = now_ts()?;
 
     let comment_rep = ctx.accounts.comment.comment_rep;
+    let comment_target = ctx.accounts.comment.comment_target;
+
+    // Ensure comment has no replies
+    if ctx.accounts.comment.reply_count > 0 {
+        return Err(error!(ErrorCode::CommentHasReplies));
+    }
 
     // Set the receiver of the lamports to be reclaimed from the rent of the accounts to be closed
     let receiver = &mut ctx.accounts.receiver;
@@ -64,13 +66,15 @@ pub fn delete_comment_on_answer(ctx: Context<DeleteCommentOnAnswer>) -> Result<(
     user_profile.reputation_score.try_sub_assign(comment_rep)?;
     user_profile.most_recent_engagement_ts = now_ts;
 
-    // Update answer account's most recent engagement timestamp
-    let answer = &mut ctx.accounts.answer;
-    answer.most_recent_engagement_ts = now_ts;
+    // Decrement parent comment account's reply count if the comment is a reply
+    if comment_target == CommentTarget::Comment {
+        let mut parent_comment = Account::<Comment>::try_from(&ctx.accounts.commented_on)?;
+        parent_comment.reply_count.try_sub_assign(1)?;
+        parent_comment.exit(&crate::ID)?;
+    }
 
-    // Update question account's most recent engagement timestamp
-    let question = &mut ctx.accounts.question;
-    question.most_recent_engagement_ts = now_ts;
+    // Update commented on account's most recent engagement timestamp
+    comment_target.record_engagement(&ctx.accounts.commented_on, now_ts)?;
 
     msg!("Comment PDA account with address {} now closed", ctx.accounts.comment.key());
     Ok(())
@@ -1,11 +1,11 @@
 use anchor_lang::prelude::*;
 
-use crate::state::{BigNote, Comment, Forum, ProposedContribution, UserProfile};
+use crate::state::{Category, Comment, CommentTarget, Forum, UserProfile};
 use prog_common::{now_ts, close_account, TrySub, errors::ErrorCode};
 
 #[derive(Accounts)]
 #[instruction(bump_moderator_profile: u8, bump_user_profile: u8, bump_comment: u8)]
-pub struct DeleteCommentOnProposedContributionModerator<'info> {
+pub struct DeleteCommentModerator<'info> {
 
     // Forum
     #[account(mut)]
@@ -14,7 +14,7 @@ pub struct DeleteCommentOnProposedContributionModerator<'info> {
     pub moderator: Signer<'info>,
 
     // The moderator profile
-    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), moderator.key().as_ref()],
+    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), moderator_profile.profile_seed.as_ref()],
               bump = bump_moderator_profile, has_one = forum, constraint = moderator_profile.profile_owner == moderator.key())]
     pub moderator_profile: Box<Account<'info, UserProfile>>,
 
@@ -22,26 +22,26 @@ pub struct DeleteCommentOnProposedContributionModerator<'info> {
     pub profile_owner: AccountInfo<'info>,
 
     // The user profile
-    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), profile_owner.key().as_ref()],
+    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), user_profile.profile_seed.as_ref()],
               bump = bump_user_profile, has_one = forum, has_one = profile_owner)]
     pub user_profile: Box<Account<'info, UserProfile>>,
 
-    // Big Note PDA account
-    #[account(mut, has_one = forum)]
-    pub big_note: Box<Account<'info, BigNote>>,
-
-    // Proposed Contribution PDA account
-    #[account(mut, has_one = big_note)]
-    pub proposed_contribution: Box<Account<'info, ProposedContribution>>,
+    /// CHECK: Account for which comment belongs, verified against the comment's state
+    #[account(mut)]
+    pub commented_on: AccountInfo<'info>,
 
     // Comment PDA account and seed
     #[account(mut, seeds = [b"comment".as_ref(), forum.key().as_ref(), user_profile.key().as_ref(), comment_seed.key().as_ref()],
-              bump = bump_comment, has_one = user_profile, has_one = comment_seed, constraint = comment.commented_on == proposed_contribution.key())]
+              bump = bump_comment, has_one = user_profile, has_one = comment_seed, has_one = commented_on)]
     pub comment: Box<Account<'info, Comment>>,
 
     /// CHECK: The seed address used for initialization of the comment PDA
     pub comment_seed: AccountInfo<'info>,
 
+    // Category of the content at the root of the comment thread, whose moderators may also moderate the comment
+    #[account(has_one = forum)]
+    pub category: Box<Account<'info, Category>>,
+
     /// CHECK:
     #[account(mut)]
     pub receiver: AccountInfo<'info>,
@@ -49,14 +49,25 @@ pub struct DeleteCommentOnProposedContributionModerator<'info> {
     pub system_program: Program<'info, System>,
 }
 
-pub fn delete_comment_on_proposed_contribution_moderator(ctx: Context<DeleteCommentOnProposedContributionModerator>) -> Result<()> {
+pub fn delete_comment_moderator<'info>(ctx: Context<'_, '_, '_, 'info, DeleteCommentModerator<'info>>) -> Result<()> {
 
     let now_ts: u64 = now_ts()?;
 
     let comment_rep = ctx.accounts.comment.comment_rep;
+    let comment_target = ctx.accounts.comment.comment_target;
 
+    // Category moderators must pass in the comment thread's parent accounts as remaining accounts
     if !ctx.accounts.moderator_profile.is_moderator {
-        return Err(error!(ErrorCode::ProfileIsNotModerator));
+        let comment_category = ctx.accounts.comment.comment_target.category(&ctx.accounts.commented_on, ctx.remaining_accounts)?;
+
+        if (comment_category != ctx.accounts.category.key()) || !ctx.accounts.category.is_category_moderator(&ctx.accounts.moderator_profile.key()) {
+            return Err(error!(ErrorCode::ProfileIsNotModerator));
+        }
+    }
+
+    // Ensure comment has no replies
+    if ctx.accounts.comment.reply_count > 0 {
+        return Err(error!(ErrorCode::CommentHasReplies));
     }
 
     // Set the receiver of the lamports to be reclaimed from the rent of the accounts to be closed
@@ -79,13 +90,15 @@ pub fn delete_comment_on_proposed_contribution_moderator(ctx: Context<DeleteComm
     let moderator_profile = &mut ctx.accounts.moderator_profile;
     moderator_profile.most_recent_engagement_ts = now_ts;
 
-    // Update Proposed Contribution account's most recent engagement timestamp
-    let proposed_contribution = &mut ctx.accounts.proposed_contribution;
-    proposed_contribution.most_recent_engagement_ts = now_ts;
+    // Decrement parent comment account's reply count if the comment is a reply
+    if comment_target == CommentTarget::Comment {
+        let mut parent_comment = Account::<Comment>::try_from(&ctx.accounts.commented_on)?;
+        parent_comment.reply_count.try_sub_assign(1)?;
+        parent_comment.exit(&crate::ID)?;
+    }
 
-    // Update Big Note account's most recent engagement timestamp
-    let big_note = &mut ctx.accounts.big_note;
-    big_note.most_recent_engagement_ts = now_ts;
+    // Update commented on account's most recent engagement timestamp
+    comment_target.record_engagement(&ctx.accounts.commented_on, now_ts)?;
 
     msg!("Comment PDA account with address {} has been closed by moderator profile with pubkey {}",
          ctx.accounts.comment.key(), ctx.accounts.moderator.key());
@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{ContentLink, Forum, UserProfile};
+use prog_common::{now_ts, close_account, errors::ErrorCode};
+
+#[derive(Accounts)]
+#[instruction(bump_moderator_profile: u8, bump_content_link: u8)]
+pub struct DeleteContentLink<'info> {
+
+    // Forum
+    pub forum: Box<Account<'info, Forum>>,
+
+    pub moderator: Signer<'info>,
+
+    // The moderator profile
+    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), moderator_profile.profile_seed.as_ref()],
+              bump = bump_moderator_profile, has_one = forum, constraint = moderator_profile.profile_owner == moderator.key())]
+    pub moderator_profile: Box<Account<'info, UserProfile>>,
+
+    // Content link PDA account
+    #[account(mut, seeds = [b"content_link".as_ref(), content_link.source_content.as_ref(), content_link.target_content.as_ref()],
+              bump = bump_content_link, has_one = forum)]
+    pub content_link: Box<Account<'info, ContentLink>>,
+
+    /// CHECK:
+    #[account(mut)]
+    pub receiver: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn delete_content_link(ctx: Context<DeleteContentLink>) -> Result<()> {
+
+    let now_ts: u64 = now_ts()?;
+
+    if !ctx.accounts.moderator_profile.is_moderator {
+        return Err(error!(ErrorCode::ProfileIsNotModerator));
+    }
+
+    // Set the receiver of the lamports to be reclaimed from the rent of the accounts to be closed
+    let receiver = &mut ctx.accounts.receiver;
+
+    // Close the content link state account
+    let content_link_account_info = &mut (*ctx.accounts.content_link).to_account_info();
+    close_account(content_link_account_info, receiver)?;
+
+    // Update moderator profile's most recent engagement
+    let moderator_profile = &mut ctx.accounts.moderator_profile;
+    moderator_profile.most_recent_engagement_ts = now_ts;
+
+    msg!("Content link PDA account with address {} has been closed by moderator profile with pubkey {}",
+         ctx.accounts.content_link.key(), ctx.accounts.moderator_profile.key());
+    Ok(())
+}
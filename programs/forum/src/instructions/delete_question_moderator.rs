@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 
-use crate::state::{BountyContribution, BountyContributionState, Forum, Question, UserProfile};
+use crate::state::{BountyContribution, BountyContributionState, Category, Forum, Question, UserProfile};
 use prog_common::{close_account, now_ts, TrySub, errors::ErrorCode};
 
 #[derive(Accounts)]
@@ -14,7 +14,7 @@ pub struct DeleteQuestionModerator<'info> {
     pub moderator: Signer<'info>,
 
     // The moderator profile
-    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), moderator.key().as_ref()],
+    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), moderator_profile.profile_seed.as_ref()],
               bump = bump_moderator_profile, has_one = forum, constraint = moderator_profile.profile_owner == moderator.key())]
     pub moderator_profile: Box<Account<'info, UserProfile>>,
 
@@ -22,7 +22,7 @@ pub struct DeleteQuestionModerator<'info> {
     pub profile_owner: AccountInfo<'info>,
 
     // The user profile
-    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), profile_owner.key().as_ref()],
+    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), user_profile.profile_seed.as_ref()],
               bump = bump_user_profile, has_one = forum, has_one = profile_owner)]
     pub user_profile: Box<Account<'info, UserProfile>>,
 
@@ -34,6 +34,10 @@ pub struct DeleteQuestionModerator<'info> {
     /// CHECK: The seed address used for initialization of the question PDA
     pub question_seed: AccountInfo<'info>,
 
+    // Category of the question (its category moderators may moderate it)
+    #[account(mut, has_one = forum, constraint = category.key() == question.category)]
+    pub category: Box<Account<'info, Category>>,
+
     /// CHECK:
     #[account(mut)]
     pub receiver: AccountInfo<'info>,
@@ -47,7 +51,8 @@ pub fn delete_question_moderator(ctx: Context<DeleteQuestionModerator>) -> Resul
 
     let question_rep = ctx.accounts.question.question_rep;
 
-    if !ctx.accounts.moderator_profile.is_moderator {
+    // Ensure the moderator is either a forum moderator or a moderator of the content's category
+    if !ctx.accounts.moderator_profile.is_moderator && !ctx.accounts.category.is_category_moderator(&ctx.accounts.moderator_profile.key()) {
         return Err(error!(ErrorCode::ProfileIsNotModerator));
     }
 
@@ -70,6 +75,10 @@ pub fn delete_question_moderator(ctx: Context<DeleteQuestionModerator>) -> Resul
     let forum = &mut ctx.accounts.forum;
     forum.forum_counts.forum_question_count.try_sub_assign(1)?;
 
+    // Decrement question count in category's state
+    let category = &mut ctx.accounts.category;
+    category.question_count.try_sub_assign(1)?;
+
     // Decrement questions asked and reputation score in user profile's state account
     let user_profile = &mut ctx.accounts.user_profile;
     user_profile.questions_asked.try_sub_assign(1)?;
@@ -1,25 +1,26 @@
 use anchor_lang::prelude::*;
 
-use crate::state::{Challenge, Forum, Submission, SubmissionState, UserProfile};
+use crate::state::{Challenge, Forum, Submission, SubmissionFeeEscrow, SubmissionState, UserProfile};
 use prog_common::{now_ts, close_account, TrySub, errors::ErrorCode};
 
 #[derive(Accounts)]
-#[instruction(bump_user_profile: u8, bump_challenge: u8, bump_submission: u8)]
+#[instruction(bump_user_profile: u8, bump_challenge: u8, bump_submission: u8, bump_submission_fee_escrow: u8)]
 pub struct DeleteSubmission<'info> {
 
     // Forum
     #[account(mut)]
     pub forum: Box<Account<'info, Forum>>,
 
+    #[account(mut)]
     pub profile_owner: Signer<'info>,
 
     // The user profile
-    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), profile_owner.key().as_ref()],
+    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), user_profile.profile_seed.as_ref()],
               bump = bump_user_profile, has_one = forum, has_one = profile_owner)]
     pub user_profile: Box<Account<'info, UserProfile>>,
 
     // Challenge PDA account and seed
-    #[account(seeds = [b"challenge".as_ref(), forum.key().as_ref(), challenge_seed.key().as_ref()],
+    #[account(mut, seeds = [b"challenge".as_ref(), forum.key().as_ref(), challenge_seed.key().as_ref()],
               bump = bump_challenge, has_one = forum, has_one = challenge_seed)]
     pub challenge: Box<Account<'info, Challenge>>,
 
@@ -30,6 +31,10 @@ pub struct DeleteSubmission<'info> {
               bump = bump_submission, has_one = challenge, has_one = user_profile)]
     pub submission: Box<Account<'info, Submission>>,
 
+    /// CHECK: The submission fee escrow PDA account (empty once the submission has been evaluated)
+    #[account(mut, seeds = [b"submission_fee_escrow".as_ref(), submission.key().as_ref()], bump = bump_submission_fee_escrow)]
+    pub submission_fee_escrow: AccountInfo<'info>,
+
     /// CHECK:
     #[account(mut)]
     pub receiver: AccountInfo<'info>,
@@ -48,13 +53,31 @@ pub fn delete_submission(ctx: Context<DeleteSubmission>) -> Result<()> {
         return Err(error!(ErrorCode::AccountCannotBeEdited));
     }
 
+    // Ensure no votes, scores, peer reviews or attempt history are left behind
+    if ctx.accounts.submission.has_evaluation_state() {
+        return Err(error!(ErrorCode::SubmissionCannotBeDeleted));
+    }
+
     // Set the receiver of the lamports to be reclaimed from the rent of the accounts to be closed
     let receiver = &mut ctx.accounts.receiver;
 
+    // Refund the submission fee in full if it is still held in escrow
+    if !ctx.accounts.submission_fee_escrow.data_is_empty() {
+        Account::<SubmissionFeeEscrow>::try_from(&ctx.accounts.submission_fee_escrow)?;
+
+        let submission_fee_escrow_account_info = &mut ctx.accounts.submission_fee_escrow.to_account_info();
+        let profile_owner_account_info = &mut ctx.accounts.profile_owner.to_account_info();
+        close_account(submission_fee_escrow_account_info, profile_owner_account_info)?;
+    }
+
     // Close the submission state account
     let submission_account_info = &mut (*ctx.accounts.submission).to_account_info();
     close_account(submission_account_info, receiver)?;
 
+    // Decrement submission count in challenge's state
+    let challenge = &mut ctx.accounts.challenge;
+    challenge.submission_count.try_sub_assign(1)?;
+
     // Decrement submission count in forum's state
     let forum = &mut ctx.accounts.forum;
     forum.forum_counts.forum_submission_count.try_sub_assign(1)?;
@@ -1,10 +1,10 @@
 use anchor_lang::prelude::*;
 
-use crate::state::{Forum, UserProfile};
+use crate::state::{Forum, UserProfile, WalletRotation};
 use prog_common::{close_account, TrySub, errors::ErrorCode};
 
 #[derive(Accounts)]
-#[instruction(bump_user_profile: u8)]
+#[instruction(bump_user_profile: u8, bump_wallet_rotation: u8)]
 pub struct DeleteUserProfile<'info> {
 
     // Forum
@@ -14,10 +14,14 @@ pub struct DeleteUserProfile<'info> {
     pub profile_owner: Signer<'info>,
 
     // The user profile
-    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), profile_owner.key().as_ref()],
+    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), user_profile.profile_seed.as_ref()],
               bump = bump_user_profile, has_one = forum, has_one = profile_owner)]
     pub user_profile: Box<Account<'info, UserProfile>>,
 
+    /// CHECK: The wallet rotation PDA account (non-empty if the profile owner took over the user profile through a wallet rotation)
+    #[account(mut, seeds = [b"wallet_rotation".as_ref(), forum.key().as_ref(), profile_owner.key().as_ref()], bump = bump_wallet_rotation)]
+    pub wallet_rotation: AccountInfo<'info>,
+
     /// CHECK:
     #[account(mut)]
     pub receiver: AccountInfo<'info>,
@@ -31,9 +35,29 @@ pub fn delete_user_profile(ctx: Context<DeleteUserProfile>) -> Result<()> {
         return Err(error!(ErrorCode::AboutMePDANotClosed));
     }
 
+    if ctx.accounts.user_profile.has_handle {
+        return Err(error!(ErrorCode::HandleNotReleased));
+    }
+
+    // Ensure the follow accounts counted against other profiles have been closed
+    if ctx.accounts.user_profile.following_count > 0 {
+        return Err(error!(ErrorCode::ProfileStillFollowing));
+    }
+
     // Set the receiver of the lamports to be reclaimed from the rent of the accounts to be closed
     let receiver = &mut ctx.accounts.receiver;
 
+    // Close the wallet rotation state account (if it exists) so the wallet can create a new user profile
+    if !ctx.accounts.wallet_rotation.data_is_empty() {
+        let wallet_rotation = Account::<WalletRotation>::try_from(&ctx.accounts.wallet_rotation)?;
+
+        if wallet_rotation.user_profile != ctx.accounts.user_profile.key() {
+            return Err(error!(ErrorCode::InvalidWalletRotation));
+        }
+
+        close_account(&mut ctx.accounts.wallet_rotation, receiver)?;
+    }
+
     // Close the user profile state account
     let user_profile_account_info = &mut (*ctx.accounts.user_profile).to_account_info();
     close_account(user_profile_account_info, receiver)?;
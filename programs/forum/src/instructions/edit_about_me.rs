@@ -1,7 +1,7 @@
 use anchor_lang::prelude::*;
 
 use crate::state::{AboutMe, Forum, UserProfile};
-use prog_common::{now_ts};
+use prog_common::{now_ts, errors::ErrorCode};
 
 #[derive(Accounts)]
 #[instruction(bump_user_profile: u8, bump_about_me: u8)]
@@ -13,7 +13,7 @@ pub struct EditAboutMe<'info> {
     pub profile_owner: Signer<'info>,
 
     // The user profile
-    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), profile_owner.key().as_ref()],
+    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), user_profile.profile_seed.as_ref()],
               bump = bump_user_profile, has_one = forum, has_one = profile_owner)]
     pub user_profile: Box<Account<'info, UserProfile>>,
 
@@ -32,6 +32,11 @@ pub fn edit_about_me(ctx: Context<EditAboutMe>) -> Result<()> {
 
     let now_ts: u64 = now_ts()?;
 
+    // Ensure the user profile has not been suspended from the forum
+    if ctx.accounts.user_profile.is_suspended {
+        return Err(error!(ErrorCode::ProfileSuspended));
+    }
+
     // Update about me account's most recent update timestamp and overwrite with the new content data hash
     let about_me = &mut ctx.accounts.about_me;
     about_me.most_recent_update_ts = now_ts;
@@ -1,22 +1,28 @@
 use anchor_lang::prelude::*;
 
-use crate::state::{Answer, Forum, Question, UserProfile};
-use prog_common::{now_ts, errors::ErrorCode};
+use crate::state::{Answer, Forum, Question, Revision, SessionScope, UserProfile};
+use prog_common::{now_ts, TryAdd, errors::ErrorCode};
 
 #[derive(Accounts)]
-#[instruction(bump_user_profile: u8, bump_answer: u8)]
+#[instruction(bump_user_profile: u8, bump_answer: u8, bump_session_key: u8)]
 pub struct EditAnswer<'info> {
 
     // Forum
     pub forum: Box<Account<'info, Forum>>,
 
-    pub profile_owner: Signer<'info>,
+    // The profile owner or a delegate holding a session key for the user profile
+    #[account(mut)]
+    pub authority: Signer<'info>,
 
     // The user profile
-    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), profile_owner.key().as_ref()],
-              bump = bump_user_profile, has_one = forum, has_one = profile_owner)]
+    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), user_profile.profile_seed.as_ref()],
+              bump = bump_user_profile, has_one = forum)]
     pub user_profile: Box<Account<'info, UserProfile>>,
 
+    /// CHECK: Session key PDA account of the signer (empty when the profile owner signs)
+    #[account(seeds = [b"session_key".as_ref(), user_profile.key().as_ref(), authority.key().as_ref()], bump = bump_session_key)]
+    pub session_key: AccountInfo<'info>,
+
     // Question PDA account
     #[account(mut, has_one = forum)]
     pub question: Box<Account<'info, Question>>,
@@ -33,6 +39,11 @@ pub struct EditAnswer<'info> {
     // The new content data hash of the answer struct
     pub new_content_data_hash: AccountInfo<'info>,
 
+    // Revision PDA account recording the answer's previous content
+    #[account(init, seeds = [b"revision".as_ref(), answer.key().as_ref(), answer.revision_count.to_le_bytes().as_ref()],
+              bump, payer = authority, space = 8 + 32 + 32 + 8 + 8 + 32 + 1 + 4 + 4)]
+    pub revision: Box<Account<'info, Revision>>,
+
     pub system_program: Program<'info, System>,
 }
 
@@ -40,6 +51,14 @@ pub fn edit_answer(ctx: Context<EditAnswer>) -> Result<()> {
 
     let now_ts: u64 = now_ts()?;
 
+    // Ensure the user profile has not been suspended from the forum
+    if ctx.accounts.user_profile.is_suspended {
+        return Err(error!(ErrorCode::ProfileSuspended));
+    }
+
+    // Ensure the signer is the profile owner or a delegate with a valid session key
+    SessionScope::CommentAndAnswer.verify_authority(&ctx.accounts.user_profile.profile_owner, &ctx.accounts.authority.key(), &ctx.accounts.session_key, now_ts)?;
+
     let is_accepted_answer = ctx.accounts.answer.accepted_answer;
 
     // Ensure answer is not an accepted answer
@@ -47,8 +66,20 @@ pub fn edit_answer(ctx: Context<EditAnswer>) -> Result<()> {
         return Err(error!(ErrorCode::AccountCannotBeEdited));
     }
 
+    // Record the answer's previous content in the revision account
+    let revision = &mut ctx.accounts.revision;
+    revision.content = ctx.accounts.answer.key();
+    revision.editor_profile = ctx.accounts.user_profile.key();
+    revision.revision_number = ctx.accounts.answer.revision_count;
+    revision.revision_ts = now_ts;
+    revision.previous_content_data_hash = ctx.accounts.answer.content_data_hash;
+    revision.moderator_edit = false;
+    revision.previous_title = String::new();
+    revision.previous_content_data_url = String::new();
+
     // Update answer account's most recent engagement timestamp and overwrite with the new content data hash
     let answer = &mut ctx.accounts.answer;
+    answer.revision_count.try_add_assign(1)?;
     answer.most_recent_engagement_ts = now_ts;
     answer.content_data_hash = ctx.accounts.new_content_data_hash.key();
 
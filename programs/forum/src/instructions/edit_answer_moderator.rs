@@ -1,7 +1,7 @@
 use anchor_lang::prelude::*;
 
-use crate::state::{Answer, Forum, Question, UserProfile};
-use prog_common::{now_ts, errors::ErrorCode};
+use crate::state::{Answer, Category, Forum, Question, Revision, UserProfile};
+use prog_common::{now_ts, TryAdd, errors::ErrorCode};
 
 #[derive(Accounts)]
 #[instruction(bump_moderator_profile: u8, bump_user_profile: u8, bump_answer: u8)]
@@ -10,10 +10,11 @@ pub struct EditAnswerModerator<'info> {
     // Forum
     pub forum: Box<Account<'info, Forum>>,
 
+    #[account(mut)]
     pub moderator: Signer<'info>,
 
     // The moderator profile
-    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), moderator.key().as_ref()],
+    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), moderator_profile.profile_seed.as_ref()],
               bump = bump_moderator_profile, has_one = forum, constraint = moderator_profile.profile_owner == moderator.key())]
     pub moderator_profile: Box<Account<'info, UserProfile>>,
 
@@ -21,7 +22,7 @@ pub struct EditAnswerModerator<'info> {
     pub profile_owner: AccountInfo<'info>,
 
     // The user profile
-    #[account(seeds = [b"user_profile".as_ref(), forum.key().as_ref(), profile_owner.key().as_ref()],
+    #[account(seeds = [b"user_profile".as_ref(), forum.key().as_ref(), user_profile.profile_seed.as_ref()],
               bump = bump_user_profile, has_one = forum, has_one = profile_owner)]
     pub user_profile: Box<Account<'info, UserProfile>>,
 
@@ -29,6 +30,10 @@ pub struct EditAnswerModerator<'info> {
     #[account(mut, has_one = forum)]
     pub question: Box<Account<'info, Question>>,
 
+    // Category of the question, whose moderators may also moderate it
+    #[account(has_one = forum, constraint = category.key() == question.category)]
+    pub category: Box<Account<'info, Category>>,
+
     // Answer PDA account and seed
     #[account(mut, seeds = [b"answer".as_ref(), forum.key().as_ref(), user_profile.key().as_ref(), answer_seed.key().as_ref()],
               bump = bump_answer, has_one = question, has_one = user_profile, has_one = answer_seed)]
@@ -41,6 +46,11 @@ pub struct EditAnswerModerator<'info> {
     // The new content data hash of the answer struct
     pub new_content_data_hash: AccountInfo<'info>,
 
+    // Revision PDA account recording the answer's previous content
+    #[account(init, seeds = [b"revision".as_ref(), answer.key().as_ref(), answer.revision_count.to_le_bytes().as_ref()],
+              bump, payer = moderator, space = 8 + 32 + 32 + 8 + 8 + 32 + 1 + 4 + 4)]
+    pub revision: Box<Account<'info, Revision>>,
+
     pub system_program: Program<'info, System>,
 }
 
@@ -48,12 +58,24 @@ pub fn edit_answer_moderator(ctx: Context<EditAnswerModerator>) -> Result<()> {
 
     let now_ts: u64 = now_ts()?;
 
-    if !ctx.accounts.moderator_profile.is_moderator {
+    if !ctx.accounts.moderator_profile.is_moderator && !ctx.accounts.category.is_category_moderator(&ctx.accounts.moderator_profile.key()) {
         return Err(error!(ErrorCode::ProfileIsNotModerator));
     }
 
+    // Record the answer's previous content in the revision account
+    let revision = &mut ctx.accounts.revision;
+    revision.content = ctx.accounts.answer.key();
+    revision.editor_profile = ctx.accounts.moderator_profile.key();
+    revision.revision_number = ctx.accounts.answer.revision_count;
+    revision.revision_ts = now_ts;
+    revision.previous_content_data_hash = ctx.accounts.answer.content_data_hash;
+    revision.moderator_edit = true;
+    revision.previous_title = String::new();
+    revision.previous_content_data_url = String::new();
+
     // Update answer account's most recent engagement timestamp and overwrite with the new content data hash
     let answer = &mut ctx.accounts.answer;
+    answer.revision_count.try_add_assign(1)?;
     answer.most_recent_engagement_ts = now_ts;
     answer.content_data_hash = ctx.accounts.new_content_data_hash.key();
 
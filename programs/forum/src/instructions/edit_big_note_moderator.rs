@@ -3,8 +3,8 @@ use anchor_lang::prelude::*;
 use anchor_lang::solana_program::program::{invoke};
 use anchor_lang::solana_program::system_instruction;
 
-use crate::state::{BigNote, BigNoteVerificationState, Forum, Tags, UserProfile};
-use prog_common::{now_ts, TrySub, errors::ErrorCode};
+use crate::state::{BigNote, Category, Forum, Revision, Tags, UserProfile};
+use prog_common::{now_ts, TryAdd, TrySub, errors::ErrorCode};
 
 #[derive(Accounts)]
 #[instruction(bump_moderator_profile: u8, bump_user_profile: u8, bump_big_note: u8)]
@@ -17,7 +17,7 @@ pub struct EditBigNoteModerator<'info> {
     pub moderator: Signer<'info>,
 
     // The moderator profile
-    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), moderator.key().as_ref()],
+    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), moderator_profile.profile_seed.as_ref()],
               bump = bump_moderator_profile, has_one = forum, constraint = moderator_profile.profile_owner == moderator.key())]
     pub moderator_profile: Box<Account<'info, UserProfile>>,
 
@@ -25,7 +25,7 @@ pub struct EditBigNoteModerator<'info> {
     pub profile_owner: AccountInfo<'info>,
 
     // The user profile
-    #[account(seeds = [b"user_profile".as_ref(), forum.key().as_ref(), profile_owner.key().as_ref()],
+    #[account(seeds = [b"user_profile".as_ref(), forum.key().as_ref(), user_profile.profile_seed.as_ref()],
               bump = bump_user_profile, has_one = forum, has_one = profile_owner)]
     pub user_profile: Box<Account<'info, UserProfile>>,
 
@@ -37,10 +37,19 @@ pub struct EditBigNoteModerator<'info> {
     /// CHECK: The seed address used for initialization of the big note PDA
     pub big_note_seed: AccountInfo<'info>,
 
+    // Category of the big note (its category moderators may moderate it)
+    #[account(has_one = forum, constraint = category.key() == big_note.category)]
+    pub category: Box<Account<'info, Category>>,
+
     /// CHECK:
     // The new content data hash of the big note struct
     pub new_content_data_hash: AccountInfo<'info>,
 
+    // Revision PDA account recording the big note's previous content
+    #[account(init, seeds = [b"revision".as_ref(), big_note.key().as_ref(), big_note.revision_count.to_le_bytes().as_ref()],
+              bump, payer = moderator, space = 8 + 32 + 32 + 8 + 8 + 32 + 1 + 4 + big_note.title.len() + 4 + big_note.content_data_url.len())]
+    pub revision: Box<Account<'info, Revision>>,
+
     pub system_program: Program<'info, System>,
 }
 
@@ -62,7 +71,8 @@ pub fn edit_big_note_moderator(ctx: Context<EditBigNoteModerator>, new_tags: Vec
 
     let now_ts: u64 = now_ts()?;
 
-    if !ctx.accounts.moderator_profile.is_moderator {
+    // Ensure the moderator is either a forum moderator or a moderator of the content's category
+    if !ctx.accounts.moderator_profile.is_moderator && !ctx.accounts.category.is_category_moderator(&ctx.accounts.moderator_profile.key()) {
         return Err(error!(ErrorCode::ProfileIsNotModerator));
     }
 
@@ -94,7 +104,9 @@ pub fn edit_big_note_moderator(ctx: Context<EditBigNoteModerator>, new_tags: Vec
     let bounty_contributions = &ctx.accounts.big_note.bounty_contributions;
     let big_note_type = &ctx.accounts.big_note.big_note_type;
     let old_verification_state = ctx.accounts.big_note.verification_state;
-    let new_verification_state = BigNoteVerificationState::Unverified;
+
+    // Moderator edits preserve the big note's verification state
+    let new_verification_state = old_verification_state;
 
     let mut contribution_buffer: Vec<u8> = Vec::new();
     bounty_contributions.serialize(&mut contribution_buffer).unwrap();
@@ -133,7 +145,7 @@ pub fn edit_big_note_moderator(ctx: Context<EditBigNoteModerator>, new_tags: Vec
     let content_data_url_buffer_slice_length: usize = content_data_url_buffer_as_slice.len();
 
     // Calculate total space required for the addition of the new data
-    let new_data_bytes_amount: usize = 8 + 120 + contribution_buffer_slice_length + type_buffer_slice_length + verification_buffer_slice_length + tag_buffer_slice_length + title_buffer_slice_length + content_data_url_buffer_slice_length + 49;
+    let new_data_bytes_amount: usize = 8 + 120 + contribution_buffer_slice_length + type_buffer_slice_length + verification_buffer_slice_length + tag_buffer_slice_length + title_buffer_slice_length + content_data_url_buffer_slice_length + 257;
     let old_data_bytes_amount: usize = ctx.accounts.big_note.to_account_info().data_len();
 
     if new_data_bytes_amount > old_data_bytes_amount {
@@ -148,22 +160,26 @@ pub fn edit_big_note_moderator(ctx: Context<EditBigNoteModerator>, new_tags: Vec
         ctx.accounts.big_note.to_account_info().realloc(new_data_bytes_amount, false)?;
     }
 
-    if old_verification_state == BigNoteVerificationState::Verified {
-        let big_note_verification_rep: u64 = ctx.accounts.big_note.big_note_verification_rep;
-        let user_profile = &mut ctx.accounts.user_profile;
-        user_profile.big_notes_verified.try_sub_assign(1)?;
-        user_profile.reputation_score.try_sub_assign(big_note_verification_rep)?;
-    }
+    // Record the big note's previous content in the revision account
+    let revision = &mut ctx.accounts.revision;
+    revision.content = ctx.accounts.big_note.key();
+    revision.editor_profile = ctx.accounts.moderator_profile.key();
+    revision.revision_number = ctx.accounts.big_note.revision_count;
+    revision.revision_ts = now_ts;
+    revision.previous_content_data_hash = ctx.accounts.big_note.content_data_hash;
+    revision.moderator_edit = true;
+    revision.previous_title = ctx.accounts.big_note.title.clone();
+    revision.previous_content_data_url = ctx.accounts.big_note.content_data_url.clone();
 
     // Update big note account's most recent engagement timestamp and overwrite with the new content and data hash
     let big_note = &mut ctx.accounts.big_note;
+    big_note.revision_count.try_add_assign(1)?;
     big_note.most_recent_engagement_ts = now_ts;
     big_note.verification_state = new_verification_state;
     big_note.tags = new_tags;
     big_note.title = new_title;
     big_note.content_data_url = new_content_data_url;
     big_note.content_data_hash = ctx.accounts.new_content_data_hash.key();
-    big_note.big_note_verification_rep = 0;
 
     // Update moderator profile's most recent engagement
     let moderator_profile = &mut ctx.accounts.moderator_profile;
@@ -3,29 +3,34 @@ use anchor_lang::prelude::*;
 use anchor_lang::solana_program::program::{invoke};
 use anchor_lang::solana_program::system_instruction;
 
-use crate::state::{BigNote, BigNoteType, BigNoteVerificationState, Forum, Tags, UserProfile};
-use prog_common::{now_ts, TrySub, errors::ErrorCode};
+use crate::state::{BigNote, BigNoteType, BigNoteVerificationRecord, BigNoteVerificationState, Forum, Revision, SessionScope, Tags, UserProfile};
+use prog_common::{now_ts, TryAdd, TrySub, errors::ErrorCode};
 
 #[derive(Accounts)]
-#[instruction(bump_editor_profile: u8, bump_user_profile: u8, bump_big_note: u8)]
+#[instruction(bump_editor_profile: u8, bump_user_profile: u8, bump_big_note: u8, bump_session_key: u8, bump_verification_record: u8)]
 pub struct EditBigNoteOpenContribution<'info> {
 
     // Forum
     pub forum: Box<Account<'info, Forum>>,
 
+    // The editor or a delegate holding a session key for the editor profile
     #[account(mut)]
     pub editor: Signer<'info>,
 
     // The editor profile
-    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), editor.key().as_ref()],
-              bump = bump_editor_profile, has_one = forum, constraint = editor_profile.profile_owner == editor.key())]
+    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), editor_profile.profile_seed.as_ref()],
+              bump = bump_editor_profile, has_one = forum)]
     pub editor_profile: Box<Account<'info, UserProfile>>,
 
+    /// CHECK: Session key PDA account of the signer (empty when the editor signs)
+    #[account(seeds = [b"session_key".as_ref(), editor_profile.key().as_ref(), editor.key().as_ref()], bump = bump_session_key)]
+    pub session_key: AccountInfo<'info>,
+
     /// CHECK: Used for seed verification of user profile pda account
     pub profile_owner: AccountInfo<'info>,
 
     // The big note creator's user profile
-    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), profile_owner.key().as_ref()],
+    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), user_profile.profile_seed.as_ref()],
               bump = bump_user_profile, has_one = forum, has_one = profile_owner)]
     pub user_profile: Box<Account<'info, UserProfile>>,
 
@@ -37,10 +42,19 @@ pub struct EditBigNoteOpenContribution<'info> {
     /// CHECK: The seed address used for initialization of the big note PDA
     pub big_note_seed: AccountInfo<'info>,
 
+    /// CHECK: Big Note Verification Record PDA account (empty when the big note has never applied for verification)
+    #[account(mut, seeds = [b"verification_record".as_ref(), big_note.key().as_ref()], bump = bump_verification_record)]
+    pub verification_record: AccountInfo<'info>,
+
     /// CHECK:
     // The new content data hash of the big note struct
     pub new_content_data_hash: AccountInfo<'info>,
 
+    // Revision PDA account recording the big note's previous content
+    #[account(init, seeds = [b"revision".as_ref(), big_note.key().as_ref(), big_note.revision_count.to_le_bytes().as_ref()],
+              bump, payer = editor, space = 8 + 32 + 32 + 8 + 8 + 32 + 1 + 4 + big_note.title.len() + 4 + big_note.content_data_url.len())]
+    pub revision: Box<Account<'info, Revision>>,
+
     pub system_program: Program<'info, System>,
 }
 
@@ -62,6 +76,14 @@ pub fn edit_big_note_open_contribution(ctx: Context<EditBigNoteOpenContribution>
 
     let now_ts: u64 = now_ts()?;
 
+    // Ensure the editor profile has not been suspended from the forum
+    if ctx.accounts.editor_profile.is_suspended {
+        return Err(error!(ErrorCode::ProfileSuspended));
+    }
+
+    // Ensure the signer is the editor or a delegate with a valid session key
+    SessionScope::PostAndEdit.verify_authority(&ctx.accounts.editor_profile.profile_owner, &ctx.accounts.editor.key(), &ctx.accounts.session_key, now_ts)?;
+
     if !(ctx.accounts.big_note.big_note_type == BigNoteType::OpenContribution) {
         return Err(error!(ErrorCode::NotOpenContribution));
     }
@@ -94,7 +116,12 @@ pub fn edit_big_note_open_contribution(ctx: Context<EditBigNoteOpenContribution>
     let bounty_contributions = &ctx.accounts.big_note.bounty_contributions;
     let big_note_type = &ctx.accounts.big_note.big_note_type;
     let old_verification_state = ctx.accounts.big_note.verification_state;
-    let new_verification_state = BigNoteVerificationState::Unverified;
+
+    // Edits by anyone other than a moderator require a verified big note to be reverified
+    let new_verification_state = match old_verification_state {
+        BigNoteVerificationState::Verified => BigNoteVerificationState::NeedsReverification,
+        _ => old_verification_state,
+    };
 
     let mut contribution_buffer: Vec<u8> = Vec::new();
     bounty_contributions.serialize(&mut contribution_buffer).unwrap();
@@ -133,7 +160,7 @@ pub fn edit_big_note_open_contribution(ctx: Context<EditBigNoteOpenContribution>
     let content_data_url_buffer_slice_length: usize = content_data_url_buffer_as_slice.len();
 
     // Calculate total space required for the addition of the new data
-    let new_data_bytes_amount: usize = 8 + 120 + contribution_buffer_slice_length + type_buffer_slice_length + verification_buffer_slice_length + tag_buffer_slice_length + title_buffer_slice_length + content_data_url_buffer_slice_length + 49;
+    let new_data_bytes_amount: usize = 8 + 120 + contribution_buffer_slice_length + type_buffer_slice_length + verification_buffer_slice_length + tag_buffer_slice_length + title_buffer_slice_length + content_data_url_buffer_slice_length + 257;
     let old_data_bytes_amount: usize = ctx.accounts.big_note.to_account_info().data_len();
 
     if new_data_bytes_amount > old_data_bytes_amount {
@@ -155,15 +182,45 @@ pub fn edit_big_note_open_contribution(ctx: Context<EditBigNoteOpenContribution>
         user_profile.reputation_score.try_sub_assign(big_note_verification_rep)?;
     }
 
+    // An edit during an open application starts a new verification round, so earlier attestations no longer count
+    if old_verification_state == BigNoteVerificationState::AppliedForVerification {
+        let mut verification_record = Account::<BigNoteVerificationRecord>::try_from(&ctx.accounts.verification_record)?;
+        verification_record.verification_round.try_add_assign(1)?;
+        verification_record.attestation_count = 0;
+        verification_record.exit(&crate::ID)?;
+    }
+
+    // An edit to a verified big note opens the slashing window for the attestations which verified it
+    if old_verification_state == BigNoteVerificationState::Verified {
+        let mut verification_record = Account::<BigNoteVerificationRecord>::try_from(&ctx.accounts.verification_record)?;
+        verification_record.reverification_ts = now_ts;
+        verification_record.exit(&crate::ID)?;
+    }
+
+    // Record the big note's previous content in the revision account
+    let revision = &mut ctx.accounts.revision;
+    revision.content = ctx.accounts.big_note.key();
+    revision.editor_profile = ctx.accounts.editor_profile.key();
+    revision.revision_number = ctx.accounts.big_note.revision_count;
+    revision.revision_ts = now_ts;
+    revision.previous_content_data_hash = ctx.accounts.big_note.content_data_hash;
+    revision.moderator_edit = false;
+    revision.previous_title = ctx.accounts.big_note.title.clone();
+    revision.previous_content_data_url = ctx.accounts.big_note.content_data_url.clone();
+
     // Update big note account's most recent engagement timestamp and overwrite with the new content and data hash
     let big_note = &mut ctx.accounts.big_note;
+    big_note.revision_count.try_add_assign(1)?;
     big_note.most_recent_engagement_ts = now_ts;
     big_note.verification_state = new_verification_state;
     big_note.tags = new_tags;
     big_note.title = new_title;
     big_note.content_data_url = new_content_data_url;
     big_note.content_data_hash = ctx.accounts.new_content_data_hash.key();
-    big_note.big_note_verification_rep = 0;
+
+    if old_verification_state == BigNoteVerificationState::Verified {
+        big_note.big_note_verification_rep = 0;
+    }
 
     // Update editor profile's most recent engagement
     let editor_profile = &mut ctx.accounts.editor_profile;
@@ -3,8 +3,8 @@ use anchor_lang::prelude::*;
 use anchor_lang::solana_program::program::{invoke};
 use anchor_lang::solana_program::system_instruction;
 
-use crate::state::{Challenge, Forum, Tags, UserProfile};
-use prog_common::{now_ts, TrySub, errors::ErrorCode};
+use crate::state::{Category, Challenge, ChallengeState, Forum, Tags, UserProfile};
+use prog_common::{now_ts, TryAdd, TrySub, errors::ErrorCode};
 
 #[derive(Accounts)]
 #[instruction(bump_moderator_profile: u8, bump_challenge: u8)]
@@ -17,7 +17,7 @@ pub struct EditChallenge<'info> {
     pub moderator: Signer<'info>,
 
     // The moderator profile
-    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), moderator.key().as_ref()],
+    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), moderator_profile.profile_seed.as_ref()],
               bump = bump_moderator_profile, has_one = forum, constraint = moderator_profile.profile_owner == moderator.key())]
     pub moderator_profile: Box<Account<'info, UserProfile>>,
 
@@ -26,6 +26,10 @@ pub struct EditChallenge<'info> {
               bump = bump_challenge, has_one = forum, has_one = challenge_seed)]
     pub challenge: Box<Account<'info, Challenge>>,
 
+    // Category of the challenge, whose moderators may also moderate it
+    #[account(has_one = forum, constraint = category.key() == challenge.category)]
+    pub category: Box<Account<'info, Category>>,
+
     /// CHECK: The seed address used for initialization of the challenge PDA
     pub challenge_seed: AccountInfo<'info>,
 
@@ -54,7 +58,7 @@ pub fn edit_challenge(ctx: Context<EditChallenge>, new_tags: Vec<Tags>, new_titl
 
     let now_ts: u64 = now_ts()?;
 
-    if !ctx.accounts.moderator_profile.is_moderator {
+    if !ctx.accounts.moderator_profile.is_moderator && !ctx.accounts.category.is_category_moderator(&ctx.accounts.moderator_profile.key()) {
         return Err(error!(ErrorCode::ProfileIsNotModerator));
     }
 
@@ -82,6 +86,12 @@ pub fn edit_challenge(ctx: Context<EditChallenge>, new_tags: Vec<Tags>, new_titl
         return Err(error!(ErrorCode::InvalidUrlStringInput));
     }
 
+    // Ensure challenge has not been closed
+    let challenge_state = ctx.accounts.challenge.challenge_state;
+    if (challenge_state == ChallengeState::Closed) || (challenge_state == ChallengeState::Archived) {
+        return Err(error!(ErrorCode::ChallengeAlreadyClosed));
+    }
+
     // Ensure new challenge expires timestamp is greater than now timestamp
     if !(new_challenge_expires_ts > now_ts) {
         return Err(error!(ErrorCode::InvalidExpiryTs));
@@ -106,8 +116,13 @@ pub fn edit_challenge(ctx: Context<EditChallenge>, new_tags: Vec<Tags>, new_titl
     let content_data_url_buffer_as_slice: &[u8] = content_data_url_buffer.as_slice();
     let content_data_url_buffer_slice_length: usize = content_data_url_buffer_as_slice.len();
 
+    let mut rubric_buffer: Vec<u8> = Vec::new();
+    ctx.accounts.challenge.rubric.serialize(&mut rubric_buffer).unwrap();
+
+    let rubric_buffer_slice_length: usize = rubric_buffer.as_slice().len();
+
     // Calculate total space required for the addition of the new data
-    let new_data_bytes_amount: usize = 88 + tag_buffer_slice_length + title_buffer_slice_length + content_data_url_buffer_slice_length + 40;
+    let new_data_bytes_amount: usize = 88 + tag_buffer_slice_length + title_buffer_slice_length + content_data_url_buffer_slice_length + 40 + rubric_buffer_slice_length + 73;
     let old_data_bytes_amount: usize = ctx.accounts.challenge.to_account_info().data_len();
 
     if new_data_bytes_amount > old_data_bytes_amount {
@@ -125,6 +140,8 @@ pub fn edit_challenge(ctx: Context<EditChallenge>, new_tags: Vec<Tags>, new_titl
     // Update challenge account's state
     let challenge = &mut ctx.accounts.challenge;
     challenge.challenge_expires_ts = new_challenge_expires_ts;
+    challenge.judging_ends_ts = new_challenge_expires_ts.try_add(ctx.accounts.forum.forum_constants.challenge_judging_period)?;
+    challenge.challenge_state = ChallengeState::Open;
     challenge.tags = new_tags;
     challenge.title = new_title;
     challenge.content_data_url = new_content_data_url;
@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{Comment, Forum, SessionScope, UserProfile};
+use prog_common::{now_ts, errors::ErrorCode};
+
+#[derive(Accounts)]
+#[instruction(bump_user_profile: u8, bump_comment: u8, bump_session_key: u8)]
+pub struct EditComment<'info> {
+
+    // Forum
+    pub forum: Box<Account<'info, Forum>>,
+
+    // The profile owner or a delegate holding a session key for the user profile
+    pub authority: Signer<'info>,
+
+    // The user profile
+    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), user_profile.profile_seed.as_ref()],
+              bump = bump_user_profile, has_one = forum)]
+    pub user_profile: Box<Account<'info, UserProfile>>,
+
+    /// CHECK: Session key PDA account of the signer (empty when the profile owner signs)
+    #[account(seeds = [b"session_key".as_ref(), user_profile.key().as_ref(), authority.key().as_ref()], bump = bump_session_key)]
+    pub session_key: AccountInfo<'info>,
+
+    /// CHECK: Account for which comment belongs, verified against the comment's state
+    #[account(mut)]
+    pub commented_on: AccountInfo<'info>,
+
+    // Comment PDA account and seed
+    #[account(mut, seeds = [b"comment".as_ref(), forum.key().as_ref(), user_profile.key().as_ref(), comment_seed.key().as_ref()],
+              bump = bump_comment, has_one = user_profile, has_one = comment_seed, has_one = commented_on)]
+    pub comment: Box<Account<'info, Comment>>,
+
+    /// CHECK: The seed address used for initialization of the comment PDA
+    pub comment_seed: AccountInfo<'info>,
+
+    /// CHECK:
+    // The new content data hash of the comment struct
+    pub new_content_data_hash: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn edit_comment(ctx: Context<EditComment>) -> Result<()> {
+
+    let now_ts: u64 = now_ts()?;
+
+    // Ensure the user profile has not been suspended from the forum
+    if ctx.accounts.user_profile.is_suspended {
+        return Err(error!(ErrorCode::ProfileSuspended));
+    }
+
+    // Ensure the signer is the profile owner or a delegate with a valid session key
+    SessionScope::CommentAndAnswer.verify_authority(&ctx.accounts.user_profile.profile_owner, &ctx.accounts.authority.key(), &ctx.accounts.session_key, now_ts)?;
+
+    // Update comment account's most recent engagement timestamp and overwrite with the new content data hash
+    let comment = &mut ctx.accounts.comment;
+    comment.most_recent_engagement_ts = now_ts;
+    comment.content_data_hash = ctx.accounts.new_content_data_hash.key();
+
+    // Update commented on account's most recent engagement timestamp
+    let comment_target = ctx.accounts.comment.comment_target;
+    comment_target.record_engagement(&ctx.accounts.commented_on, now_ts)?;
+
+    // Update user profile account's most recent engagement timestamp
+    let user_profile = &mut ctx.accounts.user_profile;
+    user_profile.most_recent_engagement_ts = now_ts;
+
+    msg!("Comment PDA account with address {} has been edited", ctx.accounts.comment.key());
+    Ok(())
+}
@@ -13,7 +13,7 @@ pub struct EditProposedContribution<'info> {
     pub profile_owner: Signer<'info>,
 
     // The user profile
-    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), profile_owner.key().as_ref()],
+    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), user_profile.profile_seed.as_ref()],
               bump = bump_user_profile, has_one = forum, has_one = profile_owner)]
     pub user_profile: Box<Account<'info, UserProfile>>,
 
@@ -40,6 +40,11 @@ pub fn edit_proposed_contribution(ctx: Context<EditProposedContribution>) -> Res
 
     let now_ts: u64 = now_ts()?;
 
+    // Ensure the user profile has not been suspended from the forum
+    if ctx.accounts.user_profile.is_suspended {
+        return Err(error!(ErrorCode::ProfileSuspended));
+    }
+
     let proposed_contribution_state: ProposedContributionState = ctx.accounts.proposed_contribution.proposed_contribution_state;
 
     // Ensure proposed contribution is not an accepted proposal
@@ -3,27 +3,32 @@ use anchor_lang::prelude::*;
 use anchor_lang::solana_program::program::{invoke};
 use anchor_lang::solana_program::system_instruction;
 
-use crate::state::{Forum, Question, Tags, UserProfile};
-use prog_common::{now_ts, TrySub, errors::ErrorCode};
+use crate::state::{Forum, Question, Revision, SessionScope, Tags, UserProfile};
+use prog_common::{now_ts, TryAdd, TrySub, errors::ErrorCode};
 
 #[derive(Accounts)]
-#[instruction(bump_user_profile: u8, bump_question: u8)]
+#[instruction(bump_user_profile: u8, bump_question: u8, bump_session_key: u8)]
 pub struct EditQuestion<'info> {
 
     // Forum
     pub forum: Box<Account<'info, Forum>>,
 
+    // The profile owner or a delegate holding a session key for the user profile
     #[account(mut)]
-    pub profile_owner: Signer<'info>,
+    pub authority: Signer<'info>,
 
     // The user profile
-    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), profile_owner.key().as_ref()],
-              bump = bump_user_profile, has_one = forum, has_one = profile_owner)]
+    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), user_profile.profile_seed.as_ref()],
+              bump = bump_user_profile, has_one = forum)]
     pub user_profile: Box<Account<'info, UserProfile>>,
 
+    /// CHECK: Session key PDA account of the signer (empty when the profile owner signs)
+    #[account(seeds = [b"session_key".as_ref(), user_profile.key().as_ref(), authority.key().as_ref()], bump = bump_session_key)]
+    pub session_key: AccountInfo<'info>,
+
     // Question pda account and seed
-    #[account(mut, seeds = [b"question".as_ref(), forum.key().as_ref(), user_profile.key().as_ref(), question_seed.key().as_ref()],
-              bump = bump_question, has_one = forum, has_one = user_profile, has_one = question_seed)]
+    #[account(mut, seeds = [b"question".as_ref(), forum.key().as_ref(), question.user_profile.as_ref(), question_seed.key().as_ref()],
+              bump = bump_question, has_one = forum, has_one = question_seed)]
     pub question: Box<Account<'info, Question>>,
 
     /// CHECK: The seed address used for initialization of the question PDA
@@ -33,15 +38,20 @@ pub struct EditQuestion<'info> {
     // The new content data hash of the question struct
     pub new_content_data_hash: AccountInfo<'info>,
 
+    // Revision PDA account recording the question's previous content
+    #[account(init, seeds = [b"revision".as_ref(), question.key().as_ref(), question.revision_count.to_le_bytes().as_ref()],
+              bump, payer = authority, space = 8 + 32 + 32 + 8 + 8 + 32 + 1 + 4 + question.title.len() + 4 + question.content_data_url.len())]
+    pub revision: Box<Account<'info, Revision>>,
+
     pub system_program: Program<'info, System>,
 }
 
 impl<'info> EditQuestion<'info> {
     fn pay_lamports_difference(&self, lamports: u64) -> Result<()> {
         invoke(
-            &system_instruction::transfer(self.profile_owner.key, &self.question.key(), lamports),
+            &system_instruction::transfer(self.authority.key, &self.question.key(), lamports),
             &[
-                self.profile_owner.to_account_info(),
+                self.authority.to_account_info(),
                 self.question.to_account_info(),
                 self.system_program.to_account_info(),
             ],
@@ -54,6 +64,19 @@ pub fn edit_question(ctx: Context<EditQuestion>, new_tags: Vec<Tags>, new_title:
 
     let now_ts: u64 = now_ts()?;
 
+    // Ensure the user profile has not been suspended from the forum
+    if ctx.accounts.user_profile.is_suspended {
+        return Err(error!(ErrorCode::ProfileSuspended));
+    }
+
+    // Ensure the signer is the profile owner or a delegate with a valid session key
+    SessionScope::PostAndEdit.verify_authority(&ctx.accounts.user_profile.profile_owner, &ctx.accounts.authority.key(), &ctx.accounts.session_key, now_ts)?;
+
+    // Ensure the signer's profile is the current owner of the question
+    if ctx.accounts.question.current_owner != ctx.accounts.user_profile.key() {
+        return Err(error!(ErrorCode::NotCurrentOwner));
+    }
+
     // Ensure bounty has not yet been awarded
     let is_bounty_awarded = ctx.accounts.question.bounty_awarded;
     if is_bounty_awarded {
@@ -112,7 +135,7 @@ pub fn edit_question(ctx: Context<EditQuestion>, new_tags: Vec<Tags>, new_title:
     let content_data_url_buffer_slice_length: usize = content_data_url_buffer_as_slice.len();
 
     // Calculate total space required for the addition of the new data
-    let new_data_bytes_amount: usize = 128 + contribution_buffer_slice_length + tag_buffer_slice_length + title_buffer_slice_length + content_data_url_buffer_slice_length + 32 + 8 + 1;
+    let new_data_bytes_amount: usize = 128 + contribution_buffer_slice_length + tag_buffer_slice_length + title_buffer_slice_length + content_data_url_buffer_slice_length + 32 + 8 + 1 + 8 + 32 + 32 + 32 + 32;
     let old_data_bytes_amount: usize = ctx.accounts.question.to_account_info().data_len();
 
     if new_data_bytes_amount > old_data_bytes_amount {
@@ -127,8 +150,20 @@ pub fn edit_question(ctx: Context<EditQuestion>, new_tags: Vec<Tags>, new_title:
         ctx.accounts.question.to_account_info().realloc(new_data_bytes_amount, false)?;
     }
 
+    // Record the question's previous content in the revision account
+    let revision = &mut ctx.accounts.revision;
+    revision.content = ctx.accounts.question.key();
+    revision.editor_profile = ctx.accounts.user_profile.key();
+    revision.revision_number = ctx.accounts.question.revision_count;
+    revision.revision_ts = now_ts;
+    revision.previous_content_data_hash = ctx.accounts.question.content_data_hash;
+    revision.moderator_edit = false;
+    revision.previous_title = ctx.accounts.question.title.clone();
+    revision.previous_content_data_url = ctx.accounts.question.content_data_url.clone();
+
     // Update question account's most recent engagement timestamp and overwrite with the new content and data hash
     let question = &mut ctx.accounts.question;
+    question.revision_count.try_add_assign(1)?;
     question.most_recent_engagement_ts = now_ts;
     question.tags = new_tags;
     question.title = new_title;
@@ -3,8 +3,8 @@ use anchor_lang::prelude::*;
 use anchor_lang::solana_program::program::{invoke};
 use anchor_lang::solana_program::system_instruction;
 
-use crate::state::{Forum, Question, Tags, UserProfile};
-use prog_common::{now_ts, TrySub, errors::ErrorCode};
+use crate::state::{Category, Forum, Question, Revision, Tags, UserProfile};
+use prog_common::{now_ts, TryAdd, TrySub, errors::ErrorCode};
 
 #[derive(Accounts)]
 #[instruction(bump_moderator_profile: u8, bump_user_profile: u8, bump_question: u8)]
@@ -17,7 +17,7 @@ pub struct EditQuestionModerator<'info> {
     pub moderator: Signer<'info>,
 
     // The moderator profile
-    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), moderator.key().as_ref()],
+    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), moderator_profile.profile_seed.as_ref()],
               bump = bump_moderator_profile, has_one = forum, constraint = moderator_profile.profile_owner == moderator.key())]
     pub moderator_profile: Box<Account<'info, UserProfile>>,
 
@@ -25,7 +25,7 @@ pub struct EditQuestionModerator<'info> {
     pub profile_owner: AccountInfo<'info>,
 
     // The user profile
-    #[account(seeds = [b"user_profile".as_ref(), forum.key().as_ref(), profile_owner.key().as_ref()],
+    #[account(seeds = [b"user_profile".as_ref(), forum.key().as_ref(), user_profile.profile_seed.as_ref()],
               bump = bump_user_profile, has_one = forum, has_one = profile_owner)]
     pub user_profile: Box<Account<'info, UserProfile>>,
 
@@ -37,10 +37,19 @@ pub struct EditQuestionModerator<'info> {
     /// CHECK: The seed address used for initialization of the question PDA
     pub question_seed: AccountInfo<'info>,
 
+    // Category of the question (its category moderators may moderate it)
+    #[account(has_one = forum, constraint = category.key() == question.category)]
+    pub category: Box<Account<'info, Category>>,
+
     /// CHECK:
     // The new content data hash of the question struct
     pub new_content_data_hash: AccountInfo<'info>,
 
+    // Revision PDA account recording the question's previous content
+    #[account(init, seeds = [b"revision".as_ref(), question.key().as_ref(), question.revision_count.to_le_bytes().as_ref()],
+              bump, payer = moderator, space = 8 + 32 + 32 + 8 + 8 + 32 + 1 + 4 + question.title.len() + 4 + question.content_data_url.len())]
+    pub revision: Box<Account<'info, Revision>>,
+
     pub system_program: Program<'info, System>,
 }
 
@@ -62,7 +71,8 @@ pub fn edit_question_moderator(ctx: Context<EditQuestionModerator>, new_tags: Ve
 
     let now_ts: u64 = now_ts()?;
 
-    if !ctx.accounts.moderator_profile.is_moderator {
+    // Ensure the moderator is either a forum moderator or a moderator of the content's category
+    if !ctx.accounts.moderator_profile.is_moderator && !ctx.accounts.category.is_category_moderator(&ctx.accounts.moderator_profile.key()) {
         return Err(error!(ErrorCode::ProfileIsNotModerator));
     }
 
@@ -118,7 +128,7 @@ pub fn edit_question_moderator(ctx: Context<EditQuestionModerator>, new_tags: Ve
     let content_data_url_buffer_slice_length: usize = content_data_url_buffer_as_slice.len();
 
     // Calculate total space required for the addition of the new data
-    let new_data_bytes_amount: usize = 128 + contribution_buffer_slice_length + tag_buffer_slice_length + title_buffer_slice_length + content_data_url_buffer_slice_length + 32 + 8 + 1;
+    let new_data_bytes_amount: usize = 128 + contribution_buffer_slice_length + tag_buffer_slice_length + title_buffer_slice_length + content_data_url_buffer_slice_length + 32 + 8 + 1 + 8 + 32 + 32 + 32 + 32;
     let old_data_bytes_amount: usize = ctx.accounts.question.to_account_info().data_len();
 
     if new_data_bytes_amount > old_data_bytes_amount {
@@ -133,8 +143,20 @@ pub fn edit_question_moderator(ctx: Context<EditQuestionModerator>, new_tags: Ve
         ctx.accounts.question.to_account_info().realloc(new_data_bytes_amount, false)?;
     }
 
+    // Record the question's previous content in the revision account
+    let revision = &mut ctx.accounts.revision;
+    revision.content = ctx.accounts.question.key();
+    revision.editor_profile = ctx.accounts.moderator_profile.key();
+    revision.revision_number = ctx.accounts.question.revision_count;
+    revision.revision_ts = now_ts;
+    revision.previous_content_data_hash = ctx.accounts.question.content_data_hash;
+    revision.moderator_edit = true;
+    revision.previous_title = ctx.accounts.question.title.clone();
+    revision.previous_content_data_url = ctx.accounts.question.content_data_url.clone();
+
     // Update question account's most recent engagement timestamp and overwrite with the new content and data hash
     let question = &mut ctx.accounts.question;
+    question.revision_count.try_add_assign(1)?;
     question.most_recent_engagement_ts = now_ts;
     question.tags = new_tags;
     question.title = new_title;
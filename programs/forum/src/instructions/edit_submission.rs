@@ -13,7 +13,7 @@ pub struct EditSubmission<'info> {
     pub profile_owner: Signer<'info>,
 
     // The user profile
-    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), profile_owner.key().as_ref()],
+    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), user_profile.profile_seed.as_ref()],
               bump = bump_user_profile, has_one = forum, has_one = profile_owner)]
     pub user_profile: Box<Account<'info, UserProfile>>,
 
@@ -41,9 +41,14 @@ pub fn edit_submission(ctx: Context<EditSubmission>) -> Result<()> {
 
     let now_ts: u64 = now_ts()?;
 
-    // Ensure submission is not completed
+    // Ensure the user profile has not been suspended from the forum
+    if ctx.accounts.user_profile.is_suspended {
+        return Err(error!(ErrorCode::ProfileSuspended));
+    }
+
+    // Ensure submission evaluation has not been finalized
     let submission_state: SubmissionState = ctx.accounts.submission.submission_state;
-    if submission_state == SubmissionState::Completed {
+    if submission_state != SubmissionState::Pending {
         return Err(error!(ErrorCode::AccountCannotBeEdited));
     }
 
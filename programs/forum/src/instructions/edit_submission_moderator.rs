@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 
-use crate::state::{Challenge, Forum, Submission, UserProfile};
+use crate::state::{Category, Challenge, Forum, Submission, UserProfile};
 use prog_common::{now_ts, errors::ErrorCode};
 
 #[derive(Accounts)]
@@ -13,7 +13,7 @@ pub struct EditSubmissionModerator<'info> {
     pub moderator: Signer<'info>,
 
     // The moderator profile
-    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), moderator.key().as_ref()],
+    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), moderator_profile.profile_seed.as_ref()],
               bump = bump_moderator_profile, has_one = forum, constraint = moderator_profile.profile_owner == moderator.key())]
     pub moderator_profile: Box<Account<'info, UserProfile>>,
 
@@ -21,7 +21,7 @@ pub struct EditSubmissionModerator<'info> {
     pub profile_owner: AccountInfo<'info>,
 
     // The user profile
-    #[account(seeds = [b"user_profile".as_ref(), forum.key().as_ref(), profile_owner.key().as_ref()],
+    #[account(seeds = [b"user_profile".as_ref(), forum.key().as_ref(), user_profile.profile_seed.as_ref()],
               bump = bump_user_profile, has_one = forum, has_one = profile_owner)]
     pub user_profile: Box<Account<'info, UserProfile>>,
 
@@ -30,6 +30,10 @@ pub struct EditSubmissionModerator<'info> {
               bump = bump_challenge, has_one = forum, has_one = challenge_seed)]
     pub challenge: Box<Account<'info, Challenge>>,
 
+    // Category of the challenge, whose moderators may also moderate it
+    #[account(has_one = forum, constraint = category.key() == challenge.category)]
+    pub category: Box<Account<'info, Category>>,
+
     /// CHECK: The seed address used for initialization of the challenge PDA
     pub challenge_seed: AccountInfo<'info>,
 
@@ -48,7 +52,7 @@ pub fn edit_submission_moderator(ctx: Context<EditSubmissionModerator>) -> Resul
 
     let now_ts: u64 = now_ts()?;
 
-    if !ctx.accounts.moderator_profile.is_moderator {
+    if !ctx.accounts.moderator_profile.is_moderator && !ctx.accounts.category.is_category_moderator(&ctx.accounts.moderator_profile.key()) {
         return Err(error!(ErrorCode::ProfileIsNotModerator));
     }
 
@@ -1,11 +1,11 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{Mint};
+use anchor_spl::token::{Mint, TokenAccount};
 
-use crate::state::{Forum, UserProfile};
-use prog_common::{now_ts};
+use crate::state::{verified_collection, Forum, PfpCollectionAllowlist, UserProfile};
+use prog_common::{now_ts, errors::ErrorCode};
 
 #[derive(Accounts)]
-#[instruction(bump_user_profile: u8)]
+#[instruction(bump_user_profile: u8, bump_pfp_collection_allowlist: u8)]
 pub struct EditUserProfile<'info> {
 
     // Forum
@@ -14,13 +14,23 @@ pub struct EditUserProfile<'info> {
     pub profile_owner: Signer<'info>,
 
     // The user profile
-    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), profile_owner.key().as_ref()],
+    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), user_profile.profile_seed.as_ref()],
               bump = bump_user_profile, has_one = forum, has_one = profile_owner)]
     pub user_profile: Box<Account<'info, UserProfile>>,
 
     // NFT token mint address to be set as PFP
     pub nft_pfp_token_mint: Box<Account<'info, Mint>>,
 
+    // Token account of the profile owner holding the NFT
+    pub nft_pfp_token_account: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK: Metaplex metadata account of the NFT (only read if the forum has a PFP collection allowlist)
+    pub nft_metadata: AccountInfo<'info>,
+
+    /// CHECK: The forum's PFP collection allowlist PDA account (empty if any NFT may be set as PFP)
+    #[account(seeds = [b"pfp_collection_allowlist".as_ref(), forum.key().as_ref()], bump = bump_pfp_collection_allowlist)]
+    pub pfp_collection_allowlist: AccountInfo<'info>,
+
     pub system_program: Program<'info, System>,
 }
 
@@ -28,9 +38,36 @@ pub fn edit_user_profile(ctx: Context<EditUserProfile>) -> Result<()> {
 
     let now_ts: u64 = now_ts()?;
 
+    // Ensure the user profile has not been suspended from the forum
+    if ctx.accounts.user_profile.is_suspended {
+        return Err(error!(ErrorCode::ProfileSuspended));
+    }
+
+    // Ensure the token mint is an NFT held by the profile owner
+    let nft_pfp_token_account = &ctx.accounts.nft_pfp_token_account;
+
+    if (ctx.accounts.nft_pfp_token_mint.decimals != 0) || (nft_pfp_token_account.mint != ctx.accounts.nft_pfp_token_mint.key()) ||
+        (nft_pfp_token_account.owner != ctx.accounts.profile_owner.key()) || (nft_pfp_token_account.amount < 1) {
+        return Err(error!(ErrorCode::InvalidNftPfp));
+    }
+
+    // Ensure the NFT is a verified member of an allowed collection if the forum restricts PFP collections
+    if !ctx.accounts.pfp_collection_allowlist.data_is_empty() {
+        let pfp_collection_allowlist = Account::<PfpCollectionAllowlist>::try_from(&ctx.accounts.pfp_collection_allowlist)?;
+
+        if pfp_collection_allowlist.collection_count > 0 {
+            let collection = verified_collection(&ctx.accounts.nft_metadata, &ctx.accounts.nft_pfp_token_mint.key());
+
+            if !collection.map_or(false, |collection| pfp_collection_allowlist.is_allowed(&collection)) {
+                return Err(error!(ErrorCode::NftPfpCollectionNotAllowed));
+            }
+        }
+    }
+
     // Update user profile account's pfp and most recent engagement timestamp
     let user_profile = &mut ctx.accounts.user_profile;
     user_profile.nft_pfp_token_mint = ctx.accounts.nft_pfp_token_mint.key();
+    user_profile.nft_pfp_token_account = ctx.accounts.nft_pfp_token_account.key();
     user_profile.most_recent_engagement_ts = now_ts;
 
     msg!("NFT PFP of user profile {} updated to token mint with account address {}",
@@ -1,73 +1,208 @@
 use anchor_lang::prelude::*;
 
-use crate::state::{Challenge, Forum, Submission, SubmissionState, UserProfile};
-use prog_common::{now_ts, TryAdd, errors::ErrorCode};
+use crate::state::{Category, Challenge, ChallengePrizePool, EvaluationPanel, EvaluationVote, Forum, Submission, SubmissionFeeEscrow, SubmissionState, UserProfile};
+use prog_common::{now_ts, close_account, TryAdd, TryDiv, TryMul, TrySub, errors::ErrorCode};
 
 #[derive(Accounts)]
-#[instruction(bump_moderator_profile: u8, bump_user_profile: u8, bump_challenge: u8, bump_submission: u8)]
+#[instruction(bump_judge_profile: u8, bump_user_profile: u8, bump_challenge: u8, bump_evaluation_panel: u8, bump_submission: u8, bump_prize_pool: u8, bump_submission_fee_escrow: u8)]
 pub struct EvaluateSubmission<'info> {
 
     // Forum
+    #[account(has_one = forum_treasury)]
     pub forum: Box<Account<'info, Forum>>,
 
-    pub moderator: Signer<'info>,
+    /// CHECK: Forum treasury account receiving the retained submission fee
+    #[account(mut)]
+    pub forum_treasury: AccountInfo<'info>,
 
-    // The moderator profile
-    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), moderator.key().as_ref()],
-              bump = bump_moderator_profile, has_one = forum, constraint = moderator_profile.profile_owner == moderator.key())]
-    pub moderator_profile: Box<Account<'info, UserProfile>>,
+    #[account(mut)]
+    pub judge: Signer<'info>,
+
+    // The judge profile
+    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), judge_profile.profile_seed.as_ref()],
+              bump = bump_judge_profile, has_one = forum, constraint = judge_profile.profile_owner == judge.key())]
+    pub judge_profile: Box<Account<'info, UserProfile>>,
 
     /// CHECK:
+    #[account(mut)]
     pub profile_owner: AccountInfo<'info>,
 
     // The user profile
-    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), profile_owner.key().as_ref()],
+    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), user_profile.profile_seed.as_ref()],
               bump = bump_user_profile, has_one = forum, has_one = profile_owner)]
     pub user_profile: Box<Account<'info, UserProfile>>,
 
     // Challenge PDA account and seed
-    #[account(seeds = [b"challenge".as_ref(), forum.key().as_ref(),challenge_seed.key().as_ref()],
+    #[account(mut, seeds = [b"challenge".as_ref(), forum.key().as_ref(),challenge_seed.key().as_ref()],
               bump = bump_challenge, has_one = forum, has_one = challenge_seed)]
     pub challenge: Box<Account<'info, Challenge>>,
 
+    // Category of the challenge, whose fee overrides apply
+    #[account(has_one = forum, constraint = category.key() == challenge.category)]
+    pub category: Box<Account<'info, Category>>,
+
     /// CHECK: The seed address used for initialization of the challenge PDA
     pub challenge_seed: AccountInfo<'info>,
 
+    // Evaluation panel PDA account
+    #[account(seeds = [b"evaluation_panel".as_ref(), challenge.key().as_ref()],
+              bump = bump_evaluation_panel, has_one = challenge)]
+    pub evaluation_panel: Box<Account<'info, EvaluationPanel>>,
+
     // Submission PDA account
     #[account(mut, seeds = [b"submission".as_ref(), challenge.key().as_ref(), user_profile.key().as_ref()],
               bump = bump_submission, has_one = challenge, has_one = user_profile)]
     pub submission: Box<Account<'info, Submission>>,
 
+    // Evaluation vote PDA account (one per judge per submission attempt)
+    #[account(init, seeds = [b"evaluation_vote".as_ref(), submission.key().as_ref(), judge_profile.key().as_ref(), submission.revision_count.to_le_bytes().as_ref()],
+              bump, payer = judge, space = 8 + std::mem::size_of::<EvaluationVote>())]
+    pub evaluation_vote: Box<Account<'info, EvaluationVote>>,
+
+    // Submission fee escrow PDA account
+    #[account(mut, seeds = [b"submission_fee_escrow".as_ref(), submission.key().as_ref()],
+              bump = bump_submission_fee_escrow, has_one = submission)]
+    pub submission_fee_escrow: Box<Account<'info, SubmissionFeeEscrow>>,
+
+    /// CHECK: The challenge prize pool PDA account (empty if the challenge has no prize pool)
+    #[account(mut, seeds = [b"challenge_prize_pool".as_ref(), challenge.key().as_ref()], bump = bump_prize_pool)]
+    pub prize_pool: AccountInfo<'info>,
+
     pub system_program: Program<'info, System>,
 }
 
-pub fn evaluate_submission(ctx: Context<EvaluateSubmission>, submission_state: SubmissionState) -> Result<()> {
+// If the challenge has an SPL prize pool, the token accounts must be passed in as remaining accounts:
+// [prize vault, profile owner token account, token program]
+pub fn evaluate_submission<'info>(ctx: Context<'_, '_, '_, 'info, EvaluateSubmission<'info>>, verdict: SubmissionState) -> Result<()> {
 
     let now_ts = now_ts()?;
+
+    // Ensure the judge profile has not been suspended from the forum
+    if ctx.accounts.judge_profile.is_suspended {
+        return Err(error!(ErrorCode::ProfileSuspended));
+    }
+
     let reputation = ctx.accounts.challenge.reputation;
+    let quorum = ctx.accounts.evaluation_panel.quorum;
+
+    // Ensure the challenge's judging period has not ended
+    if !ctx.accounts.challenge.is_accepting_evaluations(now_ts) {
+        return Err(error!(ErrorCode::JudgingPeriodEnded));
+    }
+
+    // Ensure judge profile is on the challenge's evaluation panel and is not evaluating their own submission
+    if !ctx.accounts.evaluation_panel.is_judge(&ctx.accounts.judge_profile.key()) ||
+        (ctx.accounts.judge_profile.key() == ctx.accounts.user_profile.key()) {
+        return Err(error!(ErrorCode::ProfileIsNotJudge));
+    }
+
+    // Ensure submission evaluation has not yet been finalized
+    if ctx.accounts.submission.submission_state != SubmissionState::Pending {
+        return Err(error!(ErrorCode::SubmissionAlreadyFinalized));
+    }
 
-    if !ctx.accounts.moderator_profile.is_moderator {
-        return Err(error!(ErrorCode::ProfileIsNotModerator));
+    // Ensure verdict is either completed or rejected
+    if verdict == SubmissionState::Pending {
+        return Err(error!(ErrorCode::InvalidParameter));
     }
 
-    // Update the submission's state account
+    // Record Evaluation Vote's State
+    let evaluation_vote = &mut ctx.accounts.evaluation_vote;
+    evaluation_vote.submission = ctx.accounts.submission.key();
+    evaluation_vote.judge_profile = ctx.accounts.judge_profile.key();
+    evaluation_vote.vote_ts = now_ts;
+    evaluation_vote.verdict = verdict;
+
+    // Tally the vote and finalize the submission state once the quorum agrees
     let submission = &mut ctx.accounts.submission;
     submission.most_recent_engagement_ts = now_ts;
-    submission.submission_state = submission_state;
+
+    if verdict == SubmissionState::Completed {
+        submission.approval_votes.try_add_assign(1)?;
+        if submission.approval_votes >= quorum {
+            submission.submission_state = SubmissionState::Completed;
+        }
+    } else {
+        submission.rejection_votes.try_add_assign(1)?;
+        if submission.rejection_votes >= quorum {
+            submission.submission_state = SubmissionState::Rejected;
+        }
+    }
+
+    let submission_state = submission.submission_state;
+
+    // Once finalized, settle the escrowed submission fee: kept in full on completion, partially refunded on rejection
+    if submission_state != SubmissionState::Pending {
+        let fee_amount: u64 = ctx.accounts.submission_fee_escrow.fee_amount;
+
+        let retained_fee: u64 = match submission_state {
+            SubmissionState::Rejected => {
+                let refund_bps: u64 = ctx.accounts.category.fees(&ctx.accounts.forum.forum_fees).forum_rejected_submission_refund;
+                fee_amount.try_sub(fee_amount.try_mul(refund_bps)?.try_div(10000)?)?
+            },
+            _ => fee_amount,
+        };
+
+        let submission_fee_escrow_account_info: &mut AccountInfo = &mut ctx.accounts.submission_fee_escrow.to_account_info();
+        let forum_treasury_account_info: &mut AccountInfo = &mut ctx.accounts.forum_treasury.to_account_info();
+
+        **submission_fee_escrow_account_info.lamports.borrow_mut() = submission_fee_escrow_account_info.lamports().try_sub(retained_fee)?;
+        **forum_treasury_account_info.lamports.borrow_mut() = forum_treasury_account_info.lamports().try_add(retained_fee)?;
+
+        // Close the escrow, returning the refunded fee and rent to the profile owner
+        let profile_owner_account_info: &mut AccountInfo = &mut ctx.accounts.profile_owner.to_account_info();
+        close_account(submission_fee_escrow_account_info, profile_owner_account_info)?;
+    }
 
     // If challenge completed, update the user profile's state account
     if submission_state == SubmissionState::Completed {
         let user_profile = &mut ctx.accounts.user_profile;
         user_profile.challenges_completed.try_add_assign(1)?;
         user_profile.reputation_score.try_add_assign(reputation)?;
+
+        let challenge = &mut ctx.accounts.challenge;
+        challenge.completion_count.try_add_assign(1)?;
+    }
+
+    // If completed and the challenge has a prize pool, pay out the next prize
+    if (submission_state == SubmissionState::Completed) && !ctx.accounts.prize_pool.data_is_empty() {
+        let mut prize_pool = Account::<ChallengePrizePool>::try_from(&ctx.accounts.prize_pool)?;
+        let prize_amount: u64 = prize_pool.next_prize_amount()?;
+
+        if prize_amount > 0 {
+            if prize_pool.is_spl() {
+                // Transfer the tokens from the prize vault to the profile owner
+                prize_pool.transfer_from_vault(&ctx.accounts.prize_pool, &ctx.accounts.profile_owner.key(), ctx.remaining_accounts, prize_amount)?;
+            }
+            else {
+                // Manually transfer the lamports from the prize pool PDA to the profile owner
+                let prize_pool_account_info: &mut AccountInfo = &mut ctx.accounts.prize_pool.to_account_info();
+                let profile_owner_account_info: &mut AccountInfo = &mut ctx.accounts.profile_owner.to_account_info();
+
+                let prize_pool_lamports_initial = prize_pool_account_info.lamports();
+                let profile_owner_lamports_initial = profile_owner_account_info.lamports();
+
+                **prize_pool_account_info.lamports.borrow_mut() = prize_pool_lamports_initial.try_sub(prize_amount)?;
+                **profile_owner_account_info.lamports.borrow_mut() = profile_owner_lamports_initial.try_add(prize_amount)?;
+            }
+
+            let user_profile = &mut ctx.accounts.user_profile;
+            user_profile.total_bounty_earned.try_add_assign(prize_amount)?;
+
+            prize_pool.total_paid.try_add_assign(prize_amount)?;
+            prize_pool.winners_paid.try_add_assign(1)?;
+            prize_pool.exit(&crate::ID)?;
+
+            msg!("User profile with pubkey {} awarded prize of {}", ctx.accounts.user_profile.key(), prize_amount);
+        }
     }
 
-    // Update the moderator profile's state account
-    let moderator_profile = &mut ctx.accounts.moderator_profile;
-    moderator_profile.most_recent_engagement_ts = now_ts;
+    // Update the judge profile's state account
+    let judge_profile = &mut ctx.accounts.judge_profile;
+    judge_profile.most_recent_engagement_ts = now_ts;
 
-    msg!("Submission account with address {} evaluated with submission state {:?}",
-         ctx.accounts.submission.key(), submission_state);
+    msg!("Submission account with address {} evaluated with verdict {:?} by judge profile with pubkey {}",
+         ctx.accounts.submission.key(), verdict, ctx.accounts.judge_profile.key());
 
     Ok(())
 }
@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{BigNote, BigNoteVerificationRecord, BigNoteVerificationState, Forum, UserProfile};
+use prog_common::{now_ts, TrySub, errors::ErrorCode};
+
+#[derive(Accounts)]
+#[instruction(bump_big_note: u8, bump_verification_record: u8)]
+pub struct ExpireBigNoteVerification<'info> {
+
+    // Forum
+    pub forum: Box<Account<'info, Forum>>,
+
+    // Anyone can expire a lapsed verification
+    pub cranker: Signer<'info>,
+
+    // The big note creator's profile
+    #[account(mut, has_one = forum)]
+    pub user_profile: Box<Account<'info, UserProfile>>,
+
+    // Big Note pda account and seed
+    #[account(mut, seeds = [b"big_note".as_ref(), forum.key().as_ref(), user_profile.key().as_ref(), big_note_seed.key().as_ref()],
+              bump = bump_big_note, has_one = forum, has_one = user_profile, has_one = big_note_seed)]
+    pub big_note: Box<Account<'info, BigNote>>,
+
+    /// CHECK: The seed address used for initialization of the big note PDA
+    pub big_note_seed: AccountInfo<'info>,
+
+    // Big Note Verification Record PDA account
+    #[account(mut, seeds = [b"verification_record".as_ref(), big_note.key().as_ref()],
+              bump = bump_verification_record, has_one = big_note)]
+    pub verification_record: Box<Account<'info, BigNoteVerificationRecord>>,
+}
+
+pub fn expire_big_note_verification(ctx: Context<ExpireBigNoteVerification>) -> Result<()> {
+
+    let now_ts: u64 = now_ts()?;
+
+    // Ensure that big note account's verification state is 'Verified'
+    if ctx.accounts.big_note.verification_state != BigNoteVerificationState::Verified {
+        return Err(error!(ErrorCode::BigNoteNotVerified));
+    }
+
+    // Ensure the verification has a validity period which has passed
+    let verification_expires_ts: u64 = ctx.accounts.verification_record.verification_expires_ts;
+    if (verification_expires_ts == 0) || (now_ts <= verification_expires_ts) {
+        return Err(error!(ErrorCode::VerificationNotExpired));
+    }
+
+    // Claw back the verification reputation
+    let big_note_verification_rep: u64 = ctx.accounts.big_note.big_note_verification_rep;
+
+    let user_profile = &mut ctx.accounts.user_profile;
+    user_profile.big_notes_verified.try_sub_assign(1)?;
+    user_profile.reputation_score.try_sub_assign(big_note_verification_rep)?;
+
+    // Open the slashing window for the attestations which verified the big note
+    let verification_record = &mut ctx.accounts.verification_record;
+    verification_record.reverification_ts = now_ts;
+
+    // Update big note account's verification state
+    let big_note = &mut ctx.accounts.big_note;
+    big_note.verification_state = BigNoteVerificationState::NeedsReverification;
+    big_note.big_note_verification_rep = 0;
+
+    msg!("Big Note PDA account with address {} verification has expired", ctx.accounts.big_note.key());
+    Ok(())
+}
@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{Follow, Forum, UserProfile};
+use prog_common::{now_ts, TryAdd, errors::ErrorCode};
+
+#[derive(Accounts)]
+#[instruction(bump_user_profile: u8)]
+pub struct FollowUser<'info> {
+
+    // Forum
+    pub forum: Box<Account<'info, Forum>>,
+
+    #[account(mut)]
+    pub profile_owner: Signer<'info>,
+
+    // The user profile doing the following
+    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), user_profile.profile_seed.as_ref()],
+              bump = bump_user_profile, has_one = forum, has_one = profile_owner)]
+    pub user_profile: Box<Account<'info, UserProfile>>,
+
+    // The user profile being followed
+    #[account(mut, has_one = forum)]
+    pub followed_profile: Box<Account<'info, UserProfile>>,
+
+    // Follow PDA account
+    #[account(init, seeds = [b"follow".as_ref(), user_profile.key().as_ref(), followed_profile.key().as_ref()],
+              bump, payer = profile_owner, space = 8 + std::mem::size_of::<Follow>())]
+    pub follow: Box<Account<'info, Follow>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn follow_user(ctx: Context<FollowUser>) -> Result<()> {
+
+    let now_ts: u64 = now_ts()?;
+
+    // Ensure the user profile has not been suspended from the forum
+    if ctx.accounts.user_profile.is_suspended {
+        return Err(error!(ErrorCode::ProfileSuspended));
+    }
+
+    // Ensure the user profile is not following itself
+    if ctx.accounts.user_profile.key() == ctx.accounts.followed_profile.key() {
+        return Err(error!(ErrorCode::CannotFollowSelf));
+    }
+
+    // Record Follow's State
+    let follow = &mut ctx.accounts.follow;
+    follow.follower_profile = ctx.accounts.user_profile.key();
+    follow.followed_profile = ctx.accounts.followed_profile.key();
+    follow.followed_ts = now_ts;
+
+    // Increment follower count in followed profile's state account
+    let followed_profile = &mut ctx.accounts.followed_profile;
+    followed_profile.follower_count.try_add_assign(1)?;
+
+    // Increment following count in user profile's state account
+    let user_profile = &mut ctx.accounts.user_profile;
+    user_profile.following_count.try_add_assign(1)?;
+    user_profile.most_recent_engagement_ts = now_ts;
+
+    msg!("User profile with pubkey {} now following user profile with pubkey {}",
+         ctx.accounts.user_profile.key(), ctx.accounts.followed_profile.key());
+    Ok(())
+}
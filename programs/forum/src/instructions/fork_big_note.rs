@@ -0,0 +1,208 @@
+use anchor_lang::prelude::*;
+
+use anchor_lang::solana_program::program::{invoke};
+use anchor_lang::solana_program::system_instruction;
+
+use crate::state::{BigNote, BigNoteFork, BigNoteType, BigNoteVerificationState, Category, Forum, UserProfile};
+use prog_common::{now_ts, TryAdd, errors::ErrorCode};
+
+#[derive(Accounts)]
+#[instruction(bump_treasury: u8, bump_user_profile: u8)]
+pub struct ForkBigNote<'info> {
+
+    // Forum
+    #[account(mut, has_one = forum_treasury)]
+    pub forum: Box<Account<'info, Forum>>,
+
+    /// CHECK:
+    #[account(mut, seeds = [b"treasury".as_ref(), forum.key().as_ref()], bump = bump_treasury)]
+    pub forum_treasury: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub profile_owner: Signer<'info>,
+
+    // The user profile
+    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), user_profile.profile_seed.as_ref()],
+              bump = bump_user_profile, has_one = forum, has_one = profile_owner)]
+    pub user_profile: Box<Account<'info, UserProfile>>,
+
+    // Big Note being forked
+    #[account(mut, has_one = forum)]
+    pub parent_big_note: Box<Account<'info, BigNote>>,
+
+    // Category of the big note being forked (forks stay in their parent's category)
+    #[account(mut, has_one = forum, constraint = category.key() == parent_big_note.category)]
+    pub category: Box<Account<'info, Category>>,
+
+    /// CHECK: Big note PDA account created by the fork
+    #[account(mut)]
+    pub big_note: AccountInfo<'info>,
+
+    /// CHECK: The seed address used for initialization of the big note PDA
+    pub big_note_seed: AccountInfo<'info>,
+
+    // Big Note fork PDA account linking the fork to its parent
+    #[account(init, seeds = [b"big_note_fork".as_ref(), big_note.key().as_ref()],
+              bump, payer = profile_owner, space = 8 + std::mem::size_of::<BigNoteFork>())]
+    pub big_note_fork: Box<Account<'info, BigNoteFork>>,
+
+    /// CHECK:
+    // The content data hash of the big note struct
+    pub content_data_hash: AccountInfo<'info>,
+
+    /// CHECK:
+    #[account(init, seeds = [b"bignote_bounty_pda".as_ref(), big_note.key().as_ref()], bump, payer = profile_owner, space = 8)]
+    pub bounty_pda: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ForkBigNote<'info> {
+
+    fn transfer_payment_ctx(&self, lamports: u64) -> Result<()> {
+        invoke(
+            &system_instruction::transfer(self.profile_owner.key, self.forum_treasury.key, lamports),
+            &[
+                self.profile_owner.to_account_info(),
+                self.forum_treasury.to_account_info(),
+                self.system_program.to_account_info(),
+            ],
+        )
+            .map_err(Into::into)
+    }
+}
+
+pub fn fork_big_note(ctx: Context<ForkBigNote>, big_note_type: BigNoteType, content_data_url: String) -> Result<()> {
+
+    let now_ts: u64 = now_ts()?;
+
+    // Ensure the user profile has not been suspended from the forum
+    if ctx.accounts.user_profile.is_suspended {
+        return Err(error!(ErrorCode::ProfileSuspended));
+    }
+
+    let bounty_amount: u64 = 0;
+    let bounty_awarded = false;
+    let revision_count: u64 = 0;
+    let fork_count: u64 = 0;
+    let successor: Pubkey = Pubkey::default();
+    let pending_owner: Pubkey = Pubkey::default();
+    let source_question: Pubkey = Pubkey::default();
+    let source_answer: Pubkey = Pubkey::default();
+    let verification_state = BigNoteVerificationState::Unverified;
+    let big_note_creation_rep = ctx.accounts.forum.reputation_matrix.create_big_notes_rep;
+    let big_note_verification_rep: u64 = 0;
+
+    // Copy tags and title from the parent big note
+    let tags = ctx.accounts.parent_big_note.tags.clone();
+    let title = ctx.accounts.parent_big_note.title.clone();
+
+    // Record character length of content_data_url to be added
+    let url_length: u64 = content_data_url.len() as u64;
+    let max_url_length = ctx.accounts.forum.forum_constants.max_url_length;
+
+    // Ensure that the length of the content_data_url string is non-zero and not more than max_url_length characters long
+    if (url_length == 0) || (url_length > max_url_length) {
+        return Err(error!(ErrorCode::InvalidUrlStringInput));
+    }
+
+    // find bump - doing this program-side to reduce amount of info to be passed in (tx size)
+    let (_pk, bump) = Pubkey::find_program_address(
+        &[
+            b"big_note".as_ref(),
+            ctx.accounts.forum.key().as_ref(),
+            ctx.accounts.user_profile.key().as_ref(),
+            ctx.accounts.big_note_seed.key().as_ref()
+        ],
+        ctx.program_id,
+    );
+
+    // Create the forked big note account PDA if it doesn't exist
+    if ctx.accounts.big_note.data_is_empty() {
+
+        // Record Big Note's State
+        let big_note = BigNote {
+            forum: ctx.accounts.forum.key(),
+            user_profile: ctx.accounts.user_profile.key(),
+            big_note_seed: ctx.accounts.big_note_seed.key(),
+            big_note_created_ts: now_ts,
+            most_recent_engagement_ts: now_ts,
+            bounty_amount,
+            bounty_contributions: Vec::new(),
+            big_note_type,
+            verification_state,
+            tags,
+            title,
+            content_data_url,
+            content_data_hash: ctx.accounts.content_data_hash.key(),
+            big_note_creation_rep,
+            big_note_verification_rep,
+            bounty_awarded,
+            revision_count,
+            source_question,
+            source_answer,
+            fork_count,
+            successor,
+            current_owner: ctx.accounts.user_profile.key(),
+            pending_owner,
+            category: ctx.accounts.category.key(),
+        };
+
+        // Create the big note PDA account and write the big note into it
+        big_note.create_account(
+            &[
+                b"big_note".as_ref(),
+                ctx.accounts.forum.key().as_ref(),
+                ctx.accounts.user_profile.key().as_ref(),
+                ctx.accounts.big_note_seed.key().as_ref(),
+                &[bump],
+            ],
+            &ctx.accounts.big_note,
+            &ctx.accounts.profile_owner.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+        )?;
+
+        // Transfer fee for posting big_note
+        let forum_big_notes_submission_fee = ctx.accounts.category.fees(&ctx.accounts.forum.forum_fees).forum_big_notes_submission_fee;
+
+        if forum_big_notes_submission_fee > 0 {
+            ctx.accounts.transfer_payment_ctx(forum_big_notes_submission_fee)?;
+        }
+
+        // Increment big note count in forum state's account
+        let forum = &mut ctx.accounts.forum;
+        forum.forum_counts.forum_big_notes_count.try_add_assign(1)?;
+
+        // Increment big note count in category's state account
+        let category = &mut ctx.accounts.category;
+        category.big_notes_count.try_add_assign(1)?;
+
+        // Increment big note posted count in user profile's state account
+        let user_profile = &mut ctx.accounts.user_profile;
+        user_profile.big_notes_created.try_add_assign(1)?;
+
+        // Update user profile's most recent engagement timestamp and reputation score
+        let user_profile = &mut ctx.accounts.user_profile;
+        user_profile.most_recent_engagement_ts = now_ts;
+        user_profile.reputation_score.try_add_assign(big_note_creation_rep)?;
+
+        // Record the fork's link to its parent along with the parent's content at the time of forking
+        let big_note_fork = &mut ctx.accounts.big_note_fork;
+        big_note_fork.parent_big_note = ctx.accounts.parent_big_note.key();
+        big_note_fork.fork_big_note = ctx.accounts.big_note.key();
+        big_note_fork.parent_content_data_hash = ctx.accounts.parent_big_note.content_data_hash;
+        big_note_fork.forked_ts = now_ts;
+
+        // Increment fork count in parent big note's state account
+        let parent_big_note = &mut ctx.accounts.parent_big_note;
+        parent_big_note.fork_count.try_add_assign(1)?;
+
+        msg!("Big Note PDA account with address {} now created as a fork of big note with address {}",
+             ctx.accounts.big_note.key(), ctx.accounts.parent_big_note.key());
+    }
+    else {
+        return Err(error!(ErrorCode::InvalidBigNoteFork));
+    }
+
+    Ok(())
+}
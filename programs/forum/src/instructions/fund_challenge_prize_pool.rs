@@ -0,0 +1,126 @@
+use anchor_lang::prelude::*;
+
+use anchor_lang::solana_program::program::{invoke};
+use anchor_lang::solana_program::system_instruction;
+
+use crate::state::{Challenge, ChallengePrizePool, ChallengeProposal, Forum, PrizeContribution, UserProfile};
+use prog_common::{now_ts, TryAdd, errors::ErrorCode};
+
+#[derive(Accounts)]
+#[instruction(bump_sponsor_profile: u8, bump_challenge: u8, bump_prize_pool: u8, bump_challenge_proposal: u8)]
+pub struct FundChallengePrizePool<'info> {
+
+    // Forum
+    pub forum: Box<Account<'info, Forum>>,
+
+    #[account(mut)]
+    pub sponsor: Signer<'info>,
+
+    // The sponsor profile
+    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), sponsor_profile.profile_seed.as_ref()],
+              bump = bump_sponsor_profile, has_one = forum, constraint = sponsor_profile.profile_owner == sponsor.key())]
+    pub sponsor_profile: Box<Account<'info, UserProfile>>,
+
+    // Challenge PDA account and seed
+    #[account(seeds = [b"challenge".as_ref(), forum.key().as_ref(), challenge_seed.key().as_ref()],
+              bump = bump_challenge, has_one = forum, has_one = challenge_seed)]
+    pub challenge: Box<Account<'info, Challenge>>,
+
+    /// CHECK: The seed address used for initialization of the challenge PDA
+    pub challenge_seed: AccountInfo<'info>,
+
+    /// CHECK: The challenge's proposal PDA account (empty if the challenge was created by a moderator)
+    #[account(seeds = [b"challenge_proposal".as_ref(), challenge.key().as_ref()], bump = bump_challenge_proposal)]
+    pub challenge_proposal: AccountInfo<'info>,
+
+    // Challenge prize pool PDA account
+    #[account(mut, seeds = [b"challenge_prize_pool".as_ref(), challenge.key().as_ref()],
+              bump = bump_prize_pool, has_one = challenge)]
+    pub prize_pool: Box<Account<'info, ChallengePrizePool>>,
+
+    // Prize contribution PDA account
+    #[account(init_if_needed, seeds = [b"prize_contribution".as_ref(), prize_pool.key().as_ref(), sponsor_profile.key().as_ref()],
+              bump, payer = sponsor, space = 8 + std::mem::size_of::<PrizeContribution>())]
+    pub prize_contribution: Box<Account<'info, PrizeContribution>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> FundChallengePrizePool<'info> {
+
+    fn transfer_prize_ctx(&self, lamports: u64) -> Result<()> {
+        invoke(
+            &system_instruction::transfer(self.sponsor.key, &self.prize_pool.key(), lamports),
+            &[
+                self.sponsor.to_account_info(),
+                self.prize_pool.to_account_info(),
+                self.system_program.to_account_info(),
+            ],
+        )
+            .map_err(Into::into)
+    }
+}
+
+// If the prize pool is an SPL prize pool, the token accounts must be passed in as remaining accounts:
+// [prize vault, sponsor token account, token program]
+pub fn fund_challenge_prize_pool<'info>(ctx: Context<'_, '_, '_, 'info, FundChallengePrizePool<'info>>, prize_amount: u64) -> Result<()> {
+
+    let now_ts: u64 = now_ts()?;
+
+    // Ensure the sponsor profile has not been suspended from the forum
+    if ctx.accounts.sponsor_profile.is_suspended {
+        return Err(error!(ErrorCode::ProfileSuspended));
+    }
+
+    // Ensure a community proposed challenge has been approved by a moderator
+    if !ChallengeProposal::is_challenge_approved(&ctx.accounts.challenge_proposal)? {
+        return Err(error!(ErrorCode::ChallengeNotApproved));
+    }
+
+    // Ensure challenge has not yet expired and prize payouts are still open
+    if (now_ts > ctx.accounts.challenge.challenge_expires_ts) || ctx.accounts.prize_pool.payouts_closed {
+        return Err(error!(ErrorCode::ChallengeExpired));
+    }
+
+    // Ensure no prize has been paid yet (prize amounts are calculated from the total funded)
+    if ctx.accounts.prize_pool.winners_paid > 0 {
+        return Err(error!(ErrorCode::PrizePoolFundingClosed));
+    }
+
+    // Ensure a non-zero prize amount is contributed
+    if prize_amount == 0 {
+        return Err(error!(ErrorCode::InvalidParameter));
+    }
+
+    // Transfer the prize amount into the challenge prize pool PDA account (or its vault for SPL prize pools)
+    if ctx.accounts.prize_pool.is_spl() {
+        let sponsor_account_info = ctx.accounts.sponsor.to_account_info();
+        ctx.accounts.prize_pool.transfer_to_vault(&ctx.accounts.prize_pool.key(), &sponsor_account_info, ctx.remaining_accounts, prize_amount)?;
+    }
+    else {
+        ctx.accounts.transfer_prize_ctx(prize_amount)?;
+    }
+
+    // Update the prize pool's state account (counting the sponsor on their first contribution)
+    let prize_pool = &mut ctx.accounts.prize_pool;
+    prize_pool.total_funded.try_add_assign(prize_amount)?;
+
+    if ctx.accounts.prize_contribution.contributed_amount == 0 {
+        prize_pool.sponsor_count.try_add_assign(1)?;
+    }
+
+    // Record Prize Contribution's State
+    let prize_contribution = &mut ctx.accounts.prize_contribution;
+    prize_contribution.prize_pool = ctx.accounts.prize_pool.key();
+    prize_contribution.sponsor_profile = ctx.accounts.sponsor_profile.key();
+    prize_contribution.most_recent_contribution_ts = now_ts;
+    prize_contribution.contributed_amount.try_add_assign(prize_amount)?;
+
+    // Update the sponsor profile's state account
+    let sponsor_profile = &mut ctx.accounts.sponsor_profile;
+    sponsor_profile.most_recent_engagement_ts = now_ts;
+
+    msg!("Challenge prize pool PDA account with address {} funded with prize amount of {}",
+         ctx.accounts.prize_pool.key(), prize_amount);
+    Ok(())
+}
@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 
-use crate::state::{Forum, ForumConstants, ForumFees, ReputationMatrix, LATEST_FORUM_VERSION};
+use crate::state::{Forum, ForumConstants, ForumFees, ReputationMatrix, LATEST_FORUM_VERSION, MAX_HANDLE_LENGTH};
 use prog_common::errors::ErrorCode;
 
 #[derive(Accounts)]
@@ -41,11 +41,18 @@ pub fn init_forum(ctx: Context<InitForum>, forum_fees: ForumFees, forum_constant
     // Check that the derived treasury PDA pubkey matches the one provided
     assert_eq!(ctx.accounts.forum_treasury.key(), forum_treasury_key);
 
-    // Assert forum question fee and forum big notes solicitation fee in basis points are between 0 - 10,000
-    if (forum_fees.forum_question_fee > 10000) || (forum_fees.forum_big_notes_solicitation_fee > 10000) {
+    // Assert forum question fee, forum big notes solicitation fee and rejected submission refund in basis points are between 0 - 10,000
+    if (forum_fees.forum_question_fee > 10000) || (forum_fees.forum_big_notes_solicitation_fee > 10000) ||
+        (forum_fees.forum_rejected_submission_refund > 10000) {
         return Err(error!(ErrorCode::InvalidFeeInputs));
     }
 
+    // Assert handle length limits are non-zero, ordered and fit in a PDA seed
+    if (forum_constants.min_handle_length == 0) || (forum_constants.min_handle_length > forum_constants.max_handle_length) ||
+        (forum_constants.max_handle_length > MAX_HANDLE_LENGTH) {
+        return Err(error!(ErrorCode::InvalidHandleRules));
+    }
+
     // Record Forum's State
     forum.version = LATEST_FORUM_VERSION;
     forum.forum_manager = ctx.accounts.forum_manager.key();
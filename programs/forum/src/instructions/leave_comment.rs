@@ -0,0 +1,114 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{Comment, CommentTarget, Forum, SessionScope, UserProfile};
+use prog_common::{now_ts, TryAdd, errors::ErrorCode};
+
+#[derive(Accounts)]
+#[instruction(bump_user_profile: u8, bump_session_key: u8)]
+pub struct LeaveComment<'info> {
+
+    // Forum
+    #[account(mut)]
+    pub forum: Box<Account<'info, Forum>>,
+
+    // The profile owner or a delegate holding a session key for the user profile
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    // The user profile
+    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), user_profile.profile_seed.as_ref()],
+              bump = bump_user_profile, has_one = forum)]
+    pub user_profile: Box<Account<'info, UserProfile>>,
+
+    /// CHECK: Session key PDA account of the signer (empty when the profile owner signs)
+    #[account(seeds = [b"session_key".as_ref(), user_profile.key().as_ref(), authority.key().as_ref()], bump = bump_session_key)]
+    pub session_key: AccountInfo<'info>,
+
+    /// CHECK: Question/Answer/BigNote/ProposedContribution/Challenge/Submission/Comment PDA account, verified against the comment target
+    #[account(mut)]
+    pub commented_on: AccountInfo<'info>,
+
+    // Comment PDA account and seed
+    #[account(init, seeds = [b"comment".as_ref(), forum.key().as_ref(), user_profile.key().as_ref(), comment_seed.key().as_ref()],
+              bump, payer = authority, space = 8 + std::mem::size_of::<Comment>())]
+    pub comment: Box<Account<'info, Comment>>,
+
+    /// CHECK: The seed address used for initialization of the comment PDA
+    pub comment_seed: AccountInfo<'info>,
+
+    /// CHECK:
+    // The content data hash of the comment struct
+    pub content_data_hash: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn leave_comment<'info>(ctx: Context<'_, '_, '_, 'info, LeaveComment<'info>>, comment_target: CommentTarget) -> Result<()> {
+
+    let now_ts: u64 = now_ts()?;
+
+    // Ensure the user profile has not been suspended from the forum
+    if ctx.accounts.user_profile.is_suspended {
+        return Err(error!(ErrorCode::ProfileSuspended));
+    }
+
+    // Ensure the signer is the profile owner or a delegate with a valid session key
+    SessionScope::CommentAndAnswer.verify_authority(&ctx.accounts.user_profile.profile_owner, &ctx.accounts.authority.key(), &ctx.accounts.session_key, now_ts)?;
+
+    let comment_rep = ctx.accounts.forum.reputation_matrix.comment_rep;
+    let max_comment_depth = ctx.accounts.forum.forum_constants.max_comment_depth;
+
+    // Ensure the commented on account matches the comment target and belongs to the forum
+    comment_target.verify_commented_on(&ctx.accounts.commented_on, &ctx.accounts.forum.key(), ctx.remaining_accounts)?;
+
+    // Replies must not exceed the maximum comment depth, and increment the parent comment's reply count
+    let comment_depth: u64 = if comment_target == CommentTarget::Comment {
+        let mut parent_comment = Account::<Comment>::try_from(&ctx.accounts.commented_on)?;
+        let comment_depth: u64 = parent_comment.comment_depth.try_add(1)?;
+
+        if comment_depth > max_comment_depth {
+            return Err(error!(ErrorCode::MaxCommentDepthExceeded));
+        }
+
+        parent_comment.reply_count.try_add_assign(1)?;
+        parent_comment.exit(&crate::ID)?;
+        comment_depth
+    } else {
+        0
+    };
+
+    // Record Comment's State
+    let comment = &mut ctx.accounts.comment;
+    comment.commented_on = ctx.accounts.commented_on.key();
+    comment.user_profile = ctx.accounts.user_profile.key();
+    comment.comment_seed = ctx.accounts.comment_seed.key();
+
+    comment.comment_posted_ts = now_ts;
+    comment.most_recent_engagement_ts = now_ts;
+
+    comment.content_data_hash = ctx.accounts.content_data_hash.key();
+    comment.comment_rep = comment_rep;
+
+    comment.comment_depth = comment_depth;
+    comment.reply_count = 0;
+    comment.comment_target = comment_target;
+
+    // Increment comment count in forum state's account
+    let forum = &mut ctx.accounts.forum;
+    forum.forum_counts.forum_comment_count.try_add_assign(1)?;
+
+    // Increment comments added count in user profile's state account
+    let user_profile = &mut ctx.accounts.user_profile;
+    user_profile.comments_added.try_add_assign(1)?;
+
+    // Update user profile's most recent engagement timestamp and reputation score
+    user_profile.most_recent_engagement_ts = now_ts;
+    user_profile.reputation_score.try_add_assign(comment_rep)?;
+
+    // Update commented on account's most recent engagement timestamp
+    comment_target.record_engagement(&ctx.accounts.commented_on, now_ts)?;
+
+    msg!("Comment PDA account with address {} now created on account with address {}",
+         ctx.accounts.comment.key(), ctx.accounts.commented_on.key());
+    Ok(())
+}
@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{BigNote, BigNoteFork, Forum, UserProfile};
+use prog_common::{now_ts, errors::ErrorCode};
+
+#[derive(Accounts)]
+#[instruction(bump_moderator_profile: u8, bump_big_note_fork: u8)]
+pub struct MarkBigNoteSuccessor<'info> {
+
+    // Forum
+    pub forum: Box<Account<'info, Forum>>,
+
+    pub moderator: Signer<'info>,
+
+    // The moderator profile
+    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), moderator_profile.profile_seed.as_ref()],
+              bump = bump_moderator_profile, has_one = forum, constraint = moderator_profile.profile_owner == moderator.key())]
+    pub moderator_profile: Box<Account<'info, UserProfile>>,
+
+    // Big Note which was forked
+    #[account(mut, has_one = forum)]
+    pub parent_big_note: Box<Account<'info, BigNote>>,
+
+    // Big Note fork PDA account linking the fork to its parent
+    #[account(seeds = [b"big_note_fork".as_ref(), big_note_fork.fork_big_note.as_ref()],
+              bump = bump_big_note_fork, has_one = parent_big_note)]
+    pub big_note_fork: Box<Account<'info, BigNoteFork>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn mark_big_note_successor(ctx: Context<MarkBigNoteSuccessor>) -> Result<()> {
+
+    let now_ts: u64 = now_ts()?;
+
+    if !ctx.accounts.moderator_profile.is_moderator {
+        return Err(error!(ErrorCode::ProfileIsNotModerator));
+    }
+
+    // Mark the fork as the successor of the parent big note
+    let parent_big_note = &mut ctx.accounts.parent_big_note;
+    parent_big_note.successor = ctx.accounts.big_note_fork.fork_big_note;
+
+    // Update moderator profile's most recent engagement ts
+    let moderator_profile = &mut ctx.accounts.moderator_profile;
+    moderator_profile.most_recent_engagement_ts = now_ts;
+
+    msg!("Big Note with address {} marked as the successor of big note with address {}",
+         ctx.accounts.big_note_fork.fork_big_note, ctx.accounts.parent_big_note.key());
+    Ok(())
+}
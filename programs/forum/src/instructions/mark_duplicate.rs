@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{Category, Forum, Question, UserProfile};
+use prog_common::{now_ts, errors::ErrorCode};
+
+#[derive(Accounts)]
+#[instruction(bump_moderator_profile: u8)]
+pub struct MarkDuplicate<'info> {
+
+    // Forum
+    pub forum: Box<Account<'info, Forum>>,
+
+    pub moderator: Signer<'info>,
+
+    // The moderator profile
+    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), moderator_profile.profile_seed.as_ref()],
+              bump = bump_moderator_profile, has_one = forum, constraint = moderator_profile.profile_owner == moderator.key())]
+    pub moderator_profile: Box<Account<'info, UserProfile>>,
+
+    // Question PDA account to be marked as a duplicate
+    #[account(mut, has_one = forum)]
+    pub duplicate_question: Box<Account<'info, Question>>,
+
+    // Category of the duplicate question, whose moderators may also moderate it
+    #[account(has_one = forum, constraint = category.key() == duplicate_question.category)]
+    pub category: Box<Account<'info, Category>>,
+
+    // Canonical question PDA account
+    #[account(has_one = forum)]
+    pub canonical_question: Box<Account<'info, Question>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn mark_duplicate(ctx: Context<MarkDuplicate>) -> Result<()> {
+
+    let now_ts: u64 = now_ts()?;
+
+    if !ctx.accounts.moderator_profile.is_moderator && !ctx.accounts.category.is_category_moderator(&ctx.accounts.moderator_profile.key()) {
+        return Err(error!(ErrorCode::ProfileIsNotModerator));
+    }
+
+    // Ensure question is not marked as a duplicate of itself, is not already a duplicate and the canonical question is not a duplicate
+    if (ctx.accounts.duplicate_question.key() == ctx.accounts.canonical_question.key()) ||
+        (ctx.accounts.duplicate_question.duplicate_of != Pubkey::default()) ||
+        (ctx.accounts.canonical_question.duplicate_of != Pubkey::default()) {
+        return Err(error!(ErrorCode::InvalidDuplicateQuestion));
+    }
+
+    // Record the canonical question in the duplicate question account's state
+    let duplicate_question = &mut ctx.accounts.duplicate_question;
+    duplicate_question.duplicate_of = ctx.accounts.canonical_question.key();
+    duplicate_question.most_recent_engagement_ts = now_ts;
+
+    // Update moderator profile's most recent engagement
+    let moderator_profile = &mut ctx.accounts.moderator_profile;
+    moderator_profile.most_recent_engagement_ts = now_ts;
+
+    msg!("Question PDA account with address {} marked as a duplicate of question with address {}",
+         ctx.accounts.duplicate_question.key(), ctx.accounts.canonical_question.key());
+    Ok(())
+}
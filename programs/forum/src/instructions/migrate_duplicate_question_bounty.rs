@@ -0,0 +1,142 @@
+use anchor_lang::prelude::*;
+
+use anchor_lang::solana_program::program::{invoke};
+use anchor_lang::solana_program::system_instruction;
+
+use crate::state::{BountyContribution, BountyContributionState, Category, Forum, Question, UserProfile};
+use prog_common::{now_ts, TryAdd, TrySub, errors::ErrorCode};
+
+#[derive(Accounts)]
+#[instruction(bump_moderator_profile: u8, bump_duplicate_bounty_pda: u8, bump_canonical_bounty_pda: u8)]
+pub struct MigrateDuplicateQuestionBounty<'info> {
+
+    // Forum
+    pub forum: Box<Account<'info, Forum>>,
+
+    #[account(mut)]
+    pub moderator: Signer<'info>,
+
+    // The moderator profile
+    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), moderator_profile.profile_seed.as_ref()],
+              bump = bump_moderator_profile, has_one = forum, constraint = moderator_profile.profile_owner == moderator.key())]
+    pub moderator_profile: Box<Account<'info, UserProfile>>,
+
+    // Question PDA account marked as a duplicate
+    #[account(mut, has_one = forum)]
+    pub duplicate_question: Box<Account<'info, Question>>,
+
+    // Category of the duplicate question, whose moderators may also moderate it
+    #[account(has_one = forum, constraint = category.key() == duplicate_question.category)]
+    pub category: Box<Account<'info, Category>>,
+
+    /// CHECK: The duplicate question bounty pda account
+    #[account(mut, seeds = [b"question_bounty_pda".as_ref(), duplicate_question.key().as_ref()], bump = bump_duplicate_bounty_pda)]
+    pub duplicate_bounty_pda: AccountInfo<'info>,
+
+    // Canonical question PDA account
+    #[account(mut, has_one = forum)]
+    pub canonical_question: Box<Account<'info, Question>>,
+
+    /// CHECK: The canonical question bounty pda account
+    #[account(mut, seeds = [b"question_bounty_pda".as_ref(), canonical_question.key().as_ref()], bump = bump_canonical_bounty_pda)]
+    pub canonical_bounty_pda: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> MigrateDuplicateQuestionBounty<'info> {
+
+    fn pay_lamports_difference(&self, lamports: u64) -> Result<()> {
+        invoke(
+            &system_instruction::transfer(self.moderator.key, &self.canonical_question.key(), lamports),
+            &[
+                self.moderator.to_account_info(),
+                self.canonical_question.to_account_info(),
+                self.system_program.to_account_info(),
+            ],
+        )
+            .map_err(Into::into)
+    }
+}
+
+pub fn migrate_duplicate_question_bounty(ctx: Context<MigrateDuplicateQuestionBounty>) -> Result<()> {
+
+    let now_ts: u64 = now_ts()?;
+
+    if !ctx.accounts.moderator_profile.is_moderator && !ctx.accounts.category.is_category_moderator(&ctx.accounts.moderator_profile.key()) {
+        return Err(error!(ErrorCode::ProfileIsNotModerator));
+    }
+
+    // Ensure question has been marked as a duplicate of the canonical question
+    if ctx.accounts.duplicate_question.duplicate_of != ctx.accounts.canonical_question.key() {
+        return Err(error!(ErrorCode::QuestionNotMarkedAsDuplicate));
+    }
+
+    // Ensure neither bounty has been awarded
+    if ctx.accounts.duplicate_question.bounty_awarded || ctx.accounts.canonical_question.bounty_awarded {
+        return Err(error!(ErrorCode::BountyAlreadyAwarded));
+    }
+
+    // Collect the bounty contributions that have not yet been refunded
+    let migrated_contributions: Vec<BountyContribution> = ctx.accounts.duplicate_question.bounty_contributions.iter()
+        .filter(|&x| x.bounty_contribution_state == BountyContributionState::Available).copied().collect();
+
+    let mut total_migrated_bounty_amount: u64 = 0;
+    for bounty_contribution in migrated_contributions.iter() {
+        total_migrated_bounty_amount.try_add_assign(bounty_contribution.bounty_amount)?;
+    }
+
+    // Calculate total space required for the addition of the migrated bounty contributions (57 bytes each when serialized)
+    let migrated_contributions_length: usize = migrated_contributions.len() * 57;
+    let old_data_bytes_amount: usize = ctx.accounts.canonical_question.to_account_info().data_len();
+    let new_data_bytes_amount: usize = old_data_bytes_amount + migrated_contributions_length;
+
+    if new_data_bytes_amount > old_data_bytes_amount {
+
+        let minimum_balance_for_rent_exemption: u64 = Rent::get()?.minimum_balance(new_data_bytes_amount);
+        let canonical_question_lamports: u64 = ctx.accounts.canonical_question.to_account_info().lamports();
+
+        // Transfer the required difference in Lamports to accommodate this increase in space
+        if minimum_balance_for_rent_exemption > canonical_question_lamports {
+            ctx.accounts.pay_lamports_difference(minimum_balance_for_rent_exemption.try_sub(canonical_question_lamports)?)?;
+        }
+
+        // Reallocate the canonical question pda account with the proper byte data size
+        ctx.accounts.canonical_question.to_account_info().realloc(new_data_bytes_amount, false)?;
+    }
+
+    // Manually transfer the lamports from the duplicate question's bounty PDA to the canonical question's bounty PDA
+    let duplicate_bounty_pda_account_info: &mut AccountInfo = &mut ctx.accounts.duplicate_bounty_pda.to_account_info();
+    let canonical_bounty_pda_account_info: &mut AccountInfo = &mut ctx.accounts.canonical_bounty_pda.to_account_info();
+
+    let duplicate_bounty_pda_lamports_initial = duplicate_bounty_pda_account_info.lamports();
+    let canonical_bounty_pda_lamports_initial = canonical_bounty_pda_account_info.lamports();
+
+    **duplicate_bounty_pda_account_info.lamports.borrow_mut() = duplicate_bounty_pda_lamports_initial.try_sub(total_migrated_bounty_amount)?;
+    **canonical_bounty_pda_account_info.lamports.borrow_mut() = canonical_bounty_pda_lamports_initial.try_add(total_migrated_bounty_amount)?;
+
+    // Update bounty amount and bounty contributions in duplicate question account's state
+    let duplicate_question = &mut ctx.accounts.duplicate_question;
+    duplicate_question.bounty_amount.try_sub_assign(total_migrated_bounty_amount)?;
+    duplicate_question.most_recent_engagement_ts = now_ts;
+
+    for index in 0..duplicate_question.bounty_contributions.len() {
+        if duplicate_question.bounty_contributions[index].bounty_contribution_state == BountyContributionState::Available {
+            duplicate_question.bounty_contributions[index].bounty_contribution_state = BountyContributionState::Migrated;
+        }
+    }
+
+    // Update bounty amount and bounty contributions in canonical question account's state
+    let canonical_question = &mut ctx.accounts.canonical_question;
+    canonical_question.bounty_amount.try_add_assign(total_migrated_bounty_amount)?;
+    canonical_question.bounty_contributions.extend(migrated_contributions);
+    canonical_question.most_recent_engagement_ts = now_ts;
+
+    // Update moderator profile's most recent engagement
+    let moderator_profile = &mut ctx.accounts.moderator_profile;
+    moderator_profile.most_recent_engagement_ts = now_ts;
+
+    msg!("Total bounty amount of {} migrated from question with address {} to question with address {}",
+         total_migrated_bounty_amount, ctx.accounts.duplicate_question.key(), ctx.accounts.canonical_question.key());
+    Ok(())
+}
@@ -1,16 +1,37 @@
 pub mod accept_answer;
-pub mod accept_big_note_verification_application;
 pub mod accept_proposed_contribution;
+pub mod accept_wallet_rotation;
+pub mod add_challenge_to_track;
+pub mod add_forum_allowlist_entry;
 pub mod add_moderator;
 pub mod answer_question;
 pub mod apply_for_big_note_verification;
+pub mod approve_challenge_proposal;
+pub mod approve_wallet_rotation;
+pub mod archive_challenge;
 pub mod ask_question;
+pub mod attest_big_note_verification;
+pub mod claim_big_note_ownership;
+pub mod claim_handle;
+pub mod claim_question_ownership;
+pub mod claim_track_completion;
+pub mod clear_nft_pfp;
 pub mod close_account;
+pub mod close_challenge;
+pub mod close_challenge_leaderboard;
 pub mod close_forum;
+pub mod convert_question_to_big_note;
 pub mod create_about_me;
 pub mod create_big_note;
+pub mod create_category;
 pub mod create_challenge;
+pub mod create_challenge_leaderboard;
+pub mod create_challenge_prize_pool;
+pub mod create_challenge_prize_vault;
+pub mod create_content_link;
+pub mod create_evaluation_panel;
 pub mod create_submission;
+pub mod create_track;
 pub mod create_user_profile;
 pub mod delete_about_me;
 pub mod delete_answer;
@@ -19,14 +40,9 @@ pub mod delete_big_note_moderator;
 pub mod delete_big_note_verification_application;
 pub mod delete_big_note_verification_application_moderator;
 pub mod delete_challenge;
-pub mod delete_comment_on_answer;
-pub mod delete_comment_on_answer_moderator;
-pub mod delete_comment_on_big_note;
-pub mod delete_comment_on_big_note_moderator;
-pub mod delete_comment_on_proposed_contribution;
-pub mod delete_comment_on_proposed_contribution_moderator;
-pub mod delete_comment_on_question;
-pub mod delete_comment_on_question_moderator;
+pub mod delete_comment;
+pub mod delete_comment_moderator;
+pub mod delete_content_link;
 pub mod delete_proposed_contribution;
 pub mod delete_proposed_contribution_moderator;
 pub mod delete_question_moderator;
@@ -41,14 +57,8 @@ pub mod edit_big_note_creator_curated;
 pub mod edit_big_note_moderator;
 pub mod edit_big_note_open_contribution;
 pub mod edit_challenge;
-pub mod edit_comment_on_answer;
-pub mod edit_comment_on_answer_moderator;
-pub mod edit_comment_on_big_note;
-pub mod edit_comment_on_big_note_moderator;
-pub mod edit_comment_on_proposed_contribution;
-pub mod edit_comment_on_proposed_contribution_moderator;
-pub mod edit_comment_on_question;
-pub mod edit_comment_on_question_moderator;
+pub mod edit_comment;
+pub mod edit_comment_moderator;
 pub mod edit_proposed_contribution;
 pub mod edit_proposed_contribution_moderator;
 pub mod edit_question;
@@ -57,35 +67,87 @@ pub mod edit_submission;
 pub mod edit_submission_moderator;
 pub mod edit_user_profile;
 pub mod evaluate_submission;
+pub mod expire_big_note_verification;
+pub mod follow_user;
+pub mod fork_big_note;
+pub mod fund_challenge_prize_pool;
 pub mod init_forum;
-pub mod leave_comment_on_answer;
-pub mod leave_comment_on_big_note;
-pub mod leave_comment_on_proposed_contribution;
-pub mod leave_comment_on_question;
+pub mod leave_comment;
+pub mod mark_big_note_successor;
+pub mod mark_duplicate;
+pub mod migrate_duplicate_question_bounty;
 pub mod payout_from_treasury;
+pub mod peer_review_submission;
+pub mod propose_challenge;
 pub mod propose_contribution;
+pub mod recheck_profile_eligibility;
+pub mod reclaim_handle_moderator;
 pub mod refund_big_note_bounty_supplementor_moderator;
+pub mod refund_challenge_prize_contribution;
 pub mod refund_question_bounty_supplementor_moderator;
+pub mod refund_submission_fee;
+pub mod register_session_key;
 pub mod reject_big_note_verification_application;
+pub mod reject_challenge_proposal;
 pub mod reject_proposed_contribution;
+pub mod release_handle;
+pub mod remove_challenge_from_track;
+pub mod remove_forum_allowlist_entry;
 pub mod remove_moderator;
+pub mod resubmit;
+pub mod revoke_big_note_verification;
+pub mod revoke_session_key;
+pub mod score_submission;
+pub mod set_category_moderators;
+pub mod set_forum_access_policy;
+pub mod set_pfp_collection_allowlist;
+pub mod settle_peer_review;
+pub mod subscribe;
 pub mod supplement_big_note_bounty;
 pub mod supplement_question_bounty;
+pub mod transfer_big_note_ownership;
+pub mod transfer_handle;
+pub mod transfer_question_ownership;
+pub mod unfollow_user;
+pub mod unsubscribe;
+pub mod update_category;
 pub mod update_forum_params;
+pub mod withdraw_verification_attestation;
 
 pub use accept_answer::*;
-pub use accept_big_note_verification_application::*;
 pub use accept_proposed_contribution::*;
+pub use accept_wallet_rotation::*;
+pub use add_challenge_to_track::*;
+pub use add_forum_allowlist_entry::*;
 pub use add_moderator::*;
 pub use answer_question::*;
 pub use apply_for_big_note_verification::*;
+pub use approve_challenge_proposal::*;
+pub use approve_wallet_rotation::*;
+pub use archive_challenge::*;
 pub use ask_question::*;
+pub use attest_big_note_verification::*;
+pub use claim_big_note_ownership::*;
+pub use claim_handle::*;
+pub use claim_question_ownership::*;
+pub use claim_track_completion::*;
+pub use clear_nft_pfp::*;
 pub use close_account::*;
+pub use close_challenge::*;
+pub use close_challenge_leaderboard::*;
 pub use close_forum::*;
+pub use convert_question_to_big_note::*;
 pub use create_about_me::*;
 pub use create_big_note::*;
+pub use create_category::*;
 pub use create_challenge::*;
+pub use create_challenge_leaderboard::*;
+pub use create_challenge_prize_pool::*;
+pub use create_challenge_prize_vault::*;
+pub use create_content_link::*;
+pub use create_evaluation_panel::*;
 pub use create_submission::*;
+pub use create_track::*;
 pub use create_user_profile::*;
 pub use delete_about_me::*;
 pub use delete_answer::*;
@@ -94,14 +156,9 @@ pub use delete_big_note_moderator::*;
 pub use delete_big_note_verification_application::*;
 pub use delete_big_note_verification_application_moderator::*;
 pub use delete_challenge::*;
-pub use delete_comment_on_answer::*;
-pub use delete_comment_on_answer_moderator::*;
-pub use delete_comment_on_big_note::*;
-pub use delete_comment_on_big_note_moderator::*;
-pub use delete_comment_on_proposed_contribution::*;
-pub use delete_comment_on_proposed_contribution_moderator::*;
-pub use delete_comment_on_question::*;
-pub use delete_comment_on_question_moderator::*;
+pub use delete_comment::*;
+pub use delete_comment_moderator::*;
+pub use delete_content_link::*;
 pub use delete_proposed_contribution::*;
 pub use delete_proposed_contribution_moderator::*;
 pub use delete_question_moderator::*;
@@ -116,14 +173,8 @@ pub use edit_big_note_creator_curated::*;
 pub use edit_big_note_open_contribution::*;
 pub use edit_big_note_moderator::*;
 pub use edit_challenge::*;
-pub use edit_comment_on_answer::*;
-pub use edit_comment_on_answer_moderator::*;
-pub use edit_comment_on_big_note::*;
-pub use edit_comment_on_big_note_moderator::*;
-pub use edit_comment_on_proposed_contribution::*;
-pub use edit_comment_on_proposed_contribution_moderator::*;
-pub use edit_comment_on_question::*;
-pub use edit_comment_on_question_moderator::*;
+pub use edit_comment::*;
+pub use edit_comment_moderator::*;
 pub use edit_proposed_contribution::*;
 pub use edit_proposed_contribution_moderator::*;
 pub use edit_question::*;
@@ -132,18 +183,49 @@ pub use edit_submission::*;
 pub use edit_submission_moderator::*;
 pub use edit_user_profile::*;
 pub use evaluate_submission::*;
+pub use expire_big_note_verification::*;
+pub use follow_user::*;
+pub use fork_big_note::*;
+pub use fund_challenge_prize_pool::*;
 pub use init_forum::*;
-pub use leave_comment_on_answer::*;
-pub use leave_comment_on_big_note::*;
-pub use leave_comment_on_proposed_contribution::*;
-pub use leave_comment_on_question::*;
+pub use leave_comment::*;
+pub use mark_big_note_successor::*;
+pub use mark_duplicate::*;
+pub use migrate_duplicate_question_bounty::*;
 pub use payout_from_treasury::*;
+pub use peer_review_submission::*;
+pub use propose_challenge::*;
 pub use propose_contribution::*;
+pub use recheck_profile_eligibility::*;
+pub use reclaim_handle_moderator::*;
 pub use refund_big_note_bounty_supplementor_moderator::*;
+pub use refund_challenge_prize_contribution::*;
 pub use refund_question_bounty_supplementor_moderator::*;
+pub use refund_submission_fee::*;
+pub use register_session_key::*;
 pub use reject_big_note_verification_application::*;
+pub use reject_challenge_proposal::*;
 pub use reject_proposed_contribution::*;
+pub use release_handle::*;
+pub use remove_challenge_from_track::*;
+pub use remove_forum_allowlist_entry::*;
 pub use remove_moderator::*;
+pub use resubmit::*;
+pub use revoke_big_note_verification::*;
+pub use revoke_session_key::*;
+pub use score_submission::*;
+pub use set_category_moderators::*;
+pub use set_forum_access_policy::*;
+pub use set_pfp_collection_allowlist::*;
+pub use settle_peer_review::*;
+pub use subscribe::*;
 pub use supplement_big_note_bounty::*;
 pub use supplement_question_bounty::*;
+pub use transfer_big_note_ownership::*;
+pub use transfer_handle::*;
+pub use transfer_question_ownership::*;
+pub use unfollow_user::*;
+pub use unsubscribe::*;
+pub use update_category::*;
 pub use update_forum_params::*;
+pub use withdraw_verification_attestation::*;
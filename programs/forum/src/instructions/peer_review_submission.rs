@@ -0,0 +1,97 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{Challenge, Forum, PeerReview, Submission, SubmissionState, UserProfile};
+use prog_common::{now_ts, TryAdd, errors::ErrorCode};
+
+#[derive(Accounts)]
+#[instruction(bump_reviewer_profile: u8, bump_challenge: u8, bump_reviewer_submission: u8, bump_submission: u8)]
+pub struct PeerReviewSubmission<'info> {
+
+    // Forum
+    pub forum: Box<Account<'info, Forum>>,
+
+    #[account(mut)]
+    pub reviewer: Signer<'info>,
+
+    // The reviewer profile
+    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), reviewer_profile.profile_seed.as_ref()],
+              bump = bump_reviewer_profile, has_one = forum, constraint = reviewer_profile.profile_owner == reviewer.key())]
+    pub reviewer_profile: Box<Account<'info, UserProfile>>,
+
+    // Challenge PDA account and seed
+    #[account(seeds = [b"challenge".as_ref(), forum.key().as_ref(), challenge_seed.key().as_ref()],
+              bump = bump_challenge, has_one = forum, has_one = challenge_seed)]
+    pub challenge: Box<Account<'info, Challenge>>,
+
+    /// CHECK: The seed address used for initialization of the challenge PDA
+    pub challenge_seed: AccountInfo<'info>,
+
+    // The reviewer's own submission to the challenge
+    #[account(seeds = [b"submission".as_ref(), challenge.key().as_ref(), reviewer_profile.key().as_ref()],
+              bump = bump_reviewer_submission, has_one = challenge, constraint = reviewer_submission.user_profile == reviewer_profile.key())]
+    pub reviewer_submission: Box<Account<'info, Submission>>,
+
+    // Submission PDA account being reviewed
+    #[account(mut, seeds = [b"submission".as_ref(), challenge.key().as_ref(), submission.user_profile.as_ref()],
+              bump = bump_submission, has_one = challenge)]
+    pub submission: Box<Account<'info, Submission>>,
+
+    // Peer review PDA account (one per reviewer per submission attempt)
+    #[account(init, seeds = [b"peer_review".as_ref(), submission.key().as_ref(), reviewer_profile.key().as_ref(), submission.revision_count.to_le_bytes().as_ref()],
+              bump, payer = reviewer, space = 8 + std::mem::size_of::<PeerReview>())]
+    pub peer_review: Box<Account<'info, PeerReview>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn peer_review_submission(ctx: Context<PeerReviewSubmission>, verdict: SubmissionState) -> Result<()> {
+
+    let now_ts: u64 = now_ts()?;
+
+    // Ensure the reviewer profile has not been suspended from the forum
+    if ctx.accounts.reviewer_profile.is_suspended {
+        return Err(error!(ErrorCode::ProfileSuspended));
+    }
+
+    // Ensure the reviewer has completed the challenge and is not reviewing their own submission
+    if (ctx.accounts.reviewer_submission.submission_state != SubmissionState::Completed) ||
+        (ctx.accounts.submission.key() == ctx.accounts.reviewer_submission.key()) {
+        return Err(error!(ErrorCode::ProfileCannotPeerReview));
+    }
+
+    // Ensure the challenge's judging period has not ended
+    if !ctx.accounts.challenge.is_accepting_evaluations(now_ts) {
+        return Err(error!(ErrorCode::JudgingPeriodEnded));
+    }
+
+    // Ensure submission evaluation has not yet been finalized
+    if ctx.accounts.submission.submission_state != SubmissionState::Pending {
+        return Err(error!(ErrorCode::SubmissionAlreadyFinalized));
+    }
+
+    // Ensure verdict is either completed or rejected
+    if verdict == SubmissionState::Pending {
+        return Err(error!(ErrorCode::InvalidParameter));
+    }
+
+    // Record Peer Review's State
+    let peer_review = &mut ctx.accounts.peer_review;
+    peer_review.submission = ctx.accounts.submission.key();
+    peer_review.reviewer_profile = ctx.accounts.reviewer_profile.key();
+    peer_review.attempt_number = ctx.accounts.submission.revision_count;
+    peer_review.review_ts = now_ts;
+    peer_review.verdict = verdict;
+    peer_review.is_settled = false;
+
+    // Increment peer review count in submission's state account
+    let submission = &mut ctx.accounts.submission;
+    submission.peer_review_count.try_add_assign(1)?;
+
+    // Update the reviewer profile's state account
+    let reviewer_profile = &mut ctx.accounts.reviewer_profile;
+    reviewer_profile.most_recent_engagement_ts = now_ts;
+
+    msg!("Submission account with address {} peer reviewed with verdict {:?} by user profile with pubkey {}",
+         ctx.accounts.submission.key(), verdict, ctx.accounts.reviewer_profile.key());
+    Ok(())
+}
@@ -0,0 +1,188 @@
+use anchor_lang::prelude::*;
+
+use anchor_lang::solana_program::program::{invoke};
+use anchor_lang::solana_program::system_instruction;
+
+use crate::state::{Challenge, ChallengeProposal, ChallengeProposalState, ChallengeState, Category, Forum, MAX_RUBRIC_CRITERIA, RubricCriterion, Tags, UserProfile};
+use prog_common::{now_ts, TryAdd, errors::ErrorCode};
+
+#[derive(Accounts)]
+#[instruction(bump_user_profile: u8)]
+pub struct ProposeChallenge<'info> {
+
+    // Forum
+    pub forum: Box<Account<'info, Forum>>,
+
+    #[account(mut)]
+    pub profile_owner: Signer<'info>,
+
+    // The user profile
+    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), user_profile.profile_seed.as_ref()],
+              bump = bump_user_profile, has_one = forum, has_one = profile_owner)]
+    pub user_profile: Box<Account<'info, UserProfile>>,
+
+    // Category in which the challenge is created
+    #[account(has_one = forum)]
+    pub category: Box<Account<'info, Category>>,
+
+    /// CHECK:
+    #[account(mut)]
+    pub challenge: AccountInfo<'info>,
+
+    /// CHECK: The seed address used for initialization of the challenge PDA
+    pub challenge_seed: AccountInfo<'info>,
+
+    /// CHECK:
+    // The content data hash of the challenge struct
+    pub content_data_hash: AccountInfo<'info>,
+
+    // Challenge proposal PDA account (holds the proposal deposit until reviewed)
+    #[account(init, seeds = [b"challenge_proposal".as_ref(), challenge.key().as_ref()],
+              bump, payer = profile_owner, space = 8 + std::mem::size_of::<ChallengeProposal>())]
+    pub challenge_proposal: Box<Account<'info, ChallengeProposal>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ProposeChallenge<'info> {
+
+    fn transfer_deposit_ctx(&self, lamports: u64) -> Result<()> {
+        invoke(
+            &system_instruction::transfer(self.profile_owner.key, &self.challenge_proposal.key(), lamports),
+            &[
+                self.profile_owner.to_account_info(),
+                self.challenge_proposal.to_account_info(),
+                self.system_program.to_account_info(),
+            ],
+        )
+            .map_err(Into::into)
+    }
+}
+
+pub fn propose_challenge(ctx: Context<ProposeChallenge>, tags: Vec<Tags>, title: String, content_data_url: String, challenge_expires_ts: u64, reputation: u64, rubric: Vec<RubricCriterion>) -> Result<()> {
+
+    let now_ts: u64 = now_ts()?;
+
+    // Ensure the user profile has not been suspended from the forum
+    if ctx.accounts.user_profile.is_suspended {
+        return Err(error!(ErrorCode::ProfileSuspended));
+    }
+
+    // Ensure the user has enough reputation to propose a challenge
+    if ctx.accounts.user_profile.reputation_score < ctx.accounts.forum.forum_constants.min_challenge_proposal_rep {
+        return Err(error!(ErrorCode::InsufficientReputation));
+    }
+
+    // Ensure the challenge account has not already been created
+    if !ctx.accounts.challenge.data_is_empty() {
+        return Err(error!(ErrorCode::InvalidParameter));
+    }
+
+    // Record vector length of tags and character lengths of title and content_data_url to be added
+    let tags_length: u64 = tags.len() as u64;
+    let title_length: u64 = title.len() as u64;
+    let url_length: u64 = content_data_url.len() as u64;
+
+    let max_tags_length = ctx.accounts.forum.forum_constants.max_tags_length;
+    let max_title_length = ctx.accounts.forum.forum_constants.max_title_length;
+    let max_url_length = ctx.accounts.forum.forum_constants.max_url_length;
+
+    // Ensure that the length of tags vector is non-zero and not greater than max_tags_length
+    if (tags_length == 0) || (tags_length > max_tags_length){
+        return Err(error!(ErrorCode::InvalidTagsVectorInput));
+    }
+
+    // Ensure that the length of the title string is non-zero and not more than max_title_length characters long
+    if (title_length == 0) || (title_length > max_title_length) {
+        return Err(error!(ErrorCode::InvalidTitleStringInput));
+    }
+
+    // Ensure that the length of the content_data_url string is non-zero and not more than max_url_length characters long
+    if (url_length == 0) || (url_length > max_url_length) {
+        return Err(error!(ErrorCode::InvalidUrlStringInput));
+    }
+
+    // Ensure that the rubric does not exceed the maximum number of criteria and each criterion is non-zero
+    if (rubric.len() > MAX_RUBRIC_CRITERIA) || rubric.iter().any(|x| (x.max_score == 0) || (x.weight == 0)) {
+        return Err(error!(ErrorCode::InvalidRubric));
+    }
+
+    // Ensure challenge expires timestamp is greater than now timestamp
+    if challenge_expires_ts <= now_ts {
+        return Err(error!(ErrorCode::InvalidExpiryTs));
+    }
+
+    // Judging period ends a forum-configured period after the challenge expires
+    let judging_ends_ts: u64 = challenge_expires_ts.try_add(ctx.accounts.forum.forum_constants.challenge_judging_period)?;
+
+    // find bump - doing this program-side to reduce amount of info to be passed in (tx size)
+    let (_pk, bump) = Pubkey::find_program_address(
+        &[
+            b"challenge".as_ref(),
+            ctx.accounts.forum.key().as_ref(),
+            ctx.accounts.challenge_seed.key().as_ref()
+        ],
+        ctx.program_id,
+    );
+
+    // Record Challenge's State
+    let challenge = Challenge {
+        forum: ctx.accounts.forum.key(),
+        challenge_seed: ctx.accounts.challenge_seed.key(),
+        challenge_posted_ts: now_ts,
+        challenge_expires_ts,
+        tags,
+        title,
+        content_data_url,
+        content_data_hash: ctx.accounts.content_data_hash.key(),
+        reputation,
+        rubric,
+        challenge_state: ChallengeState::Open,
+        judging_ends_ts,
+        submission_count: 0,
+        completion_count: 0,
+        final_submission_count: 0,
+        final_completion_count: 0,
+        category: ctx.accounts.category.key(),
+    };
+
+    // Create the challenge PDA account and write the challenge into it
+    challenge.create_account(
+        &[
+            b"challenge".as_ref(),
+            ctx.accounts.forum.key().as_ref(),
+            ctx.accounts.challenge_seed.key().as_ref(),
+            &[bump],
+        ],
+        &ctx.accounts.challenge,
+        &ctx.accounts.profile_owner.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
+
+    msg!("Challenge PDA account with address {} now created", ctx.accounts.challenge.key());
+
+    // Transfer deposit for proposing challenge
+    let deposit_amount = ctx.accounts.category.fees(&ctx.accounts.forum.forum_fees).forum_challenge_proposal_deposit;
+
+    if deposit_amount > 0 {
+        ctx.accounts.transfer_deposit_ctx(deposit_amount)?;
+    }
+
+    // Record Challenge Proposal's State
+    let challenge_proposal = &mut ctx.accounts.challenge_proposal;
+    challenge_proposal.forum = ctx.accounts.forum.key();
+    challenge_proposal.challenge = ctx.accounts.challenge.key();
+    challenge_proposal.author_profile = ctx.accounts.user_profile.key();
+    challenge_proposal.proposal_posted_ts = now_ts;
+    challenge_proposal.proposal_reviewed_ts = 0;
+    challenge_proposal.deposit_amount = deposit_amount;
+    challenge_proposal.proposal_state = ChallengeProposalState::Pending;
+
+    // Update the user profile's state account
+    let user_profile = &mut ctx.accounts.user_profile;
+    user_profile.most_recent_engagement_ts = now_ts;
+
+    msg!("Challenge proposal PDA account with address {} now created", ctx.accounts.challenge_proposal.key());
+    Ok(())
+}
+
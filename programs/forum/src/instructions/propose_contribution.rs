@@ -1,31 +1,36 @@
 use anchor_lang::prelude::*;
 
-use crate::state::{BigNote, Forum, ProposedContribution, ProposedContributionState, UserProfile};
-use prog_common::{now_ts, TryAdd};
+use crate::state::{BigNote, Forum, ProposedContribution, ProposedContributionState, SessionScope, UserProfile};
+use prog_common::{now_ts, TryAdd, errors::ErrorCode};
 
 #[derive(Accounts)]
-#[instruction(bump_user_profile: u8)]
+#[instruction(bump_user_profile: u8, bump_session_key: u8)]
 pub struct ProposeContribution<'info> {
 
     // Forum
     #[account(mut)]
     pub forum: Box<Account<'info, Forum>>,
 
+    // The profile owner or a delegate holding a session key for the user profile
     #[account(mut)]
-    pub profile_owner: Signer<'info>,
+    pub authority: Signer<'info>,
 
     // The user profile
-    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), profile_owner.key().as_ref()],
-              bump = bump_user_profile, has_one = forum, has_one = profile_owner)]
+    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), user_profile.profile_seed.as_ref()],
+              bump = bump_user_profile, has_one = forum)]
     pub user_profile: Box<Account<'info, UserProfile>>,
 
+    /// CHECK: Session key PDA account of the signer (empty when the profile owner signs)
+    #[account(seeds = [b"session_key".as_ref(), user_profile.key().as_ref(), authority.key().as_ref()], bump = bump_session_key)]
+    pub session_key: AccountInfo<'info>,
+
     // Big Note PDA account
     #[account(mut, has_one = forum)]
     pub big_note: Box<Account<'info, BigNote>>,
 
     // Proposed Contribution PDA account and seed
     #[account(init, seeds = [b"proposed_contribution".as_ref(), forum.key().as_ref(), user_profile.key().as_ref(), proposed_contribution_seed.key().as_ref()],
-              bump, payer = profile_owner, space = 8 + std::mem::size_of::<ProposedContribution>())]
+              bump, payer = authority, space = 8 + std::mem::size_of::<ProposedContribution>())]
     pub proposed_contribution: Box<Account<'info, ProposedContribution>>,
 
     /// CHECK: The seed address used for initialization of the Proposed Contribution PDA
@@ -41,6 +46,15 @@ pub struct ProposeContribution<'info> {
 pub fn propose_contribution(ctx: Context<ProposeContribution>) -> Result<()> {
 
     let now_ts: u64 = now_ts()?;
+
+    // Ensure the user profile has not been suspended from the forum
+    if ctx.accounts.user_profile.is_suspended {
+        return Err(error!(ErrorCode::ProfileSuspended));
+    }
+
+    // Ensure the signer is the profile owner or a delegate with a valid session key
+    SessionScope::PostAndEdit.verify_authority(&ctx.accounts.user_profile.profile_owner, &ctx.accounts.authority.key(), &ctx.accounts.session_key, now_ts)?;
+
     let proposed_contribution_rep = ctx.accounts.forum.reputation_matrix.proposed_big_notes_contribution_rep;
 
     // Record Proposed_Contribution's State
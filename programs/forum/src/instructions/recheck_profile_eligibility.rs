@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{Forum, ForumAccessPolicy, UserProfile};
+use prog_common::{now_ts, TryAdd, errors::ErrorCode};
+
+#[derive(Accounts)]
+#[instruction(bump_forum_access_policy: u8, bump_user_profile: u8)]
+pub struct RecheckProfileEligibility<'info> {
+
+    // Forum
+    pub forum: Box<Account<'info, Forum>>,
+
+    // Anyone may recheck a profile's eligibility
+    pub cranker: Signer<'info>,
+
+    // Forum access policy PDA account
+    #[account(seeds = [b"forum_access_policy".as_ref(), forum.key().as_ref()],
+              bump = bump_forum_access_policy, has_one = forum)]
+    pub forum_access_policy: Box<Account<'info, ForumAccessPolicy>>,
+
+    // The user profile
+    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), user_profile.profile_seed.as_ref()],
+              bump = bump_user_profile, has_one = forum)]
+    pub user_profile: Box<Account<'info, UserProfile>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn recheck_profile_eligibility<'info>(ctx: Context<'_, '_, '_, 'info, RecheckProfileEligibility<'info>>) -> Result<()> {
+
+    let now_ts: u64 = now_ts()?;
+    let recheck_period: u64 = ctx.accounts.forum_access_policy.recheck_period;
+
+    // Proving eligibility (proof accounts are passed in as remaining accounts) reinstates the profile and restarts the recheck period
+    if ctx.accounts.forum_access_policy.is_eligible(&ctx.accounts.user_profile.profile_owner, ctx.remaining_accounts) {
+        let user_profile = &mut ctx.accounts.user_profile;
+        user_profile.eligibility_checked_ts = now_ts;
+        user_profile.is_suspended = false;
+
+        msg!("User profile with pubkey {} remains eligible", ctx.accounts.user_profile.key());
+        return Ok(());
+    }
+
+    // Otherwise the profile can only be suspended once its eligibility recheck is overdue
+    if (recheck_period == 0) || (now_ts <= ctx.accounts.user_profile.eligibility_checked_ts.try_add(recheck_period)?) {
+        return Err(error!(ErrorCode::EligibilityRecheckNotDue));
+    }
+
+    let user_profile = &mut ctx.accounts.user_profile;
+    user_profile.is_suspended = true;
+
+    msg!("User profile with pubkey {} suspended", ctx.accounts.user_profile.key());
+    Ok(())
+}
@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{Forum, Handle, UserProfile};
+use prog_common::{now_ts, close_account, errors::ErrorCode};
+
+#[derive(Accounts)]
+#[instruction(bump_moderator_profile: u8, bump_handle: u8)]
+pub struct ReclaimHandleModerator<'info> {
+
+    // Forum
+    pub forum: Box<Account<'info, Forum>>,
+
+    pub moderator: Signer<'info>,
+
+    // The moderator profile
+    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), moderator_profile.profile_seed.as_ref()],
+              bump = bump_moderator_profile, has_one = forum, constraint = moderator_profile.profile_owner == moderator.key())]
+    pub moderator_profile: Box<Account<'info, UserProfile>>,
+
+    // The user profile holding the handle
+    #[account(mut, has_one = forum)]
+    pub user_profile: Box<Account<'info, UserProfile>>,
+
+    // Handle PDA account
+    #[account(mut, seeds = [b"handle".as_ref(), forum.key().as_ref(), handle.handle_name.as_bytes()],
+              bump = bump_handle, has_one = forum, has_one = user_profile)]
+    pub handle: Box<Account<'info, Handle>>,
+
+    /// CHECK:
+    #[account(mut)]
+    pub receiver: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn reclaim_handle_moderator(ctx: Context<ReclaimHandleModerator>) -> Result<()> {
+
+    let now_ts: u64 = now_ts()?;
+
+    if !ctx.accounts.moderator_profile.is_moderator {
+        return Err(error!(ErrorCode::ProfileIsNotModerator));
+    }
+
+    // Close the handle state account so that the handle can be claimed again
+    let receiver = &mut ctx.accounts.receiver;
+    let handle_account_info = &mut (*ctx.accounts.handle).to_account_info();
+    close_account(handle_account_info, receiver)?;
+
+    // Update user profile's state account
+    let user_profile = &mut ctx.accounts.user_profile;
+    user_profile.has_handle = false;
+
+    // Update moderator profile's most recent engagement ts
+    let moderator_profile = &mut ctx.accounts.moderator_profile;
+    moderator_profile.most_recent_engagement_ts = now_ts;
+
+    msg!("Handle {} reclaimed from user profile with pubkey {} by moderator profile with pubkey {}",
+         ctx.accounts.handle.handle_name, ctx.accounts.user_profile.key(), ctx.accounts.moderator_profile.key());
+    Ok(())
+}
@@ -0,0 +1,115 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{Challenge, ChallengeLeaderboard, ChallengePrizePool, ChallengeState, Forum, PrizeContribution, PrizeDistribution, UserProfile};
+use prog_common::{now_ts, close_account, TryAdd, TryDiv, TryMul, TrySub, errors::ErrorCode};
+
+#[derive(Accounts)]
+#[instruction(bump_sponsor_profile: u8, bump_challenge: u8, bump_prize_pool: u8, bump_prize_contribution: u8, bump_leaderboard: u8)]
+pub struct RefundChallengePrizeContribution<'info> {
+
+    // Forum
+    pub forum: Box<Account<'info, Forum>>,
+
+    #[account(mut)]
+    pub sponsor: Signer<'info>,
+
+    // The sponsor profile
+    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), sponsor_profile.profile_seed.as_ref()],
+              bump = bump_sponsor_profile, has_one = forum, constraint = sponsor_profile.profile_owner == sponsor.key())]
+    pub sponsor_profile: Box<Account<'info, UserProfile>>,
+
+    // Challenge PDA account and seed
+    #[account(seeds = [b"challenge".as_ref(), forum.key().as_ref(), challenge_seed.key().as_ref()],
+              bump = bump_challenge, has_one = forum, has_one = challenge_seed)]
+    pub challenge: Box<Account<'info, Challenge>>,
+
+    /// CHECK: The seed address used for initialization of the challenge PDA
+    pub challenge_seed: AccountInfo<'info>,
+
+    // Challenge prize pool PDA account
+    #[account(mut, seeds = [b"challenge_prize_pool".as_ref(), challenge.key().as_ref()],
+              bump = bump_prize_pool, has_one = challenge)]
+    pub prize_pool: Box<Account<'info, ChallengePrizePool>>,
+
+    // Prize contribution PDA account
+    #[account(mut, seeds = [b"prize_contribution".as_ref(), prize_pool.key().as_ref(), sponsor_profile.key().as_ref()],
+              bump = bump_prize_contribution, has_one = prize_pool, has_one = sponsor_profile)]
+    pub prize_contribution: Box<Account<'info, PrizeContribution>>,
+
+    /// CHECK: The challenge leaderboard PDA account (empty if the challenge has no leaderboard)
+    #[account(seeds = [b"challenge_leaderboard".as_ref(), challenge.key().as_ref()], bump = bump_leaderboard)]
+    pub leaderboard: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+// If the prize pool is an SPL prize pool, the token accounts must be passed in as remaining accounts:
+// [prize vault, sponsor token account, token program]
+pub fn refund_challenge_prize_contribution<'info>(ctx: Context<'_, '_, '_, 'info, RefundChallengePrizeContribution<'info>>) -> Result<()> {
+
+    let now_ts: u64 = now_ts()?;
+
+    // Ensure challenge has been closed (prizes are still paid to submissions completed during the judging period)
+    let challenge_state = ctx.accounts.challenge.challenge_state;
+    if (challenge_state != ChallengeState::Closed) && (challenge_state != ChallengeState::Archived) {
+        return Err(error!(ErrorCode::ChallengeNotClosed));
+    }
+
+    // Ensure ranked tiers prizes have been paid out by closing the leaderboard
+    if ctx.accounts.prize_pool.prize_distribution == PrizeDistribution::RankedTiers {
+        let leaderboard_closed: bool = !ctx.accounts.leaderboard.data_is_empty() &&
+            Account::<ChallengeLeaderboard>::try_from(&ctx.accounts.leaderboard)?.is_closed;
+
+        if !leaderboard_closed {
+            return Err(error!(ErrorCode::LeaderboardNotClosed));
+        }
+    }
+
+    // Close prize payouts so that the remainder to be shared between sponsors is fixed
+    let prize_pool = &mut ctx.accounts.prize_pool;
+    prize_pool.payouts_closed = true;
+
+    // Calculate the sponsor's pro-rata share of the prize amount not paid out (the last sponsor receives any rounding remainder)
+    let contributed_amount: u64 = ctx.accounts.prize_contribution.contributed_amount;
+    let unpaid_amount: u64 = prize_pool.total_funded.try_sub(prize_pool.total_paid)?;
+
+    prize_pool.sponsor_count.try_sub_assign(1)?;
+
+    let refund_amount: u64 = if prize_pool.sponsor_count == 0 {
+        prize_pool.remaining_amount()?
+    } else {
+        contributed_amount.try_mul(unpaid_amount)?.try_div(prize_pool.total_funded)?
+    };
+
+    prize_pool.total_refunded.try_add_assign(refund_amount)?;
+
+    if prize_pool.is_spl() {
+        // Transfer the tokens from the prize vault to the sponsor
+        let prize_pool_account_info = prize_pool.to_account_info();
+        prize_pool.transfer_from_vault(&prize_pool_account_info, &ctx.accounts.sponsor.key(), ctx.remaining_accounts, refund_amount)?;
+    }
+    else {
+        // Manually transfer the lamports from the prize pool PDA to the sponsor
+        let prize_pool_account_info: &mut AccountInfo = &mut ctx.accounts.prize_pool.to_account_info();
+        let sponsor_account_info: &mut AccountInfo = &mut ctx.accounts.sponsor.to_account_info();
+
+        let prize_pool_lamports_initial = prize_pool_account_info.lamports();
+        let sponsor_lamports_initial = sponsor_account_info.lamports();
+
+        **prize_pool_account_info.lamports.borrow_mut() = prize_pool_lamports_initial.try_sub(refund_amount)?;
+        **sponsor_account_info.lamports.borrow_mut() = sponsor_lamports_initial.try_add(refund_amount)?;
+    }
+
+    // Close the prize contribution state account
+    let receiver = &mut ctx.accounts.sponsor.to_account_info();
+    let prize_contribution_account_info = &mut (*ctx.accounts.prize_contribution).to_account_info();
+    close_account(prize_contribution_account_info, receiver)?;
+
+    // Update the sponsor profile's state account
+    let sponsor_profile = &mut ctx.accounts.sponsor_profile;
+    sponsor_profile.most_recent_engagement_ts = now_ts;
+
+    msg!("Prize amount of {} refunded to sponsor profile with pubkey {}",
+         refund_amount, ctx.accounts.sponsor_profile.key());
+    Ok(())
+}
@@ -0,0 +1,77 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{Forum, Submission, SubmissionFeeEscrow, UserProfile};
+use prog_common::{now_ts, close_account, TrySub, errors::ErrorCode};
+
+#[derive(Accounts)]
+#[instruction(bump_user_profile: u8, bump_challenge: u8, bump_submission: u8, bump_submission_fee_escrow: u8)]
+pub struct RefundSubmissionFee<'info> {
+
+    // Forum
+    #[account(mut)]
+    pub forum: Box<Account<'info, Forum>>,
+
+    #[account(mut)]
+    pub profile_owner: Signer<'info>,
+
+    // The user profile
+    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), user_profile.profile_seed.as_ref()],
+              bump = bump_user_profile, has_one = forum, has_one = profile_owner)]
+    pub user_profile: Box<Account<'info, UserProfile>>,
+
+    /// CHECK: The deleted challenge PDA account
+    #[account(seeds = [b"challenge".as_ref(), forum.key().as_ref(), challenge_seed.key().as_ref()], bump = bump_challenge)]
+    pub challenge: AccountInfo<'info>,
+
+    /// CHECK: The seed address used for initialization of the challenge PDA
+    pub challenge_seed: AccountInfo<'info>,
+
+    #[account(mut, seeds = [b"submission".as_ref(), challenge.key().as_ref(), user_profile.key().as_ref()],
+              bump = bump_submission, has_one = challenge, has_one = user_profile)]
+    pub submission: Box<Account<'info, Submission>>,
+
+    /// CHECK: The submission fee escrow PDA account (empty once the submission has been evaluated)
+    #[account(mut, seeds = [b"submission_fee_escrow".as_ref(), submission.key().as_ref()], bump = bump_submission_fee_escrow)]
+    pub submission_fee_escrow: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+// Refunds the escrowed submission fee (if any) in full once the challenge has been deleted, closing the orphaned submission
+pub fn refund_submission_fee(ctx: Context<RefundSubmissionFee>) -> Result<()> {
+
+    let now_ts: u64 = now_ts()?;
+
+    // Ensure the challenge has been deleted
+    if !ctx.accounts.challenge.data_is_empty() {
+        return Err(error!(ErrorCode::InvalidParameter));
+    }
+
+    let profile_owner_account_info = &mut ctx.accounts.profile_owner.to_account_info();
+
+    // Close the submission fee escrow state account if the fee is still held in escrow
+    if !ctx.accounts.submission_fee_escrow.data_is_empty() {
+        Account::<SubmissionFeeEscrow>::try_from(&ctx.accounts.submission_fee_escrow)?;
+
+        let submission_fee_escrow_account_info = &mut ctx.accounts.submission_fee_escrow.to_account_info();
+        close_account(submission_fee_escrow_account_info, profile_owner_account_info)?;
+    }
+
+    // Close the submission state account
+    let submission_account_info = &mut (*ctx.accounts.submission).to_account_info();
+    close_account(submission_account_info, profile_owner_account_info)?;
+
+    // Decrement submission count in forum's state
+    let forum = &mut ctx.accounts.forum;
+    forum.forum_counts.forum_submission_count.try_sub_assign(1)?;
+
+    // Decrement submission count in user profile's state
+    let user_profile = &mut ctx.accounts.user_profile;
+    user_profile.challenges_submitted.try_sub_assign(1)?;
+
+    // Update user profile's most recent engagement ts
+    user_profile.most_recent_engagement_ts = now_ts;
+
+    msg!("Orphaned submission PDA account with address {} now closed", ctx.accounts.submission.key());
+    Ok(())
+}
@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{Forum, SessionKey, SessionScope, UserProfile};
+use prog_common::{now_ts, TryAdd, errors::ErrorCode};
+
+#[derive(Accounts)]
+#[instruction(bump_user_profile: u8)]
+pub struct RegisterSessionKey<'info> {
+
+    // Forum
+    pub forum: Box<Account<'info, Forum>>,
+
+    #[account(mut)]
+    pub profile_owner: Signer<'info>,
+
+    // The user profile
+    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), user_profile.profile_seed.as_ref()],
+              bump = bump_user_profile, has_one = forum, has_one = profile_owner)]
+    pub user_profile: Box<Account<'info, UserProfile>>,
+
+    /// CHECK: The delegate key allowed to sign on behalf of the user profile
+    pub delegate: AccountInfo<'info>,
+
+    // Session key PDA account
+    #[account(init, seeds = [b"session_key".as_ref(), user_profile.key().as_ref(), delegate.key().as_ref()],
+              bump, payer = profile_owner, space = 8 + std::mem::size_of::<SessionKey>())]
+    pub session_key: Box<Account<'info, SessionKey>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn register_session_key(ctx: Context<RegisterSessionKey>, session_scope: SessionScope, session_duration: u64) -> Result<()> {
+
+    let now_ts: u64 = now_ts()?;
+
+    // Ensure the session has a non-zero duration and the delegate is not the profile owner
+    if (session_duration == 0) || (ctx.accounts.delegate.key() == ctx.accounts.profile_owner.key()) {
+        return Err(error!(ErrorCode::InvalidParameter));
+    }
+
+    // Record Session Key's State
+    let session_key = &mut ctx.accounts.session_key;
+    session_key.user_profile = ctx.accounts.user_profile.key();
+    session_key.profile_owner = ctx.accounts.profile_owner.key();
+    session_key.delegate = ctx.accounts.delegate.key();
+    session_key.session_created_ts = now_ts;
+    session_key.expires_ts = now_ts.try_add(session_duration)?;
+    session_key.session_scope = session_scope;
+
+    // Update user profile's most recent engagement
+    let user_profile = &mut ctx.accounts.user_profile;
+    user_profile.most_recent_engagement_ts = now_ts;
+
+    msg!("Session key PDA account with address {} registered for delegate with address {}",
+         ctx.accounts.session_key.key(), ctx.accounts.delegate.key());
+    Ok(())
+}
@@ -0,0 +1,97 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{Category, Challenge, ChallengeProposal, ChallengeProposalState, Forum, UserProfile};
+use prog_common::{now_ts, TryAdd, TrySub, errors::ErrorCode};
+
+#[derive(Accounts)]
+#[instruction(bump_moderator_profile: u8, bump_author_profile: u8, bump_challenge: u8, bump_challenge_proposal: u8)]
+pub struct RejectChallengeProposal<'info> {
+
+    // Forum
+    #[account(has_one = forum_treasury)]
+    pub forum: Box<Account<'info, Forum>>,
+
+    pub moderator: Signer<'info>,
+
+    // The moderator profile
+    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), moderator_profile.profile_seed.as_ref()],
+              bump = bump_moderator_profile, has_one = forum, constraint = moderator_profile.profile_owner == moderator.key())]
+    pub moderator_profile: Box<Account<'info, UserProfile>>,
+
+    /// CHECK: Forum treasury account receiving a slashed deposit
+    #[account(mut)]
+    pub forum_treasury: AccountInfo<'info>,
+
+    /// CHECK: Used for seed verification of author profile pda account and receives a refunded deposit
+    #[account(mut)]
+    pub author: AccountInfo<'info>,
+
+    // The challenge author's profile
+    #[account(seeds = [b"user_profile".as_ref(), forum.key().as_ref(), author_profile.profile_seed.as_ref()],
+              bump = bump_author_profile, has_one = forum, constraint = author_profile.profile_owner == author.key())]
+    pub author_profile: Box<Account<'info, UserProfile>>,
+
+    // Challenge PDA account and seed
+    #[account(seeds = [b"challenge".as_ref(), forum.key().as_ref(), challenge_seed.key().as_ref()],
+              bump = bump_challenge, has_one = forum, has_one = challenge_seed)]
+    pub challenge: Box<Account<'info, Challenge>>,
+
+    // Category of the challenge, whose moderators may also moderate it
+    #[account(has_one = forum, constraint = category.key() == challenge.category)]
+    pub category: Box<Account<'info, Category>>,
+
+    /// CHECK: The seed address used for initialization of the challenge PDA
+    pub challenge_seed: AccountInfo<'info>,
+
+    // Challenge proposal PDA account
+    #[account(mut, seeds = [b"challenge_proposal".as_ref(), challenge.key().as_ref()],
+              bump = bump_challenge_proposal, has_one = forum, has_one = challenge, has_one = author_profile)]
+    pub challenge_proposal: Box<Account<'info, ChallengeProposal>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+// The deposit is refunded to the author unless slash_deposit is set, in which case it is sent to the forum treasury
+pub fn reject_challenge_proposal(ctx: Context<RejectChallengeProposal>, slash_deposit: bool) -> Result<()> {
+
+    let now_ts: u64 = now_ts()?;
+
+    if !ctx.accounts.moderator_profile.is_moderator && !ctx.accounts.category.is_category_moderator(&ctx.accounts.moderator_profile.key()) {
+        return Err(error!(ErrorCode::ProfileIsNotModerator));
+    }
+
+    // Ensure moderators do not review their own proposals
+    if ctx.accounts.author_profile.key() == ctx.accounts.moderator_profile.key() {
+        return Err(error!(ErrorCode::InvalidParameter));
+    }
+
+    // Ensure the proposal has not already been reviewed
+    if ctx.accounts.challenge_proposal.proposal_state != ChallengeProposalState::Pending {
+        return Err(error!(ErrorCode::ProposalAlreadyReviewed));
+    }
+
+    // Refund or slash the deposit
+    let deposit_amount: u64 = ctx.accounts.challenge_proposal.deposit_amount;
+
+    let challenge_proposal_account_info = ctx.accounts.challenge_proposal.to_account_info();
+    let receiver_account_info = match slash_deposit {
+        true => ctx.accounts.forum_treasury.to_account_info(),
+        false => ctx.accounts.author.to_account_info(),
+    };
+
+    **challenge_proposal_account_info.lamports.borrow_mut() = challenge_proposal_account_info.lamports().try_sub(deposit_amount)?;
+    **receiver_account_info.lamports.borrow_mut() = receiver_account_info.lamports().try_add(deposit_amount)?;
+
+    // Update the challenge proposal's state account
+    let challenge_proposal = &mut ctx.accounts.challenge_proposal;
+    challenge_proposal.proposal_reviewed_ts = now_ts;
+    challenge_proposal.deposit_amount = 0;
+    challenge_proposal.proposal_state = ChallengeProposalState::Rejected;
+
+    // Update the moderator profile's state account
+    let moderator_profile = &mut ctx.accounts.moderator_profile;
+    moderator_profile.most_recent_engagement_ts = now_ts;
+
+    msg!("Challenge proposal PDA account with address {} now rejected with deposit slashed: {}", ctx.accounts.challenge_proposal.key(), slash_deposit);
+    Ok(())
+}
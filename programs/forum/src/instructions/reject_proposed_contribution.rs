@@ -1,7 +1,7 @@
 use anchor_lang::prelude::*;
 
 use crate::state::{BigNote, Forum, ProposedContribution, ProposedContributionState, UserProfile};
-use prog_common::{now_ts};
+use prog_common::{now_ts, errors::ErrorCode};
 
 #[derive(Accounts)]
 #[instruction(bump_user_profile: u8, bump_big_note: u8, bump_proposal_user_profile: u8, bump_proposed_contribution: u8)]
@@ -13,13 +13,13 @@ pub struct RejectProposedContribution<'info> {
     pub profile_owner: Signer<'info>,
 
     // The user profile
-    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), profile_owner.key().as_ref()],
+    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), user_profile.profile_seed.as_ref()],
               bump = bump_user_profile, has_one = forum, has_one = profile_owner)]
     pub user_profile: Box<Account<'info, UserProfile>>,
 
     // Big Note PDA account and seed
-    #[account(mut, seeds = [b"big_note".as_ref(), forum.key().as_ref(), user_profile.key().as_ref(), big_note_seed.key().as_ref()],
-              bump = bump_big_note, has_one = forum, has_one = user_profile, has_one = big_note_seed)]
+    #[account(mut, seeds = [b"big_note".as_ref(), forum.key().as_ref(), big_note.user_profile.as_ref(), big_note_seed.key().as_ref()],
+              bump = bump_big_note, has_one = forum, has_one = big_note_seed)]
     pub big_note: Box<Account<'info, BigNote>>,
 
     /// CHECK: The seed address used for initialization of the big note PDA
@@ -29,7 +29,7 @@ pub struct RejectProposedContribution<'info> {
     pub proposal_profile_owner: AccountInfo<'info>,
 
     // The user profile
-    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), proposal_profile_owner.key().as_ref()],
+    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), proposal_user_profile.profile_seed.as_ref()],
               bump = bump_proposal_user_profile, has_one = forum, constraint = proposal_user_profile.profile_owner == proposal_profile_owner.key())]
     pub proposal_user_profile: Box<Account<'info, UserProfile>>,
 
@@ -48,6 +48,16 @@ pub fn reject_proposed_contribution(ctx:Context<RejectProposedContribution>) ->
 
     let now_ts = now_ts()?;
 
+    // Ensure the user profile has not been suspended from the forum
+    if ctx.accounts.user_profile.is_suspended {
+        return Err(error!(ErrorCode::ProfileSuspended));
+    }
+
+    // Ensure the signer's profile is the current owner of the big note
+    if ctx.accounts.big_note.current_owner != ctx.accounts.user_profile.key() {
+        return Err(error!(ErrorCode::NotCurrentOwner));
+    }
+
     // Update big note account's state
     let big_note = &mut ctx.accounts.big_note;
     big_note.most_recent_engagement_ts = now_ts;
@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{Forum, Handle, UserProfile};
+use prog_common::{now_ts, close_account};
+
+#[derive(Accounts)]
+#[instruction(bump_user_profile: u8, bump_handle: u8)]
+pub struct ReleaseHandle<'info> {
+
+    // Forum
+    pub forum: Box<Account<'info, Forum>>,
+
+    #[account(mut)]
+    pub profile_owner: Signer<'info>,
+
+    // The user profile
+    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), user_profile.profile_seed.as_ref()],
+              bump = bump_user_profile, has_one = forum, has_one = profile_owner)]
+    pub user_profile: Box<Account<'info, UserProfile>>,
+
+    // Handle PDA account
+    #[account(mut, seeds = [b"handle".as_ref(), forum.key().as_ref(), handle.handle_name.as_bytes()],
+              bump = bump_handle, has_one = forum, has_one = user_profile)]
+    pub handle: Box<Account<'info, Handle>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn release_handle(ctx: Context<ReleaseHandle>) -> Result<()> {
+
+    let now_ts: u64 = now_ts()?;
+
+    // Close the handle state account
+    let receiver = &mut ctx.accounts.profile_owner.to_account_info();
+    let handle_account_info = &mut (*ctx.accounts.handle).to_account_info();
+    close_account(handle_account_info, receiver)?;
+
+    // Update user profile's state account
+    let user_profile = &mut ctx.accounts.user_profile;
+    user_profile.has_handle = false;
+    user_profile.most_recent_engagement_ts = now_ts;
+
+    msg!("Handle {} released by user profile with pubkey {}",
+         ctx.accounts.handle.handle_name, ctx.accounts.user_profile.key());
+    Ok(())
+}
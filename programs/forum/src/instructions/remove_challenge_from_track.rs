@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{Forum, Track, TrackMembership, UserProfile};
+use prog_common::{now_ts, close_account, TryAdd, TrySub, errors::ErrorCode};
+
+#[derive(Accounts)]
+#[instruction(bump_moderator_profile: u8, bump_track: u8, bump_track_membership: u8)]
+pub struct RemoveChallengeFromTrack<'info> {
+
+    // Forum
+    pub forum: Box<Account<'info, Forum>>,
+
+    pub moderator: Signer<'info>,
+
+    // The moderator profile
+    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), moderator_profile.profile_seed.as_ref()],
+              bump = bump_moderator_profile, has_one = forum, constraint = moderator_profile.profile_owner == moderator.key())]
+    pub moderator_profile: Box<Account<'info, UserProfile>>,
+
+    // Track PDA account and seed
+    #[account(mut, seeds = [b"track".as_ref(), forum.key().as_ref(), track_seed.key().as_ref()],
+              bump = bump_track, has_one = forum, has_one = track_seed)]
+    pub track: Box<Account<'info, Track>>,
+
+    /// CHECK: The seed address used for initialization of the track PDA
+    pub track_seed: AccountInfo<'info>,
+
+    // Track membership PDA account
+    #[account(mut, seeds = [b"track_membership".as_ref(), track_membership.challenge.as_ref()],
+              bump = bump_track_membership, has_one = track)]
+    pub track_membership: Box<Account<'info, TrackMembership>>,
+
+    /// CHECK:
+    #[account(mut)]
+    pub receiver: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+// Only the last challenge of a track can be removed, so the prerequisites of the remaining challenges are kept intact
+pub fn remove_challenge_from_track(ctx: Context<RemoveChallengeFromTrack>) -> Result<()> {
+
+    let now_ts: u64 = now_ts()?;
+
+    if !ctx.accounts.moderator_profile.is_moderator {
+        return Err(error!(ErrorCode::ProfileIsNotModerator));
+    }
+
+    let track_position: u64 = ctx.accounts.track_membership.track_position;
+
+    if track_position.try_add(1)? != ctx.accounts.track.challenge_count {
+        return Err(error!(ErrorCode::ChallengeNotLastInTrack));
+    }
+
+    // Update the track's state account
+    let track = &mut ctx.accounts.track;
+    track.challenges[track_position as usize] = Pubkey::default();
+    track.challenge_count.try_sub_assign(1)?;
+
+    // Close the track membership state account
+    let receiver = &mut ctx.accounts.receiver;
+    let track_membership_account_info = &mut (*ctx.accounts.track_membership).to_account_info();
+    close_account(track_membership_account_info, receiver)?;
+
+    // Update the moderator profile's state account
+    let moderator_profile = &mut ctx.accounts.moderator_profile;
+    moderator_profile.most_recent_engagement_ts = now_ts;
+
+    msg!("Challenge with address {} removed from track with address {}",
+         ctx.accounts.track_membership.challenge, ctx.accounts.track.key());
+    Ok(())
+}
@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{Forum, ForumAllowlistEntry};
+use prog_common::{close_account};
+
+#[derive(Accounts)]
+#[instruction(bump_forum_allowlist_entry: u8)]
+pub struct RemoveForumAllowlistEntry<'info> {
+
+    // Forum and Forum Manager
+    #[account(has_one = forum_manager)]
+    pub forum: Box<Account<'info, Forum>>,
+
+    #[account(mut)]
+    pub forum_manager: Signer<'info>,
+
+    // Forum allowlist entry PDA account
+    #[account(mut, seeds = [b"forum_allowlist_entry".as_ref(), forum.key().as_ref(), forum_allowlist_entry.wallet.as_ref()],
+              bump = bump_forum_allowlist_entry, has_one = forum)]
+    pub forum_allowlist_entry: Box<Account<'info, ForumAllowlistEntry>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn remove_forum_allowlist_entry(ctx: Context<RemoveForumAllowlistEntry>) -> Result<()> {
+
+    // Close the forum allowlist entry state account
+    let receiver = &mut ctx.accounts.forum_manager.to_account_info();
+    let forum_allowlist_entry_account_info = &mut (*ctx.accounts.forum_allowlist_entry).to_account_info();
+    close_account(forum_allowlist_entry_account_info, receiver)?;
+
+    msg!("Wallet with address {} removed from the allowlist of forum {}",
+         ctx.accounts.forum_allowlist_entry.wallet, ctx.accounts.forum.key());
+    Ok(())
+}
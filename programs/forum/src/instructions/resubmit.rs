@@ -0,0 +1,146 @@
+use anchor_lang::prelude::*;
+
+use anchor_lang::solana_program::program::{invoke};
+use anchor_lang::solana_program::system_instruction;
+
+use crate::state::{Category, Challenge, Forum, Submission, SubmissionAttempt, SubmissionFeeEscrow, SubmissionState, UserProfile};
+use prog_common::{now_ts, TryAdd, errors::ErrorCode};
+
+#[derive(Accounts)]
+#[instruction(bump_user_profile: u8, bump_challenge: u8, bump_submission: u8)]
+pub struct Resubmit<'info> {
+
+    // Forum
+    pub forum: Box<Account<'info, Forum>>,
+
+    #[account(mut)]
+    pub profile_owner: Signer<'info>,
+
+    // The user profile
+    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), user_profile.profile_seed.as_ref()],
+              bump = bump_user_profile, has_one = forum, has_one = profile_owner)]
+    pub user_profile: Box<Account<'info, UserProfile>>,
+
+    // Challenge PDA account and seed
+    #[account(seeds = [b"challenge".as_ref(), forum.key().as_ref(), challenge_seed.key().as_ref()],
+              bump = bump_challenge, has_one = forum, has_one = challenge_seed)]
+    pub challenge: Box<Account<'info, Challenge>>,
+
+    // Category of the challenge, whose fee overrides apply
+    #[account(has_one = forum, constraint = category.key() == challenge.category)]
+    pub category: Box<Account<'info, Category>>,
+
+    /// CHECK: The seed address used for initialization of the challenge PDA
+    pub challenge_seed: AccountInfo<'info>,
+
+    // Submission PDA account
+    #[account(mut, seeds = [b"submission".as_ref(), challenge.key().as_ref(), user_profile.key().as_ref()],
+              bump = bump_submission, has_one = challenge, has_one = user_profile)]
+    pub submission: Box<Account<'info, Submission>>,
+
+    // Submission attempt PDA account recording the rejected attempt
+    #[account(init, seeds = [b"submission_attempt".as_ref(), submission.key().as_ref(), submission.revision_count.to_le_bytes().as_ref()],
+              bump, payer = profile_owner, space = 8 + std::mem::size_of::<SubmissionAttempt>())]
+    pub submission_attempt: Box<Account<'info, SubmissionAttempt>>,
+
+    // Submission fee escrow PDA account (holds the submission fee for the new attempt until it is evaluated)
+    #[account(init, seeds = [b"submission_fee_escrow".as_ref(), submission.key().as_ref()],
+              bump, payer = profile_owner, space = 8 + std::mem::size_of::<SubmissionFeeEscrow>())]
+    pub submission_fee_escrow: Box<Account<'info, SubmissionFeeEscrow>>,
+
+    /// CHECK:
+    // The content data hash of the new attempt
+    pub content_data_hash: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> Resubmit<'info> {
+
+    fn transfer_payment_ctx(&self, lamports: u64) -> Result<()> {
+        invoke(
+            &system_instruction::transfer(self.profile_owner.key, &self.submission_fee_escrow.key(), lamports),
+            &[
+                self.profile_owner.to_account_info(),
+                self.submission_fee_escrow.to_account_info(),
+                self.system_program.to_account_info(),
+            ],
+        )
+            .map_err(Into::into)
+    }
+}
+
+pub fn resubmit(ctx: Context<Resubmit>) -> Result<()> {
+
+    let now_ts: u64 = now_ts()?;
+
+    // Ensure the user profile has not been suspended from the forum
+    if ctx.accounts.user_profile.is_suspended {
+        return Err(error!(ErrorCode::ProfileSuspended));
+    }
+
+    // Ensure challenge expires timestamp has not yet passed
+    if now_ts > ctx.accounts.challenge.challenge_expires_ts {
+        return Err(error!(ErrorCode::ChallengeExpired));
+    }
+
+    // Ensure only rejected submissions are resubmitted
+    if ctx.accounts.submission.submission_state != SubmissionState::Rejected {
+        return Err(error!(ErrorCode::SubmissionNotRejected));
+    }
+
+    // Ensure the maximum number of attempts has not been reached
+    let attempt_number: u64 = ctx.accounts.submission.revision_count;
+    let max_submission_attempts: u64 = ctx.accounts.forum.forum_constants.max_submission_attempts;
+
+    if attempt_number.try_add(1)? >= max_submission_attempts {
+        return Err(error!(ErrorCode::MaxSubmissionAttemptsReached));
+    }
+
+    // Record Submission Attempt's State (history of the rejected attempt)
+    let submission_attempt = &mut ctx.accounts.submission_attempt;
+    submission_attempt.submission = ctx.accounts.submission.key();
+    submission_attempt.attempt_number = attempt_number;
+    submission_attempt.attempt_posted_ts = ctx.accounts.submission.submission_posted_ts;
+    submission_attempt.content_data_hash = ctx.accounts.submission.content_data_hash;
+    submission_attempt.approval_votes = ctx.accounts.submission.approval_votes;
+    submission_attempt.rejection_votes = ctx.accounts.submission.rejection_votes;
+    submission_attempt.total_score = ctx.accounts.submission.total_score;
+    submission_attempt.score_count = ctx.accounts.submission.score_count;
+    submission_attempt.submission_state = ctx.accounts.submission.submission_state;
+
+    // Move the submission back to pending with the new content
+    let submission = &mut ctx.accounts.submission;
+    submission.submission_posted_ts = now_ts;
+    submission.most_recent_engagement_ts = now_ts;
+
+    submission.content_data_hash = ctx.accounts.content_data_hash.key();
+    submission.submission_state = SubmissionState::Pending;
+    submission.approval_votes = 0;
+    submission.rejection_votes = 0;
+    submission.total_score = 0;
+    submission.score_count = 0;
+    submission.peer_review_count = 0;
+    submission.revision_count.try_add_assign(1)?;
+
+    // Transfer fee for making submission into escrow
+    let submission_fee = ctx.accounts.category.fees(&ctx.accounts.forum.forum_fees).forum_challenge_submission_fee;
+
+    if submission_fee > 0 {
+        ctx.accounts.transfer_payment_ctx(submission_fee)?;
+    }
+
+    // Record Submission Fee Escrow's State
+    let submission_fee_escrow = &mut ctx.accounts.submission_fee_escrow;
+    submission_fee_escrow.submission = ctx.accounts.submission.key();
+    submission_fee_escrow.user_profile = ctx.accounts.user_profile.key();
+    submission_fee_escrow.fee_amount = submission_fee;
+
+    // Update user profile's most recent engagement ts
+    let user_profile = &mut ctx.accounts.user_profile;
+    user_profile.most_recent_engagement_ts = now_ts;
+
+    msg!("Submission PDA account with address {} now resubmitted as revision {}",
+         ctx.accounts.submission.key(), ctx.accounts.submission.revision_count);
+    Ok(())
+}
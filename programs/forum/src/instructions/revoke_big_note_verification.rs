@@ -0,0 +1,127 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{BigNote, BigNoteVerificationRecord, BigNoteVerificationState, Forum, UserProfile, VerificationAttestation};
+use prog_common::{now_ts, TryAdd, TrySub, errors::ErrorCode};
+
+#[derive(Accounts)]
+#[instruction(bump_moderator_profile: u8, bump_big_note: u8, bump_verification_record: u8)]
+pub struct RevokeBigNoteVerification<'info> {
+
+    // Forum
+    #[account(has_one = forum_treasury)]
+    pub forum: Box<Account<'info, Forum>>,
+
+    /// CHECK: Forum treasury account receiving slashed attestation stakes
+    #[account(mut)]
+    pub forum_treasury: AccountInfo<'info>,
+
+    pub moderator: Signer<'info>,
+
+    // The moderator profile
+    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), moderator_profile.profile_seed.as_ref()],
+              bump = bump_moderator_profile, has_one = forum, constraint = moderator_profile.profile_owner == moderator.key())]
+    pub moderator_profile: Box<Account<'info, UserProfile>>,
+
+    // The big note creator's profile
+    #[account(mut, has_one = forum)]
+    pub user_profile: Box<Account<'info, UserProfile>>,
+
+    // Big Note pda account and seed
+    #[account(mut, seeds = [b"big_note".as_ref(), forum.key().as_ref(), user_profile.key().as_ref(), big_note_seed.key().as_ref()],
+              bump = bump_big_note, has_one = forum, has_one = user_profile, has_one = big_note_seed)]
+    pub big_note: Box<Account<'info, BigNote>>,
+
+    /// CHECK: The seed address used for initialization of the big note PDA
+    pub big_note_seed: AccountInfo<'info>,
+
+    // Big Note Verification Record PDA account
+    #[account(seeds = [b"verification_record".as_ref(), big_note.key().as_ref()],
+              bump = bump_verification_record, has_one = big_note)]
+    pub verification_record: Box<Account<'info, BigNoteVerificationRecord>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+// If slash_attesters is set, the attestation accounts to be slashed must be passed in as remaining accounts
+pub fn revoke_big_note_verification<'info>(ctx: Context<'_, '_, '_, 'info, RevokeBigNoteVerification<'info>>, slash_attesters: bool) -> Result<()> {
+
+    let now_ts: u64 = now_ts()?;
+
+    if !ctx.accounts.moderator_profile.is_moderator {
+        return Err(error!(ErrorCode::ProfileIsNotModerator));
+    }
+
+    // Ensure moderators do not revoke verification of their own big notes
+    if ctx.accounts.user_profile.key() == ctx.accounts.moderator_profile.key() {
+        return Err(error!(ErrorCode::InvalidParameter));
+    }
+
+    // Ensure that big note account's verification state is 'Verified' or 'NeedsReverification' (attestations remain slashable),
+    // or that the attestations of a superseded verified round are still slashable
+    let old_verification_state = ctx.accounts.big_note.verification_state;
+    let is_verified: bool = (old_verification_state == BigNoteVerificationState::Verified) ||
+        (old_verification_state == BigNoteVerificationState::NeedsReverification);
+
+    let verification_round: u64 = ctx.accounts.verification_record.verification_round;
+    let slashable_round: u64 = ctx.accounts.verification_record.slashable_round;
+    let superseded_round_slashable: bool = (slashable_round < verification_round) &&
+        ctx.accounts.verification_record.is_superseded_round_slashable(slashable_round, now_ts);
+
+    if !is_verified && !superseded_round_slashable {
+        return Err(error!(ErrorCode::BigNoteNotVerified));
+    }
+
+    // Slash the stakes of the attestations which verified the big note
+    if slash_attesters {
+        let forum_treasury_account_info: &mut AccountInfo = &mut ctx.accounts.forum_treasury.to_account_info();
+
+        for attestation_account_info in ctx.remaining_accounts.iter() {
+            let mut verification_attestation = Account::<VerificationAttestation>::try_from(attestation_account_info)?;
+
+            let attestation_round: u64 = verification_attestation.verification_round;
+            let attestation_slashable: bool = (is_verified && (attestation_round == verification_round)) ||
+                (superseded_round_slashable && (attestation_round == slashable_round));
+
+            if (verification_attestation.big_note != ctx.accounts.big_note.key()) || !attestation_slashable ||
+                verification_attestation.is_slashed {
+                return Err(error!(ErrorCode::InvalidAttestationAccounts));
+            }
+
+            let stake_amount: u64 = verification_attestation.stake_amount;
+
+            **attestation_account_info.lamports.borrow_mut() = attestation_account_info.lamports().try_sub(stake_amount)?;
+            **forum_treasury_account_info.lamports.borrow_mut() = forum_treasury_account_info.lamports().try_add(stake_amount)?;
+
+            verification_attestation.is_slashed = true;
+            verification_attestation.exit(&crate::ID)?;
+
+            msg!("Attestation by profile with pubkey {} slashed by {}", verification_attestation.attester_profile, stake_amount);
+        }
+    }
+
+    // Claw back the verification reputation (already clawed back once the big note needs reverification)
+    if old_verification_state == BigNoteVerificationState::Verified {
+        let big_note_verification_rep: u64 = ctx.accounts.big_note.big_note_verification_rep;
+
+        let user_profile = &mut ctx.accounts.user_profile;
+        user_profile.big_notes_verified.try_sub_assign(1)?;
+        user_profile.reputation_score.try_sub_assign(big_note_verification_rep)?;
+    }
+
+    // Update big note account's verification state (a pending application for reverification is left in place)
+    let big_note = &mut ctx.accounts.big_note;
+    big_note.most_recent_engagement_ts = now_ts;
+
+    if is_verified {
+        big_note.verification_state = BigNoteVerificationState::Unverified;
+        big_note.big_note_verification_rep = 0;
+    }
+
+    // Update moderator profile account's most recent engagement timestamp
+    let moderator_profile = &mut ctx.accounts.moderator_profile;
+    moderator_profile.most_recent_engagement_ts = now_ts;
+
+    msg!("Big Note PDA account with address {} verification revoked by moderator profile with pubkey {}",
+         ctx.accounts.big_note.key(), ctx.accounts.moderator_profile.key());
+    Ok(())
+}
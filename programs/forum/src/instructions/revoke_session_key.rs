@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{Forum, SessionKey, UserProfile};
+use prog_common::{now_ts, close_account};
+
+#[derive(Accounts)]
+#[instruction(bump_user_profile: u8, bump_session_key: u8)]
+pub struct RevokeSessionKey<'info> {
+
+    // Forum
+    pub forum: Box<Account<'info, Forum>>,
+
+    #[account(mut)]
+    pub profile_owner: Signer<'info>,
+
+    // The user profile
+    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), user_profile.profile_seed.as_ref()],
+              bump = bump_user_profile, has_one = forum, has_one = profile_owner)]
+    pub user_profile: Box<Account<'info, UserProfile>>,
+
+    // Session key PDA account
+    #[account(mut, seeds = [b"session_key".as_ref(), user_profile.key().as_ref(), session_key.delegate.as_ref()],
+              bump = bump_session_key, has_one = user_profile)]
+    pub session_key: Box<Account<'info, SessionKey>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn revoke_session_key(ctx: Context<RevokeSessionKey>) -> Result<()> {
+
+    let now_ts: u64 = now_ts()?;
+
+    // Close the session key state account
+    let receiver = &mut ctx.accounts.profile_owner.to_account_info();
+    let session_key_account_info = &mut (*ctx.accounts.session_key).to_account_info();
+    close_account(session_key_account_info, receiver)?;
+
+    // Update user profile's most recent engagement
+    let user_profile = &mut ctx.accounts.user_profile;
+    user_profile.most_recent_engagement_ts = now_ts;
+
+    msg!("Session key PDA account with address {} revoked", ctx.accounts.session_key.key());
+    Ok(())
+}
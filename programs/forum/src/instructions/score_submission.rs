@@ -0,0 +1,126 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{Challenge, ChallengeLeaderboard, EvaluationPanel, Forum, ScoreCard, Submission, SubmissionState, UserProfile};
+use prog_common::{now_ts, TryAdd, TryDiv, TryMul, errors::ErrorCode};
+
+#[derive(Accounts)]
+#[instruction(bump_judge_profile: u8, bump_challenge: u8, bump_evaluation_panel: u8, bump_submission: u8, bump_leaderboard: u8)]
+pub struct ScoreSubmission<'info> {
+
+    // Forum
+    pub forum: Box<Account<'info, Forum>>,
+
+    #[account(mut)]
+    pub judge: Signer<'info>,
+
+    // The judge profile
+    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), judge_profile.profile_seed.as_ref()],
+              bump = bump_judge_profile, has_one = forum, constraint = judge_profile.profile_owner == judge.key())]
+    pub judge_profile: Box<Account<'info, UserProfile>>,
+
+    // Challenge PDA account and seed
+    #[account(seeds = [b"challenge".as_ref(), forum.key().as_ref(), challenge_seed.key().as_ref()],
+              bump = bump_challenge, has_one = forum, has_one = challenge_seed)]
+    pub challenge: Box<Account<'info, Challenge>>,
+
+    /// CHECK: The seed address used for initialization of the challenge PDA
+    pub challenge_seed: AccountInfo<'info>,
+
+    // Evaluation panel PDA account
+    #[account(seeds = [b"evaluation_panel".as_ref(), challenge.key().as_ref()],
+              bump = bump_evaluation_panel, has_one = challenge)]
+    pub evaluation_panel: Box<Account<'info, EvaluationPanel>>,
+
+    // Submission PDA account
+    #[account(mut, seeds = [b"submission".as_ref(), challenge.key().as_ref(), submission.user_profile.as_ref()],
+              bump = bump_submission, has_one = challenge)]
+    pub submission: Box<Account<'info, Submission>>,
+
+    // Score card PDA account (one per judge per submission attempt)
+    #[account(init, seeds = [b"score_card".as_ref(), submission.key().as_ref(), judge_profile.key().as_ref(), submission.revision_count.to_le_bytes().as_ref()],
+              bump, payer = judge, space = 8 + std::mem::size_of::<ScoreCard>())]
+    pub score_card: Box<Account<'info, ScoreCard>>,
+
+    // Challenge leaderboard PDA account
+    #[account(mut, seeds = [b"challenge_leaderboard".as_ref(), challenge.key().as_ref()],
+              bump = bump_leaderboard, has_one = challenge)]
+    pub leaderboard: Box<Account<'info, ChallengeLeaderboard>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn score_submission(ctx: Context<ScoreSubmission>, scores: Vec<u64>) -> Result<()> {
+
+    let now_ts: u64 = now_ts()?;
+
+    // Ensure the judge profile has not been suspended from the forum
+    if ctx.accounts.judge_profile.is_suspended {
+        return Err(error!(ErrorCode::ProfileSuspended));
+    }
+
+    let rubric = &ctx.accounts.challenge.rubric;
+
+    // Ensure the challenge's judging period has not ended
+    if !ctx.accounts.challenge.is_accepting_evaluations(now_ts) {
+        return Err(error!(ErrorCode::JudgingPeriodEnded));
+    }
+
+    // Ensure judge profile is on the challenge's evaluation panel and is not scoring their own submission
+    if !ctx.accounts.evaluation_panel.is_judge(&ctx.accounts.judge_profile.key()) ||
+        (ctx.accounts.judge_profile.key() == ctx.accounts.submission.user_profile) {
+        return Err(error!(ErrorCode::ProfileIsNotJudge));
+    }
+
+    // Ensure only completed submissions are scored (pending submissions may still be rejected or resubmitted)
+    if ctx.accounts.submission.submission_state != SubmissionState::Completed {
+        return Err(error!(ErrorCode::SubmissionNotCompleted));
+    }
+
+    // Ensure the leaderboard has not been closed
+    if ctx.accounts.leaderboard.is_closed {
+        return Err(error!(ErrorCode::LeaderboardAlreadyClosed));
+    }
+
+    // Ensure a score is given for every rubric criterion and no score exceeds its criterion's maximum
+    if rubric.is_empty() || (scores.len() != rubric.len()) {
+        return Err(error!(ErrorCode::InvalidRubricScores));
+    }
+
+    // Calculate the weighted total of the rubric scores
+    let mut weighted_score: u64 = 0;
+    for (criterion, &score) in rubric.iter().zip(scores.iter()) {
+        if score > criterion.max_score {
+            return Err(error!(ErrorCode::InvalidRubricScores));
+        }
+        weighted_score.try_add_assign(score.try_mul(criterion.weight)?)?;
+    }
+
+    // Record Score Card's State
+    let score_card = &mut ctx.accounts.score_card;
+    score_card.submission = ctx.accounts.submission.key();
+    score_card.judge_profile = ctx.accounts.judge_profile.key();
+    score_card.score_card_posted_ts = now_ts;
+    score_card.weighted_score = weighted_score;
+
+    // Update the submission's total score
+    let submission = &mut ctx.accounts.submission;
+    submission.total_score.try_add_assign(weighted_score)?;
+    submission.score_count.try_add_assign(1)?;
+    submission.most_recent_engagement_ts = now_ts;
+
+    let average_score: u64 = submission.total_score.try_div(submission.score_count)?;
+    let submission_key: Pubkey = submission.key();
+    let user_profile_key: Pubkey = submission.user_profile;
+
+    // Update the submission's rank on the leaderboard
+    let leaderboard = &mut ctx.accounts.leaderboard;
+    leaderboard.record_score(submission_key, user_profile_key, average_score);
+
+    // Update the judge profile's state account
+    let judge_profile = &mut ctx.accounts.judge_profile;
+    judge_profile.most_recent_engagement_ts = now_ts;
+
+    msg!("Submission account with address {} scored {} by judge profile with pubkey {}",
+         ctx.accounts.submission.key(), weighted_score, ctx.accounts.judge_profile.key());
+    Ok(())
+}
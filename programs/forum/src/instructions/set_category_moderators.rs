@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{Category, Forum, MAX_CATEGORY_MODERATORS};
+use prog_common::{errors::ErrorCode};
+
+#[derive(Accounts)]
+pub struct SetCategoryModerators<'info> {
+
+    // Forum and Forum Manager
+    #[account(has_one = forum_manager)]
+    pub forum: Box<Account<'info, Forum>>,
+
+    pub forum_manager: Signer<'info>,
+
+    // Category PDA account
+    #[account(mut, has_one = forum)]
+    pub category: Box<Account<'info, Category>>,
+}
+
+pub fn set_category_moderators(ctx: Context<SetCategoryModerators>, moderator_profiles: Vec<Pubkey>) -> Result<()> {
+
+    // Ensure the category moderators fit (an empty list leaves the category to the forum moderators)
+    if moderator_profiles.len() > MAX_CATEGORY_MODERATORS {
+        return Err(error!(ErrorCode::TooManyCategoryModerators));
+    }
+
+    // Record the user profiles moderating the category
+    let category = &mut ctx.accounts.category;
+    category.category_moderators = [Pubkey::default(); MAX_CATEGORY_MODERATORS];
+    category.category_moderators[..moderator_profiles.len()].copy_from_slice(&moderator_profiles);
+    category.moderator_count = moderator_profiles.len() as u64;
+
+    msg!("Category {} now has {} category moderators", ctx.accounts.category.key(), ctx.accounts.category.moderator_count);
+    Ok(())
+}
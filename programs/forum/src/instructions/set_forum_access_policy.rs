@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{AccessPolicyType, Forum, ForumAccessPolicy};
+
+#[derive(Accounts)]
+pub struct SetForumAccessPolicy<'info> {
+
+    // Forum and Forum Manager
+    #[account(has_one = forum_manager)]
+    pub forum: Box<Account<'info, Forum>>,
+
+    #[account(mut)]
+    pub forum_manager: Signer<'info>,
+
+    // Forum access policy PDA account
+    #[account(init_if_needed, seeds = [b"forum_access_policy".as_ref(), forum.key().as_ref()],
+              bump, payer = forum_manager, space = 8 + std::mem::size_of::<ForumAccessPolicy>())]
+    pub forum_access_policy: Box<Account<'info, ForumAccessPolicy>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn set_forum_access_policy(ctx: Context<SetForumAccessPolicy>, access_policy_type: AccessPolicyType, access_mint: Pubkey, min_token_amount: u64, recheck_period: u64) -> Result<()> {
+
+    // Record Forum Access Policy's State
+    let forum_access_policy = &mut ctx.accounts.forum_access_policy;
+    forum_access_policy.forum = ctx.accounts.forum.key();
+    forum_access_policy.access_policy_type = access_policy_type;
+    forum_access_policy.access_mint = access_mint;
+    forum_access_policy.min_token_amount = min_token_amount;
+    forum_access_policy.recheck_period = recheck_period;
+
+    msg!("Forum access policy now {:?}", ctx.accounts.forum_access_policy);
+    Ok(())
+}
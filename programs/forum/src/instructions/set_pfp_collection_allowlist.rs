@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{Forum, PfpCollectionAllowlist, MAX_PFP_COLLECTIONS};
+use prog_common::{errors::ErrorCode};
+
+#[derive(Accounts)]
+pub struct SetPfpCollectionAllowlist<'info> {
+
+    // Forum and Forum Manager
+    #[account(has_one = forum_manager)]
+    pub forum: Box<Account<'info, Forum>>,
+
+    #[account(mut)]
+    pub forum_manager: Signer<'info>,
+
+    // PFP collection allowlist PDA account
+    #[account(init_if_needed, seeds = [b"pfp_collection_allowlist".as_ref(), forum.key().as_ref()],
+              bump, payer = forum_manager, space = 8 + std::mem::size_of::<PfpCollectionAllowlist>())]
+    pub pfp_collection_allowlist: Box<Account<'info, PfpCollectionAllowlist>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn set_pfp_collection_allowlist(ctx: Context<SetPfpCollectionAllowlist>, collections: Vec<Pubkey>) -> Result<()> {
+
+    // Ensure the allowlist fits (an empty allowlist allows any NFT to be set as PFP)
+    if collections.len() > MAX_PFP_COLLECTIONS {
+        return Err(error!(ErrorCode::TooManyPfpCollections));
+    }
+
+    // Record PFP Collection Allowlist's State
+    let pfp_collection_allowlist = &mut ctx.accounts.pfp_collection_allowlist;
+    pfp_collection_allowlist.forum = ctx.accounts.forum.key();
+    pfp_collection_allowlist.collections = [Pubkey::default(); MAX_PFP_COLLECTIONS];
+    pfp_collection_allowlist.collections[..collections.len()].copy_from_slice(&collections);
+    pfp_collection_allowlist.collection_count = collections.len() as u64;
+
+    msg!("PFP collection allowlist of forum {} now has {} collections",
+         ctx.accounts.forum.key(), ctx.accounts.pfp_collection_allowlist.collection_count);
+    Ok(())
+}
@@ -0,0 +1,70 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{Forum, PeerReview, Submission, SubmissionAttempt, SubmissionState, UserProfile};
+use prog_common::{TryAdd, errors::ErrorCode};
+
+#[derive(Accounts)]
+#[instruction(bump_submission_attempt: u8)]
+pub struct SettlePeerReview<'info> {
+
+    // Forum
+    pub forum: Box<Account<'info, Forum>>,
+
+    // Anyone can settle a peer review once the submission has been finalized
+    pub cranker: Signer<'info>,
+
+    // The reviewer profile
+    #[account(mut, has_one = forum)]
+    pub reviewer_profile: Box<Account<'info, UserProfile>>,
+
+    // Submission PDA account reviewed
+    pub submission: Box<Account<'info, Submission>>,
+
+    // Peer review PDA account
+    #[account(mut, has_one = submission, has_one = reviewer_profile)]
+    pub peer_review: Box<Account<'info, PeerReview>>,
+
+    /// CHECK: Submission attempt PDA account of the reviewed attempt (empty if the reviewed attempt is the current one)
+    #[account(seeds = [b"submission_attempt".as_ref(), submission.key().as_ref(), peer_review.attempt_number.to_le_bytes().as_ref()],
+              bump = bump_submission_attempt)]
+    pub submission_attempt: AccountInfo<'info>,
+}
+
+pub fn settle_peer_review(ctx: Context<SettlePeerReview>) -> Result<()> {
+
+    // Ensure the peer review has not already been settled
+    if ctx.accounts.peer_review.is_settled {
+        return Err(error!(ErrorCode::PeerReviewAlreadySettled));
+    }
+
+    // Take the final state of the reviewed attempt (recorded in its submission attempt account once resubmitted)
+    let submission_state = if ctx.accounts.peer_review.attempt_number == ctx.accounts.submission.revision_count {
+        ctx.accounts.submission.submission_state
+    } else {
+        Account::<SubmissionAttempt>::try_from(&ctx.accounts.submission_attempt)?.submission_state
+    };
+
+    // Ensure the reviewed attempt has been finalized
+    if submission_state == SubmissionState::Pending {
+        return Err(error!(ErrorCode::SubmissionNotFinalized));
+    }
+
+    let verdict_matches: bool = ctx.accounts.peer_review.verdict == submission_state;
+
+    // Update the peer review's state account
+    let peer_review = &mut ctx.accounts.peer_review;
+    peer_review.is_settled = true;
+
+    // Reward the reviewer if their verdict matches the final decision, otherwise penalize them (without going below zero)
+    let reputation_matrix = ctx.accounts.forum.reputation_matrix;
+    let reviewer_profile = &mut ctx.accounts.reviewer_profile;
+
+    if verdict_matches {
+        reviewer_profile.reputation_score.try_add_assign(reputation_matrix.peer_review_match_rep)?;
+    } else {
+        reviewer_profile.reputation_score = reviewer_profile.reputation_score.saturating_sub(reputation_matrix.peer_review_mismatch_penalty);
+    }
+
+    msg!("Peer review PDA account with address {} settled with matching verdict: {}", ctx.accounts.peer_review.key(), verdict_matches);
+    Ok(())
+}
@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{Forum, Subscription, SubscriptionTarget, UserProfile};
+use prog_common::{now_ts, errors::ErrorCode};
+
+#[derive(Accounts)]
+#[instruction(bump_user_profile: u8)]
+pub struct Subscribe<'info> {
+
+    // Forum
+    pub forum: Box<Account<'info, Forum>>,
+
+    #[account(mut)]
+    pub profile_owner: Signer<'info>,
+
+    // The user profile
+    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), user_profile.profile_seed.as_ref()],
+              bump = bump_user_profile, has_one = forum, has_one = profile_owner)]
+    pub user_profile: Box<Account<'info, UserProfile>>,
+
+    /// CHECK: Question/BigNote/Challenge PDA account, verified against the subscription target
+    pub subscribed_to: AccountInfo<'info>,
+
+    // Subscription PDA account
+    #[account(init, seeds = [b"subscription".as_ref(), user_profile.key().as_ref(), subscribed_to.key().as_ref()],
+              bump, payer = profile_owner, space = 8 + std::mem::size_of::<Subscription>())]
+    pub subscription: Box<Account<'info, Subscription>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn subscribe(ctx: Context<Subscribe>, subscription_target: SubscriptionTarget) -> Result<()> {
+
+    let now_ts: u64 = now_ts()?;
+
+    // Ensure the user profile has not been suspended from the forum
+    if ctx.accounts.user_profile.is_suspended {
+        return Err(error!(ErrorCode::ProfileSuspended));
+    }
+
+    // Ensure the subscribed to account matches the subscription target and belongs to the forum
+    subscription_target.verify_subscribed_to(&ctx.accounts.subscribed_to, &ctx.accounts.forum.key())?;
+
+    // Record Subscription's State
+    let subscription = &mut ctx.accounts.subscription;
+    subscription.subscribed_to = ctx.accounts.subscribed_to.key();
+    subscription.subscriber_profile = ctx.accounts.user_profile.key();
+    subscription.subscribed_ts = now_ts;
+    subscription.subscription_target = subscription_target;
+
+    // Update user profile's most recent engagement
+    let user_profile = &mut ctx.accounts.user_profile;
+    user_profile.most_recent_engagement_ts = now_ts;
+
+    msg!("User profile with pubkey {} subscribed to account with address {}",
+         ctx.accounts.user_profile.key(), ctx.accounts.subscribed_to.key());
+    Ok(())
+}
@@ -3,7 +3,7 @@ use anchor_lang::prelude::*;
 use anchor_lang::solana_program::program::{invoke};
 use anchor_lang::solana_program::system_instruction;
 
-use crate::state::{BigNote, BountyContribution, BountyContributionState, Forum, UserProfile};
+use crate::state::{BigNote, BountyContribution, BountyContributionState, Category, Forum, UserProfile};
 use prog_common::{now_ts, TryAdd, TrySub, TryDiv, TryMul, errors::ErrorCode};
 
 #[derive(Accounts)]
@@ -22,7 +22,7 @@ pub struct SupplementBigNoteBounty<'info> {
     pub supplementor: Signer<'info>,
 
     // The supplementor profile
-    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), supplementor.key().as_ref()],
+    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), supplementor_profile.profile_seed.as_ref()],
               bump = bump_supplementor_profile, has_one = forum, constraint = supplementor_profile.profile_owner == supplementor.key())]
     pub supplementor_profile: Box<Account<'info, UserProfile>>,
 
@@ -30,7 +30,7 @@ pub struct SupplementBigNoteBounty<'info> {
     pub profile_owner: AccountInfo<'info>,
 
     // The user profile
-    #[account(seeds = [b"user_profile".as_ref(), forum.key().as_ref(), profile_owner.key().as_ref()],
+    #[account(seeds = [b"user_profile".as_ref(), forum.key().as_ref(), user_profile.profile_seed.as_ref()],
               bump = bump_user_profile, has_one = forum, has_one = profile_owner)]
     pub user_profile: Box<Account<'info, UserProfile>>,
 
@@ -39,6 +39,10 @@ pub struct SupplementBigNoteBounty<'info> {
               bump = bump_big_note, has_one = forum, has_one = user_profile, has_one = big_note_seed)]
     pub big_note: Box<Account<'info, BigNote>>,
 
+    // Category of the big note, whose fee overrides apply
+    #[account(has_one = forum, constraint = category.key() == big_note.category)]
+    pub category: Box<Account<'info, Category>>,
+
     /// CHECK: The seed address used for initialization of the big note PDA
     pub big_note_seed: AccountInfo<'info>,
 
@@ -92,7 +96,13 @@ pub fn supplement_big_note_bounty(ctx: Context<SupplementBigNoteBounty>, supplem
 
     let now_ts: u64 = now_ts()?;
 
-    let forum_big_notes_bounty_minimum: u64 = ctx.accounts.forum.forum_fees.forum_big_notes_bounty_minimum;
+    // Ensure the supplementor profile has not been suspended from the forum
+    if ctx.accounts.supplementor_profile.is_suspended {
+        return Err(error!(ErrorCode::ProfileSuspended));
+    }
+
+    let category_fees = ctx.accounts.category.fees(&ctx.accounts.forum.forum_fees);
+    let forum_big_notes_bounty_minimum: u64 = category_fees.forum_big_notes_bounty_minimum;
     let bounty_contribution_rep: u64 = ctx.accounts.forum.reputation_matrix.bounty_contribution_rep;
 
     // Ensure minimum bounty amount is contributed
@@ -101,7 +111,7 @@ pub fn supplement_big_note_bounty(ctx: Context<SupplementBigNoteBounty>, supplem
     }
 
     // Transfer fee for supplementing big note
-    let forum_big_notes_submission_fee = ctx.accounts.forum.forum_fees.forum_big_notes_submission_fee;
+    let forum_big_notes_submission_fee = category_fees.forum_big_notes_submission_fee;
 
     if forum_big_notes_submission_fee > 0 {
         let bounty_bps_remainder = supplemental_bounty_amount % 10000;
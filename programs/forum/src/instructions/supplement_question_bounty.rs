@@ -3,7 +3,7 @@ use anchor_lang::prelude::*;
 use anchor_lang::solana_program::program::{invoke};
 use anchor_lang::solana_program::system_instruction;
 
-use crate::state::{BountyContribution, BountyContributionState, Forum, Question, UserProfile};
+use crate::state::{BountyContribution, BountyContributionState, Category, Forum, Question, UserProfile};
 use prog_common::{now_ts, TryAdd, TrySub, TryDiv, TryMul, errors::ErrorCode};
 
 #[derive(Accounts)]
@@ -22,7 +22,7 @@ pub struct SupplementQuestionBounty<'info> {
     pub supplementor: Signer<'info>,
 
     // The supplementor profile
-    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), supplementor.key().as_ref()],
+    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), supplementor_profile.profile_seed.as_ref()],
               bump = bump_supplementor_profile, has_one = forum, constraint = supplementor_profile.profile_owner == supplementor.key())]
     pub supplementor_profile: Box<Account<'info, UserProfile>>,
 
@@ -30,7 +30,7 @@ pub struct SupplementQuestionBounty<'info> {
     pub profile_owner: AccountInfo<'info>,
 
     // The user profile
-    #[account(seeds = [b"user_profile".as_ref(), forum.key().as_ref(), profile_owner.key().as_ref()],
+    #[account(seeds = [b"user_profile".as_ref(), forum.key().as_ref(), user_profile.profile_seed.as_ref()],
               bump = bump_user_profile, has_one = forum, has_one = profile_owner)]
     pub user_profile: Box<Account<'info, UserProfile>>,
 
@@ -39,6 +39,10 @@ pub struct SupplementQuestionBounty<'info> {
               bump = bump_question, has_one = forum, has_one = user_profile, has_one = question_seed)]
     pub question: Box<Account<'info, Question>>,
 
+    // Category of the question, whose fee overrides apply
+    #[account(has_one = forum, constraint = category.key() == question.category)]
+    pub category: Box<Account<'info, Category>>,
+
     /// CHECK: The seed address used for initialization of the question PDA
     pub question_seed: AccountInfo<'info>,
 
@@ -92,7 +96,13 @@ pub fn supplement_question_bounty(ctx: Context<SupplementQuestionBounty>, supple
 
     let now_ts: u64 = now_ts()?;
 
-    let forum_question_bounty_minimum: u64 = ctx.accounts.forum.forum_fees.forum_question_bounty_minimum;
+    // Ensure the supplementor profile has not been suspended from the forum
+    if ctx.accounts.supplementor_profile.is_suspended {
+        return Err(error!(ErrorCode::ProfileSuspended));
+    }
+
+    let category_fees = ctx.accounts.category.fees(&ctx.accounts.forum.forum_fees);
+    let forum_question_bounty_minimum: u64 = category_fees.forum_question_bounty_minimum;
     let bounty_contribution_rep: u64 = ctx.accounts.forum.reputation_matrix.bounty_contribution_rep;
 
     // Ensure Bounty has not yet been awarded
@@ -101,13 +111,18 @@ pub fn supplement_question_bounty(ctx: Context<SupplementQuestionBounty>, supple
         return Err(error!(ErrorCode::BountyAlreadyAwarded));
     }
 
+    // Ensure question has not been marked as a duplicate
+    if ctx.accounts.question.duplicate_of != Pubkey::default() {
+        return Err(error!(ErrorCode::QuestionMarkedAsDuplicate));
+    }
+
     // Ensure minimum bounty amount is contributed
     if supplemental_bounty_amount < forum_question_bounty_minimum {
         return Err(error!(ErrorCode::InvalidBountyAmount));
     }
 
     // Transfer fee for supplementing question
-    let forum_question_fee = ctx.accounts.forum.forum_fees.forum_question_fee;
+    let forum_question_fee = category_fees.forum_question_fee;
 
     if forum_question_fee > 0 {
         let bounty_bps_remainder = supplemental_bounty_amount % 10000;
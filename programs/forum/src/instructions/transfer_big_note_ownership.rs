@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{Forum, BigNote, UserProfile};
+use prog_common::{now_ts, errors::ErrorCode};
+
+#[derive(Accounts)]
+#[instruction(bump_user_profile: u8, bump_big_note: u8)]
+pub struct TransferBigNoteOwnership<'info> {
+
+    // Forum
+    pub forum: Box<Account<'info, Forum>>,
+
+    pub profile_owner: Signer<'info>,
+
+    // The user profile of the current owner
+    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), user_profile.profile_seed.as_ref()],
+              bump = bump_user_profile, has_one = forum, has_one = profile_owner)]
+    pub user_profile: Box<Account<'info, UserProfile>>,
+
+    // Big Note pda account and seed
+    #[account(mut, seeds = [b"big_note".as_ref(), forum.key().as_ref(), big_note.user_profile.as_ref(), big_note_seed.key().as_ref()],
+              bump = bump_big_note, has_one = forum, has_one = big_note_seed)]
+    pub big_note: Box<Account<'info, BigNote>>,
+
+    /// CHECK: The seed address used for initialization of the big note PDA
+    pub big_note_seed: AccountInfo<'info>,
+
+    // The user profile nominated to take over ownership
+    #[account(has_one = forum)]
+    pub new_owner_profile: Box<Account<'info, UserProfile>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn transfer_big_note_ownership(ctx: Context<TransferBigNoteOwnership>) -> Result<()> {
+
+    let now_ts: u64 = now_ts()?;
+
+    // Ensure the user profile has not been suspended from the forum
+    if ctx.accounts.user_profile.is_suspended {
+        return Err(error!(ErrorCode::ProfileSuspended));
+    }
+
+    // Ensure the signer's profile is the current owner of the big note
+    if ctx.accounts.big_note.current_owner != ctx.accounts.user_profile.key() {
+        return Err(error!(ErrorCode::NotCurrentOwner));
+    }
+
+    // Nominate the new owner (nominating the current owner cancels a pending transfer)
+    let new_owner_profile_key: Pubkey = ctx.accounts.new_owner_profile.key();
+    let big_note = &mut ctx.accounts.big_note;
+
+    big_note.pending_owner = if new_owner_profile_key == big_note.current_owner {
+        Pubkey::default()
+    } else {
+        new_owner_profile_key
+    };
+
+    // Update user profile's most recent engagement
+    let user_profile = &mut ctx.accounts.user_profile;
+    user_profile.most_recent_engagement_ts = now_ts;
+
+    msg!("Ownership of big note PDA account with address {} offered to user profile with pubkey {}",
+         ctx.accounts.big_note.key(), ctx.accounts.big_note.pending_owner);
+    Ok(())
+}
@@ -0,0 +1,68 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{Forum, Handle, UserProfile};
+use prog_common::{now_ts, errors::ErrorCode};
+
+#[derive(Accounts)]
+#[instruction(bump_user_profile: u8, bump_handle: u8, bump_new_user_profile: u8)]
+pub struct TransferHandle<'info> {
+
+    // Forum
+    pub forum: Box<Account<'info, Forum>>,
+
+    pub profile_owner: Signer<'info>,
+
+    // The user profile
+    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), user_profile.profile_seed.as_ref()],
+              bump = bump_user_profile, has_one = forum, has_one = profile_owner)]
+    pub user_profile: Box<Account<'info, UserProfile>>,
+
+    // The owner of the user profile receiving the handle must also sign, accepting the handle
+    pub new_profile_owner: Signer<'info>,
+
+    // The user profile receiving the handle
+    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), new_user_profile.profile_seed.as_ref()],
+              bump = bump_new_user_profile, has_one = forum, constraint = new_user_profile.profile_owner == new_profile_owner.key(),
+              constraint = new_user_profile.key() != user_profile.key())]
+    pub new_user_profile: Box<Account<'info, UserProfile>>,
+
+    // Handle PDA account
+    #[account(mut, seeds = [b"handle".as_ref(), forum.key().as_ref(), handle.handle_name.as_bytes()],
+              bump = bump_handle, has_one = forum, has_one = user_profile)]
+    pub handle: Box<Account<'info, Handle>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn transfer_handle(ctx: Context<TransferHandle>) -> Result<()> {
+
+    let now_ts: u64 = now_ts()?;
+
+    // Ensure the user profile has not been suspended from the forum
+    if ctx.accounts.user_profile.is_suspended {
+        return Err(error!(ErrorCode::ProfileSuspended));
+    }
+
+    // Ensure the receiving user profile does not already have a handle
+    if ctx.accounts.new_user_profile.has_handle {
+        return Err(error!(ErrorCode::ProfileAlreadyHasHandle));
+    }
+
+    // Point the handle to the receiving user profile
+    let handle = &mut ctx.accounts.handle;
+    handle.user_profile = ctx.accounts.new_user_profile.key();
+    handle.handle_claimed_ts = now_ts;
+
+    let new_user_profile = &mut ctx.accounts.new_user_profile;
+    new_user_profile.has_handle = true;
+    new_user_profile.most_recent_engagement_ts = now_ts;
+
+    // Update user profile's state account
+    let user_profile = &mut ctx.accounts.user_profile;
+    user_profile.has_handle = false;
+    user_profile.most_recent_engagement_ts = now_ts;
+
+    msg!("Handle {} transferred to user profile with pubkey {}",
+         ctx.accounts.handle.handle_name, ctx.accounts.new_user_profile.key());
+    Ok(())
+}
@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{Forum, Question, UserProfile};
+use prog_common::{now_ts, errors::ErrorCode};
+
+#[derive(Accounts)]
+#[instruction(bump_user_profile: u8, bump_question: u8)]
+pub struct TransferQuestionOwnership<'info> {
+
+    // Forum
+    pub forum: Box<Account<'info, Forum>>,
+
+    pub profile_owner: Signer<'info>,
+
+    // The user profile of the current owner
+    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), user_profile.profile_seed.as_ref()],
+              bump = bump_user_profile, has_one = forum, has_one = profile_owner)]
+    pub user_profile: Box<Account<'info, UserProfile>>,
+
+    // Question PDA account and seed
+    #[account(mut, seeds = [b"question".as_ref(), forum.key().as_ref(), question.user_profile.as_ref(), question_seed.key().as_ref()],
+              bump = bump_question, has_one = forum, has_one = question_seed)]
+    pub question: Box<Account<'info, Question>>,
+
+    /// CHECK: The seed address used for initialization of the question PDA
+    pub question_seed: AccountInfo<'info>,
+
+    // The user profile nominated to take over ownership
+    #[account(has_one = forum)]
+    pub new_owner_profile: Box<Account<'info, UserProfile>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn transfer_question_ownership(ctx: Context<TransferQuestionOwnership>) -> Result<()> {
+
+    let now_ts: u64 = now_ts()?;
+
+    // Ensure the user profile has not been suspended from the forum
+    if ctx.accounts.user_profile.is_suspended {
+        return Err(error!(ErrorCode::ProfileSuspended));
+    }
+
+    // Ensure the signer's profile is the current owner of the question
+    if ctx.accounts.question.current_owner != ctx.accounts.user_profile.key() {
+        return Err(error!(ErrorCode::NotCurrentOwner));
+    }
+
+    // Nominate the new owner (nominating the current owner cancels a pending transfer)
+    let new_owner_profile_key: Pubkey = ctx.accounts.new_owner_profile.key();
+    let question = &mut ctx.accounts.question;
+
+    question.pending_owner = if new_owner_profile_key == question.current_owner {
+        Pubkey::default()
+    } else {
+        new_owner_profile_key
+    };
+
+    // Update user profile's most recent engagement
+    let user_profile = &mut ctx.accounts.user_profile;
+    user_profile.most_recent_engagement_ts = now_ts;
+
+    msg!("Ownership of question PDA account with address {} offered to user profile with pubkey {}",
+         ctx.accounts.question.key(), ctx.accounts.question.pending_owner);
+    Ok(())
+}
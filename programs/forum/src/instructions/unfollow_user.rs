@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{Follow, Forum, UserProfile};
+use prog_common::{now_ts, close_account, TrySub, errors::ErrorCode};
+
+#[derive(Accounts)]
+#[instruction(bump_user_profile: u8, bump_follow: u8)]
+pub struct UnfollowUser<'info> {
+
+    // Forum
+    pub forum: Box<Account<'info, Forum>>,
+
+    #[account(mut)]
+    pub profile_owner: Signer<'info>,
+
+    // The user profile doing the following
+    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), user_profile.profile_seed.as_ref()],
+              bump = bump_user_profile, has_one = forum, has_one = profile_owner)]
+    pub user_profile: Box<Account<'info, UserProfile>>,
+
+    /// CHECK: The user profile being followed (empty if the followed profile has since been deleted)
+    #[account(mut)]
+    pub followed_profile: AccountInfo<'info>,
+
+    // Follow PDA account
+    #[account(mut, seeds = [b"follow".as_ref(), user_profile.key().as_ref(), followed_profile.key().as_ref()],
+              bump = bump_follow, constraint = follow.follower_profile == user_profile.key(), has_one = followed_profile)]
+    pub follow: Box<Account<'info, Follow>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn unfollow_user(ctx: Context<UnfollowUser>) -> Result<()> {
+
+    let now_ts: u64 = now_ts()?;
+
+    // Close the follow state account
+    let receiver = &mut ctx.accounts.profile_owner.to_account_info();
+    let follow_account_info = &mut (*ctx.accounts.follow).to_account_info();
+    close_account(follow_account_info, receiver)?;
+
+    // Decrement follower count in followed profile's state account, unless the followed profile has been deleted
+    // (or deleted and recreated after this follow, in which case the follow was never counted)
+    if !ctx.accounts.followed_profile.data_is_empty() {
+        let mut followed_profile = Account::<UserProfile>::try_from(&ctx.accounts.followed_profile)?;
+
+        if followed_profile.forum != ctx.accounts.forum.key() {
+            return Err(error!(ErrorCode::InvalidParameter));
+        }
+
+        if ctx.accounts.follow.followed_ts >= followed_profile.profile_created_ts {
+            followed_profile.follower_count.try_sub_assign(1)?;
+            followed_profile.exit(&crate::ID)?;
+        }
+    }
+
+    // Decrement following count in user profile's state account
+    let user_profile = &mut ctx.accounts.user_profile;
+    user_profile.following_count.try_sub_assign(1)?;
+    user_profile.most_recent_engagement_ts = now_ts;
+
+    msg!("User profile with pubkey {} no longer following user profile with pubkey {}",
+         ctx.accounts.user_profile.key(), ctx.accounts.followed_profile.key());
+    Ok(())
+}
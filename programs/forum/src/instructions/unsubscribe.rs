@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{Forum, Subscription, UserProfile};
+use prog_common::{now_ts, close_account};
+
+#[derive(Accounts)]
+#[instruction(bump_user_profile: u8, bump_subscription: u8)]
+pub struct Unsubscribe<'info> {
+
+    // Forum
+    pub forum: Box<Account<'info, Forum>>,
+
+    #[account(mut)]
+    pub profile_owner: Signer<'info>,
+
+    // The user profile
+    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), user_profile.profile_seed.as_ref()],
+              bump = bump_user_profile, has_one = forum, has_one = profile_owner)]
+    pub user_profile: Box<Account<'info, UserProfile>>,
+
+    // Subscription PDA account (the subscribed to account may since have been deleted)
+    #[account(mut, seeds = [b"subscription".as_ref(), user_profile.key().as_ref(), subscription.subscribed_to.as_ref()],
+              bump = bump_subscription, constraint = subscription.subscriber_profile == user_profile.key())]
+    pub subscription: Box<Account<'info, Subscription>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn unsubscribe(ctx: Context<Unsubscribe>) -> Result<()> {
+
+    let now_ts: u64 = now_ts()?;
+
+    // Close the subscription state account
+    let receiver = &mut ctx.accounts.profile_owner.to_account_info();
+    let subscription_account_info = &mut (*ctx.accounts.subscription).to_account_info();
+    close_account(subscription_account_info, receiver)?;
+
+    // Update user profile's most recent engagement
+    let user_profile = &mut ctx.accounts.user_profile;
+    user_profile.most_recent_engagement_ts = now_ts;
+
+    msg!("User profile with pubkey {} unsubscribed from account with address {}",
+         ctx.accounts.user_profile.key(), ctx.accounts.subscription.subscribed_to);
+    Ok(())
+}
@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{Category, Forum, ForumFees};
+use prog_common::errors::ErrorCode;
+
+#[derive(Accounts)]
+pub struct UpdateCategory<'info> {
+
+    // Forum and Forum Manager
+    #[account(has_one = forum_manager)]
+    pub forum: Box<Account<'info, Forum>>,
+
+    pub forum_manager: Signer<'info>,
+
+    // Category PDA account
+    #[account(mut, has_one = forum)]
+    pub category: Box<Account<'info, Category>>,
+}
+
+pub fn update_category(ctx: Context<UpdateCategory>, fee_overrides_enabled: bool, new_category_fees: ForumFees) -> Result<()> {
+
+    // Assert new category question and big notes solicitation fees and rejected submission refund in basis points are between 0 - 10,000
+    if (new_category_fees.forum_question_fee > 10000) || (new_category_fees.forum_big_notes_solicitation_fee > 10000) ||
+        (new_category_fees.forum_rejected_submission_refund > 10000) {
+        return Err(error!(ErrorCode::InvalidFeeInputs));
+    }
+
+    let category = &mut ctx.accounts.category;
+    category.category_fees = new_category_fees;
+    category.fee_overrides_enabled = fee_overrides_enabled;
+
+    msg!("Category fees now {:?} (overrides enabled: {})", category.category_fees, category.fee_overrides_enabled);
+    Ok(())
+}
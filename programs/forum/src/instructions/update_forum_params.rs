@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 
-use crate::state::{Forum, ForumConstants, ForumFees, ReputationMatrix};
+use crate::state::{Forum, ForumConstants, ForumFees, ReputationMatrix, MAX_HANDLE_LENGTH};
 use prog_common::errors::ErrorCode;
 
 #[derive(Accounts)]
@@ -17,11 +17,18 @@ pub struct UpdateForumParams<'info> {
 
 pub fn update_forum_params(ctx: Context<UpdateForumParams>, new_forum_fees: ForumFees, new_forum_constants: ForumConstants, new_reputation_matrix: ReputationMatrix) -> Result<()> {
 
-    // Assert new forum question and big notes solicitation fees in basis points are between 0 - 10,000
-    if (new_forum_fees.forum_question_fee > 10000) || (new_forum_fees.forum_big_notes_solicitation_fee > 10000) {
+    // Assert new forum question and big notes solicitation fees and rejected submission refund in basis points are between 0 - 10,000
+    if (new_forum_fees.forum_question_fee > 10000) || (new_forum_fees.forum_big_notes_solicitation_fee > 10000) ||
+        (new_forum_fees.forum_rejected_submission_refund > 10000) {
         return Err(error!(ErrorCode::InvalidFeeInputs));
     }
 
+    // Assert handle length limits are non-zero, ordered and fit in a PDA seed
+    if (new_forum_constants.min_handle_length == 0) || (new_forum_constants.min_handle_length > new_forum_constants.max_handle_length) ||
+        (new_forum_constants.max_handle_length > MAX_HANDLE_LENGTH) {
+        return Err(error!(ErrorCode::InvalidHandleRules));
+    }
+
     let forum = &mut ctx.accounts.forum;
     forum.forum_fees = new_forum_fees;
     forum.forum_constants = new_forum_constants;
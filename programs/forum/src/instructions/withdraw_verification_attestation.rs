@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{ATTESTATION_SLASHING_WINDOW, BigNote, BigNoteVerificationRecord, BigNoteVerificationState, Forum, UserProfile, VerificationAttestation};
+use prog_common::{close_account, now_ts, TryAdd, errors::ErrorCode};
+
+#[derive(Accounts)]
+#[instruction(bump_attester_profile: u8, bump_verification_record: u8)]
+pub struct WithdrawVerificationAttestation<'info> {
+
+    // Forum
+    pub forum: Box<Account<'info, Forum>>,
+
+    #[account(mut)]
+    pub attester: Signer<'info>,
+
+    // The attester profile
+    #[account(mut, seeds = [b"user_profile".as_ref(), forum.key().as_ref(), attester_profile.profile_seed.as_ref()],
+              bump = bump_attester_profile, has_one = forum, constraint = attester_profile.profile_owner == attester.key())]
+    pub attester_profile: Box<Account<'info, UserProfile>>,
+
+    // Big Note pda account
+    #[account(has_one = forum)]
+    pub big_note: Box<Account<'info, BigNote>>,
+
+    // Big Note Verification Record PDA account
+    #[account(seeds = [b"verification_record".as_ref(), big_note.key().as_ref()],
+              bump = bump_verification_record, has_one = big_note)]
+    pub verification_record: Box<Account<'info, BigNoteVerificationRecord>>,
+
+    // Verification Attestation PDA account
+    #[account(mut, has_one = big_note, has_one = attester_profile)]
+    pub verification_attestation: Box<Account<'info, VerificationAttestation>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn withdraw_verification_attestation(ctx: Context<WithdrawVerificationAttestation>) -> Result<()> {
+
+    let now_ts: u64 = now_ts()?;
+
+    // Ensure the attestation's verification round has ended (superseded, rejected, withdrawn or revoked), or that the
+    // big note has needed reverification for longer than the slashing window
+    let slashing_window_ended: bool = (ctx.accounts.big_note.verification_state == BigNoteVerificationState::NeedsReverification) &&
+        (now_ts > ctx.accounts.verification_record.reverification_ts.try_add(ATTESTATION_SLASHING_WINDOW)?);
+
+    let attestation_round: u64 = ctx.accounts.verification_attestation.verification_round;
+
+    // A superseded round stays locked while its slashing window is still open
+    let superseded_round_ended: bool = (attestation_round < ctx.accounts.verification_record.verification_round) &&
+        !ctx.accounts.verification_record.is_superseded_round_slashable(attestation_round, now_ts);
+
+    let round_ended: bool = superseded_round_ended ||
+        ((attestation_round == ctx.accounts.verification_record.verification_round) &&
+            ((ctx.accounts.big_note.verification_state == BigNoteVerificationState::Unverified) || slashing_window_ended));
+
+    if !round_ended {
+        return Err(error!(ErrorCode::AttestationStillActive));
+    }
+
+    // Close the attestation, returning any stake not slashed and the rent to the attester
+    let attester_account_info = &mut ctx.accounts.attester.to_account_info();
+    let verification_attestation_account_info = &mut (*ctx.accounts.verification_attestation).to_account_info();
+    close_account(verification_attestation_account_info, attester_account_info)?;
+
+    // Update attester profile account's most recent engagement timestamp
+    let attester_profile = &mut ctx.accounts.attester_profile;
+    attester_profile.most_recent_engagement_ts = now_ts;
+
+    msg!("Verification attestation PDA account with address {} now withdrawn", ctx.accounts.verification_attestation.key());
+    Ok(())
+}
@@ -1,6 +1,9 @@
+// The SBF toolchain predates Option::is_some_and, so map_or(false, ..) is used in its place
+#![allow(clippy::unnecessary_map_or)]
+
 use anchor_lang::prelude::*;
 use instructions::*;
-use crate::state::{BigNoteType, ForumConstants, ForumFees, ReputationMatrix, SubmissionState, Tags};
+use crate::state::{AccessPolicyType, BigNoteType, CommentTarget, ContentRelationType, ForumConstants, ForumFees, LinkedContentType, PrizeDistribution, ReputationMatrix, RubricCriterion, SessionScope, SubmissionState, SubscriptionTarget, Tags};
 
 declare_id!("FoRUMwAz6uhSqf8uvG94nkeYdKM326mKzZazrh2Z4sZS");
 
@@ -45,6 +48,66 @@ pub mod forum {
         )
     }
 
+    pub fn set_pfp_collection_allowlist(
+        ctx: Context<SetPfpCollectionAllowlist>,
+        collections: Vec<Pubkey>,
+    ) -> Result<()> {
+        msg!("setting pfp collection allowlist");
+        instructions::set_pfp_collection_allowlist::set_pfp_collection_allowlist(ctx, collections)
+    }
+
+    pub fn create_category(
+        ctx: Context<CreateCategory>,
+        fee_overrides_enabled: bool,
+        category_fees: ForumFees,
+    ) -> Result<()> {
+        msg!("creating category");
+        instructions::create_category::create_category(ctx, fee_overrides_enabled, category_fees)
+    }
+
+    pub fn update_category(
+        ctx: Context<UpdateCategory>,
+        fee_overrides_enabled: bool,
+        new_category_fees: ForumFees,
+    ) -> Result<()> {
+        msg!("updating category");
+        instructions::update_category::update_category(ctx, fee_overrides_enabled, new_category_fees)
+    }
+
+    pub fn set_category_moderators(
+        ctx: Context<SetCategoryModerators>,
+        moderator_profiles: Vec<Pubkey>,
+    ) -> Result<()> {
+        msg!("setting category moderators");
+        instructions::set_category_moderators::set_category_moderators(ctx, moderator_profiles)
+    }
+
+    pub fn set_forum_access_policy(
+        ctx: Context<SetForumAccessPolicy>,
+        access_policy_type: AccessPolicyType,
+        access_mint: Pubkey,
+        min_token_amount: u64,
+        recheck_period: u64,
+    ) -> Result<()> {
+        msg!("setting forum access policy");
+        instructions::set_forum_access_policy::set_forum_access_policy(ctx, access_policy_type, access_mint, min_token_amount, recheck_period)
+    }
+
+    pub fn add_forum_allowlist_entry(
+        ctx: Context<AddForumAllowlistEntry>,
+    ) -> Result<()> {
+        msg!("adding forum allowlist entry");
+        instructions::add_forum_allowlist_entry::add_forum_allowlist_entry(ctx)
+    }
+
+    pub fn remove_forum_allowlist_entry(
+        ctx: Context<RemoveForumAllowlistEntry>,
+        _bump_forum_allowlist_entry: u8,
+    ) -> Result<()> {
+        msg!("removing forum allowlist entry");
+        instructions::remove_forum_allowlist_entry::remove_forum_allowlist_entry(ctx)
+    }
+
     pub fn payout_from_treasury(
         ctx: Context<PayoutFromTreasury>,
         _bump_forum_treasury: u8,
@@ -66,22 +129,153 @@ pub mod forum {
     pub fn create_user_profile(
         ctx: Context<CreateUserProfile>,
         _bump_treasury: u8,
+        _bump_wallet_rotation: u8,
+        _bump_forum_access_policy: u8,
     ) -> Result<()> {
         msg!("creating user profile");
         instructions::create_user_profile::create_user_profile(ctx)
     }
 
+    pub fn approve_wallet_rotation(
+        ctx: Context<ApproveWalletRotation>,
+        _bump_user_profile: u8,
+    ) -> Result<()> {
+        msg!("approving wallet rotation");
+        instructions::approve_wallet_rotation::approve_wallet_rotation(ctx)
+    }
+
+    pub fn accept_wallet_rotation(
+        ctx: Context<AcceptWalletRotation>,
+        _bump_user_profile: u8,
+        _bump_new_wallet_user_profile: u8,
+        _bump_previous_wallet_rotation: u8,
+    ) -> Result<()> {
+        msg!("accepting wallet rotation");
+        instructions::accept_wallet_rotation::accept_wallet_rotation(ctx)
+    }
+
+    pub fn register_session_key(
+        ctx: Context<RegisterSessionKey>,
+        _bump_user_profile: u8,
+        session_scope: SessionScope,
+        session_duration: u64,
+    ) -> Result<()> {
+        msg!("registering session key");
+        instructions::register_session_key::register_session_key(ctx, session_scope, session_duration)
+    }
+
+    pub fn revoke_session_key(
+        ctx: Context<RevokeSessionKey>,
+        _bump_user_profile: u8,
+        _bump_session_key: u8,
+    ) -> Result<()> {
+        msg!("revoking session key");
+        instructions::revoke_session_key::revoke_session_key(ctx)
+    }
+
     pub fn edit_user_profile(
         ctx: Context<EditUserProfile>,
         _bump_user_profile: u8,
+        _bump_pfp_collection_allowlist: u8,
     ) -> Result<()> {
         msg!("editing user profile");
         instructions::edit_user_profile::edit_user_profile(ctx)
     }
 
+    pub fn clear_nft_pfp(
+        ctx: Context<ClearNftPfp>,
+        _bump_user_profile: u8,
+    ) -> Result<()> {
+        msg!("clearing nft pfp");
+        instructions::clear_nft_pfp::clear_nft_pfp(ctx)
+    }
+
+    pub fn claim_handle(
+        ctx: Context<ClaimHandle>,
+        _bump_treasury: u8,
+        _bump_user_profile: u8,
+        handle_name: String,
+    ) -> Result<()> {
+        msg!("claiming handle");
+        instructions::claim_handle::claim_handle(ctx, handle_name)
+    }
+
+    pub fn release_handle(
+        ctx: Context<ReleaseHandle>,
+        _bump_user_profile: u8,
+        _bump_handle: u8,
+    ) -> Result<()> {
+        msg!("releasing handle");
+        instructions::release_handle::release_handle(ctx)
+    }
+
+    pub fn transfer_handle(
+        ctx: Context<TransferHandle>,
+        _bump_user_profile: u8,
+        _bump_handle: u8,
+        _bump_new_user_profile: u8,
+    ) -> Result<()> {
+        msg!("transferring handle");
+        instructions::transfer_handle::transfer_handle(ctx)
+    }
+
+    pub fn reclaim_handle_moderator(
+        ctx: Context<ReclaimHandleModerator>,
+        _bump_moderator_profile: u8,
+        _bump_handle: u8,
+    ) -> Result<()> {
+        msg!("moderator reclaiming handle");
+        instructions::reclaim_handle_moderator::reclaim_handle_moderator(ctx)
+    }
+
+    pub fn recheck_profile_eligibility<'info>(
+        ctx: Context<'_, '_, '_, 'info, RecheckProfileEligibility<'info>>,
+        _bump_forum_access_policy: u8,
+        _bump_user_profile: u8,
+    ) -> Result<()> {
+        msg!("rechecking profile eligibility");
+        instructions::recheck_profile_eligibility::recheck_profile_eligibility(ctx)
+    }
+
+    pub fn follow_user(
+        ctx: Context<FollowUser>,
+        _bump_user_profile: u8,
+    ) -> Result<()> {
+        msg!("following user");
+        instructions::follow_user::follow_user(ctx)
+    }
+
+    pub fn unfollow_user(
+        ctx: Context<UnfollowUser>,
+        _bump_user_profile: u8,
+        _bump_follow: u8,
+    ) -> Result<()> {
+        msg!("unfollowing user");
+        instructions::unfollow_user::unfollow_user(ctx)
+    }
+
+    pub fn subscribe(
+        ctx: Context<Subscribe>,
+        _bump_user_profile: u8,
+        subscription_target: SubscriptionTarget,
+    ) -> Result<()> {
+        msg!("subscribing");
+        instructions::subscribe::subscribe(ctx, subscription_target)
+    }
+
+    pub fn unsubscribe(
+        ctx: Context<Unsubscribe>,
+        _bump_user_profile: u8,
+        _bump_subscription: u8,
+    ) -> Result<()> {
+        msg!("unsubscribing");
+        instructions::unsubscribe::unsubscribe(ctx)
+    }
+
     pub fn delete_user_profile(
         ctx: Context<DeleteUserProfile>,
         _bump_user_profile: u8,
+        _bump_wallet_rotation: u8,
     ) -> Result<()> {
         msg!("deleting user profile");
         instructions::delete_user_profile::delete_user_profile(ctx)
@@ -119,6 +313,7 @@ pub mod forum {
         ctx: Context<DeleteUserProfileAndAboutMe>,
         _bump_user_profile: u8,
         _bump_about_me: u8,
+        _bump_wallet_rotation: u8,
     ) -> Result<()>{
         msg!("deleting user profile and about me");
         instructions::delete_user_profile_and_about_me::delete_user_profile_and_about_me(ctx)
@@ -167,6 +362,7 @@ pub mod forum {
         ctx: Context<EditQuestion>,
         _bump_user_profile: u8,
         _bump_question: u8,
+        _bump_session_key: u8,
         new_tags: Vec<Tags>,
         new_title: String,
         new_content_data_url: String
@@ -236,6 +432,42 @@ pub mod forum {
         instructions::refund_question_bounty_supplementor_moderator::refund_question_bounty_supplementor_moderator(ctx)
     }
 
+    pub fn mark_duplicate(
+        ctx: Context<MarkDuplicate>,
+        _bump_moderator_profile: u8,
+    ) -> Result<()> {
+        msg!("moderator marking question as duplicate");
+        instructions::mark_duplicate::mark_duplicate(ctx)
+    }
+
+    pub fn transfer_question_ownership(
+        ctx: Context<TransferQuestionOwnership>,
+        _bump_user_profile: u8,
+        _bump_question: u8,
+    ) -> Result<()> {
+        msg!("transferring question ownership");
+        instructions::transfer_question_ownership::transfer_question_ownership(ctx)
+    }
+
+    pub fn claim_question_ownership(
+        ctx: Context<ClaimQuestionOwnership>,
+        _bump_user_profile: u8,
+        _bump_question: u8,
+    ) -> Result<()> {
+        msg!("claiming question ownership");
+        instructions::claim_question_ownership::claim_question_ownership(ctx)
+    }
+
+    pub fn migrate_duplicate_question_bounty(
+        ctx: Context<MigrateDuplicateQuestionBounty>,
+        _bump_moderator_profile: u8,
+        _bump_duplicate_bounty_pda: u8,
+        _bump_canonical_bounty_pda: u8,
+    ) -> Result<()> {
+        msg!("moderator migrating duplicate question bounty");
+        instructions::migrate_duplicate_question_bounty::migrate_duplicate_question_bounty(ctx)
+    }
+
     pub fn accept_answer(
         ctx: Context<AcceptAnswer>,
         _bump_user_profile: u8,
@@ -253,6 +485,7 @@ pub mod forum {
     pub fn answer_question(
         ctx: Context<AnswerQuestion>,
         _bump_user_profile: u8,
+        _bump_session_key: u8,
     ) -> Result<()> {
         msg!("answering question");
         instructions::answer_question::answer_question(ctx)
@@ -262,6 +495,7 @@ pub mod forum {
         ctx: Context<EditAnswer>,
         _bump_user_profile: u8,
         _bump_answer: u8,
+        _bump_session_key: u8,
     ) -> Result<()> {
         msg!("editing answer");
         instructions::edit_answer::edit_answer(ctx)
@@ -298,126 +532,145 @@ pub mod forum {
 
 ///////////////////////////////////////////////////////////////////////////
 
-    pub fn leave_comment_on_question(
-        ctx: Context<LeaveCommentOnQuestion>,
+    pub fn leave_comment<'info>(
+        ctx: Context<'_, '_, '_, 'info, LeaveComment<'info>>,
         _bump_user_profile: u8,
+        _bump_session_key: u8,
+        comment_target: CommentTarget,
     ) -> Result<()> {
-        msg!("leaving comment on question");
-        instructions::leave_comment_on_question::leave_comment_on_question(ctx)
+        msg!("leaving comment");
+        instructions::leave_comment::leave_comment(ctx, comment_target)
     }
 
-    pub fn edit_comment_on_question(
-        ctx: Context<EditCommentOnQuestion>,
+    pub fn edit_comment(
+        ctx: Context<EditComment>,
         _bump_user_profile: u8,
         _bump_comment: u8,
+        _bump_session_key: u8,
     ) -> Result<()> {
-        msg!("editing comment on question");
-        instructions::edit_comment_on_question::edit_comment_on_question(ctx)
+        msg!("editing comment");
+        instructions::edit_comment::edit_comment(ctx)
     }
 
-    pub fn edit_comment_on_question_moderator(
-        ctx: Context<EditCommentOnQuestionModerator>,
+    pub fn edit_comment_moderator<'info>(
+        ctx: Context<'_, '_, '_, 'info, EditCommentModerator<'info>>,
         _bump_moderator_profile: u8,
         _bump_user_profile: u8,
         _bump_comment: u8,
     ) -> Result<()> {
-        msg!("moderator editing comment on question");
-        instructions::edit_comment_on_question_moderator::edit_comment_on_question_moderator(ctx)
+        msg!("moderator editing comment");
+        instructions::edit_comment_moderator::edit_comment_moderator(ctx)
     }
 
-    pub fn delete_comment_on_question(
-        ctx: Context<DeleteCommentOnQuestion>,
+    pub fn delete_comment(
+        ctx: Context<DeleteComment>,
         _bump_user_profile: u8,
         _bump_comment: u8,
     ) -> Result<()> {
-        msg!("deleting comment on question");
-        instructions::delete_comment_on_question::delete_comment_on_question(ctx)
+        msg!("deleting comment");
+        instructions::delete_comment::delete_comment(ctx)
     }
 
-    pub fn delete_comment_on_question_moderator(
-        ctx: Context<DeleteCommentOnQuestionModerator>,
+    pub fn delete_comment_moderator<'info>(
+        ctx: Context<'_, '_, '_, 'info, DeleteCommentModerator<'info>>,
         _bump_moderator_profile: u8,
         _bump_user_profile: u8,
         _bump_comment: u8,
     ) -> Result<()> {
-        msg!("moderator deleting comment on question");
-        instructions::delete_comment_on_question_moderator::delete_comment_on_question_moderator(ctx)
+        msg!("moderator deleting comment");
+        instructions::delete_comment_moderator::delete_comment_moderator(ctx)
     }
 
 ///////////////////////////////////////////////////////////////////////////
 
-    pub fn leave_comment_on_answer(
-        ctx: Context<LeaveCommentOnAnswer>,
+    pub fn create_big_note(
+        ctx: Context<CreateBigNote>,
+        _bump_treasury: u8,
         _bump_user_profile: u8,
+        big_note_type: BigNoteType,
+        tags: Vec<Tags>,
+        title: String,
+        content_data_url: String,
     ) -> Result<()> {
-        msg!("leaving comment on answer");
-        instructions::leave_comment_on_answer::leave_comment_on_answer(ctx)
+        msg!("creating big note");
+        instructions::create_big_note::create_big_note(
+            ctx,
+            big_note_type,
+            tags,
+            title,
+            content_data_url
+        )
     }
 
-    pub fn edit_comment_on_answer(
-        ctx: Context<EditCommentOnAnswer>,
+    #[allow(clippy::too_many_arguments)]
+    pub fn convert_question_to_big_note(
+        ctx: Context<ConvertQuestionToBigNote>,
+        _bump_treasury: u8,
         _bump_user_profile: u8,
-        _bump_comment: u8,
+        _bump_question: u8,
+        _bump_answer_user_profile: u8,
+        _bump_answer: u8,
+        big_note_type: BigNoteType,
+        content_data_url: String,
     ) -> Result<()> {
-        msg!("editing comment on answer");
-        instructions::edit_comment_on_answer::edit_comment_on_answer(ctx)
+        msg!("converting question to big note");
+        instructions::convert_question_to_big_note::convert_question_to_big_note(
+            ctx,
+            big_note_type,
+            content_data_url
+        )
     }
 
-    pub fn edit_comment_on_answer_moderator(
-        ctx: Context<EditCommentOnAnswerModerator>,
-        _bump_moderator_profile: u8,
+    pub fn fork_big_note(
+        ctx: Context<ForkBigNote>,
+        _bump_treasury: u8,
         _bump_user_profile: u8,
-        _bump_comment: u8,
+        big_note_type: BigNoteType,
+        content_data_url: String,
     ) -> Result<()> {
-        msg!("moderator editing comment on answer");
-        instructions::edit_comment_on_answer_moderator::edit_comment_on_answer_moderator(ctx)
+        msg!("forking big note");
+        instructions::fork_big_note::fork_big_note(
+            ctx,
+            big_note_type,
+            content_data_url
+        )
     }
 
-    pub fn delete_comment_on_answer(
-        ctx: Context<DeleteCommentOnAnswer>,
-        _bump_user_profile: u8,
-        _bump_comment: u8,
+    pub fn mark_big_note_successor(
+        ctx: Context<MarkBigNoteSuccessor>,
+        _bump_moderator_profile: u8,
+        _bump_big_note_fork: u8,
     ) -> Result<()> {
-        msg!("deleting comment on answer");
-        instructions::delete_comment_on_answer::delete_comment_on_answer(ctx)
+        msg!("marking big note successor");
+        instructions::mark_big_note_successor::mark_big_note_successor(ctx)
     }
 
-    pub fn delete_comment_on_answer_moderator(
-        ctx: Context<DeleteCommentOnAnswerModerator>,
-        _bump_moderator_profile: u8,
+    pub fn transfer_big_note_ownership(
+        ctx: Context<TransferBigNoteOwnership>,
         _bump_user_profile: u8,
-        _bump_comment: u8,
+        _bump_big_note: u8,
     ) -> Result<()> {
-        msg!("moderator deleting comment on answer");
-        instructions::delete_comment_on_answer_moderator::delete_comment_on_answer_moderator(ctx)
+        msg!("transferring big note ownership");
+        instructions::transfer_big_note_ownership::transfer_big_note_ownership(ctx)
     }
 
-///////////////////////////////////////////////////////////////////////////
-
-    pub fn create_big_note(
-        ctx: Context<CreateBigNote>,
-        _bump_treasury: u8,
+    pub fn claim_big_note_ownership(
+        ctx: Context<ClaimBigNoteOwnership>,
         _bump_user_profile: u8,
-        big_note_type: BigNoteType,
-        tags: Vec<Tags>,
-        title: String,
-        content_data_url: String,
+        _bump_big_note: u8,
     ) -> Result<()> {
-        msg!("creating big note");
-        instructions::create_big_note::create_big_note(
-            ctx,
-            big_note_type,
-            tags,
-            title,
-            content_data_url
-        )
+        msg!("claiming big note ownership");
+        instructions::claim_big_note_ownership::claim_big_note_ownership(ctx)
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn edit_big_note_open_contribution(
         ctx: Context<EditBigNoteOpenContribution>,
         _bump_editor_profile: u8,
         _bump_user_profile: u8,
         _bump_big_note: u8,
+        _bump_session_key: u8,
+        _bump_verification_record: u8,
         new_tags: Vec<Tags>,
         new_title: String,
         new_content_data_url: String,
@@ -431,10 +684,14 @@ pub mod forum {
         )
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn edit_big_note_creator_curated(
         ctx: Context<EditBigNoteCreatorCurated>,
+        _bump_editor_profile: u8,
         _bump_user_profile: u8,
         _bump_big_note: u8,
+        _bump_session_key: u8,
+        _bump_verification_record: u8,
         new_tags: Vec<Tags>,
         new_title: String,
         new_content_data_url: String,
@@ -561,17 +818,19 @@ pub mod forum {
 
 ///////////////////////////////////////////////////////////////////////////
 
-    pub fn accept_big_note_verification_application(
-        ctx: Context<AcceptBigNoteVerificationApplication>,
+    #[allow(clippy::too_many_arguments)]
+    pub fn attest_big_note_verification(
+        ctx: Context<AttestBigNoteVerification>,
         _bump_treasury: u8,
-        _bump_moderator_profile: u8,
+        _bump_attester_profile: u8,
         _bump_user_profile: u8,
         _bump_big_note: u8,
         _bump_verification_application: u8,
-        _bump_verification_fee_pda: u8
+        _bump_verification_fee_pda: u8,
+        _bump_verification_record: u8,
     ) -> Result<()> {
-        msg!("accepting big note verification application");
-        instructions::accept_big_note_verification_application::accept_big_note_verification_application(ctx)
+        msg!("attesting to big note verification");
+        instructions::attest_big_note_verification::attest_big_note_verification(ctx)
     }
 
     pub fn reject_big_note_verification_application(
@@ -586,11 +845,41 @@ pub mod forum {
         instructions::reject_big_note_verification_application::reject_big_note_verification_application(ctx)
     }
 
+    pub fn revoke_big_note_verification<'info>(
+        ctx: Context<'_, '_, '_, 'info, RevokeBigNoteVerification<'info>>,
+        _bump_moderator_profile: u8,
+        _bump_big_note: u8,
+        _bump_verification_record: u8,
+        slash_attesters: bool,
+    ) -> Result<()> {
+        msg!("revoking big note verification");
+        instructions::revoke_big_note_verification::revoke_big_note_verification(ctx, slash_attesters)
+    }
+
+    pub fn withdraw_verification_attestation(
+        ctx: Context<WithdrawVerificationAttestation>,
+        _bump_attester_profile: u8,
+        _bump_verification_record: u8,
+    ) -> Result<()> {
+        msg!("withdrawing verification attestation");
+        instructions::withdraw_verification_attestation::withdraw_verification_attestation(ctx)
+    }
+
+    pub fn expire_big_note_verification(
+        ctx: Context<ExpireBigNoteVerification>,
+        _bump_big_note: u8,
+        _bump_verification_record: u8,
+    ) -> Result<()> {
+        msg!("expiring big note verification");
+        instructions::expire_big_note_verification::expire_big_note_verification(ctx)
+    }
+
 ///////////////////////////////////////////////////////////////////////////
 
     pub fn propose_contribution(
         ctx: Context<ProposeContribution>,
         _bump_user_profile: u8,
+        _bump_session_key: u8,
     ) -> Result<()> {
         msg!("proposing contribution");
         instructions::propose_contribution::propose_contribution(ctx)
@@ -636,159 +925,271 @@ pub mod forum {
 
 ///////////////////////////////////////////////////////////////////////////
 
-    pub fn leave_comment_on_big_note(
-        ctx: Context<LeaveCommentOnBigNote>,
-        _bump_user_profile: u8,
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_challenge(
+        ctx: Context<CreateChallenge>,
+        _bump_moderator_profile: u8,
+        tags: Vec<Tags>,
+        title: String,
+        content_data_url: String,
+        challenge_expires_ts: u64,
+        reputation: u64,
+        rubric: Vec<RubricCriterion>,
     ) -> Result<()> {
-        msg!("leaving comment on big note");
-        instructions::leave_comment_on_big_note::leave_comment_on_big_note(ctx)
+        msg!("moderator creating challenge");
+        instructions::create_challenge::create_challenge(
+            ctx,
+            tags,
+            title,
+            content_data_url,
+            challenge_expires_ts,
+            reputation,
+            rubric
+        )
     }
 
-    pub fn edit_comment_on_big_note(
-        ctx: Context<EditCommentOnBigNote>,
+    #[allow(clippy::too_many_arguments)]
+    pub fn propose_challenge(
+        ctx: Context<ProposeChallenge>,
         _bump_user_profile: u8,
-        _bump_comment: u8,
+        tags: Vec<Tags>,
+        title: String,
+        content_data_url: String,
+        challenge_expires_ts: u64,
+        reputation: u64,
+        rubric: Vec<RubricCriterion>,
     ) -> Result<()> {
-        msg!("editing comment on big note");
-        instructions::edit_comment_on_big_note::edit_comment_on_big_note(ctx)
+        msg!("proposing challenge");
+        instructions::propose_challenge::propose_challenge(
+            ctx,
+            tags,
+            title,
+            content_data_url,
+            challenge_expires_ts,
+            reputation,
+            rubric
+        )
     }
 
-    pub fn edit_comment_on_big_note_moderator(
-        ctx: Context<EditCommentOnBigNoteModerator>,
+    pub fn approve_challenge_proposal(
+        ctx: Context<ApproveChallengeProposal>,
         _bump_moderator_profile: u8,
-        _bump_user_profile: u8,
-        _bump_comment: u8,
+        _bump_author_profile: u8,
+        _bump_challenge: u8,
+        _bump_challenge_proposal: u8,
     ) -> Result<()> {
-        msg!("moderator editing comment on big note");
-        instructions::edit_comment_on_big_note_moderator::edit_comment_on_big_note_moderator(ctx)
+        msg!("moderator approving challenge proposal");
+        instructions::approve_challenge_proposal::approve_challenge_proposal(ctx)
     }
 
-    pub fn delete_comment_on_big_note(
-        ctx: Context<DeleteCommentOnBigNote>,
-        _bump_user_profile: u8,
-        _bump_comment: u8,
+    pub fn reject_challenge_proposal(
+        ctx: Context<RejectChallengeProposal>,
+        _bump_moderator_profile: u8,
+        _bump_author_profile: u8,
+        _bump_challenge: u8,
+        _bump_challenge_proposal: u8,
+        slash_deposit: bool,
     ) -> Result<()> {
-        msg!("deleting comment on big note");
-        instructions::delete_comment_on_big_note::delete_comment_on_big_note(ctx)
+        msg!("moderator rejecting challenge proposal");
+        instructions::reject_challenge_proposal::reject_challenge_proposal(ctx, slash_deposit)
     }
 
-    pub fn delete_comment_on_big_note_moderator(
-        ctx: Context<DeleteCommentOnBigNoteModerator>,
+    pub fn edit_challenge(
+        ctx: Context<EditChallenge>,
         _bump_moderator_profile: u8,
-        _bump_user_profile: u8,
-        _bump_comment: u8,
+        _bump_challenge: u8,
+        new_tags: Vec<Tags>,
+        new_title: String,
+        new_content_data_url: String,
+        new_challenge_expires_ts: u64,
+        new_reputation: u64,
     ) -> Result<()> {
-        msg!("moderator deleting comment on big note");
-        instructions::delete_comment_on_big_note_moderator::delete_comment_on_big_note_moderator(ctx)
+        msg!("moderator editing challenge");
+        instructions::edit_challenge::edit_challenge(
+            ctx,
+            new_tags,
+            new_title,
+            new_content_data_url,
+            new_challenge_expires_ts,
+            new_reputation
+        )
     }
 
-///////////////////////////////////////////////////////////////////////////
-
-    pub fn leave_comment_on_proposed_contribution(
-        ctx: Context<LeaveCommentOnProposedContribution>,
-        _bump_user_profile: u8,
+    pub fn delete_challenge(
+        ctx: Context<DeleteChallenge>,
+        _bump_moderator_profile: u8,
+        _bump_challenge: u8,
+        _bump_prize_pool: u8,
+        _bump_challenge_proposal: u8,
+        _bump_track_membership: u8,
     ) -> Result<()> {
-        msg!("leaving comment on proposed contribution");
-        instructions::leave_comment_on_proposed_contribution::leave_comment_on_proposed_contribution(ctx)
+        msg!("moderator deleting challenge");
+        instructions::delete_challenge::delete_challenge(ctx)
     }
 
-    pub fn edit_comment_on_proposed_contribution(
-        ctx: Context<EditCommentOnProposedContribution>,
-        _bump_user_profile: u8,
-        _bump_comment: u8,
+    pub fn close_challenge(
+        ctx: Context<CloseChallenge>,
+        _bump_challenge: u8,
     ) -> Result<()> {
-        msg!("editing comment on proposed contribution");
-        instructions::edit_comment_on_proposed_contribution::edit_comment_on_proposed_contribution(ctx)
+        msg!("closing challenge");
+        instructions::close_challenge::close_challenge(ctx)
     }
 
-    pub fn edit_comment_on_proposed_contribution_moderator(
-        ctx: Context<EditCommentOnProposedContributionModerator>,
+    pub fn archive_challenge(
+        ctx: Context<ArchiveChallenge>,
         _bump_moderator_profile: u8,
-        _bump_user_profile: u8,
-        _bump_comment: u8,
+        _bump_challenge: u8,
     ) -> Result<()> {
-        msg!("moderator editing comment on proposed contribution");
-        instructions::edit_comment_on_proposed_contribution_moderator::edit_comment_on_proposed_contribution_moderator(ctx)
+        msg!("moderator archiving challenge");
+        instructions::archive_challenge::archive_challenge(ctx)
     }
 
-    pub fn delete_comment_on_proposed_contribution(
-        ctx: Context<DeleteCommentOnProposedContribution>,
-        _bump_user_profile: u8,
-        _bump_comment: u8,
-    ) -> Result<()> {
-        msg!("deleting comment on proposed contribution");
-        instructions::delete_comment_on_proposed_contribution::delete_comment_on_proposed_contribution(ctx)
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_challenge_prize_pool(
+        ctx: Context<CreateChallengePrizePool>,
+        _bump_moderator_profile: u8,
+        _bump_challenge: u8,
+        _bump_challenge_proposal: u8,
+        prize_distribution: PrizeDistribution,
+        winner_count: u64,
+        prize_per_winner: u64,
+        tier_bps: Vec<u16>,
+    ) -> Result<()> {
+        msg!("moderator creating challenge prize pool");
+        instructions::create_challenge_prize_pool::create_challenge_prize_pool(
+            ctx,
+            prize_distribution,
+            winner_count,
+            prize_per_winner,
+            tier_bps
+        )
     }
 
-    pub fn delete_comment_on_proposed_contribution_moderator(
-        ctx: Context<DeleteCommentOnProposedContributionModerator>,
+    pub fn create_challenge_prize_vault(
+        ctx: Context<CreateChallengePrizeVault>,
         _bump_moderator_profile: u8,
-        _bump_user_profile: u8,
-        _bump_comment: u8,
+        _bump_challenge: u8,
+        _bump_prize_pool: u8,
     ) -> Result<()> {
-        msg!("moderator deleting comment on proposed contribution");
-        instructions::delete_comment_on_proposed_contribution_moderator::delete_comment_on_proposed_contribution_moderator(ctx)
+        msg!("moderator creating challenge prize vault");
+        instructions::create_challenge_prize_vault::create_challenge_prize_vault(ctx)
     }
 
-///////////////////////////////////////////////////////////////////////////
+    pub fn fund_challenge_prize_pool<'info>(
+        ctx: Context<'_, '_, '_, 'info, FundChallengePrizePool<'info>>,
+        _bump_sponsor_profile: u8,
+        _bump_challenge: u8,
+        _bump_prize_pool: u8,
+        _bump_challenge_proposal: u8,
+        prize_amount: u64,
+    ) -> Result<()> {
+        msg!("funding challenge prize pool");
+        instructions::fund_challenge_prize_pool::fund_challenge_prize_pool(
+            ctx,
+            prize_amount
+        )
+    }
 
-    pub fn create_challenge(
-        ctx: Context<CreateChallenge>,
+    pub fn refund_challenge_prize_contribution<'info>(
+        ctx: Context<'_, '_, '_, 'info, RefundChallengePrizeContribution<'info>>,
+        _bump_sponsor_profile: u8,
+        _bump_challenge: u8,
+        _bump_prize_pool: u8,
+        _bump_prize_contribution: u8,
+        _bump_leaderboard: u8,
+    ) -> Result<()> {
+        msg!("refunding challenge prize contribution");
+        instructions::refund_challenge_prize_contribution::refund_challenge_prize_contribution(ctx)
+    }
+
+    pub fn create_evaluation_panel(
+        ctx: Context<CreateEvaluationPanel>,
         _bump_moderator_profile: u8,
-        tags: Vec<Tags>,
-        title: String,
-        content_data_url: String,
-        challenge_expires_ts: u64,
-        reputation: u64,
+        _bump_challenge: u8,
+        _bump_challenge_proposal: u8,
+        judges: Vec<Pubkey>,
+        quorum: u64,
     ) -> Result<()> {
-        msg!("moderator creating challenge");
-        instructions::create_challenge::create_challenge(
+        msg!("moderator creating evaluation panel");
+        instructions::create_evaluation_panel::create_evaluation_panel(
             ctx,
-            tags,
-            title,
-            content_data_url,
-            challenge_expires_ts,
-            reputation
+            judges,
+            quorum
         )
     }
 
-    pub fn edit_challenge(
-        ctx: Context<EditChallenge>,
+    pub fn create_challenge_leaderboard(
+        ctx: Context<CreateChallengeLeaderboard>,
         _bump_moderator_profile: u8,
         _bump_challenge: u8,
-        new_tags: Vec<Tags>,
-        new_title: String,
-        new_content_data_url: String,
-        new_challenge_expires_ts: u64,
-        new_reputation: u64,
+        max_entries: u64,
     ) -> Result<()> {
-        msg!("moderator editing challenge");
-        instructions::edit_challenge::edit_challenge(
+        msg!("moderator creating challenge leaderboard");
+        instructions::create_challenge_leaderboard::create_challenge_leaderboard(
             ctx,
-            new_tags,
-            new_title,
-            new_content_data_url,
-            new_challenge_expires_ts,
-            new_reputation
+            max_entries
         )
     }
 
-    pub fn delete_challenge(
-        ctx: Context<DeleteChallenge>,
+    pub fn close_challenge_leaderboard<'info>(
+        ctx: Context<'_, '_, '_, 'info, CloseChallengeLeaderboard<'info>>,
+        _bump_challenge: u8,
+        _bump_leaderboard: u8,
+        _bump_prize_pool: u8,
+    ) -> Result<()> {
+        msg!("closing challenge leaderboard");
+        instructions::close_challenge_leaderboard::close_challenge_leaderboard(ctx)
+    }
+
+///////////////////////////////////////////////////////////////////////////
+
+    pub fn create_track(
+        ctx: Context<CreateTrack>,
         _bump_moderator_profile: u8,
+        completion_rep: u64,
+    ) -> Result<()> {
+        msg!("moderator creating track");
+        instructions::create_track::create_track(ctx, completion_rep)
+    }
+
+    pub fn add_challenge_to_track(
+        ctx: Context<AddChallengeToTrack>,
+        _bump_moderator_profile: u8,
+        _bump_track: u8,
         _bump_challenge: u8,
+        _bump_challenge_proposal: u8,
     ) -> Result<()> {
-        msg!("moderator deleting challenge");
-        instructions::delete_challenge::delete_challenge(ctx)
+        msg!("moderator adding challenge to track");
+        instructions::add_challenge_to_track::add_challenge_to_track(ctx)
+    }
+
+    pub fn remove_challenge_from_track(
+        ctx: Context<RemoveChallengeFromTrack>,
+        _bump_moderator_profile: u8,
+        _bump_track: u8,
+        _bump_track_membership: u8,
+    ) -> Result<()> {
+        msg!("moderator removing challenge from track");
+        instructions::remove_challenge_from_track::remove_challenge_from_track(ctx)
+    }
+
+    pub fn claim_track_completion<'info>(
+        ctx: Context<'_, '_, '_, 'info, ClaimTrackCompletion<'info>>,
+        _bump_user_profile: u8,
+        _bump_track: u8,
+    ) -> Result<()> {
+        msg!("claiming track completion");
+        instructions::claim_track_completion::claim_track_completion(ctx)
     }
 
 ///////////////////////////////////////////////////////////////////////////
 
-    pub fn create_submission(
-        ctx: Context<CreateSubmission>,
-        _bump_treasury: u8,
+    pub fn create_submission<'info>(
+        ctx: Context<'_, '_, '_, 'info, CreateSubmission<'info>>,
         _bump_user_profile: u8,
         _bump_challenge: u8,
+        _bump_challenge_proposal: u8,
+        _bump_track_membership: u8,
     ) -> Result<()> {
         msg!("creating submission");
         instructions::create_submission::create_submission(ctx)
@@ -804,6 +1205,16 @@ pub mod forum {
         instructions::edit_submission::edit_submission(ctx)
     }
 
+    pub fn resubmit(
+        ctx: Context<Resubmit>,
+        _bump_user_profile: u8,
+        _bump_challenge: u8,
+        _bump_submission: u8,
+    ) -> Result<()> {
+        msg!("resubmitting submission");
+        instructions::resubmit::resubmit(ctx)
+    }
+
     pub fn edit_submission_moderator(
         ctx: Context<EditSubmissionModerator>,
         _bump_moderator_profile:u8,
@@ -820,6 +1231,7 @@ pub mod forum {
         _bump_user_profile: u8,
         _bump_challenge: u8,
         _bump_submission: u8,
+        _bump_submission_fee_escrow: u8,
     ) -> Result<()> {
         msg!("deleting submission");
         instructions::delete_submission::delete_submission(ctx)
@@ -831,26 +1243,105 @@ pub mod forum {
         _bump_user_profile: u8,
         _bump_challenge: u8,
         _bump_submission: u8,
+        _bump_submission_fee_escrow: u8,
     ) -> Result<()> {
         msg!("moderator deleting submission");
         instructions::delete_submission_moderator::delete_submission_moderator(ctx)
     }
 
-    pub fn evaluate_submission(
-        ctx: Context<EvaluateSubmission>,
-        _bump_moderator_profile: u8,
+    pub fn refund_submission_fee(
+        ctx: Context<RefundSubmissionFee>,
         _bump_user_profile: u8,
         _bump_challenge: u8,
         _bump_submission: u8,
-        submission_state: SubmissionState,
+        _bump_submission_fee_escrow: u8,
     ) -> Result<()> {
-        msg!("moderator evaluating submission");
+        msg!("refunding submission fee");
+        instructions::refund_submission_fee::refund_submission_fee(ctx)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn evaluate_submission<'info>(
+        ctx: Context<'_, '_, '_, 'info, EvaluateSubmission<'info>>,
+        _bump_judge_profile: u8,
+        _bump_user_profile: u8,
+        _bump_challenge: u8,
+        _bump_evaluation_panel: u8,
+        _bump_submission: u8,
+        _bump_prize_pool: u8,
+        _bump_submission_fee_escrow: u8,
+        verdict: SubmissionState,
+    ) -> Result<()> {
+        msg!("judge evaluating submission");
         instructions::evaluate_submission::evaluate_submission(
             ctx,
-            submission_state
+            verdict
+        )
+    }
+
+    pub fn peer_review_submission(
+        ctx: Context<PeerReviewSubmission>,
+        _bump_reviewer_profile: u8,
+        _bump_challenge: u8,
+        _bump_reviewer_submission: u8,
+        _bump_submission: u8,
+        verdict: SubmissionState,
+    ) -> Result<()> {
+        msg!("peer reviewing submission");
+        instructions::peer_review_submission::peer_review_submission(ctx, verdict)
+    }
+
+    pub fn settle_peer_review(
+        ctx: Context<SettlePeerReview>,
+        _bump_submission_attempt: u8,
+    ) -> Result<()> {
+        msg!("settling peer review");
+        instructions::settle_peer_review::settle_peer_review(ctx)
+    }
+
+    pub fn score_submission(
+        ctx: Context<ScoreSubmission>,
+        _bump_judge_profile: u8,
+        _bump_challenge: u8,
+        _bump_evaluation_panel: u8,
+        _bump_submission: u8,
+        _bump_leaderboard: u8,
+        scores: Vec<u64>,
+    ) -> Result<()> {
+        msg!("judge scoring submission");
+        instructions::score_submission::score_submission(
+            ctx,
+            scores
+        )
+    }
+
+///////////////////////////////////////////////////////////////////////////
+
+    pub fn create_content_link(
+        ctx: Context<CreateContentLink>,
+        _bump_moderator_profile: u8,
+        source_content_type: LinkedContentType,
+        target_content_type: LinkedContentType,
+        relation_type: ContentRelationType,
+    ) -> Result<()> {
+        msg!("moderator creating content link");
+        instructions::create_content_link::create_content_link(
+            ctx,
+            source_content_type,
+            target_content_type,
+            relation_type
         )
     }
 
+    pub fn delete_content_link(
+        ctx: Context<DeleteContentLink>,
+        _bump_moderator_profile: u8,
+        _bump_content_link: u8,
+    ) -> Result<()> {
+        msg!("moderator deleting content link");
+        instructions::delete_content_link::delete_content_link(ctx)
+    }
+
 ///////////////////////////////////////////////////////////////////////////
 
     pub fn close_account(
@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 
-#[proc_macros::assert_size(168)] // +7 to make it divisible by 8
+#[proc_macros::assert_size(176)] // +7 to make it divisible by 8
 #[repr(C)]
 #[account]
 #[derive(Debug)]
@@ -31,4 +31,7 @@ pub struct Answer {
 
     pub accepted_answer_rep: u64,
 
+    // ------------- Number of edits made (used in generating revision account PDA addresses)
+    pub revision_count: u64,
+
 }
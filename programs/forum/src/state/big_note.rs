@@ -1,5 +1,8 @@
 use anchor_lang::prelude::*;
 
+use anchor_lang::solana_program::program::{invoke_signed};
+use anchor_lang::solana_program::system_instruction::{create_account};
+
 use crate::state::{BigNoteType, BigNoteVerificationState, BountyContribution, Tags};
 
 #[repr(C)]
@@ -47,4 +50,61 @@ pub struct BigNote {
     // Is Bounty Awarded
     pub bounty_awarded: bool,
 
+    // ------------- Number of edits made (used in generating revision account PDA addresses)
+    pub revision_count: u64,
+
+    // ------------- Question and accepted answer the big note was converted from (default pubkeys otherwise)
+    pub source_question: Pubkey,
+
+    pub source_answer: Pubkey,
+
+    // ------------- Number of forks made of the big note
+    pub fork_count: u64,
+
+    // Fork marked by a moderator as superseding the big note (default pubkey otherwise)
+    pub successor: Pubkey,
+
+    // ------------- Ownership (the seeding user profile never changes, the current owner can be transferred)
+    pub current_owner: Pubkey,
+
+    // Profile nominated to take over ownership (default pubkey otherwise)
+    pub pending_owner: Pubkey,
+
+    // ------------- Category in which the big note was created
+    pub category: Pubkey,
+
+}
+
+impl BigNote {
+
+    // Create the big note PDA account sized to fit its contents and write the big note into it
+    pub fn create_account<'info>(
+        &self,
+        pda_seeds: &[&[u8]],
+        pda_info: &AccountInfo<'info>,
+        funder_info: &AccountInfo<'info>,
+        system_program_info: &AccountInfo<'info>,
+    ) -> Result<()> {
+
+        let space: usize = 8 + self.try_to_vec()?.len();
+
+        invoke_signed(
+            &create_account(
+                funder_info.key,
+                pda_info.key,
+                1.max(Rent::get()?.minimum_balance(space)),
+                space as u64,
+                &crate::ID,
+            ),
+            &[
+                funder_info.clone(),
+                pda_info.clone(),
+                system_program_info.clone(),
+            ],
+            &[pda_seeds],
+        )?;
+
+        let mut big_note_account_raw = pda_info.try_borrow_mut_data()?;
+        self.try_serialize(&mut &mut big_note_account_raw[..])
+    }
 }
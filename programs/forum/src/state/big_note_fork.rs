@@ -0,0 +1,20 @@
+use anchor_lang::prelude::*;
+
+#[proc_macros::assert_size(104)] // divisible by 8
+#[repr(C)]
+#[account]
+#[derive(Debug)]
+pub struct BigNoteFork {
+
+    // Big Note which was forked
+    pub parent_big_note: Pubkey,
+
+    // Big Note created by the fork
+    pub fork_big_note: Pubkey,
+
+    // Content data hash of the parent big note at the time of forking
+    pub parent_content_data_hash: Pubkey,
+
+    // ------------- Timestamps
+    pub forked_ts: u64,
+}
@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+
+// Attestations on a verification which needs reverification stay slashable for this long before they can be withdrawn
+pub const ATTESTATION_SLASHING_WINDOW: u64 = 7 * 24 * 60 * 60;
+
+#[proc_macros::assert_size(96)] // divisible by 8
+#[repr(C)]
+#[account]
+#[derive(Debug)]
+pub struct BigNoteVerificationRecord {
+
+    // Big Note for which verification record belongs
+    pub big_note: Pubkey,
+
+    // Incremented with each application for verification (used in generating attestation account PDA addresses)
+    pub verification_round: u64,
+
+    // Number of attestations received in the current round
+    pub attestation_count: u64,
+
+    // Number of attestations required at time of application
+    pub quorum: u64,
+
+    // ------------- Timestamps (verification_expires_ts is zero if the verification does not expire)
+    pub verified_ts: u64,
+
+    pub verification_expires_ts: u64,
+
+    // Set when an edit or expiry leaves the verification needing reverification (zero otherwise)
+    pub reverification_ts: u64,
+
+    // ------------- Verified round superseded by a new application, whose attestations stay slashable until slashable_until_ts
+    pub slashable_round: u64,
+
+    pub slashable_until_ts: u64,
+}
+
+impl BigNoteVerificationRecord {
+
+    // Attestations of a verified round superseded before its slashing window ended can still be slashed (and not withdrawn)
+    pub fn is_superseded_round_slashable(&self, verification_round: u64, now_ts: u64) -> bool {
+        (verification_round == self.slashable_round) && (now_ts <= self.slashable_until_ts)
+    }
+}
@@ -6,5 +6,6 @@ use anchor_lang::prelude::*;
 pub enum BigNoteVerificationState {
     Unverified,
     AppliedForVerification,
-    Verified
+    Verified,
+    NeedsReverification
 }
@@ -7,5 +7,6 @@ use anchor_lang::prelude::*;
 pub enum BountyContributionState {
     Available,
     Awarded,
-    Refunded
+    Refunded,
+    Migrated
 }
@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+
+use crate::state::ForumFees;
+
+// Maximum number of category moderators
+pub const MAX_CATEGORY_MODERATORS: usize = 5;
+
+#[proc_macros::assert_size(368)] // +7 to make it divisible by 8
+#[repr(C)]
+#[account]
+#[derive(Debug)]
+pub struct Category {
+
+    // Forum for which category belongs
+    pub forum: Pubkey,
+
+    // Seed address used to generate unique category account PDA address
+    pub category_seed: Pubkey,
+
+    // ------------- Timestamps
+    pub category_created_ts: u64,
+
+    // ------------- Moderators of this category only (only the first moderator_count entries are populated)
+    pub category_moderators: [Pubkey; MAX_CATEGORY_MODERATORS],
+
+    pub moderator_count: u64,
+
+    // ------------- Fees and minimums applied in place of the forum's when overrides are enabled
+    pub category_fees: ForumFees,
+
+    // ------------- Counts
+    pub question_count: u64,
+
+    pub big_notes_count: u64,
+
+    pub challenge_count: u64,
+
+    // Whether the category fees override the forum fees
+    pub fee_overrides_enabled: bool,
+}
+
+impl Category {
+
+    pub fn fees(&self, forum_fees: &ForumFees) -> ForumFees {
+        if self.fee_overrides_enabled {
+            self.category_fees
+        }
+        else {
+            *forum_fees
+        }
+    }
+
+    pub fn is_category_moderator(&self, moderator_profile: &Pubkey) -> bool {
+        self.category_moderators[..self.moderator_count as usize].contains(moderator_profile)
+    }
+}
@@ -1,6 +1,9 @@
 use anchor_lang::prelude::*;
 
-use crate::state::{Tags};
+use anchor_lang::solana_program::program::{invoke_signed};
+use anchor_lang::solana_program::system_instruction::{create_account};
+
+use crate::state::{ChallengeState, RubricCriterion, Tags};
 
 #[repr(C)]
 #[account]
@@ -31,4 +34,65 @@ pub struct Challenge {
     // ------------- Challenge reputation value
     pub reputation: u64,
 
+    // ------------- Scoring rubric used by judges (empty if submissions are not scored)
+    pub rubric: Vec<RubricCriterion>,
+
+    // ------------- Lifecycle
+    pub challenge_state: ChallengeState,
+
+    // Evaluations and scoring are accepted until this timestamp
+    pub judging_ends_ts: u64,
+
+    pub submission_count: u64,
+
+    pub completion_count: u64,
+
+    // Participation and completion counts recorded when the challenge is closed
+    pub final_submission_count: u64,
+
+    pub final_completion_count: u64,
+
+    // ------------- Category in which the challenge was created
+    pub category: Pubkey,
+
+}
+
+impl Challenge {
+
+    // Evaluations and scores are accepted until the judging period ends or the challenge is closed
+    pub fn is_accepting_evaluations(&self, now_ts: u64) -> bool {
+        (self.challenge_state == ChallengeState::Open || self.challenge_state == ChallengeState::Judging) &&
+            (now_ts <= self.judging_ends_ts)
+    }
+
+    // Create the challenge PDA account sized to fit its contents and write the challenge into it
+    pub fn create_account<'info>(
+        &self,
+        pda_seeds: &[&[u8]],
+        pda_info: &AccountInfo<'info>,
+        funder_info: &AccountInfo<'info>,
+        system_program_info: &AccountInfo<'info>,
+    ) -> Result<()> {
+
+        let space: usize = 8 + self.try_to_vec()?.len();
+
+        invoke_signed(
+            &create_account(
+                funder_info.key,
+                pda_info.key,
+                1.max(Rent::get()?.minimum_balance(space)),
+                space as u64,
+                &crate::ID,
+            ),
+            &[
+                funder_info.clone(),
+                pda_info.clone(),
+                system_program_info.clone(),
+            ],
+            &[pda_seeds],
+        )?;
+
+        let mut challenge_account_raw = pda_info.try_borrow_mut_data()?;
+        self.try_serialize(&mut &mut challenge_account_raw[..])
+    }
 }
@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{LeaderboardEntry};
+
+// Maximum number of ranked entries on a challenge leaderboard
+pub const MAX_LEADERBOARD_ENTRIES: usize = 10;
+
+#[proc_macros::assert_size(776)] // +7 to make it divisible by 8
+#[repr(C)]
+#[account]
+#[derive(Debug)]
+pub struct ChallengeLeaderboard {
+
+    // Challenge for which leaderboard belongs
+    pub challenge: Pubkey,
+
+    // Ranked entries, highest average score first (only the first entry_count entries are populated)
+    pub entries: [LeaderboardEntry; MAX_LEADERBOARD_ENTRIES],
+
+    // Number of ranks kept on the leaderboard (top-N)
+    pub max_entries: u64,
+
+    pub entry_count: u64,
+
+    // Is leaderboard closed and reputation awarded
+    pub is_closed: bool,
+}
+
+impl ChallengeLeaderboard {
+
+    // Insert or update a submission's entry, keeping the entries sorted and truncated to max_entries
+    pub fn record_score(&mut self, submission: Pubkey, user_profile: Pubkey, average_score: u64) {
+
+        let mut entries: Vec<LeaderboardEntry> = self.entries[..self.entry_count as usize].iter()
+            .filter(|x| x.submission != submission).copied().collect();
+
+        // Ties keep the earlier ranked submission ahead
+        let rank = entries.iter().position(|x| x.average_score < average_score).unwrap_or(entries.len());
+        entries.insert(rank, LeaderboardEntry { submission, user_profile, average_score });
+        entries.truncate(self.max_entries as usize);
+
+        self.entries = [LeaderboardEntry::default(); MAX_LEADERBOARD_ENTRIES];
+        self.entries[..entries.len()].copy_from_slice(&entries);
+        self.entry_count = entries.len() as u64;
+    }
+}
@@ -0,0 +1,150 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, TokenAccount, Transfer};
+
+use crate::state::{PrizeDistribution};
+use prog_common::{TryDiv, TryMul, TrySub, errors::ErrorCode};
+
+// Maximum number of ranked prize tiers
+pub const MAX_PRIZE_TIERS: usize = 8;
+
+#[proc_macros::assert_size(144)] // +6 to make it divisible by 8
+#[repr(C)]
+#[account]
+#[derive(Debug)]
+pub struct ChallengePrizePool {
+
+    // Challenge for which prize pool belongs
+    pub challenge: Pubkey,
+
+    // SPL token mint of the prize (default pubkey for lamport prize pools)
+    pub prize_mint: Pubkey,
+
+    // ------------- Prize amounts (in lamports or prize mint tokens)
+    pub total_funded: u64,
+
+    pub total_paid: u64,
+
+    pub total_refunded: u64,
+
+    // Number of sponsors yet to be refunded
+    pub sponsor_count: u64,
+
+    // ------------- Prize distribution info
+
+    // Number of completed submissions to be awarded a prize
+    pub winner_count: u64,
+
+    pub winners_paid: u64,
+
+    // Fixed prize paid to each winner (first n distribution)
+    pub prize_per_winner: u64,
+
+    // Share of the total prize pool in basis points paid to each rank (ranked tiers distribution)
+    pub tier_bps: [u16; MAX_PRIZE_TIERS],
+
+    pub prize_distribution: PrizeDistribution,
+
+    // Are prize payouts closed (set once sponsors start reclaiming the remainder)
+    pub payouts_closed: bool,
+
+}
+
+impl ChallengePrizePool {
+
+    // Prize amount owed to the next completed submission (ranked tiers are paid by final rank when the leaderboard closes)
+    pub fn next_prize_amount(&self) -> Result<u64> {
+
+        if self.payouts_closed || (self.winners_paid >= self.winner_count) {
+            return Ok(0);
+        }
+
+        let prize_amount: u64 = match self.prize_distribution {
+            PrizeDistribution::FirstN => self.prize_per_winner,
+            PrizeDistribution::SplitEvenly => self.total_funded.try_div(self.winner_count)?,
+            PrizeDistribution::RankedTiers => 0,
+        };
+
+        let remaining_amount: u64 = self.remaining_amount()?;
+        Ok(prize_amount.min(remaining_amount))
+    }
+
+    // Prize amount owed to the submission with the given final leaderboard rank (zero-based)
+    pub fn ranked_prize_amount(&self, rank: u64) -> Result<u64> {
+
+        if self.payouts_closed || (self.prize_distribution != PrizeDistribution::RankedTiers) || (rank >= self.winner_count) {
+            return Ok(0);
+        }
+
+        let prize_amount: u64 = self.total_funded.try_mul(self.tier_bps[rank as usize] as u64)?.try_div(10000)?;
+
+        let remaining_amount: u64 = self.remaining_amount()?;
+        Ok(prize_amount.min(remaining_amount))
+    }
+
+    // Prize amount neither paid out nor refunded
+    pub fn remaining_amount(&self) -> Result<u64> {
+        self.total_funded.try_sub(self.total_paid)?.try_sub(self.total_refunded)
+    }
+
+    pub fn is_spl(&self) -> bool {
+        self.prize_mint != Pubkey::default()
+    }
+
+    // Transfer prize tokens from a wallet into the prize vault
+    // Token accounts: [prize vault, wallet token account, token program]
+    pub fn transfer_to_vault<'info>(&self, prize_pool: &Pubkey, wallet: &AccountInfo<'info>, token_accounts: &[AccountInfo<'info>], amount: u64) -> Result<()> {
+
+        let (prize_vault, wallet_token_account, token_program) = self.verify_token_accounts(prize_pool, wallet.key, token_accounts)?;
+
+        token::transfer(
+            CpiContext::new(
+                token_program.clone(),
+                Transfer { from: wallet_token_account.clone(), to: prize_vault.clone(), authority: wallet.clone() },
+            ),
+            amount,
+        )
+    }
+
+    // Transfer prize tokens out of the prize vault to a wallet, signed by the prize pool PDA
+    // Token accounts: [prize vault, wallet token account, token program]
+    pub fn transfer_from_vault<'info>(&self, prize_pool: &AccountInfo<'info>, wallet: &Pubkey, token_accounts: &[AccountInfo<'info>], amount: u64) -> Result<()> {
+
+        let (prize_vault, wallet_token_account, token_program) = self.verify_token_accounts(prize_pool.key, wallet, token_accounts)?;
+
+        let (_pk, bump) = Pubkey::find_program_address(
+            &[b"challenge_prize_pool".as_ref(), self.challenge.as_ref()],
+            &crate::ID,
+        );
+
+        token::transfer(
+            CpiContext::new_with_signer(
+                token_program.clone(),
+                Transfer { from: prize_vault.clone(), to: wallet_token_account.clone(), authority: prize_pool.clone() },
+                &[&[b"challenge_prize_pool".as_ref(), self.challenge.as_ref(), &[bump]]],
+            ),
+            amount,
+        )
+    }
+
+    fn verify_token_accounts<'a, 'info>(&self, prize_pool: &Pubkey, wallet: &Pubkey, token_accounts: &'a [AccountInfo<'info>])
+        -> Result<(&'a AccountInfo<'info>, &'a AccountInfo<'info>, &'a AccountInfo<'info>)> {
+
+        let [prize_vault, wallet_token_account, token_program, ..] = token_accounts else {
+            return Err(error!(ErrorCode::InvalidPrizeTokenAccounts));
+        };
+
+        let (prize_vault_address, _bump) = Pubkey::find_program_address(
+            &[b"prize_pool_vault".as_ref(), prize_pool.as_ref()],
+            &crate::ID,
+        );
+
+        let wallet_token_account_valid = Account::<TokenAccount>::try_from(wallet_token_account).ok()
+            .map_or(false, |token_account| (token_account.owner == *wallet) && (token_account.mint == self.prize_mint));
+
+        if (prize_vault.key() != prize_vault_address) || !wallet_token_account_valid || (token_program.key() != token::ID) {
+            return Err(error!(ErrorCode::InvalidPrizeTokenAccounts));
+        }
+
+        Ok((prize_vault, wallet_token_account, token_program))
+    }
+}
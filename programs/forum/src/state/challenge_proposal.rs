@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{ChallengeProposalState};
+
+#[proc_macros::assert_size(128)] // +7 to make it divisible by 8
+#[repr(C)]
+#[account]
+#[derive(Debug)]
+pub struct ChallengeProposal {
+
+    // Forum for which challenge proposal belongs
+    pub forum: Pubkey,
+
+    // Proposed challenge
+    pub challenge: Pubkey,
+
+    // Profile of user proposing the challenge
+    pub author_profile: Pubkey,
+
+    // ------------- Timestamps
+    pub proposal_posted_ts: u64,
+
+    pub proposal_reviewed_ts: u64,
+
+    // ------------- Deposit held in this account until the proposal is reviewed
+    pub deposit_amount: u64,
+
+    pub proposal_state: ChallengeProposalState,
+}
+
+impl ChallengeProposal {
+
+    // A challenge without a proposal PDA account was created by a moderator, otherwise its proposal must have been approved
+    pub fn is_challenge_approved(challenge_proposal: &AccountInfo) -> Result<bool> {
+        if challenge_proposal.data_is_empty() {
+            return Ok(true);
+        }
+
+        let challenge_proposal = Account::<ChallengeProposal>::try_from(challenge_proposal)?;
+        Ok(challenge_proposal.proposal_state == ChallengeProposalState::Approved)
+    }
+}
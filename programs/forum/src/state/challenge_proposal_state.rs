@@ -0,0 +1,10 @@
+use anchor_lang::prelude::*;
+
+// Careful: Typescript does not like multiple successive capital letters such as NFTs. Using CamelCase naming is fine.
+
+#[derive(Debug, Copy, Clone, AnchorSerialize, AnchorDeserialize, PartialEq)]
+pub enum ChallengeProposalState {
+    Pending,
+    Approved,
+    Rejected
+}
@@ -1,12 +1,14 @@
 use anchor_lang::prelude::*;
 
-#[proc_macros::assert_size(152)] // divisible by 8
+use crate::state::{CommentTarget};
+
+#[proc_macros::assert_size(176)] // +7 to make it divisible by 8
 #[repr(C)]
 #[account]
 #[derive(Debug)]
 pub struct Comment {
 
-    // Account pubkey of Question/Answer/BigNote/ProposedContribution for which comment belongs
+    // Account pubkey of Question/Answer/BigNote/ProposedContribution/Challenge/Submission/Comment for which comment belongs
     pub commented_on: Pubkey,
 
     // Profile of user providing comment
@@ -25,4 +27,12 @@ pub struct Comment {
 
     // ------------- Comment reputation value
     pub comment_rep: u64,
+
+    // ------------- Comment thread info (depth is 0 for comments not left as replies)
+    pub comment_depth: u64,
+
+    pub reply_count: u64,
+
+    // Type of account for which comment belongs
+    pub comment_target: CommentTarget,
 }
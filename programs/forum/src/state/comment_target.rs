@@ -0,0 +1,139 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{Answer, BigNote, Challenge, Comment, ProposedContribution, Question, Submission};
+use prog_common::{errors::ErrorCode};
+
+// Careful: Typescript does not like multiple successive capital letters such as NFTs. Using CamelCase naming is fine.
+
+#[derive(Debug, Copy, Clone, AnchorSerialize, AnchorDeserialize, PartialEq)]
+pub enum CommentTarget {
+    Question,
+    Answer,
+    BigNote,
+    ProposedContribution,
+    Challenge,
+    Submission,
+    Comment
+}
+
+impl CommentTarget {
+
+    // Ensure the commented on account is a program account of this target type which belongs to the forum.
+    // Comments on submissions require the submission's challenge account to be passed in as the first remaining account.
+    pub fn verify_commented_on<'info>(&self, commented_on: &AccountInfo<'info>, forum: &Pubkey, remaining_accounts: &[AccountInfo<'info>]) -> Result<()> {
+
+        let belongs_to_forum: bool = match self {
+            CommentTarget::Question => {
+                let question = Account::<Question>::try_from(commented_on)?;
+                question.forum == *forum
+            }
+            CommentTarget::Answer => {
+                let answer = Account::<Answer>::try_from(commented_on)?;
+                is_program_address(commented_on, &[b"answer".as_ref(), forum.as_ref(), answer.user_profile.as_ref(), answer.answer_seed.as_ref()])
+            }
+            CommentTarget::BigNote => {
+                let big_note = Account::<BigNote>::try_from(commented_on)?;
+                big_note.forum == *forum
+            }
+            CommentTarget::ProposedContribution => {
+                let proposed_contribution = Account::<ProposedContribution>::try_from(commented_on)?;
+                is_program_address(commented_on, &[b"proposed_contribution".as_ref(), forum.as_ref(), proposed_contribution.user_profile.as_ref(),
+                                                   proposed_contribution.proposed_contribution_seed.as_ref()])
+            }
+            CommentTarget::Challenge => {
+                let challenge = Account::<Challenge>::try_from(commented_on)?;
+                challenge.forum == *forum
+            }
+            CommentTarget::Submission => {
+                let submission = Account::<Submission>::try_from(commented_on)?;
+                let challenge_account_info = remaining_accounts.first().ok_or(error!(ErrorCode::InvalidCommentTarget))?;
+                let challenge = Account::<Challenge>::try_from(challenge_account_info)?;
+                (challenge_account_info.key() == submission.challenge) && (challenge.forum == *forum)
+            }
+            CommentTarget::Comment => {
+                let comment = Account::<Comment>::try_from(commented_on)?;
+                is_program_address(commented_on, &[b"comment".as_ref(), forum.as_ref(), comment.user_profile.as_ref(), comment.comment_seed.as_ref()])
+            }
+        };
+
+        if !belongs_to_forum {
+            return Err(error!(ErrorCode::InvalidCommentTarget));
+        }
+
+        Ok(())
+    }
+
+    // Resolve the category of the question, big note or challenge at the root of a comment thread. Comments on answers,
+    // proposed contributions and submissions need that root passed in as the next remaining account, and replies need
+    // each parent comment up the thread passed in first.
+    pub fn category<'info>(&self, commented_on: &AccountInfo<'info>, remaining_accounts: &[AccountInfo<'info>]) -> Result<Pubkey> {
+
+        let (parent, parent_target): (Pubkey, CommentTarget) = match self {
+            CommentTarget::Question => return Ok(Account::<Question>::try_from(commented_on)?.category),
+            CommentTarget::BigNote => return Ok(Account::<BigNote>::try_from(commented_on)?.category),
+            CommentTarget::Challenge => return Ok(Account::<Challenge>::try_from(commented_on)?.category),
+            CommentTarget::Answer => (Account::<Answer>::try_from(commented_on)?.question, CommentTarget::Question),
+            CommentTarget::ProposedContribution => (Account::<ProposedContribution>::try_from(commented_on)?.big_note, CommentTarget::BigNote),
+            CommentTarget::Submission => (Account::<Submission>::try_from(commented_on)?.challenge, CommentTarget::Challenge),
+            CommentTarget::Comment => {
+                let comment = Account::<Comment>::try_from(commented_on)?;
+                (comment.commented_on, comment.comment_target)
+            }
+        };
+
+        let (parent_account_info, remaining_accounts) = remaining_accounts.split_first().ok_or(error!(ErrorCode::InvalidCommentTarget))?;
+
+        if parent_account_info.key() != parent {
+            return Err(error!(ErrorCode::InvalidCommentTarget));
+        }
+
+        parent_target.category(parent_account_info, remaining_accounts)
+    }
+
+    // Update the commented on account's most recent engagement timestamp (challenges do not record engagement)
+    pub fn record_engagement(&self, commented_on: &AccountInfo, now_ts: u64) -> Result<()> {
+
+        match self {
+            CommentTarget::Question => {
+                let mut question = Account::<Question>::try_from(commented_on)?;
+                question.most_recent_engagement_ts = now_ts;
+                question.exit(&crate::ID)?;
+            }
+            CommentTarget::Answer => {
+                let mut answer = Account::<Answer>::try_from(commented_on)?;
+                answer.most_recent_engagement_ts = now_ts;
+                answer.exit(&crate::ID)?;
+            }
+            CommentTarget::BigNote => {
+                let mut big_note = Account::<BigNote>::try_from(commented_on)?;
+                big_note.most_recent_engagement_ts = now_ts;
+                big_note.exit(&crate::ID)?;
+            }
+            CommentTarget::ProposedContribution => {
+                let mut proposed_contribution = Account::<ProposedContribution>::try_from(commented_on)?;
+                proposed_contribution.most_recent_engagement_ts = now_ts;
+                proposed_contribution.exit(&crate::ID)?;
+            }
+            CommentTarget::Challenge => {}
+            CommentTarget::Submission => {
+                let mut submission = Account::<Submission>::try_from(commented_on)?;
+                submission.most_recent_engagement_ts = now_ts;
+                submission.exit(&crate::ID)?;
+            }
+            CommentTarget::Comment => {
+                let mut comment = Account::<Comment>::try_from(commented_on)?;
+                comment.most_recent_engagement_ts = now_ts;
+                comment.exit(&crate::ID)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+// Auxiliary helper functions
+
+fn is_program_address(account_info: &AccountInfo, seeds: &[&[u8]]) -> bool {
+    let (pda, _bump) = Pubkey::find_program_address(seeds, &crate::ID);
+    pda == account_info.key()
+}
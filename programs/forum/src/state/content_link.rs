@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{ContentRelationType, LinkedContentType};
+
+#[proc_macros::assert_size(144)] // +5 to make it divisible by 8
+#[repr(C)]
+#[account]
+#[derive(Debug)]
+pub struct ContentLink {
+
+    // Forum for which content link belongs
+    pub forum: Pubkey,
+
+    // Profile of moderator creating the content link
+    pub moderator_profile: Pubkey,
+
+    // Account pubkey of the Question/BigNote/Challenge being linked from
+    pub source_content: Pubkey,
+
+    // Account pubkey of the Question/BigNote/Challenge being linked to
+    pub target_content: Pubkey,
+
+    // ------------- Timestamps
+    pub content_link_posted_ts: u64,
+
+    // ------------- Content Link Info
+    pub source_content_type: LinkedContentType,
+
+    pub target_content_type: LinkedContentType,
+
+    // How the source content relates to the target content
+    pub relation_type: ContentRelationType,
+}
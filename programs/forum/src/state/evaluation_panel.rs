@@ -0,0 +1,29 @@
+use anchor_lang::prelude::*;
+
+// Maximum number of judges on an evaluation panel
+pub const MAX_JUDGES: usize = 8;
+
+#[proc_macros::assert_size(304)] // divisible by 8
+#[repr(C)]
+#[account]
+#[derive(Debug)]
+pub struct EvaluationPanel {
+
+    // Challenge for which evaluation panel belongs
+    pub challenge: Pubkey,
+
+    // User profiles of the judges (unused slots are default pubkeys)
+    pub judges: [Pubkey; MAX_JUDGES],
+
+    pub judge_count: u64,
+
+    // Number of matching verdicts required to finalize a submission's evaluation
+    pub quorum: u64,
+}
+
+impl EvaluationPanel {
+
+    pub fn is_judge(&self, profile: &Pubkey) -> bool {
+        self.judges[..self.judge_count as usize].contains(profile)
+    }
+}
@@ -0,0 +1,22 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{SubmissionState};
+
+#[proc_macros::assert_size(80)] // +7 to make it divisible by 8
+#[repr(C)]
+#[account]
+#[derive(Debug)]
+pub struct EvaluationVote {
+
+    // Submission for which vote belongs
+    pub submission: Pubkey,
+
+    // Profile of judge casting the vote
+    pub judge_profile: Pubkey,
+
+    // ------------- Timestamps
+    pub vote_ts: u64,
+
+    // ------------- Verdict (Completed or Rejected)
+    pub verdict: SubmissionState,
+}
@@ -0,0 +1,17 @@
+use anchor_lang::prelude::*;
+
+#[proc_macros::assert_size(72)] // divisible by 8
+#[repr(C)]
+#[account]
+#[derive(Debug)]
+pub struct Follow {
+
+    // Profile doing the following
+    pub follower_profile: Pubkey,
+
+    // Profile being followed
+    pub followed_profile: Pubkey,
+
+    // ------------- Timestamps
+    pub followed_ts: u64,
+}
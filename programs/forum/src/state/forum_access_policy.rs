@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{TokenAccount};
+
+use crate::state::{verified_collection, AccessPolicyType, ForumAllowlistEntry};
+
+#[proc_macros::assert_size(88)] // +7 to make it divisible by 8
+#[repr(C)]
+#[account]
+#[derive(Debug)]
+pub struct ForumAccessPolicy {
+
+    // Forum for which access policy belongs
+    pub forum: Pubkey,
+
+    // SPL token mint (TokenHolder) or Metaplex collection (CollectionHolder) required for admission
+    pub access_mint: Pubkey,
+
+    // Minimum amount of the access mint to be held (TokenHolder)
+    pub min_token_amount: u64,
+
+    // Length of time in seconds after which a profile's eligibility must be rechecked (zero if never)
+    pub recheck_period: u64,
+
+    pub access_policy_type: AccessPolicyType,
+}
+
+impl ForumAccessPolicy {
+
+    // Check whether the wallet meets the access policy, using proof accounts depending on the policy type:
+    // TokenHolder: [token account], CollectionHolder: [token account, metadata account], Allowlist: [allowlist entry]
+    pub fn is_eligible(&self, wallet: &Pubkey, proof_accounts: &[AccountInfo]) -> bool {
+
+        match self.access_policy_type {
+            AccessPolicyType::Open => true,
+            AccessPolicyType::TokenHolder => {
+                proof_accounts.first()
+                    .and_then(|token_account| Account::<TokenAccount>::try_from(token_account).ok())
+                    .map_or(false, |token_account| (token_account.owner == *wallet) && (token_account.mint == self.access_mint) &&
+                        (token_account.amount >= self.min_token_amount.max(1)))
+            }
+            AccessPolicyType::CollectionHolder => {
+                let (Some(token_account), Some(metadata)) = (proof_accounts.first(), proof_accounts.get(1)) else {
+                    return false;
+                };
+
+                Account::<TokenAccount>::try_from(token_account).ok()
+                    .filter(|token_account| (token_account.owner == *wallet) && (token_account.amount >= 1))
+                    .and_then(|token_account| verified_collection(metadata, &token_account.mint))
+                    .map_or(false, |collection| collection == self.access_mint)
+            }
+            AccessPolicyType::Allowlist => {
+                let (allowlist_entry_address, _bump) = Pubkey::find_program_address(
+                    &[b"forum_allowlist_entry".as_ref(), self.forum.as_ref(), wallet.as_ref()],
+                    &crate::ID,
+                );
+
+                proof_accounts.first()
+                    .filter(|allowlist_entry| allowlist_entry.key() == allowlist_entry_address)
+                    .and_then(|allowlist_entry| Account::<ForumAllowlistEntry>::try_from(allowlist_entry).ok())
+                    .is_some()
+            }
+        }
+    }
+}
@@ -0,0 +1,17 @@
+use anchor_lang::prelude::*;
+
+#[proc_macros::assert_size(72)] // divisible by 8
+#[repr(C)]
+#[account]
+#[derive(Debug)]
+pub struct ForumAllowlistEntry {
+
+    // Forum for which allowlist entry belongs
+    pub forum: Pubkey,
+
+    // Wallet admitted to the forum
+    pub wallet: Pubkey,
+
+    // ------------- Timestamps
+    pub entry_added_ts: u64,
+}
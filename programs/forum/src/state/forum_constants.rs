@@ -1,6 +1,8 @@
 use anchor_lang::prelude::*;
 
-#[proc_macros::assert_size(32)] // divisible by 8
+use crate::state::HandleCharacterSet;
+
+#[proc_macros::assert_size(112)] // +7 to make it divisible by 8
 #[repr(C)]
 #[derive(Debug, Copy, Clone, AnchorSerialize, AnchorDeserialize)]
 pub struct ForumConstants {
@@ -17,4 +19,34 @@ pub struct ForumConstants {
     // minimum amount of inactivity time in seconds before possible refund request
     pub min_inactivity_period: u64,
 
+    // maximum depth of nested replies to comments
+    pub max_comment_depth: u64,
+
+    // minimum reputation required for a user to propose a challenge
+    pub min_challenge_proposal_rep: u64,
+
+    // maximum number of attempts (including the first) a user can make at a challenge submission
+    pub max_submission_attempts: u64,
+
+    // length of the judging period in seconds after a challenge expires
+    pub challenge_judging_period: u64,
+
+    // number of independent attestations required to verify a big note
+    pub big_note_verification_quorum: u64,
+
+    // minimum reputation required for a non-moderator to attest to a big note's verification
+    pub min_attester_rep: u64,
+
+    // length of time in seconds a big note verification remains valid (zero if verifications do not expire)
+    pub big_note_verification_validity_period: u64,
+
+    // minimum number of characters in a handle
+    pub min_handle_length: u64,
+
+    // maximum number of characters in a handle (at most 32)
+    pub max_handle_length: u64,
+
+    // characters allowed in a handle
+    pub handle_character_set: HandleCharacterSet,
+
 }
@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 
-#[proc_macros::assert_size(64)] // divisible by 8
+#[proc_macros::assert_size(96)] // divisible by 8
 #[repr(C)]
 #[derive(Debug, Copy, Clone, AnchorSerialize, AnchorDeserialize)]
 pub struct ForumFees {
@@ -29,4 +29,16 @@ pub struct ForumFees {
     // Minimum bounty amount that can accompany a big notes solicitation
     pub forum_big_notes_bounty_minimum: u64,
 
+    // Challenge proposal deposit (flat deposit held until a moderator reviews the proposal)
+    pub forum_challenge_proposal_deposit: u64,
+
+    // Share of the challenge submission fee refunded in basis points when a submission is rejected
+    pub forum_rejected_submission_refund: u64,
+
+    // Verification attestation stake (flat stake held until the verification round ends, slashable on revocation)
+    pub forum_verification_attestation_stake: u64,
+
+    // Handle registration fee (flat handle registration fee)
+    pub forum_handle_registration_fee: u64,
+
 }
@@ -0,0 +1,23 @@
+use anchor_lang::prelude::*;
+
+// Maximum number of characters in a handle (bounded by the maximum PDA seed length)
+pub const MAX_HANDLE_LENGTH: u64 = 32;
+
+#[repr(C)]
+#[account]
+#[derive(Debug)]
+pub struct Handle {
+
+    // Forum for which handle belongs
+    pub forum: Pubkey,
+
+    // Profile the handle points to
+    pub user_profile: Pubkey,
+
+    // ------------- Timestamps
+    pub handle_claimed_ts: u64,
+
+    // ------------- Normalized lowercase handle (also used to generate the unique handle account PDA address)
+    pub handle_name: String,
+
+}
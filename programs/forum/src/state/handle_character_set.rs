@@ -0,0 +1,22 @@
+use anchor_lang::prelude::*;
+
+// Careful: Typescript does not like multiple successive capital letters such as NFTs. Using CamelCase naming is fine.
+
+#[derive(Debug, Copy, Clone, AnchorSerialize, AnchorDeserialize, PartialEq)]
+pub enum HandleCharacterSet {
+    Letters,
+    Alphanumeric,
+    AlphanumericUnderscore
+}
+
+impl HandleCharacterSet {
+
+    // Handles are normalized to lowercase, so only lowercase ascii letters are ever permitted
+    pub fn permits(&self, character: u8) -> bool {
+        match self {
+            HandleCharacterSet::Letters => character.is_ascii_lowercase(),
+            HandleCharacterSet::Alphanumeric => character.is_ascii_lowercase() || character.is_ascii_digit(),
+            HandleCharacterSet::AlphanumericUnderscore => character.is_ascii_lowercase() || character.is_ascii_digit() || (character == b'_'),
+        }
+    }
+}
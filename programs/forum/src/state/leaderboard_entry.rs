@@ -0,0 +1,16 @@
+use anchor_lang::prelude::*;
+
+#[proc_macros::assert_size(72)] // divisible by 8
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Default, AnchorSerialize, AnchorDeserialize)]
+pub struct LeaderboardEntry {
+
+    // ranked submission pda account
+    pub submission: Pubkey,
+
+    // submission owner's user profile pda account
+    pub user_profile: Pubkey,
+
+    // average weighted rubric score given by judges
+    pub average_score: u64,
+}
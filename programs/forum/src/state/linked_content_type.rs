@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{BigNote, Challenge, Question};
+use prog_common::{errors::ErrorCode};
+
+// Careful: Typescript does not like multiple successive capital letters such as NFTs. Using CamelCase naming is fine.
+
+#[derive(Debug, Copy, Clone, AnchorSerialize, AnchorDeserialize, PartialEq)]
+pub enum LinkedContentType {
+    Question,
+    BigNote,
+    Challenge
+}
+
+impl LinkedContentType {
+
+    // Ensure the linked content account is a program account of this content type which belongs to the forum
+    pub fn verify_linked_content(&self, linked_content: &AccountInfo, forum: &Pubkey) -> Result<()> {
+
+        let content_forum: Pubkey = match self {
+            LinkedContentType::Question => Account::<Question>::try_from(linked_content)?.forum,
+            LinkedContentType::BigNote => Account::<BigNote>::try_from(linked_content)?.forum,
+            LinkedContentType::Challenge => Account::<Challenge>::try_from(linked_content)?.forum,
+        };
+
+        if content_forum != *forum {
+            return Err(error!(ErrorCode::InvalidLinkedContent));
+        }
+
+        Ok(())
+    }
+}
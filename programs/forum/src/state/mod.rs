@@ -1,46 +1,124 @@
 pub mod about_me;
+pub mod access_policy_type;
 pub mod answer;
 pub mod big_note;
+pub mod big_note_fork;
 pub mod big_note_type;
 pub mod big_note_verification_application;
+pub mod big_note_verification_record;
 pub mod big_note_verification_state;
 pub mod bounty_contribution;
 pub mod bounty_contribution_state;
+pub mod category;
 pub mod challenge;
+pub mod challenge_leaderboard;
+pub mod challenge_prize_pool;
+pub mod challenge_proposal;
+pub mod challenge_proposal_state;
+pub mod challenge_state;
 pub mod comment;
+pub mod comment_target;
+pub mod content_link;
+pub mod content_relation_type;
+pub mod evaluation_panel;
+pub mod evaluation_vote;
+pub mod follow;
 pub mod forum;
+pub mod forum_access_policy;
+pub mod forum_allowlist_entry;
 pub mod forum_constants;
 pub mod forum_counts;
 pub mod forum_fees;
+pub mod handle;
+pub mod handle_character_set;
+pub mod leaderboard_entry;
+pub mod linked_content_type;
+pub mod peer_review;
+pub mod pfp_collection_allowlist;
+pub mod prize_contribution;
+pub mod prize_distribution;
 pub mod proposed_contribution;
 pub mod proposed_contribution_state;
 pub mod question;
 pub mod reputation_matrix;
+pub mod revision;
+pub mod rubric_criterion;
+pub mod score_card;
+pub mod session_key;
+pub mod session_scope;
 pub mod submission;
+pub mod submission_attempt;
+pub mod submission_fee_escrow;
 pub mod submission_state;
+pub mod subscription;
+pub mod subscription_target;
 pub mod tags;
+pub mod track;
+pub mod track_completion;
+pub mod track_membership;
 pub mod user_profile;
+pub mod verification_attestation;
+pub mod wallet_rotation;
 
 pub use about_me::*;
+pub use access_policy_type::*;
 pub use answer::*;
 pub use big_note::*;
+pub use big_note_fork::*;
 pub use big_note_type::*;
 pub use big_note_verification_application::*;
+pub use big_note_verification_record::*;
 pub use big_note_verification_state::*;
 pub use bounty_contribution::*;
 pub use bounty_contribution_state::*;
+pub use category::*;
 pub use challenge::*;
+pub use challenge_leaderboard::*;
+pub use challenge_prize_pool::*;
+pub use challenge_proposal::*;
+pub use challenge_proposal_state::*;
+pub use challenge_state::*;
 pub use comment::*;
+pub use comment_target::*;
+pub use content_link::*;
+pub use content_relation_type::*;
+pub use evaluation_panel::*;
+pub use evaluation_vote::*;
+pub use follow::*;
 pub use forum::*;
+pub use forum_access_policy::*;
+pub use forum_allowlist_entry::*;
 pub use forum_constants::*;
 pub use forum_counts::*;
 pub use forum_fees::*;
+pub use handle::*;
+pub use handle_character_set::*;
+pub use leaderboard_entry::*;
+pub use linked_content_type::*;
+pub use peer_review::*;
+pub use pfp_collection_allowlist::*;
+pub use prize_contribution::*;
+pub use prize_distribution::*;
 pub use proposed_contribution::*;
 pub use proposed_contribution_state::*;
 pub use question::*;
 pub use reputation_matrix::*;
+pub use revision::*;
+pub use rubric_criterion::*;
+pub use score_card::*;
+pub use session_key::*;
+pub use session_scope::*;
 pub use submission::*;
+pub use submission_attempt::*;
+pub use submission_fee_escrow::*;
 pub use submission_state::*;
+pub use subscription::*;
+pub use subscription_target::*;
 pub use tags::*;
+pub use track::*;
+pub use track_completion::*;
+pub use track_membership::*;
 pub use user_profile::*;
+pub use verification_attestation::*;
+pub use wallet_rotation::*;
 
@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{SubmissionState};
+
+#[proc_macros::assert_size(88)] // +6 to make it divisible by 8
+#[repr(C)]
+#[account]
+#[derive(Debug)]
+pub struct PeerReview {
+
+    // Submission for which review belongs
+    pub submission: Pubkey,
+
+    // Profile of user reviewing the submission
+    pub reviewer_profile: Pubkey,
+
+    // Submission attempt reviewed
+    pub attempt_number: u64,
+
+    // ------------- Timestamps
+    pub review_ts: u64,
+
+    // ------------- Verdict (Completed or Rejected)
+    pub verdict: SubmissionState,
+
+    // Has reviewer been rewarded or penalized against the final decision
+    pub is_settled: bool,
+}
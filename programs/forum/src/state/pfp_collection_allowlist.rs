@@ -0,0 +1,79 @@
+use anchor_lang::prelude::*;
+
+// Maximum number of collections in a forum's PFP collection allowlist
+pub const MAX_PFP_COLLECTIONS: usize = 10;
+
+// Metaplex token metadata program (metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s)
+pub const TOKEN_METADATA_PROGRAM_ID: Pubkey = Pubkey::new_from_array([
+    11, 112, 101, 177, 227, 209, 124, 69, 56, 157, 82, 127, 107, 4, 195, 205,
+    88, 184, 108, 115, 26, 160, 253, 181, 73, 182, 209, 188, 3, 248, 41, 70,
+]);
+
+#[proc_macros::assert_size(360)] // divisible by 8
+#[repr(C)]
+#[account]
+#[derive(Debug)]
+pub struct PfpCollectionAllowlist {
+
+    // Forum for which allowlist belongs
+    pub forum: Pubkey,
+
+    // Metaplex collections whose NFTs may be set as PFPs (only the first collection_count entries are populated)
+    pub collections: [Pubkey; MAX_PFP_COLLECTIONS],
+
+    pub collection_count: u64,
+}
+
+impl PfpCollectionAllowlist {
+
+    pub fn is_allowed(&self, collection: &Pubkey) -> bool {
+        self.collections[..self.collection_count as usize].contains(collection)
+    }
+}
+
+// Returns the verified collection of the mint's Metaplex metadata account, if it has one
+pub fn verified_collection(metadata: &AccountInfo, mint: &Pubkey) -> Option<Pubkey> {
+
+    let (metadata_address, _bump) = Pubkey::find_program_address(
+        &[b"metadata".as_ref(), TOKEN_METADATA_PROGRAM_ID.as_ref(), mint.as_ref()],
+        &TOKEN_METADATA_PROGRAM_ID,
+    );
+
+    if (*metadata.owner != TOKEN_METADATA_PROGRAM_ID) || (metadata.key() != metadata_address) {
+        return None;
+    }
+
+    let data = metadata.try_borrow_data().ok()?;
+
+    // Skip the key, update authority and mint, then the name, symbol and uri strings and the seller fee basis points
+    let mut offset: usize = 1 + 32 + 32;
+
+    for _ in 0..3 {
+        let string_length = u32::from_le_bytes(data.get(offset..offset + 4)?.try_into().ok()?) as usize;
+        offset += 4 + string_length;
+    }
+
+    offset += 2;
+
+    // Skip the optional creators vector (34 bytes per creator)
+    if *data.get(offset)? == 1 {
+        let creator_count = u32::from_le_bytes(data.get(offset + 1..offset + 5)?.try_into().ok()?) as usize;
+        offset += 5 + creator_count * 34;
+    } else {
+        offset += 1;
+    }
+
+    // Skip primary sale happened and is mutable, then the optional edition nonce and token standard
+    offset += 2;
+
+    for _ in 0..2 {
+        offset += if *data.get(offset)? == 1 { 2 } else { 1 };
+    }
+
+    // Read the optional collection (verified flag followed by the collection key)
+    if *data.get(offset)? != 1 || *data.get(offset + 1)? != 1 {
+        return None;
+    }
+
+    Some(Pubkey::new_from_array(data.get(offset + 2..offset + 34)?.try_into().ok()?))
+}
@@ -0,0 +1,20 @@
+use anchor_lang::prelude::*;
+
+#[proc_macros::assert_size(80)] // divisible by 8
+#[repr(C)]
+#[account]
+#[derive(Debug)]
+pub struct PrizeContribution {
+
+    // Prize pool for which contribution belongs
+    pub prize_pool: Pubkey,
+
+    // Profile of sponsor funding the prize pool
+    pub sponsor_profile: Pubkey,
+
+    // ------------- Timestamps
+    pub most_recent_contribution_ts: u64,
+
+    // ------------- Amount contributed (in lamports)
+    pub contributed_amount: u64,
+}
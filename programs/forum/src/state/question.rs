@@ -41,4 +41,19 @@ pub struct Question {
     // Is Bounty Awarded
     pub bounty_awarded: bool,
 
+    // ------------- Number of edits made (used in generating revision account PDA addresses)
+    pub revision_count: u64,
+
+    // ------------- Canonical question if marked as a duplicate (default pubkey otherwise)
+    pub duplicate_of: Pubkey,
+
+    // ------------- Ownership (the seeding user profile never changes, the current owner can be transferred)
+    pub current_owner: Pubkey,
+
+    // Profile nominated to take over ownership (default pubkey otherwise)
+    pub pending_owner: Pubkey,
+
+    // ------------- Category in which the question was created
+    pub category: Pubkey,
+
 }
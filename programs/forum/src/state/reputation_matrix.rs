@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 
-#[proc_macros::assert_size(80)] // divisible by 8
+#[proc_macros::assert_size(104)] // divisible by 8
 #[repr(C)]
 #[derive(Debug, Copy, Clone, AnchorSerialize, AnchorDeserialize)]
 pub struct ReputationMatrix {
@@ -25,4 +25,10 @@ pub struct ReputationMatrix {
 
     pub bounty_contribution_rep: u64,
 
+    pub approved_challenge_proposal_rep: u64,
+
+    pub peer_review_match_rep: u64,
+
+    pub peer_review_mismatch_penalty: u64,
+
 }
@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+
+#[repr(C)]
+#[account]
+#[derive(Debug)]
+pub struct Revision {
+
+    // Account pubkey of Question/Answer/BigNote for which revision belongs
+    pub content: Pubkey,
+
+    // Profile of user which made the edit
+    pub editor_profile: Pubkey,
+
+    // Index of revision for the content account (used in generating revision account PDA address)
+    pub revision_number: u64,
+
+    // ------------- Timestamps
+    pub revision_ts: u64,
+
+    // ------------- Previous Content Info (title and url are empty strings for answers)
+    pub previous_content_data_hash: Pubkey,
+
+    // Was edit made with moderator privileges
+    pub moderator_edit: bool,
+
+    pub previous_title: String,
+
+    pub previous_content_data_url: String,
+
+}
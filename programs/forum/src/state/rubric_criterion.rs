@@ -0,0 +1,16 @@
+use anchor_lang::prelude::*;
+
+// Maximum number of criteria in a challenge rubric
+pub const MAX_RUBRIC_CRITERIA: usize = 8;
+
+#[proc_macros::assert_size(16)] // divisible by 8
+#[repr(C)]
+#[derive(Debug, Copy, Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct RubricCriterion {
+
+    // maximum score a judge may give for this criterion
+    pub max_score: u64,
+
+    // multiplier applied to this criterion's score in the weighted total
+    pub weight: u64,
+}
@@ -0,0 +1,20 @@
+use anchor_lang::prelude::*;
+
+#[proc_macros::assert_size(80)] // divisible by 8
+#[repr(C)]
+#[account]
+#[derive(Debug)]
+pub struct ScoreCard {
+
+    // Submission for which score card belongs
+    pub submission: Pubkey,
+
+    // Profile of judge giving the scores
+    pub judge_profile: Pubkey,
+
+    // ------------- Timestamps
+    pub score_card_posted_ts: u64,
+
+    // ------------- Weighted total of the rubric scores
+    pub weighted_score: u64,
+}
@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+
+use crate::state::SessionScope;
+
+#[proc_macros::assert_size(120)] // +7 to make it divisible by 8
+#[repr(C)]
+#[account]
+#[derive(Debug)]
+pub struct SessionKey {
+
+    // User profile on whose behalf the delegate acts
+    pub user_profile: Pubkey,
+
+    // Wallet owning the user profile when the session key was registered (invalidated by wallet rotation)
+    pub profile_owner: Pubkey,
+
+    // Delegate key allowed to sign for the user profile
+    pub delegate: Pubkey,
+
+    // ------------- Timestamps
+    pub session_created_ts: u64,
+
+    pub expires_ts: u64,
+
+    // ------------- Actions the delegate may perform
+    pub session_scope: SessionScope,
+}
@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+
+use crate::state::SessionKey;
+use prog_common::{errors::ErrorCode};
+
+// Careful: Typescript does not like multiple successive capital letters such as NFTs. Using CamelCase naming is fine.
+
+#[derive(Debug, Copy, Clone, AnchorSerialize, AnchorDeserialize, PartialEq)]
+pub enum SessionScope {
+    CommentAndAnswer,
+    PostAndEdit
+}
+
+impl SessionScope {
+
+    // Posting and editing scope includes commenting and answering, neither scope includes any handler moving funds
+    pub fn permits(&self, required_scope: SessionScope) -> bool {
+        match self {
+            SessionScope::CommentAndAnswer => required_scope == SessionScope::CommentAndAnswer,
+            SessionScope::PostAndEdit => true,
+        }
+    }
+
+    // Ensure the signer is either the profile owner or a delegate holding an unexpired session key which permits this scope.
+    // The session key account is expected to be the PDA seeded by the user profile and the signer, and is empty when the owner signs.
+    pub fn verify_authority<'info>(&self, profile_owner: &Pubkey, authority: &Pubkey, session_key: &AccountInfo<'info>, now_ts: u64) -> Result<()> {
+
+        if *authority == *profile_owner {
+            return Ok(());
+        }
+
+        if session_key.data_is_empty() {
+            return Err(error!(ErrorCode::InvalidSessionKey));
+        }
+
+        let session_key = Account::<SessionKey>::try_from(session_key)?;
+
+        if (session_key.delegate != *authority) || (session_key.profile_owner != *profile_owner) {
+            return Err(error!(ErrorCode::InvalidSessionKey));
+        }
+
+        if now_ts > session_key.expires_ts {
+            return Err(error!(ErrorCode::SessionKeyExpired));
+        }
+
+        if !session_key.session_scope.permits(*self) {
+            return Err(error!(ErrorCode::SessionScopeNotPermitted));
+        }
+
+        Ok(())
+    }
+}
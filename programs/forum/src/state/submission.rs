@@ -2,7 +2,7 @@ use anchor_lang::prelude::*;
 
 use crate::state::{SubmissionState};
 
-#[proc_macros::assert_size(120)] // +7 to make it divisible by 8
+#[proc_macros::assert_size(168)] // +7 to make it divisible by 8
 #[repr(C)]
 #[account]
 #[derive(Debug)]
@@ -26,4 +26,30 @@ pub struct Submission {
     // Is Challenge completed
     pub submission_state: SubmissionState,
 
+    // ------------- Evaluation panel votes
+    pub approval_votes: u64,
+
+    pub rejection_votes: u64,
+
+    // ------------- Rubric scores given by judges
+    pub total_score: u64,
+
+    pub score_count: u64,
+
+    // ------------- Peer reviews given by other completers
+    pub peer_review_count: u64,
+
+    // ------------- Number of times the submission has been resubmitted after rejection
+    pub revision_count: u64,
+
+}
+
+impl Submission {
+
+    // Completed, rejected, voted, scored, peer reviewed or resubmitted submissions have child PDA accounts and credit which
+    // deleting them would leave behind (the submission could otherwise be recreated at the same address)
+    pub fn has_evaluation_state(&self) -> bool {
+        (self.submission_state != SubmissionState::Pending) || (self.approval_votes > 0) || (self.rejection_votes > 0) ||
+            (self.score_count > 0) || (self.peer_review_count > 0) || (self.revision_count > 0)
+    }
 }
@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{SubmissionState};
+
+#[proc_macros::assert_size(120)] // +7 to make it divisible by 8
+#[repr(C)]
+#[account]
+#[derive(Debug)]
+pub struct SubmissionAttempt {
+
+    // Submission for which attempt belongs
+    pub submission: Pubkey,
+
+    // Revision number of the attempt (the first attempt is 0)
+    pub attempt_number: u64,
+
+    // ------------- Timestamps
+    pub attempt_posted_ts: u64,
+
+    // ------------- Attempt Info
+
+    pub content_data_hash: Pubkey,
+
+    // ------------- Evaluation panel votes and rubric scores received
+    pub approval_votes: u64,
+
+    pub rejection_votes: u64,
+
+    pub total_score: u64,
+
+    pub score_count: u64,
+
+    // Final state of the attempt
+    pub submission_state: SubmissionState,
+}
@@ -0,0 +1,17 @@
+use anchor_lang::prelude::*;
+
+#[proc_macros::assert_size(72)] // divisible by 8
+#[repr(C)]
+#[account]
+#[derive(Debug)]
+pub struct SubmissionFeeEscrow {
+
+    // Submission for which the fee was paid
+    pub submission: Pubkey,
+
+    // Profile of user who paid the fee
+    pub user_profile: Pubkey,
+
+    // ------------- Fee held in this account until the submission is evaluated
+    pub fee_amount: u64,
+}
@@ -0,0 +1,23 @@
+use anchor_lang::prelude::*;
+
+use crate::state::SubscriptionTarget;
+
+// Keep subscribed_to as the first field so notifiers can find a post's subscribers with a memcmp filter at offset 8
+#[proc_macros::assert_size(80)] // +7 to make it divisible by 8
+#[repr(C)]
+#[account]
+#[derive(Debug)]
+pub struct Subscription {
+
+    // Question/BigNote/Challenge PDA account subscribed to
+    pub subscribed_to: Pubkey,
+
+    // Profile subscribing
+    pub subscriber_profile: Pubkey,
+
+    // ------------- Timestamps
+    pub subscribed_ts: u64,
+
+    // ------------- Type of account subscribed to
+    pub subscription_target: SubscriptionTarget,
+}
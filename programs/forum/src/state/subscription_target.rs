@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{BigNote, Challenge, Question};
+use prog_common::{errors::ErrorCode};
+
+// Careful: Typescript does not like multiple successive capital letters such as NFTs. Using CamelCase naming is fine.
+
+#[derive(Debug, Copy, Clone, AnchorSerialize, AnchorDeserialize, PartialEq)]
+pub enum SubscriptionTarget {
+    Question,
+    BigNote,
+    Challenge
+}
+
+impl SubscriptionTarget {
+
+    // Ensure the subscribed to account is a program account of this target type which belongs to the forum
+    pub fn verify_subscribed_to(&self, subscribed_to: &AccountInfo, forum: &Pubkey) -> Result<()> {
+
+        let belongs_to_forum: bool = match self {
+            SubscriptionTarget::Question => Account::<Question>::try_from(subscribed_to)?.forum == *forum,
+            SubscriptionTarget::BigNote => Account::<BigNote>::try_from(subscribed_to)?.forum == *forum,
+            SubscriptionTarget::Challenge => Account::<Challenge>::try_from(subscribed_to)?.forum == *forum,
+        };
+
+        if !belongs_to_forum {
+            return Err(error!(ErrorCode::InvalidSubscriptionTarget));
+        }
+
+        Ok(())
+    }
+}
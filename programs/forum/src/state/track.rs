@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+
+// Maximum number of challenges in a track
+pub const MAX_TRACK_CHALLENGES: usize = 10;
+
+#[proc_macros::assert_size(408)] // divisible by 8
+#[repr(C)]
+#[account]
+#[derive(Debug)]
+pub struct Track {
+
+    // Forum for which track belongs
+    pub forum: Pubkey,
+
+    // Seed used to generate unique track account PDA address
+    pub track_seed: Pubkey,
+
+    // Challenges in the order they must be completed (only the first challenge_count entries are populated)
+    pub challenges: [Pubkey; MAX_TRACK_CHALLENGES],
+
+    pub challenge_count: u64,
+
+    // ------------- Timestamps
+    pub track_created_ts: u64,
+
+    // ------------- Bonus reputation awarded on completing every challenge in the track
+    pub completion_rep: u64,
+}
@@ -0,0 +1,20 @@
+use anchor_lang::prelude::*;
+
+#[proc_macros::assert_size(80)] // divisible by 8
+#[repr(C)]
+#[account]
+#[derive(Debug)]
+pub struct TrackCompletion {
+
+    // Track which has been completed
+    pub track: Pubkey,
+
+    // Profile of user completing the track
+    pub user_profile: Pubkey,
+
+    // ------------- Timestamps
+    pub track_completed_ts: u64,
+
+    // ------------- Bonus reputation awarded
+    pub completion_rep: u64,
+}
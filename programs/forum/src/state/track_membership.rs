@@ -0,0 +1,19 @@
+use anchor_lang::prelude::*;
+
+#[proc_macros::assert_size(104)] // divisible by 8
+#[repr(C)]
+#[account]
+#[derive(Debug)]
+pub struct TrackMembership {
+
+    // Track for which challenge belongs
+    pub track: Pubkey,
+
+    pub challenge: Pubkey,
+
+    // Challenge which must be completed before submitting to this challenge (default pubkey for the first challenge)
+    pub prerequisite_challenge: Pubkey,
+
+    // Position of the challenge in the track
+    pub track_position: u64,
+}
@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 
-#[proc_macros::assert_size(224)] // +5 to make it divisible by 8
+#[proc_macros::assert_size(360)] // +3 to make it divisible by 8
 #[repr(C)]
 #[account]
 #[derive(Debug)]
@@ -12,12 +12,21 @@ pub struct UserProfile {
 
     pub forum: Pubkey,
 
+    // Wallet the profile PDA was created with (used in its seeds, unchanged by wallet rotation)
+    pub profile_seed: Pubkey,
+
+    // Wallet approved by the profile owner to take over the profile (default pubkey otherwise)
+    pub pending_profile_owner: Pubkey,
+
     // ------------- Timestamps
 
     pub profile_created_ts: u64,
 
     pub most_recent_engagement_ts: u64,
 
+    // Last time the profile owner was found to meet the forum's access policy
+    pub eligibility_checked_ts: u64,
+
     // ------------- user engagement counters
 
     pub big_notes_created: u64,
@@ -28,10 +37,14 @@ pub struct UserProfile {
 
     pub big_notes_contributions_accepted: u64,
 
+    pub big_notes_credited: u64,
+
     pub challenges_submitted: u64,
 
     pub challenges_completed: u64,
 
+    pub tracks_completed: u64,
+
     pub questions_asked: u64,
 
     pub questions_answered: u64,
@@ -46,14 +59,28 @@ pub struct UserProfile {
 
     pub reputation_score: u64,
 
+    // ------------- social graph counters
+
+    pub follower_count: u64,
+
+    pub following_count: u64,
+
     // ------------- miscellaneous
 
     pub nft_pfp_token_mint: Pubkey,
 
+    // Token account verified to hold the NFT PFP when it was set
+    pub nft_pfp_token_account: Pubkey,
+
     pub has_about_me: bool,
 
     pub has_had_about_me: bool,
 
     pub is_moderator: bool,
 
+    pub has_handle: bool,
+
+    // Suspended after failing to prove continued eligibility under the forum's access policy
+    pub is_suspended: bool,
+
 }
@@ -0,0 +1,26 @@
+use anchor_lang::prelude::*;
+
+#[proc_macros::assert_size(96)] // +7 to make it divisible by 8
+#[repr(C)]
+#[account]
+#[derive(Debug)]
+pub struct VerificationAttestation {
+
+    // Big Note for which attestation belongs
+    pub big_note: Pubkey,
+
+    // Profile of moderator or expert attesting to the big note
+    pub attester_profile: Pubkey,
+
+    // Verification round attested to
+    pub verification_round: u64,
+
+    // ------------- Timestamps
+    pub attestation_ts: u64,
+
+    // ------------- Stake held in this account until the verification round ends
+    pub stake_amount: u64,
+
+    // Has stake been slashed following a revoked verification
+    pub is_slashed: bool,
+}
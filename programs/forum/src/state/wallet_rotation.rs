@@ -0,0 +1,23 @@
+use anchor_lang::prelude::*;
+
+#[proc_macros::assert_size(136)] // divisible by 8
+#[repr(C)]
+#[account]
+#[derive(Debug)]
+pub struct WalletRotation {
+
+    // Forum for which the wallet rotation belongs
+    pub forum: Pubkey,
+
+    // User profile taken over by the new wallet
+    pub user_profile: Pubkey,
+
+    // Wallet which owned the user profile before the rotation
+    pub previous_profile_owner: Pubkey,
+
+    // Wallet which owns the user profile after the rotation
+    pub new_profile_owner: Pubkey,
+
+    // ------------- Timestamps
+    pub rotated_ts: u64,
+}
@@ -3,6 +3,7 @@ import { BigNoteType, Tags } from '../../forum.client';
 
 type BigNoteConfig = {
     forum: PublicKey,
+    category: PublicKey,
     bigNoteType: BigNoteType,
     tags: Tags[],
     title: string,
@@ -14,6 +15,7 @@ type BigNoteConfig = {
 export const bigNoteConfig: BigNoteConfig =
     {
         forum: new PublicKey("J462agvz9tZyNFYgBgPwbnXDvf1qhGpwGrmEYowQrky3"),
+        category: new PublicKey("11111111111111111111111111111111"), // replace with a category account created for this forum
         bigNoteType: {creatorCurated: {}} as never,
         tags: [{toolsAndInfrastructure: {}} as never, {development: {}} as never],
         title: "xAndria Onchain",
@@ -1,7 +1,9 @@
 import { PublicKey } from '@solana/web3.js';
+import { CommentTarget, commentTargetValues } from '../../forum.client';
 
 type CommentConfig = {
     commentedOn: PublicKey,
+    commentTarget: CommentTarget,
     forum: PublicKey,
     content: string,
 }
@@ -9,7 +11,8 @@ type CommentConfig = {
 // // User 1
 // export const commentConfig: CommentConfig =
 //     {
-//         commentedOn:  new PublicKey("7JUWv7LtxSWWCZPaNUjQvxn9tS69iXcV9B66CaYWBVvd"), // must be question or answer account key
+//         commentedOn:  new PublicKey("7JUWv7LtxSWWCZPaNUjQvxn9tS69iXcV9B66CaYWBVvd"), // question, answer, big note, proposed contribution, challenge, submission or comment account key
+//         commentTarget: commentTargetValues.Question, // must match the kind of account commented on
 //         forum: new PublicKey("J462agvz9tZyNFYgBgPwbnXDvf1qhGpwGrmEYowQrky3"),
 //         content: "Please remove the excess tags which do not apply.",
 //     }
@@ -17,7 +20,8 @@ type CommentConfig = {
 // // User 4
 // export const commentConfig: CommentConfig =
 //     {
-//         commentedOn:  new PublicKey("7JUWv7LtxSWWCZPaNUjQvxn9tS69iXcV9B66CaYWBVvd"), // must be question or answer account key
+//         commentedOn:  new PublicKey("7JUWv7LtxSWWCZPaNUjQvxn9tS69iXcV9B66CaYWBVvd"), // question, answer, big note, proposed contribution, challenge, submission or comment account key
+//         commentTarget: commentTargetValues.Question, // must match the kind of account commented on
 //         forum: new PublicKey("J462agvz9tZyNFYgBgPwbnXDvf1qhGpwGrmEYowQrky3"),
 //         content: "Hey, leave my engagement farming alone! I'm just here trying to get some alpha.",
 //     }
@@ -25,7 +29,8 @@ type CommentConfig = {
 // User 2
 export const commentConfig: CommentConfig =
     {
-        commentedOn:  new PublicKey("DZGzyKtKjSnR6Q4A29n1nc7RsLKsCqGm7GsKb4pY4fRd"), // must be question or answer account key
+        commentedOn:  new PublicKey("DZGzyKtKjSnR6Q4A29n1nc7RsLKsCqGm7GsKb4pY4fRd"), // question, answer, big note, proposed contribution, challenge, submission or comment account key
+        commentTarget: commentTargetValues.Question, // must match the kind of account commented on
         forum: new PublicKey("J462agvz9tZyNFYgBgPwbnXDvf1qhGpwGrmEYowQrky3"),
         content: "LOL! DeGods are long gone dude.",
     }
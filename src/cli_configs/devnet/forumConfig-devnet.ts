@@ -1,5 +1,5 @@
 import { BN } from '@coral-xyz/anchor';
-import { ForumConstants, ForumFees, ReputationMatrix } from '../../forum.client';
+import { ForumConstants, ForumFees, ReputationMatrix, handleCharacterSetValues } from '../../forum.client';
 
 type ForumConfig = {
     forumFees: ForumFees,
@@ -18,12 +18,26 @@ export const forumConfig: ForumConfig =
             forumChallengeSubmissionFee: new BN(10_000_000), // 0.01 Sol
             forumQuestionBountyMinimum: new BN(100_000_000), // 0.1 Sol
             forumBigNotesBountyMinimum: new BN(500_000_000), // 0.5 Sol
+            forumChallengeProposalDeposit: new BN(100_000_000), // 0.1 Sol
+            forumRejectedSubmissionRefund: new BN(5000), // 5000 basis points
+            forumVerificationAttestationStake: new BN(10_000_000), // 0.01 Sol
+            forumHandleRegistrationFee: new BN(10_000_000), // 0.01 Sol
         },
         forumConstants: {
             maxTagsLength: new BN(3),
             maxTitleLength: new BN(256),
             maxUrlLength: new BN(256),
             minInactivityPeriod: new BN(2_592_000), // 30 days in seconds
+            maxCommentDepth: new BN(5),
+            minChallengeProposalRep: new BN(1_000),
+            maxSubmissionAttempts: new BN(3),
+            challengeJudgingPeriod: new BN(604_800), // 7 days in seconds
+            bigNoteVerificationQuorum: new BN(3),
+            minAttesterRep: new BN(1_000),
+            bigNoteVerificationValidityPeriod: new BN(31_536_000), // 365 days in seconds
+            minHandleLength: new BN(3),
+            maxHandleLength: new BN(32),
+            handleCharacterSet: handleCharacterSetValues.AlphanumericUnderscore,
         },
         reputationMatrix: {
             aboutMeRep: new BN(100), // Accessible once, relates to 0.1 Sol profile fee (100 rep per 0.1 Sol, however this content is the least valuable - reason for disproportionate rep)
@@ -36,6 +50,9 @@ export const forumConfig: ForumConfig =
             proposedBigNotesContributionRep: new BN(100), // 100 rep for each 0.5 Sol multiple (since protocol takes 0.01 of each 0.5 Sol multiple, this gives 100 rep per 0.01 raised)
             acceptedBigNotesContributionProposalRep: new BN(200), // 250 rep for valuable contribution
             bountyContributionRep: new BN(100), // 100 rep for each 0.1 Sol multiple (since protocol takes 0.0035 of each 0.1 Sol multiple, this give 100 rep per 0.0035 raised)
+            approvedChallengeProposalRep: new BN(200), // 200 rep for a challenge proposal approved by a moderator
+            peerReviewMatchRep: new BN(10), // 10 rep for a peer review that matches the judges' verdict
+            peerReviewMismatchPenalty: new BN(10), // 10 rep removed for a peer review that contradicts the judges' verdict
         }
     }
 
@@ -4,6 +4,7 @@ import { Tags } from '../../forum.client';
 
 type QuestionConfig = {
     forum: PublicKey,
+    category: PublicKey,
     tags: Tags[],
     title: string,
     content: string,
@@ -15,6 +16,7 @@ type QuestionConfig = {
 export const questionConfig: QuestionConfig =
     {
         forum: new PublicKey("4KHj59a12hW2HaJkAwDwHNAGBminH8n9kYYXPg5AzP1x"),
+        category: new PublicKey("11111111111111111111111111111111"), // replace with a category account created for this forum
         tags: [{deFi: {}} as never, {nfts: {}} as never, {trading: {}} as never],
         title: "What are the best NFTs to buy right now, in the bear market?",
         content: "I've been grinding day & night and I've acquired enough Solana to invest into 5-10 blue chip project NFTs." +
@@ -28,6 +30,7 @@ export const questionConfig: QuestionConfig =
 // export const questionConfig: QuestionConfig =
 //     {
 //         forum: new PublicKey("J462agvz9tZyNFYgBgPwbnXDvf1qhGpwGrmEYowQrky3"),
+//         category: new PublicKey("11111111111111111111111111111111"), // replace with a category account created for this forum
 //         tags: [{development: {}} as never, {gaming: {}} as never, {toolsAndInfrastructure: {}} as never],
 //         title: "What are the Instructions required to create an SFT?",
 //         content: "This question was taken from https://solana.stackexchange.com/questions/6066/what-are-the-instructions-required-to-create-a-sft \n" +
@@ -41,6 +44,7 @@ export const questionConfig: QuestionConfig =
 // export const questionConfig: QuestionConfig =
 //     {
 //         forum: new PublicKey("J462agvz9tZyNFYgBgPwbnXDvf1qhGpwGrmEYowQrky3"),
+//         category: new PublicKey("11111111111111111111111111111111"), // replace with a category account created for this forum
 //         tags: [{mobile: {}} as never, {payments: {}} as never, {development: {}} as never],
 //         title: "Fetch all() isn't working in Anchor Client",
 //         content: " I am trying to fetch all accounts owned by my program using all() method but it is giving me this nasty error: \n ```Uncaught (in promise) ReferenceError: Buffer is not defined at AccountClient.all``` \n" +
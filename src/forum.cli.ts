@@ -6,7 +6,7 @@ import { hash } from 'blake3';
 
 import { FORUM_PROG_ID } from './index';
 import { IDL as ForumIDL } from './forum.types';
-import { ForumClient, Tags } from './forum.client';
+import { CommentTarget, ForumClient, Tags } from './forum.client';
 import { findQuestionBountyPDA, findForumAuthorityPDA } from './forum.pda';
 import { ForumConstants, ForumFees, ReputationMatrix } from './forum.client';
 import { stringifyPKsAndBNs } from './render-types';
@@ -569,6 +569,7 @@ const parser = yargs(process.argv.slice(2)).options({
                  );
 
                  const forumKey: PublicKey = questionConfig.forum;
+                 const categoryKey: PublicKey = questionConfig.category;
                  const tags: Tags[] = questionConfig.tags;
                  const title: string = questionConfig.title;
                  const contentDataUrl = questionConfig.contentDataUrl;
@@ -584,6 +585,7 @@ const parser = yargs(process.argv.slice(2)).options({
                      const questionInstance = await forumClient.askQuestion(
                          forumKey,
                          wallet.payer,
+                         categoryKey,
                          contentDataHash,
                          tags,
                          title,
@@ -1067,9 +1069,9 @@ const parser = yargs(process.argv.slice(2)).options({
 
 
 
-// Leave Comment on Question
+// Leave Comment
 // Must config comment parameters in commentConfig
-    .command('leave-comment-on-question', 'Leave comment on question', {
+    .command('leave-comment', 'Leave comment on a question, answer, big note, proposed contribution, challenge, submission or comment', {
     },
              async (argv) => {
                  const rpcConn = new Connection(networkConfig.clusterApiUrl, { confirmTransactionInitialTimeout: 91000 });
@@ -1081,231 +1083,33 @@ const parser = yargs(process.argv.slice(2)).options({
                      FORUM_PROG_ID,
                  );
 
-                 const questionKey: PublicKey = commentConfig.commentedOn;
+                 const forumKey: PublicKey = commentConfig.forum;
+                 const commentedOnKey: PublicKey = commentConfig.commentedOn;
+                 const commentTarget: CommentTarget = commentConfig.commentTarget;
 
                  const contentString: string = commentConfig.content;
                  const hashResult = hash(contentString);
                  const contentDataHash: PublicKey = new PublicKey(hashResult);
 
                  if (!argv.dryRun) {
-                     const commentInstance = await forumClient.leaveCommentOnQuestion(
-                         questionKey,
-                         wallet.payer,
-                         contentDataHash,
-                     );
-                     console.log(stringifyPKsAndBNs(commentInstance));
-                 } else {
-                     console.log('Leaving comment on question account with address', stringifyPKsAndBNs(questionKey));
-                 }
-             })
-
-
-
-// Edit comment on Question
-// Must config comment parameters in commentConfig
-    .command('edit-comment-on-question', 'Edit comment on question', {
-        commentPubkey: {
-            alias: 'c',
-            type: 'string',
-            demandOption: true,
-            description: 'comment account pubkey'
-        }
-    },
-             async (argv) => {
-                 const rpcConn = new Connection(networkConfig.clusterApiUrl, { confirmTransactionInitialTimeout: 91000 });
-                 const wallet: anchor.Wallet = new anchor.Wallet(await loadWallet(networkConfig.signerKeypair));
-                 const forumClient: ForumClient = new ForumClient(
-                     rpcConn,
-                     wallet,
-                     ForumIDL,
-                     FORUM_PROG_ID,
-                 );
-
-                 const commentKey: PublicKey = new PublicKey(argv.commentPubkey);
-
-                 const newContentString: string = commentConfig.content;
-                 const hashResult = hash(newContentString);
-                 const newContentDataHash: PublicKey = new PublicKey(hashResult);
-
-                 if (!argv.dryRun) {
-                     const editCommentInstance = await forumClient.editCommentOnQuestion(
-                         commentKey,
-                         wallet.payer,
-                         newContentDataHash,
-                     );
-                     console.log(stringifyPKsAndBNs(editCommentInstance));
-                 } else {
-                     console.log('Editing comment account with address', stringifyPKsAndBNs(commentKey));
-                 }
-             })
-
-
-
-// Edit Comment on Question Moderator
-// Must config comment parameters in commentConfig
-    .command('edit-comment-on-question-moderator', 'Moderator edits comment on question', {
-        commentPubkey: {
-            alias: 'c',
-            type: 'string',
-            demandOption: true,
-            description: 'comment account pubkey'
-        }
-    },
-             async (argv) => {
-                 const rpcConn = new Connection(networkConfig.clusterApiUrl, { confirmTransactionInitialTimeout: 91000 });
-                 const wallet: anchor.Wallet = new anchor.Wallet(await loadWallet(networkConfig.signerKeypair));
-                 const forumClient: ForumClient = new ForumClient(
-                     rpcConn,
-                     wallet,
-                     ForumIDL,
-                     FORUM_PROG_ID,
-                 );
-
-                 const commentKey: PublicKey = new PublicKey(argv.commentPubkey);
-
-                 const newContentString: string = commentConfig.content;
-                 const hashResult = hash(newContentString);
-                 const newContentDataHash: PublicKey = new PublicKey(hashResult);
-
-                 if (!argv.dryRun) {
-                     const editCommentModeratorInstance = await forumClient.editCommentOnQuestionModerator(
-                         commentKey,
-                         wallet.payer,
-                         newContentDataHash,
-                     );
-                     console.log(stringifyPKsAndBNs(editCommentModeratorInstance));
-                 } else {
-                     console.log('Moderator editing comment account with address', stringifyPKsAndBNs(commentKey));
-                 }
-             })
-
-
-
-// Delete Comment on Question
-    .command('delete-comment-on-question', 'Delete comment on question', {
-        commentPubkey: {
-            alias: 'c',
-            type: 'string',
-            demandOption: true,
-            description: 'comment account pubkey'
-        },
-        receiverPubkey: {
-            alias: 'r',
-            type: 'string',
-            demandOption: false,
-            description: 'receiver account pubkey for reclaimed rent lamports'
-        }
-    },
-             async (argv) => {
-                 const rpcConn = new Connection(networkConfig.clusterApiUrl, { confirmTransactionInitialTimeout: 91000 });
-                 const wallet: anchor.Wallet = new anchor.Wallet(await loadWallet(networkConfig.signerKeypair));
-                 const forumClient: ForumClient = new ForumClient(
-                     rpcConn,
-                     wallet,
-                     ForumIDL,
-                     FORUM_PROG_ID,
-                 );
-
-                 const commentKey = new PublicKey(argv.commentPubkey);
-                 const receiverKey: PublicKey = argv.receiverPubkey ? new PublicKey(argv.receiverPubkey) : wallet.publicKey;
-
-                 if (!argv.dryRun) {
-                     const deleteCommentInstance = await forumClient.deleteCommentOnQuestion(
-                         commentKey,
-                         wallet.payer,
-                         receiverKey
-                     );
-                     console.log(stringifyPKsAndBNs(deleteCommentInstance));
-                 } else {
-                     console.log('Deleting comment account with address', commentKey.toBase58());
-                 }
-             })
-
-
-
-// Delete Comment on Question Moderator (signer required: moderator)
-    .command('delete-comment-on-question-moderator', 'Moderator deletes comment on question', {
-        commentPubkey: {
-            alias: 'c',
-            type: 'string',
-            demandOption: true,
-            description: 'comment account pubkey'
-        },
-        receiverPubkey: {
-            alias: 'r',
-            type: 'string',
-            demandOption: false,
-            description: 'receiver account pubkey for reclaimed rent lamports'
-        }
-    },
-             async (argv) => {
-                 const rpcConn = new Connection(networkConfig.clusterApiUrl, { confirmTransactionInitialTimeout: 91000 });
-                 const wallet: anchor.Wallet = new anchor.Wallet(await loadWallet(networkConfig.signerKeypair));
-                 const forumClient: ForumClient = new ForumClient(
-                     rpcConn,
-                     wallet,
-                     ForumIDL,
-                     FORUM_PROG_ID,
-                 );
-
-                 const commentKey = new PublicKey(argv.commentPubkey);
-                 const receiverKey: PublicKey = argv.receiverPubkey ? new PublicKey(argv.receiverPubkey) : wallet.publicKey;
-
-                 if (!argv.dryRun) {
-                     const deleteCommentModeratorInstance = await forumClient.deleteCommentOnQuestionModerator(
-                         commentKey,
-                         wallet.payer,
-                         receiverKey
-                     );
-                     console.log(stringifyPKsAndBNs(deleteCommentModeratorInstance));
-                 } else {
-                     console.log('Moderator deleting comment account with address', commentKey.toBase58());
-                 }
-             })
-
-
-
-// -------------------------------------------------- comment related instructions -----------------------------------------------
-
-
-
-// Leave Comment on Answer
-// Must config comment parameters in commentConfig
-    .command('leave-comment-on-answer', 'Leave comment on answer', {
-    },
-             async (argv) => {
-                 const rpcConn = new Connection(networkConfig.clusterApiUrl, { confirmTransactionInitialTimeout: 91000 });
-                 const wallet: anchor.Wallet = new anchor.Wallet(await loadWallet(networkConfig.signerKeypair));
-                 const forumClient: ForumClient = new ForumClient(
-                     rpcConn,
-                     wallet,
-                     ForumIDL,
-                     FORUM_PROG_ID,
-                 );
-
-                 const answerKey: PublicKey = commentConfig.commentedOn;
-
-                 const contentString: string = commentConfig.content;
-                 const hashResult = hash(contentString);
-                 const contentDataHash: PublicKey = new PublicKey(hashResult);
-
-                 if (!argv.dryRun) {
-                     const commentInstance = await forumClient.leaveCommentOnAnswer(
-                         answerKey,
+                     const commentInstance = await forumClient.leaveComment(
+                         forumKey,
+                         commentedOnKey,
+                         commentTarget,
                          wallet.payer,
                          contentDataHash,
                      );
                      console.log(stringifyPKsAndBNs(commentInstance));
                  } else {
-                     console.log('Leaving comment on answer account with address', stringifyPKsAndBNs(answerKey));
+                     console.log('Leaving comment on account with address', stringifyPKsAndBNs(commentedOnKey));
                  }
              })
 
 
 
-// Edit Comment on Answer
+// Edit Comment
 // Must config comment parameters in commentConfig
-    .command('edit-comment-on-answer', 'Edit comment on answer', {
+    .command('edit-comment', 'Edit comment', {
         commentPubkey: {
             alias: 'c',
             type: 'string',
@@ -1330,7 +1134,7 @@ const parser = yargs(process.argv.slice(2)).options({
                  const newContentDataHash: PublicKey = new PublicKey(hashResult);
 
                  if (!argv.dryRun) {
-                     const editCommentInstance = await forumClient.editCommentOnAnswer(
+                     const editCommentInstance = await forumClient.editComment(
                          commentKey,
                          wallet.payer,
                          newContentDataHash,
@@ -1343,9 +1147,9 @@ const parser = yargs(process.argv.slice(2)).options({
 
 
 
-// Edit Comment on Answer Moderator
+// Edit Comment Moderator
 // Must config comment parameters in commentConfig
-    .command('edit-comment-on-answer-moderator', 'Moderator edits comment on answer', {
+    .command('edit-comment-moderator', 'Moderator edits comment', {
         commentPubkey: {
             alias: 'c',
             type: 'string',
@@ -1370,7 +1174,7 @@ const parser = yargs(process.argv.slice(2)).options({
                  const newContentDataHash: PublicKey = new PublicKey(hashResult);
 
                  if (!argv.dryRun) {
-                     const editCommentModeratorInstance = await forumClient.editCommentOnAnswerModerator(
+                     const editCommentModeratorInstance = await forumClient.editCommentModerator(
                          commentKey,
                          wallet.payer,
                          newContentDataHash,
@@ -1383,8 +1187,8 @@ const parser = yargs(process.argv.slice(2)).options({
 
 
 
-// Delete Comment on Answer
-    .command('delete-comment-on-answer', 'Delete comment on answer', {
+// Delete Comment
+    .command('delete-comment', 'Delete comment', {
         commentPubkey: {
             alias: 'c',
             type: 'string',
@@ -1412,7 +1216,7 @@ const parser = yargs(process.argv.slice(2)).options({
                  const receiverKey: PublicKey = argv.receiverPubkey ? new PublicKey(argv.receiverPubkey) : wallet.publicKey;
 
                  if (!argv.dryRun) {
-                     const deleteCommentInstance = await forumClient.deleteCommentOnAnswer(
+                     const deleteCommentInstance = await forumClient.deleteComment(
                          commentKey,
                          wallet.payer,
                          receiverKey
@@ -1425,8 +1229,8 @@ const parser = yargs(process.argv.slice(2)).options({
 
 
 
-// Delete Comment on Answer Moderator (signer required: moderator)
-    .command('delete-comment-on-answer-moderator', 'Moderator deletes comment on answer', {
+// Delete Comment Moderator (signer required: moderator)
+    .command('delete-comment-moderator', 'Moderator deletes comment', {
         commentPubkey: {
             alias: 'c',
             type: 'string',
@@ -1454,7 +1258,7 @@ const parser = yargs(process.argv.slice(2)).options({
                  const receiverKey: PublicKey = argv.receiverPubkey ? new PublicKey(argv.receiverPubkey) : wallet.publicKey;
 
                  if (!argv.dryRun) {
-                     const deleteCommentModeratorInstance = await forumClient.deleteCommentOnQuestionModerator(
+                     const deleteCommentModeratorInstance = await forumClient.deleteCommentModerator(
                          commentKey,
                          wallet.payer,
                          receiverKey
@@ -1486,6 +1290,7 @@ const parser = yargs(process.argv.slice(2)).options({
                  );
 
                  const forumKey: PublicKey = bigNoteConfig.forum;
+                 const categoryKey: PublicKey = bigNoteConfig.category;
                  const bigNoteType = bigNoteConfig.bigNoteType;
                  const tags = bigNoteConfig.tags;
                  const title: string = bigNoteConfig.title;
@@ -1499,6 +1304,7 @@ const parser = yargs(process.argv.slice(2)).options({
                      const bigNoteInstance = await forumClient.createBigNote(
                          forumKey,
                          wallet.payer,
+                         categoryKey,
                          contentDataHash,
                          bigNoteType,
                          tags,
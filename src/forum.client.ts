@@ -1,6 +1,7 @@
 import * as anchor from '@coral-xyz/anchor';
 import { AnchorProvider, BN, Idl, IdlTypes, Program } from '@coral-xyz/anchor';
 import { Connection, Keypair, PublicKey, SystemProgram } from '@solana/web3.js';
+import { ASSOCIATED_TOKEN_PROGRAM_ID, Token, TOKEN_PROGRAM_ID } from '@solana/spl-token';
 import { Forum } from './forum.types';
 import {
     findForumAuthorityPDA,
@@ -18,6 +19,22 @@ import {
     findQuestionBountyPDA,
     findBigNoteBountyPDA,
     findVerificationFeePDA,
+    findWalletRotationPDA,
+    findForumAccessPolicyPDA,
+    findPfpCollectionAllowlistPDA,
+    findCategoryPDA,
+    findNftMetadataPDA,
+    findSessionKeyPDA,
+    findRevisionPDA,
+    findVerificationRecordPDA,
+    findVerificationAttestationPDA,
+    findChallengeProposalPDA,
+    findTrackMembershipPDA,
+    findChallengePrizePoolPDA,
+    findEvaluationPanelPDA,
+    findEvaluationVotePDA,
+    findSubmissionFeeEscrowPDA,
+    findPrizePoolVaultPDA,
 } from './forum.pda';
 
 // Enum: BigNoteType
@@ -30,14 +47,34 @@ export const bigNoteTypeValues = {
 export const bigNoteVerificationStateValues = {
     Unverified: {unverified: {}},
     AppliedForVerification: {appliedForVerification: {}},
-    Verified: {verified: {}}
+    Verified: {verified: {}},
+    NeedsReverification: {needsReverification: {}}
 }
 
 // Enum: BountyContributionState
 export const bountyContributionStateValues = {
     Available: {available: {}},
     Awarded: {awarded: {}},
-    Refunded: {refunded: {}}
+    Refunded: {refunded: {}},
+    Migrated: {migrated: {}}
+}
+
+// Enum: CommentTarget
+export const commentTargetValues = {
+    Question: {question: {}},
+    Answer: {answer: {}},
+    BigNote: {bigNote: {}},
+    ProposedContribution: {proposedContribution: {}},
+    Challenge: {challenge: {}},
+    Submission: {submission: {}},
+    Comment: {comment: {}}
+}
+
+// Enum: HandleCharacterSet
+export const handleCharacterSetValues = {
+    Letters: {letters: {}},
+    Alphanumeric: {alphanumeric: {}},
+    AlphanumericUnderscore: {alphanumericUnderscore: {}}
 }
 
 // Enum: ProposedContributionState
@@ -71,6 +108,8 @@ export const tagValues = {
 export type BigNoteType = IdlTypes<Forum>['BigNoteType'];
 export type BigNoteVerificationState = IdlTypes<Forum>['BigNoteVerificationState'];
 export type BountyContributionState = IdlTypes<Forum>['BountyContributionState'];
+export type CommentTarget = IdlTypes<Forum>['CommentTarget'];
+export type HandleCharacterSet = IdlTypes<Forum>['HandleCharacterSet'];
 export type ProposedContributionState = IdlTypes<Forum>['ProposedContributionState'];
 export type SubmissionState = IdlTypes<Forum>['SubmissionState'];
 export type Tags = IdlTypes<Forum>['Tags']
@@ -88,6 +127,16 @@ export interface ForumConstants {
     maxTitleLength: BN;
     maxUrlLength: BN;
     minInactivityPeriod: BN;
+    maxCommentDepth: BN;
+    minChallengeProposalRep: BN;
+    maxSubmissionAttempts: BN;
+    challengeJudgingPeriod: BN;
+    bigNoteVerificationQuorum: BN;
+    minAttesterRep: BN;
+    bigNoteVerificationValidityPeriod: BN;
+    minHandleLength: BN;
+    maxHandleLength: BN;
+    handleCharacterSet: HandleCharacterSet;
 }
 
 export interface ForumCounts {
@@ -110,6 +159,15 @@ export interface ForumFees {
     forumChallengeSubmissionFee: BN;
     forumQuestionBountyMinimum: BN;
     forumBigNotesBountyMinimum: BN;
+    forumChallengeProposalDeposit: BN;
+    forumRejectedSubmissionRefund: BN;
+    forumVerificationAttestationStake: BN;
+    forumHandleRegistrationFee: BN;
+}
+
+export interface RubricCriterion {
+    maxScore: BN;
+    weight: BN;
 }
 
 export interface ReputationMatrix {
@@ -123,6 +181,9 @@ export interface ReputationMatrix {
     proposedBigNotesContributionRep: BN;
     acceptedBigNotesContributionProposalRep: BN;
     bountyContributionRep: BN;
+    approvedChallengeProposalRep: BN;
+    peerReviewMatchRep: BN;
+    peerReviewMismatchPenalty: BN;
 }
 
 function isKp(toCheck: PublicKey | Keypair) {
@@ -177,6 +238,10 @@ export class ForumClient {
         return this.forumProgram.account.forum.fetch(forum);
     }
 
+    async fetchCategoryAccount(category: PublicKey) {
+        return this.forumProgram.account.category.fetch(category);
+    }
+
     async fetchUserProfileAccount(userProfile: PublicKey) {
         return this.forumProgram.account.userProfile.fetch(userProfile);
     }
@@ -655,6 +720,110 @@ export class ForumClient {
         }
     }
 
+    async createCategory(
+        forum: PublicKey,
+        forumManager: PublicKey | Keypair,
+        feeOverridesEnabled: boolean,
+        categoryFees: ForumFees,
+    ) {
+        const categorySeedKeypair = Keypair.generate();
+        const categorySeed: PublicKey = categorySeedKeypair.publicKey;
+
+        // Derive PDAs
+        const [category, categoryBump] = await findCategoryPDA(forum, categorySeed);
+
+        // Create Signers Array
+        const signers = [];
+        if (isKp(forumManager)) signers.push(<Keypair>forumManager);
+
+        console.log('creating category with pubkey: ', category.toBase58());
+
+        // Transaction
+        const txSig = await this.forumProgram.methods
+            .createCategory(
+                feeOverridesEnabled,
+                categoryFees,
+            )
+            .accounts({
+                forum: forum,
+                forumManager: isKp(forumManager)? (<Keypair>forumManager).publicKey : <PublicKey>forumManager,
+                category: category,
+                categorySeed: categorySeed,
+                systemProgram: SystemProgram.programId,
+            })
+            .signers(signers)
+            .rpc();
+
+        return {
+            category,
+            categoryBump,
+            categorySeed,
+            txSig
+        }
+    }
+
+    async updateCategory(
+        forum: PublicKey,
+        forumManager: PublicKey | Keypair,
+        category: PublicKey,
+        feeOverridesEnabled: boolean,
+        newCategoryFees: ForumFees,
+    ) {
+        // Create Signers Array
+        const signers = [];
+        if (isKp(forumManager)) signers.push(<Keypair>forumManager);
+
+        console.log('updating category with pubkey: ', category.toBase58());
+
+        // Transaction
+        const txSig = await this.forumProgram.methods
+            .updateCategory(
+                feeOverridesEnabled,
+                newCategoryFees,
+            )
+            .accounts({
+                forum: forum,
+                forumManager: isKp(forumManager)? (<Keypair>forumManager).publicKey : <PublicKey>forumManager,
+                category: category,
+            })
+            .signers(signers)
+            .rpc();
+
+        return {
+            txSig
+        }
+    }
+
+    async setCategoryModerators(
+        forum: PublicKey,
+        forumManager: PublicKey | Keypair,
+        category: PublicKey,
+        moderatorProfiles: PublicKey[],
+    ) {
+        // Create Signers Array
+        const signers = [];
+        if (isKp(forumManager)) signers.push(<Keypair>forumManager);
+
+        console.log('setting moderators for category with pubkey: ', category.toBase58());
+
+        // Transaction
+        const txSig = await this.forumProgram.methods
+            .setCategoryModerators(
+                moderatorProfiles,
+            )
+            .accounts({
+                forum: forum,
+                forumManager: isKp(forumManager)? (<Keypair>forumManager).publicKey : <PublicKey>forumManager,
+                category: category,
+            })
+            .signers(signers)
+            .rpc();
+
+        return {
+            txSig
+        }
+    }
+
     async payoutFromTreasury(
         forum: PublicKey,
         forumManager: PublicKey | Keypair,
@@ -731,13 +900,16 @@ export class ForumClient {
 
     async createUserProfile(
         forum: PublicKey,
-        profileOwner: PublicKey | Keypair
+        profileOwner: PublicKey | Keypair,
+        accessProofAccounts: PublicKey[] = [],
     ) {
         const profileOwnerKey = isKp(profileOwner) ? (<Keypair>profileOwner).publicKey : <PublicKey>profileOwner;
 
         // Derive PDAs
         const [forumTreasury, forumTreasuryBump] = await findForumTreasuryPDA(forum);
         const [userProfile, userProfileBump] = await findUserProfilePDA(forum, profileOwnerKey);
+        const [walletRotation, walletRotationBump] = await findWalletRotationPDA(forum, profileOwnerKey);
+        const [forumAccessPolicy, forumAccessPolicyBump] = await findForumAccessPolicyPDA(forum);
 
         // Create Signers Array
         const signers = [];
@@ -749,14 +921,19 @@ export class ForumClient {
         const txSig = await this.forumProgram.methods
             .createUserProfile(
                 forumTreasuryBump,
+                walletRotationBump,
+                forumAccessPolicyBump,
             )
             .accounts({
                 forum: forum,
                 forumTreasury: forumTreasury,
                 profileOwner: isKp(profileOwner)? (<Keypair>profileOwner).publicKey : <PublicKey>profileOwner,
                 userProfile: userProfile,
+                walletRotation: walletRotation,
+                forumAccessPolicy: forumAccessPolicy,
                 systemProgram: SystemProgram.programId,
             })
+            .remainingAccounts(accessProofAccounts.map((pubkey) => ({ pubkey, isWritable: false, isSigner: false })))
             .signers(signers)
             .rpc();
 
@@ -778,6 +955,11 @@ export class ForumClient {
 
         // Derive PDAs
         const [userProfile, userProfileBump] = await findUserProfilePDA(forum, profileOwnerKey);
+        const [nftMetadata] = await findNftMetadataPDA(nft_token_mint);
+        const [pfpCollectionAllowlist, pfpCollectionAllowlistBump] = await findPfpCollectionAllowlistPDA(forum);
+
+        // Token account of the profile owner holding the NFT
+        const nftPfpTokenAccount = await Token.getAssociatedTokenAddress(ASSOCIATED_TOKEN_PROGRAM_ID, TOKEN_PROGRAM_ID, nft_token_mint, profileOwnerKey);
 
         // Create Signers Array
         const signers = [];
@@ -788,13 +970,17 @@ export class ForumClient {
         // Transaction
         const txSig = await this.forumProgram.methods
             .editUserProfile(
-                userProfileBump
+                userProfileBump,
+                pfpCollectionAllowlistBump,
             )
             .accounts({
                 forum: forum,
                 profileOwner: isKp(profileOwner)? (<Keypair>profileOwner).publicKey : <PublicKey>profileOwner,
                 userProfile: userProfile,
                 nftPfpTokenMint: nft_token_mint,
+                nftPfpTokenAccount: nftPfpTokenAccount,
+                nftMetadata: nftMetadata,
+                pfpCollectionAllowlist: pfpCollectionAllowlist,
                 systemProgram: SystemProgram.programId,
             })
             .signers(signers)
@@ -807,15 +993,103 @@ export class ForumClient {
         }
     }
 
+    async approveWalletRotation(
+        forum: PublicKey,
+        profileOwner: PublicKey | Keypair,
+        userProfile: PublicKey,
+        newProfileOwner: PublicKey,
+    ) {
+        const userProfileAcct = await this.fetchUserProfileAccount(userProfile);
+        const profileOwnerKey = isKp(profileOwner) ? (<Keypair>profileOwner).publicKey : <PublicKey>profileOwner;
+
+        // Derive PDAs
+        const [userProfileKey, userProfileBump] = await findUserProfilePDA(forum, userProfileAcct.profileSeed);
+
+        // Create Signers Array
+        const signers = [];
+        if (isKp(profileOwner)) signers.push(<Keypair>profileOwner);
+
+        console.log('approving wallet rotation for user profile account with pubkey: ', userProfile.toBase58());
+
+        // Transaction
+        const txSig = await this.forumProgram.methods
+            .approveWalletRotation(
+                userProfileBump,
+            )
+            .accounts({
+                forum: forum,
+                profileOwner: profileOwnerKey,
+                userProfile: userProfile,
+                newProfileOwner: newProfileOwner,
+                systemProgram: SystemProgram.programId,
+            })
+            .signers(signers)
+            .rpc();
+
+        return {
+            userProfileBump,
+            txSig
+        }
+    }
+
+    async acceptWalletRotation(
+        forum: PublicKey,
+        newProfileOwner: PublicKey | Keypair,
+        userProfile: PublicKey,
+    ) {
+        const userProfileAcct = await this.fetchUserProfileAccount(userProfile);
+        const newProfileOwnerKey = isKp(newProfileOwner) ? (<Keypair>newProfileOwner).publicKey : <PublicKey>newProfileOwner;
+
+        // Derive PDAs
+        const [userProfileKey, userProfileBump] = await findUserProfilePDA(forum, userProfileAcct.profileSeed);
+        const [newWalletUserProfile, newWalletUserProfileBump] = await findUserProfilePDA(forum, newProfileOwnerKey);
+        const [walletRotation, walletRotationBump] = await findWalletRotationPDA(forum, newProfileOwnerKey);
+        const [previousWalletRotation, previousWalletRotationBump] = await findWalletRotationPDA(forum, userProfileAcct.profileOwner);
+
+        // Create Signers Array
+        const signers = [];
+        if (isKp(newProfileOwner)) signers.push(<Keypair>newProfileOwner);
+
+        console.log('accepting wallet rotation for user profile account with pubkey: ', userProfile.toBase58());
+
+        // Transaction
+        const txSig = await this.forumProgram.methods
+            .acceptWalletRotation(
+                userProfileBump,
+                newWalletUserProfileBump,
+                previousWalletRotationBump,
+            )
+            .accounts({
+                forum: forum,
+                newProfileOwner: newProfileOwnerKey,
+                userProfile: userProfile,
+                newWalletUserProfile: newWalletUserProfile,
+                walletRotation: walletRotation,
+                previousWalletRotation: previousWalletRotation,
+                previousProfileOwner: userProfileAcct.profileOwner,
+                systemProgram: SystemProgram.programId,
+            })
+            .signers(signers)
+            .rpc();
+
+        return {
+            walletRotation,
+            walletRotationBump,
+            txSig
+        }
+    }
+
     async deleteUserProfile(
         forum: PublicKey,
         profileOwner: PublicKey | Keypair,
         receiver: PublicKey,
+        profileSeed?: PublicKey,
     ) {
         const profileOwnerKey = isKp(profileOwner) ? (<Keypair>profileOwner).publicKey : <PublicKey>profileOwner;
 
-        // Derive PDAs
-        const [userProfile, userProfileBump] = await findUserProfilePDA(forum, profileOwnerKey);
+        // Derive PDAs (a rotated user profile keeps the original owner's key as its seed)
+        const [userProfile, userProfileBump] = await findUserProfilePDA(forum, profileSeed ?? profileOwnerKey);
+        const [walletRotation, walletRotationBump] = await findWalletRotationPDA(forum, profileOwnerKey);
 
         // Create Signers Array
         const signers = [];
@@ -826,12 +1100,14 @@ export class ForumClient {
         // Transaction
         const txSig = await this.forumProgram.methods
             .deleteUserProfile(
-                userProfileBump
+                userProfileBump,
+                walletRotationBump,
             )
             .accounts({
                 forum: forum,
                 profileOwner: isKp(profileOwner)? (<Keypair>profileOwner).publicKey : <PublicKey>profileOwner,
                 userProfile: userProfile,
+                walletRotation: walletRotation,
                 receiver: receiver,
                 systemProgram: SystemProgram.programId,
             })
@@ -978,13 +1254,15 @@ export class ForumClient {
     async deleteUserProfileAndAboutMe(
         forum: PublicKey,
         profileOwner: PublicKey | Keypair,
-        receiver: PublicKey
+        receiver: PublicKey,
+        profileSeed?: PublicKey,
     ) {
         const profileOwnerKey = isKp(profileOwner) ? (<Keypair>profileOwner).publicKey : <PublicKey>profileOwner;
 
-        // Derive PDAs
-        const [userProfile, userProfileBump] = await findUserProfilePDA(forum, profileOwnerKey);
+        // Derive PDAs (a rotated user profile keeps the original owner's key as its seed)
+        const [userProfile, userProfileBump] = await findUserProfilePDA(forum, profileSeed ?? profileOwnerKey);
         const [aboutMe, aboutMeBump] = await findAboutMePDA(userProfile);
+        const [walletRotation, walletRotationBump] = await findWalletRotationPDA(forum, profileOwnerKey);
 
         // Create Signers Array
         const signers = [];
@@ -997,12 +1275,14 @@ export class ForumClient {
             .deleteUserProfileAndAboutMe(
                 userProfileBump,
                 aboutMeBump,
+                walletRotationBump,
             )
             .accounts({
                 forum: forum,
                 profileOwner: isKp(profileOwner)? (<Keypair>profileOwner).publicKey : <PublicKey>profileOwner,
                 userProfile: userProfile,
                 aboutMe: aboutMe,
+                walletRotation: walletRotation,
                 receiver: receiver,
                 systemProgram: SystemProgram.programId,
             })
@@ -1029,7 +1309,7 @@ export class ForumClient {
         const profileOwner = userProfileAcct.profileOwner;
 
         // Derive PDAs
-        const [userProfileKey, userProfileBump] = await findUserProfilePDA(forum, profileOwner);
+        const [userProfileKey, userProfileBump] = await findUserProfilePDA(forum, userProfileAcct.profileSeed);
 
         // Create Signers Array
         const signers = [];
@@ -1068,7 +1348,7 @@ export class ForumClient {
         const profileOwner = userProfileAcct.profileOwner;
 
         // Derive PDAs
-        const [userProfileKey, userProfileBump] = await findUserProfilePDA(forum, profileOwner);
+        const [userProfileKey, userProfileBump] = await findUserProfilePDA(forum, userProfileAcct.profileSeed);
 
         // Create Signers Array
         const signers = [];
@@ -1103,6 +1383,7 @@ export class ForumClient {
     async askQuestion(
         forum: PublicKey,
         profileOwner: PublicKey | Keypair,
+        category: PublicKey,
         contentDataHash: PublicKey,
         tags: Tags[],
         title: string,
@@ -1141,6 +1422,7 @@ export class ForumClient {
                 forumTreasury: forumTreasury,
                 profileOwner: isKp(profileOwner)? (<Keypair>profileOwner).publicKey : <PublicKey>profileOwner,
                 userProfile: userProfile,
+                category: category,
                 question: question,
                 questionSeed: questionSeed,
                 contentDataHash: contentDataHash,
@@ -1166,25 +1448,30 @@ export class ForumClient {
 
     async editQuestion(
         question: PublicKey,
-        profileOwner: PublicKey | Keypair,
+        authority: PublicKey | Keypair,
         newContentDataHash: PublicKey,
         newTags: Tags[],
         newTitle: string,
         newContentDataUrl: string,
     ) {
-        const profileOwnerKey = isKp(profileOwner) ? (<Keypair>profileOwner).publicKey : <PublicKey>profileOwner;
+        const authorityKey = isKp(authority) ? (<Keypair>authority).publicKey : <PublicKey>authority;
 
         const questionAcct = await this.fetchQuestionAccount(question);
         const forum = questionAcct.forum;
         const questionSeed = questionAcct.questionSeed;
+        const userProfile = questionAcct.userProfile;
+
+        const userProfileAcct = await this.fetchUserProfileAccount(userProfile);
 
         // Derive PDAs
-        const [userProfile, userProfileBump] = await findUserProfilePDA(forum, profileOwnerKey);
+        const [userProfileKey, userProfileBump] = await findUserProfilePDA(forum, userProfileAcct.profileSeed);
+        const [sessionKey, sessionKeyBump] = await findSessionKeyPDA(userProfile, authorityKey);
         const [questionKey, questionBump] = await findQuestionPDA(forum, userProfile, questionSeed);
+        const [revision, revisionBump] = await findRevisionPDA(question, questionAcct.revisionCount);
 
         // Create Signers Array
         const signers = [];
-        if (isKp(profileOwner)) signers.push(<Keypair>profileOwner);
+        if (isKp(authority)) signers.push(<Keypair>authority);
 
         console.log('editing question with pubkey: ', question.toBase58());
 
@@ -1193,27 +1480,34 @@ export class ForumClient {
             .editQuestion(
                 userProfileBump,
                 questionBump,
+                sessionKeyBump,
                 newTags,
                 newTitle,
                 newContentDataUrl,
             )
             .accounts({
                 forum: forum,
-                profileOwner: isKp(profileOwner)? (<Keypair>profileOwner).publicKey : <PublicKey>profileOwner,
+                authority: authorityKey,
                 userProfile: userProfile,
+                sessionKey: sessionKey,
                 question: question,
                 questionSeed: questionSeed,
                 newContentDataHash: newContentDataHash,
+                revision: revision,
                 systemProgram: SystemProgram.programId,
             })
             .signers(signers)
             .rpc();
 
         return {
-            userProfile,
+            userProfileKey,
             userProfileBump,
+            sessionKey,
+            sessionKeyBump,
             questionKey,
             questionBump,
+            revision,
+            revisionBump,
             txSig
         }
     }
@@ -1238,8 +1532,9 @@ export class ForumClient {
 
         // Derive PDAs
         const [moderatorProfile, moderatorProfileBump] = await findUserProfilePDA(forum, moderatorKey);
-        const [userProfileKey, userProfileBump] = await findUserProfilePDA(forum, profileOwner);
+        const [userProfileKey, userProfileBump] = await findUserProfilePDA(forum, userProfileAcct.profileSeed);
         const [questionKey, questionBump] = await findQuestionPDA(forum, userProfile, questionSeed);
+        const [revision, revisionBump] = await findRevisionPDA(question, questionAcct.revisionCount);
 
         // Create Signers Array
         const signers = [];
@@ -1265,7 +1560,9 @@ export class ForumClient {
                 userProfile: userProfile,
                 question: question,
                 questionSeed: questionSeed,
+                category: questionAcct.category,
                 newContentDataHash: newContentDataHash,
+                revision: revision,
                 systemProgram: SystemProgram.programId,
             })
             .signers(signers)
@@ -1278,6 +1575,8 @@ export class ForumClient {
             userProfileBump,
             questionKey,
             questionBump,
+            revision,
+            revisionBump,
             txSig
         }
     }
@@ -1299,7 +1598,7 @@ export class ForumClient {
 
         // Derive PDAs
         const [moderatorProfile, moderatorProfileBump] = await findUserProfilePDA(forum, moderatorKey);
-        const [userProfileKey, userProfileBump] = await findUserProfilePDA(forum, profileOwner);
+        const [userProfileKey, userProfileBump] = await findUserProfilePDA(forum, userProfileAcct.profileSeed);
         const [questionKey, questionBump] = await findQuestionPDA(forum, userProfile, questionSeed);
 
         // Create Signers Array
@@ -1323,6 +1622,7 @@ export class ForumClient {
                 userProfile: userProfile,
                 question: question,
                 questionSeed: questionSeed,
+                category: questionAcct.category,
                 receiver: receiver,
                 systemProgram: SystemProgram.programId,
             })
@@ -1358,7 +1658,7 @@ export class ForumClient {
         // Derive PDAs
         const [forumTreasury, forumTreasuryBump] = await findForumTreasuryPDA(forum);
         const [supplementorProfile, supplementorProfileBump] = await findUserProfilePDA(forum, supplementorKey);
-        const [userProfileKey, userProfileBump] = await findUserProfilePDA(forum, profileOwner);
+        const [userProfileKey, userProfileBump] = await findUserProfilePDA(forum, userProfileAcct.profileSeed);
         const [questionKey, questionBump] = await findQuestionPDA(forum, userProfile, questionSeed);
         const [bountyPda, bountyPdaBump] = await findQuestionBountyPDA(question);
 
@@ -1387,6 +1687,7 @@ export class ForumClient {
                 profileOwner: profileOwner,
                 userProfile: userProfile,
                 question: question,
+                category: questionAcct.category,
                 questionSeed: questionSeed,
                 bountyPda: bountyPda,
                 systemProgram: SystemProgram.programId,
@@ -1424,7 +1725,7 @@ export class ForumClient {
 
         // Derive PDAs
         const [moderatorProfile, moderatorProfileBump] = await findUserProfilePDA(forum, moderatorKey);
-        const [userProfileKey, userProfileBump] = await findUserProfilePDA(forum, profileOwner);
+        const [userProfileKey, userProfileBump] = await findUserProfilePDA(forum, userProfileAcct.profileSeed);
         const [supplementorProfile, supplementorProfileBump] = await findUserProfilePDA(forum, supplementor);
         const [questionKey, questionBump] = await findQuestionPDA(forum, userProfile, questionSeed);
         const [bountyPda, bountyPdaBump] = await findQuestionBountyPDA(question);
@@ -1496,7 +1797,7 @@ export class ForumClient {
 
         // Derive PDAs
         const [userProfile, userProfileBump] = await findUserProfilePDA(forum, profileOwnerKey);
-        const [answerUserProfileKey, answerUserProfileBump] = await findUserProfilePDA(forum, answerProfileOwner);
+        const [answerUserProfileKey, answerUserProfileBump] = await findUserProfilePDA(forum, answerUserProfileAcct.profileSeed);
         const [questionKey, questionBump] = await findQuestionPDA(forum, userProfile, questionSeed);
         const [bountyPda, bountyPdaBump] = await findQuestionBountyPDA(question);
         const [answerKey, answerBump] = await findAnswerPDA(forum, answerUserProfile, answerSeed);
@@ -1552,8 +1853,9 @@ export class ForumClient {
 
     async answerQuestion(
         question: PublicKey,
-        profileOwner: PublicKey | Keypair,
+        authority: PublicKey | Keypair,
         contentDataHash: PublicKey,
+        profileSeed?: PublicKey,
     ) {
         const answerSeedKeypair = Keypair.generate();
         const answerSeed: PublicKey = answerSeedKeypair.publicKey;
@@ -1561,15 +1863,16 @@ export class ForumClient {
         const questionAcct = await this.fetchQuestionAccount(question);
         const forum = questionAcct.forum;
 
-        const profileOwnerKey = isKp(profileOwner) ? (<Keypair>profileOwner).publicKey : <PublicKey>profileOwner;
+        const authorityKey = isKp(authority) ? (<Keypair>authority).publicKey : <PublicKey>authority;
 
-        // Derive PDAs
-        const [userProfile, userProfileBump] = await findUserProfilePDA(forum, profileOwnerKey);
+        // Derive PDAs (a session key delegate answers on behalf of the user profile with the given seed)
+        const [userProfile, userProfileBump] = await findUserProfilePDA(forum, profileSeed ?? authorityKey);
+        const [sessionKey, sessionKeyBump] = await findSessionKeyPDA(userProfile, authorityKey);
         const [answer, answerBump] = await findAnswerPDA(forum, userProfile, answerSeed);
 
         // Create Signers Array
         const signers = [];
-        if (isKp(profileOwner)) signers.push(<Keypair>profileOwner);
+        if (isKp(authority)) signers.push(<Keypair>authority);
 
         console.log('creating answer with pubkey: ', answer.toBase58());
 
@@ -1577,11 +1880,13 @@ export class ForumClient {
         const txSig = await this.forumProgram.methods
             .answerQuestion(
                 userProfileBump,
+                sessionKeyBump,
             )
             .accounts({
                 forum: forum,
-                profileOwner: isKp(profileOwner)? (<Keypair>profileOwner).publicKey : <PublicKey>profileOwner,
+                authority: authorityKey,
                 userProfile: userProfile,
+                sessionKey: sessionKey,
                 question: question,
                 answer: answer,
                 answerSeed: answerSeed,
@@ -1594,6 +1899,8 @@ export class ForumClient {
         return {
             userProfile,
             userProfileBump,
+            sessionKey,
+            sessionKeyBump,
             answer,
             answerBump,
             answerSeed,
@@ -1603,25 +1910,30 @@ export class ForumClient {
 
     async editAnswer(
         answer: PublicKey,
-        profileOwner: PublicKey | Keypair,
+        authority: PublicKey | Keypair,
         newContentDataHash: PublicKey,
     ) {
-        const profileOwnerKey = isKp(profileOwner) ? (<Keypair>profileOwner).publicKey : <PublicKey>profileOwner;
+        const authorityKey = isKp(authority) ? (<Keypair>authority).publicKey : <PublicKey>authority;
 
         const answerAcct = await this.fetchAnswerAccount(answer);
         const answerSeed = answerAcct.answerSeed;
+        const userProfile = answerAcct.userProfile;
         const question = answerAcct.question;
 
         const questionAcct = await this.fetchQuestionAccount(question);
         const forum = questionAcct.forum;
 
+        const userProfileAcct = await this.fetchUserProfileAccount(userProfile);
+
         // Derive PDAs
-        const [userProfile, userProfileBump] = await findUserProfilePDA(forum, profileOwnerKey);
+        const [userProfileKey, userProfileBump] = await findUserProfilePDA(forum, userProfileAcct.profileSeed);
+        const [sessionKey, sessionKeyBump] = await findSessionKeyPDA(userProfile, authorityKey);
         const [answerKey, answerBump] = await findAnswerPDA(forum, userProfile, answerSeed);
+        const [revision, revisionBump] = await findRevisionPDA(answer, answerAcct.revisionCount);
 
         // Create Signers Array
         const signers = [];
-        if (isKp(profileOwner)) signers.push(<Keypair>profileOwner);
+        if (isKp(authority)) signers.push(<Keypair>authority);
 
         console.log('editing answer with pubkey: ', answer.toBase58());
 
@@ -1630,25 +1942,32 @@ export class ForumClient {
             .editAnswer(
                 userProfileBump,
                 answerBump,
+                sessionKeyBump,
             )
             .accounts({
                 forum: forum,
-                profileOwner: isKp(profileOwner)? (<Keypair>profileOwner).publicKey : <PublicKey>profileOwner,
+                authority: authorityKey,
                 userProfile: userProfile,
+                sessionKey: sessionKey,
                 question: question,
                 answer: answer,
                 answerSeed: answerSeed,
                 newContentDataHash: newContentDataHash,
+                revision: revision,
                 systemProgram: SystemProgram.programId,
             })
             .signers(signers)
             .rpc();
 
         return {
-            userProfile,
+            userProfileKey,
             userProfileBump,
+            sessionKey,
+            sessionKeyBump,
             answerKey,
             answerBump,
+            revision,
+            revisionBump,
             txSig
         }
     }
@@ -1673,8 +1992,9 @@ export class ForumClient {
 
         // Derive PDAs
         const [moderatorProfile, moderatorProfileBump] = await findUserProfilePDA(forum, moderatorKey);
-        const [userProfileKey, userProfileBump] = await findUserProfilePDA(forum, profileOwner);
+        const [userProfileKey, userProfileBump] = await findUserProfilePDA(forum, userProfileAcct.profileSeed);
         const [answerKey, answerBump] = await findAnswerPDA(forum, userProfile, answerSeed);
+        const [revision, revisionBump] = await findRevisionPDA(answer, answerAcct.revisionCount);
 
         // Create Signers Array
         const signers = [];
@@ -1696,9 +2016,11 @@ export class ForumClient {
                 profileOwner: profileOwner,
                 userProfile: userProfile,
                 question: question,
+                category: questionAcct.category,
                 answer: answer,
                 answerSeed: answerSeed,
                 newContentDataHash: newContentDataHash,
+                revision: revision,
                 systemProgram: SystemProgram.programId,
             })
             .signers(signers)
@@ -1711,6 +2033,8 @@ export class ForumClient {
             userProfileBump,
             answerKey,
             answerBump,
+            revision,
+            revisionBump,
             txSig
         }
     }
@@ -1787,7 +2111,7 @@ export class ForumClient {
 
         // Derive PDAs
         const [moderatorProfile, moderatorProfileBump] = await findUserProfilePDA(forum, moderatorKey);
-        const [userProfileKey, userProfileBump] = await findUserProfilePDA(forum, profileOwner);
+        const [userProfileKey, userProfileBump] = await findUserProfilePDA(forum, userProfileAcct.profileSeed);
         const [answerKey, answerBump] = await findAnswerPDA(forum, userProfile, answerSeed);
 
         // Create Signers Array
@@ -1810,6 +2134,7 @@ export class ForumClient {
                 profileOwner: profileOwner,
                 userProfile: userProfile,
                 question: question,
+                category: questionAcct.category,
                 answer: answer,
                 answerSeed: answerSeed,
                 receiver: receiver,
@@ -1831,50 +2156,95 @@ export class ForumClient {
 
 //////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
-    async leaveCommentOnQuestion(
-        question: PublicKey,
-        profileOwner: PublicKey | Keypair,
+    // Walk a comment thread up to its root question, big note or challenge, returning the root's category and the
+    // parent accounts a category moderator passes in as remaining accounts
+    async fetchCommentThreadCategory(commentedOn: PublicKey, commentTarget: CommentTarget) {
+        const parentAccounts: PublicKey[] = [];
+        let account = commentedOn;
+        let target = commentTarget;
+
+        while (true) {
+            if ('question' in target) {
+                return { category: (await this.fetchQuestionAccount(account)).category, parentAccounts };
+            } else if ('bigNote' in target) {
+                return { category: (await this.fetchBigNoteAccount(account)).category, parentAccounts };
+            } else if ('challenge' in target) {
+                return { category: (await this.fetchChallengeAccount(account)).category, parentAccounts };
+            } else if ('answer' in target) {
+                account = (await this.fetchAnswerAccount(account)).question;
+                target = commentTargetValues.Question;
+            } else if ('proposedContribution' in target) {
+                account = (await this.fetchProposedContributionAccount(account)).bigNote;
+                target = commentTargetValues.BigNote;
+            } else if ('submission' in target) {
+                account = (await this.fetchSubmissionAccount(account)).challenge;
+                target = commentTargetValues.Challenge;
+            } else {
+                const parentCommentAcct = await this.fetchCommentAccount(account);
+                account = parentCommentAcct.commentedOn;
+                target = parentCommentAcct.commentTarget;
+            }
+            parentAccounts.push(account);
+        }
+    }
+
+    async leaveComment(
+        forum: PublicKey,
+        commentedOn: PublicKey,
+        commentTarget: CommentTarget,
+        authority: PublicKey | Keypair,
         contentDataHash: PublicKey,
+        profileSeed?: PublicKey,
     ) {
         const commentSeedKeypair = Keypair.generate();
         const commentSeed: PublicKey = commentSeedKeypair.publicKey;
 
-        const profileOwnerKey = isKp(profileOwner) ? (<Keypair>profileOwner).publicKey : <PublicKey>profileOwner;
+        const authorityKey = isKp(authority) ? (<Keypair>authority).publicKey : <PublicKey>authority;
 
-        const questionAcct = await this.fetchQuestionAccount(question);
-        const forum = questionAcct.forum;
+        // Comments on submissions pass in the submission's challenge as a remaining account
+        const remainingAccounts: PublicKey[] = [];
+        if ('submission' in commentTarget) {
+            remainingAccounts.push((await this.fetchSubmissionAccount(commentedOn)).challenge);
+        }
 
-        // Derive PDAs
-        const [userProfile, userProfileBump] = await findUserProfilePDA(forum, profileOwnerKey);
+        // Derive PDAs (a session key delegate comments on behalf of the user profile with the given seed)
+        const [userProfile, userProfileBump] = await findUserProfilePDA(forum, profileSeed ?? authorityKey);
+        const [sessionKey, sessionKeyBump] = await findSessionKeyPDA(userProfile, authorityKey);
         const [comment, commentBump] = await findCommentPDA(forum, userProfile, commentSeed);
 
         // Create Signers Array
         const signers = [];
-        if (isKp(profileOwner)) signers.push(<Keypair>profileOwner);
+        if (isKp(authority)) signers.push(<Keypair>authority);
 
         console.log('creating comment with pubkey: ', comment.toBase58());
 
         // Transaction
         const txSig = await this.forumProgram.methods
-            .leaveCommentOnQuestion(
+            .leaveComment(
                 userProfileBump,
+                sessionKeyBump,
+                commentTarget,
             )
             .accounts({
                 forum: forum,
-                profileOwner: isKp(profileOwner)? (<Keypair>profileOwner).publicKey : <PublicKey>profileOwner,
+                authority: authorityKey,
                 userProfile: userProfile,
-                question: question,
+                sessionKey: sessionKey,
+                commentedOn: commentedOn,
                 comment: comment,
                 commentSeed: commentSeed,
                 contentDataHash: contentDataHash,
                 systemProgram: SystemProgram.programId,
             })
+            .remainingAccounts(remainingAccounts.map((pubkey) => ({ pubkey, isWritable: false, isSigner: false })))
             .signers(signers)
             .rpc();
 
         return {
             userProfile,
             userProfileBump,
+            sessionKey,
+            sessionKeyBump,
             comment,
             commentBump,
             commentSeed,
@@ -1882,41 +2252,45 @@ export class ForumClient {
         }
     }
 
-    async editCommentOnQuestion(
+    async editComment(
         comment: PublicKey,
-        profileOwner: PublicKey | Keypair,
+        authority: PublicKey | Keypair,
         newContentDataHash: PublicKey,
     ) {
-        const profileOwnerKey = isKp(profileOwner) ? (<Keypair>profileOwner).publicKey : <PublicKey>profileOwner;
+        const authorityKey = isKp(authority) ? (<Keypair>authority).publicKey : <PublicKey>authority;
 
         const commentAcct = await this.fetchCommentAccount(comment);
         const commentSeed = commentAcct.commentSeed;
-        const question = commentAcct.commentedOn;
+        const commentedOn = commentAcct.commentedOn;
+        const userProfile = commentAcct.userProfile;
 
-        const questionAcct = await this.fetchQuestionAccount(question);
-        const forum = questionAcct.forum;
+        const userProfileAcct = await this.fetchUserProfileAccount(userProfile);
+        const forum = userProfileAcct.forum;
 
         // Derive PDAs
-        const [userProfile, userProfileBump] = await findUserProfilePDA(forum, profileOwnerKey);
+        const [userProfileKey, userProfileBump] = await findUserProfilePDA(forum, userProfileAcct.profileSeed);
+        const [sessionKey, sessionKeyBump] = await findSessionKeyPDA(userProfile, authorityKey);
         const [commentKey, commentBump] = await findCommentPDA(forum, userProfile, commentSeed);
 
         // Create Signers Array
         const signers = [];
-        if (isKp(profileOwner)) signers.push(<Keypair>profileOwner);
+        if (isKp(authority)) signers.push(<Keypair>authority);
 
         console.log('editing comment with pubkey: ', comment.toBase58());
 
         // Transaction
         const txSig = await this.forumProgram.methods
-            .editCommentOnQuestion(
+            .editComment(
                 userProfileBump,
                 commentBump,
+                sessionKeyBump,
             )
             .accounts({
                 forum: forum,
-                profileOwner: isKp(profileOwner)? (<Keypair>profileOwner).publicKey : <PublicKey>profileOwner,
+                authority: authorityKey,
                 userProfile: userProfile,
-                question: question,
+                sessionKey: sessionKey,
+                commentedOn: commentedOn,
                 comment: comment,
                 commentSeed: commentSeed,
                 newContentDataHash: newContentDataHash,
@@ -1926,15 +2300,17 @@ export class ForumClient {
             .rpc();
 
         return {
-            userProfile,
+            userProfileKey,
             userProfileBump,
+            sessionKey,
+            sessionKeyBump,
             commentKey,
             commentBump,
             txSig
         }
     }
 
-    async editCommentOnQuestionModerator(
+    async editCommentModerator(
         comment: PublicKey,
         moderator: PublicKey | Keypair,
         newContentDataHash: PublicKey,
@@ -1943,18 +2319,18 @@ export class ForumClient {
 
         const commentAcct = await this.fetchCommentAccount(comment);
         const commentSeed = commentAcct.commentSeed;
-        const question = commentAcct.commentedOn;
+        const commentedOn = commentAcct.commentedOn;
         const userProfile = commentAcct.userProfile;
 
-        const questionAcct = await this.fetchQuestionAccount(question);
-        const forum = questionAcct.forum;
-
         const userProfileAcct = await this.fetchUserProfileAccount(userProfile);
+        const forum = userProfileAcct.forum;
         const profileOwner = userProfileAcct.profileOwner;
 
+        const { category, parentAccounts } = await this.fetchCommentThreadCategory(commentedOn, commentAcct.commentTarget);
+
         // Derive PDAs
         const [moderatorProfile, moderatorProfileBump] = await findUserProfilePDA(forum, moderatorKey);
-        const [userProfileKey, userProfileBump] = await findUserProfilePDA(forum, profileOwner);
+        const [userProfileKey, userProfileBump] = await findUserProfilePDA(forum, userProfileAcct.profileSeed);
         const [commentKey, commentBump] = await findCommentPDA(forum, userProfile, commentSeed);
 
         // Create Signers Array
@@ -1965,7 +2341,7 @@ export class ForumClient {
 
         // Transaction
         const txSig = await this.forumProgram.methods
-            .editCommentOnQuestionModerator(
+            .editCommentModerator(
                 moderatorProfileBump,
                 userProfileBump,
                 commentBump,
@@ -1976,12 +2352,14 @@ export class ForumClient {
                 moderatorProfile: moderatorProfile,
                 profileOwner: profileOwner,
                 userProfile: userProfile,
-                question: question,
+                commentedOn: commentedOn,
                 comment: comment,
                 commentSeed: commentSeed,
+                category: category,
                 newContentDataHash: newContentDataHash,
                 systemProgram: SystemProgram.programId,
             })
+            .remainingAccounts(parentAccounts.map((pubkey) => ({ pubkey, isWritable: false, isSigner: false })))
             .signers(signers)
             .rpc();
 
@@ -1996,22 +2374,21 @@ export class ForumClient {
         }
     }
 
-    async deleteCommentOnQuestion(
+    async deleteComment(
         comment: PublicKey,
         profileOwner: PublicKey | Keypair,
         receiver: PublicKey,
     ) {
-        const profileOwnerKey = isKp(profileOwner) ? (<Keypair>profileOwner).publicKey : <PublicKey>profileOwner;
-
         const commentAcct = await this.fetchCommentAccount(comment);
         const commentSeed = commentAcct.commentSeed;
-        const question = commentAcct.commentedOn;
+        const commentedOn = commentAcct.commentedOn;
+        const userProfile = commentAcct.userProfile;
 
-        const questionAcct = await this.fetchQuestionAccount(question);
-        const forum = questionAcct.forum;
+        const userProfileAcct = await this.fetchUserProfileAccount(userProfile);
+        const forum = userProfileAcct.forum;
 
         // Derive PDAs
-        const [userProfile, userProfileBump] = await findUserProfilePDA(forum, profileOwnerKey);
+        const [userProfileKey, userProfileBump] = await findUserProfilePDA(forum, userProfileAcct.profileSeed);
         const [commentKey, commentBump] = await findCommentPDA(forum, userProfile, commentSeed);
 
         // Create Signers Array
@@ -2022,15 +2399,15 @@ export class ForumClient {
 
         // Transaction
         const txSig = await this.forumProgram.methods
-            .deleteCommentOnQuestion(
+            .deleteComment(
                 userProfileBump,
-                commentBump
+                commentBump,
             )
             .accounts({
                 forum: forum,
                 profileOwner: isKp(profileOwner)? (<Keypair>profileOwner).publicKey : <PublicKey>profileOwner,
                 userProfile: userProfile,
-                question: question,
+                commentedOn: commentedOn,
                 comment: comment,
                 commentSeed: commentSeed,
                 receiver: receiver,
@@ -2040,7 +2417,7 @@ export class ForumClient {
             .rpc();
 
         return {
-            userProfile,
+            userProfileKey,
             userProfileBump,
             commentKey,
             commentBump,
@@ -2048,7 +2425,7 @@ export class ForumClient {
         }
     }
 
-    async deleteCommentOnQuestionModerator(
+    async deleteCommentModerator(
         comment: PublicKey,
         moderator: PublicKey | Keypair,
         receiver: PublicKey,
@@ -2057,18 +2434,18 @@ export class ForumClient {
 
         const commentAcct = await this.fetchCommentAccount(comment);
         const commentSeed = commentAcct.commentSeed;
-        const question = commentAcct.commentedOn;
+        const commentedOn = commentAcct.commentedOn;
         const userProfile = commentAcct.userProfile;
 
-        const questionAcct = await this.fetchQuestionAccount(question);
-        const forum = questionAcct.forum;
-
         const userProfileAcct = await this.fetchUserProfileAccount(userProfile);
+        const forum = userProfileAcct.forum;
         const profileOwner = userProfileAcct.profileOwner;
 
+        const { category, parentAccounts } = await this.fetchCommentThreadCategory(commentedOn, commentAcct.commentTarget);
+
         // Derive PDAs
         const [moderatorProfile, moderatorProfileBump] = await findUserProfilePDA(forum, moderatorKey);
-        const [userProfileKey, userProfileBump] = await findUserProfilePDA(forum, profileOwner);
+        const [userProfileKey, userProfileBump] = await findUserProfilePDA(forum, userProfileAcct.profileSeed);
         const [commentKey, commentBump] = await findCommentPDA(forum, userProfile, commentSeed);
 
         // Create Signers Array
@@ -2079,10 +2456,10 @@ export class ForumClient {
 
         // Transaction
         const txSig = await this.forumProgram.methods
-            .deleteCommentOnQuestionModerator(
+            .deleteCommentModerator(
                 moderatorProfileBump,
                 userProfileBump,
-                commentBump
+                commentBump,
             )
             .accounts({
                 forum: forum,
@@ -2090,12 +2467,14 @@ export class ForumClient {
                 moderatorProfile: moderatorProfile,
                 profileOwner: profileOwner,
                 userProfile: userProfile,
-                question: question,
+                commentedOn: commentedOn,
                 comment: comment,
                 commentSeed: commentSeed,
+                category: category,
                 receiver: receiver,
                 systemProgram: SystemProgram.programId,
             })
+            .remainingAccounts(parentAccounts.map((pubkey) => ({ pubkey, isWritable: false, isSigner: false })))
             .signers(signers)
             .rpc();
 
@@ -2112,1176 +2491,339 @@ export class ForumClient {
 
 //////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
-    async leaveCommentOnAnswer(
-        answer: PublicKey,
+    async createBigNote(
+        forum: PublicKey,
         profileOwner: PublicKey | Keypair,
+        category: PublicKey,
         contentDataHash: PublicKey,
+        bigNoteType: BigNoteType,
+        tags: Tags[],
+        title: string,
+        contentDataUrl: string,
     ) {
-        const commentSeedKeypair = Keypair.generate();
-        const commentSeed: PublicKey = commentSeedKeypair.publicKey;
+        const bigNoteSeedKeypair = Keypair.generate();
+        const bigNoteSeed: PublicKey = bigNoteSeedKeypair.publicKey;
 
         const profileOwnerKey = isKp(profileOwner) ? (<Keypair>profileOwner).publicKey : <PublicKey>profileOwner;
 
-        const answerAcct = await this.fetchAnswerAccount(answer);
-        const question = answerAcct.question;
-
-        const questionAcct = await this.fetchQuestionAccount(question);
-        const forum = questionAcct.forum;
-
         // Derive PDAs
+        const [forumTreasury, forumTreasuryBump] = await findForumTreasuryPDA(forum);
         const [userProfile, userProfileBump] = await findUserProfilePDA(forum, profileOwnerKey);
-        const [comment, commentBump] = await findCommentPDA(forum, userProfile, commentSeed);
+        const [bigNote, bigNoteBump] = await findBigNotePDA(forum, userProfile, bigNoteSeed);
+        const [bountyPda, bountyPdaBump] = await findBigNoteBountyPDA(bigNote);
 
         // Create Signers Array
         const signers = [];
         if (isKp(profileOwner)) signers.push(<Keypair>profileOwner);
 
-        console.log('creating comment with pubkey: ', comment.toBase58());
+        console.log('creating big note with pubkey: ', bigNote.toBase58());
 
         // Transaction
         const txSig = await this.forumProgram.methods
-            .leaveCommentOnAnswer(
-                userProfileBump,
-            )
-            .accounts({
-                forum: forum,
-                profileOwner: isKp(profileOwner)? (<Keypair>profileOwner).publicKey : <PublicKey>profileOwner,
-                userProfile: userProfile,
-                question: question,
-                answer: answer,
-                comment: comment,
-                commentSeed: commentSeed,
-                contentDataHash: contentDataHash,
-                systemProgram: SystemProgram.programId,
-            })
-            .signers(signers)
-            .rpc();
-
-        return {
-            userProfile,
-            userProfileBump,
-            comment,
-            commentBump,
-            commentSeed,
-            txSig
-        }
-    }
-
-    async editCommentOnAnswer(
-        comment: PublicKey,
-        profileOwner: PublicKey | Keypair,
-        newContentDataHash: PublicKey,
-    ) {
-        const profileOwnerKey = isKp(profileOwner) ? (<Keypair>profileOwner).publicKey : <PublicKey>profileOwner;
-
-        const commentAcct = await this.fetchCommentAccount(comment);
-        const commentSeed = commentAcct.commentSeed;
-        const answer = commentAcct.commentedOn;
-
-        const answerAcct = await this.fetchAnswerAccount(answer);
-        const question = answerAcct.question;
-
-        const questionAcct = await this.fetchQuestionAccount(question);
-        const forum = questionAcct.forum;
-
-        // Derive PDAs
-        const [userProfile, userProfileBump] = await findUserProfilePDA(forum, profileOwnerKey);
-        const [commentKey, commentBump] = await findCommentPDA(forum, userProfile, commentSeed);
-
-        // Create Signers Array
-        const signers = [];
-        if (isKp(profileOwner)) signers.push(<Keypair>profileOwner);
-
-        console.log('editing comment with pubkey: ', comment.toBase58());
-
-        // Transaction
-        const txSig = await this.forumProgram.methods
-            .editCommentOnAnswer(
-                userProfileBump,
-                commentBump,
-            )
-            .accounts({
-                forum: forum,
-                profileOwner: isKp(profileOwner)? (<Keypair>profileOwner).publicKey : <PublicKey>profileOwner,
-                userProfile: userProfile,
-                question: question,
-                answer: answer,
-                comment: comment,
-                commentSeed: commentSeed,
-                newContentDataHash: newContentDataHash,
-                systemProgram: SystemProgram.programId,
-            })
-            .signers(signers)
-            .rpc();
-
-        return {
-            userProfile,
-            userProfileBump,
-            commentKey,
-            commentBump,
-            txSig
-        }
-    }
-
-    async editCommentOnAnswerModerator(
-        comment: PublicKey,
-        moderator: PublicKey | Keypair,
-        newContentDataHash: PublicKey,
-    ) {
-        const moderatorKey = isKp(moderator) ? (<Keypair>moderator).publicKey : <PublicKey>moderator;
-
-        const commentAcct = await this.fetchCommentAccount(comment);
-        const commentSeed = commentAcct.commentSeed;
-        const answer = commentAcct.commentedOn;
-        const userProfile = commentAcct.userProfile;
-
-        const answerAcct = await this.fetchAnswerAccount(answer);
-        const question = answerAcct.question;
-
-        const questionAcct = await this.fetchQuestionAccount(question);
-        const forum = questionAcct.forum;
-
-        const userProfileAcct = await this.fetchUserProfileAccount(userProfile);
-        const profileOwner = userProfileAcct.profileOwner;
-
-        // Derive PDAs
-        const [moderatorProfile, moderatorProfileBump] = await findUserProfilePDA(forum, moderatorKey);
-        const [userProfileKey, userProfileBump] = await findUserProfilePDA(forum, profileOwner);
-        const [commentKey, commentBump] = await findCommentPDA(forum, userProfile, commentSeed);
-
-        // Create Signers Array
-        const signers = [];
-        if (isKp(moderator)) signers.push(<Keypair>moderator);
-
-        console.log('moderator editing comment with pubkey: ', comment.toBase58());
-
-        // Transaction
-        const txSig = await this.forumProgram.methods
-            .editCommentOnAnswerModerator(
-                moderatorProfileBump,
-                userProfileBump,
-                commentBump,
-            )
-            .accounts({
-                forum: forum,
-                moderator: isKp(moderator)? (<Keypair>moderator).publicKey : <PublicKey>moderator,
-                moderatorProfile: moderatorProfile,
-                profileOwner: profileOwner,
-                userProfile: userProfile,
-                question: question,
-                answer: answer,
-                comment: comment,
-                commentSeed: commentSeed,
-                newContentDataHash: newContentDataHash,
-                systemProgram: SystemProgram.programId,
-            })
-            .signers(signers)
-            .rpc();
-
-        return {
-            moderatorProfile,
-            moderatorProfileBump,
-            userProfileKey,
-            userProfileBump,
-            commentKey,
-            commentBump,
-            txSig
-        }
-    }
-
-    async deleteCommentOnAnswer(
-        comment: PublicKey,
-        profileOwner: PublicKey | Keypair,
-        receiver: PublicKey,
-    ) {
-        const profileOwnerKey = isKp(profileOwner) ? (<Keypair>profileOwner).publicKey : <PublicKey>profileOwner;
-
-        const commentAcct = await this.fetchCommentAccount(comment);
-        const commentSeed = commentAcct.commentSeed;
-        const answer = commentAcct.commentedOn;
-
-        const answerAcct = await this.fetchAnswerAccount(answer);
-        const question = answerAcct.question;
-
-        const questionAcct = await this.fetchQuestionAccount(question);
-        const forum = questionAcct.forum;
-
-        // Derive PDAs
-        const [userProfile, userProfileBump] = await findUserProfilePDA(forum, profileOwnerKey);
-        const [commentKey, commentBump] = await findCommentPDA(forum, userProfile, commentSeed);
-
-        // Create Signers Array
-        const signers = [];
-        if (isKp(profileOwner)) signers.push(<Keypair>profileOwner);
-
-        console.log('deleting comment with pubkey: ', comment.toBase58());
-
-        // Transaction
-        const txSig = await this.forumProgram.methods
-            .deleteCommentOnAnswer(
-                userProfileBump,
-                commentBump
-            )
-            .accounts({
-                forum: forum,
-                profileOwner: isKp(profileOwner)? (<Keypair>profileOwner).publicKey : <PublicKey>profileOwner,
-                userProfile: userProfile,
-                question: question,
-                answer: answer,
-                comment: comment,
-                commentSeed: commentSeed,
-                receiver: receiver,
-                systemProgram: SystemProgram.programId,
-            })
-            .signers(signers)
-            .rpc();
-
-        return {
-            userProfile,
-            userProfileBump,
-            commentKey,
-            commentBump,
-            txSig
-        }
-    }
-
-    async deleteCommentOnAnswerModerator(
-        comment: PublicKey,
-        moderator: PublicKey | Keypair,
-        receiver: PublicKey,
-    ) {
-        const moderatorKey = isKp(moderator) ? (<Keypair>moderator).publicKey : <PublicKey>moderator;
-
-        const commentAcct = await this.fetchCommentAccount(comment);
-        const commentSeed = commentAcct.commentSeed;
-        const answer = commentAcct.commentedOn;
-        const userProfile = commentAcct.userProfile;
-
-        const answerAcct = await this.fetchAnswerAccount(answer);
-        const question = answerAcct.question;
-
-        const questionAcct = await this.fetchQuestionAccount(question);
-        const forum = questionAcct.forum;
-
-        const userProfileAcct = await this.fetchUserProfileAccount(userProfile);
-        const profileOwner = userProfileAcct.profileOwner;
-
-        // Derive PDAs
-        const [moderatorProfile, moderatorProfileBump] = await findUserProfilePDA(forum, moderatorKey);
-        const [userProfileKey, userProfileBump] = await findUserProfilePDA(forum, profileOwner);
-        const [commentKey, commentBump] = await findCommentPDA(forum, userProfile, commentSeed);
-
-        // Create Signers Array
-        const signers = [];
-        if (isKp(moderator)) signers.push(<Keypair>moderator);
-
-        console.log('moderator deleting comment with pubkey: ', comment.toBase58());
-
-        // Transaction
-        const txSig = await this.forumProgram.methods
-            .deleteCommentOnAnswerModerator(
-                moderatorProfileBump,
-                userProfileBump,
-                commentBump
-            )
-            .accounts({
-                forum: forum,
-                moderator: isKp(moderator)? (<Keypair>moderator).publicKey : <PublicKey>moderator,
-                moderatorProfile: moderatorProfile,
-                profileOwner: profileOwner,
-                userProfile: userProfile,
-                question: question,
-                answer: answer,
-                comment: comment,
-                commentSeed: commentSeed,
-                receiver: receiver,
-                systemProgram: SystemProgram.programId,
-            })
-            .signers(signers)
-            .rpc();
-
-        return {
-            moderatorProfile,
-            moderatorProfileBump,
-            userProfileKey,
-            userProfileBump,
-            commentKey,
-            commentBump,
-            txSig
-        }
-    }
-
-//////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
-
-    async createBigNote(
-        forum: PublicKey,
-        profileOwner: PublicKey | Keypair,
-        contentDataHash: PublicKey,
-        bigNoteType: BigNoteType,
-        tags: Tags[],
-        title: string,
-        contentDataUrl: string,
-    ) {
-        const bigNoteSeedKeypair = Keypair.generate();
-        const bigNoteSeed: PublicKey = bigNoteSeedKeypair.publicKey;
-
-        const profileOwnerKey = isKp(profileOwner) ? (<Keypair>profileOwner).publicKey : <PublicKey>profileOwner;
-
-        // Derive PDAs
-        const [forumTreasury, forumTreasuryBump] = await findForumTreasuryPDA(forum);
-        const [userProfile, userProfileBump] = await findUserProfilePDA(forum, profileOwnerKey);
-        const [bigNote, bigNoteBump] = await findBigNotePDA(forum, userProfile, bigNoteSeed);
-        const [bountyPda, bountyPdaBump] = await findBigNoteBountyPDA(bigNote);
-
-        // Create Signers Array
-        const signers = [];
-        if (isKp(profileOwner)) signers.push(<Keypair>profileOwner);
-
-        console.log('creating big note with pubkey: ', bigNote.toBase58());
-
-        // Transaction
-        const txSig = await this.forumProgram.methods
-            .createBigNote(
-                forumTreasuryBump,
-                userProfileBump,
-                bigNoteType,
-                tags,
-                title,
-                contentDataUrl,
-            )
-            .accounts({
-                forum: forum,
-                forumTreasury: forumTreasury,
-                profileOwner: isKp(profileOwner)? (<Keypair>profileOwner).publicKey : <PublicKey>profileOwner,
-                userProfile: userProfile,
-                bigNote: bigNote,
-                bigNoteSeed: bigNoteSeed,
-                contentDataHash: contentDataHash,
-                bountyPda: bountyPda,
-                systemProgram: SystemProgram.programId,
-            })
-            .signers(signers)
-            .rpc();
-
-        return {
-            forumTreasury,
-            forumTreasuryBump,
-            userProfile,
-            userProfileBump,
-            bigNote,
-            bigNoteBump,
-            bountyPda,
-            bountyPdaBump,
-            bigNoteSeed,
-            txSig
-        }
-
-    }
-
-    async editBigNoteOpenContribution(
-        bigNote: PublicKey,
-        editor: PublicKey | Keypair,
-        newContentDataHash: PublicKey,
-        newTags: Tags[],
-        newTitle: string,
-        newContentDataUrl: string,
-    ) {
-        const editorKey = isKp(editor) ? (<Keypair>editor).publicKey : <PublicKey>editor;
-
-        const bigNoteAcct = await this.fetchBigNoteAccount(bigNote);
-        const bigNoteSeed = bigNoteAcct.bigNoteSeed;
-        const forum = bigNoteAcct.forum;
-        const userProfile = bigNoteAcct.userProfile;
-
-        const userProfileAcct = await this.fetchUserProfileAccount(userProfile);
-        const profileOwner = userProfileAcct.profileOwner;
-
-        // Derive PDAs
-        const [editorProfile, editorProfileBump] = await findUserProfilePDA(forum, editorKey);
-        const [userProfileKey, userProfileBump] = await findUserProfilePDA(forum, profileOwner);
-        const [bigNoteKey, bigNoteBump] = await findBigNotePDA(forum, userProfile, bigNoteSeed);
-
-        // Create Signers Array
-        const signers = [];
-        if (isKp(editor)) signers.push(<Keypair>editor);
-
-        console.log('editing big note of type open contribution with pubkey: ', bigNote.toBase58());
-
-        // Transaction
-        const txSig = await this.forumProgram.methods
-            .editBigNoteOpenContribution(
-                editorProfileBump,
-                userProfileBump,
-                bigNoteBump,
-                newTags,
-                newTitle,
-                newContentDataUrl,
-            )
-            .accounts({
-                forum: forum,
-                editor: isKp(editor) ? (<Keypair>editor).publicKey : <PublicKey>editor,
-                editorProfile: editorProfile,
-                profileOwner: profileOwner,
-                userProfile: userProfile,
-                bigNote: bigNote,
-                bigNoteSeed: bigNoteSeed,
-                newContentDataHash: newContentDataHash,
-                systemProgram: SystemProgram.programId,
-            })
-            .signers(signers)
-            .rpc();
-
-        return {
-            editorProfile,
-            editorProfileBump,
-            userProfileKey,
-            userProfileBump,
-            bigNoteKey,
-            bigNoteBump,
-            txSig
-        }
-    }
-
-    async editBigNoteCreatorCurated(
-        bigNote: PublicKey,
-        profileOwner: PublicKey | Keypair,
-        newContentDataHash: PublicKey,
-        newTags: Tags[],
-        newTitle: string,
-        newContentDataUrl: string,
-    ) {
-        const profileOwnerKey = isKp(profileOwner) ? (<Keypair>profileOwner).publicKey : <PublicKey>profileOwner;
-
-        const bigNoteAcct = await this.fetchBigNoteAccount(bigNote);
-        const bigNoteSeed = bigNoteAcct.bigNoteSeed;
-        const forum = bigNoteAcct.forum;
-
-        // Derive PDAs
-        const [userProfile, userProfileBump] = await findUserProfilePDA(forum, profileOwnerKey);
-        const [bigNoteKey, bigNoteBump] = await findBigNotePDA(forum, userProfile, bigNoteSeed);
-
-        // Create Signers Array
-        const signers = [];
-        if (isKp(profileOwner)) signers.push(<Keypair>profileOwner);
-
-        console.log('editing big note of type creator curated with pubkey: ', bigNote.toBase58());
-
-        // Transaction
-        const txSig = await this.forumProgram.methods
-            .editBigNoteCreatorCurated(
-                userProfileBump,
-                bigNoteBump,
-                newTags,
-                newTitle,
-                newContentDataUrl,
-            )
-            .accounts({
-                forum: forum,
-                profileOwner: isKp(profileOwner) ? (<Keypair>profileOwner).publicKey : <PublicKey>profileOwner,
-                userProfile: userProfile,
-                bigNote: bigNote,
-                bigNoteSeed: bigNoteSeed,
-                newContentDataHash: newContentDataHash,
-                systemProgram: SystemProgram.programId,
-            })
-            .signers(signers)
-            .rpc();
-
-        return {
-            userProfile,
-            userProfileBump,
-            bigNoteKey,
-            bigNoteBump,
-            txSig
-        }
-    }
-
-    async editBigNoteModerator(
-        bigNote: PublicKey,
-        moderator: PublicKey | Keypair,
-        newContentDataHash: PublicKey,
-        newTags: Tags[],
-        newTitle: string,
-        newContentDataUrl: string,
-    ) {
-        const moderatorKey = isKp(moderator) ? (<Keypair>moderator).publicKey : <PublicKey>moderator;
-
-        const bigNoteAcct = await this.fetchBigNoteAccount(bigNote);
-        const bigNoteSeed = bigNoteAcct.bigNoteSeed;
-        const forum = bigNoteAcct.forum;
-        const userProfile = bigNoteAcct.userProfile;
-
-        const userProfileAcct = await this.fetchUserProfileAccount(userProfile);
-        const profileOwner = userProfileAcct.profileOwner;
-
-        // Derive PDAs
-        const [moderatorProfile, moderatorProfileBump] = await findUserProfilePDA(forum, moderatorKey);
-        const [userProfileKey, userProfileBump] = await findUserProfilePDA(forum, profileOwner);
-        const [bigNoteKey, bigNoteBump] = await findBigNotePDA(forum, userProfile, bigNoteSeed);
-
-        // Create Signers Array
-        const signers = [];
-        if (isKp(moderator)) signers.push(<Keypair>moderator);
-
-        console.log('moderator editing big note with pubkey: ', bigNote.toBase58());
-
-        // Transaction
-        const txSig = await this.forumProgram.methods
-            .editBigNoteModerator(
-                moderatorProfileBump,
-                userProfileBump,
-                bigNoteBump,
-                newTags,
-                newTitle,
-                newContentDataUrl,
-            )
-            .accounts({
-                forum: forum,
-                moderator: isKp(moderator)? (<Keypair>moderator).publicKey : <PublicKey>moderator,
-                moderatorProfile: moderatorProfile,
-                profileOwner: profileOwner,
-                userProfile: userProfile,
-                bigNote: bigNote,
-                bigNoteSeed: bigNoteSeed,
-                newContentDataHash: newContentDataHash,
-                systemProgram: SystemProgram.programId,
-            })
-            .signers(signers)
-            .rpc();
-
-        return {
-            moderatorProfile,
-            moderatorProfileBump,
-            userProfileKey,
-            userProfileBump,
-            bigNoteKey,
-            bigNoteSeed,
-            txSig
-        }
-    }
-
-    async deleteBigNoteModerator(
-        bigNote: PublicKey,
-        moderator: PublicKey | Keypair,
-        receiver: PublicKey,
-    ) {
-        const moderatorKey = isKp(moderator) ? (<Keypair>moderator).publicKey : <PublicKey>moderator;
-
-        const bigNoteAcct = await this.fetchBigNoteAccount(bigNote);
-        const bigNoteSeed = bigNoteAcct.bigNoteSeed;
-        const forum = bigNoteAcct.forum;
-        const userProfile = bigNoteAcct.userProfile;
-
-        const userProfileAcct = await this.fetchUserProfileAccount(userProfile);
-        const profileOwner = userProfileAcct.profileOwner;
-
-        // Derive PDAs
-        const [moderatorProfile, moderatorProfileBump] = await findUserProfilePDA(forum, moderatorKey);
-        const [userProfileKey, userProfileBump] = await findUserProfilePDA(forum, profileOwner);
-        const [bigNoteKey, bigNoteBump] = await findBigNotePDA(forum, userProfile, bigNoteSeed);
-
-        // Create Signers Array
-        const signers = [];
-        if (isKp(moderator)) signers.push(<Keypair>moderator);
-
-        console.log('moderator deleting big note with pubkey: ', bigNote.toBase58());
-
-        // Transaction
-        const txSig = await this.forumProgram.methods
-            .deleteBigNoteModerator(
-                moderatorProfileBump,
-                userProfileBump,
-                bigNoteBump
-            )
-            .accounts({
-                forum: forum,
-                moderator: isKp(moderator)? (<Keypair>moderator).publicKey : <PublicKey>moderator,
-                moderatorProfile: moderatorProfile,
-                profileOwner: profileOwner,
-                userProfile: userProfile,
-                bigNote: bigNote,
-                bigNoteSeed: bigNoteSeed,
-                receiver: receiver,
-                systemProgram: SystemProgram.programId,
-            })
-            .signers(signers)
-            .rpc();
-
-        return {
-            userProfileKey,
-            userProfileBump,
-            bigNoteKey,
-            bigNoteBump,
-            txSig
-        }
-    }
-
-    async supplementBigNoteBounty(
-        bigNote: PublicKey,
-        supplementor: PublicKey | Keypair,
-        supplementalBountyAmount: BN,
-    ) {
-        const supplementorKey = isKp(supplementor) ? (<Keypair>supplementor).publicKey : <PublicKey>supplementor;
-
-        const bigNoteAcct = await this.fetchBigNoteAccount(bigNote);
-        const forum = bigNoteAcct.forum;
-        const bigNoteSeed = bigNoteAcct.questionSeed;
-        const userProfile = bigNoteAcct.userProfile;
-
-        const userProfileAcct = await this.fetchUserProfileAccount(userProfile);
-        const profileOwner = userProfileAcct.profileOwner;
-
-        // Derive PDAs
-        const [forumTreasury, forumTreasuryBump] = await findForumTreasuryPDA(forum);
-        const [supplementorProfile, supplementorProfileBump] = await findUserProfilePDA(forum, supplementorKey);
-        const [userProfileKey, userProfileBump] = await findUserProfilePDA(forum, profileOwner);
-        const [bigNoteKey, bigNoteBump] = await findBigNotePDA(forum, userProfile, bigNoteSeed);
-        const [bountyPda, bountyPdaBump] = await findBigNoteBountyPDA(bigNote);
-
-        // Create Signers Array
-        const signers = [];
-        if (isKp(supplementor)) signers.push(<Keypair>supplementor);
-
-        console.log('supplementing bounty for big note with pubkey: ', bigNote.toBase58(),
-                    'with:', supplementalBountyAmount.toString(), 'Sol');
-
-        // Transaction
-        const txSig = await this.forumProgram.methods
-            .supplementBigNoteBounty(
-                forumTreasuryBump,
-                supplementorProfileBump,
-                userProfileBump,
-                bigNoteBump,
-                bountyPdaBump,
-                supplementalBountyAmount,
-            )
-            .accounts({
-                forum: forum,
-                forumTreasury: forumTreasury,
-                supplementor: isKp(supplementor)? (<Keypair>supplementor).publicKey : <PublicKey>supplementor,
-                supplementorProfile: supplementorProfile,
-                profileOwner: profileOwner,
-                userProfile: userProfile,
-                bigNote: bigNote,
-                bigNoteSeed: bigNoteSeed,
-                bountyPda: bountyPda,
-                systemProgram: SystemProgram.programId,
-            })
-            .signers(signers)
-            .rpc();
-
-        return {
-            supplementorProfile,
-            supplementorProfileBump,
-            userProfileKey,
-            userProfileBump,
-            bigNoteKey,
-            bigNoteBump,
-            bountyPda,
-            bountyPdaBump,
-            txSig
-        }
-    }
-
-    async refundBigNoteBountySupplementorModerator(
-        bigNote: PublicKey,
-        moderator: PublicKey | Keypair,
-        supplementor: PublicKey,
-    ) {
-        const moderatorKey = isKp(moderator) ? (<Keypair>moderator).publicKey : <PublicKey>moderator;
-
-        const bigNoteAcct = await this.fetchBigNoteAccount(bigNote);
-        const forum = bigNoteAcct.forum;
-        const bigNoteSeed = bigNoteAcct.bigNoteSeed;
-        const userProfile = bigNoteAcct.userProfile;
-
-        const userProfileAcct = await this.fetchUserProfileAccount(userProfile);
-        const profileOwner = userProfileAcct.profileOwner;
-
-        // Derive PDAs
-        const [moderatorProfile, moderatorProfileBump] = await findUserProfilePDA(forum, moderatorKey);
-        const [userProfileKey, userProfileBump] = await findUserProfilePDA(forum, profileOwner);
-        const [supplementorProfile, supplementorProfileBump] = await findUserProfilePDA(forum, supplementor);
-        const [bigNoteKey, bigNoteBump] = await findBigNotePDA(forum, userProfile, bigNoteSeed);
-        const [bountyPda, bountyPdaBump] = await findBigNoteBountyPDA(bigNote);
-
-        // Create Signers Array
-        const signers = [];
-        if (isKp(moderator)) signers.push(<Keypair>moderator);
-
-        console.log('refunding user profile account with pubkey: ', userProfile.toBase58());
-
-        // Transaction
-        const txSig = await this.forumProgram.methods
-            .refundBigNoteBountySupplementorModerator(
-                moderatorProfileBump,
-                userProfileBump,
-                supplementorProfileBump,
-                bigNoteBump,
-                bountyPdaBump,
-            )
-            .accounts({
-                forum: forum,
-                moderator: isKp(moderator)? (<Keypair>moderator).publicKey : <PublicKey>moderator,
-                moderatorProfile: moderatorProfile,
-                profileOwner: profileOwner,
-                userProfile: userProfile,
-                supplementor: supplementor,
-                supplementorProfile: supplementorProfile,
-                bigNote: bigNote,
-                bigNoteSeed: bigNoteSeed,
-                bountyPda: bountyPda,
-                systemProgram: SystemProgram.programId,
-            })
-            .signers(signers)
-            .rpc();
-
-        return {
-            moderatorProfile,
-            moderatorProfileBump,
-            userProfileKey,
-            userProfileBump,
-            supplementorProfile,
-            supplementorProfileBump,
-            bigNoteKey,
-            bigNoteSeed,
-            bountyPda,
-            bountyPdaBump,
-            txSig
-        }
-    }
-
-    async acceptProposedContribution(
-        proposedContribution: PublicKey,
-        profileOwner: PublicKey | Keypair,
-        receiver: PublicKey,
-    ) {
-        const profileOwnerKey = isKp(profileOwner) ? (<Keypair>profileOwner).publicKey : <PublicKey>profileOwner;
-
-        const proposedContributionAcct = await this.fetchProposedContributionAccount(proposedContribution);
-        const bigNote = proposedContributionAcct.bigNote;
-        const proposedContributionSeed = proposedContributionAcct.proposedContributionSeed;
-        const proposalUserProfile = proposedContributionAcct.userProfile;
-
-        const bigNoteAcct = await this.fetchBigNoteAccount(bigNote);
-        const forum = bigNoteAcct.forum;
-        const bigNoteSeed = bigNoteAcct.bigNoteSeed;
-
-        const proposalUserProfileAcct = await this.fetchUserProfileAccount(proposalUserProfile);
-        const proposalProfileOwner = proposalUserProfileAcct.profileOwner;
-
-        // Derive PDAs
-        const [userProfile, userProfileBump] = await findUserProfilePDA(forum, profileOwnerKey);
-        const [proposalUserProfileKey, proposalUserProfileBump] = await findUserProfilePDA(forum, proposalProfileOwner);
-        const [bigNoteKey, bigNoteBump] = await findBigNotePDA(forum, userProfile, bigNoteSeed);
-        const [bountyPda, bountyPdaBump] = await findBigNoteBountyPDA(bigNote);
-        const [proposedContributionKey, proposedContributionBump] = await findProposedContributionPDA(forum, proposalUserProfile, proposedContributionSeed);
-
-        // Create Signers Array
-        const signers = [];
-        if (isKp(profileOwner)) signers.push(<Keypair>profileOwner);
-
-        console.log('accepting proposed contribution with pubkey: ', proposedContribution.toBase58());
-
-        // Transaction
-        const txSig = await this.forumProgram.methods
-            .acceptProposedContribution(
-                userProfileBump,
-                bigNoteBump,
-                bountyPdaBump,
-                proposalUserProfileBump,
-                proposedContributionBump,
-            )
-            .accounts({
-                forum: forum,
-                profileOwner: isKp(profileOwner)? (<Keypair>profileOwner).publicKey : <PublicKey>profileOwner,
-                userProfile: userProfile,
-                bigNote: bigNote,
-                bigNoteSeed: bigNoteSeed,
-                bountyPda: bountyPda,
-                proposalProfileOwner: proposalProfileOwner,
-                proposalUserProfile: proposalUserProfile,
-                proposedContribution: proposedContribution,
-                proposedContributionSeed: proposedContributionSeed,
-                receiver: receiver,
-                systemProgram: SystemProgram.programId,
-            })
-            .signers(signers)
-            .rpc();
-
-        return {
-            userProfile,
-            userProfileBump,
-            proposalUserProfileKey,
-            proposalUserProfileBump,
-            bigNoteKey,
-            bigNoteBump,
-            bountyPda,
-            bountyPdaBump,
-            proposedContributionKey,
-            proposedContributionSeed,
-            txSig
-        }
-    }
-
-    async rejectProposedContribution(
-        proposedContribution: PublicKey,
-        profileOwner: PublicKey | Keypair,
-    ) {
-        const profileOwnerKey = isKp(profileOwner) ? (<Keypair>profileOwner).publicKey : <PublicKey>profileOwner;
-
-        const proposedContributionAcct = await this.fetchProposedContributionAccount(proposedContribution);
-        const bigNote = proposedContributionAcct.bigNote;
-        const proposedContributionSeed = proposedContributionAcct.proposedContributionSeed;
-        const proposalUserProfile = proposedContributionAcct.userProfile;
-
-        const bigNoteAcct = await this.fetchBigNoteAccount(bigNote);
-        const forum = bigNoteAcct.forum;
-        const bigNoteSeed = bigNoteAcct.bigNoteSeed;
-
-        const proposalUserProfileAcct = await this.fetchUserProfileAccount(proposalUserProfile);
-        const proposalProfileOwner = proposalUserProfileAcct.profileOwner;
-
-        // Derive PDAs
-        const [userProfile, userProfileBump] = await findUserProfilePDA(forum, profileOwnerKey);
-        const [proposalUserProfileKey, proposalUserProfileBump] = await findUserProfilePDA(forum, proposalProfileOwner);
-        const [bigNoteKey, bigNoteBump] = await findBigNotePDA(forum, userProfile, bigNoteSeed);
-        const [proposedContributionKey, proposedContributionBump] = await findProposedContributionPDA(forum, proposalUserProfile, proposedContributionSeed);
-
-        // Create Signers Array
-        const signers = [];
-        if (isKp(profileOwner)) signers.push(<Keypair>profileOwner);
-
-        console.log('rejecting proposed contribution with pubkey: ', proposedContribution.toBase58());
-
-        // Transaction
-        const txSig = await this.forumProgram.methods
-            .rejectProposedContribution(
-                userProfileBump,
-                bigNoteBump,
-                proposalUserProfileBump,
-                proposedContributionBump,
-            )
-            .accounts({
-                forum: forum,
-                profileOwner: isKp(profileOwner)? (<Keypair>profileOwner).publicKey : <PublicKey>profileOwner,
-                userProfile: userProfile,
-                bigNote: bigNote,
-                bigNoteSeed: bigNoteSeed,
-                proposalProfileOwner: proposalProfileOwner,
-                proposalUserProfile: proposalUserProfile,
-                proposedContribution: proposedContribution,
-                proposedContributionSeed: proposedContributionSeed,
-                systemProgram: SystemProgram.programId,
-            })
-            .signers(signers)
-            .rpc();
-
-        return {
-            userProfile,
-            userProfileBump,
-            proposalUserProfileKey,
-            proposalUserProfileBump,
-            bigNoteKey,
-            bigNoteBump,
-            proposedContributionKey,
-            proposedContributionSeed,
-            txSig
-        }
-    }
-
-    async applyForBigNoteVerification(
-        bigNote: PublicKey,
-        profileOwner: PublicKey | Keypair,
-    ) {
-        const profileOwnerKey = isKp(profileOwner) ? (<Keypair>profileOwner).publicKey : <PublicKey>profileOwner;
-
-        const bigNoteAcct = await this.fetchBigNoteAccount(bigNote);
-        const forum = bigNoteAcct.forum;
-        const bigNoteSeed = bigNoteAcct.bigNoteSeed;
-
-        // Derive PDAs
-        const [userProfile, userProfileBump] = await findUserProfilePDA(forum, profileOwnerKey);
-        const [bigNoteKey, bigNoteBump] = await findBigNotePDA(forum, userProfile, bigNoteSeed);
-        const [verificationApplication, verificationApplicationBump] = await findBigNoteVerificationApplicationPDA(bigNote);
-        const [verificationFeePda, verificationFeePdaBump] = await findVerificationFeePDA(bigNote);
-
-        // Create Signers Array
-        const signers = [];
-        if (isKp(profileOwner)) signers.push(<Keypair>profileOwner);
-
-        console.log('applying for verification for big note with pubkey: ', bigNote.toBase58());
-
-        // Transaction
-        const txSig = await this.forumProgram.methods
-            .applyForBigNoteVerification(
+            .createBigNote(
+                forumTreasuryBump,
                 userProfileBump,
-                bigNoteBump,
+                bigNoteType,
+                tags,
+                title,
+                contentDataUrl,
             )
             .accounts({
                 forum: forum,
+                forumTreasury: forumTreasury,
                 profileOwner: isKp(profileOwner)? (<Keypair>profileOwner).publicKey : <PublicKey>profileOwner,
                 userProfile: userProfile,
+                category: category,
                 bigNote: bigNote,
                 bigNoteSeed: bigNoteSeed,
-                verificationApplication: verificationApplication,
-                verificationFeePda: verificationFeePda,
+                contentDataHash: contentDataHash,
+                bountyPda: bountyPda,
                 systemProgram: SystemProgram.programId,
             })
             .signers(signers)
             .rpc();
 
         return {
+            forumTreasury,
+            forumTreasuryBump,
             userProfile,
             userProfileBump,
-            bigNoteKey,
+            bigNote,
             bigNoteBump,
-            verificationApplication,
-            verificationApplicationBump,
-            verificationFeePda,
-            verificationFeePdaBump,
+            bountyPda,
+            bountyPdaBump,
+            bigNoteSeed,
             txSig
         }
+
     }
 
-    async deleteBigNoteVerificationApplication(
+    async editBigNoteOpenContribution(
         bigNote: PublicKey,
-        profileOwner: PublicKey | Keypair,
+        editor: PublicKey | Keypair,
+        newContentDataHash: PublicKey,
+        newTags: Tags[],
+        newTitle: string,
+        newContentDataUrl: string,
+        editorProfileSeed?: PublicKey,
     ) {
-        const profileOwnerKey = isKp(profileOwner) ? (<Keypair>profileOwner).publicKey : <PublicKey>profileOwner;
+        const editorKey = isKp(editor) ? (<Keypair>editor).publicKey : <PublicKey>editor;
 
         const bigNoteAcct = await this.fetchBigNoteAccount(bigNote);
-        const forum = bigNoteAcct.forum;
         const bigNoteSeed = bigNoteAcct.bigNoteSeed;
+        const forum = bigNoteAcct.forum;
+        const userProfile = bigNoteAcct.userProfile;
 
-        // Derive PDAs
-        const [userProfile, userProfileBump] = await findUserProfilePDA(forum, profileOwnerKey);
+        const userProfileAcct = await this.fetchUserProfileAccount(userProfile);
+        const profileOwner = userProfileAcct.profileOwner;
+
+        // Derive PDAs (a session key delegate edits on behalf of the editor profile with the given seed)
+        const [editorProfile, editorProfileBump] = await findUserProfilePDA(forum, editorProfileSeed ?? editorKey);
+        const [sessionKey, sessionKeyBump] = await findSessionKeyPDA(editorProfile, editorKey);
+        const [userProfileKey, userProfileBump] = await findUserProfilePDA(forum, userProfileAcct.profileSeed);
         const [bigNoteKey, bigNoteBump] = await findBigNotePDA(forum, userProfile, bigNoteSeed);
-        const [verificationApplication, verificationApplicationBump] = await findBigNoteVerificationApplicationPDA(bigNote);
-        const [verificationFeePda, verificationFeePdaBump] = await findVerificationFeePDA(bigNote);
+        const [verificationRecord, verificationRecordBump] = await findVerificationRecordPDA(bigNote);
+        const [revision, revisionBump] = await findRevisionPDA(bigNote, bigNoteAcct.revisionCount);
 
         // Create Signers Array
         const signers = [];
-        if (isKp(profileOwner)) signers.push(<Keypair>profileOwner);
+        if (isKp(editor)) signers.push(<Keypair>editor);
 
-        console.log('deleting verification application for big note with pubkey: ', bigNote.toBase58());
+        console.log('editing big note of type open contribution with pubkey: ', bigNote.toBase58());
 
         // Transaction
         const txSig = await this.forumProgram.methods
-            .deleteBigNoteVerificationApplication(
+            .editBigNoteOpenContribution(
+                editorProfileBump,
                 userProfileBump,
                 bigNoteBump,
-                verificationApplicationBump,
-                verificationFeePdaBump,
+                sessionKeyBump,
+                verificationRecordBump,
+                newTags,
+                newTitle,
+                newContentDataUrl,
             )
             .accounts({
                 forum: forum,
-                profileOwner: isKp(profileOwner)? (<Keypair>profileOwner).publicKey : <PublicKey>profileOwner,
+                editor: isKp(editor) ? (<Keypair>editor).publicKey : <PublicKey>editor,
+                editorProfile: editorProfile,
+                sessionKey: sessionKey,
+                profileOwner: profileOwner,
                 userProfile: userProfile,
                 bigNote: bigNote,
                 bigNoteSeed: bigNoteSeed,
-                verificationApplication: verificationApplication,
-                verificationFeePda: verificationFeePda,
+                verificationRecord: verificationRecord,
+                newContentDataHash: newContentDataHash,
+                revision: revision,
                 systemProgram: SystemProgram.programId,
             })
             .signers(signers)
             .rpc();
 
         return {
-            userProfile,
+            editorProfile,
+            editorProfileBump,
+            sessionKey,
+            sessionKeyBump,
+            userProfileKey,
             userProfileBump,
             bigNoteKey,
             bigNoteBump,
-            verificationApplication,
-            verificationApplicationBump,
-            verificationFeePda,
-            verificationFeePdaBump,
+            revision,
+            revisionBump,
             txSig
         }
     }
 
-    async deleteBigNoteVerificationApplicationModerator(
+    async editBigNoteCreatorCurated(
         bigNote: PublicKey,
-        moderator: PublicKey | Keypair,
+        editor: PublicKey | Keypair,
+        newContentDataHash: PublicKey,
+        newTags: Tags[],
+        newTitle: string,
+        newContentDataUrl: string,
     ) {
-        const moderatorKey = isKp(moderator) ? (<Keypair>moderator).publicKey : <PublicKey>moderator;
+        const editorKey = isKp(editor) ? (<Keypair>editor).publicKey : <PublicKey>editor;
 
         const bigNoteAcct = await this.fetchBigNoteAccount(bigNote);
+        const bigNoteSeed = bigNoteAcct.bigNoteSeed;
         const forum = bigNoteAcct.forum;
         const userProfile = bigNoteAcct.userProfile;
-        const bigNoteSeed = bigNoteAcct.bigNoteSeed;
+        const editorProfile = bigNoteAcct.currentOwner;
 
         const userProfileAcct = await this.fetchUserProfileAccount(userProfile);
         const profileOwner = userProfileAcct.profileOwner;
 
+        const editorProfileAcct = await this.fetchUserProfileAccount(editorProfile);
+
         // Derive PDAs
-        const [moderatorProfile, moderatorProfileBump] = await findUserProfilePDA(forum, moderatorKey);
-        const [userProfileKey, userProfileBump] = await findUserProfilePDA(forum, profileOwner);
+        const [editorProfileKey, editorProfileBump] = await findUserProfilePDA(forum, editorProfileAcct.profileSeed);
+        const [sessionKey, sessionKeyBump] = await findSessionKeyPDA(editorProfile, editorKey);
+        const [userProfileKey, userProfileBump] = await findUserProfilePDA(forum, userProfileAcct.profileSeed);
         const [bigNoteKey, bigNoteBump] = await findBigNotePDA(forum, userProfile, bigNoteSeed);
-        const [verificationApplication, verificationApplicationBump] = await findBigNoteVerificationApplicationPDA(bigNote);
-        const [verificationFeePda, verificationFeePdaBump] = await findVerificationFeePDA(bigNote);
+        const [verificationRecord, verificationRecordBump] = await findVerificationRecordPDA(bigNote);
+        const [revision, revisionBump] = await findRevisionPDA(bigNote, bigNoteAcct.revisionCount);
 
         // Create Signers Array
         const signers = [];
-        if (isKp(moderator)) signers.push(<Keypair>moderator);
+        if (isKp(editor)) signers.push(<Keypair>editor);
 
-        console.log('deleting verification application for big note with pubkey: ', bigNote.toBase58());
+        console.log('editing big note of type creator curated with pubkey: ', bigNote.toBase58());
 
         // Transaction
         const txSig = await this.forumProgram.methods
-            .deleteBigNoteVerificationApplicationModerator(
-                moderatorProfileBump,
+            .editBigNoteCreatorCurated(
+                editorProfileBump,
                 userProfileBump,
                 bigNoteBump,
-                verificationApplicationBump,
-                verificationFeePdaBump,
+                sessionKeyBump,
+                verificationRecordBump,
+                newTags,
+                newTitle,
+                newContentDataUrl,
             )
             .accounts({
                 forum: forum,
-                moderator: isKp(moderator) ? (<Keypair>moderator).publicKey : <PublicKey>moderator,
-                moderatorProfile: moderatorProfile,
+                editor: editorKey,
+                editorProfile: editorProfile,
+                sessionKey: sessionKey,
                 profileOwner: profileOwner,
                 userProfile: userProfile,
                 bigNote: bigNote,
                 bigNoteSeed: bigNoteSeed,
-                verificationApplication: verificationApplication,
-                verificationFeePda: verificationFeePda,
+                verificationRecord: verificationRecord,
+                newContentDataHash: newContentDataHash,
+                revision: revision,
                 systemProgram: SystemProgram.programId,
             })
             .signers(signers)
             .rpc();
 
         return {
-            moderatorProfile,
-            moderatorProfileBump,
+            editorProfileKey,
+            editorProfileBump,
+            sessionKey,
+            sessionKeyBump,
             userProfileKey,
             userProfileBump,
             bigNoteKey,
             bigNoteBump,
-            verificationApplication,
-            verificationApplicationBump,
-            verificationFeePda,
-            verificationFeePdaBump,
+            revision,
+            revisionBump,
             txSig
         }
     }
 
-//////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
-
-    async acceptBigNoteVerificationApplication(
-        verificationApplication: PublicKey,
+    async editBigNoteModerator(
+        bigNote: PublicKey,
         moderator: PublicKey | Keypair,
+        newContentDataHash: PublicKey,
+        newTags: Tags[],
+        newTitle: string,
+        newContentDataUrl: string,
     ) {
         const moderatorKey = isKp(moderator) ? (<Keypair>moderator).publicKey : <PublicKey>moderator;
 
-        const verificationApplicationAcct = await this.fetchBigNoteVerificationApplicationAccount(verificationApplication);
-        const bigNote = verificationApplicationAcct.bigNote;
-        const verificationFeePda = verificationApplicationAcct.verificationFeePda;
-
         const bigNoteAcct = await this.fetchBigNoteAccount(bigNote);
+        const bigNoteSeed = bigNoteAcct.bigNoteSeed;
         const forum = bigNoteAcct.forum;
         const userProfile = bigNoteAcct.userProfile;
-        const bigNoteSeed = bigNoteAcct.bigNoteSeed;
 
         const userProfileAcct = await this.fetchUserProfileAccount(userProfile);
         const profileOwner = userProfileAcct.profileOwner;
 
         // Derive PDAs
-        const [forumTreasury, forumTreasuryBump] = await findForumTreasuryPDA(forum);
         const [moderatorProfile, moderatorProfileBump] = await findUserProfilePDA(forum, moderatorKey);
-        const [userProfileKey, userProfileBump] = await findUserProfilePDA(forum, profileOwner);
+        const [userProfileKey, userProfileBump] = await findUserProfilePDA(forum, userProfileAcct.profileSeed);
         const [bigNoteKey, bigNoteBump] = await findBigNotePDA(forum, userProfile, bigNoteSeed);
-        const [verificationApplicationKey, verificationApplicationBump] = await findBigNoteVerificationApplicationPDA(bigNote);
-        const [verificationFeePdaKey, verificationFeePdaBump] = await findVerificationFeePDA(bigNote);
+        const [revision, revisionBump] = await findRevisionPDA(bigNote, bigNoteAcct.revisionCount);
 
         // Create Signers Array
         const signers = [];
         if (isKp(moderator)) signers.push(<Keypair>moderator);
 
-        console.log('accepting big note verification application for big note with pubkey: ', bigNote.toBase58());
+        console.log('moderator editing big note with pubkey: ', bigNote.toBase58());
 
         // Transaction
         const txSig = await this.forumProgram.methods
-            .acceptBigNoteVerificationApplication(
-                forumTreasuryBump,
+            .editBigNoteModerator(
                 moderatorProfileBump,
                 userProfileBump,
                 bigNoteBump,
-                verificationApplicationBump,
-                verificationFeePdaBump,
+                newTags,
+                newTitle,
+                newContentDataUrl,
             )
             .accounts({
                 forum: forum,
-                forumTreasury: forumTreasury,
                 moderator: isKp(moderator)? (<Keypair>moderator).publicKey : <PublicKey>moderator,
                 moderatorProfile: moderatorProfile,
                 profileOwner: profileOwner,
                 userProfile: userProfile,
                 bigNote: bigNote,
                 bigNoteSeed: bigNoteSeed,
-                verificationApplication: verificationApplication,
-                verificationFeePda: verificationFeePda,
+                category: bigNoteAcct.category,
+                newContentDataHash: newContentDataHash,
+                revision: revision,
                 systemProgram: SystemProgram.programId,
             })
             .signers(signers)
             .rpc();
 
         return {
-            forumTreasury,
-            forumTreasuryBump,
             moderatorProfile,
             moderatorProfileBump,
             userProfileKey,
             userProfileBump,
             bigNoteKey,
-            bigNoteBump,
-            verificationApplicationKey,
-            verificationApplicationBump,
-            verificationFeePdaKey,
-            verificationFeePdaBump,
+            bigNoteSeed,
+            revision,
+            revisionBump,
             txSig
         }
     }
 
-    async rejectBigNoteVerificationApplication(
-        verificationApplication: PublicKey,
+    async deleteBigNoteModerator(
+        bigNote: PublicKey,
         moderator: PublicKey | Keypair,
+        receiver: PublicKey,
     ) {
         const moderatorKey = isKp(moderator) ? (<Keypair>moderator).publicKey : <PublicKey>moderator;
 
-        const verificationApplicationAcct = await this.fetchBigNoteVerificationApplicationAccount(verificationApplication);
-        const bigNote = verificationApplicationAcct.bigNote;
-        const verificationFeePda = verificationApplicationAcct.verificationFeePda;
-
         const bigNoteAcct = await this.fetchBigNoteAccount(bigNote);
+        const bigNoteSeed = bigNoteAcct.bigNoteSeed;
         const forum = bigNoteAcct.forum;
         const userProfile = bigNoteAcct.userProfile;
-        const bigNoteSeed = bigNoteAcct.bigNoteSeed;
 
         const userProfileAcct = await this.fetchUserProfileAccount(userProfile);
         const profileOwner = userProfileAcct.profileOwner;
 
+        // A big note converted from a question credits the source answer's author (ignored otherwise)
+        let answerUserProfile = userProfile;
+        let answerSeed = bigNoteSeed;
+        if (!bigNoteAcct.sourceQuestion.equals(PublicKey.default)) {
+            const sourceAnswerAcct = await this.fetchAnswerAccount(bigNoteAcct.sourceAnswer);
+            answerUserProfile = sourceAnswerAcct.userProfile;
+            answerSeed = sourceAnswerAcct.answerSeed;
+        }
+
         // Derive PDAs
         const [moderatorProfile, moderatorProfileBump] = await findUserProfilePDA(forum, moderatorKey);
-        const [userProfileKey, userProfileBump] = await findUserProfilePDA(forum, profileOwner);
+        const [userProfileKey, userProfileBump] = await findUserProfilePDA(forum, userProfileAcct.profileSeed);
         const [bigNoteKey, bigNoteBump] = await findBigNotePDA(forum, userProfile, bigNoteSeed);
-        const [verificationApplicationKey, verificationApplicationBump] = await findBigNoteVerificationApplicationPDA(bigNote);
-        const [verificationFeePdaKey, verificationFeePdaBump] = await findVerificationFeePDA(bigNote);
 
         // Create Signers Array
         const signers = [];
         if (isKp(moderator)) signers.push(<Keypair>moderator);
 
-        console.log('rejecting big note verification application for big note with pubkey: ', bigNote.toBase58());
+        console.log('moderator deleting big note with pubkey: ', bigNote.toBase58());
 
         // Transaction
         const txSig = await this.forumProgram.methods
-            .rejectBigNoteVerificationApplication(
+            .deleteBigNoteModerator(
                 moderatorProfileBump,
                 userProfileBump,
-                bigNoteBump,
-                verificationApplicationBump,
-                verificationFeePdaBump,
+                bigNoteBump
             )
             .accounts({
                 forum: forum,
@@ -3291,188 +2833,140 @@ export class ForumClient {
                 userProfile: userProfile,
                 bigNote: bigNote,
                 bigNoteSeed: bigNoteSeed,
-                verificationApplication: verificationApplication,
-                verificationFeePda: verificationFeePda,
+                category: bigNoteAcct.category,
+                answerUserProfile: answerUserProfile,
+                answerSeed: answerSeed,
+                receiver: receiver,
                 systemProgram: SystemProgram.programId,
             })
             .signers(signers)
             .rpc();
 
         return {
-            moderatorProfile,
-            moderatorProfileBump,
             userProfileKey,
             userProfileBump,
             bigNoteKey,
             bigNoteBump,
-            verificationApplicationKey,
-            verificationApplicationBump,
-            verificationFeePdaKey,
-            verificationFeePdaBump,
             txSig
         }
     }
 
-//////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
-
-    async proposeContribution(
+    async supplementBigNoteBounty(
         bigNote: PublicKey,
-        profileOwner: PublicKey | Keypair,
-        contentDataHash: PublicKey,
+        supplementor: PublicKey | Keypair,
+        supplementalBountyAmount: BN,
     ) {
-        const proposedContributionSeedKeypair = Keypair.generate();
-        const proposedContributionSeed: PublicKey = proposedContributionSeedKeypair.publicKey;
-
-        const profileOwnerKey = isKp(profileOwner) ? (<Keypair>profileOwner).publicKey : <PublicKey>profileOwner;
+        const supplementorKey = isKp(supplementor) ? (<Keypair>supplementor).publicKey : <PublicKey>supplementor;
 
         const bigNoteAcct = await this.fetchBigNoteAccount(bigNote);
         const forum = bigNoteAcct.forum;
         const bigNoteSeed = bigNoteAcct.bigNoteSeed;
+        const userProfile = bigNoteAcct.userProfile;
 
-        // Derive PDAs
-        const [userProfile, userProfileBump] = await findUserProfilePDA(forum, profileOwnerKey);
-        const [bigNoteKey, bigNoteBump] = await findBigNotePDA(forum, userProfile, bigNoteSeed);
-        const [proposedContribution, proposedContributionBump] = await findProposedContributionPDA(forum, userProfile, proposedContributionSeed);
-
-        // Create Signers Array
-        const signers = [];
-        if (isKp(profileOwner)) signers.push(<Keypair>profileOwner);
-
-        console.log('creating proposed contribution with pubkey: ', proposedContribution.toBase58());
-
-        // Transaction
-        const txSig = await this.forumProgram.methods
-            .proposeContribution(
-                userProfileBump,
-            )
-            .accounts({
-                forum: forum,
-                profileOwner: isKp(profileOwner)? (<Keypair>profileOwner).publicKey : <PublicKey>profileOwner,
-                userProfile: userProfile,
-                bigNote: bigNote,
-                proposedContribution: proposedContribution,
-                proposedContributionSeed: proposedContributionSeed,
-                contentDataHash: contentDataHash,
-                systemProgram: SystemProgram.programId,
-            })
-            .signers(signers)
-            .rpc();
-
-        return {
-            userProfile,
-            userProfileBump,
-            bigNoteKey,
-            bigNoteBump,
-            proposedContribution,
-            proposedContributionBump,
-            txSig
-        }
-    }
-
-    async editProposedContribution(
-        proposedContribution: PublicKey,
-        profileOwner: PublicKey | Keypair,
-        newContentDataHash: PublicKey,
-    ) {
-        const profileOwnerKey = isKp(profileOwner) ? (<Keypair>profileOwner).publicKey : <PublicKey>profileOwner;
-
-        const proposedContributionAcct = await this.fetchProposedContributionAccount(proposedContribution);
-        const bigNote = proposedContributionAcct.bigNote;
-        const proposedContributionSeed = proposedContributionAcct.proposedContributionSeed;
-
-        const bigNoteAcct = await this.fetchBigNoteAccount(bigNote);
-        const forum = bigNoteAcct.forum;
-        const bigNoteSeed = bigNoteAcct.bigNoteSeed;
+        const userProfileAcct = await this.fetchUserProfileAccount(userProfile);
+        const profileOwner = userProfileAcct.profileOwner;
 
         // Derive PDAs
-        const [userProfile, userProfileBump] = await findUserProfilePDA(forum, profileOwnerKey);
+        const [forumTreasury, forumTreasuryBump] = await findForumTreasuryPDA(forum);
+        const [supplementorProfile, supplementorProfileBump] = await findUserProfilePDA(forum, supplementorKey);
+        const [userProfileKey, userProfileBump] = await findUserProfilePDA(forum, userProfileAcct.profileSeed);
         const [bigNoteKey, bigNoteBump] = await findBigNotePDA(forum, userProfile, bigNoteSeed);
-        const [proposedContributionKey, proposedContributionBump] = await findProposedContributionPDA(forum, userProfile, proposedContributionSeed);
+        const [bountyPda, bountyPdaBump] = await findBigNoteBountyPDA(bigNote);
 
         // Create Signers Array
         const signers = [];
-        if (isKp(profileOwner)) signers.push(<Keypair>profileOwner);
+        if (isKp(supplementor)) signers.push(<Keypair>supplementor);
 
-        console.log('editing proposed contribution with pubkey: ', proposedContribution.toBase58());
+        console.log('supplementing bounty for big note with pubkey: ', bigNote.toBase58(),
+                    'with:', supplementalBountyAmount.toString(), 'Sol');
 
         // Transaction
         const txSig = await this.forumProgram.methods
-            .editProposedContribution(
+            .supplementBigNoteBounty(
+                forumTreasuryBump,
+                supplementorProfileBump,
                 userProfileBump,
-                proposedContributionBump,
+                bigNoteBump,
+                bountyPdaBump,
+                supplementalBountyAmount,
             )
             .accounts({
                 forum: forum,
-                profileOwner: isKp(profileOwner)? (<Keypair>profileOwner).publicKey : <PublicKey>profileOwner,
+                forumTreasury: forumTreasury,
+                supplementor: isKp(supplementor)? (<Keypair>supplementor).publicKey : <PublicKey>supplementor,
+                supplementorProfile: supplementorProfile,
+                profileOwner: profileOwner,
                 userProfile: userProfile,
                 bigNote: bigNote,
-                proposedContribution: proposedContribution,
-                proposedContributionSeed: proposedContributionSeed,
-                newContentDataHash: newContentDataHash,
+                category: bigNoteAcct.category,
+                bigNoteSeed: bigNoteSeed,
+                bountyPda: bountyPda,
                 systemProgram: SystemProgram.programId,
             })
             .signers(signers)
             .rpc();
 
         return {
-            userProfile,
+            supplementorProfile,
+            supplementorProfileBump,
+            userProfileKey,
             userProfileBump,
             bigNoteKey,
             bigNoteBump,
-            proposedContributionKey,
-            proposedContributionBump,
+            bountyPda,
+            bountyPdaBump,
             txSig
         }
     }
 
-    async editProposedContributionModerator(
-        proposedContribution: PublicKey,
+    async refundBigNoteBountySupplementorModerator(
+        bigNote: PublicKey,
         moderator: PublicKey | Keypair,
-        newContentDataHash: PublicKey,
+        supplementor: PublicKey,
     ) {
         const moderatorKey = isKp(moderator) ? (<Keypair>moderator).publicKey : <PublicKey>moderator;
 
-        const proposedContributionAcct = await this.fetchProposedContributionAccount(proposedContribution);
-        const bigNote = proposedContributionAcct.bigNote;
-        const userProfile = proposedContributionAcct.userProfile;
-        const proposedContributionSeed = proposedContributionAcct.proposedContributionSeed;
-
-        const userProfileAcct = await this.fetchUserProfileAccount(userProfile);
-        const profileOwner = userProfileAcct.profileOwner;
-
         const bigNoteAcct = await this.fetchBigNoteAccount(bigNote);
         const forum = bigNoteAcct.forum;
         const bigNoteSeed = bigNoteAcct.bigNoteSeed;
+        const userProfile = bigNoteAcct.userProfile;
+
+        const userProfileAcct = await this.fetchUserProfileAccount(userProfile);
+        const profileOwner = userProfileAcct.profileOwner;
 
         // Derive PDAs
         const [moderatorProfile, moderatorProfileBump] = await findUserProfilePDA(forum, moderatorKey);
-        const [userProfileKey, userProfileBump] = await findUserProfilePDA(forum, profileOwner);
+        const [userProfileKey, userProfileBump] = await findUserProfilePDA(forum, userProfileAcct.profileSeed);
+        const [supplementorProfile, supplementorProfileBump] = await findUserProfilePDA(forum, supplementor);
         const [bigNoteKey, bigNoteBump] = await findBigNotePDA(forum, userProfile, bigNoteSeed);
-        const [proposedContributionKey, proposedContributionBump] = await findProposedContributionPDA(forum, userProfile, proposedContributionSeed);
+        const [bountyPda, bountyPdaBump] = await findBigNoteBountyPDA(bigNote);
 
         // Create Signers Array
         const signers = [];
         if (isKp(moderator)) signers.push(<Keypair>moderator);
 
-        console.log('moderator editing proposed contribution with pubkey: ', proposedContribution.toBase58());
+        console.log('refunding user profile account with pubkey: ', userProfile.toBase58());
 
         // Transaction
         const txSig = await this.forumProgram.methods
-            .editProposedContributionModerator(
+            .refundBigNoteBountySupplementorModerator(
                 moderatorProfileBump,
                 userProfileBump,
-                proposedContributionBump,
+                supplementorProfileBump,
+                bigNoteBump,
+                bountyPdaBump,
             )
             .accounts({
                 forum: forum,
-                moderator: isKp(moderator) ? (<Keypair>moderator).publicKey : <PublicKey>moderator,
+                moderator: isKp(moderator)? (<Keypair>moderator).publicKey : <PublicKey>moderator,
                 moderatorProfile: moderatorProfile,
                 profileOwner: profileOwner,
                 userProfile: userProfile,
+                supplementor: supplementor,
+                supplementorProfile: supplementorProfile,
                 bigNote: bigNote,
-                proposedContribution: proposedContribution,
-                proposedContributionSeed: proposedContributionSeed,
-                newContentDataHash: newContentDataHash,
+                bigNoteSeed: bigNoteSeed,
+                bountyPda: bountyPda,
                 systemProgram: SystemProgram.programId,
             })
             .signers(signers)
@@ -3483,15 +2977,17 @@ export class ForumClient {
             moderatorProfileBump,
             userProfileKey,
             userProfileBump,
+            supplementorProfile,
+            supplementorProfileBump,
             bigNoteKey,
-            bigNoteBump,
-            proposedContributionKey,
-            proposedContributionBump,
+            bigNoteSeed,
+            bountyPda,
+            bountyPdaBump,
             txSig
         }
     }
 
-    async deleteProposedContribution(
+    async acceptProposedContribution(
         proposedContribution: PublicKey,
         profileOwner: PublicKey | Keypair,
         receiver: PublicKey,
@@ -3501,26 +2997,35 @@ export class ForumClient {
         const proposedContributionAcct = await this.fetchProposedContributionAccount(proposedContribution);
         const bigNote = proposedContributionAcct.bigNote;
         const proposedContributionSeed = proposedContributionAcct.proposedContributionSeed;
+        const proposalUserProfile = proposedContributionAcct.userProfile;
 
         const bigNoteAcct = await this.fetchBigNoteAccount(bigNote);
         const forum = bigNoteAcct.forum;
         const bigNoteSeed = bigNoteAcct.bigNoteSeed;
 
+        const proposalUserProfileAcct = await this.fetchUserProfileAccount(proposalUserProfile);
+        const proposalProfileOwner = proposalUserProfileAcct.profileOwner;
+
         // Derive PDAs
         const [userProfile, userProfileBump] = await findUserProfilePDA(forum, profileOwnerKey);
+        const [proposalUserProfileKey, proposalUserProfileBump] = await findUserProfilePDA(forum, proposalUserProfileAcct.profileSeed);
         const [bigNoteKey, bigNoteBump] = await findBigNotePDA(forum, userProfile, bigNoteSeed);
-        const [proposedContributionKey, proposedContributionBump] = await findProposedContributionPDA(forum, userProfile, proposedContributionSeed);
+        const [bountyPda, bountyPdaBump] = await findBigNoteBountyPDA(bigNote);
+        const [proposedContributionKey, proposedContributionBump] = await findProposedContributionPDA(forum, proposalUserProfile, proposedContributionSeed);
 
         // Create Signers Array
         const signers = [];
         if (isKp(profileOwner)) signers.push(<Keypair>profileOwner);
 
-        console.log('deleting proposed contribution with pubkey: ', proposedContribution.toBase58());
+        console.log('accepting proposed contribution with pubkey: ', proposedContribution.toBase58());
 
         // Transaction
         const txSig = await this.forumProgram.methods
-            .deleteProposedContribution(
+            .acceptProposedContribution(
                 userProfileBump,
+                bigNoteBump,
+                bountyPdaBump,
+                proposalUserProfileBump,
                 proposedContributionBump,
             )
             .accounts({
@@ -3528,6 +3033,10 @@ export class ForumClient {
                 profileOwner: isKp(profileOwner)? (<Keypair>profileOwner).publicKey : <PublicKey>profileOwner,
                 userProfile: userProfile,
                 bigNote: bigNote,
+                bigNoteSeed: bigNoteSeed,
+                bountyPda: bountyPda,
+                proposalProfileOwner: proposalProfileOwner,
+                proposalUserProfile: proposalUserProfile,
                 proposedContribution: proposedContribution,
                 proposedContributionSeed: proposedContributionSeed,
                 receiver: receiver,
@@ -3539,119 +3048,123 @@ export class ForumClient {
         return {
             userProfile,
             userProfileBump,
+            proposalUserProfileKey,
+            proposalUserProfileBump,
             bigNoteKey,
             bigNoteBump,
+            bountyPda,
+            bountyPdaBump,
             proposedContributionKey,
-            proposedContributionBump,
+            proposedContributionSeed,
             txSig
         }
     }
 
-    async deleteProposedContributionModerator(
+    async rejectProposedContribution(
         proposedContribution: PublicKey,
-        moderator: PublicKey | Keypair,
-        receiver: PublicKey,
+        profileOwner: PublicKey | Keypair,
     ) {
-        const moderatorKey = isKp(moderator) ? (<Keypair>moderator).publicKey : <PublicKey>moderator;
+        const profileOwnerKey = isKp(profileOwner) ? (<Keypair>profileOwner).publicKey : <PublicKey>profileOwner;
 
         const proposedContributionAcct = await this.fetchProposedContributionAccount(proposedContribution);
         const bigNote = proposedContributionAcct.bigNote;
-        const userProfile = proposedContributionAcct.userProfile;
         const proposedContributionSeed = proposedContributionAcct.proposedContributionSeed;
-
-        const userProfileAcct = await this.fetchUserProfileAccount(userProfile);
-        const profileOwner = userProfileAcct.profileOwner;
+        const proposalUserProfile = proposedContributionAcct.userProfile;
 
         const bigNoteAcct = await this.fetchBigNoteAccount(bigNote);
         const forum = bigNoteAcct.forum;
         const bigNoteSeed = bigNoteAcct.bigNoteSeed;
 
+        const proposalUserProfileAcct = await this.fetchUserProfileAccount(proposalUserProfile);
+        const proposalProfileOwner = proposalUserProfileAcct.profileOwner;
+
         // Derive PDAs
-        const [moderatorProfile, moderatorProfileBump] = await findUserProfilePDA(forum, moderatorKey);
-        const [userProfileKey, userProfileBump] = await findUserProfilePDA(forum, profileOwner);
+        const [userProfile, userProfileBump] = await findUserProfilePDA(forum, profileOwnerKey);
+        const [proposalUserProfileKey, proposalUserProfileBump] = await findUserProfilePDA(forum, proposalUserProfileAcct.profileSeed);
         const [bigNoteKey, bigNoteBump] = await findBigNotePDA(forum, userProfile, bigNoteSeed);
-        const [proposedContributionKey, proposedContributionBump] = await findProposedContributionPDA(forum, userProfile, proposedContributionSeed);
+        const [proposedContributionKey, proposedContributionBump] = await findProposedContributionPDA(forum, proposalUserProfile, proposedContributionSeed);
 
         // Create Signers Array
         const signers = [];
-        if (isKp(moderator)) signers.push(<Keypair>moderator);
+        if (isKp(profileOwner)) signers.push(<Keypair>profileOwner);
 
-        console.log('moderator deleting proposed contribution with pubkey: ', proposedContribution.toBase58());
+        console.log('rejecting proposed contribution with pubkey: ', proposedContribution.toBase58());
 
         // Transaction
         const txSig = await this.forumProgram.methods
-            .deleteProposedContributionModerator(
-                moderatorProfileBump,
+            .rejectProposedContribution(
                 userProfileBump,
+                bigNoteBump,
+                proposalUserProfileBump,
                 proposedContributionBump,
             )
             .accounts({
                 forum: forum,
-                moderator: isKp(moderator) ? (<Keypair>moderator).publicKey : <PublicKey>moderator,
-                moderatorProfile: moderatorProfile,
-                profileOwner: profileOwner,
+                profileOwner: isKp(profileOwner)? (<Keypair>profileOwner).publicKey : <PublicKey>profileOwner,
                 userProfile: userProfile,
                 bigNote: bigNote,
+                bigNoteSeed: bigNoteSeed,
+                proposalProfileOwner: proposalProfileOwner,
+                proposalUserProfile: proposalUserProfile,
                 proposedContribution: proposedContribution,
                 proposedContributionSeed: proposedContributionSeed,
-                receiver: receiver,
                 systemProgram: SystemProgram.programId,
             })
             .signers(signers)
             .rpc();
 
         return {
-            moderatorProfile,
-            moderatorProfileBump,
-            userProfileKey,
+            userProfile,
             userProfileBump,
+            proposalUserProfileKey,
+            proposalUserProfileBump,
             bigNoteKey,
             bigNoteBump,
             proposedContributionKey,
-            proposedContributionBump,
+            proposedContributionSeed,
             txSig
         }
     }
 
-
-//////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
-
-    async leaveCommentOnBigNote(
+    async applyForBigNoteVerification(
         bigNote: PublicKey,
         profileOwner: PublicKey | Keypair,
-        contentDataHash: PublicKey,
     ) {
-        const commentSeedKeypair = Keypair.generate();
-        const commentSeed: PublicKey = commentSeedKeypair.publicKey;
-
         const profileOwnerKey = isKp(profileOwner) ? (<Keypair>profileOwner).publicKey : <PublicKey>profileOwner;
 
         const bigNoteAcct = await this.fetchBigNoteAccount(bigNote);
         const forum = bigNoteAcct.forum;
+        const bigNoteSeed = bigNoteAcct.bigNoteSeed;
 
         // Derive PDAs
         const [userProfile, userProfileBump] = await findUserProfilePDA(forum, profileOwnerKey);
-        const [comment, commentBump] = await findCommentPDA(forum, userProfile, commentSeed);
+        const [bigNoteKey, bigNoteBump] = await findBigNotePDA(forum, userProfile, bigNoteSeed);
+        const [verificationApplication, verificationApplicationBump] = await findBigNoteVerificationApplicationPDA(bigNote);
+        const [verificationFeePda, verificationFeePdaBump] = await findVerificationFeePDA(bigNote);
+        const [verificationRecord, verificationRecordBump] = await findVerificationRecordPDA(bigNote);
 
         // Create Signers Array
         const signers = [];
         if (isKp(profileOwner)) signers.push(<Keypair>profileOwner);
 
-        console.log('creating comment with pubkey: ', comment.toBase58());
+        console.log('applying for verification for big note with pubkey: ', bigNote.toBase58());
 
         // Transaction
         const txSig = await this.forumProgram.methods
-            .leaveCommentOnBigNote(
+            .applyForBigNoteVerification(
                 userProfileBump,
+                bigNoteBump,
             )
             .accounts({
                 forum: forum,
                 profileOwner: isKp(profileOwner)? (<Keypair>profileOwner).publicKey : <PublicKey>profileOwner,
                 userProfile: userProfile,
                 bigNote: bigNote,
-                comment: comment,
-                commentSeed: commentSeed,
-                contentDataHash: contentDataHash,
+                category: bigNoteAcct.category,
+                bigNoteSeed: bigNoteSeed,
+                verificationApplication: verificationApplication,
+                verificationFeePda: verificationFeePda,
+                verificationRecord: verificationRecord,
                 systemProgram: SystemProgram.programId,
             })
             .signers(signers)
@@ -3660,51 +3173,56 @@ export class ForumClient {
         return {
             userProfile,
             userProfileBump,
-            comment,
-            commentBump,
-            commentSeed,
+            bigNoteKey,
+            bigNoteBump,
+            verificationApplication,
+            verificationApplicationBump,
+            verificationFeePda,
+            verificationFeePdaBump,
+            verificationRecord,
+            verificationRecordBump,
             txSig
         }
     }
 
-    async editCommentOnBigNote(
-        comment: PublicKey,
+    async deleteBigNoteVerificationApplication(
+        bigNote: PublicKey,
         profileOwner: PublicKey | Keypair,
-        newContentDataHash: PublicKey,
     ) {
         const profileOwnerKey = isKp(profileOwner) ? (<Keypair>profileOwner).publicKey : <PublicKey>profileOwner;
 
-        const commentAcct = await this.fetchCommentAccount(comment);
-        const commentSeed = commentAcct.commentSeed;
-        const bigNote = commentAcct.commentedOn;
-
         const bigNoteAcct = await this.fetchBigNoteAccount(bigNote);
         const forum = bigNoteAcct.forum;
+        const bigNoteSeed = bigNoteAcct.bigNoteSeed;
 
         // Derive PDAs
         const [userProfile, userProfileBump] = await findUserProfilePDA(forum, profileOwnerKey);
-        const [commentKey, commentBump] = await findCommentPDA(forum, userProfile, commentSeed);
+        const [bigNoteKey, bigNoteBump] = await findBigNotePDA(forum, userProfile, bigNoteSeed);
+        const [verificationApplication, verificationApplicationBump] = await findBigNoteVerificationApplicationPDA(bigNote);
+        const [verificationFeePda, verificationFeePdaBump] = await findVerificationFeePDA(bigNote);
 
         // Create Signers Array
         const signers = [];
         if (isKp(profileOwner)) signers.push(<Keypair>profileOwner);
 
-        console.log('editing comment with pubkey: ', comment.toBase58());
+        console.log('deleting verification application for big note with pubkey: ', bigNote.toBase58());
 
         // Transaction
         const txSig = await this.forumProgram.methods
-            .editCommentOnBigNote(
+            .deleteBigNoteVerificationApplication(
                 userProfileBump,
-                commentBump,
+                bigNoteBump,
+                verificationApplicationBump,
+                verificationFeePdaBump,
             )
             .accounts({
                 forum: forum,
                 profileOwner: isKp(profileOwner)? (<Keypair>profileOwner).publicKey : <PublicKey>profileOwner,
                 userProfile: userProfile,
                 bigNote: bigNote,
-                comment: comment,
-                commentSeed: commentSeed,
-                newContentDataHash: newContentDataHash,
+                bigNoteSeed: bigNoteSeed,
+                verificationApplication: verificationApplication,
+                verificationFeePda: verificationFeePda,
                 systemProgram: SystemProgram.programId,
             })
             .signers(signers)
@@ -3713,58 +3231,62 @@ export class ForumClient {
         return {
             userProfile,
             userProfileBump,
-            commentKey,
-            commentBump,
+            bigNoteKey,
+            bigNoteBump,
+            verificationApplication,
+            verificationApplicationBump,
+            verificationFeePda,
+            verificationFeePdaBump,
             txSig
         }
     }
 
-    async editCommentOnBigNoteModerator(
-        comment: PublicKey,
+    async deleteBigNoteVerificationApplicationModerator(
+        bigNote: PublicKey,
         moderator: PublicKey | Keypair,
-        newContentDataHash: PublicKey,
     ) {
         const moderatorKey = isKp(moderator) ? (<Keypair>moderator).publicKey : <PublicKey>moderator;
 
-        const commentAcct = await this.fetchCommentAccount(comment);
-        const commentSeed = commentAcct.commentSeed;
-        const bigNote = commentAcct.commentedOn;
-        const userProfile = commentAcct.userProfile;
-
         const bigNoteAcct = await this.fetchBigNoteAccount(bigNote);
         const forum = bigNoteAcct.forum;
+        const userProfile = bigNoteAcct.userProfile;
+        const bigNoteSeed = bigNoteAcct.bigNoteSeed;
 
         const userProfileAcct = await this.fetchUserProfileAccount(userProfile);
         const profileOwner = userProfileAcct.profileOwner;
 
         // Derive PDAs
         const [moderatorProfile, moderatorProfileBump] = await findUserProfilePDA(forum, moderatorKey);
-        const [userProfileKey, userProfileBump] = await findUserProfilePDA(forum, profileOwner);
-        const [commentKey, commentBump] = await findCommentPDA(forum, userProfile, commentSeed);
+        const [userProfileKey, userProfileBump] = await findUserProfilePDA(forum, userProfileAcct.profileSeed);
+        const [bigNoteKey, bigNoteBump] = await findBigNotePDA(forum, userProfile, bigNoteSeed);
+        const [verificationApplication, verificationApplicationBump] = await findBigNoteVerificationApplicationPDA(bigNote);
+        const [verificationFeePda, verificationFeePdaBump] = await findVerificationFeePDA(bigNote);
 
         // Create Signers Array
         const signers = [];
         if (isKp(moderator)) signers.push(<Keypair>moderator);
 
-        console.log('moderator editing comment with pubkey: ', comment.toBase58());
+        console.log('deleting verification application for big note with pubkey: ', bigNote.toBase58());
 
         // Transaction
         const txSig = await this.forumProgram.methods
-            .editCommentOnBigNoteModerator(
+            .deleteBigNoteVerificationApplicationModerator(
                 moderatorProfileBump,
                 userProfileBump,
-                commentBump,
+                bigNoteBump,
+                verificationApplicationBump,
+                verificationFeePdaBump,
             )
             .accounts({
                 forum: forum,
-                moderator: isKp(moderator)? (<Keypair>moderator).publicKey : <PublicKey>moderator,
+                moderator: isKp(moderator) ? (<Keypair>moderator).publicKey : <PublicKey>moderator,
                 moderatorProfile: moderatorProfile,
                 profileOwner: profileOwner,
                 userProfile: userProfile,
                 bigNote: bigNote,
-                comment: comment,
-                commentSeed: commentSeed,
-                newContentDataHash: newContentDataHash,
+                bigNoteSeed: bigNoteSeed,
+                verificationApplication: verificationApplication,
+                verificationFeePda: verificationFeePda,
                 systemProgram: SystemProgram.programId,
             })
             .signers(signers)
@@ -3775,99 +3297,145 @@ export class ForumClient {
             moderatorProfileBump,
             userProfileKey,
             userProfileBump,
-            commentKey,
-            commentBump,
+            bigNoteKey,
+            bigNoteBump,
+            verificationApplication,
+            verificationApplicationBump,
+            verificationFeePda,
+            verificationFeePdaBump,
             txSig
         }
     }
 
-    async deleteCommentOnBigNote(
-        comment: PublicKey,
-        profileOwner: PublicKey | Keypair,
-        receiver: PublicKey,
+//////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+    async attestBigNoteVerification(
+        verificationApplication: PublicKey,
+        attester: PublicKey | Keypair,
     ) {
-        const profileOwnerKey = isKp(profileOwner) ? (<Keypair>profileOwner).publicKey : <PublicKey>profileOwner;
+        const attesterKey = isKp(attester) ? (<Keypair>attester).publicKey : <PublicKey>attester;
 
-        const commentAcct = await this.fetchCommentAccount(comment);
-        const commentSeed = commentAcct.commentSeed;
-        const bigNote = commentAcct.commentedOn;
+        const verificationApplicationAcct = await this.fetchBigNoteVerificationApplicationAccount(verificationApplication);
+        const bigNote = verificationApplicationAcct.bigNote;
+        const verificationFeePda = verificationApplicationAcct.verificationFeePda;
 
         const bigNoteAcct = await this.fetchBigNoteAccount(bigNote);
         const forum = bigNoteAcct.forum;
+        const userProfile = bigNoteAcct.userProfile;
+        const bigNoteSeed = bigNoteAcct.bigNoteSeed;
+
+        const userProfileAcct = await this.fetchUserProfileAccount(userProfile);
+        const profileOwner = userProfileAcct.profileOwner;
 
         // Derive PDAs
-        const [userProfile, userProfileBump] = await findUserProfilePDA(forum, profileOwnerKey);
-        const [commentKey, commentBump] = await findCommentPDA(forum, userProfile, commentSeed);
+        const [forumTreasury, forumTreasuryBump] = await findForumTreasuryPDA(forum);
+        const [attesterProfile, attesterProfileBump] = await findUserProfilePDA(forum, attesterKey);
+        const [userProfileKey, userProfileBump] = await findUserProfilePDA(forum, userProfileAcct.profileSeed);
+        const [bigNoteKey, bigNoteBump] = await findBigNotePDA(forum, userProfile, bigNoteSeed);
+        const [verificationApplicationKey, verificationApplicationBump] = await findBigNoteVerificationApplicationPDA(bigNote);
+        const [verificationFeePdaKey, verificationFeePdaBump] = await findVerificationFeePDA(bigNote);
+        const [verificationRecord, verificationRecordBump] = await findVerificationRecordPDA(bigNote);
+
+        // Attestations are recorded per verification round
+        const verificationRecordAcct = await this.forumProgram.account.bigNoteVerificationRecord.fetch(verificationRecord);
+        const [verificationAttestation, verificationAttestationBump] = await findVerificationAttestationPDA(bigNote, attesterProfile, verificationRecordAcct.verificationRound);
 
         // Create Signers Array
         const signers = [];
-        if (isKp(profileOwner)) signers.push(<Keypair>profileOwner);
+        if (isKp(attester)) signers.push(<Keypair>attester);
 
-        console.log('deleting comment with pubkey: ', comment.toBase58());
+        console.log('attesting to the verification of big note with pubkey: ', bigNote.toBase58());
 
         // Transaction
         const txSig = await this.forumProgram.methods
-            .deleteCommentOnBigNote(
+            .attestBigNoteVerification(
+                forumTreasuryBump,
+                attesterProfileBump,
                 userProfileBump,
-                commentBump
+                bigNoteBump,
+                verificationApplicationBump,
+                verificationFeePdaBump,
+                verificationRecordBump,
             )
             .accounts({
                 forum: forum,
-                profileOwner: isKp(profileOwner)? (<Keypair>profileOwner).publicKey : <PublicKey>profileOwner,
+                forumTreasury: forumTreasury,
+                attester: attesterKey,
+                attesterProfile: attesterProfile,
+                profileOwner: profileOwner,
                 userProfile: userProfile,
                 bigNote: bigNote,
-                comment: comment,
-                commentSeed: commentSeed,
-                receiver: receiver,
+                category: bigNoteAcct.category,
+                bigNoteSeed: bigNoteSeed,
+                verificationApplication: verificationApplication,
+                verificationFeePda: verificationFeePda,
+                verificationRecord: verificationRecord,
+                verificationAttestation: verificationAttestation,
                 systemProgram: SystemProgram.programId,
             })
             .signers(signers)
             .rpc();
 
         return {
-            userProfile,
+            forumTreasury,
+            forumTreasuryBump,
+            attesterProfile,
+            attesterProfileBump,
+            userProfileKey,
             userProfileBump,
-            commentKey,
-            commentBump,
+            bigNoteKey,
+            bigNoteBump,
+            verificationApplicationKey,
+            verificationApplicationBump,
+            verificationFeePdaKey,
+            verificationFeePdaBump,
+            verificationRecord,
+            verificationRecordBump,
+            verificationAttestation,
+            verificationAttestationBump,
             txSig
         }
     }
 
-    async deleteCommentOnBigNoteModerator(
-        comment: PublicKey,
+    async rejectBigNoteVerificationApplication(
+        verificationApplication: PublicKey,
         moderator: PublicKey | Keypair,
-        receiver: PublicKey,
     ) {
         const moderatorKey = isKp(moderator) ? (<Keypair>moderator).publicKey : <PublicKey>moderator;
 
-        const commentAcct = await this.fetchCommentAccount(comment);
-        const commentSeed = commentAcct.commentSeed;
-        const bigNote = commentAcct.commentedOn;
-        const userProfile = commentAcct.userProfile;
+        const verificationApplicationAcct = await this.fetchBigNoteVerificationApplicationAccount(verificationApplication);
+        const bigNote = verificationApplicationAcct.bigNote;
+        const verificationFeePda = verificationApplicationAcct.verificationFeePda;
 
         const bigNoteAcct = await this.fetchBigNoteAccount(bigNote);
         const forum = bigNoteAcct.forum;
+        const userProfile = bigNoteAcct.userProfile;
+        const bigNoteSeed = bigNoteAcct.bigNoteSeed;
 
         const userProfileAcct = await this.fetchUserProfileAccount(userProfile);
         const profileOwner = userProfileAcct.profileOwner;
 
         // Derive PDAs
         const [moderatorProfile, moderatorProfileBump] = await findUserProfilePDA(forum, moderatorKey);
-        const [userProfileKey, userProfileBump] = await findUserProfilePDA(forum, profileOwner);
-        const [commentKey, commentBump] = await findCommentPDA(forum, userProfile, commentSeed);
+        const [userProfileKey, userProfileBump] = await findUserProfilePDA(forum, userProfileAcct.profileSeed);
+        const [bigNoteKey, bigNoteBump] = await findBigNotePDA(forum, userProfile, bigNoteSeed);
+        const [verificationApplicationKey, verificationApplicationBump] = await findBigNoteVerificationApplicationPDA(bigNote);
+        const [verificationFeePdaKey, verificationFeePdaBump] = await findVerificationFeePDA(bigNote);
 
         // Create Signers Array
         const signers = [];
         if (isKp(moderator)) signers.push(<Keypair>moderator);
 
-        console.log('moderator deleting comment with pubkey: ', comment.toBase58());
+        console.log('rejecting big note verification application for big note with pubkey: ', bigNote.toBase58());
 
         // Transaction
         const txSig = await this.forumProgram.methods
-            .deleteCommentOnBigNoteModerator(
+            .rejectBigNoteVerificationApplication(
                 moderatorProfileBump,
                 userProfileBump,
-                commentBump
+                bigNoteBump,
+                verificationApplicationBump,
+                verificationFeePdaBump,
             )
             .accounts({
                 forum: forum,
@@ -3876,9 +3444,9 @@ export class ForumClient {
                 profileOwner: profileOwner,
                 userProfile: userProfile,
                 bigNote: bigNote,
-                comment: comment,
-                commentSeed: commentSeed,
-                receiver: receiver,
+                bigNoteSeed: bigNoteSeed,
+                verificationApplication: verificationApplication,
+                verificationFeePda: verificationFeePda,
                 systemProgram: SystemProgram.programId,
             })
             .signers(signers)
@@ -3889,53 +3457,59 @@ export class ForumClient {
             moderatorProfileBump,
             userProfileKey,
             userProfileBump,
-            commentKey,
-            commentBump,
+            bigNoteKey,
+            bigNoteBump,
+            verificationApplicationKey,
+            verificationApplicationBump,
+            verificationFeePdaKey,
+            verificationFeePdaBump,
             txSig
         }
     }
 
 //////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
-    async leaveCommentOnProposedContribution(
-        proposedContribution: PublicKey,
-        profileOwner: PublicKey | Keypair,
+    async proposeContribution(
+        bigNote: PublicKey,
+        authority: PublicKey | Keypair,
         contentDataHash: PublicKey,
+        profileSeed?: PublicKey,
     ) {
-        const commentSeedKeypair = Keypair.generate();
-        const commentSeed: PublicKey = commentSeedKeypair.publicKey;
-
-        const profileOwnerKey = isKp(profileOwner) ? (<Keypair>profileOwner).publicKey : <PublicKey>profileOwner;
+        const proposedContributionSeedKeypair = Keypair.generate();
+        const proposedContributionSeed: PublicKey = proposedContributionSeedKeypair.publicKey;
 
-        const proposedContributionAcct = await this.fetchProposedContributionAccount(proposedContribution);
-        const bigNote = proposedContributionAcct.bigNote;
+        const authorityKey = isKp(authority) ? (<Keypair>authority).publicKey : <PublicKey>authority;
 
         const bigNoteAcct = await this.fetchBigNoteAccount(bigNote);
         const forum = bigNoteAcct.forum;
+        const bigNoteSeed = bigNoteAcct.bigNoteSeed;
 
-        // Derive PDAs
-        const [userProfile, userProfileBump] = await findUserProfilePDA(forum, profileOwnerKey);
-        const [comment, commentBump] = await findCommentPDA(forum, userProfile, commentSeed);
+        // Derive PDAs (a session key delegate proposes on behalf of the user profile with the given seed)
+        const [userProfile, userProfileBump] = await findUserProfilePDA(forum, profileSeed ?? authorityKey);
+        const [sessionKey, sessionKeyBump] = await findSessionKeyPDA(userProfile, authorityKey);
+        const [bigNoteKey, bigNoteBump] = await findBigNotePDA(forum, bigNoteAcct.userProfile, bigNoteSeed);
+        const [proposedContribution, proposedContributionBump] = await findProposedContributionPDA(forum, userProfile, proposedContributionSeed);
 
         // Create Signers Array
         const signers = [];
-        if (isKp(profileOwner)) signers.push(<Keypair>profileOwner);
+        if (isKp(authority)) signers.push(<Keypair>authority);
 
-        console.log('creating comment with pubkey: ', comment.toBase58());
+        console.log('creating proposed contribution with pubkey: ', proposedContribution.toBase58());
 
         // Transaction
         const txSig = await this.forumProgram.methods
-            .leaveCommentOnProposedContribution(
+            .proposeContribution(
                 userProfileBump,
+                sessionKeyBump,
             )
             .accounts({
                 forum: forum,
-                profileOwner: isKp(profileOwner)? (<Keypair>profileOwner).publicKey : <PublicKey>profileOwner,
+                authority: authorityKey,
                 userProfile: userProfile,
+                sessionKey: sessionKey,
                 bigNote: bigNote,
                 proposedContribution: proposedContribution,
-                comment: comment,
-                commentSeed: commentSeed,
+                proposedContributionSeed: proposedContributionSeed,
                 contentDataHash: contentDataHash,
                 systemProgram: SystemProgram.programId,
             })
@@ -3945,45 +3519,47 @@ export class ForumClient {
         return {
             userProfile,
             userProfileBump,
-            comment,
-            commentBump,
-            commentSeed,
+            sessionKey,
+            sessionKeyBump,
+            bigNoteKey,
+            bigNoteBump,
+            proposedContribution,
+            proposedContributionBump,
             txSig
         }
     }
 
-    async editCommentOnProposedContribution(
-        comment: PublicKey,
+    async editProposedContribution(
+        proposedContribution: PublicKey,
         profileOwner: PublicKey | Keypair,
         newContentDataHash: PublicKey,
     ) {
         const profileOwnerKey = isKp(profileOwner) ? (<Keypair>profileOwner).publicKey : <PublicKey>profileOwner;
 
-        const commentAcct = await this.fetchCommentAccount(comment);
-        const commentSeed = commentAcct.commentSeed;
-        const proposedContribution = commentAcct.commentedOn;
-
         const proposedContributionAcct = await this.fetchProposedContributionAccount(proposedContribution);
         const bigNote = proposedContributionAcct.bigNote;
+        const proposedContributionSeed = proposedContributionAcct.proposedContributionSeed;
 
         const bigNoteAcct = await this.fetchBigNoteAccount(bigNote);
         const forum = bigNoteAcct.forum;
+        const bigNoteSeed = bigNoteAcct.bigNoteSeed;
 
         // Derive PDAs
         const [userProfile, userProfileBump] = await findUserProfilePDA(forum, profileOwnerKey);
-        const [commentKey, commentBump] = await findCommentPDA(forum, userProfile, commentSeed);
+        const [bigNoteKey, bigNoteBump] = await findBigNotePDA(forum, userProfile, bigNoteSeed);
+        const [proposedContributionKey, proposedContributionBump] = await findProposedContributionPDA(forum, userProfile, proposedContributionSeed);
 
         // Create Signers Array
         const signers = [];
         if (isKp(profileOwner)) signers.push(<Keypair>profileOwner);
 
-        console.log('editing comment with pubkey: ', comment.toBase58());
+        console.log('editing proposed contribution with pubkey: ', proposedContribution.toBase58());
 
         // Transaction
         const txSig = await this.forumProgram.methods
-            .editCommentOnProposedContribution(
+            .editProposedContribution(
                 userProfileBump,
-                commentBump,
+                proposedContributionBump,
             )
             .accounts({
                 forum: forum,
@@ -3991,8 +3567,7 @@ export class ForumClient {
                 userProfile: userProfile,
                 bigNote: bigNote,
                 proposedContribution: proposedContribution,
-                comment: comment,
-                commentSeed: commentSeed,
+                proposedContributionSeed: proposedContributionSeed,
                 newContentDataHash: newContentDataHash,
                 systemProgram: SystemProgram.programId,
             })
@@ -4002,61 +3577,61 @@ export class ForumClient {
         return {
             userProfile,
             userProfileBump,
-            commentKey,
-            commentBump,
+            bigNoteKey,
+            bigNoteBump,
+            proposedContributionKey,
+            proposedContributionBump,
             txSig
         }
     }
 
-    async editCommentOnProposedContributionModerator(
-        comment: PublicKey,
+    async editProposedContributionModerator(
+        proposedContribution: PublicKey,
         moderator: PublicKey | Keypair,
         newContentDataHash: PublicKey,
     ) {
         const moderatorKey = isKp(moderator) ? (<Keypair>moderator).publicKey : <PublicKey>moderator;
 
-        const commentAcct = await this.fetchCommentAccount(comment);
-        const commentSeed = commentAcct.commentSeed;
-        const proposedContribution = commentAcct.commentedOn;
-        const userProfile = commentAcct.userProfile;
-
         const proposedContributionAcct = await this.fetchProposedContributionAccount(proposedContribution);
         const bigNote = proposedContributionAcct.bigNote;
-
-        const bigNoteAcct = await this.fetchBigNoteAccount(bigNote);
-        const forum = bigNoteAcct.forum;
+        const userProfile = proposedContributionAcct.userProfile;
+        const proposedContributionSeed = proposedContributionAcct.proposedContributionSeed;
 
         const userProfileAcct = await this.fetchUserProfileAccount(userProfile);
         const profileOwner = userProfileAcct.profileOwner;
 
+        const bigNoteAcct = await this.fetchBigNoteAccount(bigNote);
+        const forum = bigNoteAcct.forum;
+        const bigNoteSeed = bigNoteAcct.bigNoteSeed;
+
         // Derive PDAs
         const [moderatorProfile, moderatorProfileBump] = await findUserProfilePDA(forum, moderatorKey);
-        const [userProfileKey, userProfileBump] = await findUserProfilePDA(forum, profileOwner);
-        const [commentKey, commentBump] = await findCommentPDA(forum, userProfile, commentSeed);
+        const [userProfileKey, userProfileBump] = await findUserProfilePDA(forum, userProfileAcct.profileSeed);
+        const [bigNoteKey, bigNoteBump] = await findBigNotePDA(forum, userProfile, bigNoteSeed);
+        const [proposedContributionKey, proposedContributionBump] = await findProposedContributionPDA(forum, userProfile, proposedContributionSeed);
 
         // Create Signers Array
         const signers = [];
         if (isKp(moderator)) signers.push(<Keypair>moderator);
 
-        console.log('moderator editing comment with pubkey: ', comment.toBase58());
+        console.log('moderator editing proposed contribution with pubkey: ', proposedContribution.toBase58());
 
         // Transaction
         const txSig = await this.forumProgram.methods
-            .editCommentOnProposedContributionModerator(
+            .editProposedContributionModerator(
                 moderatorProfileBump,
                 userProfileBump,
-                commentBump,
+                proposedContributionBump,
             )
             .accounts({
                 forum: forum,
-                moderator: isKp(moderator)? (<Keypair>moderator).publicKey : <PublicKey>moderator,
+                moderator: isKp(moderator) ? (<Keypair>moderator).publicKey : <PublicKey>moderator,
                 moderatorProfile: moderatorProfile,
                 profileOwner: profileOwner,
                 userProfile: userProfile,
                 bigNote: bigNote,
                 proposedContribution: proposedContribution,
-                comment: comment,
-                commentSeed: commentSeed,
+                proposedContributionSeed: proposedContributionSeed,
                 newContentDataHash: newContentDataHash,
                 systemProgram: SystemProgram.programId,
             })
@@ -4068,44 +3643,45 @@ export class ForumClient {
             moderatorProfileBump,
             userProfileKey,
             userProfileBump,
-            commentKey,
-            commentBump,
+            bigNoteKey,
+            bigNoteBump,
+            proposedContributionKey,
+            proposedContributionBump,
             txSig
         }
     }
 
-    async deleteCommentOnProposedContribution(
-        comment: PublicKey,
+    async deleteProposedContribution(
+        proposedContribution: PublicKey,
         profileOwner: PublicKey | Keypair,
         receiver: PublicKey,
     ) {
         const profileOwnerKey = isKp(profileOwner) ? (<Keypair>profileOwner).publicKey : <PublicKey>profileOwner;
 
-        const commentAcct = await this.fetchCommentAccount(comment);
-        const commentSeed = commentAcct.commentSeed;
-        const proposedContribution = commentAcct.commentedOn;
-
         const proposedContributionAcct = await this.fetchProposedContributionAccount(proposedContribution);
         const bigNote = proposedContributionAcct.bigNote;
+        const proposedContributionSeed = proposedContributionAcct.proposedContributionSeed;
 
         const bigNoteAcct = await this.fetchBigNoteAccount(bigNote);
         const forum = bigNoteAcct.forum;
+        const bigNoteSeed = bigNoteAcct.bigNoteSeed;
 
         // Derive PDAs
         const [userProfile, userProfileBump] = await findUserProfilePDA(forum, profileOwnerKey);
-        const [commentKey, commentBump] = await findCommentPDA(forum, userProfile, commentSeed);
+        const [bigNoteKey, bigNoteBump] = await findBigNotePDA(forum, userProfile, bigNoteSeed);
+        const [proposedContributionKey, proposedContributionBump] = await findProposedContributionPDA(forum, userProfile, proposedContributionSeed);
 
         // Create Signers Array
         const signers = [];
         if (isKp(profileOwner)) signers.push(<Keypair>profileOwner);
 
-        console.log('deleting comment with pubkey: ', comment.toBase58());
+        console.log('deleting proposed contribution with pubkey: ', proposedContribution.toBase58());
 
         // Transaction
         const txSig = await this.forumProgram.methods
-            .deleteCommentOnProposedContribution(
+            .deleteProposedContribution(
                 userProfileBump,
-                commentBump
+                proposedContributionBump,
             )
             .accounts({
                 forum: forum,
@@ -4113,8 +3689,7 @@ export class ForumClient {
                 userProfile: userProfile,
                 bigNote: bigNote,
                 proposedContribution: proposedContribution,
-                comment: comment,
-                commentSeed: commentSeed,
+                proposedContributionSeed: proposedContributionSeed,
                 receiver: receiver,
                 systemProgram: SystemProgram.programId,
             })
@@ -4124,61 +3699,61 @@ export class ForumClient {
         return {
             userProfile,
             userProfileBump,
-            commentKey,
-            commentBump,
+            bigNoteKey,
+            bigNoteBump,
+            proposedContributionKey,
+            proposedContributionBump,
             txSig
         }
     }
 
-    async deleteCommentOnProposedContributionModerator(
-        comment: PublicKey,
+    async deleteProposedContributionModerator(
+        proposedContribution: PublicKey,
         moderator: PublicKey | Keypair,
         receiver: PublicKey,
     ) {
         const moderatorKey = isKp(moderator) ? (<Keypair>moderator).publicKey : <PublicKey>moderator;
 
-        const commentAcct = await this.fetchCommentAccount(comment);
-        const commentSeed = commentAcct.commentSeed;
-        const proposedContribution = commentAcct.commentedOn;
-        const userProfile = commentAcct.userProfile;
-
         const proposedContributionAcct = await this.fetchProposedContributionAccount(proposedContribution);
         const bigNote = proposedContributionAcct.bigNote;
-
-        const bigNoteAcct = await this.fetchBigNoteAccount(bigNote);
-        const forum = bigNoteAcct.forum;
+        const userProfile = proposedContributionAcct.userProfile;
+        const proposedContributionSeed = proposedContributionAcct.proposedContributionSeed;
 
         const userProfileAcct = await this.fetchUserProfileAccount(userProfile);
         const profileOwner = userProfileAcct.profileOwner;
 
+        const bigNoteAcct = await this.fetchBigNoteAccount(bigNote);
+        const forum = bigNoteAcct.forum;
+        const bigNoteSeed = bigNoteAcct.bigNoteSeed;
+
         // Derive PDAs
         const [moderatorProfile, moderatorProfileBump] = await findUserProfilePDA(forum, moderatorKey);
-        const [userProfileKey, userProfileBump] = await findUserProfilePDA(forum, profileOwner);
-        const [commentKey, commentBump] = await findCommentPDA(forum, userProfile, commentSeed);
+        const [userProfileKey, userProfileBump] = await findUserProfilePDA(forum, userProfileAcct.profileSeed);
+        const [bigNoteKey, bigNoteBump] = await findBigNotePDA(forum, userProfile, bigNoteSeed);
+        const [proposedContributionKey, proposedContributionBump] = await findProposedContributionPDA(forum, userProfile, proposedContributionSeed);
 
         // Create Signers Array
         const signers = [];
         if (isKp(moderator)) signers.push(<Keypair>moderator);
 
-        console.log('moderator deleting comment with pubkey: ', comment.toBase58());
+        console.log('moderator deleting proposed contribution with pubkey: ', proposedContribution.toBase58());
 
         // Transaction
         const txSig = await this.forumProgram.methods
-            .deleteCommentOnProposedContributionModerator(
+            .deleteProposedContributionModerator(
                 moderatorProfileBump,
                 userProfileBump,
-                commentBump
+                proposedContributionBump,
             )
             .accounts({
                 forum: forum,
-                moderator: isKp(moderator)? (<Keypair>moderator).publicKey : <PublicKey>moderator,
+                moderator: isKp(moderator) ? (<Keypair>moderator).publicKey : <PublicKey>moderator,
                 moderatorProfile: moderatorProfile,
                 profileOwner: profileOwner,
                 userProfile: userProfile,
                 bigNote: bigNote,
                 proposedContribution: proposedContribution,
-                comment: comment,
-                commentSeed: commentSeed,
+                proposedContributionSeed: proposedContributionSeed,
                 receiver: receiver,
                 systemProgram: SystemProgram.programId,
             })
@@ -4190,8 +3765,10 @@ export class ForumClient {
             moderatorProfileBump,
             userProfileKey,
             userProfileBump,
-            commentKey,
-            commentBump,
+            bigNoteKey,
+            bigNoteBump,
+            proposedContributionKey,
+            proposedContributionBump,
             txSig
         }
     }
@@ -4201,12 +3778,14 @@ export class ForumClient {
     async createChallenge(
         forum: PublicKey,
         moderator: PublicKey | Keypair,
+        category: PublicKey,
         contentDataHash: PublicKey,
         tags: Tags[],
         title: string,
         contentDataUrl: string,
         challengeExpiresTs: BN,
         reputation: BN,
+        rubric: RubricCriterion[],
     ) {
         const challengeSeedKeypair = Keypair.generate();
         const challengeSeed: PublicKey = challengeSeedKeypair.publicKey;
@@ -4232,11 +3811,13 @@ export class ForumClient {
                 contentDataUrl,
                 challengeExpiresTs,
                 reputation,
+                rubric,
             )
             .accounts({
                 forum: forum,
                 moderator: isKp(moderator)? (<Keypair>moderator).publicKey : <PublicKey>moderator,
                 moderatorProfile: moderatorProfile,
+                category: category,
                 challenge: challenge,
                 challengeSeed: challengeSeed,
                 contentDataHash: contentDataHash,
@@ -4296,6 +3877,7 @@ export class ForumClient {
                 moderator: isKp(moderator)? (<Keypair>moderator).publicKey : <PublicKey>moderator,
                 moderatorProfile: moderatorProfile,
                 challenge: challenge,
+                category: challengeAcct.category,
                 challengeSeed: challengeSeed,
                 newContentDataHash: newContentDataHash,
                 systemProgram: SystemProgram.programId,
@@ -4326,6 +3908,9 @@ export class ForumClient {
         // Derive PDAs
         const [moderatorProfile, moderatorProfileBump] = await findUserProfilePDA(forum, moderatorKey);
         const [challengeKey, challengeBump] = await findChallengePDA(forum, challengeSeed);
+        const [challengeProposal, challengeProposalBump] = await findChallengeProposalPDA(challenge);
+        const [prizePool, prizePoolBump] = await findChallengePrizePoolPDA(challenge);
+        const [trackMembership, trackMembershipBump] = await findTrackMembershipPDA(challenge);
 
         // Create Signers Array
         const signers = [];
@@ -4338,6 +3923,9 @@ export class ForumClient {
             .deleteChallenge(
                 moderatorProfileBump,
                 challengeBump,
+                prizePoolBump,
+                challengeProposalBump,
+                trackMembershipBump,
             )
             .accounts({
                 forum: forum,
@@ -4345,6 +3933,10 @@ export class ForumClient {
                 moderatorProfile: moderatorProfile,
                 challenge: challenge,
                 challengeSeed: challengeSeed,
+                challengeProposal: challengeProposal,
+                category: challengeAcct.category,
+                prizePool: prizePool,
+                trackMembership: trackMembership,
                 receiver: receiver,
                 systemProgram: SystemProgram.programId,
             })
@@ -4356,6 +3948,12 @@ export class ForumClient {
             moderatorProfileBump,
             challengeKey,
             challengeBump,
+            challengeProposal,
+            challengeProposalBump,
+            prizePool,
+            prizePoolBump,
+            trackMembership,
+            trackMembershipBump,
             txSig
         }
     }
@@ -4374,47 +3972,66 @@ export class ForumClient {
         const challengeSeed = challengeAcct.challengeSeed;
 
         // Derive PDAs
-        const [forumTreasury, forumTreasuryBump] = await findForumTreasuryPDA(forum);
         const [userProfile, userProfileBump] = await findUserProfilePDA(forum, profileOwnerKey);
         const [challengeKey, challengeBump] = await findChallengePDA(forum, challengeSeed);
+        const [challengeProposal, challengeProposalBump] = await findChallengeProposalPDA(challenge);
+        const [trackMembership, trackMembershipBump] = await findTrackMembershipPDA(challenge);
         const [submission, submissionBump] = await findSubmissionPDA(challenge, userProfile);
+        const [submissionFeeEscrow, submissionFeeEscrowBump] = await findSubmissionFeeEscrowPDA(submission);
+
+        // Challenges later in a track pass in the completed submission to the previous challenge as a remaining account
+        const remainingAccounts: PublicKey[] = [];
+        const trackMembershipAcct = await this.forumProgram.account.trackMembership.fetchNullable(trackMembership);
+        if (trackMembershipAcct && !trackMembershipAcct.prerequisiteChallenge.equals(PublicKey.default)) {
+            const [prerequisiteSubmission] = await findSubmissionPDA(trackMembershipAcct.prerequisiteChallenge, userProfile);
+            remainingAccounts.push(prerequisiteSubmission);
+        }
 
         // Create Signers Array
         const signers = [];
         if (isKp(profileOwner)) signers.push(<Keypair>profileOwner);
 
-        console.log('creating submission with pubkey: ', challenge.toBase58());
+        console.log('creating submission with pubkey: ', submission.toBase58());
 
         // Transaction
         const txSig = await this.forumProgram.methods
             .createSubmission(
-                forumTreasuryBump,
                 userProfileBump,
                 challengeBump,
+                challengeProposalBump,
+                trackMembershipBump,
             )
             .accounts({
                 forum: forum,
-                forumTreasury: forumTreasury,
                 profileOwner: isKp(profileOwner)? (<Keypair>profileOwner).publicKey : <PublicKey>profileOwner,
                 userProfile: userProfile,
                 challenge: challenge,
+                category: challengeAcct.category,
                 challengeSeed: challengeSeed,
+                challengeProposal: challengeProposal,
+                trackMembership: trackMembership,
                 submission: submission,
+                submissionFeeEscrow: submissionFeeEscrow,
                 contentDataHash: contentDataHash,
                 systemProgram: SystemProgram.programId,
             })
+            .remainingAccounts(remainingAccounts.map((pubkey) => ({ pubkey, isWritable: false, isSigner: false })))
             .signers(signers)
             .rpc();
 
         return {
-            forumTreasury,
-            forumTreasuryBump,
             userProfile,
             userProfileBump,
             challengeKey,
             challengeBump,
+            challengeProposal,
+            challengeProposalBump,
+            trackMembership,
+            trackMembershipBump,
             submission,
             submissionBump,
+            submissionFeeEscrow,
+            submissionFeeEscrowBump,
             txSig
         }
     }
@@ -4495,7 +4112,7 @@ export class ForumClient {
 
         // Derive PDAs
         const [moderatorProfile, moderatorProfileBump] = await findUserProfilePDA(forum, moderatorKey);
-        const [userProfileKey, userProfileBump] = await findUserProfilePDA(forum, profileOwner);
+        const [userProfileKey, userProfileBump] = await findUserProfilePDA(forum, userProfileAcct.profileSeed);
         const [challengeKey, challengeBump] = await findChallengePDA(forum, challengeSeed);
         const [submissionKey, submissionBump] = await findSubmissionPDA(challenge, userProfile);
 
@@ -4520,6 +4137,7 @@ export class ForumClient {
                 profileOwner: profileOwner,
                 userProfile: userProfile,
                 challenge: challenge,
+                category: challengeAcct.category,
                 challengeSeed: challengeSeed,
                 submission: submission,
                 newContentDataHash: newContentDataHash,
@@ -4559,6 +4177,7 @@ export class ForumClient {
         const [userProfile, userProfileBump] = await findUserProfilePDA(forum, profileOwnerKey);
         const [challengeKey, challengeBump] = await findChallengePDA(forum, challengeSeed);
         const [submissionKey, submissionBump] = await findSubmissionPDA(challenge, userProfile);
+        const [submissionFeeEscrow, submissionFeeEscrowBump] = await findSubmissionFeeEscrowPDA(submission);
 
         // Create Signers Array
         const signers = [];
@@ -4571,7 +4190,8 @@ export class ForumClient {
             .deleteSubmission(
                 userProfileBump,
                 challengeBump,
-                submissionBump
+                submissionBump,
+                submissionFeeEscrowBump,
             )
             .accounts({
                 forum: forum,
@@ -4580,6 +4200,7 @@ export class ForumClient {
                 challenge: challenge,
                 challengeSeed: challengeSeed,
                 submission: submission,
+                submissionFeeEscrow: submissionFeeEscrow,
                 receiver: receiver,
                 systemProgram: SystemProgram.programId,
             })
@@ -4593,6 +4214,8 @@ export class ForumClient {
             challengeBump,
             submissionKey,
             submissionBump,
+            submissionFeeEscrow,
+            submissionFeeEscrowBump,
             txSig
         }
     }
@@ -4617,9 +4240,10 @@ export class ForumClient {
 
         // Derive PDAs
         const [moderatorProfile, moderatorProfileBump] = await findUserProfilePDA(forum, moderatorKey);
-        const [userProfileKey, userProfileBump] = await findUserProfilePDA(forum, profileOwner);
+        const [userProfileKey, userProfileBump] = await findUserProfilePDA(forum, userProfileAcct.profileSeed);
         const [challengeKey, challengeBump] = await findChallengePDA(forum, challengeSeed);
         const [submissionKey, submissionBump] = await findSubmissionPDA(challenge, userProfile);
+        const [submissionFeeEscrow, submissionFeeEscrowBump] = await findSubmissionFeeEscrowPDA(submission);
 
         // Create Signers Array
         const signers = [];
@@ -4634,6 +4258,7 @@ export class ForumClient {
                 userProfileBump,
                 challengeBump,
                 submissionBump,
+                submissionFeeEscrowBump,
             )
             .accounts({
                 forum: forum,
@@ -4642,8 +4267,10 @@ export class ForumClient {
                 profileOwner: profileOwner,
                 userProfile: userProfile,
                 challenge: challenge,
+                category: challengeAcct.category,
                 challengeSeed: challengeSeed,
                 submission: submission,
+                submissionFeeEscrow: submissionFeeEscrow,
                 receiver: receiver,
                 systemProgram: SystemProgram.programId,
             })
@@ -4659,16 +4286,18 @@ export class ForumClient {
             challengeBump,
             submissionKey,
             submissionBump,
+            submissionFeeEscrow,
+            submissionFeeEscrowBump,
             txSig
         }
     }
 
     async evaluateSubmission(
         submission: PublicKey,
-        moderator: PublicKey | Keypair,
-        submissionState: SubmissionState,
+        judge: PublicKey | Keypair,
+        verdict: SubmissionState,
     ) {
-        const moderatorKey = isKp(moderator) ? (<Keypair>moderator).publicKey : <PublicKey>moderator;
+        const judgeKey = isKp(judge) ? (<Keypair>judge).publicKey : <PublicKey>judge;
 
         const submissionAcct = await this.fetchSubmissionAccount(submission);
         const userProfile = submissionAcct.userProfile;
@@ -4682,49 +4311,87 @@ export class ForumClient {
         const profileOwner = userProfileAcct.profileOwner;
 
         // Derive PDAs
-        const [moderatorProfile, moderatorProfileBump] = await findUserProfilePDA(forum, moderatorKey);
-        const [userProfileKey, userProfileBump] = await findUserProfilePDA(forum, profileOwner);
+        const [forumTreasury, forumTreasuryBump] = await findForumTreasuryPDA(forum);
+        const [judgeProfile, judgeProfileBump] = await findUserProfilePDA(forum, judgeKey);
+        const [userProfileKey, userProfileBump] = await findUserProfilePDA(forum, userProfileAcct.profileSeed);
         const [challengeKey, challengeBump] = await findChallengePDA(forum, challengeSeed);
+        const [evaluationPanel, evaluationPanelBump] = await findEvaluationPanelPDA(challenge);
         const [submissionKey, submissionBump] = await findSubmissionPDA(challenge, userProfile);
+        const [evaluationVote, evaluationVoteBump] = await findEvaluationVotePDA(submission, judgeProfile, submissionAcct.revisionCount);
+        const [submissionFeeEscrow, submissionFeeEscrowBump] = await findSubmissionFeeEscrowPDA(submission);
+        const [prizePool, prizePoolBump] = await findChallengePrizePoolPDA(challenge);
+
+        // SPL prize pools pay out of the prize vault, which is passed in with the token accounts as remaining accounts
+        const remainingAccounts = [];
+        const prizePoolAcct = await this.forumProgram.account.challengePrizePool.fetchNullable(prizePool);
+        if (prizePoolAcct && !prizePoolAcct.prizeMint.equals(PublicKey.default)) {
+            const [prizeVault] = await findPrizePoolVaultPDA(prizePool);
+            const profileOwnerTokenAccount = await Token.getAssociatedTokenAddress(ASSOCIATED_TOKEN_PROGRAM_ID, TOKEN_PROGRAM_ID, prizePoolAcct.prizeMint, profileOwner);
+            remainingAccounts.push(
+                { pubkey: prizeVault, isWritable: true, isSigner: false },
+                { pubkey: profileOwnerTokenAccount, isWritable: true, isSigner: false },
+                { pubkey: TOKEN_PROGRAM_ID, isWritable: false, isSigner: false },
+            );
+        }
 
         // Create Signers Array
         const signers = [];
-        if (isKp(moderator)) signers.push(<Keypair>moderator);
+        if (isKp(judge)) signers.push(<Keypair>judge);
 
-        console.log('moderator evaluating submission with pubkey: ', submission.toBase58());
+        console.log('judge evaluating submission with pubkey: ', submission.toBase58());
 
         // Transaction
         const txSig = await this.forumProgram.methods
             .evaluateSubmission(
-                moderatorProfileBump,
+                judgeProfileBump,
                 userProfileBump,
                 challengeBump,
+                evaluationPanelBump,
                 submissionBump,
-                submissionState,
+                prizePoolBump,
+                submissionFeeEscrowBump,
+                verdict,
             )
             .accounts({
                 forum: forum,
-                moderator: isKp(moderator)? (<Keypair>moderator).publicKey : <PublicKey>moderator,
-                moderatorProfile: moderatorProfile,
+                forumTreasury: forumTreasury,
+                judge: judgeKey,
+                judgeProfile: judgeProfile,
                 profileOwner: profileOwner,
                 userProfile: userProfile,
                 challenge: challenge,
+                category: challengeAcct.category,
                 challengeSeed: challengeSeed,
+                evaluationPanel: evaluationPanel,
                 submission: submission,
+                evaluationVote: evaluationVote,
+                submissionFeeEscrow: submissionFeeEscrow,
+                prizePool: prizePool,
                 systemProgram: SystemProgram.programId,
             })
+            .remainingAccounts(remainingAccounts)
             .signers(signers)
             .rpc();
 
         return {
-            moderatorProfile,
-            moderatorProfileBump,
+            forumTreasury,
+            forumTreasuryBump,
+            judgeProfile,
+            judgeProfileBump,
             userProfileKey,
             userProfileBump,
             challengeKey,
             challengeBump,
+            evaluationPanel,
+            evaluationPanelBump,
             submissionKey,
             submissionBump,
+            evaluationVote,
+            evaluationVoteBump,
+            submissionFeeEscrow,
+            submissionFeeEscrowBump,
+            prizePool,
+            prizePoolBump,
             txSig
         }
     }
@@ -1,6 +1,9 @@
+import { BN } from '@coral-xyz/anchor';
 import { PublicKey } from '@solana/web3.js';
 import { FORUM_PROG_ID } from './index';
 
+export const TOKEN_METADATA_PROGRAM_ID = new PublicKey('metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s');
+
 export const findForumAuthorityPDA = async (forum: PublicKey) => {
     return PublicKey.findProgramAddressSync(
         [forum.toBytes()],
@@ -105,3 +108,115 @@ export const findVerificationFeePDA = async (bigNote: PublicKey) => {
         FORUM_PROG_ID
     );
 };
+
+export const findWalletRotationPDA = async (forum: PublicKey, newProfileOwner: PublicKey) => {
+    return PublicKey.findProgramAddressSync(
+        [Buffer.from('wallet_rotation'), forum.toBytes(), newProfileOwner.toBytes()],
+        FORUM_PROG_ID
+    );
+};
+
+export const findForumAccessPolicyPDA = async (forum: PublicKey) => {
+    return PublicKey.findProgramAddressSync(
+        [Buffer.from('forum_access_policy'), forum.toBytes()],
+        FORUM_PROG_ID
+    );
+};
+
+export const findPfpCollectionAllowlistPDA = async (forum: PublicKey) => {
+    return PublicKey.findProgramAddressSync(
+        [Buffer.from('pfp_collection_allowlist'), forum.toBytes()],
+        FORUM_PROG_ID
+    );
+};
+
+export const findCategoryPDA = async (forum: PublicKey, categorySeed: PublicKey) => {
+    return PublicKey.findProgramAddressSync(
+        [Buffer.from('category'), forum.toBytes(), categorySeed.toBytes()],
+        FORUM_PROG_ID
+    );
+};
+
+export const findNftMetadataPDA = async (mint: PublicKey) => {
+    return PublicKey.findProgramAddressSync(
+        [Buffer.from('metadata'), TOKEN_METADATA_PROGRAM_ID.toBytes(), mint.toBytes()],
+        TOKEN_METADATA_PROGRAM_ID
+    );
+};
+
+export const findSessionKeyPDA = async (userProfile: PublicKey, delegate: PublicKey) => {
+    return PublicKey.findProgramAddressSync(
+        [Buffer.from('session_key'), userProfile.toBytes(), delegate.toBytes()],
+        FORUM_PROG_ID
+    );
+};
+
+export const findRevisionPDA = async (content: PublicKey, revisionCount: BN) => {
+    return PublicKey.findProgramAddressSync(
+        [Buffer.from('revision'), content.toBytes(), revisionCount.toArrayLike(Buffer, 'le', 8)],
+        FORUM_PROG_ID
+    );
+};
+
+export const findVerificationRecordPDA = async (bigNote: PublicKey) => {
+    return PublicKey.findProgramAddressSync(
+        [Buffer.from('verification_record'), bigNote.toBytes()],
+        FORUM_PROG_ID
+    );
+};
+
+export const findVerificationAttestationPDA = async (bigNote: PublicKey, attesterProfile: PublicKey, verificationRound: BN) => {
+    return PublicKey.findProgramAddressSync(
+        [Buffer.from('verification_attestation'), bigNote.toBytes(), attesterProfile.toBytes(), verificationRound.toArrayLike(Buffer, 'le', 8)],
+        FORUM_PROG_ID
+    );
+};
+
+export const findChallengeProposalPDA = async (challenge: PublicKey) => {
+    return PublicKey.findProgramAddressSync(
+        [Buffer.from('challenge_proposal'), challenge.toBytes()],
+        FORUM_PROG_ID
+    );
+};
+
+export const findTrackMembershipPDA = async (challenge: PublicKey) => {
+    return PublicKey.findProgramAddressSync(
+        [Buffer.from('track_membership'), challenge.toBytes()],
+        FORUM_PROG_ID
+    );
+};
+
+export const findChallengePrizePoolPDA = async (challenge: PublicKey) => {
+    return PublicKey.findProgramAddressSync(
+        [Buffer.from('challenge_prize_pool'), challenge.toBytes()],
+        FORUM_PROG_ID
+    );
+};
+
+export const findEvaluationPanelPDA = async (challenge: PublicKey) => {
+    return PublicKey.findProgramAddressSync(
+        [Buffer.from('evaluation_panel'), challenge.toBytes()],
+        FORUM_PROG_ID
+    );
+};
+
+export const findEvaluationVotePDA = async (submission: PublicKey, judgeProfile: PublicKey, revisionCount: BN) => {
+    return PublicKey.findProgramAddressSync(
+        [Buffer.from('evaluation_vote'), submission.toBytes(), judgeProfile.toBytes(), revisionCount.toArrayLike(Buffer, 'le', 8)],
+        FORUM_PROG_ID
+    );
+};
+
+export const findSubmissionFeeEscrowPDA = async (submission: PublicKey) => {
+    return PublicKey.findProgramAddressSync(
+        [Buffer.from('submission_fee_escrow'), submission.toBytes()],
+        FORUM_PROG_ID
+    );
+};
+
+export const findPrizePoolVaultPDA = async (prizePool: PublicKey) => {
+    return PublicKey.findProgramAddressSync(
+        [Buffer.from('prize_pool_vault'), prizePool.toBytes()],
+        FORUM_PROG_ID
+    );
+};
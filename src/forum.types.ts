@@ -102,7 +102,7 @@ export type Forum = {
       ]
     },
     {
-      "name": "payoutFromTreasury",
+      "name": "setPfpCollectionAllowlist",
       "accounts": [
         {
           "name": "forum",
@@ -111,16 +111,11 @@ export type Forum = {
         },
         {
           "name": "forumManager",
-          "isMut": false,
-          "isSigner": true
-        },
-        {
-          "name": "forumTreasury",
           "isMut": true,
-          "isSigner": false
+          "isSigner": true
         },
         {
-          "name": "receiver",
+          "name": "pfpCollectionAllowlist",
           "isMut": true,
           "isSigner": false
         },
@@ -132,32 +127,34 @@ export type Forum = {
       ],
       "args": [
         {
-          "name": "bumpForumTreasury",
-          "type": "u8"
+          "name": "collections",
+          "type": {
+            "vec": "publicKey"
+          }
         }
       ]
     },
     {
-      "name": "closeForum",
+      "name": "createCategory",
       "accounts": [
         {
           "name": "forum",
-          "isMut": true,
+          "isMut": false,
           "isSigner": false
         },
         {
           "name": "forumManager",
-          "isMut": false,
+          "isMut": true,
           "isSigner": true
         },
         {
-          "name": "forumTreasury",
+          "name": "category",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "receiver",
-          "isMut": true,
+          "name": "categorySeed",
+          "isMut": false,
           "isSigner": false
         },
         {
@@ -168,49 +165,79 @@ export type Forum = {
       ],
       "args": [
         {
-          "name": "bumpForumTreasury",
-          "type": "u8"
+          "name": "feeOverridesEnabled",
+          "type": "bool"
+        },
+        {
+          "name": "categoryFees",
+          "type": {
+            "defined": "ForumFees"
+          }
         }
       ]
     },
     {
-      "name": "createUserProfile",
+      "name": "updateCategory",
       "accounts": [
         {
           "name": "forum",
-          "isMut": true,
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "forumTreasury",
-          "isMut": true,
-          "isSigner": false
+          "name": "forumManager",
+          "isMut": false,
+          "isSigner": true
         },
         {
-          "name": "profileOwner",
+          "name": "category",
           "isMut": true,
-          "isSigner": true
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "feeOverridesEnabled",
+          "type": "bool"
         },
         {
-          "name": "userProfile",
-          "isMut": true,
+          "name": "newCategoryFees",
+          "type": {
+            "defined": "ForumFees"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setCategoryModerators",
+      "accounts": [
+        {
+          "name": "forum",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "systemProgram",
+          "name": "forumManager",
           "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "category",
+          "isMut": true,
           "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "bumpTreasury",
-          "type": "u8"
+          "name": "moderatorProfiles",
+          "type": {
+            "vec": "publicKey"
+          }
         }
       ]
     },
     {
-      "name": "editUserProfile",
+      "name": "setForumAccessPolicy",
       "accounts": [
         {
           "name": "forum",
@@ -218,20 +245,15 @@ export type Forum = {
           "isSigner": false
         },
         {
-          "name": "profileOwner",
-          "isMut": false,
+          "name": "forumManager",
+          "isMut": true,
           "isSigner": true
         },
         {
-          "name": "userProfile",
+          "name": "forumAccessPolicy",
           "isMut": true,
           "isSigner": false
         },
-        {
-          "name": "nftPfpTokenMint",
-          "isMut": false,
-          "isSigner": false
-        },
         {
           "name": "systemProgram",
           "isMut": false,
@@ -240,31 +262,45 @@ export type Forum = {
       ],
       "args": [
         {
-          "name": "bumpUserProfile",
-          "type": "u8"
+          "name": "accessPolicyType",
+          "type": {
+            "defined": "AccessPolicyType"
+          }
+        },
+        {
+          "name": "accessMint",
+          "type": "publicKey"
+        },
+        {
+          "name": "minTokenAmount",
+          "type": "u64"
+        },
+        {
+          "name": "recheckPeriod",
+          "type": "u64"
         }
       ]
     },
     {
-      "name": "deleteUserProfile",
+      "name": "addForumAllowlistEntry",
       "accounts": [
         {
           "name": "forum",
-          "isMut": true,
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "profileOwner",
-          "isMut": false,
+          "name": "forumManager",
+          "isMut": true,
           "isSigner": true
         },
         {
-          "name": "userProfile",
-          "isMut": true,
+          "name": "wallet",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "receiver",
+          "name": "forumAllowlistEntry",
           "isMut": true,
           "isSigner": false
         },
@@ -274,15 +310,10 @@ export type Forum = {
           "isSigner": false
         }
       ],
-      "args": [
-        {
-          "name": "bumpUserProfile",
-          "type": "u8"
-        }
-      ]
+      "args": []
     },
     {
-      "name": "createAboutMe",
+      "name": "removeForumAllowlistEntry",
       "accounts": [
         {
           "name": "forum",
@@ -290,25 +321,15 @@ export type Forum = {
           "isSigner": false
         },
         {
-          "name": "profileOwner",
+          "name": "forumManager",
           "isMut": true,
           "isSigner": true
         },
         {
-          "name": "userProfile",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "aboutMe",
+          "name": "forumAllowlistEntry",
           "isMut": true,
           "isSigner": false
         },
-        {
-          "name": "contentDataHash",
-          "isMut": false,
-          "isSigner": false
-        },
         {
           "name": "systemProgram",
           "isMut": false,
@@ -317,13 +338,13 @@ export type Forum = {
       ],
       "args": [
         {
-          "name": "bumpUserProfile",
+          "name": "bumpForumAllowlistEntry",
           "type": "u8"
         }
       ]
     },
     {
-      "name": "editAboutMe",
+      "name": "payoutFromTreasury",
       "accounts": [
         {
           "name": "forum",
@@ -331,25 +352,20 @@ export type Forum = {
           "isSigner": false
         },
         {
-          "name": "profileOwner",
+          "name": "forumManager",
           "isMut": false,
           "isSigner": true
         },
         {
-          "name": "userProfile",
+          "name": "forumTreasury",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "aboutMe",
+          "name": "receiver",
           "isMut": true,
           "isSigner": false
         },
-        {
-          "name": "newContentDataHash",
-          "isMut": false,
-          "isSigner": false
-        },
         {
           "name": "systemProgram",
           "isMut": false,
@@ -358,35 +374,26 @@ export type Forum = {
       ],
       "args": [
         {
-          "name": "bumpUserProfile",
-          "type": "u8"
-        },
-        {
-          "name": "bumpAboutMe",
+          "name": "bumpForumTreasury",
           "type": "u8"
         }
       ]
     },
     {
-      "name": "deleteAboutMe",
+      "name": "closeForum",
       "accounts": [
         {
           "name": "forum",
-          "isMut": false,
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "profileOwner",
+          "name": "forumManager",
           "isMut": false,
           "isSigner": true
         },
         {
-          "name": "userProfile",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "aboutMe",
+          "name": "forumTreasury",
           "isMut": true,
           "isSigner": false
         },
@@ -403,26 +410,27 @@ export type Forum = {
       ],
       "args": [
         {
-          "name": "bumpUserProfile",
-          "type": "u8"
-        },
-        {
-          "name": "bumpAboutMe",
+          "name": "bumpForumTreasury",
           "type": "u8"
         }
       ]
     },
     {
-      "name": "deleteUserProfileAndAboutMe",
+      "name": "createUserProfile",
       "accounts": [
         {
           "name": "forum",
           "isMut": true,
           "isSigner": false
         },
+        {
+          "name": "forumTreasury",
+          "isMut": true,
+          "isSigner": false
+        },
         {
           "name": "profileOwner",
-          "isMut": false,
+          "isMut": true,
           "isSigner": true
         },
         {
@@ -431,13 +439,13 @@ export type Forum = {
           "isSigner": false
         },
         {
-          "name": "aboutMe",
-          "isMut": true,
+          "name": "walletRotation",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "receiver",
-          "isMut": true,
+          "name": "forumAccessPolicy",
+          "isMut": false,
           "isSigner": false
         },
         {
@@ -448,17 +456,21 @@ export type Forum = {
       ],
       "args": [
         {
-          "name": "bumpUserProfile",
+          "name": "bumpTreasury",
           "type": "u8"
         },
         {
-          "name": "bumpAboutMe",
+          "name": "bumpWalletRotation",
+          "type": "u8"
+        },
+        {
+          "name": "bumpForumAccessPolicy",
           "type": "u8"
         }
       ]
     },
     {
-      "name": "addModerator",
+      "name": "approveWalletRotation",
       "accounts": [
         {
           "name": "forum",
@@ -466,18 +478,18 @@ export type Forum = {
           "isSigner": false
         },
         {
-          "name": "forumManager",
+          "name": "profileOwner",
           "isMut": false,
           "isSigner": true
         },
         {
-          "name": "profileOwner",
-          "isMut": false,
+          "name": "userProfile",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "userProfile",
-          "isMut": true,
+          "name": "newProfileOwner",
+          "isMut": false,
           "isSigner": false
         },
         {
@@ -494,7 +506,7 @@ export type Forum = {
       ]
     },
     {
-      "name": "removeModerator",
+      "name": "acceptWalletRotation",
       "accounts": [
         {
           "name": "forum",
@@ -502,17 +514,32 @@ export type Forum = {
           "isSigner": false
         },
         {
-          "name": "forumManager",
-          "isMut": false,
+          "name": "newProfileOwner",
+          "isMut": true,
           "isSigner": true
         },
         {
-          "name": "profileOwner",
+          "name": "userProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "newWalletUserProfile",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "userProfile",
+          "name": "walletRotation",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "previousWalletRotation",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "previousProfileOwner",
           "isMut": true,
           "isSigner": false
         },
@@ -526,20 +553,23 @@ export type Forum = {
         {
           "name": "bumpUserProfile",
           "type": "u8"
+        },
+        {
+          "name": "bumpNewWalletUserProfile",
+          "type": "u8"
+        },
+        {
+          "name": "bumpPreviousWalletRotation",
+          "type": "u8"
         }
       ]
     },
     {
-      "name": "askQuestion",
+      "name": "registerSessionKey",
       "accounts": [
         {
           "name": "forum",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "forumTreasury",
-          "isMut": true,
+          "isMut": false,
           "isSigner": false
         },
         {
@@ -553,22 +583,12 @@ export type Forum = {
           "isSigner": false
         },
         {
-          "name": "question",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "questionSeed",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "contentDataHash",
+          "name": "delegate",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "bountyPda",
+          "name": "sessionKey",
           "isMut": true,
           "isSigner": false
         },
@@ -579,38 +599,24 @@ export type Forum = {
         }
       ],
       "args": [
-        {
-          "name": "bumpTreasury",
-          "type": "u8"
-        },
         {
           "name": "bumpUserProfile",
           "type": "u8"
         },
         {
-          "name": "tags",
+          "name": "sessionScope",
           "type": {
-            "vec": {
-              "defined": "Tags"
-            }
+            "defined": "SessionScope"
           }
         },
         {
-          "name": "title",
-          "type": "string"
-        },
-        {
-          "name": "contentDataUrl",
-          "type": "string"
-        },
-        {
-          "name": "bountyAmount",
+          "name": "sessionDuration",
           "type": "u64"
         }
       ]
     },
     {
-      "name": "editQuestion",
+      "name": "revokeSessionKey",
       "accounts": [
         {
           "name": "forum",
@@ -628,20 +634,10 @@ export type Forum = {
           "isSigner": false
         },
         {
-          "name": "question",
+          "name": "sessionKey",
           "isMut": true,
           "isSigner": false
         },
-        {
-          "name": "questionSeed",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "newContentDataHash",
-          "isMut": false,
-          "isSigner": false
-        },
         {
           "name": "systemProgram",
           "isMut": false,
@@ -654,29 +650,13 @@ export type Forum = {
           "type": "u8"
         },
         {
-          "name": "bumpQuestion",
+          "name": "bumpSessionKey",
           "type": "u8"
-        },
-        {
-          "name": "newTags",
-          "type": {
-            "vec": {
-              "defined": "Tags"
-            }
-          }
-        },
-        {
-          "name": "newTitle",
-          "type": "string"
-        },
-        {
-          "name": "newContentDataUrl",
-          "type": "string"
         }
       ]
     },
     {
-      "name": "editQuestionModerator",
+      "name": "editUserProfile",
       "accounts": [
         {
           "name": "forum",
@@ -684,37 +664,32 @@ export type Forum = {
           "isSigner": false
         },
         {
-          "name": "moderator",
-          "isMut": true,
+          "name": "profileOwner",
+          "isMut": false,
           "isSigner": true
         },
         {
-          "name": "moderatorProfile",
+          "name": "userProfile",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "profileOwner",
+          "name": "nftPfpTokenMint",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "userProfile",
+          "name": "nftPfpTokenAccount",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "question",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "questionSeed",
+          "name": "nftMetadata",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "newContentDataHash",
+          "name": "pfpCollectionAllowlist",
           "isMut": false,
           "isSigner": false
         },
@@ -725,76 +700,77 @@ export type Forum = {
         }
       ],
       "args": [
-        {
-          "name": "bumpModeratorProfile",
-          "type": "u8"
-        },
         {
           "name": "bumpUserProfile",
           "type": "u8"
         },
         {
-          "name": "bumpQuestion",
+          "name": "bumpPfpCollectionAllowlist",
           "type": "u8"
-        },
-        {
-          "name": "newTags",
-          "type": {
-            "vec": {
-              "defined": "Tags"
-            }
-          }
-        },
-        {
-          "name": "newTitle",
-          "type": "string"
-        },
-        {
-          "name": "newContentDataUrl",
-          "type": "string"
         }
       ]
     },
     {
-      "name": "deleteQuestionModerator",
+      "name": "clearNftPfp",
       "accounts": [
         {
           "name": "forum",
-          "isMut": true,
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "moderator",
+          "name": "cranker",
           "isMut": false,
           "isSigner": true
         },
         {
-          "name": "moderatorProfile",
+          "name": "userProfile",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "profileOwner",
+          "name": "nftPfpTokenAccount",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "userProfile",
-          "isMut": true,
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpUserProfile",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "claimHandle",
+      "accounts": [
+        {
+          "name": "forum",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "question",
+          "name": "forumTreasury",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "questionSeed",
-          "isMut": false,
+          "name": "profileOwner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "userProfile",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "receiver",
+          "name": "handle",
           "isMut": true,
           "isSigner": false
         },
@@ -806,7 +782,7 @@ export type Forum = {
       ],
       "args": [
         {
-          "name": "bumpModeratorProfile",
+          "name": "bumpTreasury",
           "type": "u8"
         },
         {
@@ -814,13 +790,13 @@ export type Forum = {
           "type": "u8"
         },
         {
-          "name": "bumpQuestion",
-          "type": "u8"
+          "name": "handleName",
+          "type": "string"
         }
       ]
     },
     {
-      "name": "supplementQuestionBounty",
+      "name": "releaseHandle",
       "accounts": [
         {
           "name": "forum",
@@ -828,42 +804,67 @@ export type Forum = {
           "isSigner": false
         },
         {
-          "name": "forumTreasury",
+          "name": "profileOwner",
           "isMut": true,
-          "isSigner": false
+          "isSigner": true
         },
         {
-          "name": "supplementor",
+          "name": "userProfile",
           "isMut": true,
-          "isSigner": true
+          "isSigner": false
         },
         {
-          "name": "supplementorProfile",
+          "name": "handle",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "profileOwner",
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpUserProfile",
+          "type": "u8"
         },
         {
-          "name": "userProfile",
+          "name": "bumpHandle",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "transferHandle",
+      "accounts": [
+        {
+          "name": "forum",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "question",
+          "name": "profileOwner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "userProfile",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "questionSeed",
+          "name": "newProfileOwner",
           "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "newUserProfile",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "bountyPda",
+          "name": "handle",
           "isMut": true,
           "isSigner": false
         },
@@ -874,34 +875,22 @@ export type Forum = {
         }
       ],
       "args": [
-        {
-          "name": "bumpTreasury",
-          "type": "u8"
-        },
-        {
-          "name": "bumpSupplementorProfile",
-          "type": "u8"
-        },
         {
           "name": "bumpUserProfile",
           "type": "u8"
         },
         {
-          "name": "bumpQuestion",
+          "name": "bumpHandle",
           "type": "u8"
         },
         {
-          "name": "bumpBountyPda",
+          "name": "bumpNewUserProfile",
           "type": "u8"
-        },
-        {
-          "name": "supplementalBountyAmount",
-          "type": "u64"
         }
       ]
     },
     {
-      "name": "refundQuestionBountySupplementorModerator",
+      "name": "reclaimHandleModerator",
       "accounts": [
         {
           "name": "forum",
@@ -919,37 +908,57 @@ export type Forum = {
           "isSigner": false
         },
         {
-          "name": "profileOwner",
-          "isMut": false,
+          "name": "userProfile",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "userProfile",
-          "isMut": false,
+          "name": "handle",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "supplementor",
+          "name": "receiver",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "supplementorProfile",
-          "isMut": true,
+          "name": "systemProgram",
+          "isMut": false,
           "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpModeratorProfile",
+          "type": "u8"
         },
         {
-          "name": "question",
-          "isMut": true,
+          "name": "bumpHandle",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "recheckProfileEligibility",
+      "accounts": [
+        {
+          "name": "forum",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "questionSeed",
+          "name": "cranker",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "forumAccessPolicy",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "bountyPda",
+          "name": "userProfile",
           "isMut": true,
           "isSigner": false
         },
@@ -961,29 +970,17 @@ export type Forum = {
       ],
       "args": [
         {
-          "name": "bumpModeratorProfile",
+          "name": "bumpForumAccessPolicy",
           "type": "u8"
         },
         {
           "name": "bumpUserProfile",
           "type": "u8"
-        },
-        {
-          "name": "bumpSupplementorProfile",
-          "type": "u8"
-        },
-        {
-          "name": "bumpQuestion",
-          "type": "u8"
-        },
-        {
-          "name": "bumpBountyPda",
-          "type": "u8"
         }
       ]
     },
     {
-      "name": "acceptAnswer",
+      "name": "followUser",
       "accounts": [
         {
           "name": "forum",
@@ -992,7 +989,7 @@ export type Forum = {
         },
         {
           "name": "profileOwner",
-          "isMut": false,
+          "isMut": true,
           "isSigner": true
         },
         {
@@ -1001,42 +998,53 @@ export type Forum = {
           "isSigner": false
         },
         {
-          "name": "question",
+          "name": "followedProfile",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "questionSeed",
-          "isMut": false,
+          "name": "follow",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "bountyPda",
-          "isMut": true,
+          "name": "systemProgram",
+          "isMut": false,
           "isSigner": false
-        },
+        }
+      ],
+      "args": [
         {
-          "name": "answerProfileOwner",
-          "isMut": true,
+          "name": "bumpUserProfile",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "unfollowUser",
+      "accounts": [
+        {
+          "name": "forum",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "answerUserProfile",
+          "name": "profileOwner",
           "isMut": true,
-          "isSigner": false
+          "isSigner": true
         },
         {
-          "name": "answer",
+          "name": "userProfile",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "answerSeed",
-          "isMut": false,
+          "name": "followedProfile",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "receiver",
+          "name": "follow",
           "isMut": true,
           "isSigner": false
         },
@@ -1052,29 +1060,17 @@ export type Forum = {
           "type": "u8"
         },
         {
-          "name": "bumpQuestion",
-          "type": "u8"
-        },
-        {
-          "name": "bumpBountyPda",
-          "type": "u8"
-        },
-        {
-          "name": "bumpAnswerUserProfile",
-          "type": "u8"
-        },
-        {
-          "name": "bumpAnswer",
+          "name": "bumpFollow",
           "type": "u8"
         }
       ]
     },
     {
-      "name": "answerQuestion",
+      "name": "subscribe",
       "accounts": [
         {
           "name": "forum",
-          "isMut": true,
+          "isMut": false,
           "isSigner": false
         },
         {
@@ -1088,23 +1084,13 @@ export type Forum = {
           "isSigner": false
         },
         {
-          "name": "question",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "answer",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "answerSeed",
+          "name": "subscribedTo",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "contentDataHash",
-          "isMut": false,
+          "name": "subscription",
+          "isMut": true,
           "isSigner": false
         },
         {
@@ -1117,11 +1103,17 @@ export type Forum = {
         {
           "name": "bumpUserProfile",
           "type": "u8"
+        },
+        {
+          "name": "subscriptionTarget",
+          "type": {
+            "defined": "SubscriptionTarget"
+          }
         }
       ]
     },
     {
-      "name": "editAnswer",
+      "name": "unsubscribe",
       "accounts": [
         {
           "name": "forum",
@@ -1130,7 +1122,7 @@ export type Forum = {
         },
         {
           "name": "profileOwner",
-          "isMut": false,
+          "isMut": true,
           "isSigner": true
         },
         {
@@ -1139,25 +1131,10 @@ export type Forum = {
           "isSigner": false
         },
         {
-          "name": "question",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "answer",
+          "name": "subscription",
           "isMut": true,
           "isSigner": false
         },
-        {
-          "name": "answerSeed",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "newContentDataHash",
-          "isMut": false,
-          "isSigner": false
-        },
         {
           "name": "systemProgram",
           "isMut": false,
@@ -1170,57 +1147,37 @@ export type Forum = {
           "type": "u8"
         },
         {
-          "name": "bumpAnswer",
+          "name": "bumpSubscription",
           "type": "u8"
         }
       ]
     },
     {
-      "name": "editAnswerModerator",
+      "name": "deleteUserProfile",
       "accounts": [
         {
           "name": "forum",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "moderator",
-          "isMut": false,
-          "isSigner": true
-        },
-        {
-          "name": "moderatorProfile",
           "isMut": true,
           "isSigner": false
         },
         {
           "name": "profileOwner",
           "isMut": false,
-          "isSigner": false
+          "isSigner": true
         },
         {
           "name": "userProfile",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "question",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "answer",
+          "name": "walletRotation",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "answerSeed",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "newContentDataHash",
-          "isMut": false,
+          "name": "receiver",
+          "isMut": true,
           "isSigner": false
         },
         {
@@ -1230,31 +1187,27 @@ export type Forum = {
         }
       ],
       "args": [
-        {
-          "name": "bumpModeratorProfile",
-          "type": "u8"
-        },
         {
           "name": "bumpUserProfile",
           "type": "u8"
         },
         {
-          "name": "bumpAnswer",
+          "name": "bumpWalletRotation",
           "type": "u8"
         }
       ]
     },
     {
-      "name": "deleteAnswer",
+      "name": "createAboutMe",
       "accounts": [
         {
           "name": "forum",
-          "isMut": true,
+          "isMut": false,
           "isSigner": false
         },
         {
           "name": "profileOwner",
-          "isMut": false,
+          "isMut": true,
           "isSigner": true
         },
         {
@@ -1263,25 +1216,15 @@ export type Forum = {
           "isSigner": false
         },
         {
-          "name": "question",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "answer",
+          "name": "aboutMe",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "answerSeed",
+          "name": "contentDataHash",
           "isMut": false,
           "isSigner": false
         },
-        {
-          "name": "receiver",
-          "isMut": true,
-          "isSigner": false
-        },
         {
           "name": "systemProgram",
           "isMut": false,
@@ -1292,35 +1235,21 @@ export type Forum = {
         {
           "name": "bumpUserProfile",
           "type": "u8"
-        },
-        {
-          "name": "bumpAnswer",
-          "type": "u8"
         }
       ]
     },
     {
-      "name": "deleteAnswerModerator",
+      "name": "editAboutMe",
       "accounts": [
         {
           "name": "forum",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "moderator",
           "isMut": false,
-          "isSigner": true
-        },
-        {
-          "name": "moderatorProfile",
-          "isMut": true,
           "isSigner": false
         },
         {
           "name": "profileOwner",
           "isMut": false,
-          "isSigner": false
+          "isSigner": true
         },
         {
           "name": "userProfile",
@@ -1328,25 +1257,15 @@ export type Forum = {
           "isSigner": false
         },
         {
-          "name": "question",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "answer",
+          "name": "aboutMe",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "answerSeed",
+          "name": "newContentDataHash",
           "isMut": false,
           "isSigner": false
         },
-        {
-          "name": "receiver",
-          "isMut": true,
-          "isSigner": false
-        },
         {
           "name": "systemProgram",
           "isMut": false,
@@ -1354,31 +1273,27 @@ export type Forum = {
         }
       ],
       "args": [
-        {
-          "name": "bumpModeratorProfile",
-          "type": "u8"
-        },
         {
           "name": "bumpUserProfile",
           "type": "u8"
         },
         {
-          "name": "bumpAnswer",
+          "name": "bumpAboutMe",
           "type": "u8"
         }
       ]
     },
     {
-      "name": "leaveCommentOnQuestion",
+      "name": "deleteAboutMe",
       "accounts": [
         {
           "name": "forum",
-          "isMut": true,
+          "isMut": false,
           "isSigner": false
         },
         {
           "name": "profileOwner",
-          "isMut": true,
+          "isMut": false,
           "isSigner": true
         },
         {
@@ -1387,25 +1302,15 @@ export type Forum = {
           "isSigner": false
         },
         {
-          "name": "question",
+          "name": "aboutMe",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "comment",
+          "name": "receiver",
           "isMut": true,
           "isSigner": false
         },
-        {
-          "name": "commentSeed",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "contentDataHash",
-          "isMut": false,
-          "isSigner": false
-        },
         {
           "name": "systemProgram",
           "isMut": false,
@@ -1416,15 +1321,19 @@ export type Forum = {
         {
           "name": "bumpUserProfile",
           "type": "u8"
+        },
+        {
+          "name": "bumpAboutMe",
+          "type": "u8"
         }
       ]
     },
     {
-      "name": "editCommentOnQuestion",
+      "name": "deleteUserProfileAndAboutMe",
       "accounts": [
         {
           "name": "forum",
-          "isMut": false,
+          "isMut": true,
           "isSigner": false
         },
         {
@@ -1438,23 +1347,18 @@ export type Forum = {
           "isSigner": false
         },
         {
-          "name": "question",
+          "name": "aboutMe",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "comment",
+          "name": "walletRotation",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "commentSeed",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "newContentDataHash",
-          "isMut": false,
+          "name": "receiver",
+          "isMut": true,
           "isSigner": false
         },
         {
@@ -1469,13 +1373,17 @@ export type Forum = {
           "type": "u8"
         },
         {
-          "name": "bumpComment",
+          "name": "bumpAboutMe",
+          "type": "u8"
+        },
+        {
+          "name": "bumpWalletRotation",
           "type": "u8"
         }
       ]
     },
     {
-      "name": "editCommentOnQuestionModerator",
+      "name": "addModerator",
       "accounts": [
         {
           "name": "forum",
@@ -1483,15 +1391,10 @@ export type Forum = {
           "isSigner": false
         },
         {
-          "name": "moderator",
+          "name": "forumManager",
           "isMut": false,
           "isSigner": true
         },
-        {
-          "name": "moderatorProfile",
-          "isMut": true,
-          "isSigner": false
-        },
         {
           "name": "profileOwner",
           "isMut": false,
@@ -1499,29 +1402,45 @@ export type Forum = {
         },
         {
           "name": "userProfile",
-          "isMut": false,
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "question",
-          "isMut": true,
+          "name": "systemProgram",
+          "isMut": false,
           "isSigner": false
-        },
+        }
+      ],
+      "args": [
         {
-          "name": "comment",
-          "isMut": true,
+          "name": "bumpUserProfile",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "removeModerator",
+      "accounts": [
+        {
+          "name": "forum",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "commentSeed",
+          "name": "forumManager",
           "isMut": false,
-          "isSigner": false
+          "isSigner": true
         },
         {
-          "name": "newContentDataHash",
+          "name": "profileOwner",
           "isMut": false,
           "isSigner": false
         },
+        {
+          "name": "userProfile",
+          "isMut": true,
+          "isSigner": false
+        },
         {
           "name": "systemProgram",
           "isMut": false,
@@ -1529,31 +1448,28 @@ export type Forum = {
         }
       ],
       "args": [
-        {
-          "name": "bumpModeratorProfile",
-          "type": "u8"
-        },
         {
           "name": "bumpUserProfile",
           "type": "u8"
-        },
-        {
-          "name": "bumpComment",
-          "type": "u8"
         }
       ]
     },
     {
-      "name": "deleteCommentOnQuestion",
+      "name": "askQuestion",
       "accounts": [
         {
           "name": "forum",
           "isMut": true,
           "isSigner": false
         },
+        {
+          "name": "forumTreasury",
+          "isMut": true,
+          "isSigner": false
+        },
         {
           "name": "profileOwner",
-          "isMut": false,
+          "isMut": true,
           "isSigner": true
         },
         {
@@ -1562,22 +1478,27 @@ export type Forum = {
           "isSigner": false
         },
         {
-          "name": "question",
+          "name": "category",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "comment",
+          "name": "question",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "commentSeed",
+          "name": "questionSeed",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "receiver",
+          "name": "contentDataHash",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bountyPda",
           "isMut": true,
           "isSigner": false
         },
@@ -1589,60 +1510,75 @@ export type Forum = {
       ],
       "args": [
         {
-          "name": "bumpUserProfile",
+          "name": "bumpTreasury",
           "type": "u8"
         },
         {
-          "name": "bumpComment",
+          "name": "bumpUserProfile",
           "type": "u8"
+        },
+        {
+          "name": "tags",
+          "type": {
+            "vec": {
+              "defined": "Tags"
+            }
+          }
+        },
+        {
+          "name": "title",
+          "type": "string"
+        },
+        {
+          "name": "contentDataUrl",
+          "type": "string"
+        },
+        {
+          "name": "bountyAmount",
+          "type": "u64"
         }
       ]
     },
     {
-      "name": "deleteCommentOnQuestionModerator",
+      "name": "editQuestion",
       "accounts": [
         {
           "name": "forum",
-          "isMut": true,
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "moderator",
-          "isMut": false,
+          "name": "authority",
+          "isMut": true,
           "isSigner": true
         },
         {
-          "name": "moderatorProfile",
+          "name": "userProfile",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "profileOwner",
+          "name": "sessionKey",
           "isMut": false,
           "isSigner": false
         },
-        {
-          "name": "userProfile",
-          "isMut": true,
-          "isSigner": false
-        },
         {
           "name": "question",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "comment",
-          "isMut": true,
+          "name": "questionSeed",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "commentSeed",
+          "name": "newContentDataHash",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "receiver",
+          "name": "revision",
           "isMut": true,
           "isSigner": false
         },
@@ -1654,91 +1590,61 @@ export type Forum = {
       ],
       "args": [
         {
-          "name": "bumpModeratorProfile",
+          "name": "bumpUserProfile",
           "type": "u8"
         },
         {
-          "name": "bumpUserProfile",
+          "name": "bumpQuestion",
           "type": "u8"
         },
         {
-          "name": "bumpComment",
+          "name": "bumpSessionKey",
           "type": "u8"
+        },
+        {
+          "name": "newTags",
+          "type": {
+            "vec": {
+              "defined": "Tags"
+            }
+          }
+        },
+        {
+          "name": "newTitle",
+          "type": "string"
+        },
+        {
+          "name": "newContentDataUrl",
+          "type": "string"
         }
       ]
     },
     {
-      "name": "leaveCommentOnAnswer",
+      "name": "editQuestionModerator",
       "accounts": [
         {
           "name": "forum",
-          "isMut": true,
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "profileOwner",
+          "name": "moderator",
           "isMut": true,
           "isSigner": true
         },
         {
-          "name": "userProfile",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "question",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "answer",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "comment",
+          "name": "moderatorProfile",
           "isMut": true,
           "isSigner": false
         },
-        {
-          "name": "commentSeed",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "contentDataHash",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "bumpUserProfile",
-          "type": "u8"
-        }
-      ]
-    },
-    {
-      "name": "editCommentOnAnswer",
-      "accounts": [
-        {
-          "name": "forum",
-          "isMut": false,
-          "isSigner": false
-        },
         {
           "name": "profileOwner",
           "isMut": false,
-          "isSigner": true
+          "isSigner": false
         },
         {
           "name": "userProfile",
-          "isMut": true,
+          "isMut": false,
           "isSigner": false
         },
         {
@@ -1747,23 +1653,23 @@ export type Forum = {
           "isSigner": false
         },
         {
-          "name": "answer",
-          "isMut": true,
+          "name": "questionSeed",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "comment",
-          "isMut": true,
+          "name": "category",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "commentSeed",
+          "name": "newContentDataHash",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "newContentDataHash",
-          "isMut": false,
+          "name": "revision",
+          "isMut": true,
           "isSigner": false
         },
         {
@@ -1773,22 +1679,42 @@ export type Forum = {
         }
       ],
       "args": [
+        {
+          "name": "bumpModeratorProfile",
+          "type": "u8"
+        },
         {
           "name": "bumpUserProfile",
           "type": "u8"
         },
         {
-          "name": "bumpComment",
+          "name": "bumpQuestion",
           "type": "u8"
+        },
+        {
+          "name": "newTags",
+          "type": {
+            "vec": {
+              "defined": "Tags"
+            }
+          }
+        },
+        {
+          "name": "newTitle",
+          "type": "string"
+        },
+        {
+          "name": "newContentDataUrl",
+          "type": "string"
         }
       ]
     },
     {
-      "name": "editCommentOnAnswerModerator",
+      "name": "deleteQuestionModerator",
       "accounts": [
         {
           "name": "forum",
-          "isMut": false,
+          "isMut": true,
           "isSigner": false
         },
         {
@@ -1808,7 +1734,7 @@ export type Forum = {
         },
         {
           "name": "userProfile",
-          "isMut": false,
+          "isMut": true,
           "isSigner": false
         },
         {
@@ -1817,23 +1743,18 @@ export type Forum = {
           "isSigner": false
         },
         {
-          "name": "answer",
-          "isMut": true,
+          "name": "questionSeed",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "comment",
+          "name": "category",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "commentSeed",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "newContentDataHash",
-          "isMut": false,
+          "name": "receiver",
+          "isMut": true,
           "isSigner": false
         },
         {
@@ -1852,51 +1773,61 @@ export type Forum = {
           "type": "u8"
         },
         {
-          "name": "bumpComment",
+          "name": "bumpQuestion",
           "type": "u8"
         }
       ]
     },
     {
-      "name": "deleteCommentOnAnswer",
+      "name": "supplementQuestionBounty",
       "accounts": [
         {
           "name": "forum",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "forumTreasury",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "profileOwner",
-          "isMut": false,
+          "name": "supplementor",
+          "isMut": true,
           "isSigner": true
         },
         {
-          "name": "userProfile",
+          "name": "supplementorProfile",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "question",
-          "isMut": true,
+          "name": "profileOwner",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "answer",
-          "isMut": true,
+          "name": "userProfile",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "comment",
+          "name": "question",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "commentSeed",
+          "name": "category",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "receiver",
+          "name": "questionSeed",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bountyPda",
           "isMut": true,
           "isSigner": false
         },
@@ -1907,22 +1838,38 @@ export type Forum = {
         }
       ],
       "args": [
+        {
+          "name": "bumpTreasury",
+          "type": "u8"
+        },
+        {
+          "name": "bumpSupplementorProfile",
+          "type": "u8"
+        },
         {
           "name": "bumpUserProfile",
           "type": "u8"
         },
         {
-          "name": "bumpComment",
+          "name": "bumpQuestion",
           "type": "u8"
+        },
+        {
+          "name": "bumpBountyPda",
+          "type": "u8"
+        },
+        {
+          "name": "supplementalBountyAmount",
+          "type": "u64"
         }
       ]
     },
     {
-      "name": "deleteCommentOnAnswerModerator",
+      "name": "refundQuestionBountySupplementorModerator",
       "accounts": [
         {
           "name": "forum",
-          "isMut": true,
+          "isMut": false,
           "isSigner": false
         },
         {
@@ -1942,31 +1889,31 @@ export type Forum = {
         },
         {
           "name": "userProfile",
-          "isMut": true,
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "question",
+          "name": "supplementor",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "answer",
+          "name": "supplementorProfile",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "comment",
+          "name": "question",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "commentSeed",
+          "name": "questionSeed",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "receiver",
+          "name": "bountyPda",
           "isMut": true,
           "isSigner": false
         },
@@ -1986,54 +1933,52 @@ export type Forum = {
           "type": "u8"
         },
         {
-          "name": "bumpComment",
+          "name": "bumpSupplementorProfile",
+          "type": "u8"
+        },
+        {
+          "name": "bumpQuestion",
+          "type": "u8"
+        },
+        {
+          "name": "bumpBountyPda",
           "type": "u8"
         }
       ]
     },
     {
-      "name": "createBigNote",
+      "name": "markDuplicate",
       "accounts": [
         {
           "name": "forum",
-          "isMut": true,
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "forumTreasury",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "profileOwner",
-          "isMut": true,
+          "name": "moderator",
+          "isMut": false,
           "isSigner": true
         },
         {
-          "name": "userProfile",
+          "name": "moderatorProfile",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "bigNote",
+          "name": "duplicateQuestion",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "bigNoteSeed",
+          "name": "category",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "contentDataHash",
+          "name": "canonicalQuestion",
           "isMut": false,
           "isSigner": false
         },
-        {
-          "name": "bountyPda",
-          "isMut": true,
-          "isSigner": false
-        },
         {
           "name": "systemProgram",
           "isMut": false,
@@ -2042,59 +1987,23 @@ export type Forum = {
       ],
       "args": [
         {
-          "name": "bumpTreasury",
-          "type": "u8"
-        },
-        {
-          "name": "bumpUserProfile",
+          "name": "bumpModeratorProfile",
           "type": "u8"
-        },
-        {
-          "name": "bigNoteType",
-          "type": {
-            "defined": "BigNoteType"
-          }
-        },
-        {
-          "name": "tags",
-          "type": {
-            "vec": {
-              "defined": "Tags"
-            }
-          }
-        },
-        {
-          "name": "title",
-          "type": "string"
-        },
-        {
-          "name": "contentDataUrl",
-          "type": "string"
         }
       ]
     },
     {
-      "name": "editBigNoteOpenContribution",
+      "name": "transferQuestionOwnership",
       "accounts": [
         {
           "name": "forum",
           "isMut": false,
           "isSigner": false
         },
-        {
-          "name": "editor",
-          "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "editorProfile",
-          "isMut": true,
-          "isSigner": false
-        },
         {
           "name": "profileOwner",
           "isMut": false,
-          "isSigner": false
+          "isSigner": true
         },
         {
           "name": "userProfile",
@@ -2102,17 +2011,17 @@ export type Forum = {
           "isSigner": false
         },
         {
-          "name": "bigNote",
+          "name": "question",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "bigNoteSeed",
+          "name": "questionSeed",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "newContentDataHash",
+          "name": "newOwnerProfile",
           "isMut": false,
           "isSigner": false
         },
@@ -2123,38 +2032,18 @@ export type Forum = {
         }
       ],
       "args": [
-        {
-          "name": "bumpEditorProfile",
-          "type": "u8"
-        },
         {
           "name": "bumpUserProfile",
           "type": "u8"
         },
         {
-          "name": "bumpBigNote",
+          "name": "bumpQuestion",
           "type": "u8"
-        },
-        {
-          "name": "newTags",
-          "type": {
-            "vec": {
-              "defined": "Tags"
-            }
-          }
-        },
-        {
-          "name": "newTitle",
-          "type": "string"
-        },
-        {
-          "name": "newContentDataUrl",
-          "type": "string"
         }
       ]
     },
     {
-      "name": "editBigNoteCreatorCurated",
+      "name": "claimQuestionOwnership",
       "accounts": [
         {
           "name": "forum",
@@ -2163,7 +2052,7 @@ export type Forum = {
         },
         {
           "name": "profileOwner",
-          "isMut": true,
+          "isMut": false,
           "isSigner": true
         },
         {
@@ -2172,17 +2061,12 @@ export type Forum = {
           "isSigner": false
         },
         {
-          "name": "bigNote",
+          "name": "question",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "bigNoteSeed",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "newContentDataHash",
+          "name": "questionSeed",
           "isMut": false,
           "isSigner": false
         },
@@ -2198,29 +2082,13 @@ export type Forum = {
           "type": "u8"
         },
         {
-          "name": "bumpBigNote",
+          "name": "bumpQuestion",
           "type": "u8"
-        },
-        {
-          "name": "newTags",
-          "type": {
-            "vec": {
-              "defined": "Tags"
-            }
-          }
-        },
-        {
-          "name": "newTitle",
-          "type": "string"
-        },
-        {
-          "name": "newContentDataUrl",
-          "type": "string"
         }
       ]
     },
     {
-      "name": "editBigNoteModerator",
+      "name": "migrateDuplicateQuestionBounty",
       "accounts": [
         {
           "name": "forum",
@@ -2238,28 +2106,28 @@ export type Forum = {
           "isSigner": false
         },
         {
-          "name": "profileOwner",
-          "isMut": false,
+          "name": "duplicateQuestion",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "userProfile",
+          "name": "category",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "bigNote",
+          "name": "duplicateBountyPda",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "bigNoteSeed",
-          "isMut": false,
+          "name": "canonicalQuestion",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "newContentDataHash",
-          "isMut": false,
+          "name": "canonicalBountyPda",
+          "isMut": true,
           "isSigner": false
         },
         {
@@ -2274,66 +2142,65 @@ export type Forum = {
           "type": "u8"
         },
         {
-          "name": "bumpUserProfile",
+          "name": "bumpDuplicateBountyPda",
           "type": "u8"
         },
         {
-          "name": "bumpBigNote",
+          "name": "bumpCanonicalBountyPda",
           "type": "u8"
-        },
-        {
-          "name": "newTags",
-          "type": {
-            "vec": {
-              "defined": "Tags"
-            }
-          }
-        },
-        {
-          "name": "newTitle",
-          "type": "string"
-        },
-        {
-          "name": "newContentDataUrl",
-          "type": "string"
         }
       ]
     },
     {
-      "name": "deleteBigNoteModerator",
+      "name": "acceptAnswer",
       "accounts": [
         {
           "name": "forum",
-          "isMut": true,
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "moderator",
+          "name": "profileOwner",
           "isMut": false,
           "isSigner": true
         },
         {
-          "name": "moderatorProfile",
+          "name": "userProfile",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "profileOwner",
+          "name": "question",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "questionSeed",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "userProfile",
+          "name": "bountyPda",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "bigNote",
+          "name": "answerProfileOwner",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "bigNoteSeed",
+          "name": "answerUserProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "answer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "answerSeed",
           "isMut": false,
           "isSigner": false
         },
@@ -2350,65 +2217,68 @@ export type Forum = {
       ],
       "args": [
         {
-          "name": "bumpModeratorProfile",
+          "name": "bumpUserProfile",
           "type": "u8"
         },
         {
-          "name": "bumpUserProfile",
+          "name": "bumpQuestion",
           "type": "u8"
         },
         {
-          "name": "bumpBigNote",
+          "name": "bumpBountyPda",
+          "type": "u8"
+        },
+        {
+          "name": "bumpAnswerUserProfile",
+          "type": "u8"
+        },
+        {
+          "name": "bumpAnswer",
           "type": "u8"
         }
       ]
     },
     {
-      "name": "supplementBigNoteBounty",
+      "name": "answerQuestion",
       "accounts": [
         {
           "name": "forum",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "forumTreasury",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "supplementor",
+          "name": "authority",
           "isMut": true,
           "isSigner": true
         },
         {
-          "name": "supplementorProfile",
+          "name": "userProfile",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "profileOwner",
+          "name": "sessionKey",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "userProfile",
-          "isMut": false,
+          "name": "question",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "bigNote",
+          "name": "answer",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "bigNoteSeed",
+          "name": "answerSeed",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "bountyPda",
-          "isMut": true,
+          "name": "contentDataHash",
+          "isMut": false,
           "isSigner": false
         },
         {
@@ -2418,34 +2288,18 @@ export type Forum = {
         }
       ],
       "args": [
-        {
-          "name": "bumpTreasury",
-          "type": "u8"
-        },
-        {
-          "name": "bumpSupplementorProfile",
-          "type": "u8"
-        },
         {
           "name": "bumpUserProfile",
           "type": "u8"
         },
         {
-          "name": "bumpBigNote",
-          "type": "u8"
-        },
-        {
-          "name": "bumpBountyPda",
+          "name": "bumpSessionKey",
           "type": "u8"
-        },
-        {
-          "name": "supplementalBountyAmount",
-          "type": "u64"
         }
       ]
     },
     {
-      "name": "refundBigNoteBountySupplementorModerator",
+      "name": "editAnswer",
       "accounts": [
         {
           "name": "forum",
@@ -2453,47 +2307,42 @@ export type Forum = {
           "isSigner": false
         },
         {
-          "name": "moderator",
-          "isMut": false,
+          "name": "authority",
+          "isMut": true,
           "isSigner": true
         },
         {
-          "name": "moderatorProfile",
+          "name": "userProfile",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "profileOwner",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "userProfile",
+          "name": "sessionKey",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "supplementor",
+          "name": "question",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "supplementorProfile",
+          "name": "answer",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "bigNote",
-          "isMut": true,
+          "name": "answerSeed",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "bigNoteSeed",
+          "name": "newContentDataHash",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "bountyPda",
+          "name": "revision",
           "isMut": true,
           "isSigner": false
         },
@@ -2505,29 +2354,21 @@ export type Forum = {
       ],
       "args": [
         {
-          "name": "bumpModeratorProfile",
+          "name": "bumpUserProfile",
           "type": "u8"
         },
         {
-          "name": "bumpUserProfile",
+          "name": "bumpAnswer",
           "type": "u8"
         },
         {
-          "name": "bumpSupplementorProfile",
-          "type": "u8"
-        },
-        {
-          "name": "bumpBigNote",
-          "type": "u8"
-        },
-        {
-          "name": "bumpBountyPda",
+          "name": "bumpSessionKey",
           "type": "u8"
         }
       ]
     },
     {
-      "name": "acceptProposedContribution",
+      "name": "editAnswerModerator",
       "accounts": [
         {
           "name": "forum",
@@ -2535,52 +2376,52 @@ export type Forum = {
           "isSigner": false
         },
         {
-          "name": "profileOwner",
-          "isMut": false,
+          "name": "moderator",
+          "isMut": true,
           "isSigner": true
         },
         {
-          "name": "userProfile",
+          "name": "moderatorProfile",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "bigNote",
-          "isMut": true,
+          "name": "profileOwner",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "bigNoteSeed",
+          "name": "userProfile",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "bountyPda",
+          "name": "question",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "proposalProfileOwner",
-          "isMut": true,
+          "name": "category",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "proposalUserProfile",
+          "name": "answer",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "proposedContribution",
-          "isMut": true,
+          "name": "answerSeed",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "proposedContributionSeed",
+          "name": "newContentDataHash",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "receiver",
+          "name": "revision",
           "isMut": true,
           "isSigner": false
         },
@@ -2592,33 +2433,25 @@ export type Forum = {
       ],
       "args": [
         {
-          "name": "bumpUserProfile",
-          "type": "u8"
-        },
-        {
-          "name": "bumpBigNote",
-          "type": "u8"
-        },
-        {
-          "name": "bumpBountyPda",
+          "name": "bumpModeratorProfile",
           "type": "u8"
         },
         {
-          "name": "bumpProposalUserProfile",
+          "name": "bumpUserProfile",
           "type": "u8"
         },
         {
-          "name": "bumpProposedContribution",
+          "name": "bumpAnswer",
           "type": "u8"
         }
       ]
     },
     {
-      "name": "rejectProposedContribution",
+      "name": "deleteAnswer",
       "accounts": [
         {
           "name": "forum",
-          "isMut": false,
+          "isMut": true,
           "isSigner": false
         },
         {
@@ -2632,35 +2465,25 @@ export type Forum = {
           "isSigner": false
         },
         {
-          "name": "bigNote",
+          "name": "question",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "bigNoteSeed",
-          "isMut": false,
+          "name": "answer",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "proposalProfileOwner",
+          "name": "answerSeed",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "proposalUserProfile",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "proposedContribution",
+          "name": "receiver",
           "isMut": true,
           "isSigner": false
         },
-        {
-          "name": "proposedContributionSeed",
-          "isMut": false,
-          "isSigner": false
-        },
         {
           "name": "systemProgram",
           "isMut": false,
@@ -2673,31 +2496,33 @@ export type Forum = {
           "type": "u8"
         },
         {
-          "name": "bumpBigNote",
-          "type": "u8"
-        },
-        {
-          "name": "bumpProposalUserProfile",
-          "type": "u8"
-        },
-        {
-          "name": "bumpProposedContribution",
+          "name": "bumpAnswer",
           "type": "u8"
         }
       ]
     },
     {
-      "name": "applyForBigNoteVerification",
+      "name": "deleteAnswerModerator",
       "accounts": [
         {
           "name": "forum",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "moderator",
           "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "moderatorProfile",
+          "isMut": true,
           "isSigner": false
         },
         {
           "name": "profileOwner",
-          "isMut": true,
-          "isSigner": true
+          "isMut": false,
+          "isSigner": false
         },
         {
           "name": "userProfile",
@@ -2705,22 +2530,27 @@ export type Forum = {
           "isSigner": false
         },
         {
-          "name": "bigNote",
+          "name": "question",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "bigNoteSeed",
+          "name": "category",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "verificationApplication",
+          "name": "answer",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "verificationFeePda",
+          "name": "answerSeed",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "receiver",
           "isMut": true,
           "isSigner": false
         },
@@ -2731,26 +2561,30 @@ export type Forum = {
         }
       ],
       "args": [
+        {
+          "name": "bumpModeratorProfile",
+          "type": "u8"
+        },
         {
           "name": "bumpUserProfile",
           "type": "u8"
         },
         {
-          "name": "bumpBigNote",
+          "name": "bumpAnswer",
           "type": "u8"
         }
       ]
     },
     {
-      "name": "deleteBigNoteVerificationApplication",
+      "name": "leaveComment",
       "accounts": [
         {
           "name": "forum",
-          "isMut": false,
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "profileOwner",
+          "name": "authority",
           "isMut": true,
           "isSigner": true
         },
@@ -2760,23 +2594,28 @@ export type Forum = {
           "isSigner": false
         },
         {
-          "name": "bigNote",
-          "isMut": true,
+          "name": "sessionKey",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "bigNoteSeed",
-          "isMut": false,
+          "name": "commentedOn",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "verificationApplication",
+          "name": "comment",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "verificationFeePda",
-          "isMut": true,
+          "name": "commentSeed",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "contentDataHash",
+          "isMut": false,
           "isSigner": false
         },
         {
@@ -2791,21 +2630,19 @@ export type Forum = {
           "type": "u8"
         },
         {
-          "name": "bumpBigNote",
-          "type": "u8"
-        },
-        {
-          "name": "bumpVerificationApplication",
+          "name": "bumpSessionKey",
           "type": "u8"
         },
         {
-          "name": "bumpVerificationFeePda",
-          "type": "u8"
+          "name": "commentTarget",
+          "type": {
+            "defined": "CommentTarget"
+          }
         }
       ]
     },
     {
-      "name": "deleteBigNoteVerificationApplicationModerator",
+      "name": "editComment",
       "accounts": [
         {
           "name": "forum",
@@ -2813,43 +2650,38 @@ export type Forum = {
           "isSigner": false
         },
         {
-          "name": "moderator",
+          "name": "authority",
           "isMut": false,
           "isSigner": true
         },
         {
-          "name": "moderatorProfile",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "profileOwner",
+          "name": "userProfile",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "userProfile",
+          "name": "sessionKey",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "bigNote",
+          "name": "commentedOn",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "bigNoteSeed",
-          "isMut": false,
+          "name": "comment",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "verificationApplication",
-          "isMut": true,
+          "name": "commentSeed",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "verificationFeePda",
-          "isMut": true,
+          "name": "newContentDataHash",
+          "isMut": false,
           "isSigner": false
         },
         {
@@ -2859,41 +2691,28 @@ export type Forum = {
         }
       ],
       "args": [
-        {
-          "name": "bumpModeratorProfile",
-          "type": "u8"
-        },
         {
           "name": "bumpUserProfile",
           "type": "u8"
         },
         {
-          "name": "bumpBigNote",
-          "type": "u8"
-        },
-        {
-          "name": "bumpVerificationApplication",
+          "name": "bumpComment",
           "type": "u8"
         },
         {
-          "name": "bumpVerificationFeePda",
+          "name": "bumpSessionKey",
           "type": "u8"
         }
       ]
     },
     {
-      "name": "acceptBigNoteVerificationApplication",
+      "name": "editCommentModerator",
       "accounts": [
         {
           "name": "forum",
           "isMut": false,
           "isSigner": false
         },
-        {
-          "name": "forumTreasury",
-          "isMut": true,
-          "isSigner": false
-        },
         {
           "name": "moderator",
           "isMut": false,
@@ -2906,32 +2725,37 @@ export type Forum = {
         },
         {
           "name": "profileOwner",
-          "isMut": true,
+          "isMut": false,
           "isSigner": false
         },
         {
           "name": "userProfile",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "commentedOn",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "bigNote",
+          "name": "comment",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "bigNoteSeed",
+          "name": "commentSeed",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "verificationApplication",
-          "isMut": true,
+          "name": "category",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "verificationFeePda",
-          "isMut": true,
+          "name": "newContentDataHash",
+          "isMut": false,
           "isSigner": false
         },
         {
@@ -2941,10 +2765,6 @@ export type Forum = {
         }
       ],
       "args": [
-        {
-          "name": "bumpTreasury",
-          "type": "u8"
-        },
         {
           "name": "bumpModeratorProfile",
           "type": "u8"
@@ -2954,64 +2774,46 @@ export type Forum = {
           "type": "u8"
         },
         {
-          "name": "bumpBigNote",
-          "type": "u8"
-        },
-        {
-          "name": "bumpVerificationApplication",
-          "type": "u8"
-        },
-        {
-          "name": "bumpVerificationFeePda",
+          "name": "bumpComment",
           "type": "u8"
         }
       ]
     },
     {
-      "name": "rejectBigNoteVerificationApplication",
+      "name": "deleteComment",
       "accounts": [
         {
           "name": "forum",
-          "isMut": false,
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "moderator",
+          "name": "profileOwner",
           "isMut": false,
           "isSigner": true
         },
         {
-          "name": "moderatorProfile",
+          "name": "userProfile",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "profileOwner",
+          "name": "commentedOn",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "userProfile",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "bigNote",
+          "name": "comment",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "bigNoteSeed",
+          "name": "commentSeed",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "verificationApplication",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "verificationFeePda",
+          "name": "receiver",
           "isMut": true,
           "isSigner": false
         },
@@ -3022,40 +2824,38 @@ export type Forum = {
         }
       ],
       "args": [
-        {
-          "name": "bumpModeratorProfile",
-          "type": "u8"
-        },
         {
           "name": "bumpUserProfile",
           "type": "u8"
         },
         {
-          "name": "bumpBigNote",
+          "name": "bumpComment",
           "type": "u8"
-        },
+        }
+      ]
+    },
+    {
+      "name": "deleteCommentModerator",
+      "accounts": [
         {
-          "name": "bumpVerificationApplication",
-          "type": "u8"
+          "name": "forum",
+          "isMut": true,
+          "isSigner": false
         },
         {
-          "name": "bumpVerificationFeePda",
-          "type": "u8"
-        }
-      ]
-    },
-    {
-      "name": "proposeContribution",
-      "accounts": [
+          "name": "moderator",
+          "isMut": false,
+          "isSigner": true
+        },
         {
-          "name": "forum",
+          "name": "moderatorProfile",
           "isMut": true,
           "isSigner": false
         },
         {
           "name": "profileOwner",
-          "isMut": true,
-          "isSigner": true
+          "isMut": false,
+          "isSigner": false
         },
         {
           "name": "userProfile",
@@ -3063,25 +2863,30 @@ export type Forum = {
           "isSigner": false
         },
         {
-          "name": "bigNote",
+          "name": "commentedOn",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "proposedContribution",
+          "name": "comment",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "proposedContributionSeed",
+          "name": "commentSeed",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "contentDataHash",
+          "name": "category",
           "isMut": false,
           "isSigner": false
         },
+        {
+          "name": "receiver",
+          "isMut": true,
+          "isSigner": false
+        },
         {
           "name": "systemProgram",
           "isMut": false,
@@ -3089,23 +2894,36 @@ export type Forum = {
         }
       ],
       "args": [
+        {
+          "name": "bumpModeratorProfile",
+          "type": "u8"
+        },
         {
           "name": "bumpUserProfile",
           "type": "u8"
+        },
+        {
+          "name": "bumpComment",
+          "type": "u8"
         }
       ]
     },
     {
-      "name": "editProposedContribution",
+      "name": "createBigNote",
       "accounts": [
         {
           "name": "forum",
-          "isMut": false,
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "forumTreasury",
+          "isMut": true,
           "isSigner": false
         },
         {
           "name": "profileOwner",
-          "isMut": false,
+          "isMut": true,
           "isSigner": true
         },
         {
@@ -3114,25 +2932,30 @@ export type Forum = {
           "isSigner": false
         },
         {
-          "name": "bigNote",
+          "name": "category",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "proposedContribution",
+          "name": "bigNote",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "proposedContributionSeed",
+          "name": "bigNoteSeed",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "newContentDataHash",
+          "name": "contentDataHash",
           "isMut": false,
           "isSigner": false
         },
+        {
+          "name": "bountyPda",
+          "isMut": true,
+          "isSigner": false
+        },
         {
           "name": "systemProgram",
           "isMut": false,
@@ -3141,63 +2964,110 @@ export type Forum = {
       ],
       "args": [
         {
-          "name": "bumpUserProfile",
+          "name": "bumpTreasury",
           "type": "u8"
         },
         {
-          "name": "bumpProposedContribution",
+          "name": "bumpUserProfile",
           "type": "u8"
+        },
+        {
+          "name": "bigNoteType",
+          "type": {
+            "defined": "BigNoteType"
+          }
+        },
+        {
+          "name": "tags",
+          "type": {
+            "vec": {
+              "defined": "Tags"
+            }
+          }
+        },
+        {
+          "name": "title",
+          "type": "string"
+        },
+        {
+          "name": "contentDataUrl",
+          "type": "string"
         }
       ]
     },
     {
-      "name": "editProposedContributionModerator",
+      "name": "convertQuestionToBigNote",
       "accounts": [
         {
           "name": "forum",
-          "isMut": false,
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "moderator",
-          "isMut": false,
+          "name": "forumTreasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "profileOwner",
+          "isMut": true,
           "isSigner": true
         },
         {
-          "name": "moderatorProfile",
+          "name": "userProfile",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "profileOwner",
+          "name": "question",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "questionSeed",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "userProfile",
+          "name": "category",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "answerProfileOwner",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "bigNote",
+          "name": "answerUserProfile",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "proposedContribution",
+          "name": "answer",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "proposedContributionSeed",
+          "name": "answerSeed",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "newContentDataHash",
+          "name": "bigNote",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "contentDataHash",
           "isMut": false,
           "isSigner": false
         },
+        {
+          "name": "bountyPda",
+          "isMut": true,
+          "isSigner": false
+        },
         {
           "name": "systemProgram",
           "isMut": false,
@@ -3206,7 +3076,7 @@ export type Forum = {
       ],
       "args": [
         {
-          "name": "bumpModeratorProfile",
+          "name": "bumpTreasury",
           "type": "u8"
         },
         {
@@ -3214,22 +3084,45 @@ export type Forum = {
           "type": "u8"
         },
         {
-          "name": "bumpProposedContribution",
+          "name": "bumpQuestion",
+          "type": "u8"
+        },
+        {
+          "name": "bumpAnswerUserProfile",
+          "type": "u8"
+        },
+        {
+          "name": "bumpAnswer",
           "type": "u8"
+        },
+        {
+          "name": "bigNoteType",
+          "type": {
+            "defined": "BigNoteType"
+          }
+        },
+        {
+          "name": "contentDataUrl",
+          "type": "string"
         }
       ]
     },
     {
-      "name": "deleteProposedContribution",
+      "name": "forkBigNote",
       "accounts": [
         {
           "name": "forum",
           "isMut": true,
           "isSigner": false
         },
+        {
+          "name": "forumTreasury",
+          "isMut": true,
+          "isSigner": false
+        },
         {
           "name": "profileOwner",
-          "isMut": false,
+          "isMut": true,
           "isSigner": true
         },
         {
@@ -3237,23 +3130,38 @@ export type Forum = {
           "isMut": true,
           "isSigner": false
         },
+        {
+          "name": "parentBigNote",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "category",
+          "isMut": true,
+          "isSigner": false
+        },
         {
           "name": "bigNote",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "proposedContribution",
+          "name": "bigNoteSeed",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bigNoteFork",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "proposedContributionSeed",
+          "name": "contentDataHash",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "receiver",
+          "name": "bountyPda",
           "isMut": true,
           "isSigner": false
         },
@@ -3265,21 +3173,31 @@ export type Forum = {
       ],
       "args": [
         {
-          "name": "bumpUserProfile",
+          "name": "bumpTreasury",
           "type": "u8"
         },
         {
-          "name": "bumpProposedContribution",
+          "name": "bumpUserProfile",
           "type": "u8"
+        },
+        {
+          "name": "bigNoteType",
+          "type": {
+            "defined": "BigNoteType"
+          }
+        },
+        {
+          "name": "contentDataUrl",
+          "type": "string"
         }
       ]
     },
     {
-      "name": "deleteProposedContributionModerator",
+      "name": "markBigNoteSuccessor",
       "accounts": [
         {
           "name": "forum",
-          "isMut": true,
+          "isMut": false,
           "isSigner": false
         },
         {
@@ -3293,35 +3211,15 @@ export type Forum = {
           "isSigner": false
         },
         {
-          "name": "profileOwner",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "userProfile",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "bigNote",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "proposedContribution",
+          "name": "parentBigNote",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "proposedContributionSeed",
+          "name": "bigNoteFork",
           "isMut": false,
           "isSigner": false
         },
-        {
-          "name": "receiver",
-          "isMut": true,
-          "isSigner": false
-        },
         {
           "name": "systemProgram",
           "isMut": false,
@@ -3334,26 +3232,22 @@ export type Forum = {
           "type": "u8"
         },
         {
-          "name": "bumpUserProfile",
-          "type": "u8"
-        },
-        {
-          "name": "bumpProposedContribution",
+          "name": "bumpBigNoteFork",
           "type": "u8"
         }
       ]
     },
     {
-      "name": "leaveCommentOnBigNote",
+      "name": "transferBigNoteOwnership",
       "accounts": [
         {
           "name": "forum",
-          "isMut": true,
+          "isMut": false,
           "isSigner": false
         },
         {
           "name": "profileOwner",
-          "isMut": true,
+          "isMut": false,
           "isSigner": true
         },
         {
@@ -3367,17 +3261,12 @@ export type Forum = {
           "isSigner": false
         },
         {
-          "name": "comment",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "commentSeed",
+          "name": "bigNoteSeed",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "contentDataHash",
+          "name": "newOwnerProfile",
           "isMut": false,
           "isSigner": false
         },
@@ -3391,11 +3280,15 @@ export type Forum = {
         {
           "name": "bumpUserProfile",
           "type": "u8"
+        },
+        {
+          "name": "bumpBigNote",
+          "type": "u8"
         }
       ]
     },
     {
-      "name": "editCommentOnBigNote",
+      "name": "claimBigNoteOwnership",
       "accounts": [
         {
           "name": "forum",
@@ -3418,17 +3311,7 @@ export type Forum = {
           "isSigner": false
         },
         {
-          "name": "comment",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "commentSeed",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "newContentDataHash",
+          "name": "bigNoteSeed",
           "isMut": false,
           "isSigner": false
         },
@@ -3444,13 +3327,13 @@ export type Forum = {
           "type": "u8"
         },
         {
-          "name": "bumpComment",
+          "name": "bumpBigNote",
           "type": "u8"
         }
       ]
     },
     {
-      "name": "editCommentOnBigNoteModerator",
+      "name": "editBigNoteOpenContribution",
       "accounts": [
         {
           "name": "forum",
@@ -3458,45 +3341,55 @@ export type Forum = {
           "isSigner": false
         },
         {
-          "name": "moderator",
-          "isMut": false,
+          "name": "editor",
+          "isMut": true,
           "isSigner": true
         },
         {
-          "name": "moderatorProfile",
+          "name": "editorProfile",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "profileOwner",
+          "name": "sessionKey",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "userProfile",
+          "name": "profileOwner",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "bigNote",
+          "name": "userProfile",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "comment",
+          "name": "bigNote",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "commentSeed",
+          "name": "bigNoteSeed",
           "isMut": false,
           "isSigner": false
         },
+        {
+          "name": "verificationRecord",
+          "isMut": true,
+          "isSigner": false
+        },
         {
           "name": "newContentDataHash",
           "isMut": false,
           "isSigner": false
         },
+        {
+          "name": "revision",
+          "isMut": true,
+          "isSigner": false
+        },
         {
           "name": "systemProgram",
           "isMut": false,
@@ -3505,7 +3398,7 @@ export type Forum = {
       ],
       "args": [
         {
-          "name": "bumpModeratorProfile",
+          "name": "bumpEditorProfile",
           "type": "u8"
         },
         {
@@ -3513,23 +3406,62 @@ export type Forum = {
           "type": "u8"
         },
         {
-          "name": "bumpComment",
+          "name": "bumpBigNote",
+          "type": "u8"
+        },
+        {
+          "name": "bumpSessionKey",
           "type": "u8"
+        },
+        {
+          "name": "bumpVerificationRecord",
+          "type": "u8"
+        },
+        {
+          "name": "newTags",
+          "type": {
+            "vec": {
+              "defined": "Tags"
+            }
+          }
+        },
+        {
+          "name": "newTitle",
+          "type": "string"
+        },
+        {
+          "name": "newContentDataUrl",
+          "type": "string"
         }
       ]
     },
     {
-      "name": "deleteCommentOnBigNote",
+      "name": "editBigNoteCreatorCurated",
       "accounts": [
         {
           "name": "forum",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "editor",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "editorProfile",
           "isMut": true,
           "isSigner": false
         },
+        {
+          "name": "sessionKey",
+          "isMut": false,
+          "isSigner": false
+        },
         {
           "name": "profileOwner",
           "isMut": false,
-          "isSigner": true
+          "isSigner": false
         },
         {
           "name": "userProfile",
@@ -3542,17 +3474,22 @@ export type Forum = {
           "isSigner": false
         },
         {
-          "name": "comment",
+          "name": "bigNoteSeed",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "verificationRecord",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "commentSeed",
+          "name": "newContentDataHash",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "receiver",
+          "name": "revision",
           "isMut": true,
           "isSigner": false
         },
@@ -3563,27 +3500,55 @@ export type Forum = {
         }
       ],
       "args": [
+        {
+          "name": "bumpEditorProfile",
+          "type": "u8"
+        },
         {
           "name": "bumpUserProfile",
           "type": "u8"
         },
         {
-          "name": "bumpComment",
+          "name": "bumpBigNote",
+          "type": "u8"
+        },
+        {
+          "name": "bumpSessionKey",
+          "type": "u8"
+        },
+        {
+          "name": "bumpVerificationRecord",
           "type": "u8"
+        },
+        {
+          "name": "newTags",
+          "type": {
+            "vec": {
+              "defined": "Tags"
+            }
+          }
+        },
+        {
+          "name": "newTitle",
+          "type": "string"
+        },
+        {
+          "name": "newContentDataUrl",
+          "type": "string"
         }
       ]
     },
     {
-      "name": "deleteCommentOnBigNoteModerator",
+      "name": "editBigNoteModerator",
       "accounts": [
         {
           "name": "forum",
-          "isMut": true,
+          "isMut": false,
           "isSigner": false
         },
         {
           "name": "moderator",
-          "isMut": false,
+          "isMut": true,
           "isSigner": true
         },
         {
@@ -3598,7 +3563,7 @@ export type Forum = {
         },
         {
           "name": "userProfile",
-          "isMut": true,
+          "isMut": false,
           "isSigner": false
         },
         {
@@ -3607,17 +3572,22 @@ export type Forum = {
           "isSigner": false
         },
         {
-          "name": "comment",
-          "isMut": true,
+          "name": "bigNoteSeed",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "commentSeed",
+          "name": "category",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "receiver",
+          "name": "newContentDataHash",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "revision",
           "isMut": true,
           "isSigner": false
         },
@@ -3637,13 +3607,29 @@ export type Forum = {
           "type": "u8"
         },
         {
-          "name": "bumpComment",
+          "name": "bumpBigNote",
           "type": "u8"
+        },
+        {
+          "name": "newTags",
+          "type": {
+            "vec": {
+              "defined": "Tags"
+            }
+          }
+        },
+        {
+          "name": "newTitle",
+          "type": "string"
+        },
+        {
+          "name": "newContentDataUrl",
+          "type": "string"
         }
       ]
     },
     {
-      "name": "leaveCommentOnProposedContribution",
+      "name": "deleteBigNoteModerator",
       "accounts": [
         {
           "name": "forum",
@@ -3651,10 +3637,20 @@ export type Forum = {
           "isSigner": false
         },
         {
-          "name": "profileOwner",
-          "isMut": true,
+          "name": "moderator",
+          "isMut": false,
           "isSigner": true
         },
+        {
+          "name": "moderatorProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "profileOwner",
+          "isMut": false,
+          "isSigner": false
+        },
         {
           "name": "userProfile",
           "isMut": true,
@@ -3666,23 +3662,31 @@ export type Forum = {
           "isSigner": false
         },
         {
-          "name": "proposedContribution",
-          "isMut": true,
+          "name": "bigNoteSeed",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "comment",
+          "name": "category",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "commentSeed",
+          "name": "answerUserProfile",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "was converted from a question, and left untouched if the profile has since been deleted)"
+          ]
+        },
+        {
+          "name": "answerSeed",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "contentDataHash",
-          "isMut": false,
+          "name": "receiver",
+          "isMut": true,
           "isSigner": false
         },
         {
@@ -3692,14 +3696,22 @@ export type Forum = {
         }
       ],
       "args": [
+        {
+          "name": "bumpModeratorProfile",
+          "type": "u8"
+        },
         {
           "name": "bumpUserProfile",
           "type": "u8"
+        },
+        {
+          "name": "bumpBigNote",
+          "type": "u8"
         }
       ]
     },
     {
-      "name": "editCommentOnProposedContribution",
+      "name": "supplementBigNoteBounty",
       "accounts": [
         {
           "name": "forum",
@@ -3707,40 +3719,50 @@ export type Forum = {
           "isSigner": false
         },
         {
-          "name": "profileOwner",
-          "isMut": false,
+          "name": "forumTreasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "supplementor",
+          "isMut": true,
           "isSigner": true
         },
         {
-          "name": "userProfile",
+          "name": "supplementorProfile",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "bigNote",
-          "isMut": true,
+          "name": "profileOwner",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "proposedContribution",
-          "isMut": true,
+          "name": "userProfile",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "comment",
+          "name": "bigNote",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "commentSeed",
+          "name": "category",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "newContentDataHash",
+          "name": "bigNoteSeed",
           "isMut": false,
           "isSigner": false
         },
+        {
+          "name": "bountyPda",
+          "isMut": true,
+          "isSigner": false
+        },
         {
           "name": "systemProgram",
           "isMut": false,
@@ -3748,18 +3770,34 @@ export type Forum = {
         }
       ],
       "args": [
+        {
+          "name": "bumpTreasury",
+          "type": "u8"
+        },
+        {
+          "name": "bumpSupplementorProfile",
+          "type": "u8"
+        },
         {
           "name": "bumpUserProfile",
           "type": "u8"
         },
         {
-          "name": "bumpComment",
+          "name": "bumpBigNote",
+          "type": "u8"
+        },
+        {
+          "name": "bumpBountyPda",
           "type": "u8"
+        },
+        {
+          "name": "supplementalBountyAmount",
+          "type": "u64"
         }
       ]
     },
     {
-      "name": "editCommentOnProposedContributionModerator",
+      "name": "refundBigNoteBountySupplementorModerator",
       "accounts": [
         {
           "name": "forum",
@@ -3787,28 +3825,28 @@ export type Forum = {
           "isSigner": false
         },
         {
-          "name": "bigNote",
+          "name": "supplementor",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "proposedContribution",
+          "name": "supplementorProfile",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "comment",
+          "name": "bigNote",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "commentSeed",
+          "name": "bigNoteSeed",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "newContentDataHash",
-          "isMut": false,
+          "name": "bountyPda",
+          "isMut": true,
           "isSigner": false
         },
         {
@@ -3827,17 +3865,25 @@ export type Forum = {
           "type": "u8"
         },
         {
-          "name": "bumpComment",
+          "name": "bumpSupplementorProfile",
+          "type": "u8"
+        },
+        {
+          "name": "bumpBigNote",
+          "type": "u8"
+        },
+        {
+          "name": "bumpBountyPda",
           "type": "u8"
         }
       ]
     },
     {
-      "name": "deleteCommentOnProposedContribution",
+      "name": "acceptProposedContribution",
       "accounts": [
         {
           "name": "forum",
-          "isMut": true,
+          "isMut": false,
           "isSigner": false
         },
         {
@@ -3856,17 +3902,32 @@ export type Forum = {
           "isSigner": false
         },
         {
-          "name": "proposedContribution",
-          "isMut": true,
-          "isSigner": false
+          "name": "bigNoteSeed",
+          "isMut": false,
+          "isSigner": false
         },
         {
-          "name": "comment",
+          "name": "bountyPda",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "commentSeed",
+          "name": "proposalProfileOwner",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "proposalUserProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "proposedContribution",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "proposedContributionSeed",
           "isMut": false,
           "isSigner": false
         },
@@ -3887,64 +3948,71 @@ export type Forum = {
           "type": "u8"
         },
         {
-          "name": "bumpComment",
+          "name": "bumpBigNote",
+          "type": "u8"
+        },
+        {
+          "name": "bumpBountyPda",
+          "type": "u8"
+        },
+        {
+          "name": "bumpProposalUserProfile",
+          "type": "u8"
+        },
+        {
+          "name": "bumpProposedContribution",
           "type": "u8"
         }
       ]
     },
     {
-      "name": "deleteCommentOnProposedContributionModerator",
+      "name": "rejectProposedContribution",
       "accounts": [
         {
           "name": "forum",
-          "isMut": true,
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "moderator",
+          "name": "profileOwner",
           "isMut": false,
           "isSigner": true
         },
         {
-          "name": "moderatorProfile",
+          "name": "userProfile",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "profileOwner",
-          "isMut": false,
+          "name": "bigNote",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "userProfile",
-          "isMut": true,
+          "name": "bigNoteSeed",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "bigNote",
-          "isMut": true,
+          "name": "proposalProfileOwner",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "proposedContribution",
+          "name": "proposalUserProfile",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "comment",
+          "name": "proposedContribution",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "commentSeed",
+          "name": "proposedContributionSeed",
           "isMut": false,
           "isSigner": false
         },
-        {
-          "name": "receiver",
-          "isMut": true,
-          "isSigner": false
-        },
         {
           "name": "systemProgram",
           "isMut": false,
@@ -3953,91 +4021,90 @@ export type Forum = {
       ],
       "args": [
         {
-          "name": "bumpModeratorProfile",
+          "name": "bumpUserProfile",
           "type": "u8"
         },
         {
-          "name": "bumpUserProfile",
+          "name": "bumpBigNote",
           "type": "u8"
         },
         {
-          "name": "bumpComment",
+          "name": "bumpProposalUserProfile",
+          "type": "u8"
+        },
+        {
+          "name": "bumpProposedContribution",
           "type": "u8"
         }
       ]
     },
     {
-      "name": "createChallenge",
+      "name": "applyForBigNoteVerification",
       "accounts": [
         {
           "name": "forum",
-          "isMut": true,
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "moderator",
+          "name": "profileOwner",
           "isMut": true,
           "isSigner": true
         },
         {
-          "name": "moderatorProfile",
+          "name": "userProfile",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "challenge",
+          "name": "bigNote",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "challengeSeed",
+          "name": "category",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "contentDataHash",
+          "name": "bigNoteSeed",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "systemProgram",
-          "isMut": false,
+          "name": "verificationApplication",
+          "isMut": true,
           "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "bumpModeratorProfile",
-          "type": "u8"
         },
         {
-          "name": "tags",
-          "type": {
-            "vec": {
-              "defined": "Tags"
-            }
-          }
+          "name": "verificationFeePda",
+          "isMut": true,
+          "isSigner": false
         },
         {
-          "name": "title",
-          "type": "string"
+          "name": "verificationRecord",
+          "isMut": true,
+          "isSigner": false
         },
         {
-          "name": "contentDataUrl",
-          "type": "string"
-        },
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
         {
-          "name": "challengeExpiresTs",
-          "type": "u64"
+          "name": "bumpUserProfile",
+          "type": "u8"
         },
         {
-          "name": "reputation",
-          "type": "u64"
+          "name": "bumpBigNote",
+          "type": "u8"
         }
       ]
     },
     {
-      "name": "editChallenge",
+      "name": "deleteBigNoteVerificationApplication",
       "accounts": [
         {
           "name": "forum",
@@ -4045,28 +4112,33 @@ export type Forum = {
           "isSigner": false
         },
         {
-          "name": "moderator",
+          "name": "profileOwner",
           "isMut": true,
           "isSigner": true
         },
         {
-          "name": "moderatorProfile",
+          "name": "userProfile",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "challenge",
+          "name": "bigNote",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "challengeSeed",
+          "name": "bigNoteSeed",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "newContentDataHash",
-          "isMut": false,
+          "name": "verificationApplication",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "verificationFeePda",
+          "isMut": true,
           "isSigner": false
         },
         {
@@ -4077,45 +4149,29 @@ export type Forum = {
       ],
       "args": [
         {
-          "name": "bumpModeratorProfile",
+          "name": "bumpUserProfile",
           "type": "u8"
         },
         {
-          "name": "bumpChallenge",
+          "name": "bumpBigNote",
           "type": "u8"
         },
         {
-          "name": "newTags",
-          "type": {
-            "vec": {
-              "defined": "Tags"
-            }
-          }
-        },
-        {
-          "name": "newTitle",
-          "type": "string"
-        },
-        {
-          "name": "newContentDataUrl",
-          "type": "string"
-        },
-        {
-          "name": "newChallengeExpiresTs",
-          "type": "u64"
+          "name": "bumpVerificationApplication",
+          "type": "u8"
         },
         {
-          "name": "newReputation",
-          "type": "u64"
+          "name": "bumpVerificationFeePda",
+          "type": "u8"
         }
       ]
     },
     {
-      "name": "deleteChallenge",
+      "name": "deleteBigNoteVerificationApplicationModerator",
       "accounts": [
         {
           "name": "forum",
-          "isMut": true,
+          "isMut": false,
           "isSigner": false
         },
         {
@@ -4129,17 +4185,32 @@ export type Forum = {
           "isSigner": false
         },
         {
-          "name": "challenge",
+          "name": "profileOwner",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "challengeSeed",
+          "name": "userProfile",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "receiver",
+          "name": "bigNote",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bigNoteSeed",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "verificationApplication",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "verificationFeePda",
           "isMut": true,
           "isSigner": false
         },
@@ -4155,17 +4226,29 @@ export type Forum = {
           "type": "u8"
         },
         {
-          "name": "bumpChallenge",
+          "name": "bumpUserProfile",
+          "type": "u8"
+        },
+        {
+          "name": "bumpBigNote",
+          "type": "u8"
+        },
+        {
+          "name": "bumpVerificationApplication",
+          "type": "u8"
+        },
+        {
+          "name": "bumpVerificationFeePda",
           "type": "u8"
         }
       ]
     },
     {
-      "name": "createSubmission",
+      "name": "attestBigNoteVerification",
       "accounts": [
         {
           "name": "forum",
-          "isMut": true,
+          "isMut": false,
           "isSigner": false
         },
         {
@@ -4174,33 +4257,58 @@ export type Forum = {
           "isSigner": false
         },
         {
-          "name": "profileOwner",
+          "name": "attester",
           "isMut": true,
           "isSigner": true
         },
+        {
+          "name": "attesterProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "profileOwner",
+          "isMut": true,
+          "isSigner": false
+        },
         {
           "name": "userProfile",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "challenge",
+          "name": "bigNote",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "category",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "challengeSeed",
+          "name": "bigNoteSeed",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "submission",
+          "name": "verificationApplication",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "contentDataHash",
-          "isMut": false,
+          "name": "verificationFeePda",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "verificationRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "verificationAttestation",
+          "isMut": true,
           "isSigner": false
         },
         {
@@ -4214,18 +4322,34 @@ export type Forum = {
           "name": "bumpTreasury",
           "type": "u8"
         },
+        {
+          "name": "bumpAttesterProfile",
+          "type": "u8"
+        },
         {
           "name": "bumpUserProfile",
           "type": "u8"
         },
         {
-          "name": "bumpChallenge",
+          "name": "bumpBigNote",
           "type": "u8"
-        }
+        },
+        {
+          "name": "bumpVerificationApplication",
+          "type": "u8"
+        },
+        {
+          "name": "bumpVerificationFeePda",
+          "type": "u8"
+        },
+        {
+          "name": "bumpVerificationRecord",
+          "type": "u8"
+        }
       ]
     },
     {
-      "name": "editSubmission",
+      "name": "rejectBigNoteVerificationApplication",
       "accounts": [
         {
           "name": "forum",
@@ -4233,35 +4357,45 @@ export type Forum = {
           "isSigner": false
         },
         {
-          "name": "profileOwner",
+          "name": "moderator",
           "isMut": false,
           "isSigner": true
         },
         {
-          "name": "userProfile",
+          "name": "moderatorProfile",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "challenge",
-          "isMut": false,
+          "name": "profileOwner",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "challengeSeed",
+          "name": "userProfile",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "submission",
+          "name": "bigNote",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "newContentDataHash",
+          "name": "bigNoteSeed",
           "isMut": false,
           "isSigner": false
         },
+        {
+          "name": "verificationApplication",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "verificationFeePda",
+          "isMut": true,
+          "isSigner": false
+        },
         {
           "name": "systemProgram",
           "isMut": false,
@@ -4269,28 +4403,41 @@ export type Forum = {
         }
       ],
       "args": [
+        {
+          "name": "bumpModeratorProfile",
+          "type": "u8"
+        },
         {
           "name": "bumpUserProfile",
           "type": "u8"
         },
         {
-          "name": "bumpChallenge",
+          "name": "bumpBigNote",
           "type": "u8"
         },
         {
-          "name": "bumpSubmission",
+          "name": "bumpVerificationApplication",
+          "type": "u8"
+        },
+        {
+          "name": "bumpVerificationFeePda",
           "type": "u8"
         }
       ]
     },
     {
-      "name": "editSubmissionModerator",
+      "name": "revokeBigNoteVerification",
       "accounts": [
         {
           "name": "forum",
           "isMut": false,
           "isSigner": false
         },
+        {
+          "name": "forumTreasury",
+          "isMut": true,
+          "isSigner": false
+        },
         {
           "name": "moderator",
           "isMut": false,
@@ -4302,35 +4449,83 @@ export type Forum = {
           "isSigner": false
         },
         {
-          "name": "profileOwner",
+          "name": "userProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bigNote",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bigNoteSeed",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "userProfile",
+          "name": "verificationRecord",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "challenge",
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpModeratorProfile",
+          "type": "u8"
         },
         {
-          "name": "challengeSeed",
+          "name": "bumpBigNote",
+          "type": "u8"
+        },
+        {
+          "name": "bumpVerificationRecord",
+          "type": "u8"
+        },
+        {
+          "name": "slashAttesters",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "withdrawVerificationAttestation",
+      "accounts": [
+        {
+          "name": "forum",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "submission",
+          "name": "attester",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "attesterProfile",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "newContentDataHash",
+          "name": "bigNote",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "verificationRecord",
           "isMut": false,
           "isSigner": false
         },
+        {
+          "name": "verificationAttestation",
+          "isMut": true,
+          "isSigner": false
+        },
         {
           "name": "systemProgram",
           "isMut": false,
@@ -4339,25 +4534,62 @@ export type Forum = {
       ],
       "args": [
         {
-          "name": "bumpModeratorProfile",
+          "name": "bumpAttesterProfile",
           "type": "u8"
         },
         {
-          "name": "bumpUserProfile",
+          "name": "bumpVerificationRecord",
           "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "expireBigNoteVerification",
+      "accounts": [
+        {
+          "name": "forum",
+          "isMut": false,
+          "isSigner": false
         },
         {
-          "name": "bumpChallenge",
+          "name": "cranker",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "userProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bigNote",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bigNoteSeed",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "verificationRecord",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpBigNote",
           "type": "u8"
         },
         {
-          "name": "bumpSubmission",
+          "name": "bumpVerificationRecord",
           "type": "u8"
         }
       ]
     },
     {
-      "name": "deleteSubmission",
+      "name": "proposeContribution",
       "accounts": [
         {
           "name": "forum",
@@ -4365,8 +4597,8 @@ export type Forum = {
           "isSigner": false
         },
         {
-          "name": "profileOwner",
-          "isMut": false,
+          "name": "authority",
+          "isMut": true,
           "isSigner": true
         },
         {
@@ -4375,23 +4607,28 @@ export type Forum = {
           "isSigner": false
         },
         {
-          "name": "challenge",
+          "name": "sessionKey",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "challengeSeed",
-          "isMut": false,
+          "name": "bigNote",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "submission",
+          "name": "proposedContribution",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "receiver",
-          "isMut": true,
+          "name": "proposedContributionSeed",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "contentDataHash",
+          "isMut": false,
           "isSigner": false
         },
         {
@@ -4406,63 +4643,49 @@ export type Forum = {
           "type": "u8"
         },
         {
-          "name": "bumpChallenge",
-          "type": "u8"
-        },
-        {
-          "name": "bumpSubmission",
+          "name": "bumpSessionKey",
           "type": "u8"
         }
       ]
     },
     {
-      "name": "deleteSubmissionModerator",
+      "name": "editProposedContribution",
       "accounts": [
         {
           "name": "forum",
-          "isMut": true,
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "moderator",
+          "name": "profileOwner",
           "isMut": false,
           "isSigner": true
         },
         {
-          "name": "moderatorProfile",
+          "name": "userProfile",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "profileOwner",
-          "isMut": false,
+          "name": "bigNote",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "userProfile",
+          "name": "proposedContribution",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "challenge",
+          "name": "proposedContributionSeed",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "challengeSeed",
+          "name": "newContentDataHash",
           "isMut": false,
           "isSigner": false
         },
-        {
-          "name": "submission",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "receiver",
-          "isMut": true,
-          "isSigner": false
-        },
         {
           "name": "systemProgram",
           "isMut": false,
@@ -4470,26 +4693,18 @@ export type Forum = {
         }
       ],
       "args": [
-        {
-          "name": "bumpModeratorProfile",
-          "type": "u8"
-        },
         {
           "name": "bumpUserProfile",
           "type": "u8"
         },
         {
-          "name": "bumpChallenge",
-          "type": "u8"
-        },
-        {
-          "name": "bumpSubmission",
+          "name": "bumpProposedContribution",
           "type": "u8"
         }
       ]
     },
     {
-      "name": "evaluateSubmission",
+      "name": "editProposedContributionModerator",
       "accounts": [
         {
           "name": "forum",
@@ -4513,22 +4728,27 @@ export type Forum = {
         },
         {
           "name": "userProfile",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bigNote",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "challenge",
-          "isMut": false,
+          "name": "proposedContribution",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "challengeSeed",
+          "name": "proposedContributionSeed",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "submission",
-          "isMut": true,
+          "name": "newContentDataHash",
+          "isMut": false,
           "isSigner": false
         },
         {
@@ -4547,937 +4767,7263 @@ export type Forum = {
           "type": "u8"
         },
         {
-          "name": "bumpChallenge",
-          "type": "u8"
-        },
-        {
-          "name": "bumpSubmission",
+          "name": "bumpProposedContribution",
           "type": "u8"
-        },
-        {
-          "name": "submissionState",
-          "type": {
-            "defined": "SubmissionState"
-          }
         }
       ]
     },
     {
-      "name": "closeAccount",
+      "name": "deleteProposedContribution",
       "accounts": [
         {
-          "name": "signer",
+          "name": "forum",
           "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "profileOwner",
+          "isMut": false,
           "isSigner": true
         },
         {
-          "name": "accountToClose",
+          "name": "userProfile",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "systemProgram",
-          "isMut": false,
+          "name": "bigNote",
+          "isMut": true,
           "isSigner": false
-        }
-      ],
-      "args": []
-    }
-  ],
-  "accounts": [
+        },
+        {
+          "name": "proposedContribution",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "proposedContributionSeed",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "receiver",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpUserProfile",
+          "type": "u8"
+        },
+        {
+          "name": "bumpProposedContribution",
+          "type": "u8"
+        }
+      ]
+    },
     {
-      "name": "aboutMe",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "userProfile",
-            "type": "publicKey"
-          },
-          {
-            "name": "aboutMeCreatedTs",
-            "type": "u64"
-          },
-          {
-            "name": "mostRecentUpdateTs",
-            "type": "u64"
-          },
-          {
-            "name": "contentDataHash",
-            "type": "publicKey"
-          }
-        ]
-      }
+      "name": "deleteProposedContributionModerator",
+      "accounts": [
+        {
+          "name": "forum",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "moderator",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "moderatorProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "profileOwner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "userProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bigNote",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "proposedContribution",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "proposedContributionSeed",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "receiver",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpModeratorProfile",
+          "type": "u8"
+        },
+        {
+          "name": "bumpUserProfile",
+          "type": "u8"
+        },
+        {
+          "name": "bumpProposedContribution",
+          "type": "u8"
+        }
+      ]
     },
     {
-      "name": "answer",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "question",
-            "type": "publicKey"
-          },
-          {
-            "name": "userProfile",
-            "type": "publicKey"
-          },
-          {
-            "name": "answerSeed",
-            "type": "publicKey"
-          },
-          {
-            "name": "answerPostedTs",
-            "type": "u64"
-          },
-          {
-            "name": "mostRecentEngagementTs",
-            "type": "u64"
-          },
-          {
-            "name": "contentDataHash",
-            "type": "publicKey"
-          },
-          {
-            "name": "answerRep",
-            "type": "u64"
-          },
-          {
-            "name": "acceptedAnswer",
-            "type": "bool"
-          },
-          {
-            "name": "acceptedAnswerRep",
-            "type": "u64"
+      "name": "createChallenge",
+      "accounts": [
+        {
+          "name": "forum",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "moderator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "moderatorProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "category",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "challenge",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "challengeSeed",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "contentDataHash",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpModeratorProfile",
+          "type": "u8"
+        },
+        {
+          "name": "tags",
+          "type": {
+            "vec": {
+              "defined": "Tags"
+            }
           }
-        ]
-      }
+        },
+        {
+          "name": "title",
+          "type": "string"
+        },
+        {
+          "name": "contentDataUrl",
+          "type": "string"
+        },
+        {
+          "name": "challengeExpiresTs",
+          "type": "u64"
+        },
+        {
+          "name": "reputation",
+          "type": "u64"
+        },
+        {
+          "name": "rubric",
+          "type": {
+            "vec": {
+              "defined": "RubricCriterion"
+            }
+          }
+        }
+      ]
     },
     {
-      "name": "bigNoteVerificationApplication",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "bigNote",
-            "type": "publicKey"
-          },
-          {
-            "name": "verificationFeePda",
-            "type": "publicKey"
-          },
-          {
-            "name": "verificationFee",
-            "type": "u64"
-          }
-        ]
-      }
-    },
-    {
-      "name": "bigNote",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "forum",
-            "type": "publicKey"
-          },
-          {
-            "name": "userProfile",
-            "type": "publicKey"
-          },
-          {
-            "name": "bigNoteSeed",
-            "type": "publicKey"
-          },
-          {
-            "name": "bigNoteCreatedTs",
-            "type": "u64"
-          },
-          {
-            "name": "mostRecentEngagementTs",
-            "type": "u64"
-          },
-          {
-            "name": "bountyAmount",
-            "type": "u64"
-          },
-          {
-            "name": "bountyContributions",
-            "type": {
-              "vec": {
-                "defined": "BountyContribution"
-              }
-            }
-          },
-          {
-            "name": "bigNoteType",
-            "type": {
-              "defined": "BigNoteType"
-            }
-          },
-          {
-            "name": "verificationState",
-            "type": {
-              "defined": "BigNoteVerificationState"
-            }
-          },
-          {
-            "name": "tags",
-            "type": {
-              "vec": {
-                "defined": "Tags"
-              }
+      "name": "proposeChallenge",
+      "accounts": [
+        {
+          "name": "forum",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "profileOwner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "userProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "category",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "challenge",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "challengeSeed",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "contentDataHash",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "challengeProposal",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpUserProfile",
+          "type": "u8"
+        },
+        {
+          "name": "tags",
+          "type": {
+            "vec": {
+              "defined": "Tags"
             }
-          },
-          {
-            "name": "title",
-            "type": "string"
-          },
-          {
-            "name": "contentDataUrl",
-            "type": "string"
-          },
-          {
-            "name": "contentDataHash",
-            "type": "publicKey"
-          },
-          {
-            "name": "bigNoteCreationRep",
-            "type": "u64"
-          },
-          {
-            "name": "bigNoteVerificationRep",
-            "type": "u64"
-          },
-          {
-            "name": "bountyAwarded",
-            "type": "bool"
           }
-        ]
-      }
-    },
-    {
-      "name": "challenge",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "forum",
-            "type": "publicKey"
-          },
-          {
-            "name": "challengeSeed",
-            "type": "publicKey"
-          },
-          {
-            "name": "challengePostedTs",
-            "type": "u64"
-          },
-          {
-            "name": "challengeExpiresTs",
-            "type": "u64"
-          },
-          {
-            "name": "tags",
-            "type": {
-              "vec": {
-                "defined": "Tags"
-              }
+        },
+        {
+          "name": "title",
+          "type": "string"
+        },
+        {
+          "name": "contentDataUrl",
+          "type": "string"
+        },
+        {
+          "name": "challengeExpiresTs",
+          "type": "u64"
+        },
+        {
+          "name": "reputation",
+          "type": "u64"
+        },
+        {
+          "name": "rubric",
+          "type": {
+            "vec": {
+              "defined": "RubricCriterion"
             }
-          },
-          {
-            "name": "title",
-            "type": "string"
-          },
-          {
-            "name": "contentDataUrl",
-            "type": "string"
-          },
-          {
-            "name": "contentDataHash",
-            "type": "publicKey"
-          },
-          {
-            "name": "reputation",
-            "type": "u64"
-          }
-        ]
-      }
-    },
-    {
-      "name": "comment",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "commentedOn",
-            "type": "publicKey"
-          },
-          {
-            "name": "userProfile",
-            "type": "publicKey"
-          },
-          {
-            "name": "commentSeed",
-            "type": "publicKey"
-          },
-          {
-            "name": "commentPostedTs",
-            "type": "u64"
-          },
-          {
-            "name": "mostRecentEngagementTs",
-            "type": "u64"
-          },
-          {
-            "name": "contentDataHash",
-            "type": "publicKey"
-          },
-          {
-            "name": "commentRep",
-            "type": "u64"
           }
-        ]
-      }
+        }
+      ]
     },
     {
-      "name": "forum",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "version",
-            "type": "u16"
-          },
-          {
-            "name": "forumManager",
-            "type": "publicKey"
-          },
-          {
-            "name": "forumAuthority",
-            "type": "publicKey"
-          },
-          {
-            "name": "forumAuthoritySeed",
-            "type": "publicKey"
-          },
-          {
-            "name": "forumAuthorityBumpSeed",
-            "type": {
-              "array": [
-                "u8",
-                1
-              ]
-            }
-          },
-          {
-            "name": "forumTreasury",
-            "type": "publicKey"
-          },
-          {
-            "name": "forumFees",
-            "type": {
-              "defined": "ForumFees"
-            }
-          },
-          {
-            "name": "forumConstants",
-            "type": {
-              "defined": "ForumConstants"
-            }
-          },
-          {
-            "name": "forumCounts",
-            "type": {
-              "defined": "ForumCounts"
-            }
-          },
-          {
-            "name": "reputationMatrix",
-            "type": {
-              "defined": "ReputationMatrix"
+      "name": "approveChallengeProposal",
+      "accounts": [
+        {
+          "name": "forum",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "moderator",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "moderatorProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "author",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authorProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "challenge",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "category",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "challengeSeed",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "challengeProposal",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpModeratorProfile",
+          "type": "u8"
+        },
+        {
+          "name": "bumpAuthorProfile",
+          "type": "u8"
+        },
+        {
+          "name": "bumpChallenge",
+          "type": "u8"
+        },
+        {
+          "name": "bumpChallengeProposal",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "rejectChallengeProposal",
+      "accounts": [
+        {
+          "name": "forum",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "moderator",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "moderatorProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "forumTreasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "author",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authorProfile",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "challenge",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "category",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "challengeSeed",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "challengeProposal",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpModeratorProfile",
+          "type": "u8"
+        },
+        {
+          "name": "bumpAuthorProfile",
+          "type": "u8"
+        },
+        {
+          "name": "bumpChallenge",
+          "type": "u8"
+        },
+        {
+          "name": "bumpChallengeProposal",
+          "type": "u8"
+        },
+        {
+          "name": "slashDeposit",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "editChallenge",
+      "accounts": [
+        {
+          "name": "forum",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "moderator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "moderatorProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "challenge",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "category",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "challengeSeed",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "newContentDataHash",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpModeratorProfile",
+          "type": "u8"
+        },
+        {
+          "name": "bumpChallenge",
+          "type": "u8"
+        },
+        {
+          "name": "newTags",
+          "type": {
+            "vec": {
+              "defined": "Tags"
             }
           }
-        ]
-      }
+        },
+        {
+          "name": "newTitle",
+          "type": "string"
+        },
+        {
+          "name": "newContentDataUrl",
+          "type": "string"
+        },
+        {
+          "name": "newChallengeExpiresTs",
+          "type": "u64"
+        },
+        {
+          "name": "newReputation",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "deleteChallenge",
+      "accounts": [
+        {
+          "name": "forum",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "moderator",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "moderatorProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "challenge",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "challengeSeed",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "challengeProposal",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "category",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "prizePool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "trackMembership",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "receiver",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpModeratorProfile",
+          "type": "u8"
+        },
+        {
+          "name": "bumpChallenge",
+          "type": "u8"
+        },
+        {
+          "name": "bumpPrizePool",
+          "type": "u8"
+        },
+        {
+          "name": "bumpChallengeProposal",
+          "type": "u8"
+        },
+        {
+          "name": "bumpTrackMembership",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "closeChallenge",
+      "accounts": [
+        {
+          "name": "forum",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "cranker",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "challenge",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "challengeSeed",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpChallenge",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "archiveChallenge",
+      "accounts": [
+        {
+          "name": "forum",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "moderator",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "moderatorProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "challenge",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "category",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "challengeSeed",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpModeratorProfile",
+          "type": "u8"
+        },
+        {
+          "name": "bumpChallenge",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "createChallengePrizePool",
+      "accounts": [
+        {
+          "name": "forum",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "moderator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "moderatorProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "challenge",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "category",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "challengeSeed",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "challengeProposal",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "prizePool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpModeratorProfile",
+          "type": "u8"
+        },
+        {
+          "name": "bumpChallenge",
+          "type": "u8"
+        },
+        {
+          "name": "bumpChallengeProposal",
+          "type": "u8"
+        },
+        {
+          "name": "prizeDistribution",
+          "type": {
+            "defined": "PrizeDistribution"
+          }
+        },
+        {
+          "name": "winnerCount",
+          "type": "u64"
+        },
+        {
+          "name": "prizePerWinner",
+          "type": "u64"
+        },
+        {
+          "name": "tierBps",
+          "type": {
+            "vec": "u16"
+          }
+        }
+      ]
+    },
+    {
+      "name": "createChallengePrizeVault",
+      "accounts": [
+        {
+          "name": "forum",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "moderator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "moderatorProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "challenge",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "category",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "challengeSeed",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "prizePool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "prizeMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "prizeVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpModeratorProfile",
+          "type": "u8"
+        },
+        {
+          "name": "bumpChallenge",
+          "type": "u8"
+        },
+        {
+          "name": "bumpPrizePool",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "fundChallengePrizePool",
+      "accounts": [
+        {
+          "name": "forum",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sponsor",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "sponsorProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "challenge",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "challengeSeed",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "challengeProposal",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "prizePool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "prizeContribution",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpSponsorProfile",
+          "type": "u8"
+        },
+        {
+          "name": "bumpChallenge",
+          "type": "u8"
+        },
+        {
+          "name": "bumpPrizePool",
+          "type": "u8"
+        },
+        {
+          "name": "bumpChallengeProposal",
+          "type": "u8"
+        },
+        {
+          "name": "prizeAmount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "refundChallengePrizeContribution",
+      "accounts": [
+        {
+          "name": "forum",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sponsor",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "sponsorProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "challenge",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "challengeSeed",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "prizePool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "prizeContribution",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "leaderboard",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpSponsorProfile",
+          "type": "u8"
+        },
+        {
+          "name": "bumpChallenge",
+          "type": "u8"
+        },
+        {
+          "name": "bumpPrizePool",
+          "type": "u8"
+        },
+        {
+          "name": "bumpPrizeContribution",
+          "type": "u8"
+        },
+        {
+          "name": "bumpLeaderboard",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "createEvaluationPanel",
+      "accounts": [
+        {
+          "name": "forum",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "moderator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "moderatorProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "challenge",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "category",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "challengeSeed",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "challengeProposal",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "evaluationPanel",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpModeratorProfile",
+          "type": "u8"
+        },
+        {
+          "name": "bumpChallenge",
+          "type": "u8"
+        },
+        {
+          "name": "bumpChallengeProposal",
+          "type": "u8"
+        },
+        {
+          "name": "judges",
+          "type": {
+            "vec": "publicKey"
+          }
+        },
+        {
+          "name": "quorum",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "createChallengeLeaderboard",
+      "accounts": [
+        {
+          "name": "forum",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "moderator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "moderatorProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "challenge",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "category",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "challengeSeed",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "leaderboard",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpModeratorProfile",
+          "type": "u8"
+        },
+        {
+          "name": "bumpChallenge",
+          "type": "u8"
+        },
+        {
+          "name": "maxEntries",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "closeChallengeLeaderboard",
+      "accounts": [
+        {
+          "name": "forum",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "cranker",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "challenge",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "challengeSeed",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "leaderboard",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "prizePool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpChallenge",
+          "type": "u8"
+        },
+        {
+          "name": "bumpLeaderboard",
+          "type": "u8"
+        },
+        {
+          "name": "bumpPrizePool",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "createTrack",
+      "accounts": [
+        {
+          "name": "forum",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "moderator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "moderatorProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "track",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "trackSeed",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpModeratorProfile",
+          "type": "u8"
+        },
+        {
+          "name": "completionRep",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "addChallengeToTrack",
+      "accounts": [
+        {
+          "name": "forum",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "moderator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "moderatorProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "track",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "trackSeed",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "challenge",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "challengeSeed",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "challengeProposal",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "trackMembership",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpModeratorProfile",
+          "type": "u8"
+        },
+        {
+          "name": "bumpTrack",
+          "type": "u8"
+        },
+        {
+          "name": "bumpChallenge",
+          "type": "u8"
+        },
+        {
+          "name": "bumpChallengeProposal",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "removeChallengeFromTrack",
+      "accounts": [
+        {
+          "name": "forum",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "moderator",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "moderatorProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "track",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "trackSeed",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "trackMembership",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "receiver",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpModeratorProfile",
+          "type": "u8"
+        },
+        {
+          "name": "bumpTrack",
+          "type": "u8"
+        },
+        {
+          "name": "bumpTrackMembership",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "claimTrackCompletion",
+      "accounts": [
+        {
+          "name": "forum",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "profileOwner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "userProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "track",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "trackSeed",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "trackCompletion",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpUserProfile",
+          "type": "u8"
+        },
+        {
+          "name": "bumpTrack",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "createSubmission",
+      "accounts": [
+        {
+          "name": "forum",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "profileOwner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "userProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "challenge",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "category",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "challengeSeed",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "challengeProposal",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "trackMembership",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "submission",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "submissionFeeEscrow",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "contentDataHash",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpUserProfile",
+          "type": "u8"
+        },
+        {
+          "name": "bumpChallenge",
+          "type": "u8"
+        },
+        {
+          "name": "bumpChallengeProposal",
+          "type": "u8"
+        },
+        {
+          "name": "bumpTrackMembership",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "editSubmission",
+      "accounts": [
+        {
+          "name": "forum",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "profileOwner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "userProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "challenge",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "challengeSeed",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "submission",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "newContentDataHash",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpUserProfile",
+          "type": "u8"
+        },
+        {
+          "name": "bumpChallenge",
+          "type": "u8"
+        },
+        {
+          "name": "bumpSubmission",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "resubmit",
+      "accounts": [
+        {
+          "name": "forum",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "profileOwner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "userProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "challenge",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "category",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "challengeSeed",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "submission",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "submissionAttempt",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "submissionFeeEscrow",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "contentDataHash",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpUserProfile",
+          "type": "u8"
+        },
+        {
+          "name": "bumpChallenge",
+          "type": "u8"
+        },
+        {
+          "name": "bumpSubmission",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "editSubmissionModerator",
+      "accounts": [
+        {
+          "name": "forum",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "moderator",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "moderatorProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "profileOwner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "userProfile",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "challenge",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "category",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "challengeSeed",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "submission",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "newContentDataHash",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpModeratorProfile",
+          "type": "u8"
+        },
+        {
+          "name": "bumpUserProfile",
+          "type": "u8"
+        },
+        {
+          "name": "bumpChallenge",
+          "type": "u8"
+        },
+        {
+          "name": "bumpSubmission",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "deleteSubmission",
+      "accounts": [
+        {
+          "name": "forum",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "profileOwner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "userProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "challenge",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "challengeSeed",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "submission",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "submissionFeeEscrow",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "receiver",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpUserProfile",
+          "type": "u8"
+        },
+        {
+          "name": "bumpChallenge",
+          "type": "u8"
+        },
+        {
+          "name": "bumpSubmission",
+          "type": "u8"
+        },
+        {
+          "name": "bumpSubmissionFeeEscrow",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "deleteSubmissionModerator",
+      "accounts": [
+        {
+          "name": "forum",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "moderator",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "moderatorProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "profileOwner",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "challenge",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "category",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "challengeSeed",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "submission",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "submissionFeeEscrow",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "receiver",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpModeratorProfile",
+          "type": "u8"
+        },
+        {
+          "name": "bumpUserProfile",
+          "type": "u8"
+        },
+        {
+          "name": "bumpChallenge",
+          "type": "u8"
+        },
+        {
+          "name": "bumpSubmission",
+          "type": "u8"
+        },
+        {
+          "name": "bumpSubmissionFeeEscrow",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "refundSubmissionFee",
+      "accounts": [
+        {
+          "name": "forum",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "profileOwner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "userProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "challenge",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "challengeSeed",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "submission",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "submissionFeeEscrow",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpUserProfile",
+          "type": "u8"
+        },
+        {
+          "name": "bumpChallenge",
+          "type": "u8"
+        },
+        {
+          "name": "bumpSubmission",
+          "type": "u8"
+        },
+        {
+          "name": "bumpSubmissionFeeEscrow",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "evaluateSubmission",
+      "accounts": [
+        {
+          "name": "forum",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "forumTreasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "judge",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "judgeProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "profileOwner",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "challenge",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "category",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "challengeSeed",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "evaluationPanel",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "submission",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "evaluationVote",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "submissionFeeEscrow",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "prizePool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpJudgeProfile",
+          "type": "u8"
+        },
+        {
+          "name": "bumpUserProfile",
+          "type": "u8"
+        },
+        {
+          "name": "bumpChallenge",
+          "type": "u8"
+        },
+        {
+          "name": "bumpEvaluationPanel",
+          "type": "u8"
+        },
+        {
+          "name": "bumpSubmission",
+          "type": "u8"
+        },
+        {
+          "name": "bumpPrizePool",
+          "type": "u8"
+        },
+        {
+          "name": "bumpSubmissionFeeEscrow",
+          "type": "u8"
+        },
+        {
+          "name": "verdict",
+          "type": {
+            "defined": "SubmissionState"
+          }
+        }
+      ]
+    },
+    {
+      "name": "peerReviewSubmission",
+      "accounts": [
+        {
+          "name": "forum",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "reviewer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "reviewerProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "challenge",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "challengeSeed",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "reviewerSubmission",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "submission",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "peerReview",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpReviewerProfile",
+          "type": "u8"
+        },
+        {
+          "name": "bumpChallenge",
+          "type": "u8"
+        },
+        {
+          "name": "bumpReviewerSubmission",
+          "type": "u8"
+        },
+        {
+          "name": "bumpSubmission",
+          "type": "u8"
+        },
+        {
+          "name": "verdict",
+          "type": {
+            "defined": "SubmissionState"
+          }
+        }
+      ]
+    },
+    {
+      "name": "settlePeerReview",
+      "accounts": [
+        {
+          "name": "forum",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "cranker",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "reviewerProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "submission",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "peerReview",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "submissionAttempt",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpSubmissionAttempt",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "scoreSubmission",
+      "accounts": [
+        {
+          "name": "forum",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "judge",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "judgeProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "challenge",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "challengeSeed",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "evaluationPanel",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "submission",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "scoreCard",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "leaderboard",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpJudgeProfile",
+          "type": "u8"
+        },
+        {
+          "name": "bumpChallenge",
+          "type": "u8"
+        },
+        {
+          "name": "bumpEvaluationPanel",
+          "type": "u8"
+        },
+        {
+          "name": "bumpSubmission",
+          "type": "u8"
+        },
+        {
+          "name": "bumpLeaderboard",
+          "type": "u8"
+        },
+        {
+          "name": "scores",
+          "type": {
+            "vec": "u64"
+          }
+        }
+      ]
+    },
+    {
+      "name": "createContentLink",
+      "accounts": [
+        {
+          "name": "forum",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "moderator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "moderatorProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sourceContent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "targetContent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "contentLink",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpModeratorProfile",
+          "type": "u8"
+        },
+        {
+          "name": "sourceContentType",
+          "type": {
+            "defined": "LinkedContentType"
+          }
+        },
+        {
+          "name": "targetContentType",
+          "type": {
+            "defined": "LinkedContentType"
+          }
+        },
+        {
+          "name": "relationType",
+          "type": {
+            "defined": "ContentRelationType"
+          }
+        }
+      ]
+    },
+    {
+      "name": "deleteContentLink",
+      "accounts": [
+        {
+          "name": "forum",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "moderator",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "moderatorProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "contentLink",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "receiver",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpModeratorProfile",
+          "type": "u8"
+        },
+        {
+          "name": "bumpContentLink",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "closeAccount",
+      "accounts": [
+        {
+          "name": "signer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "accountToClose",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    }
+  ],
+  "accounts": [
+    {
+      "name": "aboutMe",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "userProfile",
+            "type": "publicKey"
+          },
+          {
+            "name": "aboutMeCreatedTs",
+            "type": "u64"
+          },
+          {
+            "name": "mostRecentUpdateTs",
+            "type": "u64"
+          },
+          {
+            "name": "contentDataHash",
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "answer",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "question",
+            "type": "publicKey"
+          },
+          {
+            "name": "userProfile",
+            "type": "publicKey"
+          },
+          {
+            "name": "answerSeed",
+            "type": "publicKey"
+          },
+          {
+            "name": "answerPostedTs",
+            "type": "u64"
+          },
+          {
+            "name": "mostRecentEngagementTs",
+            "type": "u64"
+          },
+          {
+            "name": "contentDataHash",
+            "type": "publicKey"
+          },
+          {
+            "name": "answerRep",
+            "type": "u64"
+          },
+          {
+            "name": "acceptedAnswer",
+            "type": "bool"
+          },
+          {
+            "name": "acceptedAnswerRep",
+            "type": "u64"
+          },
+          {
+            "name": "revisionCount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "bigNoteFork",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "parentBigNote",
+            "type": "publicKey"
+          },
+          {
+            "name": "forkBigNote",
+            "type": "publicKey"
+          },
+          {
+            "name": "parentContentDataHash",
+            "type": "publicKey"
+          },
+          {
+            "name": "forkedTs",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "bigNoteVerificationApplication",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bigNote",
+            "type": "publicKey"
+          },
+          {
+            "name": "verificationFeePda",
+            "type": "publicKey"
+          },
+          {
+            "name": "verificationFee",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "bigNoteVerificationRecord",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bigNote",
+            "type": "publicKey"
+          },
+          {
+            "name": "verificationRound",
+            "type": "u64"
+          },
+          {
+            "name": "attestationCount",
+            "type": "u64"
+          },
+          {
+            "name": "quorum",
+            "type": "u64"
+          },
+          {
+            "name": "verifiedTs",
+            "type": "u64"
+          },
+          {
+            "name": "verificationExpiresTs",
+            "type": "u64"
+          },
+          {
+            "name": "reverificationTs",
+            "type": "u64"
+          },
+          {
+            "name": "slashableRound",
+            "type": "u64"
+          },
+          {
+            "name": "slashableUntilTs",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "bigNote",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "forum",
+            "type": "publicKey"
+          },
+          {
+            "name": "userProfile",
+            "type": "publicKey"
+          },
+          {
+            "name": "bigNoteSeed",
+            "type": "publicKey"
+          },
+          {
+            "name": "bigNoteCreatedTs",
+            "type": "u64"
+          },
+          {
+            "name": "mostRecentEngagementTs",
+            "type": "u64"
+          },
+          {
+            "name": "bountyAmount",
+            "type": "u64"
+          },
+          {
+            "name": "bountyContributions",
+            "type": {
+              "vec": {
+                "defined": "BountyContribution"
+              }
+            }
+          },
+          {
+            "name": "bigNoteType",
+            "type": {
+              "defined": "BigNoteType"
+            }
+          },
+          {
+            "name": "verificationState",
+            "type": {
+              "defined": "BigNoteVerificationState"
+            }
+          },
+          {
+            "name": "tags",
+            "type": {
+              "vec": {
+                "defined": "Tags"
+              }
+            }
+          },
+          {
+            "name": "title",
+            "type": "string"
+          },
+          {
+            "name": "contentDataUrl",
+            "type": "string"
+          },
+          {
+            "name": "contentDataHash",
+            "type": "publicKey"
+          },
+          {
+            "name": "bigNoteCreationRep",
+            "type": "u64"
+          },
+          {
+            "name": "bigNoteVerificationRep",
+            "type": "u64"
+          },
+          {
+            "name": "bountyAwarded",
+            "type": "bool"
+          },
+          {
+            "name": "revisionCount",
+            "type": "u64"
+          },
+          {
+            "name": "sourceQuestion",
+            "type": "publicKey"
+          },
+          {
+            "name": "sourceAnswer",
+            "type": "publicKey"
+          },
+          {
+            "name": "forkCount",
+            "type": "u64"
+          },
+          {
+            "name": "successor",
+            "type": "publicKey"
+          },
+          {
+            "name": "currentOwner",
+            "type": "publicKey"
+          },
+          {
+            "name": "pendingOwner",
+            "type": "publicKey"
+          },
+          {
+            "name": "category",
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "category",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "forum",
+            "type": "publicKey"
+          },
+          {
+            "name": "categorySeed",
+            "type": "publicKey"
+          },
+          {
+            "name": "categoryCreatedTs",
+            "type": "u64"
+          },
+          {
+            "name": "categoryModerators",
+            "type": {
+              "array": [
+                "publicKey",
+                5
+              ]
+            }
+          },
+          {
+            "name": "moderatorCount",
+            "type": "u64"
+          },
+          {
+            "name": "categoryFees",
+            "type": {
+              "defined": "ForumFees"
+            }
+          },
+          {
+            "name": "questionCount",
+            "type": "u64"
+          },
+          {
+            "name": "bigNotesCount",
+            "type": "u64"
+          },
+          {
+            "name": "challengeCount",
+            "type": "u64"
+          },
+          {
+            "name": "feeOverridesEnabled",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "challengeLeaderboard",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "challenge",
+            "type": "publicKey"
+          },
+          {
+            "name": "entries",
+            "type": {
+              "array": [
+                {
+                  "defined": "LeaderboardEntry"
+                },
+                10
+              ]
+            }
+          },
+          {
+            "name": "maxEntries",
+            "type": "u64"
+          },
+          {
+            "name": "entryCount",
+            "type": "u64"
+          },
+          {
+            "name": "isClosed",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "challengePrizePool",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "challenge",
+            "type": "publicKey"
+          },
+          {
+            "name": "prizeMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "totalFunded",
+            "type": "u64"
+          },
+          {
+            "name": "totalPaid",
+            "type": "u64"
+          },
+          {
+            "name": "totalRefunded",
+            "type": "u64"
+          },
+          {
+            "name": "sponsorCount",
+            "type": "u64"
+          },
+          {
+            "name": "winnerCount",
+            "type": "u64"
+          },
+          {
+            "name": "winnersPaid",
+            "type": "u64"
+          },
+          {
+            "name": "prizePerWinner",
+            "type": "u64"
+          },
+          {
+            "name": "tierBps",
+            "type": {
+              "array": [
+                "u16",
+                8
+              ]
+            }
+          },
+          {
+            "name": "prizeDistribution",
+            "type": {
+              "defined": "PrizeDistribution"
+            }
+          },
+          {
+            "name": "payoutsClosed",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "challengeProposal",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "forum",
+            "type": "publicKey"
+          },
+          {
+            "name": "challenge",
+            "type": "publicKey"
+          },
+          {
+            "name": "authorProfile",
+            "type": "publicKey"
+          },
+          {
+            "name": "proposalPostedTs",
+            "type": "u64"
+          },
+          {
+            "name": "proposalReviewedTs",
+            "type": "u64"
+          },
+          {
+            "name": "depositAmount",
+            "type": "u64"
+          },
+          {
+            "name": "proposalState",
+            "type": {
+              "defined": "ChallengeProposalState"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "challenge",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "forum",
+            "type": "publicKey"
+          },
+          {
+            "name": "challengeSeed",
+            "type": "publicKey"
+          },
+          {
+            "name": "challengePostedTs",
+            "type": "u64"
+          },
+          {
+            "name": "challengeExpiresTs",
+            "type": "u64"
+          },
+          {
+            "name": "tags",
+            "type": {
+              "vec": {
+                "defined": "Tags"
+              }
+            }
+          },
+          {
+            "name": "title",
+            "type": "string"
+          },
+          {
+            "name": "contentDataUrl",
+            "type": "string"
+          },
+          {
+            "name": "contentDataHash",
+            "type": "publicKey"
+          },
+          {
+            "name": "reputation",
+            "type": "u64"
+          },
+          {
+            "name": "rubric",
+            "type": {
+              "vec": {
+                "defined": "RubricCriterion"
+              }
+            }
+          },
+          {
+            "name": "challengeState",
+            "type": {
+              "defined": "ChallengeState"
+            }
+          },
+          {
+            "name": "judgingEndsTs",
+            "type": "u64"
+          },
+          {
+            "name": "submissionCount",
+            "type": "u64"
+          },
+          {
+            "name": "completionCount",
+            "type": "u64"
+          },
+          {
+            "name": "finalSubmissionCount",
+            "type": "u64"
+          },
+          {
+            "name": "finalCompletionCount",
+            "type": "u64"
+          },
+          {
+            "name": "category",
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "comment",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "commentedOn",
+            "type": "publicKey"
+          },
+          {
+            "name": "userProfile",
+            "type": "publicKey"
+          },
+          {
+            "name": "commentSeed",
+            "type": "publicKey"
+          },
+          {
+            "name": "commentPostedTs",
+            "type": "u64"
+          },
+          {
+            "name": "mostRecentEngagementTs",
+            "type": "u64"
+          },
+          {
+            "name": "contentDataHash",
+            "type": "publicKey"
+          },
+          {
+            "name": "commentRep",
+            "type": "u64"
+          },
+          {
+            "name": "commentDepth",
+            "type": "u64"
+          },
+          {
+            "name": "replyCount",
+            "type": "u64"
+          },
+          {
+            "name": "commentTarget",
+            "type": {
+              "defined": "CommentTarget"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "contentLink",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "forum",
+            "type": "publicKey"
+          },
+          {
+            "name": "moderatorProfile",
+            "type": "publicKey"
+          },
+          {
+            "name": "sourceContent",
+            "type": "publicKey"
+          },
+          {
+            "name": "targetContent",
+            "type": "publicKey"
+          },
+          {
+            "name": "contentLinkPostedTs",
+            "type": "u64"
+          },
+          {
+            "name": "sourceContentType",
+            "type": {
+              "defined": "LinkedContentType"
+            }
+          },
+          {
+            "name": "targetContentType",
+            "type": {
+              "defined": "LinkedContentType"
+            }
+          },
+          {
+            "name": "relationType",
+            "type": {
+              "defined": "ContentRelationType"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "evaluationPanel",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "challenge",
+            "type": "publicKey"
+          },
+          {
+            "name": "judges",
+            "type": {
+              "array": [
+                "publicKey",
+                8
+              ]
+            }
+          },
+          {
+            "name": "judgeCount",
+            "type": "u64"
+          },
+          {
+            "name": "quorum",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "evaluationVote",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "submission",
+            "type": "publicKey"
+          },
+          {
+            "name": "judgeProfile",
+            "type": "publicKey"
+          },
+          {
+            "name": "voteTs",
+            "type": "u64"
+          },
+          {
+            "name": "verdict",
+            "type": {
+              "defined": "SubmissionState"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "follow",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "followerProfile",
+            "type": "publicKey"
+          },
+          {
+            "name": "followedProfile",
+            "type": "publicKey"
+          },
+          {
+            "name": "followedTs",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "forumAccessPolicy",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "forum",
+            "type": "publicKey"
+          },
+          {
+            "name": "accessMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "minTokenAmount",
+            "type": "u64"
+          },
+          {
+            "name": "recheckPeriod",
+            "type": "u64"
+          },
+          {
+            "name": "accessPolicyType",
+            "type": {
+              "defined": "AccessPolicyType"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "forumAllowlistEntry",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "forum",
+            "type": "publicKey"
+          },
+          {
+            "name": "wallet",
+            "type": "publicKey"
+          },
+          {
+            "name": "entryAddedTs",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "forum",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "version",
+            "type": "u16"
+          },
+          {
+            "name": "forumManager",
+            "type": "publicKey"
+          },
+          {
+            "name": "forumAuthority",
+            "type": "publicKey"
+          },
+          {
+            "name": "forumAuthoritySeed",
+            "type": "publicKey"
+          },
+          {
+            "name": "forumAuthorityBumpSeed",
+            "type": {
+              "array": [
+                "u8",
+                1
+              ]
+            }
+          },
+          {
+            "name": "forumTreasury",
+            "type": "publicKey"
+          },
+          {
+            "name": "forumFees",
+            "type": {
+              "defined": "ForumFees"
+            }
+          },
+          {
+            "name": "forumConstants",
+            "type": {
+              "defined": "ForumConstants"
+            }
+          },
+          {
+            "name": "forumCounts",
+            "type": {
+              "defined": "ForumCounts"
+            }
+          },
+          {
+            "name": "reputationMatrix",
+            "type": {
+              "defined": "ReputationMatrix"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "handle",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "forum",
+            "type": "publicKey"
+          },
+          {
+            "name": "userProfile",
+            "type": "publicKey"
+          },
+          {
+            "name": "handleClaimedTs",
+            "type": "u64"
+          },
+          {
+            "name": "handleName",
+            "type": "string"
+          }
+        ]
+      }
+    },
+    {
+      "name": "peerReview",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "submission",
+            "type": "publicKey"
+          },
+          {
+            "name": "reviewerProfile",
+            "type": "publicKey"
+          },
+          {
+            "name": "attemptNumber",
+            "type": "u64"
+          },
+          {
+            "name": "reviewTs",
+            "type": "u64"
+          },
+          {
+            "name": "verdict",
+            "type": {
+              "defined": "SubmissionState"
+            }
+          },
+          {
+            "name": "isSettled",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "pfpCollectionAllowlist",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "forum",
+            "type": "publicKey"
+          },
+          {
+            "name": "collections",
+            "type": {
+              "array": [
+                "publicKey",
+                10
+              ]
+            }
+          },
+          {
+            "name": "collectionCount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "prizeContribution",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "prizePool",
+            "type": "publicKey"
+          },
+          {
+            "name": "sponsorProfile",
+            "type": "publicKey"
+          },
+          {
+            "name": "mostRecentContributionTs",
+            "type": "u64"
+          },
+          {
+            "name": "contributedAmount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "proposedContribution",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bigNote",
+            "type": "publicKey"
+          },
+          {
+            "name": "userProfile",
+            "type": "publicKey"
+          },
+          {
+            "name": "proposedContributionSeed",
+            "type": "publicKey"
+          },
+          {
+            "name": "proposedContributionPostedTs",
+            "type": "u64"
+          },
+          {
+            "name": "mostRecentEngagementTs",
+            "type": "u64"
+          },
+          {
+            "name": "contentDataHash",
+            "type": "publicKey"
+          },
+          {
+            "name": "proposedContributionRep",
+            "type": "u64"
+          },
+          {
+            "name": "proposedContributionState",
+            "type": {
+              "defined": "ProposedContributionState"
+            }
+          },
+          {
+            "name": "acceptedContributionProposalRep",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "question",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "forum",
+            "type": "publicKey"
+          },
+          {
+            "name": "userProfile",
+            "type": "publicKey"
+          },
+          {
+            "name": "questionSeed",
+            "type": "publicKey"
+          },
+          {
+            "name": "questionPostedTs",
+            "type": "u64"
+          },
+          {
+            "name": "mostRecentEngagementTs",
+            "type": "u64"
+          },
+          {
+            "name": "bountyAmount",
+            "type": "u64"
+          },
+          {
+            "name": "bountyContributions",
+            "type": {
+              "vec": {
+                "defined": "BountyContribution"
+              }
+            }
+          },
+          {
+            "name": "tags",
+            "type": {
+              "vec": {
+                "defined": "Tags"
+              }
+            }
+          },
+          {
+            "name": "title",
+            "type": "string"
+          },
+          {
+            "name": "contentDataUrl",
+            "type": "string"
+          },
+          {
+            "name": "contentDataHash",
+            "type": "publicKey"
+          },
+          {
+            "name": "questionRep",
+            "type": "u64"
+          },
+          {
+            "name": "bountyAwarded",
+            "type": "bool"
+          },
+          {
+            "name": "revisionCount",
+            "type": "u64"
+          },
+          {
+            "name": "duplicateOf",
+            "type": "publicKey"
+          },
+          {
+            "name": "currentOwner",
+            "type": "publicKey"
+          },
+          {
+            "name": "pendingOwner",
+            "type": "publicKey"
+          },
+          {
+            "name": "category",
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "revision",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "content",
+            "type": "publicKey"
+          },
+          {
+            "name": "editorProfile",
+            "type": "publicKey"
+          },
+          {
+            "name": "revisionNumber",
+            "type": "u64"
+          },
+          {
+            "name": "revisionTs",
+            "type": "u64"
+          },
+          {
+            "name": "previousContentDataHash",
+            "type": "publicKey"
+          },
+          {
+            "name": "moderatorEdit",
+            "type": "bool"
+          },
+          {
+            "name": "previousTitle",
+            "type": "string"
+          },
+          {
+            "name": "previousContentDataUrl",
+            "type": "string"
+          }
+        ]
+      }
+    },
+    {
+      "name": "scoreCard",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "submission",
+            "type": "publicKey"
+          },
+          {
+            "name": "judgeProfile",
+            "type": "publicKey"
+          },
+          {
+            "name": "scoreCardPostedTs",
+            "type": "u64"
+          },
+          {
+            "name": "weightedScore",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "sessionKey",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "userProfile",
+            "type": "publicKey"
+          },
+          {
+            "name": "profileOwner",
+            "type": "publicKey"
+          },
+          {
+            "name": "delegate",
+            "type": "publicKey"
+          },
+          {
+            "name": "sessionCreatedTs",
+            "type": "u64"
+          },
+          {
+            "name": "expiresTs",
+            "type": "u64"
+          },
+          {
+            "name": "sessionScope",
+            "type": {
+              "defined": "SessionScope"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "submissionAttempt",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "submission",
+            "type": "publicKey"
+          },
+          {
+            "name": "attemptNumber",
+            "type": "u64"
+          },
+          {
+            "name": "attemptPostedTs",
+            "type": "u64"
+          },
+          {
+            "name": "contentDataHash",
+            "type": "publicKey"
+          },
+          {
+            "name": "approvalVotes",
+            "type": "u64"
+          },
+          {
+            "name": "rejectionVotes",
+            "type": "u64"
+          },
+          {
+            "name": "totalScore",
+            "type": "u64"
+          },
+          {
+            "name": "scoreCount",
+            "type": "u64"
+          },
+          {
+            "name": "submissionState",
+            "type": {
+              "defined": "SubmissionState"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "submissionFeeEscrow",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "submission",
+            "type": "publicKey"
+          },
+          {
+            "name": "userProfile",
+            "type": "publicKey"
+          },
+          {
+            "name": "feeAmount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "submission",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "challenge",
+            "type": "publicKey"
+          },
+          {
+            "name": "userProfile",
+            "type": "publicKey"
+          },
+          {
+            "name": "submissionPostedTs",
+            "type": "u64"
+          },
+          {
+            "name": "mostRecentEngagementTs",
+            "type": "u64"
+          },
+          {
+            "name": "contentDataHash",
+            "type": "publicKey"
+          },
+          {
+            "name": "submissionState",
+            "type": {
+              "defined": "SubmissionState"
+            }
+          },
+          {
+            "name": "approvalVotes",
+            "type": "u64"
+          },
+          {
+            "name": "rejectionVotes",
+            "type": "u64"
+          },
+          {
+            "name": "totalScore",
+            "type": "u64"
+          },
+          {
+            "name": "scoreCount",
+            "type": "u64"
+          },
+          {
+            "name": "peerReviewCount",
+            "type": "u64"
+          },
+          {
+            "name": "revisionCount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "subscription",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "subscribedTo",
+            "type": "publicKey"
+          },
+          {
+            "name": "subscriberProfile",
+            "type": "publicKey"
+          },
+          {
+            "name": "subscribedTs",
+            "type": "u64"
+          },
+          {
+            "name": "subscriptionTarget",
+            "type": {
+              "defined": "SubscriptionTarget"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "trackCompletion",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "track",
+            "type": "publicKey"
+          },
+          {
+            "name": "userProfile",
+            "type": "publicKey"
+          },
+          {
+            "name": "trackCompletedTs",
+            "type": "u64"
+          },
+          {
+            "name": "completionRep",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "trackMembership",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "track",
+            "type": "publicKey"
+          },
+          {
+            "name": "challenge",
+            "type": "publicKey"
+          },
+          {
+            "name": "prerequisiteChallenge",
+            "type": "publicKey"
+          },
+          {
+            "name": "trackPosition",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "track",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "forum",
+            "type": "publicKey"
+          },
+          {
+            "name": "trackSeed",
+            "type": "publicKey"
+          },
+          {
+            "name": "challenges",
+            "type": {
+              "array": [
+                "publicKey",
+                10
+              ]
+            }
+          },
+          {
+            "name": "challengeCount",
+            "type": "u64"
+          },
+          {
+            "name": "trackCreatedTs",
+            "type": "u64"
+          },
+          {
+            "name": "completionRep",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "userProfile",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "profileOwner",
+            "type": "publicKey"
+          },
+          {
+            "name": "forum",
+            "type": "publicKey"
+          },
+          {
+            "name": "profileSeed",
+            "type": "publicKey"
+          },
+          {
+            "name": "pendingProfileOwner",
+            "type": "publicKey"
+          },
+          {
+            "name": "profileCreatedTs",
+            "type": "u64"
+          },
+          {
+            "name": "mostRecentEngagementTs",
+            "type": "u64"
+          },
+          {
+            "name": "eligibilityCheckedTs",
+            "type": "u64"
+          },
+          {
+            "name": "bigNotesCreated",
+            "type": "u64"
+          },
+          {
+            "name": "bigNotesVerified",
+            "type": "u64"
+          },
+          {
+            "name": "bigNotesContributionsProposed",
+            "type": "u64"
+          },
+          {
+            "name": "bigNotesContributionsAccepted",
+            "type": "u64"
+          },
+          {
+            "name": "bigNotesCredited",
+            "type": "u64"
+          },
+          {
+            "name": "challengesSubmitted",
+            "type": "u64"
+          },
+          {
+            "name": "challengesCompleted",
+            "type": "u64"
+          },
+          {
+            "name": "tracksCompleted",
+            "type": "u64"
+          },
+          {
+            "name": "questionsAsked",
+            "type": "u64"
+          },
+          {
+            "name": "questionsAnswered",
+            "type": "u64"
+          },
+          {
+            "name": "answersAccepted",
+            "type": "u64"
+          },
+          {
+            "name": "commentsAdded",
+            "type": "u64"
+          },
+          {
+            "name": "totalBountyContributed",
+            "type": "u64"
+          },
+          {
+            "name": "totalBountyEarned",
+            "type": "u64"
+          },
+          {
+            "name": "reputationScore",
+            "type": "u64"
+          },
+          {
+            "name": "followerCount",
+            "type": "u64"
+          },
+          {
+            "name": "followingCount",
+            "type": "u64"
+          },
+          {
+            "name": "nftPfpTokenMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "nftPfpTokenAccount",
+            "type": "publicKey"
+          },
+          {
+            "name": "hasAboutMe",
+            "type": "bool"
+          },
+          {
+            "name": "hasHadAboutMe",
+            "type": "bool"
+          },
+          {
+            "name": "isModerator",
+            "type": "bool"
+          },
+          {
+            "name": "hasHandle",
+            "type": "bool"
+          },
+          {
+            "name": "isSuspended",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "verificationAttestation",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bigNote",
+            "type": "publicKey"
+          },
+          {
+            "name": "attesterProfile",
+            "type": "publicKey"
+          },
+          {
+            "name": "verificationRound",
+            "type": "u64"
+          },
+          {
+            "name": "attestationTs",
+            "type": "u64"
+          },
+          {
+            "name": "stakeAmount",
+            "type": "u64"
+          },
+          {
+            "name": "isSlashed",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "walletRotation",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "forum",
+            "type": "publicKey"
+          },
+          {
+            "name": "userProfile",
+            "type": "publicKey"
+          },
+          {
+            "name": "previousProfileOwner",
+            "type": "publicKey"
+          },
+          {
+            "name": "newProfileOwner",
+            "type": "publicKey"
+          },
+          {
+            "name": "rotatedTs",
+            "type": "u64"
+          }
+        ]
+      }
+    }
+  ],
+  "types": [
+    {
+      "name": "BountyContribution",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bountyContributor",
+            "type": "publicKey"
+          },
+          {
+            "name": "bountyAmount",
+            "type": "u64"
+          },
+          {
+            "name": "forumBountyMinimum",
+            "type": "u64"
+          },
+          {
+            "name": "bountyContributionRep",
+            "type": "u64"
+          },
+          {
+            "name": "bountyContributionState",
+            "type": {
+              "defined": "BountyContributionState"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "ForumConstants",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "maxTagsLength",
+            "type": "u64"
+          },
+          {
+            "name": "maxTitleLength",
+            "type": "u64"
+          },
+          {
+            "name": "maxUrlLength",
+            "type": "u64"
+          },
+          {
+            "name": "minInactivityPeriod",
+            "type": "u64"
+          },
+          {
+            "name": "maxCommentDepth",
+            "type": "u64"
+          },
+          {
+            "name": "minChallengeProposalRep",
+            "type": "u64"
+          },
+          {
+            "name": "maxSubmissionAttempts",
+            "type": "u64"
+          },
+          {
+            "name": "challengeJudgingPeriod",
+            "type": "u64"
+          },
+          {
+            "name": "bigNoteVerificationQuorum",
+            "type": "u64"
+          },
+          {
+            "name": "minAttesterRep",
+            "type": "u64"
+          },
+          {
+            "name": "bigNoteVerificationValidityPeriod",
+            "type": "u64"
+          },
+          {
+            "name": "minHandleLength",
+            "type": "u64"
+          },
+          {
+            "name": "maxHandleLength",
+            "type": "u64"
+          },
+          {
+            "name": "handleCharacterSet",
+            "type": {
+              "defined": "HandleCharacterSet"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "ForumCounts",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "forumProfileCount",
+            "type": "u64"
+          },
+          {
+            "name": "forumQuestionCount",
+            "type": "u64"
+          },
+          {
+            "name": "forumAnswerCount",
+            "type": "u64"
+          },
+          {
+            "name": "forumCommentCount",
+            "type": "u64"
+          },
+          {
+            "name": "forumBigNotesCount",
+            "type": "u64"
+          },
+          {
+            "name": "forumProposedContributionCount",
+            "type": "u64"
+          },
+          {
+            "name": "forumChallengeCount",
+            "type": "u64"
+          },
+          {
+            "name": "forumSubmissionCount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ForumFees",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "forumProfileFee",
+            "type": "u64"
+          },
+          {
+            "name": "forumQuestionFee",
+            "type": "u64"
+          },
+          {
+            "name": "forumBigNotesSubmissionFee",
+            "type": "u64"
+          },
+          {
+            "name": "forumBigNotesSolicitationFee",
+            "type": "u64"
+          },
+          {
+            "name": "forumBigNotesVerificationFee",
+            "type": "u64"
+          },
+          {
+            "name": "forumChallengeSubmissionFee",
+            "type": "u64"
+          },
+          {
+            "name": "forumQuestionBountyMinimum",
+            "type": "u64"
+          },
+          {
+            "name": "forumBigNotesBountyMinimum",
+            "type": "u64"
+          },
+          {
+            "name": "forumChallengeProposalDeposit",
+            "type": "u64"
+          },
+          {
+            "name": "forumRejectedSubmissionRefund",
+            "type": "u64"
+          },
+          {
+            "name": "forumVerificationAttestationStake",
+            "type": "u64"
+          },
+          {
+            "name": "forumHandleRegistrationFee",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "LeaderboardEntry",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "submission",
+            "type": "publicKey"
+          },
+          {
+            "name": "userProfile",
+            "type": "publicKey"
+          },
+          {
+            "name": "averageScore",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ReputationMatrix",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "aboutMeRep",
+            "type": "u64"
+          },
+          {
+            "name": "questionRep",
+            "type": "u64"
+          },
+          {
+            "name": "answerRep",
+            "type": "u64"
+          },
+          {
+            "name": "commentRep",
+            "type": "u64"
+          },
+          {
+            "name": "acceptedAnswerRep",
+            "type": "u64"
+          },
+          {
+            "name": "createBigNotesRep",
+            "type": "u64"
+          },
+          {
+            "name": "bigNotesVerificationRep",
+            "type": "u64"
+          },
+          {
+            "name": "proposedBigNotesContributionRep",
+            "type": "u64"
+          },
+          {
+            "name": "acceptedBigNotesContributionProposalRep",
+            "type": "u64"
+          },
+          {
+            "name": "bountyContributionRep",
+            "type": "u64"
+          },
+          {
+            "name": "approvedChallengeProposalRep",
+            "type": "u64"
+          },
+          {
+            "name": "peerReviewMatchRep",
+            "type": "u64"
+          },
+          {
+            "name": "peerReviewMismatchPenalty",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "RubricCriterion",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "maxScore",
+            "type": "u64"
+          },
+          {
+            "name": "weight",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "AccessPolicyType",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Open"
+          },
+          {
+            "name": "TokenHolder"
+          },
+          {
+            "name": "CollectionHolder"
+          },
+          {
+            "name": "Allowlist"
+          }
+        ]
+      }
+    },
+    {
+      "name": "BigNoteType",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "OpenContribution"
+          },
+          {
+            "name": "CreatorCurated"
+          }
+        ]
+      }
+    },
+    {
+      "name": "BigNoteVerificationState",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Unverified"
+          },
+          {
+            "name": "AppliedForVerification"
+          },
+          {
+            "name": "Verified"
+          },
+          {
+            "name": "NeedsReverification"
+          }
+        ]
+      }
+    },
+    {
+      "name": "BountyContributionState",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Available"
+          },
+          {
+            "name": "Awarded"
+          },
+          {
+            "name": "Refunded"
+          },
+          {
+            "name": "Migrated"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ChallengeProposalState",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Pending"
+          },
+          {
+            "name": "Approved"
+          },
+          {
+            "name": "Rejected"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ChallengeState",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Open"
+          },
+          {
+            "name": "Judging"
+          },
+          {
+            "name": "Closed"
+          },
+          {
+            "name": "Archived"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CommentTarget",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Question"
+          },
+          {
+            "name": "Answer"
+          },
+          {
+            "name": "BigNote"
+          },
+          {
+            "name": "ProposedContribution"
+          },
+          {
+            "name": "Challenge"
+          },
+          {
+            "name": "Submission"
+          },
+          {
+            "name": "Comment"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ContentRelationType",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Duplicates"
+          },
+          {
+            "name": "Answers"
+          },
+          {
+            "name": "Supersedes"
+          },
+          {
+            "name": "Related"
+          }
+        ]
+      }
+    },
+    {
+      "name": "HandleCharacterSet",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Letters"
+          },
+          {
+            "name": "Alphanumeric"
+          },
+          {
+            "name": "AlphanumericUnderscore"
+          }
+        ]
+      }
+    },
+    {
+      "name": "LinkedContentType",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Question"
+          },
+          {
+            "name": "BigNote"
+          },
+          {
+            "name": "Challenge"
+          }
+        ]
+      }
+    },
+    {
+      "name": "PrizeDistribution",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "FirstN"
+          },
+          {
+            "name": "SplitEvenly"
+          },
+          {
+            "name": "RankedTiers"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ProposedContributionState",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Accepted"
+          },
+          {
+            "name": "Rejected"
+          },
+          {
+            "name": "Pending"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SessionScope",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "CommentAndAnswer"
+          },
+          {
+            "name": "PostAndEdit"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SubmissionState",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Completed"
+          },
+          {
+            "name": "Rejected"
+          },
+          {
+            "name": "Pending"
+          }
+        ]
+      }
+    },
+    {
+      "name": "SubscriptionTarget",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Question"
+          },
+          {
+            "name": "BigNote"
+          },
+          {
+            "name": "Challenge"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Tags",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "DaosAndGovernance"
+          },
+          {
+            "name": "DataAndAnalytics"
+          },
+          {
+            "name": "DeFi"
+          },
+          {
+            "name": "Development"
+          },
+          {
+            "name": "Gaming"
+          },
+          {
+            "name": "Mobile"
+          },
+          {
+            "name": "Nfts"
+          },
+          {
+            "name": "Payments"
+          },
+          {
+            "name": "ToolsAndInfrastructure"
+          },
+          {
+            "name": "Trading"
+          }
+        ]
+      }
+    }
+  ]
+};
+
+export const IDL: Forum = {
+  "version": "0.1.0",
+  "name": "forum",
+  "instructions": [
+    {
+      "name": "initForum",
+      "accounts": [
+        {
+          "name": "forum",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "forumManager",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "forumAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "forumTreasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpForumAuth",
+          "type": "u8"
+        },
+        {
+          "name": "forumFees",
+          "type": {
+            "defined": "ForumFees"
+          }
+        },
+        {
+          "name": "forumConstants",
+          "type": {
+            "defined": "ForumConstants"
+          }
+        },
+        {
+          "name": "reputationMatrix",
+          "type": {
+            "defined": "ReputationMatrix"
+          }
+        }
+      ]
+    },
+    {
+      "name": "updateForumParams",
+      "accounts": [
+        {
+          "name": "forum",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "forumManager",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "newForumFees",
+          "type": {
+            "defined": "ForumFees"
+          }
+        },
+        {
+          "name": "newForumConstants",
+          "type": {
+            "defined": "ForumConstants"
+          }
+        },
+        {
+          "name": "newReputationMatrix",
+          "type": {
+            "defined": "ReputationMatrix"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setPfpCollectionAllowlist",
+      "accounts": [
+        {
+          "name": "forum",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "forumManager",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "pfpCollectionAllowlist",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "collections",
+          "type": {
+            "vec": "publicKey"
+          }
+        }
+      ]
+    },
+    {
+      "name": "createCategory",
+      "accounts": [
+        {
+          "name": "forum",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "forumManager",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "category",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "categorySeed",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "feeOverridesEnabled",
+          "type": "bool"
+        },
+        {
+          "name": "categoryFees",
+          "type": {
+            "defined": "ForumFees"
+          }
+        }
+      ]
+    },
+    {
+      "name": "updateCategory",
+      "accounts": [
+        {
+          "name": "forum",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "forumManager",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "category",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "feeOverridesEnabled",
+          "type": "bool"
+        },
+        {
+          "name": "newCategoryFees",
+          "type": {
+            "defined": "ForumFees"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setCategoryModerators",
+      "accounts": [
+        {
+          "name": "forum",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "forumManager",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "category",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "moderatorProfiles",
+          "type": {
+            "vec": "publicKey"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setForumAccessPolicy",
+      "accounts": [
+        {
+          "name": "forum",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "forumManager",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "forumAccessPolicy",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "accessPolicyType",
+          "type": {
+            "defined": "AccessPolicyType"
+          }
+        },
+        {
+          "name": "accessMint",
+          "type": "publicKey"
+        },
+        {
+          "name": "minTokenAmount",
+          "type": "u64"
+        },
+        {
+          "name": "recheckPeriod",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "addForumAllowlistEntry",
+      "accounts": [
+        {
+          "name": "forum",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "forumManager",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "wallet",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "forumAllowlistEntry",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "removeForumAllowlistEntry",
+      "accounts": [
+        {
+          "name": "forum",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "forumManager",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "forumAllowlistEntry",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpForumAllowlistEntry",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "payoutFromTreasury",
+      "accounts": [
+        {
+          "name": "forum",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "forumManager",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "forumTreasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "receiver",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpForumTreasury",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "closeForum",
+      "accounts": [
+        {
+          "name": "forum",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "forumManager",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "forumTreasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "receiver",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpForumTreasury",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "createUserProfile",
+      "accounts": [
+        {
+          "name": "forum",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "forumTreasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "profileOwner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "userProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "walletRotation",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "forumAccessPolicy",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpTreasury",
+          "type": "u8"
+        },
+        {
+          "name": "bumpWalletRotation",
+          "type": "u8"
+        },
+        {
+          "name": "bumpForumAccessPolicy",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "approveWalletRotation",
+      "accounts": [
+        {
+          "name": "forum",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "profileOwner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "userProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "newProfileOwner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpUserProfile",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "acceptWalletRotation",
+      "accounts": [
+        {
+          "name": "forum",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "newProfileOwner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "userProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "newWalletUserProfile",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "walletRotation",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "previousWalletRotation",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "previousProfileOwner",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpUserProfile",
+          "type": "u8"
+        },
+        {
+          "name": "bumpNewWalletUserProfile",
+          "type": "u8"
+        },
+        {
+          "name": "bumpPreviousWalletRotation",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "registerSessionKey",
+      "accounts": [
+        {
+          "name": "forum",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "profileOwner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "userProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "delegate",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "sessionKey",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpUserProfile",
+          "type": "u8"
+        },
+        {
+          "name": "sessionScope",
+          "type": {
+            "defined": "SessionScope"
+          }
+        },
+        {
+          "name": "sessionDuration",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "revokeSessionKey",
+      "accounts": [
+        {
+          "name": "forum",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "profileOwner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "userProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sessionKey",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpUserProfile",
+          "type": "u8"
+        },
+        {
+          "name": "bumpSessionKey",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "editUserProfile",
+      "accounts": [
+        {
+          "name": "forum",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "profileOwner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "userProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "nftPfpTokenMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "nftPfpTokenAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "nftMetadata",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "pfpCollectionAllowlist",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpUserProfile",
+          "type": "u8"
+        },
+        {
+          "name": "bumpPfpCollectionAllowlist",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "clearNftPfp",
+      "accounts": [
+        {
+          "name": "forum",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "cranker",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "userProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "nftPfpTokenAccount",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpUserProfile",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "claimHandle",
+      "accounts": [
+        {
+          "name": "forum",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "forumTreasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "profileOwner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "userProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "handle",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpTreasury",
+          "type": "u8"
+        },
+        {
+          "name": "bumpUserProfile",
+          "type": "u8"
+        },
+        {
+          "name": "handleName",
+          "type": "string"
+        }
+      ]
+    },
+    {
+      "name": "releaseHandle",
+      "accounts": [
+        {
+          "name": "forum",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "profileOwner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "userProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "handle",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpUserProfile",
+          "type": "u8"
+        },
+        {
+          "name": "bumpHandle",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "transferHandle",
+      "accounts": [
+        {
+          "name": "forum",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "profileOwner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "userProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "newProfileOwner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "newUserProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "handle",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpUserProfile",
+          "type": "u8"
+        },
+        {
+          "name": "bumpHandle",
+          "type": "u8"
+        },
+        {
+          "name": "bumpNewUserProfile",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "reclaimHandleModerator",
+      "accounts": [
+        {
+          "name": "forum",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "moderator",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "moderatorProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "userProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "handle",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "receiver",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpModeratorProfile",
+          "type": "u8"
+        },
+        {
+          "name": "bumpHandle",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "recheckProfileEligibility",
+      "accounts": [
+        {
+          "name": "forum",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "cranker",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "forumAccessPolicy",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "userProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpForumAccessPolicy",
+          "type": "u8"
+        },
+        {
+          "name": "bumpUserProfile",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "followUser",
+      "accounts": [
+        {
+          "name": "forum",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "profileOwner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "userProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "followedProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "follow",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpUserProfile",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "unfollowUser",
+      "accounts": [
+        {
+          "name": "forum",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "profileOwner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "userProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "followedProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "follow",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpUserProfile",
+          "type": "u8"
+        },
+        {
+          "name": "bumpFollow",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "subscribe",
+      "accounts": [
+        {
+          "name": "forum",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "profileOwner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "userProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "subscribedTo",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "subscription",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpUserProfile",
+          "type": "u8"
+        },
+        {
+          "name": "subscriptionTarget",
+          "type": {
+            "defined": "SubscriptionTarget"
+          }
+        }
+      ]
+    },
+    {
+      "name": "unsubscribe",
+      "accounts": [
+        {
+          "name": "forum",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "profileOwner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "userProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "subscription",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpUserProfile",
+          "type": "u8"
+        },
+        {
+          "name": "bumpSubscription",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "deleteUserProfile",
+      "accounts": [
+        {
+          "name": "forum",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "profileOwner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "userProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "walletRotation",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "receiver",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpUserProfile",
+          "type": "u8"
+        },
+        {
+          "name": "bumpWalletRotation",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "createAboutMe",
+      "accounts": [
+        {
+          "name": "forum",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "profileOwner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "userProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "aboutMe",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "contentDataHash",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpUserProfile",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "editAboutMe",
+      "accounts": [
+        {
+          "name": "forum",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "profileOwner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "userProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "aboutMe",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "newContentDataHash",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpUserProfile",
+          "type": "u8"
+        },
+        {
+          "name": "bumpAboutMe",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "deleteAboutMe",
+      "accounts": [
+        {
+          "name": "forum",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "profileOwner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "userProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "aboutMe",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "receiver",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpUserProfile",
+          "type": "u8"
+        },
+        {
+          "name": "bumpAboutMe",
+          "type": "u8"
+        }
+      ]
     },
     {
-      "name": "proposedContribution",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "bigNote",
-            "type": "publicKey"
-          },
-          {
-            "name": "userProfile",
-            "type": "publicKey"
-          },
-          {
-            "name": "proposedContributionSeed",
-            "type": "publicKey"
-          },
-          {
-            "name": "proposedContributionPostedTs",
-            "type": "u64"
-          },
-          {
-            "name": "mostRecentEngagementTs",
-            "type": "u64"
-          },
-          {
-            "name": "contentDataHash",
-            "type": "publicKey"
-          },
-          {
-            "name": "proposedContributionRep",
-            "type": "u64"
-          },
-          {
-            "name": "proposedContributionState",
-            "type": {
-              "defined": "ProposedContributionState"
+      "name": "deleteUserProfileAndAboutMe",
+      "accounts": [
+        {
+          "name": "forum",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "profileOwner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "userProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "aboutMe",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "walletRotation",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "receiver",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpUserProfile",
+          "type": "u8"
+        },
+        {
+          "name": "bumpAboutMe",
+          "type": "u8"
+        },
+        {
+          "name": "bumpWalletRotation",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "addModerator",
+      "accounts": [
+        {
+          "name": "forum",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "forumManager",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "profileOwner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "userProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpUserProfile",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "removeModerator",
+      "accounts": [
+        {
+          "name": "forum",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "forumManager",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "profileOwner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "userProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpUserProfile",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "askQuestion",
+      "accounts": [
+        {
+          "name": "forum",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "forumTreasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "profileOwner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "userProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "category",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "question",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "questionSeed",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "contentDataHash",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bountyPda",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpTreasury",
+          "type": "u8"
+        },
+        {
+          "name": "bumpUserProfile",
+          "type": "u8"
+        },
+        {
+          "name": "tags",
+          "type": {
+            "vec": {
+              "defined": "Tags"
             }
-          },
-          {
-            "name": "acceptedContributionProposalRep",
-            "type": "u64"
           }
-        ]
-      }
+        },
+        {
+          "name": "title",
+          "type": "string"
+        },
+        {
+          "name": "contentDataUrl",
+          "type": "string"
+        },
+        {
+          "name": "bountyAmount",
+          "type": "u64"
+        }
+      ]
     },
     {
-      "name": "question",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "forum",
-            "type": "publicKey"
-          },
-          {
-            "name": "userProfile",
-            "type": "publicKey"
-          },
-          {
-            "name": "questionSeed",
-            "type": "publicKey"
-          },
-          {
-            "name": "questionPostedTs",
-            "type": "u64"
-          },
-          {
-            "name": "mostRecentEngagementTs",
-            "type": "u64"
-          },
-          {
-            "name": "bountyAmount",
-            "type": "u64"
-          },
-          {
-            "name": "bountyContributions",
-            "type": {
-              "vec": {
-                "defined": "BountyContribution"
-              }
-            }
-          },
-          {
-            "name": "tags",
-            "type": {
-              "vec": {
-                "defined": "Tags"
-              }
+      "name": "editQuestion",
+      "accounts": [
+        {
+          "name": "forum",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "userProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sessionKey",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "question",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "questionSeed",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "newContentDataHash",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "revision",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpUserProfile",
+          "type": "u8"
+        },
+        {
+          "name": "bumpQuestion",
+          "type": "u8"
+        },
+        {
+          "name": "bumpSessionKey",
+          "type": "u8"
+        },
+        {
+          "name": "newTags",
+          "type": {
+            "vec": {
+              "defined": "Tags"
             }
-          },
-          {
-            "name": "title",
-            "type": "string"
-          },
-          {
-            "name": "contentDataUrl",
-            "type": "string"
-          },
-          {
-            "name": "contentDataHash",
-            "type": "publicKey"
-          },
-          {
-            "name": "questionRep",
-            "type": "u64"
-          },
-          {
-            "name": "bountyAwarded",
-            "type": "bool"
           }
-        ]
-      }
+        },
+        {
+          "name": "newTitle",
+          "type": "string"
+        },
+        {
+          "name": "newContentDataUrl",
+          "type": "string"
+        }
+      ]
     },
     {
-      "name": "submission",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "challenge",
-            "type": "publicKey"
-          },
-          {
-            "name": "userProfile",
-            "type": "publicKey"
-          },
-          {
-            "name": "submissionPostedTs",
-            "type": "u64"
-          },
-          {
-            "name": "mostRecentEngagementTs",
-            "type": "u64"
-          },
-          {
-            "name": "contentDataHash",
-            "type": "publicKey"
-          },
-          {
-            "name": "submissionState",
-            "type": {
-              "defined": "SubmissionState"
+      "name": "editQuestionModerator",
+      "accounts": [
+        {
+          "name": "forum",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "moderator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "moderatorProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "profileOwner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "userProfile",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "question",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "questionSeed",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "category",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "newContentDataHash",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "revision",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpModeratorProfile",
+          "type": "u8"
+        },
+        {
+          "name": "bumpUserProfile",
+          "type": "u8"
+        },
+        {
+          "name": "bumpQuestion",
+          "type": "u8"
+        },
+        {
+          "name": "newTags",
+          "type": {
+            "vec": {
+              "defined": "Tags"
             }
           }
-        ]
-      }
+        },
+        {
+          "name": "newTitle",
+          "type": "string"
+        },
+        {
+          "name": "newContentDataUrl",
+          "type": "string"
+        }
+      ]
     },
     {
-      "name": "userProfile",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "profileOwner",
-            "type": "publicKey"
-          },
-          {
-            "name": "forum",
-            "type": "publicKey"
-          },
-          {
-            "name": "profileCreatedTs",
-            "type": "u64"
-          },
-          {
-            "name": "mostRecentEngagementTs",
-            "type": "u64"
-          },
-          {
-            "name": "bigNotesCreated",
-            "type": "u64"
-          },
-          {
-            "name": "bigNotesVerified",
-            "type": "u64"
-          },
-          {
-            "name": "bigNotesContributionsProposed",
-            "type": "u64"
-          },
-          {
-            "name": "bigNotesContributionsAccepted",
-            "type": "u64"
-          },
-          {
-            "name": "challengesSubmitted",
-            "type": "u64"
-          },
-          {
-            "name": "challengesCompleted",
-            "type": "u64"
-          },
-          {
-            "name": "questionsAsked",
-            "type": "u64"
-          },
-          {
-            "name": "questionsAnswered",
-            "type": "u64"
-          },
-          {
-            "name": "answersAccepted",
-            "type": "u64"
-          },
-          {
-            "name": "commentsAdded",
-            "type": "u64"
-          },
-          {
-            "name": "totalBountyContributed",
-            "type": "u64"
-          },
-          {
-            "name": "totalBountyEarned",
-            "type": "u64"
-          },
-          {
-            "name": "reputationScore",
-            "type": "u64"
-          },
-          {
-            "name": "nftPfpTokenMint",
-            "type": "publicKey"
-          },
-          {
-            "name": "hasAboutMe",
-            "type": "bool"
-          },
-          {
-            "name": "hasHadAboutMe",
-            "type": "bool"
-          },
-          {
-            "name": "isModerator",
-            "type": "bool"
-          }
-        ]
-      }
-    }
-  ],
-  "types": [
+      "name": "deleteQuestionModerator",
+      "accounts": [
+        {
+          "name": "forum",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "moderator",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "moderatorProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "profileOwner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "userProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "question",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "questionSeed",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "category",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "receiver",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpModeratorProfile",
+          "type": "u8"
+        },
+        {
+          "name": "bumpUserProfile",
+          "type": "u8"
+        },
+        {
+          "name": "bumpQuestion",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "supplementQuestionBounty",
+      "accounts": [
+        {
+          "name": "forum",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "forumTreasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "supplementor",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "supplementorProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "profileOwner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "userProfile",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "question",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "category",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "questionSeed",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bountyPda",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpTreasury",
+          "type": "u8"
+        },
+        {
+          "name": "bumpSupplementorProfile",
+          "type": "u8"
+        },
+        {
+          "name": "bumpUserProfile",
+          "type": "u8"
+        },
+        {
+          "name": "bumpQuestion",
+          "type": "u8"
+        },
+        {
+          "name": "bumpBountyPda",
+          "type": "u8"
+        },
+        {
+          "name": "supplementalBountyAmount",
+          "type": "u64"
+        }
+      ]
+    },
     {
-      "name": "BountyContribution",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "bountyContributor",
-            "type": "publicKey"
-          },
-          {
-            "name": "bountyAmount",
-            "type": "u64"
-          },
-          {
-            "name": "forumBountyMinimum",
-            "type": "u64"
-          },
-          {
-            "name": "bountyContributionRep",
-            "type": "u64"
-          },
-          {
-            "name": "bountyContributionState",
-            "type": {
-              "defined": "BountyContributionState"
-            }
-          }
-        ]
-      }
+      "name": "refundQuestionBountySupplementorModerator",
+      "accounts": [
+        {
+          "name": "forum",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "moderator",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "moderatorProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "profileOwner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "userProfile",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "supplementor",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "supplementorProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "question",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "questionSeed",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bountyPda",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpModeratorProfile",
+          "type": "u8"
+        },
+        {
+          "name": "bumpUserProfile",
+          "type": "u8"
+        },
+        {
+          "name": "bumpSupplementorProfile",
+          "type": "u8"
+        },
+        {
+          "name": "bumpQuestion",
+          "type": "u8"
+        },
+        {
+          "name": "bumpBountyPda",
+          "type": "u8"
+        }
+      ]
     },
     {
-      "name": "ForumConstants",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "maxTagsLength",
-            "type": "u64"
-          },
-          {
-            "name": "maxTitleLength",
-            "type": "u64"
-          },
-          {
-            "name": "maxUrlLength",
-            "type": "u64"
-          },
-          {
-            "name": "minInactivityPeriod",
-            "type": "u64"
-          }
-        ]
-      }
+      "name": "markDuplicate",
+      "accounts": [
+        {
+          "name": "forum",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "moderator",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "moderatorProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "duplicateQuestion",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "category",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "canonicalQuestion",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpModeratorProfile",
+          "type": "u8"
+        }
+      ]
     },
     {
-      "name": "ForumCounts",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "forumProfileCount",
-            "type": "u64"
-          },
-          {
-            "name": "forumQuestionCount",
-            "type": "u64"
-          },
-          {
-            "name": "forumAnswerCount",
-            "type": "u64"
-          },
-          {
-            "name": "forumCommentCount",
-            "type": "u64"
-          },
-          {
-            "name": "forumBigNotesCount",
-            "type": "u64"
-          },
-          {
-            "name": "forumProposedContributionCount",
-            "type": "u64"
-          },
-          {
-            "name": "forumChallengeCount",
-            "type": "u64"
-          },
-          {
-            "name": "forumSubmissionCount",
-            "type": "u64"
-          }
-        ]
-      }
+      "name": "transferQuestionOwnership",
+      "accounts": [
+        {
+          "name": "forum",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "profileOwner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "userProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "question",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "questionSeed",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "newOwnerProfile",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpUserProfile",
+          "type": "u8"
+        },
+        {
+          "name": "bumpQuestion",
+          "type": "u8"
+        }
+      ]
     },
     {
-      "name": "ForumFees",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "forumProfileFee",
-            "type": "u64"
-          },
-          {
-            "name": "forumQuestionFee",
-            "type": "u64"
-          },
-          {
-            "name": "forumBigNotesSubmissionFee",
-            "type": "u64"
-          },
-          {
-            "name": "forumBigNotesSolicitationFee",
-            "type": "u64"
-          },
-          {
-            "name": "forumBigNotesVerificationFee",
-            "type": "u64"
-          },
-          {
-            "name": "forumChallengeSubmissionFee",
-            "type": "u64"
-          },
-          {
-            "name": "forumQuestionBountyMinimum",
-            "type": "u64"
-          },
-          {
-            "name": "forumBigNotesBountyMinimum",
-            "type": "u64"
-          }
-        ]
-      }
+      "name": "claimQuestionOwnership",
+      "accounts": [
+        {
+          "name": "forum",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "profileOwner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "userProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "question",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "questionSeed",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpUserProfile",
+          "type": "u8"
+        },
+        {
+          "name": "bumpQuestion",
+          "type": "u8"
+        }
+      ]
     },
     {
-      "name": "ReputationMatrix",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "aboutMeRep",
-            "type": "u64"
-          },
-          {
-            "name": "questionRep",
-            "type": "u64"
-          },
-          {
-            "name": "answerRep",
-            "type": "u64"
-          },
-          {
-            "name": "commentRep",
-            "type": "u64"
-          },
-          {
-            "name": "acceptedAnswerRep",
-            "type": "u64"
-          },
-          {
-            "name": "createBigNotesRep",
-            "type": "u64"
-          },
-          {
-            "name": "bigNotesVerificationRep",
-            "type": "u64"
-          },
-          {
-            "name": "proposedBigNotesContributionRep",
-            "type": "u64"
-          },
-          {
-            "name": "acceptedBigNotesContributionProposalRep",
-            "type": "u64"
-          },
-          {
-            "name": "bountyContributionRep",
-            "type": "u64"
-          }
-        ]
-      }
+      "name": "migrateDuplicateQuestionBounty",
+      "accounts": [
+        {
+          "name": "forum",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "moderator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "moderatorProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "duplicateQuestion",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "category",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "duplicateBountyPda",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "canonicalQuestion",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "canonicalBountyPda",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpModeratorProfile",
+          "type": "u8"
+        },
+        {
+          "name": "bumpDuplicateBountyPda",
+          "type": "u8"
+        },
+        {
+          "name": "bumpCanonicalBountyPda",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "acceptAnswer",
+      "accounts": [
+        {
+          "name": "forum",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "profileOwner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "userProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "question",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "questionSeed",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bountyPda",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "answerProfileOwner",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "answerUserProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "answer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "answerSeed",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "receiver",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpUserProfile",
+          "type": "u8"
+        },
+        {
+          "name": "bumpQuestion",
+          "type": "u8"
+        },
+        {
+          "name": "bumpBountyPda",
+          "type": "u8"
+        },
+        {
+          "name": "bumpAnswerUserProfile",
+          "type": "u8"
+        },
+        {
+          "name": "bumpAnswer",
+          "type": "u8"
+        }
+      ]
     },
     {
-      "name": "BigNoteType",
-      "type": {
-        "kind": "enum",
-        "variants": [
-          {
-            "name": "OpenContribution"
-          },
-          {
-            "name": "CreatorCurated"
-          }
-        ]
-      }
+      "name": "answerQuestion",
+      "accounts": [
+        {
+          "name": "forum",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "userProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sessionKey",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "question",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "answer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "answerSeed",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "contentDataHash",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpUserProfile",
+          "type": "u8"
+        },
+        {
+          "name": "bumpSessionKey",
+          "type": "u8"
+        }
+      ]
     },
     {
-      "name": "BigNoteVerificationState",
-      "type": {
-        "kind": "enum",
-        "variants": [
-          {
-            "name": "Unverified"
-          },
-          {
-            "name": "AppliedForVerification"
-          },
-          {
-            "name": "Verified"
-          }
-        ]
-      }
+      "name": "editAnswer",
+      "accounts": [
+        {
+          "name": "forum",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "userProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sessionKey",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "question",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "answer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "answerSeed",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "newContentDataHash",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "revision",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpUserProfile",
+          "type": "u8"
+        },
+        {
+          "name": "bumpAnswer",
+          "type": "u8"
+        },
+        {
+          "name": "bumpSessionKey",
+          "type": "u8"
+        }
+      ]
     },
     {
-      "name": "BountyContributionState",
-      "type": {
-        "kind": "enum",
-        "variants": [
-          {
-            "name": "Available"
-          },
-          {
-            "name": "Awarded"
-          },
-          {
-            "name": "Refunded"
-          }
-        ]
-      }
+      "name": "editAnswerModerator",
+      "accounts": [
+        {
+          "name": "forum",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "moderator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "moderatorProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "profileOwner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "userProfile",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "question",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "category",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "answer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "answerSeed",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "newContentDataHash",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "revision",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpModeratorProfile",
+          "type": "u8"
+        },
+        {
+          "name": "bumpUserProfile",
+          "type": "u8"
+        },
+        {
+          "name": "bumpAnswer",
+          "type": "u8"
+        }
+      ]
     },
     {
-      "name": "ProposedContributionState",
-      "type": {
-        "kind": "enum",
-        "variants": [
-          {
-            "name": "Accepted"
-          },
-          {
-            "name": "Rejected"
-          },
-          {
-            "name": "Pending"
-          }
-        ]
-      }
+      "name": "deleteAnswer",
+      "accounts": [
+        {
+          "name": "forum",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "profileOwner",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "userProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "question",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "answer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "answerSeed",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "receiver",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpUserProfile",
+          "type": "u8"
+        },
+        {
+          "name": "bumpAnswer",
+          "type": "u8"
+        }
+      ]
     },
     {
-      "name": "SubmissionState",
-      "type": {
-        "kind": "enum",
-        "variants": [
-          {
-            "name": "Completed"
-          },
-          {
-            "name": "Rejected"
-          },
-          {
-            "name": "Pending"
-          }
-        ]
-      }
+      "name": "deleteAnswerModerator",
+      "accounts": [
+        {
+          "name": "forum",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "moderator",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "moderatorProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "profileOwner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "userProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "question",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "category",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "answer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "answerSeed",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "receiver",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpModeratorProfile",
+          "type": "u8"
+        },
+        {
+          "name": "bumpUserProfile",
+          "type": "u8"
+        },
+        {
+          "name": "bumpAnswer",
+          "type": "u8"
+        }
+      ]
     },
     {
-      "name": "Tags",
-      "type": {
-        "kind": "enum",
-        "variants": [
-          {
-            "name": "DaosAndGovernance"
-          },
-          {
-            "name": "DataAndAnalytics"
-          },
-          {
-            "name": "DeFi"
-          },
-          {
-            "name": "Development"
-          },
-          {
-            "name": "Gaming"
-          },
-          {
-            "name": "Mobile"
-          },
-          {
-            "name": "Nfts"
-          },
-          {
-            "name": "Payments"
-          },
-          {
-            "name": "ToolsAndInfrastructure"
-          },
-          {
-            "name": "Trading"
+      "name": "leaveComment",
+      "accounts": [
+        {
+          "name": "forum",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "userProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sessionKey",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "commentedOn",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "comment",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "commentSeed",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "contentDataHash",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpUserProfile",
+          "type": "u8"
+        },
+        {
+          "name": "bumpSessionKey",
+          "type": "u8"
+        },
+        {
+          "name": "commentTarget",
+          "type": {
+            "defined": "CommentTarget"
           }
-        ]
-      }
-    }
-  ]
-};
-
-export const IDL: Forum = {
-  "version": "0.1.0",
-  "name": "forum",
-  "instructions": [
+        }
+      ]
+    },
     {
-      "name": "initForum",
+      "name": "editComment",
       "accounts": [
         {
           "name": "forum",
-          "isMut": true,
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
           "isSigner": true
         },
         {
-          "name": "forumManager",
+          "name": "userProfile",
           "isMut": true,
-          "isSigner": true
+          "isSigner": false
         },
         {
-          "name": "forumAuthority",
+          "name": "sessionKey",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "forumTreasury",
+          "name": "commentedOn",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "rent",
+          "name": "comment",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "commentSeed",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "newContentDataHash",
           "isMut": false,
           "isSigner": false
         },
@@ -5489,42 +12035,72 @@ export const IDL: Forum = {
       ],
       "args": [
         {
-          "name": "bumpForumAuth",
+          "name": "bumpUserProfile",
           "type": "u8"
         },
         {
-          "name": "forumFees",
-          "type": {
-            "defined": "ForumFees"
-          }
-        },
-        {
-          "name": "forumConstants",
-          "type": {
-            "defined": "ForumConstants"
-          }
+          "name": "bumpComment",
+          "type": "u8"
         },
         {
-          "name": "reputationMatrix",
-          "type": {
-            "defined": "ReputationMatrix"
-          }
+          "name": "bumpSessionKey",
+          "type": "u8"
         }
       ]
     },
     {
-      "name": "updateForumParams",
+      "name": "editCommentModerator",
       "accounts": [
         {
           "name": "forum",
-          "isMut": true,
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "forumManager",
+          "name": "moderator",
           "isMut": false,
           "isSigner": true
         },
+        {
+          "name": "moderatorProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "profileOwner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "userProfile",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "commentedOn",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "comment",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "commentSeed",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "category",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "newContentDataHash",
+          "isMut": false,
+          "isSigner": false
+        },
         {
           "name": "systemProgram",
           "isMut": false,
@@ -5533,43 +12109,52 @@ export const IDL: Forum = {
       ],
       "args": [
         {
-          "name": "newForumFees",
-          "type": {
-            "defined": "ForumFees"
-          }
+          "name": "bumpModeratorProfile",
+          "type": "u8"
         },
         {
-          "name": "newForumConstants",
-          "type": {
-            "defined": "ForumConstants"
-          }
+          "name": "bumpUserProfile",
+          "type": "u8"
         },
         {
-          "name": "newReputationMatrix",
-          "type": {
-            "defined": "ReputationMatrix"
-          }
+          "name": "bumpComment",
+          "type": "u8"
         }
       ]
     },
     {
-      "name": "payoutFromTreasury",
+      "name": "deleteComment",
       "accounts": [
         {
           "name": "forum",
-          "isMut": false,
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "forumManager",
+          "name": "profileOwner",
           "isMut": false,
           "isSigner": true
         },
         {
-          "name": "forumTreasury",
+          "name": "userProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "commentedOn",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "comment",
           "isMut": true,
           "isSigner": false
         },
+        {
+          "name": "commentSeed",
+          "isMut": false,
+          "isSigner": false
+        },
         {
           "name": "receiver",
           "isMut": true,
@@ -5583,13 +12168,17 @@ export const IDL: Forum = {
       ],
       "args": [
         {
-          "name": "bumpForumTreasury",
+          "name": "bumpUserProfile",
+          "type": "u8"
+        },
+        {
+          "name": "bumpComment",
           "type": "u8"
         }
       ]
     },
     {
-      "name": "closeForum",
+      "name": "deleteCommentModerator",
       "accounts": [
         {
           "name": "forum",
@@ -5597,15 +12186,45 @@ export const IDL: Forum = {
           "isSigner": false
         },
         {
-          "name": "forumManager",
+          "name": "moderator",
           "isMut": false,
           "isSigner": true
         },
         {
-          "name": "forumTreasury",
+          "name": "moderatorProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "profileOwner",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "userProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "commentedOn",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "comment",
           "isMut": true,
           "isSigner": false
         },
+        {
+          "name": "commentSeed",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "category",
+          "isMut": false,
+          "isSigner": false
+        },
         {
           "name": "receiver",
           "isMut": true,
@@ -5619,13 +12238,108 @@ export const IDL: Forum = {
       ],
       "args": [
         {
-          "name": "bumpForumTreasury",
-          "type": "u8"
+          "name": "bumpModeratorProfile",
+          "type": "u8"
+        },
+        {
+          "name": "bumpUserProfile",
+          "type": "u8"
+        },
+        {
+          "name": "bumpComment",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "createBigNote",
+      "accounts": [
+        {
+          "name": "forum",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "forumTreasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "profileOwner",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "userProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "category",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bigNote",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bigNoteSeed",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "contentDataHash",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bountyPda",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpTreasury",
+          "type": "u8"
+        },
+        {
+          "name": "bumpUserProfile",
+          "type": "u8"
+        },
+        {
+          "name": "bigNoteType",
+          "type": {
+            "defined": "BigNoteType"
+          }
+        },
+        {
+          "name": "tags",
+          "type": {
+            "vec": {
+              "defined": "Tags"
+            }
+          }
+        },
+        {
+          "name": "title",
+          "type": "string"
+        },
+        {
+          "name": "contentDataUrl",
+          "type": "string"
         }
       ]
     },
     {
-      "name": "createUserProfile",
+      "name": "convertQuestionToBigNote",
       "accounts": [
         {
           "name": "forum",
@@ -5648,41 +12362,55 @@ export const IDL: Forum = {
           "isSigner": false
         },
         {
-          "name": "systemProgram",
+          "name": "question",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "questionSeed",
           "isMut": false,
           "isSigner": false
-        }
-      ],
-      "args": [
+        },
         {
-          "name": "bumpTreasury",
-          "type": "u8"
-        }
-      ]
-    },
-    {
-      "name": "editUserProfile",
-      "accounts": [
+          "name": "category",
+          "isMut": true,
+          "isSigner": false
+        },
         {
-          "name": "forum",
+          "name": "answerProfileOwner",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "profileOwner",
+          "name": "answerUserProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "answer",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "answerSeed",
           "isMut": false,
-          "isSigner": true
+          "isSigner": false
         },
         {
-          "name": "userProfile",
+          "name": "bigNote",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "nftPfpTokenMint",
+          "name": "contentDataHash",
           "isMut": false,
           "isSigner": false
         },
+        {
+          "name": "bountyPda",
+          "isMut": true,
+          "isSigner": false
+        },
         {
           "name": "systemProgram",
           "isMut": false,
@@ -5690,23 +12418,54 @@ export const IDL: Forum = {
         }
       ],
       "args": [
+        {
+          "name": "bumpTreasury",
+          "type": "u8"
+        },
         {
           "name": "bumpUserProfile",
           "type": "u8"
+        },
+        {
+          "name": "bumpQuestion",
+          "type": "u8"
+        },
+        {
+          "name": "bumpAnswerUserProfile",
+          "type": "u8"
+        },
+        {
+          "name": "bumpAnswer",
+          "type": "u8"
+        },
+        {
+          "name": "bigNoteType",
+          "type": {
+            "defined": "BigNoteType"
+          }
+        },
+        {
+          "name": "contentDataUrl",
+          "type": "string"
         }
       ]
     },
     {
-      "name": "deleteUserProfile",
+      "name": "forkBigNote",
       "accounts": [
         {
           "name": "forum",
           "isMut": true,
           "isSigner": false
         },
+        {
+          "name": "forumTreasury",
+          "isMut": true,
+          "isSigner": false
+        },
         {
           "name": "profileOwner",
-          "isMut": false,
+          "isMut": true,
           "isSigner": true
         },
         {
@@ -5715,43 +12474,27 @@ export const IDL: Forum = {
           "isSigner": false
         },
         {
-          "name": "receiver",
+          "name": "parentBigNote",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "bumpUserProfile",
-          "type": "u8"
-        }
-      ]
-    },
-    {
-      "name": "createAboutMe",
-      "accounts": [
-        {
-          "name": "forum",
-          "isMut": false,
+          "name": "category",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "profileOwner",
+          "name": "bigNote",
           "isMut": true,
-          "isSigner": true
+          "isSigner": false
         },
         {
-          "name": "userProfile",
-          "isMut": true,
+          "name": "bigNoteSeed",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "aboutMe",
+          "name": "bigNoteFork",
           "isMut": true,
           "isSigner": false
         },
@@ -5760,6 +12503,11 @@ export const IDL: Forum = {
           "isMut": false,
           "isSigner": false
         },
+        {
+          "name": "bountyPda",
+          "isMut": true,
+          "isSigner": false
+        },
         {
           "name": "systemProgram",
           "isMut": false,
@@ -5767,14 +12515,28 @@ export const IDL: Forum = {
         }
       ],
       "args": [
+        {
+          "name": "bumpTreasury",
+          "type": "u8"
+        },
         {
           "name": "bumpUserProfile",
           "type": "u8"
+        },
+        {
+          "name": "bigNoteType",
+          "type": {
+            "defined": "BigNoteType"
+          }
+        },
+        {
+          "name": "contentDataUrl",
+          "type": "string"
         }
       ]
     },
     {
-      "name": "editAboutMe",
+      "name": "markBigNoteSuccessor",
       "accounts": [
         {
           "name": "forum",
@@ -5782,22 +12544,22 @@ export const IDL: Forum = {
           "isSigner": false
         },
         {
-          "name": "profileOwner",
+          "name": "moderator",
           "isMut": false,
           "isSigner": true
         },
         {
-          "name": "userProfile",
+          "name": "moderatorProfile",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "aboutMe",
+          "name": "parentBigNote",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "newContentDataHash",
+          "name": "bigNoteFork",
           "isMut": false,
           "isSigner": false
         },
@@ -5809,17 +12571,17 @@ export const IDL: Forum = {
       ],
       "args": [
         {
-          "name": "bumpUserProfile",
+          "name": "bumpModeratorProfile",
           "type": "u8"
         },
         {
-          "name": "bumpAboutMe",
+          "name": "bumpBigNoteFork",
           "type": "u8"
         }
       ]
     },
     {
-      "name": "deleteAboutMe",
+      "name": "transferBigNoteOwnership",
       "accounts": [
         {
           "name": "forum",
@@ -5837,13 +12599,18 @@ export const IDL: Forum = {
           "isSigner": false
         },
         {
-          "name": "aboutMe",
+          "name": "bigNote",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "receiver",
-          "isMut": true,
+          "name": "bigNoteSeed",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "newOwnerProfile",
+          "isMut": false,
           "isSigner": false
         },
         {
@@ -5858,17 +12625,17 @@ export const IDL: Forum = {
           "type": "u8"
         },
         {
-          "name": "bumpAboutMe",
+          "name": "bumpBigNote",
           "type": "u8"
         }
       ]
     },
     {
-      "name": "deleteUserProfileAndAboutMe",
+      "name": "claimBigNoteOwnership",
       "accounts": [
         {
           "name": "forum",
-          "isMut": true,
+          "isMut": false,
           "isSigner": false
         },
         {
@@ -5882,13 +12649,13 @@ export const IDL: Forum = {
           "isSigner": false
         },
         {
-          "name": "aboutMe",
+          "name": "bigNote",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "receiver",
-          "isMut": true,
+          "name": "bigNoteSeed",
+          "isMut": false,
           "isSigner": false
         },
         {
@@ -5903,13 +12670,13 @@ export const IDL: Forum = {
           "type": "u8"
         },
         {
-          "name": "bumpAboutMe",
+          "name": "bumpBigNote",
           "type": "u8"
         }
       ]
     },
     {
-      "name": "addModerator",
+      "name": "editBigNoteOpenContribution",
       "accounts": [
         {
           "name": "forum",
@@ -5917,46 +12684,20 @@ export const IDL: Forum = {
           "isSigner": false
         },
         {
-          "name": "forumManager",
-          "isMut": false,
+          "name": "editor",
+          "isMut": true,
           "isSigner": true
         },
         {
-          "name": "profileOwner",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "userProfile",
+          "name": "editorProfile",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "bumpUserProfile",
-          "type": "u8"
-        }
-      ]
-    },
-    {
-      "name": "removeModerator",
-      "accounts": [
-        {
-          "name": "forum",
+          "name": "sessionKey",
           "isMut": false,
           "isSigner": false
         },
-        {
-          "name": "forumManager",
-          "isMut": false,
-          "isSigner": true
-        },
         {
           "name": "profileOwner",
           "isMut": false,
@@ -5968,78 +12709,59 @@ export const IDL: Forum = {
           "isSigner": false
         },
         {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "bumpUserProfile",
-          "type": "u8"
-        }
-      ]
-    },
-    {
-      "name": "askQuestion",
-      "accounts": [
-        {
-          "name": "forum",
+          "name": "bigNote",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "forumTreasury",
-          "isMut": true,
+          "name": "bigNoteSeed",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "profileOwner",
+          "name": "verificationRecord",
           "isMut": true,
-          "isSigner": true
+          "isSigner": false
         },
         {
-          "name": "userProfile",
-          "isMut": true,
+          "name": "newContentDataHash",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "question",
+          "name": "revision",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "questionSeed",
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpEditorProfile",
+          "type": "u8"
         },
         {
-          "name": "contentDataHash",
-          "isMut": false,
-          "isSigner": false
+          "name": "bumpUserProfile",
+          "type": "u8"
         },
         {
-          "name": "bountyPda",
-          "isMut": true,
-          "isSigner": false
+          "name": "bumpBigNote",
+          "type": "u8"
         },
         {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "bumpTreasury",
+          "name": "bumpSessionKey",
           "type": "u8"
         },
         {
-          "name": "bumpUserProfile",
+          "name": "bumpVerificationRecord",
           "type": "u8"
         },
         {
-          "name": "tags",
+          "name": "newTags",
           "type": {
             "vec": {
               "defined": "Tags"
@@ -6047,21 +12769,17 @@ export const IDL: Forum = {
           }
         },
         {
-          "name": "title",
+          "name": "newTitle",
           "type": "string"
         },
         {
-          "name": "contentDataUrl",
+          "name": "newContentDataUrl",
           "type": "string"
-        },
-        {
-          "name": "bountyAmount",
-          "type": "u64"
         }
       ]
     },
     {
-      "name": "editQuestion",
+      "name": "editBigNoteCreatorCurated",
       "accounts": [
         {
           "name": "forum",
@@ -6069,30 +12787,55 @@ export const IDL: Forum = {
           "isSigner": false
         },
         {
-          "name": "profileOwner",
+          "name": "editor",
           "isMut": true,
           "isSigner": true
         },
+        {
+          "name": "editorProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "sessionKey",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "profileOwner",
+          "isMut": false,
+          "isSigner": false
+        },
         {
           "name": "userProfile",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "question",
+          "name": "bigNote",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "questionSeed",
+          "name": "bigNoteSeed",
           "isMut": false,
           "isSigner": false
         },
+        {
+          "name": "verificationRecord",
+          "isMut": true,
+          "isSigner": false
+        },
         {
           "name": "newContentDataHash",
           "isMut": false,
           "isSigner": false
         },
+        {
+          "name": "revision",
+          "isMut": true,
+          "isSigner": false
+        },
         {
           "name": "systemProgram",
           "isMut": false,
@@ -6100,12 +12843,24 @@ export const IDL: Forum = {
         }
       ],
       "args": [
+        {
+          "name": "bumpEditorProfile",
+          "type": "u8"
+        },
         {
           "name": "bumpUserProfile",
           "type": "u8"
         },
         {
-          "name": "bumpQuestion",
+          "name": "bumpBigNote",
+          "type": "u8"
+        },
+        {
+          "name": "bumpSessionKey",
+          "type": "u8"
+        },
+        {
+          "name": "bumpVerificationRecord",
           "type": "u8"
         },
         {
@@ -6127,7 +12882,7 @@ export const IDL: Forum = {
       ]
     },
     {
-      "name": "editQuestionModerator",
+      "name": "editBigNoteModerator",
       "accounts": [
         {
           "name": "forum",
@@ -6155,12 +12910,17 @@ export const IDL: Forum = {
           "isSigner": false
         },
         {
-          "name": "question",
+          "name": "bigNote",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "questionSeed",
+          "name": "bigNoteSeed",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "category",
           "isMut": false,
           "isSigner": false
         },
@@ -6169,6 +12929,11 @@ export const IDL: Forum = {
           "isMut": false,
           "isSigner": false
         },
+        {
+          "name": "revision",
+          "isMut": true,
+          "isSigner": false
+        },
         {
           "name": "systemProgram",
           "isMut": false,
@@ -6185,7 +12950,7 @@ export const IDL: Forum = {
           "type": "u8"
         },
         {
-          "name": "bumpQuestion",
+          "name": "bumpBigNote",
           "type": "u8"
         },
         {
@@ -6207,7 +12972,7 @@ export const IDL: Forum = {
       ]
     },
     {
-      "name": "deleteQuestionModerator",
+      "name": "deleteBigNoteModerator",
       "accounts": [
         {
           "name": "forum",
@@ -6235,12 +13000,30 @@ export const IDL: Forum = {
           "isSigner": false
         },
         {
-          "name": "question",
+          "name": "bigNote",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "questionSeed",
+          "name": "bigNoteSeed",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "category",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "answerUserProfile",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "was converted from a question, and left untouched if the profile has since been deleted)"
+          ]
+        },
+        {
+          "name": "answerSeed",
           "isMut": false,
           "isSigner": false
         },
@@ -6265,13 +13048,13 @@ export const IDL: Forum = {
           "type": "u8"
         },
         {
-          "name": "bumpQuestion",
+          "name": "bumpBigNote",
           "type": "u8"
         }
       ]
     },
     {
-      "name": "supplementQuestionBounty",
+      "name": "supplementBigNoteBounty",
       "accounts": [
         {
           "name": "forum",
@@ -6304,12 +13087,17 @@ export const IDL: Forum = {
           "isSigner": false
         },
         {
-          "name": "question",
+          "name": "bigNote",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "questionSeed",
+          "name": "category",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bigNoteSeed",
           "isMut": false,
           "isSigner": false
         },
@@ -6338,7 +13126,7 @@ export const IDL: Forum = {
           "type": "u8"
         },
         {
-          "name": "bumpQuestion",
+          "name": "bumpBigNote",
           "type": "u8"
         },
         {
@@ -6352,7 +13140,7 @@ export const IDL: Forum = {
       ]
     },
     {
-      "name": "refundQuestionBountySupplementorModerator",
+      "name": "refundBigNoteBountySupplementorModerator",
       "accounts": [
         {
           "name": "forum",
@@ -6390,12 +13178,12 @@ export const IDL: Forum = {
           "isSigner": false
         },
         {
-          "name": "question",
+          "name": "bigNote",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "questionSeed",
+          "name": "bigNoteSeed",
           "isMut": false,
           "isSigner": false
         },
@@ -6424,7 +13212,7 @@ export const IDL: Forum = {
           "type": "u8"
         },
         {
-          "name": "bumpQuestion",
+          "name": "bumpBigNote",
           "type": "u8"
         },
         {
@@ -6434,7 +13222,7 @@ export const IDL: Forum = {
       ]
     },
     {
-      "name": "acceptAnswer",
+      "name": "acceptProposedContribution",
       "accounts": [
         {
           "name": "forum",
@@ -6452,12 +13240,12 @@ export const IDL: Forum = {
           "isSigner": false
         },
         {
-          "name": "question",
+          "name": "bigNote",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "questionSeed",
+          "name": "bigNoteSeed",
           "isMut": false,
           "isSigner": false
         },
@@ -6467,22 +13255,22 @@ export const IDL: Forum = {
           "isSigner": false
         },
         {
-          "name": "answerProfileOwner",
+          "name": "proposalProfileOwner",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "answerUserProfile",
+          "name": "proposalUserProfile",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "answer",
+          "name": "proposedContribution",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "answerSeed",
+          "name": "proposedContributionSeed",
           "isMut": false,
           "isSigner": false
         },
@@ -6503,7 +13291,7 @@ export const IDL: Forum = {
           "type": "u8"
         },
         {
-          "name": "bumpQuestion",
+          "name": "bumpBigNote",
           "type": "u8"
         },
         {
@@ -6511,26 +13299,26 @@ export const IDL: Forum = {
           "type": "u8"
         },
         {
-          "name": "bumpAnswerUserProfile",
+          "name": "bumpProposalUserProfile",
           "type": "u8"
         },
         {
-          "name": "bumpAnswer",
+          "name": "bumpProposedContribution",
           "type": "u8"
         }
       ]
     },
     {
-      "name": "answerQuestion",
+      "name": "rejectProposedContribution",
       "accounts": [
         {
           "name": "forum",
-          "isMut": true,
+          "isMut": false,
           "isSigner": false
         },
         {
           "name": "profileOwner",
-          "isMut": true,
+          "isMut": false,
           "isSigner": true
         },
         {
@@ -6539,73 +13327,32 @@ export const IDL: Forum = {
           "isSigner": false
         },
         {
-          "name": "question",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "answer",
+          "name": "bigNote",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "answerSeed",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "contentDataHash",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "bumpUserProfile",
-          "type": "u8"
-        }
-      ]
-    },
-    {
-      "name": "editAnswer",
-      "accounts": [
-        {
-          "name": "forum",
+          "name": "bigNoteSeed",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "profileOwner",
+          "name": "proposalProfileOwner",
           "isMut": false,
-          "isSigner": true
-        },
-        {
-          "name": "userProfile",
-          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "question",
+          "name": "proposalUserProfile",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "answer",
+          "name": "proposedContribution",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "answerSeed",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "newContentDataHash",
+          "name": "proposedContributionSeed",
           "isMut": false,
           "isSigner": false
         },
@@ -6621,13 +13368,21 @@ export const IDL: Forum = {
           "type": "u8"
         },
         {
-          "name": "bumpAnswer",
+          "name": "bumpBigNote",
+          "type": "u8"
+        },
+        {
+          "name": "bumpProposalUserProfile",
+          "type": "u8"
+        },
+        {
+          "name": "bumpProposedContribution",
           "type": "u8"
         }
       ]
     },
     {
-      "name": "editAnswerModerator",
+      "name": "applyForBigNoteVerification",
       "accounts": [
         {
           "name": "forum",
@@ -6635,43 +13390,43 @@ export const IDL: Forum = {
           "isSigner": false
         },
         {
-          "name": "moderator",
-          "isMut": false,
+          "name": "profileOwner",
+          "isMut": true,
           "isSigner": true
         },
         {
-          "name": "moderatorProfile",
+          "name": "userProfile",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "profileOwner",
-          "isMut": false,
+          "name": "bigNote",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "userProfile",
+          "name": "category",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "question",
-          "isMut": true,
+          "name": "bigNoteSeed",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "answer",
+          "name": "verificationApplication",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "answerSeed",
-          "isMut": false,
+          "name": "verificationFeePda",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "newContentDataHash",
-          "isMut": false,
+          "name": "verificationRecord",
+          "isMut": true,
           "isSigner": false
         },
         {
@@ -6681,31 +13436,27 @@ export const IDL: Forum = {
         }
       ],
       "args": [
-        {
-          "name": "bumpModeratorProfile",
-          "type": "u8"
-        },
         {
           "name": "bumpUserProfile",
           "type": "u8"
         },
         {
-          "name": "bumpAnswer",
+          "name": "bumpBigNote",
           "type": "u8"
         }
       ]
     },
     {
-      "name": "deleteAnswer",
+      "name": "deleteBigNoteVerificationApplication",
       "accounts": [
         {
           "name": "forum",
-          "isMut": true,
+          "isMut": false,
           "isSigner": false
         },
         {
           "name": "profileOwner",
-          "isMut": false,
+          "isMut": true,
           "isSigner": true
         },
         {
@@ -6714,22 +13465,22 @@ export const IDL: Forum = {
           "isSigner": false
         },
         {
-          "name": "question",
+          "name": "bigNote",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "answer",
-          "isMut": true,
+          "name": "bigNoteSeed",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "answerSeed",
-          "isMut": false,
+          "name": "verificationApplication",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "receiver",
+          "name": "verificationFeePda",
           "isMut": true,
           "isSigner": false
         },
@@ -6745,17 +13496,25 @@ export const IDL: Forum = {
           "type": "u8"
         },
         {
-          "name": "bumpAnswer",
+          "name": "bumpBigNote",
+          "type": "u8"
+        },
+        {
+          "name": "bumpVerificationApplication",
+          "type": "u8"
+        },
+        {
+          "name": "bumpVerificationFeePda",
           "type": "u8"
         }
       ]
     },
     {
-      "name": "deleteAnswerModerator",
+      "name": "deleteBigNoteVerificationApplicationModerator",
       "accounts": [
         {
           "name": "forum",
-          "isMut": true,
+          "isMut": false,
           "isSigner": false
         },
         {
@@ -6770,31 +13529,31 @@ export const IDL: Forum = {
         },
         {
           "name": "profileOwner",
-          "isMut": false,
+          "isMut": true,
           "isSigner": false
         },
         {
           "name": "userProfile",
-          "isMut": true,
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "question",
+          "name": "bigNote",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "answer",
-          "isMut": true,
+          "name": "bigNoteSeed",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "answerSeed",
-          "isMut": false,
+          "name": "verificationApplication",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "receiver",
+          "name": "verificationFeePda",
           "isMut": true,
           "isSigner": false
         },
@@ -6814,49 +13573,87 @@ export const IDL: Forum = {
           "type": "u8"
         },
         {
-          "name": "bumpAnswer",
+          "name": "bumpBigNote",
+          "type": "u8"
+        },
+        {
+          "name": "bumpVerificationApplication",
+          "type": "u8"
+        },
+        {
+          "name": "bumpVerificationFeePda",
           "type": "u8"
         }
       ]
     },
     {
-      "name": "leaveCommentOnQuestion",
+      "name": "attestBigNoteVerification",
       "accounts": [
         {
           "name": "forum",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "forumTreasury",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "profileOwner",
+          "name": "attester",
           "isMut": true,
           "isSigner": true
         },
         {
-          "name": "userProfile",
+          "name": "attesterProfile",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "question",
+          "name": "profileOwner",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "comment",
+          "name": "userProfile",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "commentSeed",
+          "name": "bigNote",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "category",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "contentDataHash",
+          "name": "bigNoteSeed",
           "isMut": false,
           "isSigner": false
         },
+        {
+          "name": "verificationApplication",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "verificationFeePda",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "verificationRecord",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "verificationAttestation",
+          "isMut": true,
+          "isSigner": false
+        },
         {
           "name": "systemProgram",
           "isMut": false,
@@ -6864,14 +13661,38 @@ export const IDL: Forum = {
         }
       ],
       "args": [
+        {
+          "name": "bumpTreasury",
+          "type": "u8"
+        },
+        {
+          "name": "bumpAttesterProfile",
+          "type": "u8"
+        },
         {
           "name": "bumpUserProfile",
           "type": "u8"
+        },
+        {
+          "name": "bumpBigNote",
+          "type": "u8"
+        },
+        {
+          "name": "bumpVerificationApplication",
+          "type": "u8"
+        },
+        {
+          "name": "bumpVerificationFeePda",
+          "type": "u8"
+        },
+        {
+          "name": "bumpVerificationRecord",
+          "type": "u8"
         }
       ]
     },
     {
-      "name": "editCommentOnQuestion",
+      "name": "rejectBigNoteVerificationApplication",
       "accounts": [
         {
           "name": "forum",
@@ -6879,33 +13700,43 @@ export const IDL: Forum = {
           "isSigner": false
         },
         {
-          "name": "profileOwner",
+          "name": "moderator",
           "isMut": false,
           "isSigner": true
         },
         {
-          "name": "userProfile",
+          "name": "moderatorProfile",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "question",
+          "name": "profileOwner",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "comment",
+          "name": "userProfile",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bigNote",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "commentSeed",
+          "name": "bigNoteSeed",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "newContentDataHash",
-          "isMut": false,
+          "name": "verificationApplication",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "verificationFeePda",
+          "isMut": true,
           "isSigner": false
         },
         {
@@ -6915,24 +13746,41 @@ export const IDL: Forum = {
         }
       ],
       "args": [
+        {
+          "name": "bumpModeratorProfile",
+          "type": "u8"
+        },
         {
           "name": "bumpUserProfile",
           "type": "u8"
         },
         {
-          "name": "bumpComment",
+          "name": "bumpBigNote",
+          "type": "u8"
+        },
+        {
+          "name": "bumpVerificationApplication",
+          "type": "u8"
+        },
+        {
+          "name": "bumpVerificationFeePda",
           "type": "u8"
         }
       ]
     },
     {
-      "name": "editCommentOnQuestionModerator",
+      "name": "revokeBigNoteVerification",
       "accounts": [
         {
           "name": "forum",
           "isMut": false,
           "isSigner": false
         },
+        {
+          "name": "forumTreasury",
+          "isMut": true,
+          "isSigner": false
+        },
         {
           "name": "moderator",
           "isMut": false,
@@ -6943,33 +13791,23 @@ export const IDL: Forum = {
           "isMut": true,
           "isSigner": false
         },
-        {
-          "name": "profileOwner",
-          "isMut": false,
-          "isSigner": false
-        },
         {
           "name": "userProfile",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "question",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "comment",
+          "name": "bigNote",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "commentSeed",
+          "name": "bigNoteSeed",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "newContentDataHash",
+          "name": "verificationRecord",
           "isMut": false,
           "isSigner": false
         },
@@ -6985,50 +13823,49 @@ export const IDL: Forum = {
           "type": "u8"
         },
         {
-          "name": "bumpUserProfile",
+          "name": "bumpBigNote",
           "type": "u8"
         },
         {
-          "name": "bumpComment",
+          "name": "bumpVerificationRecord",
           "type": "u8"
+        },
+        {
+          "name": "slashAttesters",
+          "type": "bool"
         }
       ]
     },
     {
-      "name": "deleteCommentOnQuestion",
+      "name": "withdrawVerificationAttestation",
       "accounts": [
         {
           "name": "forum",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "profileOwner",
           "isMut": false,
-          "isSigner": true
+          "isSigner": false
         },
         {
-          "name": "userProfile",
+          "name": "attester",
           "isMut": true,
-          "isSigner": false
+          "isSigner": true
         },
         {
-          "name": "question",
+          "name": "attesterProfile",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "comment",
-          "isMut": true,
+          "name": "bigNote",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "commentSeed",
+          "name": "verificationRecord",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "receiver",
+          "name": "verificationAttestation",
           "isMut": true,
           "isSigner": false
         },
@@ -7040,37 +13877,27 @@ export const IDL: Forum = {
       ],
       "args": [
         {
-          "name": "bumpUserProfile",
+          "name": "bumpAttesterProfile",
           "type": "u8"
         },
         {
-          "name": "bumpComment",
+          "name": "bumpVerificationRecord",
           "type": "u8"
         }
       ]
     },
     {
-      "name": "deleteCommentOnQuestionModerator",
+      "name": "expireBigNoteVerification",
       "accounts": [
         {
           "name": "forum",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "moderator",
           "isMut": false,
-          "isSigner": true
-        },
-        {
-          "name": "moderatorProfile",
-          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "profileOwner",
+          "name": "cranker",
           "isMut": false,
-          "isSigner": false
+          "isSigner": true
         },
         {
           "name": "userProfile",
@@ -7078,48 +13905,34 @@ export const IDL: Forum = {
           "isSigner": false
         },
         {
-          "name": "question",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "comment",
+          "name": "bigNote",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "commentSeed",
+          "name": "bigNoteSeed",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "receiver",
+          "name": "verificationRecord",
           "isMut": true,
           "isSigner": false
-        },
-        {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "bumpModeratorProfile",
-          "type": "u8"
-        },
-        {
-          "name": "bumpUserProfile",
+          "name": "bumpBigNote",
           "type": "u8"
         },
         {
-          "name": "bumpComment",
+          "name": "bumpVerificationRecord",
           "type": "u8"
         }
       ]
     },
     {
-      "name": "leaveCommentOnAnswer",
+      "name": "proposeContribution",
       "accounts": [
         {
           "name": "forum",
@@ -7127,7 +13940,7 @@ export const IDL: Forum = {
           "isSigner": false
         },
         {
-          "name": "profileOwner",
+          "name": "authority",
           "isMut": true,
           "isSigner": true
         },
@@ -7137,22 +13950,22 @@ export const IDL: Forum = {
           "isSigner": false
         },
         {
-          "name": "question",
-          "isMut": true,
+          "name": "sessionKey",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "answer",
+          "name": "bigNote",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "comment",
+          "name": "proposedContribution",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "commentSeed",
+          "name": "proposedContributionSeed",
           "isMut": false,
           "isSigner": false
         },
@@ -7171,11 +13984,15 @@ export const IDL: Forum = {
         {
           "name": "bumpUserProfile",
           "type": "u8"
+        },
+        {
+          "name": "bumpSessionKey",
+          "type": "u8"
         }
       ]
     },
     {
-      "name": "editCommentOnAnswer",
+      "name": "editProposedContribution",
       "accounts": [
         {
           "name": "forum",
@@ -7193,22 +14010,17 @@ export const IDL: Forum = {
           "isSigner": false
         },
         {
-          "name": "question",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "answer",
+          "name": "bigNote",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "comment",
+          "name": "proposedContribution",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "commentSeed",
+          "name": "proposedContributionSeed",
           "isMut": false,
           "isSigner": false
         },
@@ -7229,13 +14041,13 @@ export const IDL: Forum = {
           "type": "u8"
         },
         {
-          "name": "bumpComment",
+          "name": "bumpProposedContribution",
           "type": "u8"
         }
       ]
     },
     {
-      "name": "editCommentOnAnswerModerator",
+      "name": "editProposedContributionModerator",
       "accounts": [
         {
           "name": "forum",
@@ -7257,28 +14069,23 @@ export const IDL: Forum = {
           "isMut": false,
           "isSigner": false
         },
-        {
-          "name": "userProfile",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "question",
-          "isMut": true,
+        {
+          "name": "userProfile",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "answer",
+          "name": "bigNote",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "comment",
+          "name": "proposedContribution",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "commentSeed",
+          "name": "proposedContributionSeed",
           "isMut": false,
           "isSigner": false
         },
@@ -7303,13 +14110,13 @@ export const IDL: Forum = {
           "type": "u8"
         },
         {
-          "name": "bumpComment",
+          "name": "bumpProposedContribution",
           "type": "u8"
         }
       ]
     },
     {
-      "name": "deleteCommentOnAnswer",
+      "name": "deleteProposedContribution",
       "accounts": [
         {
           "name": "forum",
@@ -7327,22 +14134,17 @@ export const IDL: Forum = {
           "isSigner": false
         },
         {
-          "name": "question",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "answer",
+          "name": "bigNote",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "comment",
+          "name": "proposedContribution",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "commentSeed",
+          "name": "proposedContributionSeed",
           "isMut": false,
           "isSigner": false
         },
@@ -7363,13 +14165,13 @@ export const IDL: Forum = {
           "type": "u8"
         },
         {
-          "name": "bumpComment",
+          "name": "bumpProposedContribution",
           "type": "u8"
         }
       ]
     },
     {
-      "name": "deleteCommentOnAnswerModerator",
+      "name": "deleteProposedContributionModerator",
       "accounts": [
         {
           "name": "forum",
@@ -7397,22 +14199,17 @@ export const IDL: Forum = {
           "isSigner": false
         },
         {
-          "name": "question",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "answer",
+          "name": "bigNote",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "comment",
+          "name": "proposedContribution",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "commentSeed",
+          "name": "proposedContributionSeed",
           "isMut": false,
           "isSigner": false
         },
@@ -7437,13 +14234,13 @@ export const IDL: Forum = {
           "type": "u8"
         },
         {
-          "name": "bumpComment",
+          "name": "bumpProposedContribution",
           "type": "u8"
         }
       ]
     },
     {
-      "name": "createBigNote",
+      "name": "createChallenge",
       "accounts": [
         {
           "name": "forum",
@@ -7451,27 +14248,27 @@ export const IDL: Forum = {
           "isSigner": false
         },
         {
-          "name": "forumTreasury",
+          "name": "moderator",
           "isMut": true,
-          "isSigner": false
+          "isSigner": true
         },
         {
-          "name": "profileOwner",
+          "name": "moderatorProfile",
           "isMut": true,
-          "isSigner": true
+          "isSigner": false
         },
         {
-          "name": "userProfile",
+          "name": "category",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "bigNote",
+          "name": "challenge",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "bigNoteSeed",
+          "name": "challengeSeed",
           "isMut": false,
           "isSigner": false
         },
@@ -7480,11 +14277,6 @@ export const IDL: Forum = {
           "isMut": false,
           "isSigner": false
         },
-        {
-          "name": "bountyPda",
-          "isMut": true,
-          "isSigner": false
-        },
         {
           "name": "systemProgram",
           "isMut": false,
@@ -7493,19 +14285,9 @@ export const IDL: Forum = {
       ],
       "args": [
         {
-          "name": "bumpTreasury",
-          "type": "u8"
-        },
-        {
-          "name": "bumpUserProfile",
+          "name": "bumpModeratorProfile",
           "type": "u8"
         },
-        {
-          "name": "bigNoteType",
-          "type": {
-            "defined": "BigNoteType"
-          }
-        },
         {
           "name": "tags",
           "type": {
@@ -7521,11 +14303,27 @@ export const IDL: Forum = {
         {
           "name": "contentDataUrl",
           "type": "string"
+        },
+        {
+          "name": "challengeExpiresTs",
+          "type": "u64"
+        },
+        {
+          "name": "reputation",
+          "type": "u64"
+        },
+        {
+          "name": "rubric",
+          "type": {
+            "vec": {
+              "defined": "RubricCriterion"
+            }
+          }
         }
       ]
     },
     {
-      "name": "editBigNoteOpenContribution",
+      "name": "proposeChallenge",
       "accounts": [
         {
           "name": "forum",
@@ -7533,38 +14331,38 @@ export const IDL: Forum = {
           "isSigner": false
         },
         {
-          "name": "editor",
+          "name": "profileOwner",
           "isMut": true,
           "isSigner": true
         },
         {
-          "name": "editorProfile",
+          "name": "userProfile",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "profileOwner",
+          "name": "category",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "userProfile",
+          "name": "challenge",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "bigNote",
-          "isMut": true,
+          "name": "challengeSeed",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "bigNoteSeed",
+          "name": "contentDataHash",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "newContentDataHash",
-          "isMut": false,
+          "name": "challengeProposal",
+          "isMut": true,
           "isSigner": false
         },
         {
@@ -7574,20 +14372,12 @@ export const IDL: Forum = {
         }
       ],
       "args": [
-        {
-          "name": "bumpEditorProfile",
-          "type": "u8"
-        },
         {
           "name": "bumpUserProfile",
           "type": "u8"
         },
         {
-          "name": "bumpBigNote",
-          "type": "u8"
-        },
-        {
-          "name": "newTags",
+          "name": "tags",
           "type": {
             "vec": {
               "defined": "Tags"
@@ -7595,92 +14385,42 @@ export const IDL: Forum = {
           }
         },
         {
-          "name": "newTitle",
+          "name": "title",
           "type": "string"
         },
         {
-          "name": "newContentDataUrl",
+          "name": "contentDataUrl",
           "type": "string"
-        }
-      ]
-    },
-    {
-      "name": "editBigNoteCreatorCurated",
-      "accounts": [
-        {
-          "name": "forum",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "profileOwner",
-          "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "userProfile",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "bigNote",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "bigNoteSeed",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "newContentDataHash",
-          "isMut": false,
-          "isSigner": false
         },
         {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "bumpUserProfile",
-          "type": "u8"
+          "name": "challengeExpiresTs",
+          "type": "u64"
         },
         {
-          "name": "bumpBigNote",
-          "type": "u8"
+          "name": "reputation",
+          "type": "u64"
         },
         {
-          "name": "newTags",
+          "name": "rubric",
           "type": {
             "vec": {
-              "defined": "Tags"
+              "defined": "RubricCriterion"
             }
           }
-        },
-        {
-          "name": "newTitle",
-          "type": "string"
-        },
-        {
-          "name": "newContentDataUrl",
-          "type": "string"
         }
       ]
     },
     {
-      "name": "editBigNoteModerator",
+      "name": "approveChallengeProposal",
       "accounts": [
         {
           "name": "forum",
-          "isMut": false,
+          "isMut": true,
           "isSigner": false
         },
         {
           "name": "moderator",
-          "isMut": true,
+          "isMut": false,
           "isSigner": true
         },
         {
@@ -7689,28 +14429,33 @@ export const IDL: Forum = {
           "isSigner": false
         },
         {
-          "name": "profileOwner",
-          "isMut": false,
+          "name": "author",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "userProfile",
+          "name": "authorProfile",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "challenge",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "bigNote",
+          "name": "category",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "bigNoteSeed",
+          "name": "challengeSeed",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "newContentDataHash",
-          "isMut": false,
+          "name": "challengeProposal",
+          "isMut": true,
           "isSigner": false
         },
         {
@@ -7725,37 +14470,25 @@ export const IDL: Forum = {
           "type": "u8"
         },
         {
-          "name": "bumpUserProfile",
+          "name": "bumpAuthorProfile",
           "type": "u8"
         },
         {
-          "name": "bumpBigNote",
+          "name": "bumpChallenge",
           "type": "u8"
         },
         {
-          "name": "newTags",
-          "type": {
-            "vec": {
-              "defined": "Tags"
-            }
-          }
-        },
-        {
-          "name": "newTitle",
-          "type": "string"
-        },
-        {
-          "name": "newContentDataUrl",
-          "type": "string"
+          "name": "bumpChallengeProposal",
+          "type": "u8"
         }
       ]
     },
     {
-      "name": "deleteBigNoteModerator",
+      "name": "rejectChallengeProposal",
       "accounts": [
         {
           "name": "forum",
-          "isMut": true,
+          "isMut": false,
           "isSigner": false
         },
         {
@@ -7769,27 +14502,37 @@ export const IDL: Forum = {
           "isSigner": false
         },
         {
-          "name": "profileOwner",
-          "isMut": false,
+          "name": "forumTreasury",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "userProfile",
+          "name": "author",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "bigNote",
-          "isMut": true,
+          "name": "authorProfile",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "bigNoteSeed",
+          "name": "challenge",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "receiver",
+          "name": "category",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "challengeSeed",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "challengeProposal",
           "isMut": true,
           "isSigner": false
         },
@@ -7805,17 +14548,25 @@ export const IDL: Forum = {
           "type": "u8"
         },
         {
-          "name": "bumpUserProfile",
+          "name": "bumpAuthorProfile",
           "type": "u8"
         },
         {
-          "name": "bumpBigNote",
+          "name": "bumpChallenge",
           "type": "u8"
+        },
+        {
+          "name": "bumpChallengeProposal",
+          "type": "u8"
+        },
+        {
+          "name": "slashDeposit",
+          "type": "bool"
         }
       ]
     },
     {
-      "name": "supplementBigNoteBounty",
+      "name": "editChallenge",
       "accounts": [
         {
           "name": "forum",
@@ -7823,43 +14574,33 @@ export const IDL: Forum = {
           "isSigner": false
         },
         {
-          "name": "forumTreasury",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "supplementor",
+          "name": "moderator",
           "isMut": true,
           "isSigner": true
         },
         {
-          "name": "supplementorProfile",
+          "name": "moderatorProfile",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "profileOwner",
-          "isMut": false,
+          "name": "challenge",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "userProfile",
+          "name": "category",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "bigNote",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "bigNoteSeed",
+          "name": "challengeSeed",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "bountyPda",
-          "isMut": true,
+          "name": "newContentDataHash",
+          "isMut": false,
           "isSigner": false
         },
         {
@@ -7870,37 +14611,45 @@ export const IDL: Forum = {
       ],
       "args": [
         {
-          "name": "bumpTreasury",
+          "name": "bumpModeratorProfile",
           "type": "u8"
         },
         {
-          "name": "bumpSupplementorProfile",
+          "name": "bumpChallenge",
           "type": "u8"
         },
         {
-          "name": "bumpUserProfile",
-          "type": "u8"
+          "name": "newTags",
+          "type": {
+            "vec": {
+              "defined": "Tags"
+            }
+          }
         },
         {
-          "name": "bumpBigNote",
-          "type": "u8"
+          "name": "newTitle",
+          "type": "string"
         },
         {
-          "name": "bumpBountyPda",
-          "type": "u8"
+          "name": "newContentDataUrl",
+          "type": "string"
         },
         {
-          "name": "supplementalBountyAmount",
+          "name": "newChallengeExpiresTs",
+          "type": "u64"
+        },
+        {
+          "name": "newReputation",
           "type": "u64"
         }
       ]
     },
     {
-      "name": "refundBigNoteBountySupplementorModerator",
+      "name": "deleteChallenge",
       "accounts": [
         {
           "name": "forum",
-          "isMut": false,
+          "isMut": true,
           "isSigner": false
         },
         {
@@ -7914,37 +14663,37 @@ export const IDL: Forum = {
           "isSigner": false
         },
         {
-          "name": "profileOwner",
-          "isMut": false,
+          "name": "challenge",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "userProfile",
+          "name": "challengeSeed",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "supplementor",
+          "name": "challengeProposal",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "supplementorProfile",
+          "name": "category",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "bigNote",
+          "name": "prizePool",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "bigNoteSeed",
+          "name": "trackMembership",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "bountyPda",
+          "name": "receiver",
           "isMut": true,
           "isSigner": false
         },
@@ -7960,25 +14709,25 @@ export const IDL: Forum = {
           "type": "u8"
         },
         {
-          "name": "bumpUserProfile",
+          "name": "bumpChallenge",
           "type": "u8"
         },
         {
-          "name": "bumpSupplementorProfile",
+          "name": "bumpPrizePool",
           "type": "u8"
         },
         {
-          "name": "bumpBigNote",
+          "name": "bumpChallengeProposal",
           "type": "u8"
         },
         {
-          "name": "bumpBountyPda",
+          "name": "bumpTrackMembership",
           "type": "u8"
         }
       ]
     },
     {
-      "name": "acceptProposedContribution",
+      "name": "closeChallenge",
       "accounts": [
         {
           "name": "forum",
@@ -7986,52 +14735,113 @@ export const IDL: Forum = {
           "isSigner": false
         },
         {
-          "name": "profileOwner",
+          "name": "cranker",
           "isMut": false,
           "isSigner": true
         },
         {
-          "name": "userProfile",
+          "name": "challenge",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "bigNote",
-          "isMut": true,
+          "name": "challengeSeed",
+          "isMut": false,
           "isSigner": false
-        },
+        }
+      ],
+      "args": [
         {
-          "name": "bigNoteSeed",
+          "name": "bumpChallenge",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "archiveChallenge",
+      "accounts": [
+        {
+          "name": "forum",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "bountyPda",
+          "name": "moderator",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "moderatorProfile",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "proposalProfileOwner",
+          "name": "challenge",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "proposalUserProfile",
-          "isMut": true,
+          "name": "category",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "proposedContribution",
+          "name": "challengeSeed",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpModeratorProfile",
+          "type": "u8"
+        },
+        {
+          "name": "bumpChallenge",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "createChallengePrizePool",
+      "accounts": [
+        {
+          "name": "forum",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "moderator",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "moderatorProfile",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "proposedContributionSeed",
+          "name": "challenge",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "receiver",
+          "name": "category",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "challengeSeed",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "challengeProposal",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "prizePool",
           "isMut": true,
           "isSigner": false
         },
@@ -8043,29 +14853,41 @@ export const IDL: Forum = {
       ],
       "args": [
         {
-          "name": "bumpUserProfile",
+          "name": "bumpModeratorProfile",
           "type": "u8"
         },
         {
-          "name": "bumpBigNote",
+          "name": "bumpChallenge",
           "type": "u8"
         },
         {
-          "name": "bumpBountyPda",
+          "name": "bumpChallengeProposal",
           "type": "u8"
         },
         {
-          "name": "bumpProposalUserProfile",
-          "type": "u8"
+          "name": "prizeDistribution",
+          "type": {
+            "defined": "PrizeDistribution"
+          }
         },
         {
-          "name": "bumpProposedContribution",
-          "type": "u8"
+          "name": "winnerCount",
+          "type": "u64"
+        },
+        {
+          "name": "prizePerWinner",
+          "type": "u64"
+        },
+        {
+          "name": "tierBps",
+          "type": {
+            "vec": "u16"
+          }
         }
       ]
     },
     {
-      "name": "rejectProposedContribution",
+      "name": "createChallengePrizeVault",
       "accounts": [
         {
           "name": "forum",
@@ -8073,42 +14895,47 @@ export const IDL: Forum = {
           "isSigner": false
         },
         {
-          "name": "profileOwner",
-          "isMut": false,
+          "name": "moderator",
+          "isMut": true,
           "isSigner": true
         },
         {
-          "name": "userProfile",
+          "name": "moderatorProfile",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "bigNote",
-          "isMut": true,
+          "name": "challenge",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "bigNoteSeed",
+          "name": "category",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "proposalProfileOwner",
+          "name": "challengeSeed",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "proposalUserProfile",
+          "name": "prizePool",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "proposedContribution",
+          "name": "prizeMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "prizeVault",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "proposedContributionSeed",
+          "name": "tokenProgram",
           "isMut": false,
           "isSigner": false
         },
@@ -8120,25 +14947,21 @@ export const IDL: Forum = {
       ],
       "args": [
         {
-          "name": "bumpUserProfile",
-          "type": "u8"
-        },
-        {
-          "name": "bumpBigNote",
+          "name": "bumpModeratorProfile",
           "type": "u8"
         },
         {
-          "name": "bumpProposalUserProfile",
+          "name": "bumpChallenge",
           "type": "u8"
         },
         {
-          "name": "bumpProposedContribution",
+          "name": "bumpPrizePool",
           "type": "u8"
         }
       ]
     },
     {
-      "name": "applyForBigNoteVerification",
+      "name": "fundChallengePrizePool",
       "accounts": [
         {
           "name": "forum",
@@ -8146,32 +14969,37 @@ export const IDL: Forum = {
           "isSigner": false
         },
         {
-          "name": "profileOwner",
+          "name": "sponsor",
           "isMut": true,
           "isSigner": true
         },
         {
-          "name": "userProfile",
+          "name": "sponsorProfile",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "bigNote",
-          "isMut": true,
+          "name": "challenge",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "bigNoteSeed",
+          "name": "challengeSeed",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "verificationApplication",
+          "name": "challengeProposal",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "prizePool",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "verificationFeePda",
+          "name": "prizeContribution",
           "isMut": true,
           "isSigner": false
         },
@@ -8183,17 +15011,29 @@ export const IDL: Forum = {
       ],
       "args": [
         {
-          "name": "bumpUserProfile",
+          "name": "bumpSponsorProfile",
           "type": "u8"
         },
         {
-          "name": "bumpBigNote",
+          "name": "bumpChallenge",
+          "type": "u8"
+        },
+        {
+          "name": "bumpPrizePool",
+          "type": "u8"
+        },
+        {
+          "name": "bumpChallengeProposal",
           "type": "u8"
+        },
+        {
+          "name": "prizeAmount",
+          "type": "u64"
         }
       ]
     },
     {
-      "name": "deleteBigNoteVerificationApplication",
+      "name": "refundChallengePrizeContribution",
       "accounts": [
         {
           "name": "forum",
@@ -8201,35 +15041,40 @@ export const IDL: Forum = {
           "isSigner": false
         },
         {
-          "name": "profileOwner",
+          "name": "sponsor",
           "isMut": true,
           "isSigner": true
         },
         {
-          "name": "userProfile",
+          "name": "sponsorProfile",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "bigNote",
-          "isMut": true,
+          "name": "challenge",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "bigNoteSeed",
+          "name": "challengeSeed",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "verificationApplication",
+          "name": "prizePool",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "verificationFeePda",
+          "name": "prizeContribution",
           "isMut": true,
           "isSigner": false
         },
+        {
+          "name": "leaderboard",
+          "isMut": false,
+          "isSigner": false
+        },
         {
           "name": "systemProgram",
           "isMut": false,
@@ -8238,25 +15083,29 @@ export const IDL: Forum = {
       ],
       "args": [
         {
-          "name": "bumpUserProfile",
+          "name": "bumpSponsorProfile",
           "type": "u8"
         },
         {
-          "name": "bumpBigNote",
+          "name": "bumpChallenge",
           "type": "u8"
         },
         {
-          "name": "bumpVerificationApplication",
+          "name": "bumpPrizePool",
           "type": "u8"
         },
         {
-          "name": "bumpVerificationFeePda",
+          "name": "bumpPrizeContribution",
+          "type": "u8"
+        },
+        {
+          "name": "bumpLeaderboard",
           "type": "u8"
         }
       ]
     },
     {
-      "name": "deleteBigNoteVerificationApplicationModerator",
+      "name": "createEvaluationPanel",
       "accounts": [
         {
           "name": "forum",
@@ -8265,7 +15114,7 @@ export const IDL: Forum = {
         },
         {
           "name": "moderator",
-          "isMut": false,
+          "isMut": true,
           "isSigner": true
         },
         {
@@ -8274,32 +15123,27 @@ export const IDL: Forum = {
           "isSigner": false
         },
         {
-          "name": "profileOwner",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "userProfile",
+          "name": "challenge",
           "isMut": false,
           "isSigner": false
         },
-        {
-          "name": "bigNote",
-          "isMut": true,
+        {
+          "name": "category",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "bigNoteSeed",
+          "name": "challengeSeed",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "verificationApplication",
-          "isMut": true,
+          "name": "challengeProposal",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "verificationFeePda",
+          "name": "evaluationPanel",
           "isMut": true,
           "isSigner": false
         },
@@ -8315,39 +15159,36 @@ export const IDL: Forum = {
           "type": "u8"
         },
         {
-          "name": "bumpUserProfile",
+          "name": "bumpChallenge",
           "type": "u8"
         },
         {
-          "name": "bumpBigNote",
+          "name": "bumpChallengeProposal",
           "type": "u8"
         },
         {
-          "name": "bumpVerificationApplication",
-          "type": "u8"
+          "name": "judges",
+          "type": {
+            "vec": "publicKey"
+          }
         },
         {
-          "name": "bumpVerificationFeePda",
-          "type": "u8"
+          "name": "quorum",
+          "type": "u64"
         }
       ]
     },
     {
-      "name": "acceptBigNoteVerificationApplication",
+      "name": "createChallengeLeaderboard",
       "accounts": [
         {
           "name": "forum",
           "isMut": false,
           "isSigner": false
         },
-        {
-          "name": "forumTreasury",
-          "isMut": true,
-          "isSigner": false
-        },
         {
           "name": "moderator",
-          "isMut": false,
+          "isMut": true,
           "isSigner": true
         },
         {
@@ -8356,32 +15197,22 @@ export const IDL: Forum = {
           "isSigner": false
         },
         {
-          "name": "profileOwner",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "userProfile",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "bigNote",
-          "isMut": true,
+          "name": "challenge",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "bigNoteSeed",
+          "name": "category",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "verificationApplication",
-          "isMut": true,
+          "name": "challengeSeed",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "verificationFeePda",
+          "name": "leaderboard",
           "isMut": true,
           "isSigner": false
         },
@@ -8392,34 +15223,22 @@ export const IDL: Forum = {
         }
       ],
       "args": [
-        {
-          "name": "bumpTreasury",
-          "type": "u8"
-        },
         {
           "name": "bumpModeratorProfile",
           "type": "u8"
         },
         {
-          "name": "bumpUserProfile",
-          "type": "u8"
-        },
-        {
-          "name": "bumpBigNote",
-          "type": "u8"
-        },
-        {
-          "name": "bumpVerificationApplication",
+          "name": "bumpChallenge",
           "type": "u8"
         },
         {
-          "name": "bumpVerificationFeePda",
-          "type": "u8"
+          "name": "maxEntries",
+          "type": "u64"
         }
       ]
     },
     {
-      "name": "rejectBigNoteVerificationApplication",
+      "name": "closeChallengeLeaderboard",
       "accounts": [
         {
           "name": "forum",
@@ -8427,42 +15246,27 @@ export const IDL: Forum = {
           "isSigner": false
         },
         {
-          "name": "moderator",
+          "name": "cranker",
           "isMut": false,
           "isSigner": true
         },
         {
-          "name": "moderatorProfile",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "profileOwner",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "userProfile",
+          "name": "challenge",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "bigNote",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "bigNoteSeed",
+          "name": "challengeSeed",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "verificationApplication",
+          "name": "leaderboard",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "verificationFeePda",
+          "name": "prizePool",
           "isMut": true,
           "isSigner": false
         },
@@ -8474,62 +15278,44 @@ export const IDL: Forum = {
       ],
       "args": [
         {
-          "name": "bumpModeratorProfile",
-          "type": "u8"
-        },
-        {
-          "name": "bumpUserProfile",
-          "type": "u8"
-        },
-        {
-          "name": "bumpBigNote",
+          "name": "bumpChallenge",
           "type": "u8"
         },
         {
-          "name": "bumpVerificationApplication",
+          "name": "bumpLeaderboard",
           "type": "u8"
         },
         {
-          "name": "bumpVerificationFeePda",
+          "name": "bumpPrizePool",
           "type": "u8"
         }
       ]
     },
     {
-      "name": "proposeContribution",
+      "name": "createTrack",
       "accounts": [
         {
           "name": "forum",
-          "isMut": true,
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "profileOwner",
+          "name": "moderator",
           "isMut": true,
           "isSigner": true
         },
         {
-          "name": "userProfile",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "bigNote",
+          "name": "moderatorProfile",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "proposedContribution",
+          "name": "track",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "proposedContributionSeed",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "contentDataHash",
+          "name": "trackSeed",
           "isMut": false,
           "isSigner": false
         },
@@ -8541,13 +15327,17 @@ export const IDL: Forum = {
       ],
       "args": [
         {
-          "name": "bumpUserProfile",
+          "name": "bumpModeratorProfile",
           "type": "u8"
+        },
+        {
+          "name": "completionRep",
+          "type": "u64"
         }
       ]
     },
     {
-      "name": "editProposedContribution",
+      "name": "addChallengeToTrack",
       "accounts": [
         {
           "name": "forum",
@@ -8555,35 +15345,45 @@ export const IDL: Forum = {
           "isSigner": false
         },
         {
-          "name": "profileOwner",
-          "isMut": false,
+          "name": "moderator",
+          "isMut": true,
           "isSigner": true
         },
         {
-          "name": "userProfile",
+          "name": "moderatorProfile",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "bigNote",
+          "name": "track",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "proposedContribution",
-          "isMut": true,
+          "name": "trackSeed",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "proposedContributionSeed",
+          "name": "challenge",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "newContentDataHash",
+          "name": "challengeSeed",
           "isMut": false,
           "isSigner": false
         },
+        {
+          "name": "challengeProposal",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "trackMembership",
+          "isMut": true,
+          "isSigner": false
+        },
         {
           "name": "systemProgram",
           "isMut": false,
@@ -8592,17 +15392,25 @@ export const IDL: Forum = {
       ],
       "args": [
         {
-          "name": "bumpUserProfile",
+          "name": "bumpModeratorProfile",
           "type": "u8"
         },
         {
-          "name": "bumpProposedContribution",
+          "name": "bumpTrack",
+          "type": "u8"
+        },
+        {
+          "name": "bumpChallenge",
+          "type": "u8"
+        },
+        {
+          "name": "bumpChallengeProposal",
           "type": "u8"
         }
       ]
     },
     {
-      "name": "editProposedContributionModerator",
+      "name": "removeChallengeFromTrack",
       "accounts": [
         {
           "name": "forum",
@@ -8620,35 +15428,25 @@ export const IDL: Forum = {
           "isSigner": false
         },
         {
-          "name": "profileOwner",
-          "isMut": false,
+          "name": "track",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "userProfile",
+          "name": "trackSeed",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "bigNote",
+          "name": "trackMembership",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "proposedContribution",
+          "name": "receiver",
           "isMut": true,
           "isSigner": false
         },
-        {
-          "name": "proposedContributionSeed",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "newContentDataHash",
-          "isMut": false,
-          "isSigner": false
-        },
         {
           "name": "systemProgram",
           "isMut": false,
@@ -8661,26 +15459,26 @@ export const IDL: Forum = {
           "type": "u8"
         },
         {
-          "name": "bumpUserProfile",
+          "name": "bumpTrack",
           "type": "u8"
         },
         {
-          "name": "bumpProposedContribution",
+          "name": "bumpTrackMembership",
           "type": "u8"
         }
       ]
     },
     {
-      "name": "deleteProposedContribution",
+      "name": "claimTrackCompletion",
       "accounts": [
         {
           "name": "forum",
-          "isMut": true,
+          "isMut": false,
           "isSigner": false
         },
         {
           "name": "profileOwner",
-          "isMut": false,
+          "isMut": true,
           "isSigner": true
         },
         {
@@ -8689,22 +15487,17 @@ export const IDL: Forum = {
           "isSigner": false
         },
         {
-          "name": "bigNote",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "proposedContribution",
-          "isMut": true,
+          "name": "track",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "proposedContributionSeed",
+          "name": "trackSeed",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "receiver",
+          "name": "trackCompletion",
           "isMut": true,
           "isSigner": false
         },
@@ -8720,13 +15513,13 @@ export const IDL: Forum = {
           "type": "u8"
         },
         {
-          "name": "bumpProposedContribution",
+          "name": "bumpTrack",
           "type": "u8"
         }
       ]
     },
     {
-      "name": "deleteProposedContributionModerator",
+      "name": "createSubmission",
       "accounts": [
         {
           "name": "forum",
@@ -8734,45 +15527,55 @@ export const IDL: Forum = {
           "isSigner": false
         },
         {
-          "name": "moderator",
-          "isMut": false,
+          "name": "profileOwner",
+          "isMut": true,
           "isSigner": true
         },
         {
-          "name": "moderatorProfile",
+          "name": "userProfile",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "profileOwner",
-          "isMut": false,
+          "name": "challenge",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "userProfile",
-          "isMut": true,
+          "name": "category",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "bigNote",
-          "isMut": true,
+          "name": "challengeSeed",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "proposedContribution",
-          "isMut": true,
+          "name": "challengeProposal",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "proposedContributionSeed",
+          "name": "trackMembership",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "receiver",
+          "name": "submission",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "submissionFeeEscrow",
           "isMut": true,
           "isSigner": false
         },
+        {
+          "name": "contentDataHash",
+          "isMut": false,
+          "isSigner": false
+        },
         {
           "name": "systemProgram",
           "isMut": false,
@@ -8781,30 +15584,34 @@ export const IDL: Forum = {
       ],
       "args": [
         {
-          "name": "bumpModeratorProfile",
+          "name": "bumpUserProfile",
           "type": "u8"
         },
         {
-          "name": "bumpUserProfile",
+          "name": "bumpChallenge",
           "type": "u8"
         },
         {
-          "name": "bumpProposedContribution",
+          "name": "bumpChallengeProposal",
+          "type": "u8"
+        },
+        {
+          "name": "bumpTrackMembership",
           "type": "u8"
         }
       ]
     },
     {
-      "name": "leaveCommentOnBigNote",
+      "name": "editSubmission",
       "accounts": [
         {
           "name": "forum",
-          "isMut": true,
+          "isMut": false,
           "isSigner": false
         },
         {
           "name": "profileOwner",
-          "isMut": true,
+          "isMut": false,
           "isSigner": true
         },
         {
@@ -8813,22 +15620,22 @@ export const IDL: Forum = {
           "isSigner": false
         },
         {
-          "name": "bigNote",
-          "isMut": true,
+          "name": "challenge",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "comment",
-          "isMut": true,
+          "name": "challengeSeed",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "commentSeed",
-          "isMut": false,
+          "name": "submission",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "contentDataHash",
+          "name": "newContentDataHash",
           "isMut": false,
           "isSigner": false
         },
@@ -8840,13 +15647,21 @@ export const IDL: Forum = {
       ],
       "args": [
         {
-          "name": "bumpUserProfile",
+          "name": "bumpUserProfile",
+          "type": "u8"
+        },
+        {
+          "name": "bumpChallenge",
+          "type": "u8"
+        },
+        {
+          "name": "bumpSubmission",
           "type": "u8"
         }
       ]
     },
     {
-      "name": "editCommentOnBigNote",
+      "name": "resubmit",
       "accounts": [
         {
           "name": "forum",
@@ -8855,7 +15670,7 @@ export const IDL: Forum = {
         },
         {
           "name": "profileOwner",
-          "isMut": false,
+          "isMut": true,
           "isSigner": true
         },
         {
@@ -8864,22 +15679,37 @@ export const IDL: Forum = {
           "isSigner": false
         },
         {
-          "name": "bigNote",
+          "name": "challenge",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "category",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "challengeSeed",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "submission",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "comment",
+          "name": "submissionAttempt",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "commentSeed",
-          "isMut": false,
+          "name": "submissionFeeEscrow",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "newContentDataHash",
+          "name": "contentDataHash",
           "isMut": false,
           "isSigner": false
         },
@@ -8895,13 +15725,17 @@ export const IDL: Forum = {
           "type": "u8"
         },
         {
-          "name": "bumpComment",
+          "name": "bumpChallenge",
+          "type": "u8"
+        },
+        {
+          "name": "bumpSubmission",
           "type": "u8"
         }
       ]
     },
     {
-      "name": "editCommentOnBigNoteModerator",
+      "name": "editSubmissionModerator",
       "accounts": [
         {
           "name": "forum",
@@ -8929,20 +15763,25 @@ export const IDL: Forum = {
           "isSigner": false
         },
         {
-          "name": "bigNote",
-          "isMut": true,
+          "name": "challenge",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "comment",
-          "isMut": true,
+          "name": "category",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "commentSeed",
+          "name": "challengeSeed",
           "isMut": false,
           "isSigner": false
         },
+        {
+          "name": "submission",
+          "isMut": true,
+          "isSigner": false
+        },
         {
           "name": "newContentDataHash",
           "isMut": false,
@@ -8964,13 +15803,17 @@ export const IDL: Forum = {
           "type": "u8"
         },
         {
-          "name": "bumpComment",
+          "name": "bumpChallenge",
+          "type": "u8"
+        },
+        {
+          "name": "bumpSubmission",
           "type": "u8"
         }
       ]
     },
     {
-      "name": "deleteCommentOnBigNote",
+      "name": "deleteSubmission",
       "accounts": [
         {
           "name": "forum",
@@ -8979,7 +15822,7 @@ export const IDL: Forum = {
         },
         {
           "name": "profileOwner",
-          "isMut": false,
+          "isMut": true,
           "isSigner": true
         },
         {
@@ -8988,18 +15831,23 @@ export const IDL: Forum = {
           "isSigner": false
         },
         {
-          "name": "bigNote",
+          "name": "challenge",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "comment",
+          "name": "challengeSeed",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "submission",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "commentSeed",
-          "isMut": false,
+          "name": "submissionFeeEscrow",
+          "isMut": true,
           "isSigner": false
         },
         {
@@ -9019,13 +15867,21 @@ export const IDL: Forum = {
           "type": "u8"
         },
         {
-          "name": "bumpComment",
+          "name": "bumpChallenge",
+          "type": "u8"
+        },
+        {
+          "name": "bumpSubmission",
+          "type": "u8"
+        },
+        {
+          "name": "bumpSubmissionFeeEscrow",
           "type": "u8"
         }
       ]
     },
     {
-      "name": "deleteCommentOnBigNoteModerator",
+      "name": "deleteSubmissionModerator",
       "accounts": [
         {
           "name": "forum",
@@ -9044,7 +15900,7 @@ export const IDL: Forum = {
         },
         {
           "name": "profileOwner",
-          "isMut": false,
+          "isMut": true,
           "isSigner": false
         },
         {
@@ -9053,20 +15909,30 @@ export const IDL: Forum = {
           "isSigner": false
         },
         {
-          "name": "bigNote",
+          "name": "challenge",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "comment",
-          "isMut": true,
+          "name": "category",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "commentSeed",
+          "name": "challengeSeed",
           "isMut": false,
           "isSigner": false
         },
+        {
+          "name": "submission",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "submissionFeeEscrow",
+          "isMut": true,
+          "isSigner": false
+        },
         {
           "name": "receiver",
           "isMut": true,
@@ -9088,13 +15954,21 @@ export const IDL: Forum = {
           "type": "u8"
         },
         {
-          "name": "bumpComment",
+          "name": "bumpChallenge",
+          "type": "u8"
+        },
+        {
+          "name": "bumpSubmission",
+          "type": "u8"
+        },
+        {
+          "name": "bumpSubmissionFeeEscrow",
           "type": "u8"
         }
       ]
     },
     {
-      "name": "leaveCommentOnProposedContribution",
+      "name": "refundSubmissionFee",
       "accounts": [
         {
           "name": "forum",
@@ -9112,28 +15986,23 @@ export const IDL: Forum = {
           "isSigner": false
         },
         {
-          "name": "bigNote",
-          "isMut": true,
+          "name": "challenge",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "proposedContribution",
-          "isMut": true,
+          "name": "challengeSeed",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "comment",
+          "name": "submission",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "commentSeed",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "contentDataHash",
-          "isMut": false,
+          "name": "submissionFeeEscrow",
+          "isMut": true,
           "isSigner": false
         },
         {
@@ -9146,11 +16015,23 @@ export const IDL: Forum = {
         {
           "name": "bumpUserProfile",
           "type": "u8"
+        },
+        {
+          "name": "bumpChallenge",
+          "type": "u8"
+        },
+        {
+          "name": "bumpSubmission",
+          "type": "u8"
+        },
+        {
+          "name": "bumpSubmissionFeeEscrow",
+          "type": "u8"
         }
       ]
     },
     {
-      "name": "editCommentOnProposedContribution",
+      "name": "evaluateSubmission",
       "accounts": [
         {
           "name": "forum",
@@ -9158,40 +16039,70 @@ export const IDL: Forum = {
           "isSigner": false
         },
         {
-          "name": "profileOwner",
-          "isMut": false,
+          "name": "forumTreasury",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "judge",
+          "isMut": true,
           "isSigner": true
         },
         {
-          "name": "userProfile",
+          "name": "judgeProfile",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "bigNote",
+          "name": "profileOwner",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "proposedContribution",
+          "name": "userProfile",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "comment",
+          "name": "challenge",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "commentSeed",
+          "name": "category",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "newContentDataHash",
+          "name": "challengeSeed",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "evaluationPanel",
           "isMut": false,
           "isSigner": false
         },
+        {
+          "name": "submission",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "evaluationVote",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "submissionFeeEscrow",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "prizePool",
+          "isMut": true,
+          "isSigner": false
+        },
         {
           "name": "systemProgram",
           "isMut": false,
@@ -9199,18 +16110,44 @@ export const IDL: Forum = {
         }
       ],
       "args": [
+        {
+          "name": "bumpJudgeProfile",
+          "type": "u8"
+        },
         {
           "name": "bumpUserProfile",
           "type": "u8"
         },
         {
-          "name": "bumpComment",
+          "name": "bumpChallenge",
+          "type": "u8"
+        },
+        {
+          "name": "bumpEvaluationPanel",
+          "type": "u8"
+        },
+        {
+          "name": "bumpSubmission",
+          "type": "u8"
+        },
+        {
+          "name": "bumpPrizePool",
           "type": "u8"
+        },
+        {
+          "name": "bumpSubmissionFeeEscrow",
+          "type": "u8"
+        },
+        {
+          "name": "verdict",
+          "type": {
+            "defined": "SubmissionState"
+          }
         }
       ]
     },
     {
-      "name": "editCommentOnProposedContributionModerator",
+      "name": "peerReviewSubmission",
       "accounts": [
         {
           "name": "forum",
@@ -9218,111 +16155,157 @@ export const IDL: Forum = {
           "isSigner": false
         },
         {
-          "name": "moderator",
-          "isMut": false,
+          "name": "reviewer",
+          "isMut": true,
           "isSigner": true
         },
         {
-          "name": "moderatorProfile",
+          "name": "reviewerProfile",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "profileOwner",
+          "name": "challenge",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "userProfile",
+          "name": "challengeSeed",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "bigNote",
+          "name": "reviewerSubmission",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "submission",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "proposedContribution",
+          "name": "peerReview",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "comment",
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bumpReviewerProfile",
+          "type": "u8"
+        },
+        {
+          "name": "bumpChallenge",
+          "type": "u8"
+        },
+        {
+          "name": "bumpReviewerSubmission",
+          "type": "u8"
+        },
+        {
+          "name": "bumpSubmission",
+          "type": "u8"
+        },
+        {
+          "name": "verdict",
+          "type": {
+            "defined": "SubmissionState"
+          }
+        }
+      ]
+    },
+    {
+      "name": "settlePeerReview",
+      "accounts": [
+        {
+          "name": "forum",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "cranker",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "reviewerProfile",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "commentSeed",
+          "name": "submission",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "newContentDataHash",
-          "isMut": false,
+          "name": "peerReview",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "systemProgram",
+          "name": "submissionAttempt",
           "isMut": false,
           "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "bumpModeratorProfile",
-          "type": "u8"
-        },
-        {
-          "name": "bumpUserProfile",
-          "type": "u8"
-        },
-        {
-          "name": "bumpComment",
+          "name": "bumpSubmissionAttempt",
           "type": "u8"
         }
       ]
     },
     {
-      "name": "deleteCommentOnProposedContribution",
+      "name": "scoreSubmission",
       "accounts": [
         {
           "name": "forum",
-          "isMut": true,
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "profileOwner",
-          "isMut": false,
+          "name": "judge",
+          "isMut": true,
           "isSigner": true
         },
         {
-          "name": "userProfile",
+          "name": "judgeProfile",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "bigNote",
-          "isMut": true,
+          "name": "challenge",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "proposedContribution",
-          "isMut": true,
+          "name": "challengeSeed",
+          "isMut": false,
           "isSigner": false
         },
         {
-          "name": "comment",
+          "name": "evaluationPanel",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "submission",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "commentSeed",
-          "isMut": false,
+          "name": "scoreCard",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "receiver",
+          "name": "leaderboard",
           "isMut": true,
           "isSigner": false
         },
@@ -9334,26 +16317,44 @@ export const IDL: Forum = {
       ],
       "args": [
         {
-          "name": "bumpUserProfile",
+          "name": "bumpJudgeProfile",
           "type": "u8"
         },
         {
-          "name": "bumpComment",
+          "name": "bumpChallenge",
+          "type": "u8"
+        },
+        {
+          "name": "bumpEvaluationPanel",
+          "type": "u8"
+        },
+        {
+          "name": "bumpSubmission",
           "type": "u8"
+        },
+        {
+          "name": "bumpLeaderboard",
+          "type": "u8"
+        },
+        {
+          "name": "scores",
+          "type": {
+            "vec": "u64"
+          }
         }
       ]
     },
     {
-      "name": "deleteCommentOnProposedContributionModerator",
+      "name": "createContentLink",
       "accounts": [
         {
           "name": "forum",
-          "isMut": true,
+          "isMut": false,
           "isSigner": false
         },
         {
           "name": "moderator",
-          "isMut": false,
+          "isMut": true,
           "isSigner": true
         },
         {
@@ -9362,37 +16363,17 @@ export const IDL: Forum = {
           "isSigner": false
         },
         {
-          "name": "profileOwner",
+          "name": "sourceContent",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "userProfile",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "bigNote",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "proposedContribution",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "comment",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "commentSeed",
+          "name": "targetContent",
           "isMut": false,
           "isSigner": false
         },
         {
-          "name": "receiver",
+          "name": "contentLink",
           "isMut": true,
           "isSigner": false
         },
@@ -9408,26 +16389,36 @@ export const IDL: Forum = {
           "type": "u8"
         },
         {
-          "name": "bumpUserProfile",
-          "type": "u8"
+          "name": "sourceContentType",
+          "type": {
+            "defined": "LinkedContentType"
+          }
         },
         {
-          "name": "bumpComment",
-          "type": "u8"
+          "name": "targetContentType",
+          "type": {
+            "defined": "LinkedContentType"
+          }
+        },
+        {
+          "name": "relationType",
+          "type": {
+            "defined": "ContentRelationType"
+          }
         }
       ]
     },
     {
-      "name": "createChallenge",
+      "name": "deleteContentLink",
       "accounts": [
         {
           "name": "forum",
-          "isMut": true,
+          "isMut": false,
           "isSigner": false
         },
         {
           "name": "moderator",
-          "isMut": true,
+          "isMut": false,
           "isSigner": true
         },
         {
@@ -9436,18 +16427,13 @@ export const IDL: Forum = {
           "isSigner": false
         },
         {
-          "name": "challenge",
+          "name": "contentLink",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "challengeSeed",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "contentDataHash",
-          "isMut": false,
+          "name": "receiver",
+          "isMut": true,
           "isSigner": false
         },
         {
@@ -9462,611 +16448,876 @@ export const IDL: Forum = {
           "type": "u8"
         },
         {
-          "name": "tags",
-          "type": {
-            "vec": {
-              "defined": "Tags"
-            }
+          "name": "bumpContentLink",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "closeAccount",
+      "accounts": [
+        {
+          "name": "signer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "accountToClose",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    }
+  ],
+  "accounts": [
+    {
+      "name": "aboutMe",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "userProfile",
+            "type": "publicKey"
+          },
+          {
+            "name": "aboutMeCreatedTs",
+            "type": "u64"
+          },
+          {
+            "name": "mostRecentUpdateTs",
+            "type": "u64"
+          },
+          {
+            "name": "contentDataHash",
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "answer",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "question",
+            "type": "publicKey"
+          },
+          {
+            "name": "userProfile",
+            "type": "publicKey"
+          },
+          {
+            "name": "answerSeed",
+            "type": "publicKey"
+          },
+          {
+            "name": "answerPostedTs",
+            "type": "u64"
+          },
+          {
+            "name": "mostRecentEngagementTs",
+            "type": "u64"
+          },
+          {
+            "name": "contentDataHash",
+            "type": "publicKey"
+          },
+          {
+            "name": "answerRep",
+            "type": "u64"
+          },
+          {
+            "name": "acceptedAnswer",
+            "type": "bool"
+          },
+          {
+            "name": "acceptedAnswerRep",
+            "type": "u64"
+          },
+          {
+            "name": "revisionCount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "bigNoteFork",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "parentBigNote",
+            "type": "publicKey"
+          },
+          {
+            "name": "forkBigNote",
+            "type": "publicKey"
+          },
+          {
+            "name": "parentContentDataHash",
+            "type": "publicKey"
+          },
+          {
+            "name": "forkedTs",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "bigNoteVerificationApplication",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bigNote",
+            "type": "publicKey"
+          },
+          {
+            "name": "verificationFeePda",
+            "type": "publicKey"
+          },
+          {
+            "name": "verificationFee",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "bigNoteVerificationRecord",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bigNote",
+            "type": "publicKey"
+          },
+          {
+            "name": "verificationRound",
+            "type": "u64"
+          },
+          {
+            "name": "attestationCount",
+            "type": "u64"
+          },
+          {
+            "name": "quorum",
+            "type": "u64"
+          },
+          {
+            "name": "verifiedTs",
+            "type": "u64"
+          },
+          {
+            "name": "verificationExpiresTs",
+            "type": "u64"
+          },
+          {
+            "name": "reverificationTs",
+            "type": "u64"
+          },
+          {
+            "name": "slashableRound",
+            "type": "u64"
+          },
+          {
+            "name": "slashableUntilTs",
+            "type": "u64"
           }
-        },
-        {
-          "name": "title",
-          "type": "string"
-        },
-        {
-          "name": "contentDataUrl",
-          "type": "string"
-        },
-        {
-          "name": "challengeExpiresTs",
-          "type": "u64"
-        },
-        {
-          "name": "reputation",
-          "type": "u64"
-        }
-      ]
+        ]
+      }
     },
     {
-      "name": "editChallenge",
-      "accounts": [
-        {
-          "name": "forum",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "moderator",
-          "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "moderatorProfile",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "challenge",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "challengeSeed",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "newContentDataHash",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "bumpModeratorProfile",
-          "type": "u8"
-        },
-        {
-          "name": "bumpChallenge",
-          "type": "u8"
-        },
-        {
-          "name": "newTags",
-          "type": {
-            "vec": {
-              "defined": "Tags"
+      "name": "bigNote",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "forum",
+            "type": "publicKey"
+          },
+          {
+            "name": "userProfile",
+            "type": "publicKey"
+          },
+          {
+            "name": "bigNoteSeed",
+            "type": "publicKey"
+          },
+          {
+            "name": "bigNoteCreatedTs",
+            "type": "u64"
+          },
+          {
+            "name": "mostRecentEngagementTs",
+            "type": "u64"
+          },
+          {
+            "name": "bountyAmount",
+            "type": "u64"
+          },
+          {
+            "name": "bountyContributions",
+            "type": {
+              "vec": {
+                "defined": "BountyContribution"
+              }
+            }
+          },
+          {
+            "name": "bigNoteType",
+            "type": {
+              "defined": "BigNoteType"
+            }
+          },
+          {
+            "name": "verificationState",
+            "type": {
+              "defined": "BigNoteVerificationState"
             }
+          },
+          {
+            "name": "tags",
+            "type": {
+              "vec": {
+                "defined": "Tags"
+              }
+            }
+          },
+          {
+            "name": "title",
+            "type": "string"
+          },
+          {
+            "name": "contentDataUrl",
+            "type": "string"
+          },
+          {
+            "name": "contentDataHash",
+            "type": "publicKey"
+          },
+          {
+            "name": "bigNoteCreationRep",
+            "type": "u64"
+          },
+          {
+            "name": "bigNoteVerificationRep",
+            "type": "u64"
+          },
+          {
+            "name": "bountyAwarded",
+            "type": "bool"
+          },
+          {
+            "name": "revisionCount",
+            "type": "u64"
+          },
+          {
+            "name": "sourceQuestion",
+            "type": "publicKey"
+          },
+          {
+            "name": "sourceAnswer",
+            "type": "publicKey"
+          },
+          {
+            "name": "forkCount",
+            "type": "u64"
+          },
+          {
+            "name": "successor",
+            "type": "publicKey"
+          },
+          {
+            "name": "currentOwner",
+            "type": "publicKey"
+          },
+          {
+            "name": "pendingOwner",
+            "type": "publicKey"
+          },
+          {
+            "name": "category",
+            "type": "publicKey"
           }
-        },
-        {
-          "name": "newTitle",
-          "type": "string"
-        },
-        {
-          "name": "newContentDataUrl",
-          "type": "string"
-        },
-        {
-          "name": "newChallengeExpiresTs",
-          "type": "u64"
-        },
-        {
-          "name": "newReputation",
-          "type": "u64"
-        }
-      ]
+        ]
+      }
+    },
+    {
+      "name": "category",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "forum",
+            "type": "publicKey"
+          },
+          {
+            "name": "categorySeed",
+            "type": "publicKey"
+          },
+          {
+            "name": "categoryCreatedTs",
+            "type": "u64"
+          },
+          {
+            "name": "categoryModerators",
+            "type": {
+              "array": [
+                "publicKey",
+                5
+              ]
+            }
+          },
+          {
+            "name": "moderatorCount",
+            "type": "u64"
+          },
+          {
+            "name": "categoryFees",
+            "type": {
+              "defined": "ForumFees"
+            }
+          },
+          {
+            "name": "questionCount",
+            "type": "u64"
+          },
+          {
+            "name": "bigNotesCount",
+            "type": "u64"
+          },
+          {
+            "name": "challengeCount",
+            "type": "u64"
+          },
+          {
+            "name": "feeOverridesEnabled",
+            "type": "bool"
+          }
+        ]
+      }
     },
     {
-      "name": "deleteChallenge",
-      "accounts": [
-        {
-          "name": "forum",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "moderator",
-          "isMut": false,
-          "isSigner": true
-        },
-        {
-          "name": "moderatorProfile",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "challenge",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "challengeSeed",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "receiver",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "bumpModeratorProfile",
-          "type": "u8"
-        },
-        {
-          "name": "bumpChallenge",
-          "type": "u8"
-        }
-      ]
+      "name": "challengeLeaderboard",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "challenge",
+            "type": "publicKey"
+          },
+          {
+            "name": "entries",
+            "type": {
+              "array": [
+                {
+                  "defined": "LeaderboardEntry"
+                },
+                10
+              ]
+            }
+          },
+          {
+            "name": "maxEntries",
+            "type": "u64"
+          },
+          {
+            "name": "entryCount",
+            "type": "u64"
+          },
+          {
+            "name": "isClosed",
+            "type": "bool"
+          }
+        ]
+      }
     },
     {
-      "name": "createSubmission",
-      "accounts": [
-        {
-          "name": "forum",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "forumTreasury",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "profileOwner",
-          "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "userProfile",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "challenge",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "challengeSeed",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "submission",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "contentDataHash",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "bumpTreasury",
-          "type": "u8"
-        },
-        {
-          "name": "bumpUserProfile",
-          "type": "u8"
-        },
-        {
-          "name": "bumpChallenge",
-          "type": "u8"
-        }
-      ]
+      "name": "challengePrizePool",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "challenge",
+            "type": "publicKey"
+          },
+          {
+            "name": "prizeMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "totalFunded",
+            "type": "u64"
+          },
+          {
+            "name": "totalPaid",
+            "type": "u64"
+          },
+          {
+            "name": "totalRefunded",
+            "type": "u64"
+          },
+          {
+            "name": "sponsorCount",
+            "type": "u64"
+          },
+          {
+            "name": "winnerCount",
+            "type": "u64"
+          },
+          {
+            "name": "winnersPaid",
+            "type": "u64"
+          },
+          {
+            "name": "prizePerWinner",
+            "type": "u64"
+          },
+          {
+            "name": "tierBps",
+            "type": {
+              "array": [
+                "u16",
+                8
+              ]
+            }
+          },
+          {
+            "name": "prizeDistribution",
+            "type": {
+              "defined": "PrizeDistribution"
+            }
+          },
+          {
+            "name": "payoutsClosed",
+            "type": "bool"
+          }
+        ]
+      }
     },
     {
-      "name": "editSubmission",
-      "accounts": [
-        {
-          "name": "forum",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "profileOwner",
-          "isMut": false,
-          "isSigner": true
-        },
-        {
-          "name": "userProfile",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "challenge",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "challengeSeed",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "submission",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "newContentDataHash",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "bumpUserProfile",
-          "type": "u8"
-        },
-        {
-          "name": "bumpChallenge",
-          "type": "u8"
-        },
-        {
-          "name": "bumpSubmission",
-          "type": "u8"
-        }
-      ]
+      "name": "challengeProposal",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "forum",
+            "type": "publicKey"
+          },
+          {
+            "name": "challenge",
+            "type": "publicKey"
+          },
+          {
+            "name": "authorProfile",
+            "type": "publicKey"
+          },
+          {
+            "name": "proposalPostedTs",
+            "type": "u64"
+          },
+          {
+            "name": "proposalReviewedTs",
+            "type": "u64"
+          },
+          {
+            "name": "depositAmount",
+            "type": "u64"
+          },
+          {
+            "name": "proposalState",
+            "type": {
+              "defined": "ChallengeProposalState"
+            }
+          }
+        ]
+      }
     },
     {
-      "name": "editSubmissionModerator",
-      "accounts": [
-        {
-          "name": "forum",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "moderator",
-          "isMut": false,
-          "isSigner": true
-        },
-        {
-          "name": "moderatorProfile",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "profileOwner",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "userProfile",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "challenge",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "challengeSeed",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "submission",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "newContentDataHash",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "bumpModeratorProfile",
-          "type": "u8"
-        },
-        {
-          "name": "bumpUserProfile",
-          "type": "u8"
-        },
-        {
-          "name": "bumpChallenge",
-          "type": "u8"
-        },
-        {
-          "name": "bumpSubmission",
-          "type": "u8"
-        }
-      ]
+      "name": "challenge",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "forum",
+            "type": "publicKey"
+          },
+          {
+            "name": "challengeSeed",
+            "type": "publicKey"
+          },
+          {
+            "name": "challengePostedTs",
+            "type": "u64"
+          },
+          {
+            "name": "challengeExpiresTs",
+            "type": "u64"
+          },
+          {
+            "name": "tags",
+            "type": {
+              "vec": {
+                "defined": "Tags"
+              }
+            }
+          },
+          {
+            "name": "title",
+            "type": "string"
+          },
+          {
+            "name": "contentDataUrl",
+            "type": "string"
+          },
+          {
+            "name": "contentDataHash",
+            "type": "publicKey"
+          },
+          {
+            "name": "reputation",
+            "type": "u64"
+          },
+          {
+            "name": "rubric",
+            "type": {
+              "vec": {
+                "defined": "RubricCriterion"
+              }
+            }
+          },
+          {
+            "name": "challengeState",
+            "type": {
+              "defined": "ChallengeState"
+            }
+          },
+          {
+            "name": "judgingEndsTs",
+            "type": "u64"
+          },
+          {
+            "name": "submissionCount",
+            "type": "u64"
+          },
+          {
+            "name": "completionCount",
+            "type": "u64"
+          },
+          {
+            "name": "finalSubmissionCount",
+            "type": "u64"
+          },
+          {
+            "name": "finalCompletionCount",
+            "type": "u64"
+          },
+          {
+            "name": "category",
+            "type": "publicKey"
+          }
+        ]
+      }
     },
     {
-      "name": "deleteSubmission",
-      "accounts": [
-        {
-          "name": "forum",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "profileOwner",
-          "isMut": false,
-          "isSigner": true
-        },
-        {
-          "name": "userProfile",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "challenge",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "challengeSeed",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "submission",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "receiver",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "bumpUserProfile",
-          "type": "u8"
-        },
-        {
-          "name": "bumpChallenge",
-          "type": "u8"
-        },
-        {
-          "name": "bumpSubmission",
-          "type": "u8"
-        }
-      ]
+      "name": "comment",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "commentedOn",
+            "type": "publicKey"
+          },
+          {
+            "name": "userProfile",
+            "type": "publicKey"
+          },
+          {
+            "name": "commentSeed",
+            "type": "publicKey"
+          },
+          {
+            "name": "commentPostedTs",
+            "type": "u64"
+          },
+          {
+            "name": "mostRecentEngagementTs",
+            "type": "u64"
+          },
+          {
+            "name": "contentDataHash",
+            "type": "publicKey"
+          },
+          {
+            "name": "commentRep",
+            "type": "u64"
+          },
+          {
+            "name": "commentDepth",
+            "type": "u64"
+          },
+          {
+            "name": "replyCount",
+            "type": "u64"
+          },
+          {
+            "name": "commentTarget",
+            "type": {
+              "defined": "CommentTarget"
+            }
+          }
+        ]
+      }
     },
     {
-      "name": "deleteSubmissionModerator",
-      "accounts": [
-        {
-          "name": "forum",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "moderator",
-          "isMut": false,
-          "isSigner": true
-        },
-        {
-          "name": "moderatorProfile",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "profileOwner",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "userProfile",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "challenge",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "challengeSeed",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "submission",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "receiver",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "bumpModeratorProfile",
-          "type": "u8"
-        },
-        {
-          "name": "bumpUserProfile",
-          "type": "u8"
-        },
-        {
-          "name": "bumpChallenge",
-          "type": "u8"
-        },
-        {
-          "name": "bumpSubmission",
-          "type": "u8"
-        }
-      ]
+      "name": "contentLink",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "forum",
+            "type": "publicKey"
+          },
+          {
+            "name": "moderatorProfile",
+            "type": "publicKey"
+          },
+          {
+            "name": "sourceContent",
+            "type": "publicKey"
+          },
+          {
+            "name": "targetContent",
+            "type": "publicKey"
+          },
+          {
+            "name": "contentLinkPostedTs",
+            "type": "u64"
+          },
+          {
+            "name": "sourceContentType",
+            "type": {
+              "defined": "LinkedContentType"
+            }
+          },
+          {
+            "name": "targetContentType",
+            "type": {
+              "defined": "LinkedContentType"
+            }
+          },
+          {
+            "name": "relationType",
+            "type": {
+              "defined": "ContentRelationType"
+            }
+          }
+        ]
+      }
     },
     {
-      "name": "evaluateSubmission",
-      "accounts": [
-        {
-          "name": "forum",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "moderator",
-          "isMut": false,
-          "isSigner": true
-        },
-        {
-          "name": "moderatorProfile",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "profileOwner",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "userProfile",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "challenge",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "challengeSeed",
-          "isMut": false,
-          "isSigner": false
-        },
-        {
-          "name": "submission",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "bumpModeratorProfile",
-          "type": "u8"
-        },
-        {
-          "name": "bumpUserProfile",
-          "type": "u8"
-        },
-        {
-          "name": "bumpChallenge",
-          "type": "u8"
-        },
-        {
-          "name": "bumpSubmission",
-          "type": "u8"
-        },
-        {
-          "name": "submissionState",
-          "type": {
-            "defined": "SubmissionState"
+      "name": "evaluationPanel",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "challenge",
+            "type": "publicKey"
+          },
+          {
+            "name": "judges",
+            "type": {
+              "array": [
+                "publicKey",
+                8
+              ]
+            }
+          },
+          {
+            "name": "judgeCount",
+            "type": "u64"
+          },
+          {
+            "name": "quorum",
+            "type": "u64"
           }
-        }
-      ]
+        ]
+      }
     },
     {
-      "name": "closeAccount",
-      "accounts": [
-        {
-          "name": "signer",
-          "isMut": true,
-          "isSigner": true
-        },
-        {
-          "name": "accountToClose",
-          "isMut": true,
-          "isSigner": false
-        },
-        {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": []
-    }
-  ],
-  "accounts": [
+      "name": "evaluationVote",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "submission",
+            "type": "publicKey"
+          },
+          {
+            "name": "judgeProfile",
+            "type": "publicKey"
+          },
+          {
+            "name": "voteTs",
+            "type": "u64"
+          },
+          {
+            "name": "verdict",
+            "type": {
+              "defined": "SubmissionState"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "follow",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "followerProfile",
+            "type": "publicKey"
+          },
+          {
+            "name": "followedProfile",
+            "type": "publicKey"
+          },
+          {
+            "name": "followedTs",
+            "type": "u64"
+          }
+        ]
+      }
+    },
     {
-      "name": "aboutMe",
+      "name": "forumAccessPolicy",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "userProfile",
+            "name": "forum",
             "type": "publicKey"
           },
           {
-            "name": "aboutMeCreatedTs",
+            "name": "accessMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "minTokenAmount",
             "type": "u64"
           },
           {
-            "name": "mostRecentUpdateTs",
+            "name": "recheckPeriod",
             "type": "u64"
           },
           {
-            "name": "contentDataHash",
+            "name": "accessPolicyType",
+            "type": {
+              "defined": "AccessPolicyType"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "forumAllowlistEntry",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "forum",
             "type": "publicKey"
+          },
+          {
+            "name": "wallet",
+            "type": "publicKey"
+          },
+          {
+            "name": "entryAddedTs",
+            "type": "u64"
           }
         ]
       }
     },
     {
-      "name": "answer",
+      "name": "forum",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "question",
+            "name": "version",
+            "type": "u16"
+          },
+          {
+            "name": "forumManager",
+            "type": "publicKey"
+          },
+          {
+            "name": "forumAuthority",
+            "type": "publicKey"
+          },
+          {
+            "name": "forumAuthoritySeed",
+            "type": "publicKey"
+          },
+          {
+            "name": "forumAuthorityBumpSeed",
+            "type": {
+              "array": [
+                "u8",
+                1
+              ]
+            }
+          },
+          {
+            "name": "forumTreasury",
+            "type": "publicKey"
+          },
+          {
+            "name": "forumFees",
+            "type": {
+              "defined": "ForumFees"
+            }
+          },
+          {
+            "name": "forumConstants",
+            "type": {
+              "defined": "ForumConstants"
+            }
+          },
+          {
+            "name": "forumCounts",
+            "type": {
+              "defined": "ForumCounts"
+            }
+          },
+          {
+            "name": "reputationMatrix",
+            "type": {
+              "defined": "ReputationMatrix"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "handle",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "forum",
             "type": "publicKey"
           },
           {
@@ -10074,38 +17325,101 @@ export const IDL: Forum = {
             "type": "publicKey"
           },
           {
-            "name": "answerSeed",
+            "name": "handleClaimedTs",
+            "type": "u64"
+          },
+          {
+            "name": "handleName",
+            "type": "string"
+          }
+        ]
+      }
+    },
+    {
+      "name": "peerReview",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "submission",
             "type": "publicKey"
           },
           {
-            "name": "answerPostedTs",
+            "name": "reviewerProfile",
+            "type": "publicKey"
+          },
+          {
+            "name": "attemptNumber",
             "type": "u64"
           },
           {
-            "name": "mostRecentEngagementTs",
+            "name": "reviewTs",
             "type": "u64"
           },
           {
-            "name": "contentDataHash",
+            "name": "verdict",
+            "type": {
+              "defined": "SubmissionState"
+            }
+          },
+          {
+            "name": "isSettled",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "pfpCollectionAllowlist",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "forum",
             "type": "publicKey"
           },
           {
-            "name": "answerRep",
+            "name": "collections",
+            "type": {
+              "array": [
+                "publicKey",
+                10
+              ]
+            }
+          },
+          {
+            "name": "collectionCount",
             "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "prizeContribution",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "prizePool",
+            "type": "publicKey"
           },
           {
-            "name": "acceptedAnswer",
-            "type": "bool"
+            "name": "sponsorProfile",
+            "type": "publicKey"
           },
           {
-            "name": "acceptedAnswerRep",
+            "name": "mostRecentContributionTs",
+            "type": "u64"
+          },
+          {
+            "name": "contributedAmount",
             "type": "u64"
           }
         ]
       }
     },
     {
-      "name": "bigNoteVerificationApplication",
+      "name": "proposedContribution",
       "type": {
         "kind": "struct",
         "fields": [
@@ -10114,18 +17428,44 @@ export const IDL: Forum = {
             "type": "publicKey"
           },
           {
-            "name": "verificationFeePda",
+            "name": "userProfile",
             "type": "publicKey"
           },
           {
-            "name": "verificationFee",
+            "name": "proposedContributionSeed",
+            "type": "publicKey"
+          },
+          {
+            "name": "proposedContributionPostedTs",
+            "type": "u64"
+          },
+          {
+            "name": "mostRecentEngagementTs",
+            "type": "u64"
+          },
+          {
+            "name": "contentDataHash",
+            "type": "publicKey"
+          },
+          {
+            "name": "proposedContributionRep",
+            "type": "u64"
+          },
+          {
+            "name": "proposedContributionState",
+            "type": {
+              "defined": "ProposedContributionState"
+            }
+          },
+          {
+            "name": "acceptedContributionProposalRep",
             "type": "u64"
           }
         ]
       }
     },
     {
-      "name": "bigNote",
+      "name": "question",
       "type": {
         "kind": "struct",
         "fields": [
@@ -10138,11 +17478,11 @@ export const IDL: Forum = {
             "type": "publicKey"
           },
           {
-            "name": "bigNoteSeed",
+            "name": "questionSeed",
             "type": "publicKey"
           },
           {
-            "name": "bigNoteCreatedTs",
+            "name": "questionPostedTs",
             "type": "u64"
           },
           {
@@ -10161,18 +17501,6 @@ export const IDL: Forum = {
               }
             }
           },
-          {
-            "name": "bigNoteType",
-            "type": {
-              "defined": "BigNoteType"
-            }
-          },
-          {
-            "name": "verificationState",
-            "type": {
-              "defined": "BigNoteVerificationState"
-            }
-          },
           {
             "name": "tags",
             "type": {
@@ -10194,172 +17522,187 @@ export const IDL: Forum = {
             "type": "publicKey"
           },
           {
-            "name": "bigNoteCreationRep",
+            "name": "questionRep",
             "type": "u64"
           },
           {
-            "name": "bigNoteVerificationRep",
+            "name": "bountyAwarded",
+            "type": "bool"
+          },
+          {
+            "name": "revisionCount",
             "type": "u64"
           },
           {
-            "name": "bountyAwarded",
-            "type": "bool"
+            "name": "duplicateOf",
+            "type": "publicKey"
+          },
+          {
+            "name": "currentOwner",
+            "type": "publicKey"
+          },
+          {
+            "name": "pendingOwner",
+            "type": "publicKey"
+          },
+          {
+            "name": "category",
+            "type": "publicKey"
           }
         ]
       }
     },
     {
-      "name": "challenge",
+      "name": "revision",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "forum",
+            "name": "content",
             "type": "publicKey"
           },
           {
-            "name": "challengeSeed",
+            "name": "editorProfile",
             "type": "publicKey"
           },
           {
-            "name": "challengePostedTs",
+            "name": "revisionNumber",
             "type": "u64"
           },
           {
-            "name": "challengeExpiresTs",
+            "name": "revisionTs",
             "type": "u64"
           },
           {
-            "name": "tags",
-            "type": {
-              "vec": {
-                "defined": "Tags"
-              }
-            }
+            "name": "previousContentDataHash",
+            "type": "publicKey"
           },
           {
-            "name": "title",
+            "name": "moderatorEdit",
+            "type": "bool"
+          },
+          {
+            "name": "previousTitle",
             "type": "string"
           },
           {
-            "name": "contentDataUrl",
+            "name": "previousContentDataUrl",
             "type": "string"
+          }
+        ]
+      }
+    },
+    {
+      "name": "scoreCard",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "submission",
+            "type": "publicKey"
           },
           {
-            "name": "contentDataHash",
+            "name": "judgeProfile",
             "type": "publicKey"
           },
           {
-            "name": "reputation",
+            "name": "scoreCardPostedTs",
+            "type": "u64"
+          },
+          {
+            "name": "weightedScore",
             "type": "u64"
           }
         ]
       }
     },
     {
-      "name": "comment",
+      "name": "sessionKey",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "commentedOn",
+            "name": "userProfile",
             "type": "publicKey"
           },
           {
-            "name": "userProfile",
+            "name": "profileOwner",
             "type": "publicKey"
           },
           {
-            "name": "commentSeed",
+            "name": "delegate",
             "type": "publicKey"
           },
           {
-            "name": "commentPostedTs",
+            "name": "sessionCreatedTs",
             "type": "u64"
           },
           {
-            "name": "mostRecentEngagementTs",
+            "name": "expiresTs",
             "type": "u64"
           },
           {
-            "name": "contentDataHash",
-            "type": "publicKey"
-          },
-          {
-            "name": "commentRep",
-            "type": "u64"
+            "name": "sessionScope",
+            "type": {
+              "defined": "SessionScope"
+            }
           }
         ]
       }
     },
     {
-      "name": "forum",
+      "name": "submissionAttempt",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "version",
-            "type": "u16"
-          },
-          {
-            "name": "forumManager",
+            "name": "submission",
             "type": "publicKey"
           },
           {
-            "name": "forumAuthority",
-            "type": "publicKey"
+            "name": "attemptNumber",
+            "type": "u64"
           },
           {
-            "name": "forumAuthoritySeed",
-            "type": "publicKey"
+            "name": "attemptPostedTs",
+            "type": "u64"
           },
           {
-            "name": "forumAuthorityBumpSeed",
-            "type": {
-              "array": [
-                "u8",
-                1
-              ]
-            }
+            "name": "contentDataHash",
+            "type": "publicKey"
           },
           {
-            "name": "forumTreasury",
-            "type": "publicKey"
+            "name": "approvalVotes",
+            "type": "u64"
           },
           {
-            "name": "forumFees",
-            "type": {
-              "defined": "ForumFees"
-            }
+            "name": "rejectionVotes",
+            "type": "u64"
           },
           {
-            "name": "forumConstants",
-            "type": {
-              "defined": "ForumConstants"
-            }
+            "name": "totalScore",
+            "type": "u64"
           },
           {
-            "name": "forumCounts",
-            "type": {
-              "defined": "ForumCounts"
-            }
+            "name": "scoreCount",
+            "type": "u64"
           },
           {
-            "name": "reputationMatrix",
+            "name": "submissionState",
             "type": {
-              "defined": "ReputationMatrix"
+              "defined": "SubmissionState"
             }
           }
         ]
       }
     },
     {
-      "name": "proposedContribution",
+      "name": "submissionFeeEscrow",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "bigNote",
+            "name": "submission",
             "type": "publicKey"
           },
           {
@@ -10367,11 +17710,27 @@ export const IDL: Forum = {
             "type": "publicKey"
           },
           {
-            "name": "proposedContributionSeed",
+            "name": "feeAmount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "submission",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "challenge",
             "type": "publicKey"
           },
           {
-            "name": "proposedContributionPostedTs",
+            "name": "userProfile",
+            "type": "publicKey"
+          },
+          {
+            "name": "submissionPostedTs",
             "type": "u64"
           },
           {
@@ -10383,120 +17742,145 @@ export const IDL: Forum = {
             "type": "publicKey"
           },
           {
-            "name": "proposedContributionRep",
+            "name": "submissionState",
+            "type": {
+              "defined": "SubmissionState"
+            }
+          },
+          {
+            "name": "approvalVotes",
             "type": "u64"
           },
           {
-            "name": "proposedContributionState",
-            "type": {
-              "defined": "ProposedContributionState"
-            }
+            "name": "rejectionVotes",
+            "type": "u64"
           },
           {
-            "name": "acceptedContributionProposalRep",
+            "name": "totalScore",
+            "type": "u64"
+          },
+          {
+            "name": "scoreCount",
+            "type": "u64"
+          },
+          {
+            "name": "peerReviewCount",
+            "type": "u64"
+          },
+          {
+            "name": "revisionCount",
             "type": "u64"
           }
         ]
       }
     },
     {
-      "name": "question",
+      "name": "subscription",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "forum",
-            "type": "publicKey"
-          },
-          {
-            "name": "userProfile",
+            "name": "subscribedTo",
             "type": "publicKey"
           },
           {
-            "name": "questionSeed",
+            "name": "subscriberProfile",
             "type": "publicKey"
           },
           {
-            "name": "questionPostedTs",
-            "type": "u64"
-          },
-          {
-            "name": "mostRecentEngagementTs",
-            "type": "u64"
-          },
-          {
-            "name": "bountyAmount",
+            "name": "subscribedTs",
             "type": "u64"
           },
           {
-            "name": "bountyContributions",
+            "name": "subscriptionTarget",
             "type": {
-              "vec": {
-                "defined": "BountyContribution"
-              }
+              "defined": "SubscriptionTarget"
             }
+          }
+        ]
+      }
+    },
+    {
+      "name": "trackCompletion",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "track",
+            "type": "publicKey"
           },
           {
-            "name": "tags",
-            "type": {
-              "vec": {
-                "defined": "Tags"
-              }
-            }
+            "name": "userProfile",
+            "type": "publicKey"
           },
           {
-            "name": "title",
-            "type": "string"
+            "name": "trackCompletedTs",
+            "type": "u64"
           },
           {
-            "name": "contentDataUrl",
-            "type": "string"
+            "name": "completionRep",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "trackMembership",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "track",
+            "type": "publicKey"
           },
           {
-            "name": "contentDataHash",
+            "name": "challenge",
             "type": "publicKey"
           },
           {
-            "name": "questionRep",
-            "type": "u64"
+            "name": "prerequisiteChallenge",
+            "type": "publicKey"
           },
           {
-            "name": "bountyAwarded",
-            "type": "bool"
+            "name": "trackPosition",
+            "type": "u64"
           }
         ]
       }
     },
     {
-      "name": "submission",
+      "name": "track",
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "challenge",
+            "name": "forum",
             "type": "publicKey"
           },
           {
-            "name": "userProfile",
+            "name": "trackSeed",
             "type": "publicKey"
           },
           {
-            "name": "submissionPostedTs",
-            "type": "u64"
+            "name": "challenges",
+            "type": {
+              "array": [
+                "publicKey",
+                10
+              ]
+            }
           },
           {
-            "name": "mostRecentEngagementTs",
+            "name": "challengeCount",
             "type": "u64"
           },
           {
-            "name": "contentDataHash",
-            "type": "publicKey"
+            "name": "trackCreatedTs",
+            "type": "u64"
           },
           {
-            "name": "submissionState",
-            "type": {
-              "defined": "SubmissionState"
-            }
+            "name": "completionRep",
+            "type": "u64"
           }
         ]
       }
@@ -10514,6 +17898,14 @@ export const IDL: Forum = {
             "name": "forum",
             "type": "publicKey"
           },
+          {
+            "name": "profileSeed",
+            "type": "publicKey"
+          },
+          {
+            "name": "pendingProfileOwner",
+            "type": "publicKey"
+          },
           {
             "name": "profileCreatedTs",
             "type": "u64"
@@ -10522,6 +17914,10 @@ export const IDL: Forum = {
             "name": "mostRecentEngagementTs",
             "type": "u64"
           },
+          {
+            "name": "eligibilityCheckedTs",
+            "type": "u64"
+          },
           {
             "name": "bigNotesCreated",
             "type": "u64"
@@ -10538,6 +17934,10 @@ export const IDL: Forum = {
             "name": "bigNotesContributionsAccepted",
             "type": "u64"
           },
+          {
+            "name": "bigNotesCredited",
+            "type": "u64"
+          },
           {
             "name": "challengesSubmitted",
             "type": "u64"
@@ -10546,6 +17946,10 @@ export const IDL: Forum = {
             "name": "challengesCompleted",
             "type": "u64"
           },
+          {
+            "name": "tracksCompleted",
+            "type": "u64"
+          },
           {
             "name": "questionsAsked",
             "type": "u64"
@@ -10567,28 +17971,108 @@ export const IDL: Forum = {
             "type": "u64"
           },
           {
-            "name": "totalBountyEarned",
+            "name": "totalBountyEarned",
+            "type": "u64"
+          },
+          {
+            "name": "reputationScore",
+            "type": "u64"
+          },
+          {
+            "name": "followerCount",
+            "type": "u64"
+          },
+          {
+            "name": "followingCount",
+            "type": "u64"
+          },
+          {
+            "name": "nftPfpTokenMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "nftPfpTokenAccount",
+            "type": "publicKey"
+          },
+          {
+            "name": "hasAboutMe",
+            "type": "bool"
+          },
+          {
+            "name": "hasHadAboutMe",
+            "type": "bool"
+          },
+          {
+            "name": "isModerator",
+            "type": "bool"
+          },
+          {
+            "name": "hasHandle",
+            "type": "bool"
+          },
+          {
+            "name": "isSuspended",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "verificationAttestation",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bigNote",
+            "type": "publicKey"
+          },
+          {
+            "name": "attesterProfile",
+            "type": "publicKey"
+          },
+          {
+            "name": "verificationRound",
+            "type": "u64"
+          },
+          {
+            "name": "attestationTs",
+            "type": "u64"
+          },
+          {
+            "name": "stakeAmount",
             "type": "u64"
           },
           {
-            "name": "reputationScore",
-            "type": "u64"
+            "name": "isSlashed",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "walletRotation",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "forum",
+            "type": "publicKey"
           },
           {
-            "name": "nftPfpTokenMint",
+            "name": "userProfile",
             "type": "publicKey"
           },
           {
-            "name": "hasAboutMe",
-            "type": "bool"
+            "name": "previousProfileOwner",
+            "type": "publicKey"
           },
           {
-            "name": "hasHadAboutMe",
-            "type": "bool"
+            "name": "newProfileOwner",
+            "type": "publicKey"
           },
           {
-            "name": "isModerator",
-            "type": "bool"
+            "name": "rotatedTs",
+            "type": "u64"
           }
         ]
       }
@@ -10645,6 +18129,48 @@ export const IDL: Forum = {
           {
             "name": "minInactivityPeriod",
             "type": "u64"
+          },
+          {
+            "name": "maxCommentDepth",
+            "type": "u64"
+          },
+          {
+            "name": "minChallengeProposalRep",
+            "type": "u64"
+          },
+          {
+            "name": "maxSubmissionAttempts",
+            "type": "u64"
+          },
+          {
+            "name": "challengeJudgingPeriod",
+            "type": "u64"
+          },
+          {
+            "name": "bigNoteVerificationQuorum",
+            "type": "u64"
+          },
+          {
+            "name": "minAttesterRep",
+            "type": "u64"
+          },
+          {
+            "name": "bigNoteVerificationValidityPeriod",
+            "type": "u64"
+          },
+          {
+            "name": "minHandleLength",
+            "type": "u64"
+          },
+          {
+            "name": "maxHandleLength",
+            "type": "u64"
+          },
+          {
+            "name": "handleCharacterSet",
+            "type": {
+              "defined": "HandleCharacterSet"
+            }
           }
         ]
       }
@@ -10725,6 +18251,42 @@ export const IDL: Forum = {
           {
             "name": "forumBigNotesBountyMinimum",
             "type": "u64"
+          },
+          {
+            "name": "forumChallengeProposalDeposit",
+            "type": "u64"
+          },
+          {
+            "name": "forumRejectedSubmissionRefund",
+            "type": "u64"
+          },
+          {
+            "name": "forumVerificationAttestationStake",
+            "type": "u64"
+          },
+          {
+            "name": "forumHandleRegistrationFee",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "LeaderboardEntry",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "submission",
+            "type": "publicKey"
+          },
+          {
+            "name": "userProfile",
+            "type": "publicKey"
+          },
+          {
+            "name": "averageScore",
+            "type": "u64"
           }
         ]
       }
@@ -10773,6 +18335,54 @@ export const IDL: Forum = {
           {
             "name": "bountyContributionRep",
             "type": "u64"
+          },
+          {
+            "name": "approvedChallengeProposalRep",
+            "type": "u64"
+          },
+          {
+            "name": "peerReviewMatchRep",
+            "type": "u64"
+          },
+          {
+            "name": "peerReviewMismatchPenalty",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "RubricCriterion",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "maxScore",
+            "type": "u64"
+          },
+          {
+            "name": "weight",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "AccessPolicyType",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Open"
+          },
+          {
+            "name": "TokenHolder"
+          },
+          {
+            "name": "CollectionHolder"
+          },
+          {
+            "name": "Allowlist"
           }
         ]
       }
@@ -10804,6 +18414,9 @@ export const IDL: Forum = {
           },
           {
             "name": "Verified"
+          },
+          {
+            "name": "NeedsReverification"
           }
         ]
       }
@@ -10821,6 +18434,146 @@ export const IDL: Forum = {
           },
           {
             "name": "Refunded"
+          },
+          {
+            "name": "Migrated"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ChallengeProposalState",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Pending"
+          },
+          {
+            "name": "Approved"
+          },
+          {
+            "name": "Rejected"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ChallengeState",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Open"
+          },
+          {
+            "name": "Judging"
+          },
+          {
+            "name": "Closed"
+          },
+          {
+            "name": "Archived"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CommentTarget",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Question"
+          },
+          {
+            "name": "Answer"
+          },
+          {
+            "name": "BigNote"
+          },
+          {
+            "name": "ProposedContribution"
+          },
+          {
+            "name": "Challenge"
+          },
+          {
+            "name": "Submission"
+          },
+          {
+            "name": "Comment"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ContentRelationType",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Duplicates"
+          },
+          {
+            "name": "Answers"
+          },
+          {
+            "name": "Supersedes"
+          },
+          {
+            "name": "Related"
+          }
+        ]
+      }
+    },
+    {
+      "name": "HandleCharacterSet",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Letters"
+          },
+          {
+            "name": "Alphanumeric"
+          },
+          {
+            "name": "AlphanumericUnderscore"
+          }
+        ]
+      }
+    },
+    {
+      "name": "LinkedContentType",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Question"
+          },
+          {
+            "name": "BigNote"
+          },
+          {
+            "name": "Challenge"
+          }
+        ]
+      }
+    },
+    {
+      "name": "PrizeDistribution",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "FirstN"
+          },
+          {
+            "name": "SplitEvenly"
+          },
+          {
+            "name": "RankedTiers"
           }
         ]
       }
@@ -10842,6 +18595,20 @@ export const IDL: Forum = {
         ]
       }
     },
+    {
+      "name": "SessionScope",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "CommentAndAnswer"
+          },
+          {
+            "name": "PostAndEdit"
+          }
+        ]
+      }
+    },
     {
       "name": "SubmissionState",
       "type": {
@@ -10859,6 +18626,23 @@ export const IDL: Forum = {
         ]
       }
     },
+    {
+      "name": "SubscriptionTarget",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Question"
+          },
+          {
+            "name": "BigNote"
+          },
+          {
+            "name": "Challenge"
+          }
+        ]
+      }
+    },
     {
       "name": "Tags",
       "type": {
@@ -0,0 +1,70 @@
+import * as anchor from '@coral-xyz/anchor';
+import { Keypair, LAMPORTS_PER_SOL, PublicKey } from '@solana/web3.js';
+import chai, { assert, expect } from 'chai';
+import chaiAsPromised from 'chai-as-promised';
+import { ForumClient } from '../src/forum.client';
+import { findUserProfilePDA, findWalletRotationPDA } from '../src/forum.pda';
+import { forumConfig } from '../src/cli_configs/devnet/forumConfig-devnet';
+
+chai.use(chaiAsPromised);
+
+describe('wallet rotation', () => {
+    const provider = anchor.AnchorProvider.env();
+    const forumClient = new ForumClient(provider.connection, provider.wallet as anchor.Wallet);
+
+    const forum = Keypair.generate();
+    const walletA = Keypair.generate();
+    const walletB = Keypair.generate();
+
+    let userProfileA: PublicKey;
+
+    const airdrop = async (receiver: PublicKey) => {
+        const sig = await provider.connection.requestAirdrop(receiver, 10 * LAMPORTS_PER_SOL);
+        await provider.connection.confirmTransaction(sig, 'confirmed');
+    };
+
+    before(async () => {
+        await airdrop(walletA.publicKey);
+        await airdrop(walletB.publicKey);
+
+        await forumClient.initForum(
+            forum,
+            provider.wallet.publicKey,
+            forumConfig.forumFees,
+            forumConfig.forumConstants,
+            forumConfig.reputationMatrix,
+        );
+
+        ({ userProfile: userProfileA } = await forumClient.createUserProfile(forum.publicKey, walletA));
+    });
+
+    it('rotates a user profile to a new wallet', async () => {
+        await forumClient.approveWalletRotation(forum.publicKey, walletA, userProfileA, walletB.publicKey);
+        await forumClient.acceptWalletRotation(forum.publicKey, walletB, userProfileA);
+
+        const userProfileAcct = await forumClient.fetchUserProfileAccount(userProfileA);
+        assert.isTrue(userProfileAcct.profileOwner.equals(walletB.publicKey));
+        assert.isTrue(userProfileAcct.profileSeed.equals(walletA.publicKey));
+    });
+
+    it('prevents the new wallet from creating a second user profile while it owns the rotated one', async () => {
+        await expect(forumClient.createUserProfile(forum.publicKey, walletB)).to.be.rejected;
+    });
+
+    it('frees the new wallet to create a user profile once the rotated one is deleted', async () => {
+        await forumClient.deleteUserProfile(forum.publicKey, walletB, walletB.publicKey, walletA.publicKey);
+
+        const [walletRotation] = await findWalletRotationPDA(forum.publicKey, walletB.publicKey);
+        assert.isNull(await provider.connection.getAccountInfo(walletRotation));
+        assert.isNull(await provider.connection.getAccountInfo(userProfileA));
+
+        const { userProfile: userProfileB } = await forumClient.createUserProfile(forum.publicKey, walletB);
+
+        const [expectedUserProfileB] = await findUserProfilePDA(forum.publicKey, walletB.publicKey);
+        assert.isTrue(userProfileB.equals(expectedUserProfileB));
+
+        const userProfileAcct = await forumClient.fetchUserProfileAccount(userProfileB);
+        assert.isTrue(userProfileAcct.profileOwner.equals(walletB.publicKey));
+        assert.isTrue(userProfileAcct.profileSeed.equals(walletB.publicKey));
+    });
+});